@@ -0,0 +1,152 @@
+//! Procedural macros for [mediatrix](https://docs.rs/mediatrix).
+//!
+//! Use them through the `derive` feature of `mediatrix`
+//! instead of depending on this crate directly.
+#![deny(missing_docs, unused_imports, unsafe_code)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::{
+    parse_macro_input, Error, FnArg, GenericArgument, ImplItem, ImplItemFn, ItemImpl,
+    PathArguments, ReturnType, Type,
+};
+
+/// Generates a request handler implementation for every method of an `impl` block.
+///
+/// The `impl` block must be on a mediator, e.g. `BasicMediator<MyEvent>`,
+/// whose last generic argument is the event type `Ev`.
+/// Every method must take `&self` and the request `Req` by value.
+/// The method name is irrelevant, the request type decides which handler is generated:
+///
+/// - `fn(&self, req: Req) -> Resp` implements `RequestHandler<Req, Ev, Resp>`
+/// - `fn(&self, req: Req, cx: &Cx) -> Resp` implements `CxAwareRequestHandler<Cx, Req, Ev, Resp>`
+/// - `async fn(&self, req: Req) -> Resp` implements `AsyncRequestHandler<Req, Ev, Resp>`
+/// - `async fn(&self, req: Req, cx: &Cx) -> Resp` implements `CxAwareAsyncRequestHandler<Cx, Req, Ev, Resp>`
+///
+/// If no return type is given, `Resp` is `()`.
+#[proc_macro_attribute]
+pub fn mediator_handler(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return Error::new_spanned(
+            TokenStream2::from(attr),
+            "mediator_handler does not take any arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let item = parse_macro_input!(item as ItemImpl);
+    expand(item)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn expand(item: ItemImpl) -> syn::Result<TokenStream2> {
+    if let Some((path, _)) = &item.trait_ {
+        return Err(Error::new_spanned(
+            path,
+            "mediator_handler expects an inherent impl block",
+        ));
+    }
+    let ev = event_type(&item.self_ty)?;
+    item.items
+        .iter()
+        .map(|impl_item| match impl_item {
+            ImplItem::Fn(f) => expand_fn(&item, ev, f),
+            other => Err(Error::new_spanned(
+                other,
+                "mediator_handler only supports methods",
+            )),
+        })
+        .collect()
+}
+
+/// Extracts the event type `Ev` as the last generic argument of the mediator.
+fn event_type(self_ty: &Type) -> syn::Result<&Type> {
+    let Type::Path(ty) = self_ty else {
+        return Err(Error::new_spanned(self_ty, "expected a mediator type"));
+    };
+    let args = ty.path.segments.last().map(|segment| &segment.arguments);
+    let Some(PathArguments::AngleBracketed(args)) = args else {
+        return Err(Error::new_spanned(
+            self_ty,
+            "expected a mediator type with an event type argument",
+        ));
+    };
+    match args.args.last() {
+        Some(GenericArgument::Type(ev)) => Ok(ev),
+        _ => Err(Error::new_spanned(
+            self_ty,
+            "expected the event type as last type argument",
+        )),
+    }
+}
+
+fn expand_fn(item: &ItemImpl, ev: &Type, f: &ImplItemFn) -> syn::Result<TokenStream2> {
+    let sig = &f.sig;
+    if sig.receiver().is_none() {
+        return Err(Error::new_spanned(sig, "handler methods must take `&self`"));
+    }
+    let args: Vec<_> = sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(arg) => Some(arg),
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+    let resp = match &sig.output {
+        ReturnType::Default => quote!(()),
+        ReturnType::Type(_, ty) => ty.to_token_stream(),
+    };
+    let is_async = sig.asyncness.is_some();
+
+    let (trait_path, params) = match args.as_slice() {
+        [req] => {
+            let req_ty = &req.ty;
+            let path = if is_async {
+                quote!(::mediatrix::asynchronous::basic::AsyncRequestHandler<#req_ty, #ev, #resp>)
+            } else {
+                quote!(::mediatrix::synchronous::basic::RequestHandler<#req_ty, #ev, #resp>)
+            };
+            (path, quote!(#req))
+        }
+        [req, cx] => {
+            let req_ty = &req.ty;
+            let Type::Reference(cx_ty) = &*cx.ty else {
+                return Err(Error::new_spanned(
+                    &cx.ty,
+                    "the context must be taken by reference, e.g. `cx: &Cx`",
+                ));
+            };
+            let cx_ty = &cx_ty.elem;
+            let path = if is_async {
+                quote!(::mediatrix::asynchronous::contextaware::CxAwareAsyncRequestHandler<#cx_ty, #req_ty, #ev, #resp>)
+            } else {
+                quote!(::mediatrix::synchronous::contextaware::CxAwareRequestHandler<#cx_ty, #req_ty, #ev, #resp>)
+            };
+            (path, quote!(#req, #cx))
+        }
+        _ => {
+            return Err(Error::new_spanned(
+                &sig.inputs,
+                "handler methods must take a request and optionally a context: `(&self, req: Req)` or `(&self, req: Req, cx: &Cx)`",
+            ))
+        }
+    };
+
+    let attrs = &f.attrs;
+    let block = &f.block;
+    let self_ty = &item.self_ty;
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+    let asyncness = &sig.asyncness;
+    let async_trait = is_async.then(|| quote!(#[::mediatrix::__private::async_trait]));
+
+    Ok(quote! {
+        #async_trait
+        impl #impl_generics #trait_path for #self_ty #where_clause {
+            #(#attrs)*
+            #asyncness fn handle(&self, #params) -> #resp #block
+        }
+    })
+}
@@ -6,13 +6,13 @@
 //! The [`BasicAsyncMediator`] on the other hand is needed for asynchronous
 //! handlers.
 //! If you need your handler to include some sort of context,
-//! use the [`CxAwareAsyncMediator`]. This mediator requires a user-defined
-//! type to be injected through its builder.
+//! use the [`CxAwareMediator`] or the [`CxAwareAsyncMediator`]. These mediators require a user-defined
+//! type to be injected through their builder.
 //!
 //! # Crate Architecture
 //!
 //! The crate is structured in an additive way.
-//! The [`BasicMediator`] is used as a basis for [`BasicAsyncMediator`].
+//! The [`BasicMediator`] is used as a basis for [`BasicAsyncMediator`] and [`CxAwareMediator`].
 //! In turn, the [`BasicAsyncMediator`] is used as a basis for the [`CxAwareAsyncMediator`].
 //! This way, code duplication is minimal and the code in general is less error-prone.
 //!
@@ -30,21 +30,130 @@
 //! internal structure and implementation details for the
 //! respective mediator.
 //!
+//! # Features
+//!
+//! - `async`: enables the asynchronous mediators.
+//! - `derive`: enables the [`mediator_handler`] attribute, which generates
+//!   request handler implementations from plain methods.
+//!
 //! [`BasicMediator`]: synchronous::basic::BasicMediator
+//! [`CxAwareMediator`]: synchronous::contextaware::CxAwareMediator
 //! [`BasicAsyncMediator`]: asynchronous::basic::BasicAsyncMediator
 //! [`CxAwareAsyncMediator`]: asynchronous::contextaware::CxAwareAsyncMediator
 
 #![doc(html_root_url = "https://docs.rs/mediatrix/1.0.0")]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 #![deny(missing_docs, unused_imports, unsafe_code)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 mod mediator;
 
+#[cfg(feature = "actix")]
+pub use mediator::actix;
 #[cfg(feature = "async")]
 pub use mediator::asynchronous;
+#[cfg(feature = "axum")]
+pub use mediator::axum;
+pub use mediator::behavior;
+pub use mediator::bridge;
+#[cfg(feature = "brokers")]
+pub use mediator::brokers;
 pub use mediator::builder;
+#[cfg(feature = "async")]
+pub use mediator::cancellation;
+#[cfg(feature = "causality")]
+pub use mediator::causality;
+pub use mediator::channel;
+#[cfg(feature = "persistence")]
+pub use mediator::codec;
+pub use mediator::correlation;
+pub use mediator::deadletter;
+pub use mediator::dependencies;
+pub use mediator::error;
+#[cfg(feature = "async")]
+pub use mediator::executor;
+#[cfg(feature = "async")]
+pub use mediator::lifecycle;
 pub use mediator::listener;
+#[cfg(feature = "tracing")]
+pub use mediator::logging;
+pub use mediator::observer;
+#[cfg(feature = "async")]
+pub use mediator::ordering;
+#[cfg(feature = "persistence")]
+pub use mediator::persistence;
+pub use mediator::profile;
+#[cfg(feature = "async")]
+pub use mediator::queue;
+#[cfg(feature = "std")]
+pub use mediator::ratelimit;
+#[cfg(feature = "remote")]
+pub use mediator::remote;
+#[cfg(feature = "async")]
+pub use mediator::retry;
+pub use mediator::saga;
+#[cfg(feature = "schema")]
+pub use mediator::schema;
+pub use mediator::stats;
 pub use mediator::synchronous;
+pub use mediator::system;
+#[cfg(feature = "async")]
+pub use mediator::testing;
+pub use mediator::topics;
+#[cfg(feature = "async")]
+pub use mediator::worker;
+
+/// Generates request handler implementations for a mediator.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use mediatrix::mediator_handler;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     Incremented
+/// }
+///
+/// struct Increment;
+/// struct Square(u32);
+///
+/// #[mediator_handler]
+/// impl BasicMediator<MyEvent> {
+///     fn increment(&self, _req: Increment) {
+///         self.publish(MyEvent::Incremented);
+///     }
+///
+///     fn square(&self, req: Square) -> u32 {
+///         req.0 * req.0
+///     }
+/// }
+///
+/// let mediator = BasicMediator::<MyEvent>::builder().build();
+///
+/// mediator.send(Increment);
+/// assert_eq!(mediator.send_and_receive::<_, u32>(Square(3)), 9);
+/// assert_eq!(mediator.next_all(), 1);
+///
+#[cfg(feature = "derive")]
+pub use mediatrix_derive::mediator_handler;
+
+#[doc(hidden)]
+pub mod __private {
+    //! Used by the code generated through the `derive` feature.
+
+    #[cfg(feature = "async")]
+    pub use async_trait::async_trait;
+}
+
+#[cfg(test)]
+extern crate self as mediatrix;
 
 #[cfg(test)]
+#[allow(clippy::assign_op_pattern, clippy::clone_on_copy)]
 mod test;
@@ -0,0 +1,239 @@
+use ::actix::{Actor, Context, Handler, Message, Recipient, ResponseFuture};
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::mediator::{
+    asynchronous::{
+        basic::{AsyncRequestHandler, BasicAsyncMediator, MediatorHandle},
+        contextaware::{CxAwareAsyncMediator, CxAwareAsyncRequestHandler, CxAwareMediatorHandle},
+    },
+    listener::MaybeDebug,
+    observer::MediatorObserver,
+};
+
+/// Forwards every event published by a mediator to an actix [`Recipient`] as a message.
+///
+/// A [`RecipientForwarder`] is a [`MediatorObserver`], add it to a mediator with `with_observer()`.
+/// Events are cloned and sent with [`Recipient::do_send()`],
+/// so publishing never waits for the actor.
+/// Events are dropped once the actor has stopped.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::asynchronous::basic::*;
+/// use mediatrix::actix::RecipientForwarder;
+/// use actix::{Actor, Context, Handler, Message};
+///
+/// #[derive(Debug, Clone)]
+/// enum MyEvent {
+///     Greeted(String)
+/// }
+///
+/// impl Message for MyEvent {
+///     type Result = ();
+/// }
+///
+/// struct Greeter;
+///
+/// impl Actor for Greeter {
+///     type Context = Context<Self>;
+/// }
+///
+/// impl Handler<MyEvent> for Greeter {
+///     type Result = ();
+///
+///     fn handle(&mut self, ev: MyEvent, _ctx: &mut Context<Self>) {
+///         println!("{ev:?}");
+///     }
+/// }
+///
+/// actix::System::new().block_on(async {
+///     let greeter = Greeter.start();
+///     let mediator = BasicAsyncMediator::<MyEvent>::builder()
+///         .with_observer(RecipientForwarder::new(greeter.recipient()))
+///         .build();
+///
+///     mediator.publish(MyEvent::Greeted("actix".to_string())).await;
+/// });
+///
+pub struct RecipientForwarder<Ev>
+where
+    Ev: Message<Result = ()> + Send,
+{
+    recipient: Recipient<Ev>,
+}
+
+impl<Ev> RecipientForwarder<Ev>
+where
+    Ev: Message<Result = ()> + Send,
+{
+    /// Creates a [`RecipientForwarder`] sending all events to the `recipient`.
+    pub fn new(recipient: Recipient<Ev>) -> Self {
+        Self { recipient }
+    }
+}
+
+impl<Ev> MediatorObserver<Ev> for RecipientForwarder<Ev>
+where
+    Ev: MaybeDebug + Message<Result = ()> + Clone + Send,
+{
+    fn on_event_published(&self, ev: &Ev, _topic: Option<&str>) {
+        self.recipient.do_send(ev.clone());
+    }
+}
+
+impl<Ev> Debug for RecipientForwarder<Ev>
+where
+    Ev: Message<Result = ()> + Send,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecipientForwarder").finish_non_exhaustive()
+    }
+}
+
+/// A request `Req` sent by an actor to a [`MediatorActor`],
+/// which answers with the response `Resp` of the request handler.
+pub struct MediatorRequest<Req, Resp = ()> {
+    req: Req,
+    _resp: PhantomData<fn() -> Resp>,
+}
+
+impl<Req, Resp> MediatorRequest<Req, Resp> {
+    /// Wraps the request `req` to send it to a [`MediatorActor`].
+    pub fn new(req: Req) -> Self {
+        Self {
+            req,
+            _resp: PhantomData,
+        }
+    }
+}
+
+impl<Req, Resp> Message for MediatorRequest<Req, Resp>
+where
+    Resp: 'static,
+{
+    type Result = Resp;
+}
+
+impl<Req: Debug, Resp> Debug for MediatorRequest<Req, Resp> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("MediatorRequest").field(&self.req).finish()
+    }
+}
+
+/// An actor accepting [`MediatorRequest`]s from other actors
+/// and sending them to an async mediator through its handle `H`,
+/// either a [`MediatorHandle`] or a [`CxAwareMediatorHandle`].
+///
+/// This allows migrating between actors and request handlers gradually:
+/// actors keep sending messages, while the mediator handles them.
+/// The requests are handled on the actix runtime, events are still processed by the mediator.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::asynchronous::basic::*;
+/// use mediatrix::actix::{MediatorActor, MediatorRequest};
+/// use actix::Actor;
+/// use async_trait::async_trait;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     Doubled(u32)
+/// }
+///
+/// struct Double(u32);
+///
+/// #[async_trait]
+/// impl AsyncRequestHandler<Double, MyEvent, u32> for BasicAsyncMediator<MyEvent> {
+///     async fn handle(&self, req: Double) -> u32 {
+///         self.publish(MyEvent::Doubled(req.0 * 2)).await;
+///         req.0 * 2
+///     }
+/// }
+///
+/// actix::System::new().block_on(async {
+///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+///     let actor = MediatorActor::new(mediator.new_handle()).start();
+///
+///     let doubled = actor.send(MediatorRequest::<_, u32>::new(Double(21))).await.unwrap();
+///     assert_eq!(doubled, 42);
+///     assert_eq!(mediator.next_all().await, 1);
+/// });
+///
+pub struct MediatorActor<H> {
+    handle: H,
+}
+
+impl<H> MediatorActor<H>
+where
+    H: Unpin + 'static,
+{
+    /// Creates a [`MediatorActor`] sending requests through the `handle`.
+    pub fn new(handle: H) -> Self {
+        Self { handle }
+    }
+}
+
+impl<H> Actor for MediatorActor<H>
+where
+    H: Unpin + 'static,
+{
+    type Context = Context<Self>;
+}
+
+impl<H> Debug for MediatorActor<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MediatorActor").finish_non_exhaustive()
+    }
+}
+
+impl<Ev, Req, Resp> Handler<MediatorRequest<Req, Resp>> for MediatorActor<MediatorHandle<Ev>>
+where
+    BasicAsyncMediator<Ev>: AsyncRequestHandler<Req, Ev, Resp>,
+    Ev: MaybeDebug + Send + Sync,
+    Req: Send + 'static,
+    Resp: Send + 'static,
+{
+    type Result = ResponseFuture<Resp>;
+
+    fn handle(
+        &mut self,
+        msg: MediatorRequest<Req, Resp>,
+        _ctx: &mut Context<Self>,
+    ) -> Self::Result {
+        let handle = self.handle.clone();
+        Box::pin(async move { handle.send_and_receive(msg.req).await })
+    }
+}
+
+impl<Cx, Ev, Req, Resp> Handler<MediatorRequest<Req, Resp>>
+    for MediatorActor<CxAwareMediatorHandle<Cx, Ev>>
+where
+    CxAwareAsyncMediator<Cx, Ev>: CxAwareAsyncRequestHandler<Cx, Req, Ev, Resp>,
+    Cx: Send + Sync + 'static,
+    Ev: MaybeDebug + Send + Sync,
+    Req: Send + 'static,
+    Resp: Send + 'static,
+{
+    type Result = ResponseFuture<Resp>;
+
+    fn handle(
+        &mut self,
+        msg: MediatorRequest<Req, Resp>,
+        _ctx: &mut Context<Self>,
+    ) -> Self::Result {
+        let handle = self.handle.clone();
+        Box::pin(async move {
+            // Requests are sent from the actor, which never holds the context.
+            handle
+                .send_and_receive(msg.req)
+                .await
+                .expect("the actor doesn't hold the context")
+        })
+    }
+}
@@ -1,11 +1,63 @@
-use std::sync::mpsc::TryRecvError;
+use std::{
+    any::{type_name, type_name_of_val, Any},
+    iter,
+};
 
-use async_std::sync::Mutex;
 use async_trait::async_trait;
-use std::fmt::Debug;
+use event_listener::Event;
+use futures_core::Stream;
+use std::{
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+#[cfg(feature = "causality")]
+use crate::mediator::causality::CausalityGraph;
+#[cfg(feature = "schema")]
+use crate::mediator::schema::MediatorDescription;
+#[cfg(feature = "persistence")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "causality")]
+use std::collections::BTreeMap;
+#[cfg(feature = "persistence")]
+use std::{io, path::Path};
 
 use super::*;
-use crate::synchronous::basic::{BasicMediator, SyncMediatorInternal, SyncMediatorInternalNext};
+use crate::mediator::{
+    behavior::{run_async_pipeline, AnyRequest},
+    cancellation::{cancellable, timeout},
+    channel::{OverflowPolicy, TryRecvError, TrySendError},
+    concurrency::join_limited,
+    correlation::{self, CorrelationId, Trace},
+    instrument,
+    listener::{ListenerPanic, ListenerRegistry},
+    observer::Observers,
+    ordering::{Sequencer, Turn},
+    queue::{Priority, RequestQueue},
+    ratelimit::Throttled,
+    registry::{AsyncDynHandler, AsyncFanOutHandler, BoxFuture, HandlerRegistry},
+    retry::Retry,
+    runtime,
+    scheduler::Scheduler,
+    stats::MediatorStats,
+    system,
+    ticker::{self, Ticker},
+    time::{Instant, Stopwatch},
+    topics::Envelope,
+    worker::Worker,
+};
+#[cfg(feature = "persistence")]
+use crate::mediator::{
+    codec::{Codec, JsonCodec},
+    persistence::read_journal_with,
+};
+use crate::synchronous::basic::{
+    BasicMediator, SyncMediatorInternalDeadLetters, SyncMediatorInternalSubscribe,
+};
 
 /// Basic async mediator for asynchronous environments with events of type `Ev`.
 ///
@@ -15,8 +67,12 @@ use crate::synchronous::basic::{BasicMediator, SyncMediatorInternal, SyncMediato
 /// From within this `async` handler, events of type `Ev` can be published using the
 /// [`BasicAsyncMediator::publish()`] functionality.
 /// Listeners injected with [`super::BasicAsyncBuilder::add_listener()`]
+/// and [`super::BasicAsyncBuilder::add_async_listener()`]
 /// are invoked when the user calls [`BasicAsyncMediator::next()`].
 ///
+/// A [`BasicAsyncMediator`] is [`Send`] and [`Sync`] whenever `Ev` is [`Send`],
+/// so it can be shared between tasks behind an `Arc`.
+///
 /// # Examples
 ///
 /// Basic usage:
@@ -63,20 +119,313 @@ use crate::synchronous::basic::{BasicMediator, SyncMediatorInternal, SyncMediato
 #[derive(Debug)]
 pub struct BasicAsyncMediator<Ev>
 where
-    Ev: Debug + 'static,
+    Ev: MaybeDebug + 'static,
+{
+    pub(crate) inner: Arc<Inner<Ev>>,
+}
+
+/// The state of a [`BasicAsyncMediator`],
+/// which is shared with its [`MediatorHandle`]s.
+#[derive(Debug)]
+pub(crate) struct Inner<Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    pub(crate) basic: BasicMediator<Ev>,
+    pub(crate) async_listener: ListenerRegistry<Arc<dyn AsyncListener<Ev>>>,
+    pub(crate) async_shared_listener: ListenerRegistry<Arc<dyn AsyncListener<Arc<Ev>>>>,
+    pub(crate) published: Arc<Event>,
+    pub(crate) consumed: Event,
+    pub(crate) behavior: Vec<Box<dyn AsyncBehavior>>,
+    pub(crate) observer: Observers<Ev>,
+    pub(crate) retry: Retry<Ev>,
+    pub(crate) scheduler: Scheduler<Ev>,
+    pub(crate) tickers: Vec<Ticker<Ev>>,
+    pub(crate) requests: RequestQueue<Ev>,
+    pub(crate) handlers: HandlerRegistry<AsyncDynHandler>,
+    pub(crate) concurrency: usize,
+    pub(crate) sequencer: Sequencer,
+}
+
+impl<Ev> BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Receives the next published event, invokes all listeners
+    /// with a `&Ev` and hands the event back to the caller,
+    /// together with all caught listener panics.
+    pub(crate) async fn process_next(
+        &self,
+    ) -> Result<(Envelope<Ev>, Vec<ListenerPanic>), TryRecvError> {
+        let m = &self.inner.basic;
+        self.flush_scheduled(m);
+        if m.paused.is_paused() {
+            return Err(TryRecvError::Empty);
+        }
+        let envelope = m.channel.try_recv()?;
+        let turn = self.inner.sequencer.turn(envelope.topic.as_deref());
+        self.process(envelope, turn).await
+    }
+
+    /// Same as [`BasicAsyncMediator::process_next()`], but with an already received `envelope`
+    /// and the `turn` taken when receiving it.
+    async fn process<'a>(
+        &'a self,
+        mut envelope: Envelope<Ev>,
+        mut turn: Option<Turn<'a>>,
+    ) -> Result<(Envelope<Ev>, Vec<ListenerPanic>), TryRecvError> {
+        let m = &self.inner.basic;
+        let sequencer = &self.inner.sequencer;
+        let (envelope, turn) = loop {
+            let limited = self.limit_rate(m, envelope).await?;
+            turn = sequencer.retake(turn, limited.topic.as_deref());
+            if let Some(envelope) = m.unexpired(limited) {
+                break (envelope, turn);
+            }
+            drop(turn);
+            envelope = m.channel.try_recv()?;
+            turn = sequencer.turn(envelope.topic.as_deref());
+        };
+        let (envelope, mut panics) = m.notify_all(envelope);
+        let isolate = m.isolate_listeners;
+        self.inner.consumed.notify(usize::MAX);
+        let topic = envelope.topic.as_deref();
+        if let Some(turn) = &turn {
+            turn.wait().await;
+        }
+        let limit = self.listener_limit();
+        correlation::scope_async(
+            envelope.trace,
+            instrument::next_async(&envelope.ev, topic, async {
+                if isolate {
+                    panics.extend(
+                        self.inner
+                            .async_listener
+                            .notify_isolated(&envelope.ev, topic, &self.inner.observer, limit)
+                            .await,
+                    );
+                } else {
+                    self.inner
+                        .async_listener
+                        .notify(&envelope.ev, topic, &self.inner.observer, limit)
+                        .await;
+                }
+            }),
+        )
+        .await;
+        let processed = self.notify_shared(envelope, panics).await;
+        m.observer.counters.processed();
+        Ok(processed)
+    }
+
+    /// Applies the rate limit, if any, to the received `envelope`,
+    /// waiting without blocking the thread.
+    async fn limit_rate(
+        &self,
+        m: &BasicMediator<Ev>,
+        mut envelope: Envelope<Ev>,
+    ) -> Result<Envelope<Ev>, TryRecvError> {
+        let Some(limiter) = &m.rate_limit else {
+            return Ok(envelope);
+        };
+        loop {
+            match m.throttle(limiter, envelope)? {
+                Throttled::Deliver(envelope) => return Ok(envelope),
+                Throttled::Wait(pending, wait) => {
+                    runtime::sleep(wait).await;
+                    envelope = m.coalesce(limiter, pending);
+                }
+            }
+        }
+    }
+
+    /// Returns how many listeners of a single event may be awaited concurrently.
+    fn listener_limit(&self) -> usize {
+        let ordering = self.inner.sequencer.ordering;
+        ordering.listener_limit(self.inner.concurrency)
+    }
+
+    /// Awaits all async shared listeners with an `Arc<Ev>` and takes the event back afterwards.
+    /// The event is only cloned if a listener kept the [`Arc`].
+    async fn notify_shared(
+        &self,
+        envelope: Envelope<Ev>,
+        mut panics: Vec<ListenerPanic>,
+    ) -> (Envelope<Ev>, Vec<ListenerPanic>) {
+        let Some(recover) = self.inner.basic.recover else {
+            return (envelope, panics);
+        };
+        let Envelope {
+            topic,
+            trace,
+            ev,
+            attempts,
+            expiry,
+        } = envelope;
+        let ev = Arc::new(ev);
+        let listeners = &self.inner.async_shared_listener;
+        let (observer, limit) = (&self.inner.observer, self.listener_limit());
+        correlation::scope_async(trace, async {
+            if self.inner.basic.isolate_listeners {
+                panics.extend(
+                    listeners
+                        .notify_isolated(&ev, topic.as_deref(), observer, limit)
+                        .await,
+                );
+            } else {
+                listeners
+                    .notify(&ev, topic.as_deref(), observer, limit)
+                    .await;
+            }
+        })
+        .await;
+        let ev = recover(ev);
+        (
+            Envelope {
+                topic,
+                trace,
+                ev,
+                attempts,
+                expiry,
+            },
+            panics,
+        )
+    }
+
+    /// Same as [`BasicAsyncMediator::process_next()`],
+    /// but waits until an event is published.
+    ///
+    /// The default backend, an [`AsyncChannel`](crate::channel::AsyncChannel), is awaited directly.
+    /// Otherwise, this waits until everyone waiting for events is woken up,
+    /// which also happens for events that don't arrive through the channel:
+    /// urgent events, due scheduled events and resuming.
+    pub(crate) async fn process_next_async(
+        &self,
+    ) -> Result<(Envelope<Ev>, Vec<ListenerPanic>), NextError> {
+        let m = &self.inner.basic;
+        loop {
+            let woken = self.inner.published.listen();
+            match self.process_next().await {
+                Err(TryRecvError::Empty) => {}
+                res => return Ok(res?),
+            }
+            if m.paused.is_paused() {
+                woken.await;
+                continue;
+            }
+            let Some(received) = m.channel.recv_or(woken).await else {
+                continue;
+            };
+            // Same as `process_next()`, due scheduled events are queued
+            // and urgent events go first, even if they arrived while waiting.
+            self.flush_scheduled(m);
+            let received = m.channel.urgent_before(received?);
+            let turn = self.inner.sequencer.turn(received.topic.as_deref());
+            match self.process(received, turn).await {
+                Err(TryRecvError::Empty) => {}
+                res => return Ok(res?),
+            }
+        }
+    }
+
+    /// Moves the scheduled events that are due into the channel.
+    ///
+    /// If the channel is full and configured with [`OverflowPolicy::Block`],
+    /// the remaining events stay due until the next event was processed.
+    fn flush_scheduled(&self, m: &BasicMediator<Ev>) {
+        self.inner
+            .scheduler
+            .flush(|envelope| match m.try_dispatch(envelope) {
+                Err(TrySendError::Full(rejected)) if m.channel.policy == OverflowPolicy::Block => {
+                    Err(rejected)
+                }
+                Err(TrySendError::Full(rejected) | TrySendError::Disconnected(rejected)) => {
+                    m.dead_letter_overflow(vec![rejected]);
+                    Ok(())
+                }
+                Ok(()) => Ok(()),
+            });
+    }
+}
+
+impl<Ev> BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug,
 {
-    pub(crate) basic: Mutex<BasicMediator<Ev>>,
+    /// Sends the [`Envelope`] through the channel.
+    ///
+    /// If the channel is full and configured with [`OverflowPolicy::Block`],
+    /// this awaits space in the default backend, an [`AsyncChannel`](crate::channel::AsyncChannel).
+    /// Other backends wait until an event was processed.
+    async fn send_envelope(
+        &self,
+        envelope: Envelope<Ev>,
+    ) -> Result<(), TrySendError<Envelope<Ev>>> {
+        self.inner.observer.published(&envelope);
+        self.dispatch_envelope(envelope).await
+    }
+
+    /// Same as [`BasicAsyncMediator::send_envelope()`], but without notifying the observers.
+    async fn dispatch_envelope(
+        &self,
+        mut envelope: Envelope<Ev>,
+    ) -> Result<(), TrySendError<Envelope<Ev>>> {
+        let m = &self.inner.basic;
+        loop {
+            let consumed = self.inner.consumed.listen();
+            match m.dispatch_async(envelope).await {
+                Err(TrySendError::Full(rejected)) if m.channel.policy == OverflowPolicy::Block => {
+                    envelope = rejected;
+                    consumed.await;
+                }
+                res => {
+                    self.inner.published.notify(usize::MAX);
+                    return res;
+                }
+            }
+        }
+    }
+
+    /// Publishes an event `Ev` without waiting, even if the channel is configured
+    /// with [`OverflowPolicy::Block`]. A rejected event becomes a dead letter.
+    fn publish_now(&self, event: Ev) {
+        let m = &self.inner.basic;
+        let Some(event) = m.intercept(event) else {
+            return;
+        };
+        let envelope = Envelope::new(None, event);
+        self.inner.observer.published(&envelope);
+        if let Err(TrySendError::Full(rejected) | TrySendError::Disconnected(rejected)) =
+            m.try_dispatch(envelope)
+        {
+            m.dead_letter_overflow(vec![rejected]);
+        }
+        self.inner.published.notify(usize::MAX);
+    }
+
+    /// Same as [`BasicAsyncMediator::send_envelope()`],
+    /// but a rejected event becomes a dead letter instead of being handed back.
+    async fn publish_envelope(&self, envelope: Envelope<Ev>) {
+        if let Err(TrySendError::Full(envelope) | TrySendError::Disconnected(envelope)) =
+            self.send_envelope(envelope).await
+        {
+            self.inner.basic.dead_letter_overflow(vec![envelope]);
+        }
+    }
 }
 
 #[async_trait]
 impl<Ev> AsyncMediatorInternal<Ev> for BasicAsyncMediator<Ev>
 where
-    Ev: Debug + Send,
+    Ev: MaybeDebug + Send,
 {
     /// Publishes an event `Ev` asynchronously.
     ///
-    /// This method locks the `Mutex` and instructs
-    /// the underlying [`BasicMediator`] to publish an event.
+    /// This method instructs the underlying [`BasicMediator`] to publish an event.
+    /// No lock is taken, so publishing never waits for listeners that are currently running.
+    /// Everyone waiting in [`BasicAsyncMediator::next_async()`] is woken up.
+    /// If the channel is bounded, full and configured with [`OverflowPolicy::Block`],
+    /// this waits until an event was processed.
     /// Best used within [`AsyncRequestHandler::handle()`].
     ///
     /// You need to await the `Future` using `.await`.
@@ -109,48 +458,1731 @@ where
     /// }
     ///
     async fn publish(&self, event: Ev) {
-        let m = self.basic.lock().await;
-        m.publish(event)
+        let Some(event) = self.inner.basic.intercept(event) else {
+            return;
+        };
+        self.publish_envelope(Envelope::new(None, event)).await
+    }
+
+    /// Tries to publish an event `Ev` asynchronously.
+    ///
+    /// Behaves like [`BasicAsyncMediator::publish()`], but if the channel is full
+    /// and [`OverflowPolicy::Error`] was configured,
+    /// the event is handed back as a [`TrySendError::Full`].
+    ///
+    /// See [`BasicMediator::try_publish()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn try_publish(&self, event: Ev) -> Result<(), TrySendError<Ev>> {
+        let Some(event) = self.inner.basic.intercept(event) else {
+            return Ok(());
+        };
+        self.send_envelope(Envelope::new(None, event))
+            .await
+            .map_err(Envelope::open_rejected)
+    }
+
+    /// Publishes an event `Ev` to the given `topic` asynchronously.
+    ///
+    /// See [`BasicMediator::publish_to()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn publish_to(&self, topic: &str, event: Ev) {
+        let Some(event) = self.inner.basic.intercept(event) else {
+            return;
+        };
+        self.publish_envelope(Envelope::new(Some(topic), event))
+            .await
+    }
+
+    /// Publishes an event `Ev` asynchronously that expires once the `ttl` elapsed.
+    ///
+    /// See [`BasicMediator::publish_with_ttl()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn publish_with_ttl(&self, event: Ev, ttl: Duration) {
+        let Some(event) = self.inner.basic.intercept(event) else {
+            return;
+        };
+        self.publish_envelope(Envelope::new(None, event).with_ttl(ttl))
+            .await
+    }
+
+    /// Publishes an urgent event `Ev` asynchronously, which is delivered before all regular events.
+    ///
+    /// The event never waits for space in the channel, even with [`OverflowPolicy::Block`],
+    /// and everyone waiting in [`BasicAsyncMediator::next_async()`] is woken up.
+    ///
+    /// See [`BasicMediator::publish_urgent()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn publish_urgent(&self, event: Ev) {
+        let Some(event) = self.inner.basic.intercept(event) else {
+            return;
+        };
+        let envelope = Envelope::new(None, event);
+        self.inner.observer.published(&envelope);
+        self.inner.basic.channel.send_urgent(envelope);
+        self.inner.published.notify(usize::MAX);
+    }
+
+    /// Publishes an event `Ev` asynchronously once the `delay` elapsed.
+    ///
+    /// The event is handed to an internal timer task and this method returns immediately.
+    /// Once the `delay` elapsed, the event is published and
+    /// everyone waiting in [`BasicAsyncMediator::next_async()`] is woken up.
+    /// Due events enter the channel when events are processed,
+    /// so the [`OverflowPolicy`] applies as usual.
+    /// Events that are not due yet when the mediator is dropped are discarded.
+    /// Best used within [`AsyncRequestHandler::handle()`] to schedule
+    /// follow-up events like reminders, timeouts or heartbeats.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Ordered,
+    ///     Reminder
+    /// }
+    ///
+    /// struct Order;
+    ///
+    /// #[async_trait]
+    /// impl AsyncRequestHandler<Order, MyEvent> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, _: Order) {
+    ///         self.publish(MyEvent::Ordered).await;
+    ///         self.publish_after(MyEvent::Reminder, Duration::from_millis(10)).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///
+    ///     mediator.send(Order).await;
+    ///     assert_eq!(mediator.next_all().await, 1);
+    ///
+    ///     // Waits for the reminder.
+    ///     mediator.next_async().await.ok();
+    /// });
+    ///
+    async fn publish_after(&self, event: Ev, delay: Duration) {
+        self.publish_at(event, Instant::now() + delay).await
+    }
+
+    /// Publishes an event `Ev` asynchronously at the given `deadline`.
+    ///
+    /// A `deadline` in the past publishes the event as soon as possible.
+    ///
+    /// See [`BasicAsyncMediator::publish_after()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn publish_at(&self, event: Ev, deadline: Instant) {
+        let Some(event) = self.inner.basic.intercept(event) else {
+            return;
+        };
+        self.inner
+            .scheduler
+            .schedule(Envelope::new(None, event), deadline);
+    }
+}
+
+impl<Ev> BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Passes the request `Req` through all behaviors to `handle` asynchronously.
+    pub(crate) async fn run_request<Req, Fut>(
+        &self,
+        op: &'static str,
+        req: Req,
+        handle: impl FnOnce(Req) -> Fut + Send,
+    ) where
+        Req: Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        self.report::<Req, _>(
+            system::completed,
+            self.inner.observer.request_async::<Req, _>(op, async {
+                if self.inner.behavior.is_empty() {
+                    return handle(req).await;
+                }
+                run_async_pipeline(&self.inner.behavior, AnyRequest::new(req), |req| {
+                    handle(req.into_inner())
+                })
+                .await
+            }),
+        )
+        .await
+    }
+
+    /// Awaits the request `Req` and publishes its request event afterwards,
+    /// if enabled with `with_request_events()`. `outcome` tells whether it failed.
+    ///
+    /// The request event never waits for a full channel, see [`BasicAsyncMediator::publish_now()`].
+    pub(crate) async fn report<Req, Fut>(
+        &self,
+        outcome: impl FnOnce(&Fut::Output) -> Option<&'static str>,
+        fut: Fut,
+    ) -> Fut::Output
+    where
+        Fut: Future,
+    {
+        let Some(event) = self.inner.basic.request_events else {
+            return fut.await;
+        };
+        let start = Stopwatch::start();
+        let res = fut.await;
+        self.publish_now(event(type_name::<Req>(), start.elapsed(), outcome(&res)));
+        res
     }
 }
 
 #[async_trait]
 impl<Ev> AsyncMediatorInternalHandle<Ev> for BasicAsyncMediator<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Send a request of type `Req` to the mediator asynchronously.
     ///
     /// The request will be processed internally by [`AsyncRequestHandler::handle()`].
     /// This is why it is required to implement [`AsyncRequestHandler`] for [`BasicAsyncMediator`].
+    /// If any [`AsyncBehavior`] was added, the request passes through them first.
     ///
     /// You need to await the `Future` using `.await`.
     ///
     async fn send<Req>(&self, req: Req)
     where
         Self: AsyncRequestHandler<Req, Ev>,
-        Req: Send,
+        Req: Send + 'static,
     {
-        <Self as AsyncRequestHandler<Req, Ev>>::handle(self, req).await
+        self.run_request("send", req, |req| {
+            <Self as AsyncRequestHandler<Req, Ev>>::handle(self, req)
+        })
+        .await
     }
-}
 
-#[async_trait]
-impl<Ev> AsyncMediatorInternalNext for BasicAsyncMediator<Ev>
-where
-    Ev: Debug + Send,
-{
-    /// Process the next published event `Ev` asynchronously.
+    /// Send a request of type `Req` to the mediator without waiting for it to be handled.
     ///
-    /// This method locks the `Mutex` and instructs
-    /// the underlying [`BasicMediator`] to process the next event.
+    /// The request is processed like in [`BasicAsyncMediator::send()`],
+    /// but on a task spawned onto the runtime, so the caller continues right away.
+    /// This is useful for fire-and-forget commands.
+    /// Await the returned [`JoinHandle`] to wait for the request to be handled after all,
+    /// or drop it to detach the task.
     ///
-    /// See [`BasicMediator::next()`] for more info.
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Sent
+    /// }
+    ///
+    /// struct SendMail;
+    ///
+    /// #[async_trait]
+    /// impl AsyncRequestHandler<SendMail, MyEvent> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, _req: SendMail) {
+    ///         self.publish(MyEvent::Sent).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///
+    ///     let task = mediator.send_detached(SendMail);
+    ///     task.await;
+    ///
+    ///     assert_eq!(mediator.next_all().await, 1);
+    /// });
+    ///
+    fn send_detached<Req>(&self, req: Req) -> JoinHandle<()>
+    where
+        Self: AsyncRequestHandler<Req, Ev> + Send + Sync + 'static,
+        Req: Send + 'static,
+    {
+        let mediator = Self {
+            inner: self.inner.clone(),
+        };
+        JoinHandle::spawn(async move { mediator.send(req).await })
+    }
+
+    /// Send a batch of requests of type `Req` to the mediator asynchronously.
+    ///
+    /// The batch will be processed internally by [`AsyncBatchRequestHandler::handle_batch()`],
+    /// which is implemented for every [`AsyncRequestHandler`] and then handles the requests one after another.
+    /// See [`crate::synchronous::basic::BasicMediator::send_batch()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Inserted(usize)
+    /// }
+    ///
+    /// struct Insert(u32);
+    ///
+    /// #[async_trait]
+    /// impl AsyncBatchRequestHandler<Insert, MyEvent> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle_batch(&self, reqs: Vec<Insert>) {
+    ///         // Insert all rows in a single round-trip
+    ///         self.publish(MyEvent::Inserted(reqs.len())).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///
+    ///     mediator.send_batch(vec![Insert(1), Insert(2), Insert(3)]).await;
+    ///
+    ///     assert_eq!(mediator.next_all().await, 1);
+    /// });
+    ///
+    async fn send_batch<Req>(&self, reqs: Vec<Req>)
+    where
+        Self: AsyncBatchRequestHandler<Req, Ev>,
+        Req: Send + 'static,
+    {
+        self.run_request("send_batch", reqs, |reqs| {
+            <Self as AsyncBatchRequestHandler<Req, Ev>>::handle_batch(self, reqs)
+        })
+        .await
+    }
+
+    /// Send a request of unknown type to the mediator asynchronously.
+    ///
+    /// The request is processed by the handler registered for its type
+    /// via [`BasicAsyncBuilder::register_handler()`](super::BasicAsyncBuilder::register_handler()).
+    ///
+    /// See [`BasicMediator::send_dyn()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn send_dyn(&self, req: Box<dyn Any + Send>) -> Result<(), Box<dyn Any + Send>>
+    where
+        Self: Sync + 'static,
+    {
+        match self.inner.handlers.get(&*req) {
+            Some(handler) => {
+                handler(self, req).await;
+                Ok(())
+            }
+            None => Err(req),
+        }
+    }
+
+    /// Send a request of type `Req` to the mediator
+    /// and receive a stream of results of type `Item`.
+    ///
+    /// The request will be processed internally by [`StreamRequestHandler::handle()`].
+    /// This is why it is required to implement [`StreamRequestHandler`] for [`BasicAsyncMediator`].
+    /// The results are produced while the stream is polled,
+    /// which is useful for paginated or incremental results.
+    /// Events published while polling it carry `Req` as their source.
+    /// Behaviors are not applied to streaming requests.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use futures::stream::{self, Stream, StreamExt};
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Fetched(u32)
+    /// }
+    ///
+    /// struct Pages(u32);
+    ///
+    /// impl StreamRequestHandler<Pages, MyEvent, u32> for BasicAsyncMediator<MyEvent> {
+    ///     fn handle(&self, req: Pages) -> impl Stream<Item = u32> + Send + '_ {
+    ///         stream::iter(1..=req.0).then(move |page| async move {
+    ///             self.publish(MyEvent::Fetched(page)).await;
+    ///             page
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///
+    ///     let pages: Vec<u32> = mediator.send_stream(Pages(3)).collect().await;
+    ///     assert_eq!(pages, vec![1, 2, 3]);
+    ///     assert_eq!(mediator.next_all().await, 3);
+    /// });
+    ///
+    fn send_stream<Req, Item>(&self, req: Req) -> impl Stream<Item = Item> + Send + Unpin + '_
+    where
+        Self: StreamRequestHandler<Req, Ev, Item>,
+        Req: Send + 'static,
+    {
+        let stream = <Self as StreamRequestHandler<Req, Ev, Item>>::handle(self, req);
+        self.inner.observer.request_stream::<Req, _>(stream)
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously
+    /// and correlate it with the resulting events.
+    ///
+    /// The request is processed like in [`BasicAsyncMediator::send()`],
+    /// but a new [`CorrelationId`] is assigned to it and returned to the caller.
+    /// Every event published while handling the request carries that ID,
+    /// which listeners access through [`crate::correlation::Enveloped`]
+    /// or [`CorrelationId::current()`].
+    /// Tasks spawned by the handler don't carry the ID along.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn send_traced<Req>(&self, req: Req) -> CorrelationId
+    where
+        Self: AsyncRequestHandler<Req, Ev>,
+        Req: Send + 'static,
+    {
+        let id = CorrelationId::next();
+        correlation::scope_async(Trace::current().traced(id), self.send(req)).await;
+        id
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously
+    /// and receive a response of type `Resp`.
+    ///
+    /// The request will be processed internally by [`AsyncRequestHandler::handle()`],
+    /// whose return value is handed back to the caller.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Queried
+    /// }
+    ///
+    /// struct Square(u32);
+    ///
+    /// #[async_trait]
+    /// impl AsyncRequestHandler<Square, MyEvent, u32> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, req: Square) -> u32 {
+    ///         self.publish(MyEvent::Queried).await;
+    ///         req.0 * req.0
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///
+    ///     let res: u32 = mediator.send_and_receive(Square(3)).await;
+    ///     assert_eq!(res, 9);
+    /// });
+    ///
+    async fn send_and_receive<Req, Resp>(&self, req: Req) -> Resp
+    where
+        Self: AsyncRequestHandler<Req, Ev, Resp>,
+        Req: Send,
+        Resp: Send,
+    {
+        self.report::<Req, _>(
+            system::completed,
+            self.inner
+                .observer
+                .request_async::<Req, _>("send_and_receive", async {
+                    <Self as AsyncRequestHandler<Req, Ev, Resp>>::handle(self, req).await
+                }),
+        )
+        .await
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously,
+    /// which may fail with an error of type `Err`.
+    ///
+    /// The request will be processed internally by [`TryAsyncRequestHandler::handle()`],
+    /// whose error is handed back to the caller.
+    /// If any [`AsyncBehavior`] was added, the request passes through them first.
+    /// A request that is short-circuited by a behavior counts as `Ok(())`.
     ///
     /// You need to await the `Future` using `.await`.
     ///
-    async fn next(&self) -> Result<(), TryRecvError> {
-        let m = self.basic.lock().await;
-        m.next()
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Withdrawn(u32)
+    /// }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum WithdrawError {
+    ///     TooMuch
+    /// }
+    ///
+    /// struct Withdraw(u32);
+    ///
+    /// #[async_trait]
+    /// impl TryAsyncRequestHandler<Withdraw, MyEvent, WithdrawError> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, req: Withdraw) -> Result<(), WithdrawError> {
+    ///         if req.0 > 100 {
+    ///             return Err(WithdrawError::TooMuch);
+    ///         }
+    ///         self.publish(MyEvent::Withdrawn(req.0)).await;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///
+    ///     assert_eq!(mediator.try_send(Withdraw(50)).await, Ok(()));
+    ///     assert_eq!(mediator.try_send(Withdraw(500)).await, Err(WithdrawError::TooMuch));
+    /// });
+    ///
+    async fn try_send<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Self: TryAsyncRequestHandler<Req, Ev, Err>,
+        Req: Send + 'static,
+        Err: Send,
+    {
+        self.report::<Req, _>(
+            system::failed,
+            self.inner
+                .observer
+                .request_async::<Req, _>("try_send", async {
+                    if self.inner.behavior.is_empty() {
+                        return <Self as TryAsyncRequestHandler<Req, Ev, Err>>::handle(self, req)
+                            .await;
+                    }
+                    let mut res = Ok(());
+                    run_async_pipeline(&self.inner.behavior, AnyRequest::new(req), |req| async {
+                        res = <Self as TryAsyncRequestHandler<Req, Ev, Err>>::handle(
+                            self,
+                            req.into_inner(),
+                        )
+                        .await
+                    })
+                    .await;
+                    res
+                }),
+        )
+        .await
+    }
+
+    /// Try to send a request of type `Req` to the mediator asynchronously,
+    /// retrying it according to the configured [`RetryPolicy`].
+    ///
+    /// The request is processed like in [`BasicAsyncMediator::try_send()`].
+    /// If the handler fails, a clone of the request is sent again
+    /// after the backoff of the policy, which doubles with every attempt.
+    /// Once all attempts failed, the event configured with
+    /// [`super::BasicAsyncBuilder::on_retry_exhausted()`] is published, if any,
+    /// and the error of the last attempt is returned.
+    /// Without a configured policy, the request is attempted once.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    /// use std::{sync::atomic::{AtomicU32, Ordering}, time::Duration};
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     GaveUp(u32)
+    /// }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Unavailable;
+    ///
+    /// #[derive(Clone)]
+    /// struct Fetch;
+    ///
+    /// static CALLS: AtomicU32 = AtomicU32::new(0);
+    ///
+    /// #[async_trait]
+    /// impl TryAsyncRequestHandler<Fetch, MyEvent, Unavailable> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, _: Fetch) -> Result<(), Unavailable> {
+    ///         CALLS.fetch_add(1, Ordering::SeqCst);
+    ///         Err(Unavailable)
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///         .with_retry_policy(RetryPolicy {
+    ///             max_attempts: 3,
+    ///             backoff: Duration::from_millis(1),
+    ///         })
+    ///         .on_retry_exhausted(|exhausted| MyEvent::GaveUp(exhausted.attempts))
+    ///         .build();
+    ///
+    ///     assert_eq!(mediator.try_send_with_retry(Fetch).await, Err(Unavailable));
+    ///     assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+    /// });
+    ///
+    async fn try_send_with_retry<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Self: TryAsyncRequestHandler<Req, Ev, Err>,
+        Req: Clone + Send + Sync + 'static,
+        Ev: Send,
+        Err: Send,
+    {
+        match self
+            .inner
+            .retry
+            .run::<Req, _, _>(|| self.try_send(req.clone()))
+            .await
+        {
+            Ok(()) => Ok(()),
+            Err((err, exhausted)) => {
+                if let Some(ev) = exhausted {
+                    self.publish(ev).await;
+                }
+                Err(err)
+            }
+        }
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously,
+    /// which can be cancelled through the given [`CancellationToken`].
+    ///
+    /// The request is processed like in [`BasicAsyncMediator::send()`],
+    /// but as soon as `token` is cancelled, the handler is dropped
+    /// at its current await point and [`CancelledError`] is returned.
+    /// If `token` is already cancelled, the handler is not invoked at all.
+    /// Move a clone of `token` into the request to observe
+    /// the cancellation from within the handler.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Imported
+    /// }
+    ///
+    /// struct Import;
+    ///
+    /// #[async_trait]
+    /// impl AsyncRequestHandler<Import, MyEvent> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, _: Import) {
+    ///         /* Long-running work, e.g. hung I/O */
+    ///         async_std::task::sleep(Duration::from_secs(3600)).await;
+    ///         self.publish(MyEvent::Imported).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///     let token = CancellationToken::new();
+    ///
+    ///     let canceller = token.clone();
+    ///     async_std::task::spawn(async move { canceller.cancel() });
+    ///
+    ///     let res = mediator.send_with_cancellation(Import, &token).await;
+    ///     assert_eq!(res, Err(CancelledError));
+    /// });
+    ///
+    async fn send_with_cancellation<Req>(
+        &self,
+        req: Req,
+        token: &CancellationToken,
+    ) -> Result<(), CancelledError>
+    where
+        Self: AsyncRequestHandler<Req, Ev>,
+        Req: Send + 'static,
+    {
+        cancellable(self.send(req), token).await
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously,
+    /// which has to complete within the given `duration`.
+    ///
+    /// The request is processed like in [`BasicAsyncMediator::send()`],
+    /// but races the handler against a timer.
+    /// If the timer elapses first, the handler is dropped
+    /// at its current await point and [`TimeoutError`] is returned.
+    /// This way, a stuck handler, e.g. waiting on hung I/O,
+    /// can't block the caller indefinitely.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Fetched
+    /// }
+    ///
+    /// struct Fetch(Duration);
+    ///
+    /// #[async_trait]
+    /// impl AsyncRequestHandler<Fetch, MyEvent> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, req: Fetch) {
+    ///         async_std::task::sleep(req.0).await;
+    ///         self.publish(MyEvent::Fetched).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///     let timeout = Duration::from_millis(100);
+    ///
+    ///     let res = mediator.send_with_timeout(Fetch(Duration::ZERO), timeout).await;
+    ///     assert_eq!(res, Ok(()));
+    ///
+    ///     let res = mediator.send_with_timeout(Fetch(Duration::from_secs(3600)), timeout).await;
+    ///     assert_eq!(res, Err(TimeoutError));
+    /// });
+    ///
+    async fn send_with_timeout<Req>(&self, req: Req, duration: Duration) -> Result<(), TimeoutError>
+    where
+        Self: AsyncRequestHandler<Req, Ev>,
+        Req: Send + 'static,
+    {
+        timeout(self.send(req), duration).await
+    }
+
+    /// Queue a request of type `Req` with the given [`Priority`]
+    /// and wait until the running mediator handled it.
+    ///
+    /// Queued requests are handled by [`BasicAsyncMediator::run()`], e.g. in a [`Worker`],
+    /// in between processing events. Requests with a higher [`Priority`] jump ahead
+    /// of the queued ones with a lower [`Priority`], so control commands can preempt bulk work.
+    /// The request is processed like in [`BasicAsyncMediator::send()`] otherwise.
+    ///
+    /// If the mediator doesn't run, the request stays queued and this method waits until it does.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Imported(u32),
+    ///     Paused
+    /// }
+    ///
+    /// struct Import(u32);
+    /// struct Pause;
+    ///
+    /// #[async_trait]
+    /// impl AsyncRequestHandler<Import, MyEvent> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, req: Import) {
+    ///         self.publish(MyEvent::Imported(req.0)).await;
+    ///     }
+    /// }
+    ///
+    /// #[async_trait]
+    /// impl AsyncRequestHandler<Pause, MyEvent> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, _req: Pause) {
+    ///         self.publish(MyEvent::Paused).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let seen = Arc::new(Mutex::new(vec![]));
+    ///     let cloned = seen.clone();
+    ///     let mediator = Arc::new(
+    ///         BasicAsyncMediator::<MyEvent>::builder()
+    ///             .add_listener(move |ev: &MyEvent| cloned.lock().unwrap().push(format!("{ev:?}")))
+    ///             .build(),
+    ///     );
+    ///
+    ///     let bulk = mediator.clone();
+    ///     let import = async_std::task::spawn(async move {
+    ///         bulk.send_with_priority(Import(1), Priority::Low).await
+    ///     });
+    ///     let control = mediator.clone();
+    ///     let pause = async_std::task::spawn(async move {
+    ///         async_std::task::sleep(std::time::Duration::from_millis(10)).await;
+    ///         control.send_with_priority(Pause, Priority::High).await
+    ///     });
+    ///     async_std::task::sleep(std::time::Duration::from_millis(50)).await;
+    ///
+    ///     let worker = mediator.clone().spawn_worker();
+    ///     import.await;
+    ///     pause.await;
+    ///     worker.shutdown().await;
+    ///
+    ///     assert_eq!(*seen.lock().unwrap(), vec!["Paused", "Imported(1)"]);
+    /// });
+    ///
+    async fn send_with_priority<Req>(&self, req: Req, priority: Priority)
+    where
+        Self: AsyncRequestHandler<Req, Ev> + Send + Sync + 'static,
+        Req: Send + 'static,
+    {
+        let (handled, done) = async_std::channel::bounded(1);
+        self.inner.requests.push(
+            priority,
+            Box::new(move |mediator: Self| {
+                Box::pin(async move {
+                    mediator.send(req).await;
+                    handled.try_send(()).ok();
+                })
+            }),
+        );
+        self.inner.published.notify(usize::MAX);
+        done.recv().await.ok();
+    }
+
+    /// Queue a request of type `Req` to be handled by the running mediator
+    /// and return immediately.
+    ///
+    /// Other than [`BasicAsyncMediator::send()`], the request isn't handled by the caller,
+    /// but by the task processing events with [`BasicAsyncMediator::run()`],
+    /// so producers never wait for the request handler.
+    /// Any number of producers may queue requests, e.g. through [`MediatorHandle`]s.
+    /// Requests are handled in queuing order, with [`Priority::Normal`],
+    /// see [`BasicAsyncMediator::send_with_priority()`].
+    ///
+    /// Requests queued while the mediator isn't running wait until it runs.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Resized(u32)
+    /// }
+    ///
+    /// struct Resize(u32);
+    ///
+    /// #[async_trait]
+    /// impl AsyncRequestHandler<Resize, MyEvent> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, req: Resize) {
+    ///         /* Your expensive handling logic */
+    ///         self.publish(MyEvent::Resized(req.0)).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = Arc::new(BasicAsyncMediator::<MyEvent>::builder().build());
+    ///
+    ///     for size in [64, 128, 256] {
+    ///         mediator.queue_request(Resize(size));
+    ///     }
+    ///
+    ///     let worker = mediator.clone().spawn_worker();
+    ///     async_std::task::sleep(std::time::Duration::from_millis(20)).await;
+    ///     assert_eq!(worker.shutdown().await, 3);
+    /// });
+    ///
+    fn queue_request<Req>(&self, req: Req)
+    where
+        Self: AsyncRequestHandler<Req, Ev> + Send + Sync + 'static,
+        Req: Send + 'static,
+    {
+        self.inner.requests.push(
+            Priority::Normal,
+            Box::new(move |mediator: Self| Box::pin(async move { mediator.send(req).await })),
+        );
+        self.inner.published.notify(usize::MAX);
+    }
+}
+
+#[async_trait]
+impl<Ev> AsyncMediatorInternalNext for BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Process the next published event `Ev` asynchronously.
+    ///
+    /// This method instructs the underlying [`BasicMediator`] to process the next event.
+    /// Afterwards, every [`AsyncListener`]
+    /// is awaited one after another with a `&Ev`,
+    /// or concurrently if configured with
+    /// [`BasicAsyncBuilder::with_concurrency()`](super::BasicAsyncBuilder::with_concurrency()).
+    ///
+    /// See [`BasicMediator::next()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn next(&self) -> Result<(), NextError> {
+        let (envelope, panics) = self.process_next().await?;
+        self.inner.basic.dead_letter_panicked(envelope, &panics);
+        NextError::from_panics(panics)
+    }
+
+    /// Process all pending published events `Ev` asynchronously.
+    ///
+    /// Awaits [`BasicAsyncMediator::next()`] until the channel is empty
+    /// and returns the number of processed events.
+    /// If configured with [`BasicAsyncBuilder::with_concurrency()`](super::BasicAsyncBuilder::with_concurrency()),
+    /// multiple events are processed concurrently.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One
+    /// }
+    ///
+    /// struct Request;
+    ///
+    /// #[async_trait]
+    /// impl AsyncRequestHandler<Request, MyEvent> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, _req: Request) {
+    ///         self.publish(MyEvent::One).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///
+    ///     mediator.send(Request).await;
+    ///     mediator.send(Request).await;
+    ///
+    ///     assert_eq!(mediator.next_all().await, 2);
+    /// });
+    ///
+    async fn next_all(&self) -> usize {
+        let drain = || async {
+            let mut processed = 0;
+            while let Ok(()) | Err(NextError::ListenerPanicked(_)) = self.next().await {
+                processed += 1;
+            }
+            processed
+        };
+        let concurrency = self.inner.concurrency;
+        join_limited(iter::repeat_with(drain).take(concurrency), concurrency)
+            .await
+            .into_iter()
+            .sum()
+    }
+
+    /// Process the next published event `Ev` asynchronously, waiting until one is available.
+    ///
+    /// Unlike [`BasicAsyncMediator::next()`], this method does not return
+    /// immediately if the channel is empty, but waits until an event is published.
+    /// No lock is held while waiting, so handlers can still publish.
+    /// This allows for a simple event loop without busy-polling.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One
+    /// }
+    ///
+    /// struct Request;
+    ///
+    /// #[async_trait]
+    /// impl AsyncRequestHandler<Request, MyEvent> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, _req: Request) {
+    ///         self.publish(MyEvent::One).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = Arc::new(BasicAsyncMediator::<MyEvent>::builder().build());
+    ///
+    ///     let sender = mediator.clone();
+    ///     async_std::task::spawn(async move {
+    ///         sender.send(Request).await;
+    ///     });
+    ///
+    ///     mediator.next_async().await.unwrap();
+    /// });
+    ///
+    async fn next_async(&self) -> Result<(), NextError> {
+        let (envelope, panics) = self.process_next_async().await?;
+        self.inner.basic.dead_letter_panicked(envelope, &panics);
+        NextError::from_panics(panics)
+    }
+}
+
+#[async_trait]
+impl<Ev> AsyncMediatorInternalRun for BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Processes published events `Ev` asynchronously until `shutdown` is cancelled.
+    ///
+    /// Replaces a manual loop around [`BasicAsyncMediator::next_async()`]:
+    /// events are processed as they are published, without busy-polling.
+    /// Once `shutdown` is cancelled, the event currently being processed
+    /// and all pending events are still processed before this method returns.
+    /// Listener panics caught by isolated listeners don't stop the loop.
+    /// If configured with [`BasicAsyncBuilder::with_concurrency()`](super::BasicAsyncBuilder::with_concurrency()),
+    /// multiple events are processed concurrently.
+    /// Requests queued with [`BasicAsyncMediator::send_with_priority()`]
+    /// are handled in between, before the next event.
+    ///
+    /// Returns the number of processed events.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = Arc::new(BasicAsyncMediator::<MyEvent>::builder().build());
+    ///     let shutdown = CancellationToken::new();
+    ///
+    ///     let publisher = mediator.clone();
+    ///     let signal = shutdown.clone();
+    ///     async_std::task::spawn(async move {
+    ///         publisher.publish(MyEvent::One).await;
+    ///         signal.cancel();
+    ///     });
+    ///
+    ///     assert_eq!(mediator.run(&shutdown).await, 1);
+    /// });
+    ///
+    async fn run(&self, shutdown: &CancellationToken) -> usize {
+        let process = || async {
+            let mut processed = 0;
+            loop {
+                let published = self.inner.published.listen();
+                if let Some(job) = self.inner.requests.pop() {
+                    job(Self {
+                        inner: self.inner.clone(),
+                    })
+                    .await;
+                    continue;
+                }
+                match self.next().await {
+                    Ok(()) | Err(NextError::ListenerPanicked(_)) => processed += 1,
+                    Err(NextError::Empty) if !shutdown.is_cancelled() => {
+                        cancellable(published, shutdown).await.ok();
+                    }
+                    Err(_) => return processed,
+                }
+            }
+        };
+        let concurrency = self.inner.concurrency;
+        let processing = join_limited(iter::repeat_with(process).take(concurrency), concurrency);
+        ticker::while_ticking(&self.inner.tickers, self, processing)
+            .await
+            .into_iter()
+            .sum()
+    }
+
+    /// Spawns a [`Worker`] processing published events `Ev` on a background task.
+    ///
+    /// The worker runs [`BasicAsyncMediator::run()`]
+    /// until [`Worker::shutdown()`] is called or the [`Worker`] is dropped.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = Arc::new(
+    ///         BasicAsyncMediator::<MyEvent>::builder()
+    ///             .add_listener(|ev: &MyEvent| println!("{ev:?}"))
+    ///             .build(),
+    ///     );
+    ///
+    ///     let worker = mediator.clone().spawn_worker();
+    ///
+    ///     mediator.publish(MyEvent::One).await;
+    ///     mediator.publish(MyEvent::Two).await;
+    ///
+    ///     assert_eq!(worker.shutdown().await, 2);
+    /// });
+    ///
+    fn spawn_worker(self: Arc<Self>) -> Worker
+    where
+        Self: 'static,
+    {
+        Worker::spawn(|shutdown| async move { self.run(&shutdown).await })
+    }
+}
+
+impl<Ev> AsyncMediatorInternalShare for BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    type Handle = MediatorHandle<Ev>;
+
+    /// Returns a [`MediatorHandle`] to send requests and publish events from other tasks.
+    ///
+    /// Handles are cheap to clone and share the state of the mediator,
+    /// including its listeners, channel and the configuration of the builder.
+    /// Processing events remains the responsibility of the mediator.
+    ///
+    /// See [`MediatorHandle`] for an example.
+    ///
+    fn new_handle(&self) -> MediatorHandle<Ev> {
+        MediatorHandle::new(self)
+    }
+}
+
+#[async_trait]
+impl<Ev> AsyncMediatorInternalSubscribe<Ev> for BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug + Send,
+{
+    /// Subscribes a user-defined listener to the already built [`BasicAsyncMediator`] asynchronously.
+    ///
+    /// This method instructs
+    /// the underlying [`BasicMediator`] to subscribe the listener.
+    ///
+    /// See [`BasicMediator::subscribe()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///
+    ///     let handle = mediator.subscribe(|_: &MyEvent| {
+    ///         /* Your listening logic */
+    ///     }).await;
+    ///
+    ///     assert!(mediator.unsubscribe(handle).await);
+    /// });
+    ///
+    async fn subscribe<F>(&self, f: F) -> ListenerHandle
+    where
+        F: Listener<Ev>,
+    {
+        self.inner.basic.subscribe(f)
+    }
+
+    /// Subscribes a user-defined async listener to the already built [`BasicAsyncMediator`] asynchronously.
+    ///
+    /// This allows plugins loaded after the mediator was built to attach to it,
+    /// even while it is running.
+    /// The listener receives all events processed after it was subscribed,
+    /// together with the async listeners added to the [`BasicAsyncBuilder`].
+    /// It can be unsubscribed with its [`ListenerHandle`].
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///
+    ///     let handle = mediator.subscribe_async(|ev: &MyEvent| {
+    ///         let msg = format!("{:?}", ev);
+    ///         async move {
+    ///             /* Your asynchronous listening logic */
+    ///         }
+    ///     }).await;
+    ///
+    ///     assert!(mediator.unsubscribe(handle).await);
+    /// });
+    ///
+    async fn subscribe_async<F>(&self, f: F) -> ListenerHandle
+    where
+        F: AsyncListener<Ev>,
+    {
+        self.inner
+            .async_listener
+            .add(type_name_of_val(&f), Arc::new(f))
+    }
+
+    /// Unsubscribes the listener identified by `handle` asynchronously.
+    ///
+    /// This works for both a [`Listener`] and an [`AsyncListener`].
+    /// See [`BasicMediator::unsubscribe()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn unsubscribe(&self, handle: ListenerHandle) -> bool {
+        self.inner.basic.unsubscribe(handle) || self.inner.async_listener.remove(handle)
+    }
+
+    /// Subscribes a user-defined listener to the already built [`BasicAsyncMediator`]
+    /// and replays the last processed events to it asynchronously.
+    ///
+    /// This method instructs
+    /// the underlying [`BasicMediator`] to subscribe the listener.
+    ///
+    /// See [`BasicMediator::add_listener_with_replay()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn add_listener_with_replay<F>(&self, f: F) -> ListenerHandle
+    where
+        F: Listener<Ev>,
+    {
+        self.inner.basic.add_listener_with_replay(f)
+    }
+}
+
+#[async_trait]
+impl<Ev> AsyncMediatorInternalRegistered for BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Send a request of type `Req` asynchronously to the closure added for it.
+    ///
+    /// The closure is added via
+    /// [`BasicAsyncBuilder::add_request_handler()`](super::BasicAsyncBuilder::add_request_handler()).
+    ///
+    /// See [`BasicMediator::send_registered()`](crate::synchronous::basic::BasicMediator::send_registered()) for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Greeted(String)
+    /// }
+    ///
+    /// struct Greet(String);
+    /// struct Add(u32, u32);
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///         .add_request_handler(|m: MediatorHandle<MyEvent>, req: Greet| async move {
+    ///             m.publish(MyEvent::Greeted(req.0)).await;
+    ///         })
+    ///         .add_request_handler(|_, req: Add| async move { req.0 + req.1 })
+    ///         .build();
+    ///
+    ///     assert!(mediator.send_registered(Greet("World".to_string())).await.is_ok());
+    ///     assert_eq!(mediator.send_registered_and_receive::<_, u32>(Add(1, 2)).await.ok(), Some(3));
+    ///     assert_eq!(mediator.next_all().await, 1);
+    /// });
+    ///
+    async fn send_registered<Req>(&self, req: Req) -> Result<(), Req>
+    where
+        Self: Sync + 'static,
+        Req: Send + 'static,
+    {
+        let closures = &self.inner.basic.closures;
+        let Some(handler) = closures.closure::<Self, Req, BoxFuture<()>>() else {
+            return Err(req);
+        };
+        self.run_request("send_registered", req, |req| handler(self, req))
+            .await;
+        Ok(())
+    }
+
+    /// Send a request of type `Req` asynchronously to the closure added for it
+    /// and receive a response of type `Resp`.
+    ///
+    /// See [`BasicMediator::send_registered_and_receive()`](crate::synchronous::basic::BasicMediator::send_registered_and_receive()) for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn send_registered_and_receive<Req, Resp>(&self, req: Req) -> Result<Resp, Req>
+    where
+        Self: Sync + 'static,
+        Req: Send + 'static,
+        Resp: Send + 'static,
+    {
+        let closures = &self.inner.basic.closures;
+        let Some(handler) = closures.closure::<Self, Req, BoxFuture<Resp>>() else {
+            return Err(req);
+        };
+        Ok(self
+            .report::<Req, _>(
+                system::completed,
+                self.inner
+                    .observer
+                    .request_async::<Req, _>("send_registered_and_receive", handler(self, req)),
+            )
+            .await)
+    }
+
+    /// Send a request of type `Req` asynchronously to all closures added for it.
+    ///
+    /// The closures are added via
+    /// [`BasicAsyncBuilder::add_fan_out_handler()`](super::BasicAsyncBuilder::add_fan_out_handler())
+    /// and share the request.
+    /// By default, they are awaited one after another, in the order they were added.
+    /// If configured with [`BasicAsyncBuilder::with_concurrency()`](super::BasicAsyncBuilder::with_concurrency()),
+    /// up to that many closures are awaited concurrently.
+    ///
+    /// See [`BasicMediator::send_fan_out()`](crate::synchronous::basic::BasicMediator::send_fan_out()) for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Validated(u32),
+    ///     Ordered(u32),
+    /// }
+    ///
+    /// struct Order(u32);
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///         .add_fan_out_handler(|m, req: std::sync::Arc<Order>| async move {
+    ///             m.publish(MyEvent::Validated(req.0)).await;
+    ///         })
+    ///         .add_fan_out_handler(|m, req: std::sync::Arc<Order>| async move {
+    ///             m.publish(MyEvent::Ordered(req.0)).await;
+    ///         })
+    ///         .with_concurrency(2)
+    ///         .build();
+    ///
+    ///     assert_eq!(mediator.send_fan_out(Order(1)).await, 2);
+    ///     assert_eq!(mediator.next_all().await, 2);
+    /// });
+    ///
+    async fn send_fan_out<Req>(&self, req: Req) -> usize
+    where
+        Self: Sync + 'static,
+        Req: Send + Sync + 'static,
+    {
+        let fan_out = &self.inner.basic.fan_out;
+        let handlers = fan_out.fan_out::<Req, AsyncFanOutHandler<Self, Req>>();
+        let concurrency = self.inner.concurrency;
+        self.run_request("send_fan_out", req, |req| async move {
+            let req = Arc::new(req);
+            let handled: Vec<_> = handlers
+                .iter()
+                .map(|handler| handler(self, req.clone()))
+                .collect();
+            join_limited(handled, concurrency).await;
+        })
+        .await;
+        handlers.len()
+    }
+}
+
+#[async_trait]
+impl<Ev> AsyncMediatorInternalDeadLetters<Ev> for BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug + Send,
+{
+    /// Takes all events out of the dead-letter queue asynchronously, oldest first.
+    ///
+    /// This method instructs
+    /// the underlying [`BasicMediator`] to drain its dead-letter queue.
+    ///
+    /// See [`BasicMediator::dead_letters()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn dead_letters(&self) -> DeadLetters<Ev> {
+        self.inner.basic.dead_letters()
+    }
+
+    /// Publishes all events in the dead-letter queue again asynchronously, oldest first.
+    ///
+    /// If the channel is full and configured with [`OverflowPolicy::Block`],
+    /// this waits until an event was processed.
+    /// See [`BasicMediator::redeliver_all()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn redeliver_all(&self) -> usize {
+        self.redeliver_with(RedeliveryPolicy::default()).await
+    }
+
+    /// Publishes the events in the dead-letter queue that are due according to the `policy` asynchronously.
+    ///
+    /// See [`BasicMediator::redeliver_with()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn redeliver_with(&self, policy: RedeliveryPolicy) -> usize {
+        let due = policy.take_due(self.inner.basic.dead_letters.as_ref());
+        let redelivered = due.len();
+        for envelope in due {
+            if let Err(TrySendError::Full(envelope) | TrySendError::Disconnected(envelope)) =
+                self.dispatch_envelope(envelope).await
+            {
+                self.inner.basic.dead_letter_overflow(vec![envelope]);
+            }
+        }
+        redelivered
+    }
+}
+
+impl<Ev> AsyncMediatorInternalPause for BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Pauses the delivery of events to listeners.
+    ///
+    /// While paused, [`BasicAsyncMediator::publish()`] still enqueues events,
+    /// but [`BasicAsyncMediator::next()`] delivers none of them and returns [`NextError::Empty`],
+    /// as if no event was pending. [`BasicAsyncMediator::next_async()`],
+    /// [`BasicAsyncMediator::run()`] and workers wait until resumed,
+    /// while still handling queued requests.
+    ///
+    /// See [`BasicMediator::pause()`] for more info.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Imported(u32)
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///
+    ///     mediator.pause();
+    ///     mediator.publish(MyEvent::Imported(1)).await;
+    ///     assert_eq!(mediator.next_all().await, 0);
+    ///
+    ///     mediator.resume();
+    ///     assert_eq!(mediator.next_all().await, 1);
+    /// });
+    ///
+    fn pause(&self) {
+        self.inner.basic.paused.pause()
+    }
+
+    /// Resumes the delivery of events paused with [`BasicAsyncMediator::pause()`],
+    /// waking everyone waiting for events.
+    ///
+    fn resume(&self) {
+        self.inner.basic.paused.resume();
+        self.inner.published.notify(usize::MAX);
+    }
+
+    /// Returns whether the delivery of events is paused, see [`BasicAsyncMediator::pause()`].
+    ///
+    fn is_paused(&self) -> bool {
+        self.inner.basic.paused.is_paused()
+    }
+}
+
+impl<Ev> AsyncMediatorInternalStats for BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Returns a snapshot of the counters of the [`BasicAsyncMediator`],
+    /// including its async listeners.
+    ///
+    /// See [`BasicMediator::stats()`] for more info.
+    ///
+    fn stats(&self) -> MediatorStats {
+        let m = &self.inner.basic;
+        let mut listeners = m.listener.stats();
+        listeners.extend(m.shared_listener.stats());
+        listeners.extend(self.inner.async_listener.stats());
+        listeners.extend(self.inner.async_shared_listener.stats());
+        m.observer.counters.snapshot(m.channel.len(), listeners)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<Ev> AsyncMediatorInternalDescribe for BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug + schemars::JsonSchema,
+{
+    /// Returns a catalog of the events and listeners of the [`BasicAsyncMediator`],
+    /// including its async listeners.
+    ///
+    /// See [`BasicMediator::describe()`] for more info.
+    ///
+    fn describe(&self) -> MediatorDescription {
+        let m = &self.inner.basic;
+        let mut listeners = m.listener.describe(false);
+        listeners.extend(m.shared_listener.describe(false));
+        listeners.extend(self.inner.async_listener.describe(true));
+        listeners.extend(self.inner.async_shared_listener.describe(true));
+        MediatorDescription::new::<Ev>(listeners)
+    }
+}
+
+#[cfg(feature = "causality")]
+impl<Ev> AsyncMediatorInternalCausality for BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Returns the flows of requests, events and listeners recorded by the [`BasicAsyncMediator`],
+    /// including its async listeners.
+    ///
+    /// See [`BasicMediator::causality_graph()`] for more info.
+    ///
+    fn causality_graph(&self) -> CausalityGraph {
+        let mut names = BTreeMap::new();
+        self.inner.async_listener.names(&mut names);
+        self.inner.async_shared_listener.names(&mut names);
+        self.inner.basic.causality_graph_with(names)
+    }
+}
+
+impl<Ev> AsyncMediatorInternalStream<Ev> for BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Returns an [`EventStream`] of all published events `Ev`.
+    ///
+    /// Polling the stream processes the next published event
+    /// just like [`BasicAsyncMediator::next_async()`] does,
+    /// which means that all listeners are invoked before the event is yielded.
+    /// This allows consuming events with stream combinators.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    /// use futures::StreamExt;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// struct Request(u32);
+    ///
+    /// #[async_trait]
+    /// impl AsyncRequestHandler<Request, MyEvent> for BasicAsyncMediator<MyEvent> {
+    ///     async fn handle(&self, req: Request) {
+    ///         match req.0 {
+    ///             1 => self.publish(MyEvent::One).await,
+    ///             _ => self.publish(MyEvent::Two).await,
+    ///         };
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+    ///
+    ///     mediator.send(Request(1)).await;
+    ///     mediator.send(Request(2)).await;
+    ///
+    ///     let events: Vec<MyEvent> = mediator.event_stream().take(2).collect().await;
+    ///     assert!(matches!(events[..], [MyEvent::One, MyEvent::Two]));
+    /// });
+    ///
+    fn event_stream(&self) -> EventStream<'_, Ev> {
+        EventStream {
+            mediator: self,
+            pending: None,
+        }
+    }
+}
+
+/// A [`Stream`] of published events `Ev`.
+///
+/// Returned by [`BasicAsyncMediator::event_stream()`].
+/// The stream only ends if the underlying channel is disconnected.
+pub struct EventStream<'a, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    mediator: &'a BasicAsyncMediator<Ev>,
+    pending: Option<PendingEvent<'a, Ev>>,
+}
+
+type PendingEvent<'a, Ev> = Pin<
+    Box<dyn Future<Output = Result<(Envelope<Ev>, Vec<ListenerPanic>), NextError>> + Send + 'a>,
+>;
+
+impl<Ev> Debug for EventStream<'_, Ev>
+where
+    Ev: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventStream")
+            .field("mediator", &self.mediator)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Ev> Stream for EventStream<'_, Ev>
+where
+    Ev: MaybeDebug + Send + Sync,
+{
+    type Item = Ev;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mediator = self.mediator;
+        let pending = self
+            .pending
+            .get_or_insert_with(|| Box::pin(mediator.process_next_async()));
+        match pending.as_mut().poll(cx) {
+            Poll::Ready(res) => {
+                self.pending = None;
+                Poll::Ready(res.ok().map(|(envelope, _)| envelope.ev))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(feature = "persistence")]
+#[async_trait]
+impl<Ev> AsyncMediatorInternalJournal<Ev> for BasicAsyncMediator<Ev>
+where
+    Ev: MaybeDebug + Send + DeserializeOwned,
+{
+    /// Publishes all events recorded in the journal at `path` asynchronously, oldest first.
+    ///
+    /// See [`BasicMediator::replay_from()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn replay_from(&self, path: impl AsRef<Path> + Send) -> io::Result<usize> {
+        self.replay_from_with(path, JsonCodec).await
+    }
+
+    /// Publishes all events recorded in the journal at `path` in the format of the `codec`
+    /// asynchronously, oldest first.
+    ///
+    /// See [`BasicMediator::replay_from_with()`] for more info.
+    ///
+    async fn replay_from_with(
+        &self,
+        path: impl AsRef<Path> + Send,
+        codec: impl Codec,
+    ) -> io::Result<usize> {
+        let mut count = 0;
+        let upcasters = self.inner.basic.upcasters.clone();
+        for entry in read_journal_with(path, codec, upcasters)? {
+            let entry = entry?;
+            match entry.topic {
+                Some(topic) => self.publish_to(&topic, entry.event).await,
+                None => self.publish(entry.event).await,
+            }
+            count += 1;
+        }
+        Ok(count)
     }
 }
@@ -1,12 +1,78 @@
-use async_std::sync::Mutex;
+use event_listener::Event;
 
 use crate::mediator::{
-    asynchronous::basic::basic::BasicAsyncMediator,
+    asynchronous::basic::{
+        basic::{BasicAsyncMediator, Inner},
+        handle::MediatorHandle,
+        interface::{
+            AsyncBehaviorMediatorBuilderInterface, AsyncClosureHandlerBuilderInterface,
+            AsyncConcurrencyMediatorBuilderInterface, AsyncDebounceBuilderInterface,
+            AsyncDynamicHandlerBuilderInterface, AsyncFilterMediatorBuilderInterface,
+            AsyncMediatorBuilderInterface, AsyncMediatorInternalShare,
+            AsyncNamedListenerBuilderInterface, AsyncOrderingMediatorBuilderInterface,
+            AsyncPriorityMediatorBuilderInterface, AsyncRetryMediatorBuilderInterface,
+            AsyncSharedListenerBuilderInterface, AsyncSpawnedListenerBuilderInterface,
+            AsyncTickerBuilderInterface, AsyncTopicMediatorBuilderInterface,
+        },
+    },
+    behavior::AsyncBehavior,
     builder::{BuilderFlow, BuilderInternal},
-    listener::Listener,
-    synchronous::basic::{basic::BasicMediator, interface::BasicMediatorBuilderInterface},
+    channel::{ChannelFlavor, EventChannel, OverflowPolicy},
+    correlation::{Enveloped, EventMeta},
+    debounce::Debouncer,
+    listener::{
+        recover, variant_filter, AsyncListener, AsyncSharedListener, ExclusiveListener, Filter,
+        FilteredAsyncListener, Listener, ListenerRegistry, MaybeDebug, NotificationHandler,
+        PublishInterceptor, SharedAsyncListener, SharedListener,
+    },
+    observer::{MediatorObserver, RequestMeta},
+    ordering::{Ordering, Sequencer},
+    profile::Profile,
+    queue::RequestQueue,
+    ratelimit::{RateLimit, RateLimiter},
+    registry::{erase_async, AsyncDynHandler, AsyncFanOutHandler, BoxFuture, HandlerRegistry},
+    replay::ReplayBuffer,
+    retry::{Retry, RetryExhausted, RetryPolicy},
+    scheduler::Scheduler,
+    spawned::Spawned,
+    synchronous::basic::{
+        basic::BasicMediator,
+        interface::{
+            BasicMediatorBuilderInterface, CatchAllMediatorBuilderInterface,
+            ChannelBackendBuilderInterface, ChannelMediatorBuilderInterface,
+            ConvertingMediatorBuilderInterface, DeadLetterMediatorBuilderInterface,
+            EnvelopedMediatorBuilderInterface, FilterMediatorBuilderInterface,
+            HandlerMediatorBuilderInterface, InterceptorBuilderInterface,
+            ListenerIsolationBuilderInterface, NamedListenerBuilderInterface,
+            ObserverMediatorBuilderInterface, PriorityMediatorBuilderInterface,
+            ProfileBuilderInterface, RateLimitBuilderInterface, ReplayMediatorBuilderInterface,
+            RequestEventsBuilderInterface, RequestHookBuilderInterface,
+            SharedListenerBuilderInterface, TopicMediatorBuilderInterface,
+            VariantMediatorBuilderInterface,
+        },
+    },
+    system::{RequestEvent, SystemEvent},
+    ticker::Ticker,
+    topics::TopicPattern,
 };
-use std::{fmt::Debug, sync::mpsc::channel};
+use std::{any::type_name_of_val, future::Future, sync::Arc, time::Duration};
+
+#[cfg(feature = "tracing")]
+use crate::mediator::{
+    logging::LoggingListener, synchronous::basic::interface::LoggingMediatorBuilderInterface,
+};
+
+#[cfg(feature = "persistence")]
+use crate::mediator::synchronous::basic::interface::UpcasterBuilderInterface;
+#[cfg(feature = "persistence")]
+use serde_json::Value;
+
+#[cfg(feature = "causality")]
+use crate::mediator::{
+    causality::CausalityRecorder, synchronous::basic::interface::CausalityBuilderInterface,
+};
+#[cfg(feature = "causality")]
+use core::fmt::Debug;
 
 /// The [`BasicAsyncBuilder`] helps you to create a [`BasicAsyncMediator`].
 ///
@@ -18,30 +84,46 @@ use std::{fmt::Debug, sync::mpsc::channel};
 ///
 pub struct BasicAsyncBuilder<Ev>
 where
-    Ev: Debug + 'static,
+    Ev: MaybeDebug + 'static,
 {
     mediator: BasicMediator<Ev>,
+    async_listener: ListenerRegistry<Arc<dyn AsyncListener<Ev>>>,
+    async_shared_listener: ListenerRegistry<Arc<dyn AsyncListener<Arc<Ev>>>>,
+    behavior: Vec<Box<dyn AsyncBehavior>>,
+    retry: Retry<Ev>,
+    tickers: Vec<Ticker<Ev>>,
+    handlers: HandlerRegistry<AsyncDynHandler>,
+    concurrency: usize,
+    ordering: Ordering,
 }
 
 impl<Ev> BuilderInternal<BasicAsyncMediator<Ev>, BasicAsyncBuilder<Ev>> for BasicAsyncMediator<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Creates a [`BasicAsyncBuilder`] with the goal of producing a [`BasicAsyncMediator`].
     ///
     fn builder() -> BasicAsyncBuilder<Ev> {
         BasicAsyncBuilder::<Ev> {
-            mediator: BasicMediator::<Ev> {
-                channel: channel(),
-                listener: vec![],
+            mediator: BasicMediator {
+                channel: EventChannel::asynchronous(),
+                ..BasicMediator::new()
             },
+            async_listener: ListenerRegistry::default(),
+            async_shared_listener: ListenerRegistry::default(),
+            behavior: vec![],
+            retry: Retry::default(),
+            tickers: vec![],
+            handlers: HandlerRegistry::default(),
+            concurrency: 1,
+            ordering: Ordering::default(),
         }
     }
 }
 
 impl<M, Ev> BasicMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Adds a user-defined listener to the [`BasicAsyncBuilder`].
     ///
@@ -50,54 +132,1651 @@ where
     ///
     /// Also it must be a `Fn(Ev)` with a return type of `()`
     /// where `Ev` is the user-defined event type
-    /// that must be [`Debug`].
+    /// that must be [`MaybeDebug`].
     ///
-    fn add_listener(mut self, f: impl Listener<Ev>) -> Self {
-        self.mediator.listener.push(Box::new(f));
+    fn add_listener(self, f: impl Listener<Ev>) -> Self {
+        self.mediator
+            .listener
+            .add(type_name_of_val(&f), ExclusiveListener::new(f));
         self
     }
 }
 
-impl<Ev> BasicAsyncBuilder<Ev>
+impl<M, Ev> NamedListenerBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
-    /// Adds a user-defined listener to the [`BasicAsyncBuilder`].
+    /// Adds a user-defined listener with a `name` to the [`BasicAsyncBuilder`].
     ///
-    /// The supplied type must be a [`Listener`].
-    /// As such, it must implement [`Send`] and `Fn(Ev)`,
-    /// besides being `'static`.
+    fn add_listener_named(self, name: &'static str, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add(name, ExclusiveListener::new(f));
+        self
+    }
+}
+
+impl<M, Ev> AsyncNamedListenerBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined async listener with a `name` to the [`BasicAsyncBuilder`].
     ///
-    /// As a side note, here, `Ev` is the user-defined event type
-    /// that must be [`Debug`].
+    fn add_async_listener_named(self, name: &'static str, f: impl AsyncListener<Ev>) -> Self {
+        self.async_listener.add(name, Arc::new(f));
+        self
+    }
+}
+
+impl<M, Ev> AsyncMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined async listener to the [`BasicAsyncBuilder`].
     ///
-    /// # Examples
+    /// To be able to supply a type that implements [`AsyncListener`],
+    /// it must satisfy [`Send`], [`Sync`] and `'static` bounds.
     ///
-    /// Basic usage:
+    fn add_async_listener(self, f: impl AsyncListener<Ev>) -> Self {
+        self.async_listener.add(type_name_of_val(&f), Arc::new(f));
+        self
+    }
+}
+
+impl<M, Ev> AsyncDebounceBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a debounced async listener to the [`BasicAsyncBuilder`].
     ///
-    /// ```
-    /// use mediatrix::asynchronous::basic::*;
+    fn add_debounced_listener<K>(
+        self,
+        window: Duration,
+        key_fn: impl Fn(&Ev) -> K + Send + Sync + 'static,
+        f: impl AsyncListener<Ev>,
+    ) -> Self
+    where
+        Ev: MaybeDebug + Clone + Send + Sync,
+        K: Ord + Clone + Send + Sync + 'static,
+    {
+        self.async_listener.add(
+            type_name_of_val(&f),
+            Arc::new(Debouncer::new(window, key_fn, f)),
+        );
+        self
+    }
+}
+
+impl<M, Ev> AsyncSpawnedListenerBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds an async listener running on its own spawned task to the [`BasicAsyncBuilder`].
     ///
-    /// #[derive(Debug)]
-    /// enum MyEvent {
-    ///     One,
-    ///     Two
-    /// }
+    fn add_spawned_listener(self, f: impl AsyncListener<Ev>) -> Self
+    where
+        Ev: MaybeDebug + Clone + Send + Sync,
+    {
+        self.async_listener
+            .add(type_name_of_val(&f), Arc::new(Spawned::new(f)));
+        self
+    }
+}
+
+impl<M, Ev> AsyncTickerBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a ticker publishing the event generated by `f` every `interval` to the [`BasicAsyncBuilder`].
     ///
-    /// let mediator = BasicAsyncMediator::<MyEvent>::builder()
-    ///     .add_listener(move |_: &MyEvent| {
-    ///         /* Your listening logic */
-    ///     })
-    ///     .build();
+    /// # Panics
     ///
-    pub fn add_listener(self, f: impl Listener<Ev>) -> Self {
-        <Self as BasicMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::add_listener(self, f)
+    /// Panics if `interval` is zero.
+    ///
+    fn with_ticker(
+        mut self,
+        interval: Duration,
+        f: impl Fn() -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        assert!(
+            !interval.is_zero(),
+            "ticker interval must be greater than 0"
+        );
+        self.tickers.push(Ticker::new(interval, f));
+        self
+    }
+}
+
+impl<M, Ev> AsyncBehaviorMediatorBuilderInterface<M> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined [`AsyncBehavior`] to the [`BasicAsyncBuilder`].
+    ///
+    /// Behaviors wrap every request sent to the [`BasicAsyncMediator`]
+    /// in the order they were added.
+    ///
+    fn add_behavior(mut self, b: impl AsyncBehavior) -> Self {
+        self.behavior.push(Box::new(b));
+        self
+    }
+}
+
+impl<M, Ev> ChannelMediatorBuilderInterface<M> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Bounds the channel of the [`BasicAsyncBuilder`] to `capacity` events.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`.
+    ///
+    fn with_capacity(mut self, capacity: usize) -> Self {
+        assert!(capacity > 0, "channel capacity must be greater than 0");
+        self.mediator.channel.set_capacity(Some(capacity));
+        self
+    }
+
+    /// Sets the [`OverflowPolicy`] of the [`BasicAsyncBuilder`].
+    ///
+    fn with_overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.mediator.channel.policy = policy;
+        self
+    }
+}
+
+impl<M, Ev> ListenerIsolationBuilderInterface<M> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Enables or disables listener isolation of the [`BasicAsyncBuilder`].
+    ///
+    fn isolate_listeners(mut self, isolate: bool) -> Self {
+        self.mediator.isolate_listeners = isolate;
+        self
+    }
+}
+
+impl<M, Ev> RateLimitBuilderInterface<M> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the [`RateLimit`] of the [`BasicAsyncBuilder`].
+    ///
+    fn with_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.mediator.rate_limit = Some(RateLimiter::new(limit));
+        self
+    }
+}
+
+impl<M, Ev> TopicMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener for a topic to the [`BasicAsyncBuilder`].
+    ///
+    /// The listener only receives events published to a topic
+    /// matching the [`TopicPattern`] `topic`.
+    ///
+    fn add_listener_for_topic(self, topic: &str, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add_for_topic(
+            type_name_of_val(&f),
+            Some(TopicPattern::new(topic)),
+            ExclusiveListener::new(f),
+        );
+        self
+    }
+}
+
+impl<M, Ev> AsyncTopicMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined async listener for a topic to the [`BasicAsyncBuilder`].
+    ///
+    /// The listener only receives events published to a topic
+    /// matching the [`TopicPattern`] `topic`.
+    ///
+    fn add_async_listener_for_topic(self, topic: &str, f: impl AsyncListener<Ev>) -> Self {
+        self.async_listener.add_for_topic(
+            type_name_of_val(&f),
+            Some(TopicPattern::new(topic)),
+            Arc::new(f),
+        );
+        self
+    }
+}
+
+impl<M, Ev> FilterMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener guarded by a [`Filter`] to the [`BasicAsyncBuilder`].
+    ///
+    /// The listener is only invoked if `filter` returns `true` for an event.
+    ///
+    fn add_filtered_listener(self, filter: impl Filter<Ev>, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| {
+                if filter(ev) {
+                    f(ev)
+                }
+            }),
+        );
+        self
+    }
+}
+
+impl<M, Ev> AsyncFilterMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug + Sync,
+{
+    /// Adds a user-defined async listener guarded by a [`Filter`] to the [`BasicAsyncBuilder`].
+    ///
+    /// The listener is only awaited if `filter` returns `true` for an event.
+    ///
+    fn add_filtered_async_listener(
+        self,
+        filter: impl Filter<Ev>,
+        f: impl AsyncListener<Ev>,
+    ) -> Self {
+        self.async_listener.add(
+            type_name_of_val(&f),
+            Arc::new(FilteredAsyncListener {
+                filter,
+                listener: f,
+            }),
+        );
+        self
+    }
+}
+
+impl<M, Ev> VariantMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener for events with certain variant names to the [`BasicAsyncBuilder`].
+    ///
+    fn add_listener_for_variants(
+        self,
+        variants: impl IntoIterator<Item = &'static str>,
+        f: impl Listener<Ev>,
+    ) -> Self
+    where
+        for<'a> &'a Ev: Into<&'static str>,
+    {
+        <Self as FilterMediatorBuilderInterface<M, Ev>>::add_filtered_listener(
+            self,
+            variant_filter(variants),
+            f,
+        )
+    }
+}
+
+impl<M, Ev> ConvertingMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener for the events convertible into `SubEv` to the [`BasicAsyncBuilder`].
+    ///
+    fn add_converting_listener<SubEv>(self, f: impl Fn(SubEv) + Send + Sync + 'static) -> Self
+    where
+        Ev: Clone + TryInto<SubEv>,
+    {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| {
+                if let Ok(ev) = ev.clone().try_into() {
+                    f(ev)
+                }
+            }),
+        );
+        self
+    }
+}
+
+impl<M, Ev> PriorityMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener with a `priority` to the [`BasicAsyncBuilder`].
+    ///
+    /// Listeners are invoked in descending priority.
+    ///
+    fn add_listener_with_priority(self, priority: i32, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add_with_priority(
+            type_name_of_val(&f),
+            priority,
+            ExclusiveListener::new(f),
+        );
+        self
+    }
+}
+
+impl<M, Ev> AsyncPriorityMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined async listener with a `priority` to the [`BasicAsyncBuilder`].
+    ///
+    /// Async listeners are awaited in descending priority.
+    ///
+    fn add_async_listener_with_priority(self, priority: i32, f: impl AsyncListener<Ev>) -> Self {
+        self.async_listener
+            .add_with_priority(type_name_of_val(&f), priority, Arc::new(f));
+        self
+    }
+}
+
+impl<M, Ev> HandlerMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined notification handler to the [`BasicAsyncBuilder`].
+    ///
+    /// The handler is invoked like any other listener.
+    ///
+    fn add_handler(self, handler: Arc<dyn NotificationHandler<Ev>>) -> Self
+    where
+        Ev: 'static,
+    {
+        self.mediator.listener.add(
+            handler.name(),
+            ExclusiveListener::new(move |ev: &Ev| handler.on_event(ev)),
+        );
+        self
+    }
+}
+
+impl<M, Ev> ObserverMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a [`MediatorObserver`] to the [`BasicAsyncBuilder`].
+    ///
+    /// Observers are notified in the order they were added.
+    ///
+    fn with_observer(mut self, observer: impl MediatorObserver<Ev> + 'static) -> Self {
+        self.mediator.observer.add(observer);
+        self
+    }
+}
+
+impl<M, Ev> RequestHookBuilderInterface<M> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a hook invoked before every request sent to the [`BasicAsyncBuilder`].
+    ///
+    fn on_before_send(mut self, f: impl Fn(&RequestMeta) + Send + Sync + 'static) -> Self {
+        self.mediator.observer.before_send(f);
+        self
+    }
+
+    /// Adds a hook invoked after every request sent to the [`BasicAsyncBuilder`].
+    ///
+    fn on_after_send(mut self, f: impl Fn(&RequestMeta, Duration) + Send + Sync + 'static) -> Self {
+        self.mediator.observer.after_send(f);
+        self
+    }
+}
+
+#[cfg(feature = "causality")]
+impl<M, Ev> CausalityBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Records the flows of requests, events and listeners of the [`BasicAsyncBuilder`].
+    ///
+    fn record_causality(mut self) -> Self
+    where
+        Ev: Debug,
+    {
+        self.mediator.observer.causality = Some(Arc::new(CausalityRecorder::new()));
+        self
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<M, Ev> LoggingMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    /// Adds a [`LoggingListener`] to the [`BasicAsyncBuilder`].
+    ///
+    fn add_logging_listener(self, listener: impl Into<LoggingListener<Ev>>) -> Self {
+        let listener = listener.into();
+        self.mediator.listener.add(
+            type_name_of_val(&listener),
+            ExclusiveListener::new(move |ev: &Ev| listener.log(&Enveloped::current(ev))),
+        );
+        self
+    }
+}
+
+#[cfg(feature = "persistence")]
+impl<M, Ev> UpcasterBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds an upcaster for events of the version `from_version` to the [`BasicAsyncBuilder`].
+    ///
+    fn register_upcaster(
+        mut self,
+        from_version: u32,
+        f: impl Fn(Value) -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        self.mediator.upcasters.register(from_version, f);
+        self
+    }
+}
+
+impl<M, Ev> ReplayMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the capacity of the replay buffer of the [`BasicAsyncBuilder`].
+    ///
+    /// A capacity of `0` disables the replay buffer.
+    ///
+    fn with_replay_capacity(mut self, capacity: usize) -> Self
+    where
+        Ev: Clone,
+    {
+        self.mediator.replay = (capacity > 0).then(|| ReplayBuffer::new(capacity));
+        self
+    }
+}
+
+impl<M, Ev> DeadLetterMediatorBuilderInterface<M> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Enables or disables the dead-letter queue of the [`BasicAsyncBuilder`].
+    ///
+    fn collect_dead_letters(mut self, collect: bool) -> Self {
+        self.mediator.dead_letters = collect.then(EventChannel::default);
+        self
+    }
+}
+
+impl<M, Ev> AsyncRetryMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the [`RetryPolicy`] of the [`BasicAsyncBuilder`].
+    ///
+    fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry.policy = policy;
+        self
+    }
+
+    /// Sets the function creating the event published once a [`RetryPolicy`] is exhausted.
+    ///
+    fn on_retry_exhausted(
+        mut self,
+        f: impl Fn(&RetryExhausted) -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        self.retry.exhausted = Some(Box::new(f));
+        self
+    }
+}
+
+impl<M, Ev> SharedListenerBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener receiving the event in an [`Arc`] to the [`BasicAsyncBuilder`].
+    ///
+    fn add_shared_listener(mut self, f: impl SharedListener<Ev>) -> Self
+    where
+        Ev: Clone,
+    {
+        self.mediator.shared_listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Arc<Ev>| f(ev.clone())),
+        );
+        self.mediator.recover = Some(recover::<Ev>);
+        self
+    }
+}
+
+impl<M, Ev> AsyncSharedListenerBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Adds a user-defined async listener receiving the event in an [`Arc`] to the [`BasicAsyncBuilder`].
+    ///
+    fn add_async_shared_listener(mut self, f: impl AsyncSharedListener<Ev>) -> Self
+    where
+        Ev: Clone,
+    {
+        self.async_shared_listener
+            .add(type_name_of_val(&f), Arc::new(SharedAsyncListener(f)));
+        self.mediator.recover = Some(recover::<Ev>);
+        self
+    }
+}
+
+impl<M, Ev> EnvelopedMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener receiving the event as [`Enveloped`] to the [`BasicAsyncBuilder`].
+    ///
+    fn add_enveloped_listener(
+        self,
+        f: impl Fn(&Enveloped<'_, Ev>) + Send + Sync + 'static,
+    ) -> Self {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| f(&Enveloped::current(ev))),
+        );
+        self
+    }
+}
+
+impl<M, Ev> CatchAllMediatorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener receiving every event along with its [`EventMeta`] to the [`BasicAsyncBuilder`].
+    ///
+    fn add_catch_all_listener(
+        mut self,
+        f: impl Fn(&Ev, &EventMeta) + Send + Sync + 'static,
+    ) -> Self {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| f(ev, &EventMeta::current())),
+        );
+        self.mediator.catch_all = true;
+        self
+    }
+}
+
+impl<M, Ev> InterceptorBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined [`PublishInterceptor`] to the [`BasicAsyncBuilder`].
+    ///
+    fn add_publish_interceptor(mut self, f: impl PublishInterceptor<Ev>) -> Self {
+        self.mediator.interceptors.push(Box::new(f));
+        self
+    }
+}
+
+impl<M, Ev> RequestEventsBuilderInterface<M, SystemEvent<Ev>> for BasicAsyncBuilder<SystemEvent<Ev>>
+where
+    Ev: MaybeDebug,
+{
+    /// Enables or disables the request events of the [`BasicAsyncBuilder`].
+    ///
+    fn with_request_events(mut self, enabled: bool) -> Self {
+        self.mediator.request_events = enabled.then_some(SystemEvent::request as RequestEvent<_>);
+        self
+    }
+}
+
+impl<Ev> AsyncDynamicHandlerBuilderInterface<BasicAsyncMediator<Ev>, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug + Send + Sync + 'static,
+{
+    /// Registers a handler for requests of type `Req` with the [`BasicAsyncBuilder`].
+    ///
+    fn register_handler<Req, Fut>(
+        mut self,
+        handler: impl Fn(MediatorHandle<Ev>, Req) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handler = Arc::new(handler);
+        self.handlers
+            .insert::<Req>(erase_async(move |m: &BasicAsyncMediator<Ev>, req: Req| {
+                let handler = handler.clone();
+                Box::pin(m.run_request("send_dyn", req, move |req| handler(m.new_handle(), req)))
+            }));
+        self
+    }
+}
+
+impl<Ev> AsyncClosureHandlerBuilderInterface<BasicAsyncMediator<Ev>, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug + Send + Sync + 'static,
+{
+    /// Adds a closure handling requests of type `Req` to the [`BasicAsyncBuilder`].
+    ///
+    fn add_request_handler<Req, Resp, Fut>(
+        mut self,
+        handler: impl Fn(MediatorHandle<Ev>, Req) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Resp: Send + 'static,
+        Fut: Future<Output = Resp> + Send + 'static,
+    {
+        self.mediator.closures.insert_closure(Box::new(
+            move |m: &BasicAsyncMediator<Ev>, req: Req| -> BoxFuture<Resp> {
+                Box::pin(handler(m.new_handle(), req))
+            },
+        ));
+        self
+    }
+
+    /// Adds a closure handling requests of type `Req` together with others to the [`BasicAsyncBuilder`].
+    ///
+    fn add_fan_out_handler<Req, Fut>(
+        mut self,
+        handler: impl Fn(MediatorHandle<Ev>, Arc<Req>) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.mediator
+            .fan_out
+            .push_fan_out::<Req, AsyncFanOutHandler<BasicAsyncMediator<Ev>, Req>>(Box::new(
+                move |m: &BasicAsyncMediator<Ev>, req: Arc<Req>| -> BoxFuture<()> {
+                    Box::pin(handler(m.new_handle(), req))
+                },
+            ));
+        self
+    }
+}
+
+impl<M, Ev> AsyncConcurrencyMediatorBuilderInterface<M> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the number of listeners and events the [`BasicAsyncBuilder`] processes concurrently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is `0`.
+    ///
+    fn with_concurrency(mut self, limit: usize) -> Self {
+        assert!(limit > 0, "concurrency limit must be greater than 0");
+        self.concurrency = limit;
+        self
+    }
+}
+
+impl<M, Ev> AsyncOrderingMediatorBuilderInterface<M> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the [`Ordering`] in which the [`BasicAsyncBuilder`] processes events and their listeners.
+    ///
+    fn with_ordering(mut self, ordering: Ordering) -> Self {
+        self.ordering = ordering;
+        self
+    }
+}
+
+impl<M, Ev> ChannelBackendBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Creates the channel of the [`BasicAsyncBuilder`] through the [`ChannelFlavor`] `F`.
+    ///
+    fn with_channel_backend<F: ChannelFlavor>(mut self) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        self.mediator.channel.set_flavor::<F>();
+        self
+    }
+}
+
+impl<M, Ev> ProfileBuilderInterface<M, Ev> for BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Configures the channel, concurrency and ordering of the [`BasicAsyncBuilder`] from the [`Profile`].
+    ///
+    fn with_profile(mut self, profile: Profile) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        profile.configure(&mut self.mediator.channel);
+        self.concurrency = profile.concurrency();
+        self.ordering = profile.ordering();
+        self
+    }
+}
+
+impl<Ev> BasicAsyncBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener to the [`BasicAsyncBuilder`].
+    ///
+    /// The supplied type must be a [`Listener`].
+    /// As such, it must implement [`Send`] and `Fn(Ev)`,
+    /// besides being `'static`.
+    ///
+    /// As a side note, here, `Ev` is the user-defined event type
+    /// that must be [`MaybeDebug`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///     .add_listener(move |_: &MyEvent| {
+    ///         /* Your listening logic */
+    ///     })
+    ///     .build();
+    ///
+    pub fn add_listener(self, f: impl Listener<Ev>) -> Self {
+        <Self as BasicMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::add_listener(self, f)
+    }
+
+    /// Adds a user-defined listener with a `name` to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_named()`] for more info.
+    ///
+    pub fn add_listener_named(self, name: &'static str, f: impl Listener<Ev>) -> Self {
+        <Self as NamedListenerBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_listener_named(
+            self, name, f,
+        )
+    }
+
+    /// Adds a user-defined async listener to the [`BasicAsyncBuilder`].
+    ///
+    /// The supplied type must be an [`AsyncListener`].
+    /// As such, it must implement [`Send`] and [`Sync`],
+    /// besides being `'static`.
+    /// A closure `Fn(&Ev) -> Fut` qualifies, if `Fut` is a `Future`
+    /// that is [`Send`] and has an output of `()`.
+    ///
+    /// Async listeners are awaited one after another
+    /// by [`crate::asynchronous::basic::AsyncMediatorInternalNext::next()`],
+    /// after all synchronous listeners were invoked.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///     .add_async_listener(|ev: &MyEvent| {
+    ///         let msg = format!("{:?}", ev);
+    ///         async move {
+    ///             /* Your asynchronous listening logic */
+    ///         }
+    ///     })
+    ///     .build();
+    ///
+    pub fn add_async_listener(self, f: impl AsyncListener<Ev>) -> Self {
+        <Self as AsyncMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_async_listener(
+            self, f,
+        )
+    }
+
+    /// Adds a user-defined async listener with a `name` to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_named()`] for more info.
+    ///
+    pub fn add_async_listener_named(self, name: &'static str, f: impl AsyncListener<Ev>) -> Self {
+        <Self as AsyncNamedListenerBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_async_listener_named(
+            self, name, f,
+        )
+    }
+
+    /// Adds a debounced async listener to the [`BasicAsyncBuilder`].
+    ///
+    /// Instead of every event, the listener receives only the latest event per key
+    /// within the time `window`, where `key_fn` maps an event to its key.
+    /// The first event of a key opens a window, later events of the same key replace it
+    /// until the window closes and the latest one is delivered.
+    /// This is useful for UI refreshes or cache invalidations driven by bursty events.
+    ///
+    /// The events are cloned and delivered on a spawned task, after they were processed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyEvent {
+    ///     Changed { table: &'static str, row: u32 }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let refreshed = Arc::new(Mutex::new(vec![]));
+    ///     let cloned = refreshed.clone();
+    ///
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///         .add_debounced_listener(
+    ///             Duration::from_millis(20),
+    ///             |MyEvent::Changed { table, .. }: &MyEvent| *table,
+    ///             move |MyEvent::Changed { table, row }: &MyEvent| {
+    ///                 let (refreshed, table, row) = (cloned.clone(), *table, *row);
+    ///                 async move { refreshed.lock().unwrap().push((table, row)) }
+    ///             },
+    ///         )
+    ///         .build();
+    ///
+    ///     for row in 0..3 {
+    ///         mediator.publish(MyEvent::Changed { table: "users", row }).await;
+    ///     }
+    ///     mediator.next_all().await;
+    ///     async_std::task::sleep(Duration::from_millis(100)).await;
+    ///
+    ///     assert_eq!(*refreshed.lock().unwrap(), vec![("users", 2)]);
+    /// });
+    ///
+    pub fn add_debounced_listener<K>(
+        self,
+        window: Duration,
+        key_fn: impl Fn(&Ev) -> K + Send + Sync + 'static,
+        f: impl AsyncListener<Ev>,
+    ) -> Self
+    where
+        Ev: Clone + Send + Sync,
+        K: Ord + Clone + Send + Sync + 'static,
+    {
+        <Self as AsyncDebounceBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_debounced_listener(
+            self, window, key_fn, f,
+        )
+    }
+
+    /// Adds an async listener running on its own spawned task to the [`BasicAsyncBuilder`].
+    ///
+    /// Every event is cloned into a dedicated channel of the listener,
+    /// which receives the events one at a time in publishing order,
+    /// while processing the events returns without waiting for it.
+    /// This keeps a slow listener, e.g. one writing to a remote API,
+    /// from adding latency to [`BasicAsyncMediator::next()`] for all other listeners.
+    ///
+    /// The task is spawned with the first event. A panic of the listener only skips that event.
+    /// Once the [`BasicAsyncMediator`] is dropped, the task delivers the remaining events and ends.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyEvent {
+    ///     Saved(u32)
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let uploaded = Arc::new(Mutex::new(vec![]));
+    ///     let cloned = uploaded.clone();
+    ///
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///         .add_spawned_listener(move |MyEvent::Saved(id): &MyEvent| {
+    ///             let (uploaded, id) = (cloned.clone(), *id);
+    ///             async move {
+    ///                 // A slow call to a remote API.
+    ///                 async_std::task::sleep(Duration::from_millis(20)).await;
+    ///                 uploaded.lock().unwrap().push(id);
+    ///             }
+    ///         })
+    ///         .build();
+    ///
+    ///     mediator.publish(MyEvent::Saved(1)).await;
+    ///     mediator.publish(MyEvent::Saved(2)).await;
+    ///     // Returns without waiting for the upload.
+    ///     mediator.next_all().await;
+    ///     assert!(uploaded.lock().unwrap().is_empty());
+    ///
+    ///     async_std::task::sleep(Duration::from_millis(200)).await;
+    ///     assert_eq!(*uploaded.lock().unwrap(), vec![1, 2]);
+    /// });
+    ///
+    pub fn add_spawned_listener(self, f: impl AsyncListener<Ev>) -> Self
+    where
+        Ev: Clone + Send + Sync,
+    {
+        <Self as AsyncSpawnedListenerBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_spawned_listener(
+            self, f,
+        )
+    }
+
+    /// Adds a ticker publishing the event generated by `f` every `interval` to the [`BasicAsyncBuilder`].
+    ///
+    /// Tickers only tick while the [`BasicAsyncMediator`] runs,
+    /// i.e. during [`BasicAsyncMediator::run()`] or in a [`crate::worker::Worker`],
+    /// and stop when it shuts down. The first event is published one `interval` after it started.
+    /// This is useful for heartbeats, polling or expiring caches.
+    ///
+    /// Ticks don't drift with the time it takes to publish an event,
+    /// but a tick may be delayed if publishing waits on a full channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Heartbeat
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = Arc::new(
+    ///         BasicAsyncMediator::<MyEvent>::builder()
+    ///             .add_listener(|ev: &MyEvent| println!("{ev:?}"))
+    ///             .with_ticker(Duration::from_millis(10), || MyEvent::Heartbeat)
+    ///             .build(),
+    ///     );
+    ///
+    ///     let worker = mediator.clone().spawn_worker();
+    ///     async_std::task::sleep(Duration::from_millis(55)).await;
+    ///
+    ///     assert!(worker.shutdown().await >= 3);
+    /// });
+    ///
+    pub fn with_ticker(
+        self,
+        interval: Duration,
+        f: impl Fn() -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        <Self as AsyncTickerBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::with_ticker(
+            self, interval, f,
+        )
+    }
+
+    /// Adds a user-defined [`AsyncBehavior`] to the [`BasicAsyncBuilder`].
+    ///
+    /// Behaviors wrap every request sent to the mediator
+    /// in the order they were added.
+    /// A behavior may short-circuit a request by not calling [`crate::behavior::AsyncNext::run()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// struct Logging;
+    ///
+    /// #[async_trait]
+    /// impl AsyncBehavior for Logging {
+    ///     async fn handle(&self, req: AnyRequest, next: AsyncNext<'_>) {
+    ///         println!("Handling {}", req.type_name());
+    ///         next.run(req).await;
+    ///     }
+    /// }
+    ///
+    /// let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///     .add_behavior(Logging)
+    ///     .build();
+    ///
+    pub fn add_behavior(self, b: impl AsyncBehavior) -> Self {
+        <Self as AsyncBehaviorMediatorBuilderInterface<BasicAsyncMediator<Ev>>>::add_behavior(
+            self, b,
+        )
+    }
+    /// Bounds the channel of the [`BasicAsyncBuilder`] to `capacity` events.
+    ///
+    /// If the channel is full and the [`OverflowPolicy`] is [`OverflowPolicy::Block`],
+    /// publishing waits until an event was processed.
+    /// See [`crate::synchronous::basic::BasicBuilder::with_capacity()`] for more info.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`.
+    ///
+    pub fn with_capacity(self, capacity: usize) -> Self {
+        <Self as ChannelMediatorBuilderInterface<BasicAsyncMediator<Ev>>>::with_capacity(
+            self, capacity,
+        )
+    }
+
+    /// Sets the [`OverflowPolicy`] of the [`BasicAsyncBuilder`].
+    ///
+    /// The policy only takes effect if the channel was bounded
+    /// with [`BasicAsyncBuilder::with_capacity()`].
+    ///
+    pub fn with_overflow_policy(self, policy: OverflowPolicy) -> Self {
+        <Self as ChannelMediatorBuilderInterface<BasicAsyncMediator<Ev>>>::with_overflow_policy(
+            self, policy,
+        )
+    }
+    /// Enables or disables listener isolation of the [`BasicAsyncBuilder`].
+    ///
+    /// This also applies to every [`AsyncListener`].
+    /// See [`crate::synchronous::basic::BasicBuilder::isolate_listeners()`] for more info.
+    ///
+    pub fn isolate_listeners(self, isolate: bool) -> Self {
+        <Self as ListenerIsolationBuilderInterface<BasicAsyncMediator<Ev>>>::isolate_listeners(
+            self, isolate,
+        )
+    }
+    /// Sets the [`RateLimit`] of the [`BasicAsyncBuilder`].
+    ///
+    /// Waiting for the next slot doesn't block the thread.
+    /// See [`crate::synchronous::basic::BasicBuilder::with_rate_limit()`] for more info.
+    ///
+    pub fn with_rate_limit(self, limit: RateLimit) -> Self {
+        <Self as RateLimitBuilderInterface<BasicAsyncMediator<Ev>>>::with_rate_limit(self, limit)
+    }
+    /// Adds a user-defined listener for a topic to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_for_topic()`] for more info.
+    ///
+    pub fn add_listener_for_topic(self, topic: &str, f: impl Listener<Ev>) -> Self {
+        <Self as TopicMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_listener_for_topic(
+            self, topic, f,
+        )
+    }
+
+    /// Adds a user-defined async listener for a topic to the [`BasicAsyncBuilder`].
+    ///
+    /// The listener only receives events published to a topic
+    /// matching the [`TopicPattern`] `topic`.
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_for_topic()`] for more info.
+    ///
+    pub fn add_async_listener_for_topic(self, topic: &str, f: impl AsyncListener<Ev>) -> Self {
+        <Self as AsyncTopicMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_async_listener_for_topic(
+            self, topic, f,
+        )
+    }
+
+    /// Adds a user-defined listener guarded by a [`Filter`] to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_filtered_listener()`] for more info.
+    ///
+    pub fn add_filtered_listener(self, filter: impl Filter<Ev>, f: impl Listener<Ev>) -> Self {
+        <Self as FilterMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_filtered_listener(
+            self, filter, f,
+        )
+    }
+
+    /// Adds a user-defined listener for events with certain variant names to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_for_variants()`] for more info.
+    ///
+    pub fn add_listener_for_variants(
+        self,
+        variants: impl IntoIterator<Item = &'static str>,
+        f: impl Listener<Ev>,
+    ) -> Self
+    where
+        for<'a> &'a Ev: Into<&'static str>,
+    {
+        <Self as VariantMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_listener_for_variants(
+            self, variants, f,
+        )
+    }
+
+    /// Adds a user-defined listener for the events convertible into `SubEv` to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_converting_listener()`] for more info.
+    ///
+    pub fn add_converting_listener<SubEv>(self, f: impl Fn(SubEv) + Send + Sync + 'static) -> Self
+    where
+        Ev: Clone + TryInto<SubEv>,
+    {
+        <Self as ConvertingMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_converting_listener(self, f)
+    }
+
+    /// Adds a user-defined async listener guarded by a [`Filter`] to the [`BasicAsyncBuilder`].
+    ///
+    /// The listener is only awaited if `filter` returns `true` for an event.
+    /// See [`crate::synchronous::basic::BasicBuilder::add_filtered_listener()`] for more info.
+    ///
+    pub fn add_filtered_async_listener(
+        self,
+        filter: impl Filter<Ev>,
+        f: impl AsyncListener<Ev>,
+    ) -> Self
+    where
+        Ev: Sync,
+    {
+        <Self as AsyncFilterMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_filtered_async_listener(
+            self, filter, f,
+        )
+    }
+
+    /// Adds a user-defined listener with a `priority` to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_with_priority()`] for more info.
+    ///
+    pub fn add_listener_with_priority(self, priority: i32, f: impl Listener<Ev>) -> Self {
+        <Self as PriorityMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_listener_with_priority(
+            self, priority, f,
+        )
+    }
+
+    /// Adds a user-defined async listener with a `priority` to the [`BasicAsyncBuilder`].
+    ///
+    /// Async listeners are awaited in descending priority,
+    /// after all synchronous listeners were invoked.
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_with_priority()`] for more info.
+    ///
+    pub fn add_async_listener_with_priority(
+        self,
+        priority: i32,
+        f: impl AsyncListener<Ev>,
+    ) -> Self {
+        <Self as AsyncPriorityMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_async_listener_with_priority(
+            self, priority, f,
+        )
+    }
+
+    /// Adds a user-defined notification handler to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_handler()`] for more info.
+    ///
+    pub fn add_handler(self, handler: Arc<dyn NotificationHandler<Ev>>) -> Self
+    where
+        Ev: 'static,
+    {
+        <Self as HandlerMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_handler(
+            self, handler,
+        )
+    }
+
+    /// Adds a [`MediatorObserver`] to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_observer()`] for more info.
+    ///
+    pub fn with_observer(self, observer: impl MediatorObserver<Ev> + 'static) -> Self {
+        <Self as ObserverMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::with_observer(
+            self, observer,
+        )
+    }
+
+    /// Adds a hook invoked before every request sent to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::on_before_send()`] for more info.
+    ///
+    pub fn on_before_send(self, f: impl Fn(&RequestMeta) + Send + Sync + 'static) -> Self {
+        <Self as RequestHookBuilderInterface<BasicAsyncMediator<Ev>>>::on_before_send(self, f)
+    }
+
+    /// Adds a hook invoked after every request sent to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::on_after_send()`] for more info.
+    ///
+    pub fn on_after_send(self, f: impl Fn(&RequestMeta, Duration) + Send + Sync + 'static) -> Self {
+        <Self as RequestHookBuilderInterface<BasicAsyncMediator<Ev>>>::on_after_send(self, f)
+    }
+
+    /// Records the flows of requests, events and listeners of the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::record_causality()`] for more info.
+    ///
+    #[cfg(feature = "causality")]
+    pub fn record_causality(self) -> Self
+    where
+        Ev: Debug,
+    {
+        <Self as CausalityBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::record_causality(self)
+    }
+
+    /// Sets the capacity of the replay buffer of the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_replay_capacity()`] for more info.
+    ///
+    pub fn with_replay_capacity(self, capacity: usize) -> Self
+    where
+        Ev: Clone,
+    {
+        <Self as ReplayMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::with_replay_capacity(
+            self, capacity,
+        )
+    }
+
+    /// Enables or disables the dead-letter queue of the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::collect_dead_letters()`] for more info.
+    ///
+    pub fn collect_dead_letters(self, collect: bool) -> Self {
+        <Self as DeadLetterMediatorBuilderInterface<BasicAsyncMediator<Ev>>>::collect_dead_letters(
+            self, collect,
+        )
+    }
+
+    /// Sets the [`RetryPolicy`] of the [`BasicAsyncBuilder`].
+    ///
+    /// The policy applies to [`BasicAsyncMediator::try_send_with_retry()`]:
+    /// a failing request is attempted up to `max_attempts` times,
+    /// waiting `backoff` before the first retry and twice as long before each further one.
+    /// By default, a request is attempted once.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///     .with_retry_policy(RetryPolicy {
+    ///         max_attempts: 5,
+    ///         backoff: Duration::from_millis(100),
+    ///     })
+    ///     .build();
+    ///
+    pub fn with_retry_policy(self, policy: RetryPolicy) -> Self {
+        <Self as AsyncRetryMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::with_retry_policy(
+            self, policy,
+        )
+    }
+
+    /// Sets the function creating the event published once a [`RetryPolicy`] is exhausted.
+    ///
+    /// When [`BasicAsyncMediator::try_send_with_retry()`] gives up,
+    /// `f` is called with the [`RetryExhausted`] details of the request
+    /// and the returned event is published before the error is handed back.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     GaveUp(&'static str)
+    /// }
+    ///
+    /// let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///     .on_retry_exhausted(|exhausted| MyEvent::GaveUp(exhausted.request))
+    ///     .build();
+    ///
+    pub fn on_retry_exhausted(
+        self,
+        f: impl Fn(&RetryExhausted) -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        <Self as AsyncRetryMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::on_retry_exhausted(
+            self, f,
+        )
+    }
+
+    /// Adds a user-defined listener receiving the event as [`Enveloped`] to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_enveloped_listener()`] for more info.
+    ///
+    pub fn add_enveloped_listener(
+        self,
+        f: impl Fn(&Enveloped<'_, Ev>) + Send + Sync + 'static,
+    ) -> Self {
+        <Self as EnvelopedMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_enveloped_listener(
+            self, f,
+        )
+    }
+
+    /// Adds a user-defined listener receiving every event along with its [`EventMeta`] to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_catch_all_listener()`] for more info.
+    ///
+    pub fn add_catch_all_listener(
+        self,
+        f: impl Fn(&Ev, &EventMeta) + Send + Sync + 'static,
+    ) -> Self {
+        <Self as CatchAllMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_catch_all_listener(self, f)
+    }
+
+    /// Adds a user-defined [`PublishInterceptor`] to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_publish_interceptor()`] for more info.
+    ///
+    pub fn add_publish_interceptor(self, f: impl PublishInterceptor<Ev>) -> Self {
+        <Self as InterceptorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_publish_interceptor(
+            self, f,
+        )
+    }
+
+    /// Adds a [`LoggingListener`] to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_logging_listener()`] for more info.
+    ///
+    #[cfg(feature = "tracing")]
+    pub fn add_logging_listener(self, listener: impl Into<LoggingListener<Ev>>) -> Self
+    where
+        Ev: 'static,
+    {
+        <Self as LoggingMediatorBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_logging_listener(
+            self, listener,
+        )
+    }
+
+    /// Adds an upcaster for events of the version `from_version` to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::register_upcaster()`] for more info.
+    ///
+    #[cfg(feature = "persistence")]
+    pub fn register_upcaster(
+        self,
+        from_version: u32,
+        f: impl Fn(Value) -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        <Self as UpcasterBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::register_upcaster(
+            self,
+            from_version,
+            f,
+        )
+    }
+
+    /// Adds a user-defined listener receiving the event in an [`Arc`] to the [`BasicAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_shared_listener()`] for more info.
+    ///
+    pub fn add_shared_listener(self, f: impl SharedListener<Ev>) -> Self
+    where
+        Ev: Clone,
+    {
+        <Self as SharedListenerBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_shared_listener(
+            self, f,
+        )
+    }
+
+    /// Adds a user-defined async listener receiving the event in an [`Arc`] to the [`BasicAsyncBuilder`].
+    ///
+    /// The returned `Future` can move the event into its `async` block
+    /// without cloning it, unlike the one of an [`AsyncListener`].
+    /// Shared listeners are awaited after all other listeners.
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_shared_listener()`] for more info.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///     .add_async_shared_listener(|ev: Arc<MyEvent>| async move {
+    ///         println!("{:?}", ev);
+    ///     })
+    ///     .build();
+    ///
+    pub fn add_async_shared_listener(self, f: impl AsyncSharedListener<Ev>) -> Self
+    where
+        Ev: Clone + Send + Sync,
+    {
+        <Self as AsyncSharedListenerBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_async_shared_listener(
+            self, f,
+        )
+    }
+
+    /// Registers an async handler for requests of type `Req` with the [`BasicAsyncBuilder`].
+    ///
+    /// The handler receives a [`MediatorHandle`] to publish events
+    /// and returns a `Future`, which is awaited by [`BasicAsyncMediator::send_dyn()`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::register_handler()`] for more info.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Greeted(String)
+    /// }
+    ///
+    /// struct Greet(String);
+    ///
+    /// async_std::task::block_on(async {
+    ///     let async_mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///         .register_handler(|m: MediatorHandle<MyEvent>, req: Greet| async move {
+    ///             m.publish(MyEvent::Greeted(req.0)).await;
+    ///         })
+    ///         .build();
+    ///
+    ///     assert!(async_mediator.send_dyn(Box::new(Greet("World".to_string()))).await.is_ok());
+    ///     assert_eq!(async_mediator.next_all().await, 1);
+    /// });
+    ///
+    pub fn register_handler<Req, Fut>(
+        self,
+        handler: impl Fn(MediatorHandle<Ev>, Req) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Ev: Send + Sync,
+        Req: Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        <Self as AsyncDynamicHandlerBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::register_handler(
+            self, handler,
+        )
+    }
+
+    /// Adds a closure handling requests of type `Req` to the [`BasicAsyncBuilder`].
+    ///
+    /// The closure receives a [`MediatorHandle`] and returns a `Future`,
+    /// whose output is the response of type `Resp`.
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_request_handler()`] for more info.
+    ///
+    pub fn add_request_handler<Req, Resp, Fut>(
+        self,
+        handler: impl Fn(MediatorHandle<Ev>, Req) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Ev: Send + Sync + 'static,
+        Req: Send + 'static,
+        Resp: Send + 'static,
+        Fut: Future<Output = Resp> + Send + 'static,
+    {
+        <Self as AsyncClosureHandlerBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_request_handler(
+            self, handler,
+        )
+    }
+
+    /// Adds a closure handling requests of type `Req` together with others to the [`BasicAsyncBuilder`].
+    ///
+    /// The closure receives a [`MediatorHandle`] and the shared request.
+    /// If configured with [`BasicAsyncBuilder::with_concurrency()`],
+    /// up to that many closures handle a request concurrently.
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_fan_out_handler()`] for more info.
+    ///
+    pub fn add_fan_out_handler<Req, Fut>(
+        self,
+        handler: impl Fn(MediatorHandle<Ev>, Arc<Req>) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Ev: Send + Sync + 'static,
+        Req: Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        <Self as AsyncClosureHandlerBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::add_fan_out_handler(
+            self, handler,
+        )
+    }
+
+    /// Sets the number of listeners and events the [`BasicAsyncBuilder`] processes concurrently.
+    ///
+    /// By default, every [`AsyncListener`] is awaited one after another
+    /// and events are processed one at a time.
+    /// With a `limit` greater than `1`, [`BasicAsyncMediator::next()`] awaits
+    /// up to `limit` async listeners of an event concurrently,
+    /// while [`BasicAsyncMediator::next_all()`] and [`BasicAsyncMediator::run()`]
+    /// additionally process up to `limit` events concurrently.
+    /// This improves the throughput of I/O-bound listeners,
+    /// but neither the order of listeners nor the order of events is preserved,
+    /// unless configured otherwise with [`BasicAsyncBuilder::with_ordering()`].
+    /// Synchronous listeners are still invoked one after another.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///         .add_async_listener(|_: &MyEvent| async {
+    ///             // e.g. a slow network call
+    ///             async_std::task::sleep(Duration::from_millis(10)).await;
+    ///         })
+    ///         .with_concurrency(4)
+    ///         .build();
+    ///
+    ///     mediator.publish(MyEvent::One).await;
+    ///     mediator.publish(MyEvent::Two).await;
+    ///
+    ///     assert_eq!(mediator.next_all().await, 2);
+    /// });
+    ///
+    pub fn with_concurrency(self, limit: usize) -> Self {
+        <Self as AsyncConcurrencyMediatorBuilderInterface<BasicAsyncMediator<Ev>>>::with_concurrency(
+            self, limit,
+        )
+    }
+
+    /// Sets the [`Ordering`] in which the [`BasicAsyncBuilder`] processes events and their listeners.
+    ///
+    /// Once events are processed concurrently, e.g. with [`BasicAsyncBuilder::with_concurrency()`]
+    /// or by multiple tasks, [`Ordering::Unordered`] (the default) gives the most throughput.
+    /// [`Ordering::StrictFifo`] awaits the async listeners of one event after another
+    /// in publishing order, [`Ordering::PerTopicFifo`] only does so for events of the same topic.
+    /// Synchronous listeners are always invoked in publishing order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One
+    /// }
+    ///
+    /// let mediator = BasicAsyncMediator::<MyEvent>::builder()
+    ///     .with_concurrency(4)
+    ///     .with_ordering(Ordering::PerTopicFifo)
+    ///     .build();
+    ///
+    pub fn with_ordering(self, ordering: Ordering) -> Self {
+        <Self as AsyncOrderingMediatorBuilderInterface<BasicAsyncMediator<Ev>>>::with_ordering(
+            self, ordering,
+        )
+    }
+
+    /// Creates the channel of the [`BasicAsyncBuilder`] through the [`ChannelFlavor`] `F`.
+    ///
+    /// By default, the channel is one of `async-std`, see [`crate::channel::AsyncChannel`].
+    /// See [`crate::synchronous::basic::BasicBuilder::with_channel_backend()`] for more info.
+    ///
+    pub fn with_channel_backend<F: ChannelFlavor>(self) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        <Self as ChannelBackendBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::with_channel_backend::<
+            F,
+        >(self)
+    }
+
+    /// Configures the channel, concurrency and ordering of the [`BasicAsyncBuilder`] from the [`Profile`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_profile()`] for more info.
+    ///
+    pub fn with_profile(self, profile: Profile) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        <Self as ProfileBuilderInterface<BasicAsyncMediator<Ev>, Ev>>::with_profile(self, profile)
+    }
+}
+
+impl<Ev> BasicAsyncBuilder<SystemEvent<Ev>>
+where
+    Ev: MaybeDebug,
+{
+    /// Publishes the request events of every request, if `enabled`.
+    ///
+    /// See [`BasicBuilder::with_request_events()`](crate::synchronous::basic::BasicBuilder::with_request_events) for more info.
+    ///
+    pub fn with_request_events(self, enabled: bool) -> Self {
+        <Self as RequestEventsBuilderInterface<
+            BasicAsyncMediator<SystemEvent<Ev>>,
+            SystemEvent<Ev>,
+        >>::with_request_events(self, enabled)
     }
 }
 
 impl<Ev> BuilderFlow<BasicAsyncMediator<Ev>> for BasicAsyncBuilder<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Builds the [`BasicAsyncMediator`] and returns it.
     ///
@@ -107,8 +1786,24 @@ where
     /// always return a [`BasicAsyncMediator`] as stated by the return type.
     ///
     fn build(self) -> BasicAsyncMediator<Ev> {
+        let published = Arc::new(Event::new());
         BasicAsyncMediator {
-            basic: Mutex::new(self.mediator),
+            inner: Arc::new(Inner {
+                observer: self.mediator.observer.clone(),
+                scheduler: Scheduler::new(published.clone(), self.mediator.observer.clone()),
+                basic: self.mediator,
+                async_listener: self.async_listener,
+                async_shared_listener: self.async_shared_listener,
+                published,
+                consumed: Event::new(),
+                behavior: self.behavior,
+                retry: self.retry,
+                tickers: self.tickers,
+                requests: RequestQueue::default(),
+                handlers: self.handlers,
+                concurrency: self.concurrency,
+                sequencer: Sequencer::new(self.ordering),
+            }),
         }
     }
 }
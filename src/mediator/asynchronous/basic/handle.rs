@@ -0,0 +1,220 @@
+use futures_core::Stream;
+use std::{fmt::Debug, time::Duration};
+
+use crate::mediator::channel::TrySendError;
+
+use super::*;
+
+/// A cheaply cloneable handle to a [`BasicAsyncMediator`] with events of type `Ev`.
+///
+/// Returned by [`BasicAsyncMediator::new_handle()`].
+/// All clones share the mediator, so a handle can be moved into as many tasks as needed
+/// to send requests and publish events, while the mediator itself
+/// stays in charge of processing events, e.g. with [`BasicAsyncMediator::run()`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::asynchronous::basic::*;
+/// use async_trait::async_trait;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     One
+/// }
+///
+/// struct Request;
+///
+/// #[async_trait]
+/// impl AsyncRequestHandler<Request, MyEvent> for BasicAsyncMediator<MyEvent> {
+///     async fn handle(&self, _req: Request) {
+///         self.publish(MyEvent::One).await;
+///     }
+/// }
+///
+/// async_std::task::block_on(async {
+///     let mediator = BasicAsyncMediator::<MyEvent>::builder().build();
+///
+///     let tasks: Vec<_> = (0..4)
+///         .map(|_| {
+///             let handle = mediator.new_handle();
+///             async_std::task::spawn(async move { handle.send(Request).await })
+///         })
+///         .collect();
+///     for task in tasks {
+///         task.await;
+///     }
+///
+///     assert_eq!(mediator.next_all().await, 4);
+/// });
+///
+#[derive(Debug)]
+pub struct MediatorHandle<Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    mediator: BasicAsyncMediator<Ev>,
+}
+
+impl<Ev> Clone for MediatorHandle<Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn clone(&self) -> Self {
+        Self::new(&self.mediator)
+    }
+}
+
+impl<Ev> MediatorHandle<Ev>
+where
+    Ev: MaybeDebug,
+{
+    pub(crate) fn new(mediator: &BasicAsyncMediator<Ev>) -> Self {
+        Self {
+            mediator: BasicAsyncMediator {
+                inner: mediator.inner.clone(),
+            },
+        }
+    }
+}
+
+impl<Ev> MediatorHandle<Ev>
+where
+    Ev: MaybeDebug + Send,
+{
+    /// Send a request of type `Req` to the mediator asynchronously.
+    ///
+    /// See [`BasicAsyncMediator::send()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn send<Req>(&self, req: Req)
+    where
+        BasicAsyncMediator<Ev>: AsyncRequestHandler<Req, Ev>,
+        Req: Send + 'static,
+    {
+        self.mediator.send(req).await
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously
+    /// and receive a response of type `Resp`.
+    ///
+    /// See [`BasicAsyncMediator::send_and_receive()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn send_and_receive<Req, Resp>(&self, req: Req) -> Resp
+    where
+        BasicAsyncMediator<Ev>: AsyncRequestHandler<Req, Ev, Resp>,
+        Req: Send,
+        Resp: Send,
+    {
+        self.mediator.send_and_receive(req).await
+    }
+
+    /// Send a batch of requests of type `Req` to the mediator asynchronously.
+    ///
+    /// See [`BasicAsyncMediator::send_batch()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn send_batch<Req>(&self, reqs: Vec<Req>)
+    where
+        BasicAsyncMediator<Ev>: AsyncBatchRequestHandler<Req, Ev>,
+        Req: Send + 'static,
+    {
+        self.mediator.send_batch(reqs).await
+    }
+
+    /// Queue a request of type `Req` to be handled by the running mediator.
+    ///
+    /// See [`BasicAsyncMediator::queue_request()`] for more info.
+    ///
+    pub fn queue_request<Req>(&self, req: Req)
+    where
+        BasicAsyncMediator<Ev>: AsyncRequestHandler<Req, Ev> + Send + Sync + 'static,
+        Req: Send + 'static,
+    {
+        self.mediator.queue_request(req)
+    }
+
+    /// Send a request of type `Req` to the mediator
+    /// and receive a stream of results of type `Item`.
+    ///
+    /// See [`BasicAsyncMediator::send_stream()`] for more info.
+    ///
+    pub fn send_stream<Req, Item>(&self, req: Req) -> impl Stream<Item = Item> + Send + Unpin + '_
+    where
+        BasicAsyncMediator<Ev>: StreamRequestHandler<Req, Ev, Item>,
+        Req: Send + 'static,
+    {
+        self.mediator.send_stream(req)
+    }
+
+    /// Try to send a request of type `Req` to the mediator asynchronously.
+    ///
+    /// See [`BasicAsyncMediator::try_send()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn try_send<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        BasicAsyncMediator<Ev>: TryAsyncRequestHandler<Req, Ev, Err>,
+        Req: Send + 'static,
+        Err: Send,
+    {
+        self.mediator.try_send(req).await
+    }
+
+    /// Publishes an event `Ev` asynchronously.
+    ///
+    /// See [`BasicAsyncMediator::publish()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn publish(&self, event: Ev) {
+        self.mediator.publish(event).await
+    }
+
+    /// Tries to publish an event `Ev` asynchronously.
+    ///
+    /// See [`BasicAsyncMediator::try_publish()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn try_publish(&self, event: Ev) -> Result<(), TrySendError<Ev>> {
+        self.mediator.try_publish(event).await
+    }
+
+    /// Publishes an event `Ev` to the given `topic` asynchronously.
+    ///
+    /// See [`BasicAsyncMediator::publish_to()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn publish_to(&self, topic: &str, event: Ev) {
+        self.mediator.publish_to(topic, event).await
+    }
+
+    /// Publishes an event `Ev` asynchronously that expires once the `ttl` elapsed.
+    ///
+    /// See [`BasicAsyncMediator::publish_with_ttl()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn publish_with_ttl(&self, event: Ev, ttl: Duration) {
+        self.mediator.publish_with_ttl(event, ttl).await
+    }
+
+    /// Publishes an urgent event `Ev` asynchronously, which is delivered before all regular events.
+    ///
+    /// See [`BasicAsyncMediator::publish_urgent()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn publish_urgent(&self, event: Ev) {
+        self.mediator.publish_urgent(event).await
+    }
+}
@@ -1,39 +1,526 @@
 use async_trait::async_trait;
-use std::{fmt::Debug, sync::mpsc::TryRecvError};
+use futures_core::Stream;
+use std::{any::Any, future::Future, sync::Arc, time::Duration};
+
+#[cfg(feature = "causality")]
+use crate::mediator::causality::CausalityGraph;
+#[cfg(feature = "persistence")]
+use crate::mediator::codec::Codec;
+#[cfg(feature = "schema")]
+use crate::mediator::schema::MediatorDescription;
+#[cfg(feature = "persistence")]
+use std::{io, path::Path};
+
+use super::EventStream;
+use crate::mediator::{
+    behavior::AsyncBehavior,
+    cancellation::{CancellationToken, CancelledError, TimeoutError},
+    channel::TrySendError,
+    correlation::CorrelationId,
+    deadletter::{DeadLetters, RedeliveryPolicy},
+    listener::{
+        AsyncListener, AsyncSharedListener, Filter, Listener, ListenerHandle, MaybeDebug, NextError,
+    },
+    ordering::Ordering,
+    queue::Priority,
+    retry::{RetryExhausted, RetryPolicy},
+    stats::MediatorStats,
+    time::Instant,
+    worker::{JoinHandle, Worker},
+};
 
 /// Publish an event `Ev` asynchronously from within a handler.
+/// Alternatively, try to publish it and get it back if the channel is full,
+/// publish it to a topic or schedule it for later.
 #[async_trait]
-pub trait AsyncMediatorInternal<Ev: Debug> {
+pub trait AsyncMediatorInternal<Ev: MaybeDebug> {
     #[allow(missing_docs)]
     async fn publish(&self, event: Ev);
+
+    #[allow(missing_docs)]
+    async fn try_publish(&self, event: Ev) -> Result<(), TrySendError<Ev>>;
+
+    #[allow(missing_docs)]
+    async fn publish_to(&self, topic: &str, event: Ev);
+
+    #[allow(missing_docs)]
+    async fn publish_with_ttl(&self, event: Ev, ttl: Duration);
+
+    #[allow(missing_docs)]
+    async fn publish_urgent(&self, event: Ev);
+
+    #[allow(missing_docs)]
+    async fn publish_after(&self, event: Ev, delay: Duration);
+
+    #[allow(missing_docs)]
+    async fn publish_at(&self, event: Ev, deadline: Instant);
+}
+
+/// Publish the events `Ev` recorded in a [`Journal`](crate::persistence::Journal) again
+/// asynchronously, e.g. to rebuild state in a fresh mediator.
+#[cfg(feature = "persistence")]
+#[async_trait]
+pub trait AsyncMediatorInternalJournal<Ev: MaybeDebug> {
+    #[allow(missing_docs)]
+    async fn replay_from(&self, path: impl AsRef<Path> + Send) -> io::Result<usize>;
+
+    #[allow(missing_docs)]
+    async fn replay_from_with(
+        &self,
+        path: impl AsRef<Path> + Send,
+        codec: impl Codec,
+    ) -> io::Result<usize>;
 }
 
 /// Send a request `Req` asynchronously for processing to the mediator.
 /// This will call the handler.
 #[async_trait]
-pub trait AsyncMediatorInternalHandle<Ev: Debug> {
+pub trait AsyncMediatorInternalHandle<Ev: MaybeDebug> {
     #[allow(missing_docs)]
     async fn send<Req>(&self, req: Req)
+    where
+        Req: Send + 'static,
+        Self: AsyncRequestHandler<Req, Ev>;
+
+    #[allow(missing_docs)]
+    fn send_detached<Req>(&self, req: Req) -> JoinHandle<()>
+    where
+        Req: Send + 'static,
+        Self: AsyncRequestHandler<Req, Ev> + Send + Sync + 'static;
+
+    #[allow(missing_docs)]
+    async fn send_traced<Req>(&self, req: Req) -> CorrelationId
+    where
+        Req: Send + 'static,
+        Self: AsyncRequestHandler<Req, Ev>;
+
+    #[allow(missing_docs)]
+    async fn send_and_receive<Req, Resp>(&self, req: Req) -> Resp
     where
         Req: Send,
+        Resp: Send,
+        Self: AsyncRequestHandler<Req, Ev, Resp>;
+
+    #[allow(missing_docs)]
+    async fn send_batch<Req>(&self, reqs: Vec<Req>)
+    where
+        Req: Send + 'static,
+        Self: AsyncBatchRequestHandler<Req, Ev>;
+
+    #[allow(missing_docs)]
+    async fn send_dyn(&self, req: Box<dyn Any + Send>) -> Result<(), Box<dyn Any + Send>>
+    where
+        Self: Sync + 'static;
+
+    #[allow(missing_docs)]
+    fn send_stream<Req, Item>(&self, req: Req) -> impl Stream<Item = Item> + Send + Unpin + '_
+    where
+        Req: Send + 'static,
+        Self: StreamRequestHandler<Req, Ev, Item>;
+
+    #[allow(missing_docs)]
+    async fn try_send<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Req: Send + 'static,
+        Err: Send,
+        Self: TryAsyncRequestHandler<Req, Ev, Err>;
+
+    #[allow(missing_docs)]
+    async fn try_send_with_retry<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Req: Clone + Send + Sync + 'static,
+        Ev: Send,
+        Err: Send,
+        Self: TryAsyncRequestHandler<Req, Ev, Err>;
+
+    #[allow(missing_docs)]
+    async fn send_with_cancellation<Req>(
+        &self,
+        req: Req,
+        token: &CancellationToken,
+    ) -> Result<(), CancelledError>
+    where
+        Req: Send + 'static,
+        Self: AsyncRequestHandler<Req, Ev>;
+
+    #[allow(missing_docs)]
+    async fn send_with_timeout<Req>(
+        &self,
+        req: Req,
+        duration: Duration,
+    ) -> Result<(), TimeoutError>
+    where
+        Req: Send + 'static,
         Self: AsyncRequestHandler<Req, Ev>;
+
+    #[allow(missing_docs)]
+    async fn send_with_priority<Req>(&self, req: Req, priority: Priority)
+    where
+        Req: Send + 'static,
+        Self: AsyncRequestHandler<Req, Ev> + Send + Sync + 'static;
+
+    #[allow(missing_docs)]
+    fn queue_request<Req>(&self, req: Req)
+    where
+        Req: Send + 'static,
+        Self: AsyncRequestHandler<Req, Ev> + Send + Sync + 'static;
 }
 
 /// Process the next event `Ev` from the channel asynchronously.
 /// This will call all listeners with a `&Ev`.
+/// Alternatively, process all pending events at once
+/// or wait until the next event is available.
 #[async_trait]
 pub trait AsyncMediatorInternalNext {
     #[allow(missing_docs)]
-    async fn next(&self) -> Result<(), TryRecvError>;
+    async fn next(&self) -> Result<(), NextError>;
+
+    #[allow(missing_docs)]
+    async fn next_all(&self) -> usize;
+
+    #[allow(missing_docs)]
+    async fn next_async(&self) -> Result<(), NextError>;
+}
+
+/// Process events `Ev` continuously until a shutdown is signalled,
+/// either on the current task or on a background task.
+#[async_trait]
+pub trait AsyncMediatorInternalRun {
+    #[allow(missing_docs)]
+    async fn run(&self, shutdown: &CancellationToken) -> usize;
+
+    #[allow(missing_docs)]
+    fn spawn_worker(self: Arc<Self>) -> Worker
+    where
+        Self: 'static;
+}
+
+/// Create cheaply cloneable handles to the mediator,
+/// which send requests and publish events from other tasks.
+/// Not named `handle()` to avoid ambiguities with the request handler traits.
+pub trait AsyncMediatorInternalShare {
+    #[allow(missing_docs)]
+    type Handle: Clone;
+
+    #[allow(missing_docs)]
+    fn new_handle(&self) -> Self::Handle;
+}
+
+/// Pause and resume the delivery of events to listeners.
+pub trait AsyncMediatorInternalPause {
+    #[allow(missing_docs)]
+    fn pause(&self);
+
+    #[allow(missing_docs)]
+    fn resume(&self);
+
+    #[allow(missing_docs)]
+    fn is_paused(&self) -> bool;
+}
+
+/// Take a snapshot of the counters of the mediator.
+pub trait AsyncMediatorInternalStats {
+    #[allow(missing_docs)]
+    fn stats(&self) -> MediatorStats;
+}
+
+/// Describe the events and listeners of the mediator for documentation tooling.
+#[cfg(feature = "schema")]
+pub trait AsyncMediatorInternalDescribe {
+    #[allow(missing_docs)]
+    fn describe(&self) -> MediatorDescription;
+}
+
+/// Export the recorded flows of requests, events and listeners.
+#[cfg(feature = "causality")]
+pub trait AsyncMediatorInternalCausality {
+    #[allow(missing_docs)]
+    fn causality_graph(&self) -> CausalityGraph;
+}
+
+/// Consume published events `Ev` as a stream.
+/// This will call all listeners with a `&Ev` before yielding the event.
+pub trait AsyncMediatorInternalStream<Ev: MaybeDebug + 'static> {
+    #[allow(missing_docs)]
+    fn event_stream(&self) -> EventStream<'_, Ev>;
+}
+
+/// Subscribe and unsubscribe listeners on an already built mediator asynchronously.
+#[async_trait]
+pub trait AsyncMediatorInternalSubscribe<Ev: MaybeDebug> {
+    #[allow(missing_docs)]
+    async fn subscribe<F>(&self, f: F) -> ListenerHandle
+    where
+        F: Listener<Ev>;
+
+    #[allow(missing_docs)]
+    async fn subscribe_async<F>(&self, f: F) -> ListenerHandle
+    where
+        F: AsyncListener<Ev>;
+
+    #[allow(missing_docs)]
+    async fn unsubscribe(&self, handle: ListenerHandle) -> bool;
+
+    #[allow(missing_docs)]
+    async fn add_listener_with_replay<F>(&self, f: F) -> ListenerHandle
+    where
+        F: Listener<Ev>;
+}
+
+/// Send a request `Req` asynchronously to the closure added for its type with `add_request_handler()`.
+/// Alternatively, receive the response of the closure
+/// or send it to all closures added with `add_fan_out_handler()`.
+#[async_trait]
+pub trait AsyncMediatorInternalRegistered {
+    #[allow(missing_docs)]
+    async fn send_registered<Req>(&self, req: Req) -> Result<(), Req>
+    where
+        Self: Sync + 'static,
+        Req: Send + 'static;
+
+    #[allow(missing_docs)]
+    async fn send_registered_and_receive<Req, Resp>(&self, req: Req) -> Result<Resp, Req>
+    where
+        Self: Sync + 'static,
+        Req: Send + 'static,
+        Resp: Send + 'static;
+
+    #[allow(missing_docs)]
+    async fn send_fan_out<Req>(&self, req: Req) -> usize
+    where
+        Self: Sync + 'static,
+        Req: Send + Sync + 'static;
+}
+
+/// Take the events that could not be processed out of the dead-letter queue asynchronously.
+/// Alternatively, redeliver them to the listeners.
+#[async_trait]
+pub trait AsyncMediatorInternalDeadLetters<Ev: MaybeDebug> {
+    #[allow(missing_docs)]
+    async fn dead_letters(&self) -> DeadLetters<Ev>;
+
+    #[allow(missing_docs)]
+    async fn redeliver_all(&self) -> usize;
+
+    #[allow(missing_docs)]
+    async fn redeliver_with(&self, policy: RedeliveryPolicy) -> usize;
 }
 
 /// Handles the request `Req` asynchronously.
 /// Implemented by the user.
+/// Returns a response of type `Resp`, which defaults to `()`.
 #[async_trait]
-pub trait AsyncRequestHandler<Req, Res>
+pub trait AsyncRequestHandler<Req, Ev, Resp = ()>
 where
     Self: Sync,
 {
     #[allow(missing_docs)]
-    async fn handle(&self, req: Req);
+    async fn handle(&self, req: Req) -> Resp;
+}
+
+/// Handles a batch of requests `Req` asynchronously at once.
+/// Implemented for every [`AsyncRequestHandler`], handling the requests one after another.
+/// Implement it instead of [`AsyncRequestHandler`] for a more efficient code path,
+/// e.g. a single database round-trip for the whole batch.
+#[async_trait]
+pub trait AsyncBatchRequestHandler<Req, Ev>
+where
+    Self: Sync,
+{
+    #[allow(missing_docs)]
+    async fn handle_batch(&self, reqs: Vec<Req>);
+}
+
+#[async_trait]
+impl<M, Req, Ev> AsyncBatchRequestHandler<Req, Ev> for M
+where
+    M: AsyncRequestHandler<Req, Ev> + Sync,
+    Req: Send + 'static,
+{
+    async fn handle_batch(&self, reqs: Vec<Req>) {
+        for req in reqs {
+            self.handle(req).await
+        }
+    }
+}
+
+/// Handles the request `Req` asynchronously, which may fail with an error of type `Err`.
+/// Implemented by the user.
+#[async_trait]
+pub trait TryAsyncRequestHandler<Req, Ev, Err>
+where
+    Self: Sync,
+{
+    #[allow(missing_docs)]
+    async fn handle(&self, req: Req) -> Result<(), Err>;
+}
+
+/// Handles the request `Req` asynchronously, yielding a stream of results of type `Item`.
+/// Implemented by the user.
+/// Useful for paginated or incremental results.
+pub trait StreamRequestHandler<Req, Ev, Item>
+where
+    Self: Sync,
+{
+    #[allow(missing_docs)]
+    fn handle(&self, req: Req) -> impl Stream<Item = Item> + Send + '_;
+}
+
+/// Async builder fuctionality:
+/// Adding an [`AsyncListener`] to the builder.
+pub trait AsyncMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_async_listener(self, f: impl AsyncListener<Ev>) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Async named listener builder fuctionality:
+/// Adding an [`AsyncListener`] with a name to the builder.
+pub trait AsyncNamedListenerBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_async_listener_named(self, name: &'static str, f: impl AsyncListener<Ev>) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Async debounce builder fuctionality:
+/// Adding an [`AsyncListener`] receiving only the latest event per key within a time window.
+pub trait AsyncDebounceBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_debounced_listener<K>(
+        self,
+        window: Duration,
+        key_fn: impl Fn(&Ev) -> K + Send + Sync + 'static,
+        f: impl AsyncListener<Ev>,
+    ) -> Self
+    where
+        Ev: MaybeDebug + Clone + Send + Sync,
+        K: Ord + Clone + Send + Sync + 'static;
+}
+
+/// Async spawned listener builder fuctionality:
+/// Adding an [`AsyncListener`] running on its own spawned task.
+pub trait AsyncSpawnedListenerBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_spawned_listener(self, f: impl AsyncListener<Ev>) -> Self
+    where
+        Ev: MaybeDebug + Clone + Send + Sync;
+}
+
+/// Async ticker builder fuctionality:
+/// Publishing a generated event on a fixed interval while the mediator runs.
+pub trait AsyncTickerBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn with_ticker(self, interval: Duration, f: impl Fn() -> Ev + Send + Sync + 'static) -> Self;
+}
+
+/// Async pipeline builder fuctionality:
+/// Adding an [`AsyncBehavior`] to the builder.
+pub trait AsyncBehaviorMediatorBuilderInterface<M> {
+    #[allow(missing_docs)]
+    fn add_behavior(self, b: impl AsyncBehavior) -> Self;
+}
+
+/// Async topic builder fuctionality:
+/// Adding an [`AsyncListener`] for a topic to the builder.
+pub trait AsyncTopicMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_async_listener_for_topic(self, topic: &str, f: impl AsyncListener<Ev>) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Async filter builder fuctionality:
+/// Adding an [`AsyncListener`] guarded by a [`Filter`] to the builder.
+pub trait AsyncFilterMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_filtered_async_listener(
+        self,
+        filter: impl Filter<Ev>,
+        f: impl AsyncListener<Ev>,
+    ) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Async priority builder fuctionality:
+/// Adding an [`AsyncListener`] with a priority to the builder.
+pub trait AsyncPriorityMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_async_listener_with_priority(self, priority: i32, f: impl AsyncListener<Ev>) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Async retry builder fuctionality:
+/// Configuring the [`RetryPolicy`] for fallible requests
+/// and the event published once it is exhausted.
+pub trait AsyncRetryMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn with_retry_policy(self, policy: RetryPolicy) -> Self;
+
+    #[allow(missing_docs)]
+    fn on_retry_exhausted(self, f: impl Fn(&RetryExhausted) -> Ev + Send + Sync + 'static) -> Self;
+}
+
+/// Async concurrency builder fuctionality:
+/// Limiting the number of listeners and events processed concurrently.
+pub trait AsyncConcurrencyMediatorBuilderInterface<M> {
+    #[allow(missing_docs)]
+    fn with_concurrency(self, limit: usize) -> Self;
+}
+
+/// Async ordering builder fuctionality:
+/// Setting the [`Ordering`] in which events and their listeners are processed.
+pub trait AsyncOrderingMediatorBuilderInterface<M> {
+    #[allow(missing_docs)]
+    fn with_ordering(self, ordering: Ordering) -> Self;
+}
+
+/// Async shared listener builder fuctionality:
+/// Adding an [`AsyncSharedListener`] that receives the event in an [`Arc`] to the builder.
+pub trait AsyncSharedListenerBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_async_shared_listener(self, f: impl AsyncSharedListener<Ev>) -> Self
+    where
+        Ev: MaybeDebug + Clone;
+}
+
+/// Register a handler for requests `Req` sent to the mediator `M` via `send_dyn()`.
+/// The handler receives a handle to the mediator, see [`AsyncMediatorInternalShare`].
+pub trait AsyncDynamicHandlerBuilderInterface<M: AsyncMediatorInternalShare, Ev> {
+    #[allow(missing_docs)]
+    fn register_handler<Req, Fut>(
+        self,
+        handler: impl Fn(M::Handle, Req) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static;
+}
+
+/// Closure handler builder fuctionality:
+/// Adding a closure handling requests `Req` sent to the mediator `M` via `send_registered()`,
+/// or one of many closures handling requests sent via `send_fan_out()`.
+/// The closure receives a handle to the mediator, see [`AsyncMediatorInternalShare`].
+pub trait AsyncClosureHandlerBuilderInterface<M: AsyncMediatorInternalShare, Ev> {
+    #[allow(missing_docs)]
+    fn add_request_handler<Req, Resp, Fut>(
+        self,
+        handler: impl Fn(M::Handle, Req) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Resp: Send + 'static,
+        Fut: Future<Output = Resp> + Send + 'static;
+
+    #[allow(missing_docs)]
+    fn add_fan_out_handler<Req, Fut>(
+        self,
+        handler: impl Fn(M::Handle, Arc<Req>) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static;
 }
@@ -1,10 +1,49 @@
+#[allow(clippy::module_inception)]
 pub(crate) mod basic;
 pub(crate) mod builder;
+pub(crate) mod handle;
 pub(crate) mod interface;
+pub(crate) mod sharded;
 
 pub use basic::*;
 pub use builder::*;
+pub use handle::*;
 pub use interface::*;
+pub use sharded::*;
 
+pub use crate::behavior::*;
 pub use crate::builder::{BuilderFlow, BuilderInternal};
+pub use crate::cancellation::{CancellationToken, CancelledError, TimeoutError};
+#[cfg(feature = "causality")]
+pub use crate::causality::{CausalityGraph, CausalityNode};
+pub use crate::channel::OverflowPolicy;
+pub use crate::correlation::{CorrelationId, Enveloped, EventMeta};
+pub use crate::deadletter::{DeadLetter, DeadLetterReason, DeadLetters, RedeliveryPolicy};
 pub use crate::listener::*;
+#[cfg(feature = "causality")]
+pub use crate::mediator::synchronous::basic::interface::CausalityBuilderInterface;
+#[cfg(feature = "persistence")]
+pub use crate::mediator::synchronous::basic::interface::UpcasterBuilderInterface;
+pub use crate::mediator::synchronous::basic::interface::{
+    CatchAllMediatorBuilderInterface, ChannelBackendBuilderInterface,
+    ChannelMediatorBuilderInterface, DeadLetterMediatorBuilderInterface,
+    EnvelopedMediatorBuilderInterface, FilterMediatorBuilderInterface,
+    HandlerMediatorBuilderInterface, InterceptorBuilderInterface,
+    ListenerIsolationBuilderInterface, NamedListenerBuilderInterface,
+    ObserverMediatorBuilderInterface, PriorityMediatorBuilderInterface, ProfileBuilderInterface,
+    RateLimitBuilderInterface, ReplayMediatorBuilderInterface, RequestEventsBuilderInterface,
+    TopicMediatorBuilderInterface,
+};
+pub use crate::observer::{MediatorObserver, RequestMeta};
+pub use crate::ordering::Ordering;
+#[cfg(feature = "persistence")]
+pub use crate::persistence::{Journal, JournalEntry};
+pub use crate::profile::Profile;
+pub use crate::queue::Priority;
+#[cfg(feature = "std")]
+pub use crate::ratelimit::{RateLimit, ThrottlePolicy};
+pub use crate::retry::{RetryExhausted, RetryPolicy};
+#[cfg(feature = "schema")]
+pub use crate::schema::{ListenerDescription, MediatorDescription};
+pub use crate::stats::{ListenerStats, MediatorStats};
+pub use crate::worker::{JoinHandle, Worker};
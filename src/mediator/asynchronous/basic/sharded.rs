@@ -0,0 +1,193 @@
+use std::{
+    fmt::Debug,
+    hash::{BuildHasher, BuildHasherDefault, DefaultHasher, Hash},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+use crate::mediator::concurrency::join_limited;
+
+use super::*;
+
+/// A set of [`BasicAsyncMediator`]s, called shards, with events of type `Ev`,
+/// which routes requests and events by a key of type `K`.
+///
+/// Created with [`ShardedAsyncMediator::new()`] from a fixed number of shards.
+/// [`ShardedAsyncMediator::send_keyed()`] and [`ShardedAsyncMediator::publish_keyed()`]
+/// hash the key to pick a shard, so everything for the same key, e.g. the ID of an entity,
+/// ends up in the same shard.
+/// Every shard processes its requests and events one at a time and in order,
+/// while the shards run in parallel, e.g. with [`ShardedAsyncMediator::spawn_workers()`].
+/// This guarantees per-key ordering while processing different keys in parallel,
+/// the standard pattern for per-entity command streams.
+///
+/// A shard built with a concurrency above `1` processes its events concurrently,
+/// giving up the ordering.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::asynchronous::basic::*;
+/// use async_trait::async_trait;
+///
+/// #[derive(Debug, Clone)]
+/// enum AccountEvent {
+///     Deposited { account: u32, amount: u32 }
+/// }
+///
+/// struct Deposit {
+///     account: u32,
+///     amount: u32
+/// }
+///
+/// #[async_trait]
+/// impl AsyncRequestHandler<Deposit, AccountEvent> for BasicAsyncMediator<AccountEvent> {
+///     async fn handle(&self, req: Deposit) {
+///         self.publish(AccountEvent::Deposited { account: req.account, amount: req.amount }).await;
+///     }
+/// }
+///
+/// async_std::task::block_on(async {
+///     let mediator = ShardedAsyncMediator::<u32, AccountEvent>::new(4, |_shard| {
+///         BasicAsyncMediator::<AccountEvent>::builder()
+///             .add_listener(|ev: &AccountEvent| println!("{ev:?}"))
+///             .build()
+///     });
+///
+///     let workers = mediator.spawn_workers();
+///
+///     mediator.send_keyed(&7, Deposit { account: 7, amount: 10 }).await;
+///     mediator.send_keyed(&7, Deposit { account: 7, amount: 20 }).await;
+///     mediator.send_keyed(&9, Deposit { account: 9, amount: 30 }).await;
+///
+///     let mut processed = 0;
+///     for worker in workers {
+///         processed += worker.shutdown().await;
+///     }
+///     assert_eq!(processed, 3);
+/// });
+///
+pub struct ShardedAsyncMediator<K, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    shards: Vec<BasicAsyncMediator<Ev>>,
+    key: PhantomData<fn(&K)>,
+}
+
+impl<K, Ev> ShardedAsyncMediator<K, Ev>
+where
+    K: Hash,
+    Ev: MaybeDebug + Send + Sync + 'static,
+{
+    /// Creates a [`ShardedAsyncMediator`] with the given number of `shards`,
+    /// each built by `build` from its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shards` is zero.
+    pub fn new(shards: usize, build: impl FnMut(usize) -> BasicAsyncMediator<Ev>) -> Self {
+        assert!(shards > 0, "a sharded mediator needs at least one shard");
+        Self {
+            shards: (0..shards).map(build).collect(),
+            key: PhantomData,
+        }
+    }
+
+    /// Returns the index of the shard the `key` is routed to.
+    ///
+    /// The same key is always routed to the same shard.
+    pub fn shard_of(&self, key: &K) -> usize {
+        let hash = BuildHasherDefault::<DefaultHasher>::default().hash_one(key);
+        (hash % self.shards.len() as u64) as usize
+    }
+
+    /// Returns the shard the `key` is routed to.
+    pub fn shard(&self, key: &K) -> &BasicAsyncMediator<Ev> {
+        &self.shards[self.shard_of(key)]
+    }
+
+    /// Returns all shards, in the order they were built.
+    pub fn shards(&self) -> &[BasicAsyncMediator<Ev>] {
+        &self.shards
+    }
+
+    /// Send a request of type `Req` to the shard of the `key`
+    /// and wait until the shard handled it.
+    ///
+    /// The request is queued like with [`BasicAsyncMediator::send_with_priority()`],
+    /// so it is handled after all requests sent for the same key before,
+    /// by whoever runs the shard, e.g. [`ShardedAsyncMediator::spawn_workers()`].
+    /// Without a running shard, this waits forever.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn send_keyed<Req>(&self, key: &K, req: Req)
+    where
+        BasicAsyncMediator<Ev>: AsyncRequestHandler<Req, Ev>,
+        Req: Send + 'static,
+    {
+        self.shard(key)
+            .send_with_priority(req, Priority::Normal)
+            .await
+    }
+
+    /// Publishes an event `Ev` to the shard of the `key`.
+    ///
+    /// The event is processed after all events published for the same key before.
+    ///
+    /// See [`BasicAsyncMediator::publish()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn publish_keyed(&self, key: &K, event: Ev) {
+        self.shard(key).publish(event).await
+    }
+
+    /// Runs all shards concurrently until the `shutdown` token is cancelled.
+    ///
+    /// Returns the number of events processed by all shards.
+    ///
+    /// See [`BasicAsyncMediator::run()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn run(&self, shutdown: &CancellationToken) -> usize {
+        let shards = self.shards.iter().map(|shard| shard.run(shutdown));
+        join_limited(shards, self.shards.len())
+            .await
+            .into_iter()
+            .sum()
+    }
+
+    /// Spawns a [`Worker`] for every shard, which runs the shard on its own background task.
+    ///
+    /// The workers are returned in the order of the shards.
+    ///
+    /// See [`BasicAsyncMediator::spawn_worker()`] for more info.
+    ///
+    pub fn spawn_workers(&self) -> Vec<Worker> {
+        self.shards
+            .iter()
+            .map(|shard| {
+                Arc::new(BasicAsyncMediator {
+                    inner: shard.inner.clone(),
+                })
+                .spawn_worker()
+            })
+            .collect()
+    }
+}
+
+impl<K, Ev> Debug for ShardedAsyncMediator<K, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShardedAsyncMediator")
+            .field("shards", &self.shards.len())
+            .finish_non_exhaustive()
+    }
+}
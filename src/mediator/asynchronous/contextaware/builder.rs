@@ -1,17 +1,99 @@
-use async_std::sync::Mutex;
+use event_listener::Event;
 
 use crate::mediator::{
     asynchronous::{
-        basic::basic::BasicAsyncMediator,
+        basic::{
+            basic::{BasicAsyncMediator, Inner},
+            interface::{
+                AsyncBehaviorMediatorBuilderInterface, AsyncConcurrencyMediatorBuilderInterface,
+                AsyncDebounceBuilderInterface, AsyncFilterMediatorBuilderInterface,
+                AsyncMediatorBuilderInterface, AsyncMediatorInternalShare,
+                AsyncNamedListenerBuilderInterface, AsyncOrderingMediatorBuilderInterface,
+                AsyncPriorityMediatorBuilderInterface, AsyncRetryMediatorBuilderInterface,
+                AsyncSharedListenerBuilderInterface, AsyncSpawnedListenerBuilderInterface,
+                AsyncTickerBuilderInterface, AsyncTopicMediatorBuilderInterface,
+            },
+        },
         contextaware::{
-            contextaware::CxAwareAsyncMediator, interface::CxAwareMediatorBuilderInterface,
+            context::{CxFactory, LazyContext},
+            contextaware::CxAwareAsyncMediator,
+            contexts::{ContextTuple, Contexts},
+            handle::CxAwareMediatorHandle,
+            interface::{
+                CxAwareAsyncClosureHandlerBuilderInterface, CxAwareAsyncContextsBuilderInterface,
+                CxAwareAsyncDynamicHandlerBuilderInterface,
+                CxAwareAsyncLazyContextBuilderInterface,
+                CxAwareAsyncReadWriteContextBuilderInterface,
+                CxAwareAsyncSharedContextBuilderInterface,
+            },
+        },
+    },
+    behavior::AsyncBehavior,
+    builder::{validate_strict, BuildError, TryBuilderFlow, TryBuilderInternal},
+    channel::{ChannelFlavor, EventChannel, OverflowPolicy},
+    correlation::{Enveloped, EventMeta},
+    debounce::Debouncer,
+    dependencies::Dependencies,
+    lifecycle::{CxLifecycle, Lifecycle},
+    listener::{
+        recover, variant_filter, AsyncListener, AsyncSharedListener, ExclusiveListener, Filter,
+        FilteredAsyncListener, Listener, ListenerRegistry, MaybeDebug, NotificationHandler,
+        PublishInterceptor, SharedAsyncListener, SharedListener,
+    },
+    observer::{MediatorObserver, RequestMeta},
+    ordering::{Ordering, Sequencer},
+    profile::Profile,
+    queue::RequestQueue,
+    ratelimit::{RateLimit, RateLimiter},
+    registry::{erase_async, AsyncDynHandler, AsyncFanOutHandler, BoxFuture, HandlerRegistry},
+    replay::ReplayBuffer,
+    retry::{Retry, RetryExhausted, RetryPolicy},
+    scheduler::Scheduler,
+    spawned::Spawned,
+    synchronous::{
+        basic::{
+            basic::BasicMediator,
+            interface::{
+                BasicMediatorBuilderInterface, CatchAllMediatorBuilderInterface,
+                ChannelBackendBuilderInterface, ChannelMediatorBuilderInterface,
+                ConvertingMediatorBuilderInterface, DeadLetterMediatorBuilderInterface,
+                EnvelopedMediatorBuilderInterface, FilterMediatorBuilderInterface,
+                HandlerMediatorBuilderInterface, InterceptorBuilderInterface,
+                ListenerIsolationBuilderInterface, NamedListenerBuilderInterface,
+                ObserverMediatorBuilderInterface, PriorityMediatorBuilderInterface,
+                ProfileBuilderInterface, RateLimitBuilderInterface, ReplayMediatorBuilderInterface,
+                RequestEventsBuilderInterface, RequestHookBuilderInterface,
+                SharedListenerBuilderInterface, TopicMediatorBuilderInterface,
+                VariantMediatorBuilderInterface,
+            },
+        },
+        contextaware::interface::{
+            CxAwareDependencyBuilderInterface, CxAwareLifecycleBuilderInterface,
+            CxAwareMediatorBuilderInterface, StrictBuilderInterface,
         },
     },
-    builder::{TryBuilderFlow, TryBuilderInternal},
-    listener::Listener,
-    synchronous::basic::{basic::BasicMediator, interface::BasicMediatorBuilderInterface},
+    system::{RequestEvent, SystemEvent},
+    ticker::Ticker,
+    topics::TopicPattern,
 };
-use std::{fmt::Debug, sync::mpsc::channel};
+use std::{any::type_name_of_val, future::Future, sync::Arc, time::Duration};
+
+#[cfg(feature = "tracing")]
+use crate::mediator::{
+    logging::LoggingListener, synchronous::basic::interface::LoggingMediatorBuilderInterface,
+};
+
+#[cfg(feature = "persistence")]
+use crate::mediator::synchronous::basic::interface::UpcasterBuilderInterface;
+#[cfg(feature = "persistence")]
+use serde_json::Value;
+
+#[cfg(feature = "causality")]
+use crate::mediator::{
+    causality::CausalityRecorder, synchronous::basic::interface::CausalityBuilderInterface,
+};
+#[cfg(feature = "causality")]
+use core::fmt::Debug;
 
 /// The [`CxAwareAsyncBuilder`] helps you to create a [`CxAwareAsyncMediator`].
 ///
@@ -25,36 +107,61 @@ use std::{fmt::Debug, sync::mpsc::channel};
 ///
 pub struct CxAwareAsyncBuilder<Cx, Ev>
 where
-    Cx: Debug,
-    Ev: Debug + 'static,
+    Ev: MaybeDebug + 'static,
 {
     mediator: BasicMediator<Ev>,
+    async_listener: ListenerRegistry<Arc<dyn AsyncListener<Ev>>>,
+    async_shared_listener: ListenerRegistry<Arc<dyn AsyncListener<Arc<Ev>>>>,
+    behavior: Vec<Box<dyn AsyncBehavior>>,
+    retry: Retry<Ev>,
+    tickers: Vec<Ticker<Ev>>,
+    handlers: HandlerRegistry<AsyncDynHandler>,
+    concurrency: usize,
+    ordering: Ordering,
     cx: Option<Cx>,
+    cx_factory: Option<CxFactory<Cx>>,
+    cx_shared: Option<Arc<Cx>>,
+    read_write: bool,
+    lifecycle: Option<Lifecycle<Cx>>,
+    strict: bool,
+    error: Option<BuildError>,
 }
 
 impl<Cx, Ev> TryBuilderInternal<CxAwareAsyncMediator<Cx, Ev>, CxAwareAsyncBuilder<Cx, Ev>>
     for CxAwareAsyncMediator<Cx, Ev>
 where
-    Cx: Debug,
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Creates a [`CxAwareAsyncBuilder`] with the goal of producing a [`CxAwareAsyncMediator`].
     ///
     fn builder() -> CxAwareAsyncBuilder<Cx, Ev> {
         CxAwareAsyncBuilder::<Cx, Ev> {
-            mediator: BasicMediator::<Ev> {
-                channel: channel(),
-                listener: vec![],
+            mediator: BasicMediator {
+                channel: EventChannel::asynchronous(),
+                ..BasicMediator::new()
             },
+            async_listener: ListenerRegistry::default(),
+            async_shared_listener: ListenerRegistry::default(),
+            behavior: vec![],
+            retry: Retry::default(),
+            tickers: vec![],
+            handlers: HandlerRegistry::default(),
+            concurrency: 1,
+            ordering: Ordering::default(),
             cx: None,
+            cx_factory: None,
+            cx_shared: None,
+            read_write: false,
+            lifecycle: None,
+            strict: false,
+            error: None,
         }
     }
 }
 
 impl<M, Cx, Ev> BasicMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
 where
-    Cx: Debug,
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Adds a user-defined listener to the [`CxAwareAsyncBuilder`].
     ///
@@ -63,18 +170,43 @@ where
     ///
     /// Also it must be a `Fn(Ev)` with a return type of `()`
     /// where `Ev` is the user-defined event type
-    /// that must be [`Debug`].
+    /// that must be [`MaybeDebug`].
+    ///
+    fn add_listener(self, f: impl Listener<Ev>) -> Self {
+        self.mediator
+            .listener
+            .add(type_name_of_val(&f), ExclusiveListener::new(f));
+        self
+    }
+}
+
+impl<M, Cx, Ev> NamedListenerBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener with a `name` to the [`CxAwareAsyncBuilder`].
+    ///
+    fn add_listener_named(self, name: &'static str, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add(name, ExclusiveListener::new(f));
+        self
+    }
+}
+
+impl<M, Cx, Ev> AsyncNamedListenerBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined async listener with a `name` to the [`CxAwareAsyncBuilder`].
     ///
-    fn add_listener(mut self, f: impl Listener<Ev>) -> Self {
-        self.mediator.listener.push(Box::new(f));
+    fn add_async_listener_named(self, name: &'static str, f: impl AsyncListener<Ev>) -> Self {
+        self.async_listener.add(name, Arc::new(f));
         self
     }
 }
 
 impl<M, Cx, Ev> CxAwareMediatorBuilderInterface<M, Cx, Ev> for CxAwareAsyncBuilder<Cx, Ev>
 where
-    Cx: Debug,
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Adds a user-defined context of type `Cx` to the [`CxAwareAsyncBuilder`].
     ///
@@ -82,117 +214,1851 @@ where
     ///
     fn add_context(mut self, cx: Cx) -> Self
     where
-        Ev: Debug,
+        Ev: MaybeDebug,
     {
         self.cx = Some(cx);
+        self.cx_factory = None;
+        self.cx_shared = None;
         self
     }
 }
 
-impl<Cx, Ev> CxAwareAsyncBuilder<Cx, Ev>
+impl<M, Cx, Ev> CxAwareAsyncLazyContextBuilderInterface<M, Cx> for CxAwareAsyncBuilder<Cx, Ev>
 where
-    Cx: Debug,
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
-    /// Adds a user-defined listener to the [`CxAwareAsyncBuilder`].
+    /// Adds a factory creating the context `Cx` of the [`CxAwareAsyncBuilder`] on first use.
     ///
-    /// The supplied type must be a [`Listener`].
-    /// As such, it must implement [`Send`] and `Fn(Ev)`,
-    /// besides being `'static`.
+    fn add_context_with<F, Fut>(mut self, f: F) -> Self
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Cx> + Send + 'static,
+    {
+        self.cx = None;
+        self.cx_factory = Some(Box::new(move || Box::pin(f())));
+        self.cx_shared = None;
+        self
+    }
+}
+
+impl<M, Cx, Ev> CxAwareAsyncSharedContextBuilderInterface<M, Cx> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds an immutable context `Cx`, shared without locking, to the [`CxAwareAsyncBuilder`].
     ///
-    /// As a side note, here, `Ev` is the user-defined event type
-    /// that must be [`Debug`].
+    fn add_shared_context(mut self, cx: Arc<Cx>) -> Self {
+        self.cx = None;
+        self.cx_factory = None;
+        self.cx_shared = Some(cx);
+        self
+    }
+}
+
+impl<M, T, Ev> CxAwareAsyncContextsBuilderInterface<M, T> for CxAwareAsyncBuilder<Contexts<T>, Ev>
+where
+    T: ContextTuple,
+    Ev: MaybeDebug,
+{
+    /// Adds the tuple of contexts `T`, each locked independently, to the [`CxAwareAsyncBuilder`].
     ///
-    /// Note: The following example will add a [`Listener`] to the builder,
-    /// but the result of `.build()` here will be an `Err` value.
-    /// This is because in order to receive a valid [`CxAwareAsyncMediator`]
-    /// you need to add a context. See [`CxAwareAsyncBuilder::add_context()`].
+    fn add_contexts(self, cx: T) -> Self {
+        <Self as CxAwareAsyncSharedContextBuilderInterface<M, Contexts<T>>>::add_shared_context(
+            self,
+            Arc::new(Contexts::new(cx)),
+        )
+    }
+}
+
+impl<M, Cx, Ev> CxAwareAsyncReadWriteContextBuilderInterface<M> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Lets requests that only read the context `Cx` share it.
     ///
-    /// # Examples
+    fn read_write_context(mut self) -> Self {
+        self.read_write = true;
+        self
+    }
+}
+
+impl<M, Cx, Ev> CxAwareLifecycleBuilderInterface<M, Cx> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Calls the [`CxLifecycle`] hooks of the context.
     ///
-    /// Basic usage:
+    fn with_lifecycle(mut self) -> Self
+    where
+        Cx: CxLifecycle,
+    {
+        self.lifecycle = Some(Lifecycle::new());
+        self
+    }
+}
+
+impl<M, Cx, Ev> AsyncMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined async listener to the [`CxAwareAsyncBuilder`].
     ///
-    /// ```
-    /// use mediatrix::asynchronous::contextaware::*;
-    /// use std::sync::Arc;
+    /// To be able to supply a type that implements [`AsyncListener`],
+    /// it must satisfy [`Send`], [`Sync`] and `'static` bounds.
     ///
-    /// #[derive(Debug)]
-    /// enum MyEvent {
-    ///     One,
-    ///     Two
-    /// }
+    fn add_async_listener(self, f: impl AsyncListener<Ev>) -> Self {
+        self.async_listener.add(type_name_of_val(&f), Arc::new(f));
+        self
+    }
+}
+
+impl<M, Cx, Ev> AsyncDebounceBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a debounced async listener to the [`CxAwareAsyncBuilder`].
     ///
-    /// #[derive(Debug, Default)]
-    /// struct MyContext(Arc<u32>);
+    fn add_debounced_listener<K>(
+        self,
+        window: Duration,
+        key_fn: impl Fn(&Ev) -> K + Send + Sync + 'static,
+        f: impl AsyncListener<Ev>,
+    ) -> Self
+    where
+        Ev: MaybeDebug + Clone + Send + Sync,
+        K: Ord + Clone + Send + Sync + 'static,
+    {
+        self.async_listener.add(
+            type_name_of_val(&f),
+            Arc::new(Debouncer::new(window, key_fn, f)),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> AsyncSpawnedListenerBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds an async listener running on its own spawned task to the [`CxAwareAsyncBuilder`].
     ///
-    /// let mediator = CxAwareAsyncMediator::<MyContext, MyEvent>::builder()
-    ///     .add_listener(|_: &MyEvent| {
-    ///         /* Your listening logic */
-    ///     })
-    ///     .build();
+    fn add_spawned_listener(self, f: impl AsyncListener<Ev>) -> Self
+    where
+        Ev: MaybeDebug + Clone + Send + Sync,
+    {
+        self.async_listener
+            .add(type_name_of_val(&f), Arc::new(Spawned::new(f)));
+        self
+    }
+}
+
+impl<M, Cx, Ev> AsyncTickerBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a ticker publishing the event generated by `f` every `interval` to the [`CxAwareAsyncBuilder`].
     ///
-    pub fn add_listener(self, f: impl Listener<Ev>) -> Self {
-        <Self as BasicMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_listener(
-            self, f,
-        )
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    ///
+    fn with_ticker(
+        mut self,
+        interval: Duration,
+        f: impl Fn() -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        assert!(
+            !interval.is_zero(),
+            "ticker interval must be greater than 0"
+        );
+        self.tickers.push(Ticker::new(interval, f));
+        self
     }
+}
 
-    /// Adds a user-defined context of type `Cx` to the [`CxAwareAsyncBuilder`].
+impl<M, Cx, Ev> AsyncBehaviorMediatorBuilderInterface<M> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined [`AsyncBehavior`] to the [`CxAwareAsyncBuilder`].
     ///
-    /// The context is available in [`super::CxAwareAsyncRequestHandler::handle()`].
+    /// Behaviors wrap every request sent to the [`CxAwareAsyncMediator`]
+    /// in the order they were added.
     ///
-    /// # Examples
+    fn add_behavior(mut self, b: impl AsyncBehavior) -> Self {
+        self.behavior.push(Box::new(b));
+        self
+    }
+}
+
+impl<M, Cx, Ev> ChannelMediatorBuilderInterface<M> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Bounds the channel of the [`CxAwareAsyncBuilder`] to `capacity` events.
     ///
-    /// Basic usage:
+    /// Building fails with [`BuildError::InvalidCapacity`] if `capacity` is `0`.
     ///
-    /// ```
-    /// use mediatrix::asynchronous::contextaware::*;
-    /// use std::sync::Arc;
+    fn with_capacity(mut self, capacity: usize) -> Self {
+        if capacity == 0 {
+            self.error.get_or_insert(BuildError::InvalidCapacity);
+        } else {
+            self.mediator.channel.set_capacity(Some(capacity));
+        }
+        self
+    }
+
+    /// Sets the [`OverflowPolicy`] of the [`CxAwareAsyncBuilder`].
     ///
-    /// #[derive(Debug)]
-    /// enum MyEvent {
-    ///     One,
-    ///     Two
-    /// }
+    fn with_overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.mediator.channel.policy = policy;
+        self
+    }
+}
+
+impl<M, Cx, Ev> ListenerIsolationBuilderInterface<M> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Enables or disables listener isolation of the [`CxAwareAsyncBuilder`].
     ///
-    /// #[derive(Debug, Default)]
-    /// struct MyContext(Arc<u32>);
+    fn isolate_listeners(mut self, isolate: bool) -> Self {
+        self.mediator.isolate_listeners = isolate;
+        self
+    }
+}
+
+impl<M, Cx, Ev> RateLimitBuilderInterface<M> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the [`RateLimit`] of the [`CxAwareAsyncBuilder`].
     ///
-    /// let mediator = CxAwareAsyncMediator::<MyContext, MyEvent>::builder()
-    ///     .add_context(MyContext::default())
-    ///     .build();
+    fn with_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.mediator.rate_limit = Some(RateLimiter::new(limit));
+        self
+    }
+}
+
+impl<M, Cx, Ev> TopicMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener for a topic to the [`CxAwareAsyncBuilder`].
     ///
-    pub fn add_context(self, cx: Cx) -> Self {
-        <Self as CxAwareMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Cx, Ev>>::add_context(
-            self, cx,
+    /// The listener only receives events published to a topic
+    /// matching the [`TopicPattern`] `topic`.
+    ///
+    fn add_listener_for_topic(self, topic: &str, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add_for_topic(
+            type_name_of_val(&f),
+            Some(TopicPattern::new(topic)),
+            ExclusiveListener::new(f),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> AsyncTopicMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined async listener for a topic to the [`CxAwareAsyncBuilder`].
+    ///
+    /// The listener only receives events published to a topic
+    /// matching the [`TopicPattern`] `topic`.
+    ///
+    fn add_async_listener_for_topic(self, topic: &str, f: impl AsyncListener<Ev>) -> Self {
+        self.async_listener.add_for_topic(
+            type_name_of_val(&f),
+            Some(TopicPattern::new(topic)),
+            Arc::new(f),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> FilterMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener guarded by a [`Filter`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// The listener is only invoked if `filter` returns `true` for an event.
+    ///
+    fn add_filtered_listener(self, filter: impl Filter<Ev>, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| {
+                if filter(ev) {
+                    f(ev)
+                }
+            }),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> AsyncFilterMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug + Sync,
+{
+    /// Adds a user-defined async listener guarded by a [`Filter`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// The listener is only awaited if `filter` returns `true` for an event.
+    ///
+    fn add_filtered_async_listener(
+        self,
+        filter: impl Filter<Ev>,
+        f: impl AsyncListener<Ev>,
+    ) -> Self {
+        self.async_listener.add(
+            type_name_of_val(&f),
+            Arc::new(FilteredAsyncListener {
+                filter,
+                listener: f,
+            }),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> VariantMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener for events with certain variant names to the [`CxAwareAsyncBuilder`].
+    ///
+    fn add_listener_for_variants(
+        self,
+        variants: impl IntoIterator<Item = &'static str>,
+        f: impl Listener<Ev>,
+    ) -> Self
+    where
+        for<'a> &'a Ev: Into<&'static str>,
+    {
+        <Self as FilterMediatorBuilderInterface<M, Ev>>::add_filtered_listener(
+            self,
+            variant_filter(variants),
+            f,
         )
     }
 }
 
-#[derive(Debug)]
-/// Error: No context was given while building.
-pub struct NoCxAvailable;
+impl<M, Cx, Ev> ConvertingMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener for the events convertible into `SubEv` to the [`CxAwareAsyncBuilder`].
+    ///
+    fn add_converting_listener<SubEv>(self, f: impl Fn(SubEv) + Send + Sync + 'static) -> Self
+    where
+        Ev: Clone + TryInto<SubEv>,
+    {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| {
+                if let Ok(ev) = ev.clone().try_into() {
+                    f(ev)
+                }
+            }),
+        );
+        self
+    }
+}
 
-impl<Cx, Ev> TryBuilderFlow<CxAwareAsyncMediator<Cx, Ev>> for CxAwareAsyncBuilder<Cx, Ev>
+impl<M, Cx, Ev> PriorityMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
 where
-    Cx: Debug,
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
-    type Error = NoCxAvailable;
-    /// Builds the [`CxAwareAsyncMediator`] and returns it.
+    /// Adds a user-defined listener with a `priority` to the [`CxAwareAsyncBuilder`].
     ///
-    /// Because [`CxAwareAsyncMediator`] implements [`TryBuilderInternal`],
-    /// which in turn means, that the [`CxAwareAsyncBuilder`] implements [`TryBuilderFlow`]
-    /// this method will return a [`Result<CxAwareAsyncMediator<Cx, Ev>, Self::Error>`] as stated by the return type.
-    /// Note that here `Self::Error` is of type [`NoCxAvailable`], which means that no dependecy was added in
-    /// the process of building.
+    /// Listeners are invoked in descending priority.
     ///
-    fn build(self) -> Result<CxAwareAsyncMediator<Cx, Ev>, Self::Error> {
+    fn add_listener_with_priority(self, priority: i32, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add_with_priority(
+            type_name_of_val(&f),
+            priority,
+            ExclusiveListener::new(f),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> AsyncPriorityMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined async listener with a `priority` to the [`CxAwareAsyncBuilder`].
+    ///
+    /// Async listeners are awaited in descending priority.
+    ///
+    fn add_async_listener_with_priority(self, priority: i32, f: impl AsyncListener<Ev>) -> Self {
+        self.async_listener
+            .add_with_priority(type_name_of_val(&f), priority, Arc::new(f));
+        self
+    }
+}
+
+impl<M, Cx, Ev> HandlerMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined notification handler to the [`CxAwareAsyncBuilder`].
+    ///
+    /// The handler is invoked like any other listener.
+    ///
+    fn add_handler(self, handler: Arc<dyn NotificationHandler<Ev>>) -> Self
+    where
+        Ev: 'static,
+    {
+        self.mediator.listener.add(
+            handler.name(),
+            ExclusiveListener::new(move |ev: &Ev| handler.on_event(ev)),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> ObserverMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a [`MediatorObserver`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// Observers are notified in the order they were added.
+    ///
+    fn with_observer(mut self, observer: impl MediatorObserver<Ev> + 'static) -> Self {
+        self.mediator.observer.add(observer);
+        self
+    }
+}
+
+impl<M, Cx, Ev> RequestHookBuilderInterface<M> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a hook invoked before every request sent to the [`CxAwareAsyncBuilder`].
+    ///
+    fn on_before_send(mut self, f: impl Fn(&RequestMeta) + Send + Sync + 'static) -> Self {
+        self.mediator.observer.before_send(f);
+        self
+    }
+
+    /// Adds a hook invoked after every request sent to the [`CxAwareAsyncBuilder`].
+    ///
+    fn on_after_send(mut self, f: impl Fn(&RequestMeta, Duration) + Send + Sync + 'static) -> Self {
+        self.mediator.observer.after_send(f);
+        self
+    }
+}
+
+#[cfg(feature = "causality")]
+impl<M, Cx, Ev> CausalityBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Records the flows of requests, events and listeners of the [`CxAwareAsyncBuilder`].
+    ///
+    fn record_causality(mut self) -> Self
+    where
+        Ev: Debug,
+    {
+        self.mediator.observer.causality = Some(Arc::new(CausalityRecorder::new()));
+        self
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<M, Cx, Ev> LoggingMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    /// Adds a [`LoggingListener`] to the [`CxAwareAsyncBuilder`].
+    ///
+    fn add_logging_listener(self, listener: impl Into<LoggingListener<Ev>>) -> Self {
+        let listener = listener.into();
+        self.mediator.listener.add(
+            type_name_of_val(&listener),
+            ExclusiveListener::new(move |ev: &Ev| listener.log(&Enveloped::current(ev))),
+        );
+        self
+    }
+}
+
+#[cfg(feature = "persistence")]
+impl<M, Cx, Ev> UpcasterBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds an upcaster for events of the version `from_version` to the [`CxAwareAsyncBuilder`].
+    ///
+    fn register_upcaster(
+        mut self,
+        from_version: u32,
+        f: impl Fn(Value) -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        self.mediator.upcasters.register(from_version, f);
+        self
+    }
+}
+
+impl<M, Cx, Ev> ReplayMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the capacity of the replay buffer of the [`CxAwareAsyncBuilder`].
+    ///
+    /// A capacity of `0` disables the replay buffer.
+    ///
+    fn with_replay_capacity(mut self, capacity: usize) -> Self
+    where
+        Ev: Clone,
+    {
+        self.mediator.replay = (capacity > 0).then(|| ReplayBuffer::new(capacity));
+        self
+    }
+}
+
+impl<M, Cx, Ev> DeadLetterMediatorBuilderInterface<M> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Enables or disables the dead-letter queue of the [`CxAwareAsyncBuilder`].
+    ///
+    fn collect_dead_letters(mut self, collect: bool) -> Self {
+        self.mediator.dead_letters = collect.then(EventChannel::default);
+        self
+    }
+}
+
+impl<M, Cx, Ev> AsyncRetryMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the [`RetryPolicy`] of the [`CxAwareAsyncBuilder`].
+    ///
+    fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry.policy = policy;
+        self
+    }
+
+    /// Sets the function creating the event published once a [`RetryPolicy`] is exhausted.
+    ///
+    fn on_retry_exhausted(
+        mut self,
+        f: impl Fn(&RetryExhausted) -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        self.retry.exhausted = Some(Box::new(f));
+        self
+    }
+}
+
+impl<M, Cx, Ev> SharedListenerBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener receiving the event in an [`Arc`] to the [`CxAwareAsyncBuilder`].
+    ///
+    fn add_shared_listener(mut self, f: impl SharedListener<Ev>) -> Self
+    where
+        Ev: Clone,
+    {
+        self.mediator.shared_listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Arc<Ev>| f(ev.clone())),
+        );
+        self.mediator.recover = Some(recover::<Ev>);
+        self
+    }
+}
+
+impl<M, Cx, Ev> AsyncSharedListenerBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Adds a user-defined async listener receiving the event in an [`Arc`] to the [`CxAwareAsyncBuilder`].
+    ///
+    fn add_async_shared_listener(mut self, f: impl AsyncSharedListener<Ev>) -> Self
+    where
+        Ev: Clone,
+    {
+        self.async_shared_listener
+            .add(type_name_of_val(&f), Arc::new(SharedAsyncListener(f)));
+        self.mediator.recover = Some(recover::<Ev>);
+        self
+    }
+}
+
+impl<M, Cx, Ev> EnvelopedMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener receiving the event as [`Enveloped`] to the [`CxAwareAsyncBuilder`].
+    ///
+    fn add_enveloped_listener(
+        self,
+        f: impl Fn(&Enveloped<'_, Ev>) + Send + Sync + 'static,
+    ) -> Self {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| f(&Enveloped::current(ev))),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> CatchAllMediatorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener receiving every event along with its [`EventMeta`] to the [`CxAwareAsyncBuilder`].
+    ///
+    fn add_catch_all_listener(
+        mut self,
+        f: impl Fn(&Ev, &EventMeta) + Send + Sync + 'static,
+    ) -> Self {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| f(ev, &EventMeta::current())),
+        );
+        self.mediator.catch_all = true;
+        self
+    }
+}
+
+impl<M, Cx, Ev> InterceptorBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined [`PublishInterceptor`] to the [`CxAwareAsyncBuilder`].
+    ///
+    fn add_publish_interceptor(mut self, f: impl PublishInterceptor<Ev>) -> Self {
+        self.mediator.interceptors.push(Box::new(f));
+        self
+    }
+}
+
+impl<M, Cx, Ev> RequestEventsBuilderInterface<M, SystemEvent<Ev>>
+    for CxAwareAsyncBuilder<Cx, SystemEvent<Ev>>
+where
+    Ev: MaybeDebug,
+{
+    /// Enables or disables the request events of the [`CxAwareAsyncBuilder`].
+    ///
+    fn with_request_events(mut self, enabled: bool) -> Self {
+        self.mediator.request_events = enabled.then_some(SystemEvent::request as RequestEvent<_>);
+        self
+    }
+}
+
+impl<Cx, Ev> CxAwareAsyncDynamicHandlerBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Cx, Ev>
+    for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Cx: Send + Sync + 'static,
+    Ev: MaybeDebug + Send + Sync + 'static,
+{
+    /// Registers a handler for requests of type `Req` with the [`CxAwareAsyncBuilder`].
+    ///
+    fn register_handler<Req, Fut>(
+        mut self,
+        handler: impl Fn(CxAwareMediatorHandle<Cx, Ev>, Req, Arc<LazyContext<Cx>>) -> Fut
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handler = Arc::new(handler);
+        self.handlers.insert::<Req>(erase_async(
+            move |m: &CxAwareAsyncMediator<Cx, Ev>, req: Req| {
+                let handler = handler.clone();
+                Box::pin(m.basic.run_request("send_dyn", req, move |req| {
+                    handler(m.new_handle(), req, m.cx.clone())
+                }))
+            },
+        ));
+        self
+    }
+}
+
+impl<Cx, Ev> CxAwareAsyncClosureHandlerBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Cx, Ev>
+    for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Cx: Send + Sync + 'static,
+    Ev: MaybeDebug + Send + Sync + 'static,
+{
+    /// Adds a closure handling requests of type `Req` to the [`CxAwareAsyncBuilder`].
+    ///
+    fn add_request_handler<Req, Resp, Fut>(
+        mut self,
+        handler: impl Fn(CxAwareMediatorHandle<Cx, Ev>, Req, Arc<LazyContext<Cx>>) -> Fut
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Resp: Send + 'static,
+        Fut: Future<Output = Resp> + Send + 'static,
+    {
+        self.mediator.closures.insert_closure(Box::new(
+            move |m: &CxAwareAsyncMediator<Cx, Ev>, req: Req| -> BoxFuture<Resp> {
+                Box::pin(handler(m.new_handle(), req, m.cx.clone()))
+            },
+        ));
+        self
+    }
+
+    /// Adds a closure handling requests of type `Req` together with others to the [`CxAwareAsyncBuilder`].
+    ///
+    fn add_fan_out_handler<Req, Fut>(
+        mut self,
+        handler: impl Fn(CxAwareMediatorHandle<Cx, Ev>, Arc<Req>, Arc<LazyContext<Cx>>) -> Fut
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self
+    where
+        Req: Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.mediator
+            .fan_out
+            .push_fan_out::<Req, AsyncFanOutHandler<CxAwareAsyncMediator<Cx, Ev>, Req>>(Box::new(
+                move |m: &CxAwareAsyncMediator<Cx, Ev>, req: Arc<Req>| -> BoxFuture<()> {
+                    Box::pin(handler(m.new_handle(), req, m.cx.clone()))
+                },
+            ));
+        self
+    }
+}
+
+impl<M, Cx, Ev> AsyncConcurrencyMediatorBuilderInterface<M> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the number of listeners and events the [`CxAwareAsyncBuilder`] processes concurrently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is `0`.
+    ///
+    fn with_concurrency(mut self, limit: usize) -> Self {
+        assert!(limit > 0, "concurrency limit must be greater than 0");
+        self.concurrency = limit;
+        self
+    }
+}
+
+impl<M, Cx, Ev> AsyncOrderingMediatorBuilderInterface<M> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the [`Ordering`] in which the [`CxAwareAsyncBuilder`] processes events and their listeners.
+    ///
+    fn with_ordering(mut self, ordering: Ordering) -> Self {
+        self.ordering = ordering;
+        self
+    }
+}
+
+impl<M, Cx, Ev> ChannelBackendBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Creates the channel of the [`CxAwareAsyncBuilder`] through the [`ChannelFlavor`] `F`.
+    ///
+    fn with_channel_backend<F: ChannelFlavor>(mut self) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        self.mediator.channel.set_flavor::<F>();
+        self
+    }
+}
+
+impl<M, Cx, Ev> ProfileBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Configures the channel, concurrency and ordering of the [`CxAwareAsyncBuilder`] from the [`Profile`].
+    ///
+    fn with_profile(mut self, profile: Profile) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        profile.configure(&mut self.mediator.channel);
+        self.concurrency = profile.concurrency();
+        self.ordering = profile.ordering();
+        self
+    }
+}
+
+impl<Cx, Ev> CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener to the [`CxAwareAsyncBuilder`].
+    ///
+    /// The supplied type must be a [`Listener`].
+    /// As such, it must implement [`Send`] and `Fn(Ev)`,
+    /// besides being `'static`.
+    ///
+    /// As a side note, here, `Ev` is the user-defined event type
+    /// that must be [`MaybeDebug`].
+    ///
+    /// Note: The following example will add a [`Listener`] to the builder,
+    /// but the result of `.build()` here will be an `Err` value.
+    /// This is because in order to receive a valid [`CxAwareAsyncMediator`]
+    /// you need to add a context. See [`CxAwareAsyncBuilder::add_context()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct MyContext(Arc<u32>);
+    ///
+    /// let mediator = CxAwareAsyncMediator::<MyContext, MyEvent>::builder()
+    ///     .add_listener(|_: &MyEvent| {
+    ///         /* Your listening logic */
+    ///     })
+    ///     .build();
+    ///
+    pub fn add_listener(self, f: impl Listener<Ev>) -> Self {
+        <Self as BasicMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_listener(
+            self, f,
+        )
+    }
+
+    /// Adds a user-defined listener with a `name` to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_named()`] for more info.
+    ///
+    pub fn add_listener_named(self, name: &'static str, f: impl Listener<Ev>) -> Self {
+        <Self as NamedListenerBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_listener_named(self, name, f)
+    }
+
+    /// Adds a user-defined async listener to the [`CxAwareAsyncBuilder`].
+    ///
+    /// The supplied type must be an [`AsyncListener`].
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::add_async_listener()`] for more info.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct MyContext(u32);
+    ///
+    /// let mediator = CxAwareAsyncMediator::<MyContext, MyEvent>::builder()
+    ///     .add_async_listener(|_: &MyEvent| async {
+    ///         /* Your asynchronous listening logic */
+    ///     })
+    ///     .add_context(MyContext::default())
+    ///     .build();
+    ///
+    pub fn add_async_listener(self, f: impl AsyncListener<Ev>) -> Self {
+        <Self as AsyncMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_async_listener(
+            self, f,
+        )
+    }
+
+    /// Adds a user-defined async listener with a `name` to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_named()`] for more info.
+    ///
+    pub fn add_async_listener_named(self, name: &'static str, f: impl AsyncListener<Ev>) -> Self {
+        <Self as AsyncNamedListenerBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_async_listener_named(
+            self, name, f,
+        )
+    }
+
+    /// Adds a debounced async listener to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::add_debounced_listener()`] for more info.
+    ///
+    pub fn add_debounced_listener<K>(
+        self,
+        window: Duration,
+        key_fn: impl Fn(&Ev) -> K + Send + Sync + 'static,
+        f: impl AsyncListener<Ev>,
+    ) -> Self
+    where
+        Ev: Clone + Send + Sync,
+        K: Ord + Clone + Send + Sync + 'static,
+    {
+        <Self as AsyncDebounceBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_debounced_listener(
+            self, window, key_fn, f,
+        )
+    }
+
+    /// Adds an async listener running on its own spawned task to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::add_spawned_listener()`] for more info.
+    ///
+    pub fn add_spawned_listener(self, f: impl AsyncListener<Ev>) -> Self
+    where
+        Ev: Clone + Send + Sync,
+    {
+        <Self as AsyncSpawnedListenerBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_spawned_listener(
+            self, f,
+        )
+    }
+
+    /// Adds a ticker publishing the event generated by `f` every `interval` to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::with_ticker()`] for more info.
+    ///
+    pub fn with_ticker(
+        self,
+        interval: Duration,
+        f: impl Fn() -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        <Self as AsyncTickerBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::with_ticker(
+            self, interval, f,
+        )
+    }
+
+    /// Adds a user-defined context of type `Cx` to the [`CxAwareAsyncBuilder`].
+    ///
+    /// The context is available in [`super::CxAwareAsyncRequestHandler::handle()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct MyContext(Arc<u32>);
+    ///
+    /// let mediator = CxAwareAsyncMediator::<MyContext, MyEvent>::builder()
+    ///     .add_context(MyContext::default())
+    ///     .build();
+    ///
+    pub fn add_context(self, cx: Cx) -> Self {
+        <Self as CxAwareMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Cx, Ev>>::add_context(
+            self, cx,
+        )
+    }
+
+    /// Adds a factory creating the context `Cx` of the [`CxAwareAsyncBuilder`] on first use.
+    ///
+    /// Instead of being created up front, the context, e.g. a database pool or an API client,
+    /// is created by the first request that needs it.
+    /// So building doesn't require the resource to be available yet.
+    /// Concurrent requests wait until the context is created.
+    /// Replaces a context added before.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Connected(u32)
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct Connection(u32);
+    ///
+    /// impl Connection {
+    ///     async fn connect() -> Self {
+    ///         Connection(42)
+    ///     }
+    /// }
+    ///
+    /// struct Request;
+    ///
+    /// #[async_trait]
+    /// impl CxAwareAsyncRequestHandler<Connection, Request, MyEvent> for CxAwareAsyncMediator<Connection, MyEvent> {
+    ///     async fn handle(&self, _req: Request, cx: &Connection) {
+    ///         self.publish(MyEvent::Connected(cx.0)).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = CxAwareAsyncMediator::<Connection, MyEvent>::builder()
+    ///         .add_context_with(|| async { Connection::connect().await })
+    ///         .build()
+    ///         .unwrap();
+    ///
+    ///     mediator.send(Request).await;
+    ///     mediator.next().await.ok();
+    /// });
+    ///
+    pub fn add_context_with<F, Fut>(self, f: F) -> Self
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Cx> + Send + 'static,
+    {
+        <Self as CxAwareAsyncLazyContextBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Cx>>::add_context_with(
+            self, f,
+        )
+    }
+
+    /// Adds an immutable context of type `Cx` to the [`CxAwareAsyncBuilder`],
+    /// which is shared with all requests without locking.
+    ///
+    /// Handlers receive `&Cx` just like with [`CxAwareAsyncBuilder::add_context()`],
+    /// but requests never wait for each other to access the context.
+    /// Because the context can't be mutated, requests sent with
+    /// [`CxAwareAsyncMediator::send_mut()`] and calls to [`LazyContext::lock()`] panic.
+    /// Use [`LazyContext::read()`] to access the context in a handler
+    /// registered with [`CxAwareAsyncBuilder::register_handler()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    /// use async_trait::async_trait;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Greeted(String)
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct Config {
+    ///     greeting: String
+    /// }
+    ///
+    /// struct Greet;
+    ///
+    /// #[async_trait]
+    /// impl CxAwareAsyncRequestHandler<Config, Greet, MyEvent> for CxAwareAsyncMediator<Config, MyEvent> {
+    ///     async fn handle(&self, _req: Greet, cx: &Config) {
+    ///         self.publish(MyEvent::Greeted(cx.greeting.clone())).await;
+    ///     }
+    /// }
+    ///
+    /// let config = Arc::new(Config { greeting: "hello".into() });
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = CxAwareAsyncMediator::<Config, MyEvent>::builder()
+    ///         .add_shared_context(config.clone())
+    ///         .build()
+    ///         .unwrap();
+    ///
+    ///     mediator.send(Greet).await;
+    ///     mediator.next().await.ok();
+    /// });
+    ///
+    pub fn add_shared_context(self, cx: Arc<Cx>) -> Self {
+        <Self as CxAwareAsyncSharedContextBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Cx>>::add_shared_context(self, cx)
+    }
+
+    /// Lets requests that only read the context `Cx` share it.
+    ///
+    /// By default, every request locks the context exclusively,
+    /// so requests are handled one after another, even if their handlers only read it.
+    /// With this option, requests sent with `send()`, `send_and_receive()`,
+    /// `send_with_scope()` and `try_send()` lock the context for reading only
+    /// and may be handled concurrently.
+    /// Requests that mutate the context are sent with
+    /// [`CxAwareAsyncMediator::send_mut()`], which still locks it exclusively.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Looked(u32)
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct Cache(u32);
+    ///
+    /// struct Lookup;
+    ///
+    /// #[async_trait]
+    /// impl CxAwareAsyncRequestHandler<Cache, Lookup, MyEvent> for CxAwareAsyncMediator<Cache, MyEvent> {
+    ///     async fn handle(&self, _req: Lookup, cx: &Cache) {
+    ///         self.publish(MyEvent::Looked(cx.0)).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = CxAwareAsyncMediator::<Cache, MyEvent>::builder()
+    ///         .add_context(Cache(42))
+    ///         .read_write_context()
+    ///         .build()
+    ///         .unwrap();
+    ///
+    ///     mediator.send(Lookup).await;
+    ///     mediator.next().await.ok();
+    /// });
+    ///
+    pub fn read_write_context(self) -> Self {
+        <Self as CxAwareAsyncReadWriteContextBuilderInterface<CxAwareAsyncMediator<Cx, Ev>>>::read_write_context(self)
+    }
+
+    /// Makes the [`CxAwareAsyncBuilder`] strict.
+    ///
+    /// Synchronous and asynchronous listeners are validated together.
+    /// See [`crate::synchronous::contextaware::CxAwareBuilder::strict()`] for more info.
+    ///
+    pub fn strict(self) -> Self {
+        <Self as StrictBuilderInterface<CxAwareAsyncMediator<Cx, Ev>>>::strict(self)
+    }
+
+    /// Calls the [`CxLifecycle`] hooks of the context.
+    ///
+    /// [`CxLifecycle::on_start()`] is called when the [`CxAwareAsyncMediator`] is built
+    /// or, if the context is added with `add_context_with()`, once it was created.
+    /// [`CxLifecycle::on_shutdown()`] is called by [`CxAwareAsyncMediator::shutdown()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One
+    /// }
+    ///
+    /// struct Pool;
+    ///
+    /// #[async_trait]
+    /// impl CxLifecycle for Pool {
+    ///     fn on_start(&self) {
+    ///         /* Open the connections */
+    ///     }
+    ///
+    ///     async fn on_shutdown(&self) {
+    ///         /* Close the connections */
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = CxAwareAsyncMediator::<Pool, MyEvent>::builder()
+    ///         .add_context_with(|| async { Pool })
+    ///         .with_lifecycle()
+    ///         .build()
+    ///         .unwrap();
+    ///
+    ///     mediator.shutdown().await;
+    /// });
+    ///
+    pub fn with_lifecycle(self) -> Self
+    where
+        Cx: CxLifecycle,
+    {
+        <Self as CxAwareLifecycleBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Cx>>::with_lifecycle(
+            self,
+        )
+    }
+
+    /// Adds a user-defined [`AsyncBehavior`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// Behaviors wrap every request sent to the mediator
+    /// in the order they were added.
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::add_behavior()`] for more info.
+    ///
+    pub fn add_behavior(self, b: impl AsyncBehavior) -> Self {
+        <Self as AsyncBehaviorMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>>>::add_behavior(
+            self, b,
+        )
+    }
+    /// Bounds the channel of the [`CxAwareAsyncBuilder`] to `capacity` events.
+    ///
+    /// If the channel is full and the [`OverflowPolicy`] is [`OverflowPolicy::Block`],
+    /// publishing waits until an event was processed.
+    /// See [`crate::synchronous::basic::BasicBuilder::with_capacity()`] for more info.
+    ///
+    /// # Errors
+    ///
+    /// Building fails with [`BuildError::InvalidCapacity`] if `capacity` is `0`.
+    ///
+    pub fn with_capacity(self, capacity: usize) -> Self {
+        <Self as ChannelMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>>>::with_capacity(
+            self, capacity,
+        )
+    }
+
+    /// Sets the [`OverflowPolicy`] of the [`CxAwareAsyncBuilder`].
+    ///
+    /// The policy only takes effect if the channel was bounded
+    /// with [`CxAwareAsyncBuilder::with_capacity()`].
+    ///
+    pub fn with_overflow_policy(self, policy: OverflowPolicy) -> Self {
+        <Self as ChannelMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>>>::with_overflow_policy(self, policy)
+    }
+    /// Enables or disables listener isolation of the [`CxAwareAsyncBuilder`].
+    ///
+    /// This also applies to every [`AsyncListener`].
+    /// See [`crate::synchronous::basic::BasicBuilder::isolate_listeners()`] for more info.
+    ///
+    pub fn isolate_listeners(self, isolate: bool) -> Self {
+        <Self as ListenerIsolationBuilderInterface<CxAwareAsyncMediator<Cx, Ev>>>::isolate_listeners(
+            self, isolate,
+        )
+    }
+    /// Sets the [`RateLimit`] of the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_rate_limit()`] for more info.
+    ///
+    pub fn with_rate_limit(self, limit: RateLimit) -> Self {
+        <Self as RateLimitBuilderInterface<CxAwareAsyncMediator<Cx, Ev>>>::with_rate_limit(
+            self, limit,
+        )
+    }
+    /// Adds a user-defined listener for a topic to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_for_topic()`] for more info.
+    ///
+    pub fn add_listener_for_topic(self, topic: &str, f: impl Listener<Ev>) -> Self {
+        <Self as TopicMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_listener_for_topic(self, topic, f)
+    }
+
+    /// Adds a user-defined async listener for a topic to the [`CxAwareAsyncBuilder`].
+    ///
+    /// The listener only receives events published to a topic
+    /// matching the [`TopicPattern`] `topic`.
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_for_topic()`] for more info.
+    ///
+    pub fn add_async_listener_for_topic(self, topic: &str, f: impl AsyncListener<Ev>) -> Self {
+        <Self as AsyncTopicMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_async_listener_for_topic(
+            self, topic, f,
+        )
+    }
+
+    /// Adds a user-defined listener guarded by a [`Filter`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_filtered_listener()`] for more info.
+    ///
+    pub fn add_filtered_listener(self, filter: impl Filter<Ev>, f: impl Listener<Ev>) -> Self {
+        <Self as FilterMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_filtered_listener(self, filter, f)
+    }
+
+    /// Adds a user-defined listener for events with certain variant names to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_for_variants()`] for more info.
+    ///
+    pub fn add_listener_for_variants(
+        self,
+        variants: impl IntoIterator<Item = &'static str>,
+        f: impl Listener<Ev>,
+    ) -> Self
+    where
+        for<'a> &'a Ev: Into<&'static str>,
+    {
+        <Self as VariantMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_listener_for_variants(
+            self, variants, f,
+        )
+    }
+
+    /// Adds a user-defined listener for the events convertible into `SubEv` to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_converting_listener()`] for more info.
+    ///
+    pub fn add_converting_listener<SubEv>(self, f: impl Fn(SubEv) + Send + Sync + 'static) -> Self
+    where
+        Ev: Clone + TryInto<SubEv>,
+    {
+        <Self as ConvertingMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_converting_listener(self, f)
+    }
+
+    /// Adds a user-defined async listener guarded by a [`Filter`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// The listener is only awaited if `filter` returns `true` for an event.
+    /// See [`crate::synchronous::basic::BasicBuilder::add_filtered_listener()`] for more info.
+    ///
+    pub fn add_filtered_async_listener(
+        self,
+        filter: impl Filter<Ev>,
+        f: impl AsyncListener<Ev>,
+    ) -> Self
+    where
+        Ev: Sync,
+    {
+        <Self as AsyncFilterMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_filtered_async_listener(
+            self, filter, f,
+        )
+    }
+
+    /// Adds a user-defined listener with a `priority` to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_with_priority()`] for more info.
+    ///
+    pub fn add_listener_with_priority(self, priority: i32, f: impl Listener<Ev>) -> Self {
+        <Self as PriorityMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_listener_with_priority(
+            self, priority, f,
+        )
+    }
+
+    /// Adds a user-defined async listener with a `priority` to the [`CxAwareAsyncBuilder`].
+    ///
+    /// Async listeners are awaited in descending priority,
+    /// after all synchronous listeners were invoked.
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_with_priority()`] for more info.
+    ///
+    pub fn add_async_listener_with_priority(
+        self,
+        priority: i32,
+        f: impl AsyncListener<Ev>,
+    ) -> Self {
+        <Self as AsyncPriorityMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_async_listener_with_priority(
+            self, priority, f,
+        )
+    }
+
+    /// Adds a user-defined notification handler to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_handler()`] for more info.
+    ///
+    pub fn add_handler(self, handler: Arc<dyn NotificationHandler<Ev>>) -> Self
+    where
+        Ev: 'static,
+    {
+        <Self as HandlerMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_handler(
+            self, handler,
+        )
+    }
+
+    /// Adds a [`MediatorObserver`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_observer()`] for more info.
+    ///
+    pub fn with_observer(self, observer: impl MediatorObserver<Ev> + 'static) -> Self {
+        <Self as ObserverMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::with_observer(
+            self, observer,
+        )
+    }
+
+    /// Adds a hook invoked before every request sent to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::on_before_send()`] for more info.
+    ///
+    pub fn on_before_send(self, f: impl Fn(&RequestMeta) + Send + Sync + 'static) -> Self {
+        <Self as RequestHookBuilderInterface<CxAwareAsyncMediator<Cx, Ev>>>::on_before_send(self, f)
+    }
+
+    /// Adds a hook invoked after every request sent to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::on_after_send()`] for more info.
+    ///
+    pub fn on_after_send(self, f: impl Fn(&RequestMeta, Duration) + Send + Sync + 'static) -> Self {
+        <Self as RequestHookBuilderInterface<CxAwareAsyncMediator<Cx, Ev>>>::on_after_send(self, f)
+    }
+
+    /// Records the flows of requests, events and listeners of the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::record_causality()`] for more info.
+    ///
+    #[cfg(feature = "causality")]
+    pub fn record_causality(self) -> Self
+    where
+        Ev: Debug,
+    {
+        <Self as CausalityBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::record_causality(
+            self,
+        )
+    }
+
+    /// Sets the capacity of the replay buffer of the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_replay_capacity()`] for more info.
+    ///
+    pub fn with_replay_capacity(self, capacity: usize) -> Self
+    where
+        Ev: Clone,
+    {
+        <Self as ReplayMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::with_replay_capacity(self, capacity)
+    }
+
+    /// Enables or disables the dead-letter queue of the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::collect_dead_letters()`] for more info.
+    ///
+    pub fn collect_dead_letters(self, collect: bool) -> Self {
+        <Self as DeadLetterMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>>>::collect_dead_letters(self, collect)
+    }
+
+    /// Sets the [`RetryPolicy`] of the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::with_retry_policy()`] for more info.
+    ///
+    pub fn with_retry_policy(self, policy: RetryPolicy) -> Self {
+        <Self as AsyncRetryMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::with_retry_policy(
+            self, policy,
+        )
+    }
+
+    /// Sets the function creating the event published once a [`RetryPolicy`] is exhausted.
+    ///
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::on_retry_exhausted()`] for more info.
+    ///
+    pub fn on_retry_exhausted(
+        self,
+        f: impl Fn(&RetryExhausted) -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        <Self as AsyncRetryMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::on_retry_exhausted(
+            self, f,
+        )
+    }
+
+    /// Adds a user-defined listener receiving the event as [`Enveloped`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_enveloped_listener()`] for more info.
+    ///
+    pub fn add_enveloped_listener(
+        self,
+        f: impl Fn(&Enveloped<'_, Ev>) + Send + Sync + 'static,
+    ) -> Self {
+        <Self as EnvelopedMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_enveloped_listener(
+            self, f,
+        )
+    }
+
+    /// Adds a user-defined listener receiving every event along with its [`EventMeta`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_catch_all_listener()`] for more info.
+    ///
+    pub fn add_catch_all_listener(
+        self,
+        f: impl Fn(&Ev, &EventMeta) + Send + Sync + 'static,
+    ) -> Self {
+        <Self as CatchAllMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_catch_all_listener(self, f)
+    }
+
+    /// Adds a user-defined [`PublishInterceptor`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_publish_interceptor()`] for more info.
+    ///
+    pub fn add_publish_interceptor(self, f: impl PublishInterceptor<Ev>) -> Self {
+        <Self as InterceptorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_publish_interceptor(self, f)
+    }
+
+    /// Adds a [`LoggingListener`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_logging_listener()`] for more info.
+    ///
+    #[cfg(feature = "tracing")]
+    pub fn add_logging_listener(self, listener: impl Into<LoggingListener<Ev>>) -> Self
+    where
+        Ev: 'static,
+    {
+        <Self as LoggingMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_logging_listener(self, listener)
+    }
+
+    /// Adds an upcaster for events of the version `from_version` to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::register_upcaster()`] for more info.
+    ///
+    #[cfg(feature = "persistence")]
+    pub fn register_upcaster(
+        self,
+        from_version: u32,
+        f: impl Fn(Value) -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        <Self as UpcasterBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::register_upcaster(
+            self,
+            from_version,
+            f,
+        )
+    }
+
+    /// Adds a user-defined listener receiving the event in an [`Arc`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_shared_listener()`] for more info.
+    ///
+    pub fn add_shared_listener(self, f: impl SharedListener<Ev>) -> Self
+    where
+        Ev: Clone,
+    {
+        <Self as SharedListenerBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_shared_listener(self, f)
+    }
+
+    /// Adds a user-defined async listener receiving the event in an [`Arc`] to the [`CxAwareAsyncBuilder`].
+    ///
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::add_async_shared_listener()`] for more info.
+    ///
+    pub fn add_async_shared_listener(self, f: impl AsyncSharedListener<Ev>) -> Self
+    where
+        Ev: Clone + Send + Sync,
+    {
+        <Self as AsyncSharedListenerBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::add_async_shared_listener(
+            self, f,
+        )
+    }
+
+    /// Registers an async handler for requests of type `Req` with the [`CxAwareAsyncBuilder`].
+    ///
+    /// Besides a [`CxAwareMediatorHandle`], the handler receives the shared context `Cx`.
+    ///
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::register_handler()`] for more info.
+    ///
+    pub fn register_handler<Req, Fut>(
+        self,
+        handler: impl Fn(CxAwareMediatorHandle<Cx, Ev>, Req, Arc<LazyContext<Cx>>) -> Fut
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self
+    where
+        Cx: Send + Sync + 'static,
+        Ev: Send + Sync,
+        Req: Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        <Self as CxAwareAsyncDynamicHandlerBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Cx, Ev>>::register_handler(
+            self, handler,
+        )
+    }
+
+    /// Adds a closure handling requests of type `Req` to the [`CxAwareAsyncBuilder`].
+    ///
+    /// Besides a [`CxAwareMediatorHandle`], the closure receives the shared context `Cx`.
+    ///
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::add_request_handler()`] for more info.
+    ///
+    pub fn add_request_handler<Req, Resp, Fut>(
+        self,
+        handler: impl Fn(CxAwareMediatorHandle<Cx, Ev>, Req, Arc<LazyContext<Cx>>) -> Fut
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self
+    where
+        Cx: Send + Sync + 'static,
+        Ev: Send + Sync,
+        Req: Send + 'static,
+        Resp: Send + 'static,
+        Fut: Future<Output = Resp> + Send + 'static,
+    {
+        <Self as CxAwareAsyncClosureHandlerBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Cx, Ev>>::add_request_handler(
+            self, handler,
+        )
+    }
+
+    /// Adds a closure handling requests of type `Req` together with others to the [`CxAwareAsyncBuilder`].
+    ///
+    /// Besides a [`CxAwareMediatorHandle`] and the shared request, the closure receives the shared context `Cx`.
+    ///
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::add_fan_out_handler()`] for more info.
+    ///
+    pub fn add_fan_out_handler<Req, Fut>(
+        self,
+        handler: impl Fn(CxAwareMediatorHandle<Cx, Ev>, Arc<Req>, Arc<LazyContext<Cx>>) -> Fut
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self
+    where
+        Cx: Send + Sync + 'static,
+        Ev: Send + Sync,
+        Req: Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        <Self as CxAwareAsyncClosureHandlerBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Cx, Ev>>::add_fan_out_handler(
+            self, handler,
+        )
+    }
+
+    /// Sets the number of listeners and events the [`CxAwareAsyncBuilder`] processes concurrently.
+    ///
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::with_concurrency()`] for more info.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is `0`.
+    ///
+    pub fn with_concurrency(self, limit: usize) -> Self {
+        <Self as AsyncConcurrencyMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>>>::with_concurrency(
+            self, limit,
+        )
+    }
+
+    /// Sets the [`Ordering`] in which the [`CxAwareAsyncBuilder`] processes events and their listeners.
+    ///
+    /// See [`crate::asynchronous::basic::BasicAsyncBuilder::with_ordering()`] for more info.
+    ///
+    pub fn with_ordering(self, ordering: Ordering) -> Self {
+        <Self as AsyncOrderingMediatorBuilderInterface<CxAwareAsyncMediator<Cx, Ev>>>::with_ordering(
+            self, ordering,
+        )
+    }
+
+    /// Creates the channel of the [`CxAwareAsyncBuilder`] through the [`ChannelFlavor`] `F`.
+    ///
+    /// By default, the channel is one of `async-std`, see [`crate::channel::AsyncChannel`].
+    /// See [`crate::synchronous::basic::BasicBuilder::with_channel_backend()`] for more info.
+    ///
+    pub fn with_channel_backend<F: ChannelFlavor>(self) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        <Self as ChannelBackendBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::with_channel_backend::<F>(self)
+    }
+
+    /// Configures the channel, concurrency and ordering of the [`CxAwareAsyncBuilder`] from the [`Profile`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_profile()`] for more info.
+    ///
+    pub fn with_profile(self, profile: Profile) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        <Self as ProfileBuilderInterface<CxAwareAsyncMediator<Cx, Ev>, Ev>>::with_profile(
+            self, profile,
+        )
+    }
+}
+
+impl<M, Ev> CxAwareDependencyBuilderInterface<M, Ev> for CxAwareAsyncBuilder<Dependencies, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined dependency of type `T` to the [`CxAwareAsyncBuilder`].
+    ///
+    /// The dependency is available in the [`Dependencies`] context
+    /// of [`super::CxAwareAsyncRequestHandler::handle()`].
+    ///
+    fn add_dependency<T>(mut self, value: T) -> Self
+    where
+        T: Send + Sync + 'static,
+    {
+        self.cx
+            .get_or_insert_with(Dependencies::default)
+            .insert(value);
+        self
+    }
+}
+
+impl<Ev> CxAwareAsyncBuilder<Dependencies, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined dependency of type `T` to the [`CxAwareAsyncBuilder`].
+    ///
+    /// This is only available if the context is of type [`Dependencies`].
+    /// Each dependency is stored by its type, which means that adding
+    /// a dependency of the same type twice replaces the first one.
+    /// Adding a dependency counts as adding a context.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// struct DbPool;
+    /// struct Config { retries: u32 }
+    ///
+    /// let mediator = CxAwareAsyncMediator::<Dependencies, MyEvent>::builder()
+    ///     .add_dependency(DbPool)
+    ///     .add_dependency(Config { retries: 3 })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    pub fn add_dependency<T>(self, value: T) -> Self
+    where
+        T: Send + Sync + 'static,
+    {
+        <Self as CxAwareDependencyBuilderInterface<CxAwareAsyncMediator<Dependencies, Ev>, Ev>>::add_dependency(
+            self, value,
+        )
+    }
+}
+
+impl<T, Ev> CxAwareAsyncBuilder<Contexts<T>, Ev>
+where
+    T: ContextTuple,
+    Ev: MaybeDebug,
+{
+    /// Adds the tuple of contexts `cx`, e.g. `(db, cache, config)`, to the [`CxAwareAsyncBuilder`].
+    ///
+    /// This is only available if the context is of type [`Contexts`].
+    /// Instead of locking the whole tuple for every request,
+    /// each element is locked independently by the handlers using it,
+    /// so requests using different elements don't contend on a single lock.
+    /// Replaces a context added before.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyEvent {
+    ///     Saved(u32)
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Db(Vec<u32>);
+    /// #[derive(Default)]
+    /// struct Cache(Vec<u32>);
+    /// struct Config { cached: bool }
+    ///
+    /// type Cx = Contexts<(Db, Cache, Config)>;
+    ///
+    /// struct Save(u32);
+    ///
+    /// #[async_trait]
+    /// impl CxAwareAsyncRequestHandler<Cx, Save, MyEvent> for CxAwareAsyncMediator<Cx, MyEvent> {
+    ///     async fn handle(&self, req: Save, cx: &Cx) {
+    ///         let (db, cache, config) = cx.split();
+    ///         db.lock().await.0.push(req.0);
+    ///         if config.read().await.cached {
+    ///             cache.lock().await.0.push(req.0);
+    ///         }
+    ///         self.publish(MyEvent::Saved(req.0)).await;
+    ///     }
+    /// }
+    ///
+    /// let mediator = CxAwareAsyncMediator::<Cx, MyEvent>::builder()
+    ///     .add_contexts((Db::default(), Cache::default(), Config { cached: true }))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// async_std::task::block_on(async {
+    ///     mediator.send(Save(1)).await;
+    ///     assert_eq!(mediator.next_all().await, 1);
+    /// });
+    ///
+    pub fn add_contexts(self, cx: T) -> Self {
+        <Self as CxAwareAsyncContextsBuilderInterface<CxAwareAsyncMediator<Contexts<T>, Ev>, T>>::add_contexts(
+            self, cx,
+        )
+    }
+}
+
+impl<M, Cx, Ev> StrictBuilderInterface<M> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Makes the [`CxAwareAsyncBuilder`] strict.
+    ///
+    fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+}
+
+impl<Cx, Ev> CxAwareAsyncBuilder<Cx, SystemEvent<Ev>>
+where
+    Ev: MaybeDebug,
+{
+    /// Publishes the request events of every request, if `enabled`.
+    ///
+    /// See [`BasicBuilder::with_request_events()`](crate::synchronous::basic::BasicBuilder::with_request_events) for more info.
+    ///
+    pub fn with_request_events(self, enabled: bool) -> Self {
+        <Self as RequestEventsBuilderInterface<
+            CxAwareAsyncMediator<Cx, SystemEvent<Ev>>,
+            SystemEvent<Ev>,
+        >>::with_request_events(self, enabled)
+    }
+}
+
+impl<Cx, Ev> TryBuilderFlow<CxAwareAsyncMediator<Cx, Ev>> for CxAwareAsyncBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    type Error = BuildError;
+    /// Builds the [`CxAwareAsyncMediator`] and returns it.
+    ///
+    /// Because [`CxAwareAsyncMediator`] implements [`TryBuilderInternal`],
+    /// which in turn means, that the [`CxAwareAsyncBuilder`] implements [`TryBuilderFlow`]
+    /// this method will return a [`Result<CxAwareAsyncMediator<Cx, Ev>, Self::Error>`] as stated by the return type.
+    /// Note that here `Self::Error` is a [`BuildError`], e.g. [`BuildError::NoCxAvailable`]
+    /// if no context was added in the process of building.
+    ///
+    fn build(self) -> Result<CxAwareAsyncMediator<Cx, Ev>, Self::Error> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        let cx = match (self.cx, self.cx_factory, self.cx_shared) {
+            (Some(cx), _, _) => LazyContext::ready(cx),
+            (None, Some(init), _) => LazyContext::lazy(init),
+            (None, None, Some(cx)) => LazyContext::immutable(cx),
+            (None, None, None) => return Err(BuildError::NoCxAvailable),
+        };
+        if self.strict {
+            let mut topics = self.mediator.listener.topics();
+            topics.extend(self.async_listener.topics());
+            validate_strict(topics)?;
+        }
+        let published = Arc::new(Event::new());
         Ok(CxAwareAsyncMediator {
             basic: BasicAsyncMediator {
-                basic: Mutex::new(self.mediator),
+                inner: Arc::new(Inner {
+                    observer: self.mediator.observer.clone(),
+                    scheduler: Scheduler::new(published.clone(), self.mediator.observer.clone()),
+                    basic: self.mediator,
+                    async_listener: self.async_listener,
+                    async_shared_listener: self.async_shared_listener,
+                    published,
+                    consumed: Event::new(),
+                    behavior: self.behavior,
+                    retry: self.retry,
+                    tickers: self.tickers,
+                    requests: RequestQueue::default(),
+                    handlers: self.handlers,
+                    concurrency: self.concurrency,
+                    sequencer: Sequencer::new(self.ordering),
+                }),
             },
-            cx: Mutex::new(self.cx.ok_or(NoCxAvailable)?),
+            cx: Arc::new(cx.shared(self.read_write).with_lifecycle(self.lifecycle)),
+            deferred: Arc::default(),
         })
     }
 }
@@ -0,0 +1,448 @@
+use async_std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, VecDeque},
+    fmt::{Debug, Display},
+    future::{poll_fn, Future},
+    ops::{Deref, DerefMut},
+    pin::{pin, Pin},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use crate::mediator::lifecycle::Lifecycle;
+
+/// Creates the context `Cx` of a mediator on first use.
+pub(crate) type CxFactory<Cx> =
+    Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = Cx> + Send>> + Send>;
+
+std::thread_local! {
+    /// The contexts held by the requests being polled on this thread, innermost last,
+    /// each with the [`Deferral`] of the request holding it.
+    static HELD: RefCell<Vec<(usize, u64)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The shared context `Cx` of a [`CxAwareAsyncMediator`](super::CxAwareAsyncMediator).
+///
+/// If the context was added with
+/// [`CxAwareAsyncBuilder::add_context_with()`](super::CxAwareAsyncBuilder::add_context_with()),
+/// it is created by whichever request locks it first.
+/// Concurrent requests wait until it is created.
+///
+/// The context is stored behind a read-write lock.
+/// Requests lock it exclusively, unless the mediator was built with
+/// [`CxAwareAsyncBuilder::read_write_context()`](super::CxAwareAsyncBuilder::read_write_context()).
+/// A context added with
+/// [`CxAwareAsyncBuilder::add_shared_context()`](super::CxAwareAsyncBuilder::add_shared_context())
+/// is immutable and never locked.
+pub struct LazyContext<Cx> {
+    cx: RwLock<Option<Cx>>,
+    init: std::sync::Mutex<Option<CxFactory<Cx>>>,
+    shared: bool,
+    immutable: Option<Arc<Cx>>,
+    lifecycle: std::sync::Mutex<Option<Lifecycle<Cx>>>,
+}
+
+impl<Cx> LazyContext<Cx> {
+    /// Wraps a context that already exists.
+    pub(crate) fn ready(cx: Cx) -> Self {
+        Self {
+            cx: RwLock::new(Some(cx)),
+            init: std::sync::Mutex::new(None),
+            shared: false,
+            immutable: None,
+            lifecycle: std::sync::Mutex::new(None),
+        }
+    }
+
+    /// Wraps a context that is created by `init` on first use.
+    pub(crate) fn lazy(init: CxFactory<Cx>) -> Self {
+        Self {
+            cx: RwLock::new(None),
+            init: std::sync::Mutex::new(Some(init)),
+            shared: false,
+            immutable: None,
+            lifecycle: std::sync::Mutex::new(None),
+        }
+    }
+
+    /// Wraps an immutable context that is shared without locking.
+    pub(crate) fn immutable(cx: Arc<Cx>) -> Self {
+        Self {
+            cx: RwLock::new(None),
+            init: std::sync::Mutex::new(None),
+            shared: false,
+            immutable: Some(cx),
+            lifecycle: std::sync::Mutex::new(None),
+        }
+    }
+
+    /// Lets requests share the context, see [`LazyContext::for_request()`].
+    pub(crate) fn shared(mut self, shared: bool) -> Self {
+        self.shared = shared;
+        self
+    }
+
+    /// Calls the `lifecycle` hooks of the context, starting it right away if it exists.
+    pub(crate) fn with_lifecycle(mut self, lifecycle: Option<Lifecycle<Cx>>) -> Self {
+        if let Some(lifecycle) = &lifecycle {
+            if let Some(cx) = self.immutable.as_deref().or(self.cx.get_mut().as_ref()) {
+                lifecycle.start(cx);
+            }
+        }
+        self.lifecycle = std::sync::Mutex::new(lifecycle);
+        self
+    }
+
+    /// Shuts the context down, if it was created.
+    ///
+    /// Waits until no request holds the context.
+    pub(crate) async fn shutdown(&self) {
+        let lifecycle = self
+            .lifecycle
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .take();
+        let Some(lifecycle) = lifecycle else {
+            return;
+        };
+        if let Some(cx) = &self.immutable {
+            return lifecycle.shutdown(cx).await;
+        }
+        if let Some(cx) = &*self.cx.read().await {
+            lifecycle.shutdown(cx).await;
+        }
+    }
+
+    /// Locks the context, creating it first if it doesn't exist yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if creating the context panicked before
+    /// or if the context is immutable.
+    pub async fn lock(&self) -> ContextGuard<'_, Cx> {
+        assert!(
+            self.immutable.is_none(),
+            "the context was added with `add_shared_context()` and is immutable"
+        );
+        let mut cx = self.cx.write().await;
+        if cx.is_none() {
+            let init = self
+                .init
+                .lock()
+                .unwrap_or_else(|err| err.into_inner())
+                .take();
+            if let Some(init) = init {
+                let created = cx.insert(init().await);
+                let lifecycle = *self.lifecycle.lock().unwrap_or_else(|err| err.into_inner());
+                if let Some(lifecycle) = lifecycle {
+                    lifecycle.start(created);
+                }
+            }
+        }
+        ContextGuard(cx)
+    }
+
+    /// Locks the context for reading, creating it first if it doesn't exist yet.
+    ///
+    /// Any number of readers may hold the lock at the same time.
+    /// An immutable context is returned without locking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if creating the context panicked before.
+    pub async fn read(&self) -> ContextReadGuard<'_, Cx> {
+        if let Some(cx) = &self.immutable {
+            return ContextReadGuard(ReadGuard::Unlocked(cx));
+        }
+        let cx = self.cx.read().await;
+        if cx.is_some() {
+            return ContextReadGuard(ReadGuard::Locked(cx));
+        }
+        drop(cx);
+        drop(self.lock().await);
+        ContextReadGuard(ReadGuard::Locked(self.cx.read().await))
+    }
+
+    /// Locks the context for handling a request that only reads it.
+    ///
+    /// The lock is shared if the mediator was built with `read_write_context()`
+    /// and exclusive otherwise. An immutable context isn't locked at all.
+    pub(crate) async fn for_request(&self) -> ContextRef<'_, Cx> {
+        if self.shared || self.immutable.is_some() {
+            ContextRef::Shared(self.read().await)
+        } else {
+            ContextRef::Exclusive(self.lock().await)
+        }
+    }
+
+    fn id(&self) -> usize {
+        self as *const Self as usize
+    }
+
+    /// Returns `true` if the current task holds the context while handling a request,
+    /// i.e. locking it again would never complete.
+    /// An immutable context is never held.
+    pub(crate) fn is_held(&self) -> bool {
+        self.holder().is_some()
+    }
+
+    /// Returns the token of the [`Deferral`] of the request holding the context
+    /// in the current task, if any, see [`LazyContext::is_held()`].
+    pub(crate) fn holder(&self) -> Option<u64> {
+        if self.immutable.is_some() {
+            return None;
+        }
+        let id = self.id();
+        HELD.with(|held| {
+            held.borrow()
+                .iter()
+                .rev()
+                .find(|(held, _)| *held == id)
+                .map(|(_, token)| *token)
+        })
+    }
+
+    /// Fails if the current task holds the context, see [`LazyContext::is_held()`].
+    pub(crate) fn check_not_held(&self) -> Result<(), ReentrantSendError> {
+        match self.is_held() {
+            true => Err(ReentrantSendError),
+            false => Ok(()),
+        }
+    }
+
+    /// Awaits `fut`, which locks the context, marking it as held by the current task
+    /// for the request that defers to `token`.
+    ///
+    /// The mark only lives while `fut` is polled, so tasks spawned from within
+    /// don't hold the context.
+    pub(crate) async fn hold<F: Future>(&self, token: u64, fut: F) -> F::Output {
+        let id = self.id();
+        let mut fut = pin!(fut);
+        poll_fn(|cx| {
+            HELD.with(|held| held.borrow_mut().push((id, token)));
+            let _released = Released;
+            fut.as_mut().poll(cx)
+        })
+        .await
+    }
+}
+
+/// Error returned if a request waiting for its outcome, e.g. with `send_and_receive()`,
+/// is sent from within a handler of the mediator while it holds the context,
+/// which would wait for itself forever.
+///
+/// Use `defer_send()` to send the request once the request being handled completes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReentrantSendError;
+
+impl Display for ReentrantSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "request was sent while the context is held by the request being handled"
+        )
+    }
+}
+
+impl std::error::Error for ReentrantSendError {}
+
+/// Removes the innermost mark of [`LazyContext::hold()`] once dropped, even if polling panicked.
+struct Released;
+
+impl Drop for Released {
+    fn drop(&mut self) {
+        HELD.with(|held| held.borrow_mut().pop());
+    }
+}
+
+/// The future of a deferred request, see [`Deferred`].
+pub(crate) type DeferredFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+/// Sends a deferred request to the mediator `M`.
+type DeferredSend<M> = Box<dyn for<'a> FnOnce(&'a M) -> DeferredFuture<'a> + Send>;
+
+/// The requests a mediator `M` deferred while its context was held,
+/// handled once the request holding it completes, see `defer_send()`.
+///
+/// The requests are kept apart by the [`Deferral`] of the request that held the context,
+/// so the clones of a mediator only handle the requests they deferred themselves.
+pub(crate) struct Deferred<M> {
+    requests: std::sync::Mutex<BTreeMap<u64, VecDeque<DeferredSend<M>>>>,
+    next: AtomicU64,
+}
+
+impl<M> Deferred<M> {
+    /// Starts deferring the requests sent while handling a request.
+    pub(crate) fn begin(&self) -> Deferral<'_, M> {
+        Deferral {
+            deferred: self,
+            token: self.next.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// Defers the request sent by `send` until the request holding `token` completes.
+    pub(crate) fn push<F>(&self, token: u64, send: F)
+    where
+        F: for<'a> FnOnce(&'a M) -> DeferredFuture<'a> + Send + 'static,
+    {
+        self.requests
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .entry(token)
+            .or_default()
+            .push_back(Box::new(send));
+    }
+
+    fn pop(&self, token: u64) -> Option<DeferredSend<M>> {
+        let mut requests = self.requests.lock().unwrap_or_else(|err| err.into_inner());
+        let deferred = requests.get_mut(&token)?;
+        let send = deferred.pop_front();
+        if deferred.is_empty() {
+            requests.remove(&token);
+        }
+        send
+    }
+}
+
+impl<M> Default for Deferred<M> {
+    fn default() -> Self {
+        Self {
+            requests: std::sync::Mutex::new(BTreeMap::new()),
+            next: AtomicU64::new(0),
+        }
+    }
+}
+
+impl<M> Debug for Deferred<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let len = self.requests.lock().map_or(0, |requests| {
+            requests.values().map(VecDeque::len).sum::<usize>()
+        });
+        f.debug_struct("Deferred").field("len", &len).finish()
+    }
+}
+
+/// The requests deferred while a single request holds the context, see [`Deferred::begin()`].
+///
+/// Requests that were never handled, e.g. because the request was cancelled,
+/// are dropped together with the [`Deferral`].
+pub(crate) struct Deferral<'a, M> {
+    deferred: &'a Deferred<M>,
+    pub(crate) token: u64,
+}
+
+impl<M> Deferral<'_, M> {
+    /// Handles the deferred requests in order.
+    ///
+    /// Each of them is sent like any other request,
+    /// handling the requests it defers in turn once it completes.
+    pub(crate) async fn run(self, mediator: &M) {
+        while let Some(send) = self.deferred.pop(self.token) {
+            send(mediator).await;
+        }
+    }
+}
+
+impl<M> Drop for Deferral<'_, M> {
+    fn drop(&mut self) {
+        self.deferred
+            .requests
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(&self.token);
+    }
+}
+
+impl<Cx> Debug for LazyContext<Cx>
+where
+    Cx: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut f = f.debug_tuple("LazyContext");
+        if let Some(cx) = &self.immutable {
+            return f.field(cx).finish();
+        }
+        match self.cx.try_read() {
+            Some(cx) => match &*cx {
+                Some(cx) => f.field(cx),
+                None => f.field(&format_args!("<uninitialized>")),
+            },
+            None => f.field(&format_args!("<locked>")),
+        }
+        .finish()
+    }
+}
+
+/// Exclusive access to the context `Cx`, returned by [`LazyContext::lock()`].
+pub struct ContextGuard<'a, Cx>(RwLockWriteGuard<'a, Option<Cx>>);
+
+impl<Cx> Deref for ContextGuard<'_, Cx> {
+    type Target = Cx;
+
+    fn deref(&self) -> &Cx {
+        self.0.as_ref().expect("creating the context panicked")
+    }
+}
+
+impl<Cx> DerefMut for ContextGuard<'_, Cx> {
+    fn deref_mut(&mut self) -> &mut Cx {
+        self.0.as_mut().expect("creating the context panicked")
+    }
+}
+
+impl<Cx> Debug for ContextGuard<'_, Cx>
+where
+    Cx: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+/// Shared access to the context `Cx`, returned by [`LazyContext::read()`].
+pub struct ContextReadGuard<'a, Cx>(ReadGuard<'a, Cx>);
+
+enum ReadGuard<'a, Cx> {
+    Locked(RwLockReadGuard<'a, Option<Cx>>),
+    Unlocked(&'a Cx),
+}
+
+impl<Cx> Deref for ContextReadGuard<'_, Cx> {
+    type Target = Cx;
+
+    fn deref(&self) -> &Cx {
+        match &self.0 {
+            ReadGuard::Locked(cx) => cx.as_ref().expect("creating the context panicked"),
+            ReadGuard::Unlocked(cx) => cx,
+        }
+    }
+}
+
+impl<Cx> Debug for ContextReadGuard<'_, Cx>
+where
+    Cx: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+/// Access to the context `Cx` while handling a request, see [`LazyContext::for_request()`].
+pub(crate) enum ContextRef<'a, Cx> {
+    Shared(ContextReadGuard<'a, Cx>),
+    Exclusive(ContextGuard<'a, Cx>),
+}
+
+impl<Cx> Deref for ContextRef<'_, Cx> {
+    type Target = Cx;
+
+    fn deref(&self) -> &Cx {
+        match self {
+            ContextRef::Shared(cx) => cx,
+            ContextRef::Exclusive(cx) => cx,
+        }
+    }
+}
@@ -1,17 +1,39 @@
-use std::sync::mpsc::TryRecvError;
-
-use async_std::sync::Mutex;
 use async_trait::async_trait;
-use std::fmt::Debug;
+use std::{any::Any, fmt::Debug, sync::Arc, time::Duration};
+
+#[cfg(feature = "causality")]
+use crate::mediator::causality::CausalityGraph;
+#[cfg(feature = "persistence")]
+use crate::mediator::codec::Codec;
+#[cfg(feature = "schema")]
+use crate::mediator::schema::MediatorDescription;
+#[cfg(feature = "persistence")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "persistence")]
+use std::{io, path::Path};
 
-use crate::asynchronous::basic::BasicAsyncMediator;
+use crate::asynchronous::basic::{BasicAsyncMediator, EventStream};
+use crate::mediator::behavior::{run_async_pipeline, AnyRequest};
+use crate::mediator::cancellation::{cancellable, timeout};
+use crate::mediator::channel::TrySendError;
+use crate::mediator::concurrency::join_limited;
+use crate::mediator::correlation::{self, CorrelationId, Trace};
+use crate::mediator::queue::Priority;
+use crate::mediator::registry::{AsyncFanOutHandler, BoxFuture};
+use crate::mediator::stats::MediatorStats;
+use crate::mediator::system;
+use crate::mediator::time::Instant;
 
+use super::context::Deferred;
 use super::*;
 
 /// Context aware async mediator for asynchronous environments with events of type `Ev`.
 ///
 /// Uses an underlying [`BasicAsyncMediator`] for base functionality
-/// and a `Mutex` to store the user-defined context `Cx`.
+/// and a [`LazyContext`] to store the user-defined context `Cx`.
+///
+/// A [`CxAwareAsyncMediator`] is [`Send`] and [`Sync`] whenever `Ev` is [`Send`]
+/// and `Cx` is [`Send`] and [`Sync`], so it can be shared between tasks behind an `Arc`.
 ///
 /// # Examples
 ///
@@ -66,18 +88,18 @@ use super::*;
 #[derive(Debug)]
 pub struct CxAwareAsyncMediator<Cx, Ev>
 where
-    Cx: Debug,
-    Ev: Debug + 'static,
+    Ev: MaybeDebug + 'static,
 {
     pub(crate) basic: BasicAsyncMediator<Ev>,
-    pub(crate) cx: Mutex<Cx>,
+    pub(crate) cx: Arc<LazyContext<Cx>>,
+    pub(crate) deferred: Arc<Deferred<Self>>,
 }
 
 #[async_trait]
 impl<Cx, Ev> AsyncMediatorInternal<Ev> for CxAwareAsyncMediator<Cx, Ev>
 where
-    Cx: Debug + Send,
-    Ev: Debug + Send,
+    Cx: Send + Sync,
+    Ev: MaybeDebug + Send,
 {
     /// Publishes an event `Ev` asynchronously.
     ///
@@ -123,37 +145,702 @@ where
     async fn publish(&self, event: Ev) {
         self.basic.publish(event).await
     }
+
+    /// Tries to publish an event `Ev` asynchronously.
+    ///
+    /// See [`BasicAsyncMediator::try_publish()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn try_publish(&self, event: Ev) -> Result<(), TrySendError<Ev>> {
+        self.basic.try_publish(event).await
+    }
+
+    /// Publishes an event `Ev` to the given `topic` asynchronously.
+    ///
+    /// See [`BasicAsyncMediator::publish_to()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn publish_to(&self, topic: &str, event: Ev) {
+        self.basic.publish_to(topic, event).await
+    }
+
+    /// Publishes an event `Ev` asynchronously that expires once the `ttl` elapsed.
+    ///
+    /// See [`BasicMediator::publish_with_ttl()`](crate::synchronous::basic::BasicMediator::publish_with_ttl()) for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn publish_with_ttl(&self, event: Ev, ttl: Duration) {
+        self.basic.publish_with_ttl(event, ttl).await
+    }
+
+    /// Publishes an urgent event `Ev` asynchronously, which is delivered before all regular events.
+    ///
+    /// See [`BasicAsyncMediator::publish_urgent()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn publish_urgent(&self, event: Ev) {
+        self.basic.publish_urgent(event).await
+    }
+
+    /// Publishes an event `Ev` asynchronously once the `delay` elapsed.
+    ///
+    /// See [`BasicAsyncMediator::publish_after()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn publish_after(&self, event: Ev, delay: Duration) {
+        self.basic.publish_after(event, delay).await
+    }
+
+    /// Publishes an event `Ev` asynchronously at the given `deadline`.
+    ///
+    /// See [`BasicAsyncMediator::publish_at()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn publish_at(&self, event: Ev, deadline: Instant) {
+        self.basic.publish_at(event, deadline).await
+    }
 }
 
 #[async_trait]
 impl<Cx, Ev> CxAwareAsyncMediatorInternalHandle<Cx, Ev> for CxAwareAsyncMediator<Cx, Ev>
 where
-    Cx: Debug + Send + Sync,
-    Ev: Debug + Send,
+    Cx: Send + Sync,
+    Ev: MaybeDebug + Send,
 {
     /// Send a request of type `Req` to the mediator asynchronously.
     ///
     /// The request will be processed internally by [`CxAwareAsyncRequestHandler::handle()`].
     /// This is why it is required to implement [`CxAwareAsyncRequestHandler`] for [`CxAwareAsyncMediator`].
     /// A `Mutex` will be locked in order to gain access to the context `Cx`.
+    /// If any [`AsyncBehavior`] was added, the request passes through them first.
+    /// Sent from within a handler while the context is held, the request is deferred
+    /// until the outermost request completes, see [`CxAwareAsyncMediator::defer_send()`].
     ///
     /// You need to await the `Future` using `.await`.
     ///
     async fn send<Req>(&self, req: Req)
     where
         Self: CxAwareAsyncRequestHandler<Cx, Req, Ev>,
+        Req: Send + 'static,
+    {
+        if self.cx.is_held() {
+            return self.defer_send(req).await;
+        }
+        let deferral = self.deferred.begin();
+        let request = self.basic.inner.observer.request_async::<Req, _>(
+            "send",
+            self.cx.hold(deferral.token, async {
+                let m = self.cx.for_request().await;
+                if self.basic.inner.behavior.is_empty() {
+                    return <Self as CxAwareAsyncRequestHandler<Cx, Req, Ev>>::handle(
+                        self, req, &m,
+                    )
+                    .await;
+                }
+                run_async_pipeline(&self.basic.inner.behavior, AnyRequest::new(req), |req| {
+                    <Self as CxAwareAsyncRequestHandler<Cx, Req, Ev>>::handle(
+                        self,
+                        req.into_inner(),
+                        &m,
+                    )
+                })
+                .await
+            }),
+        );
+        self.basic
+            .report::<Req, _>(system::completed, request)
+            .await;
+        deferral.run(self).await;
+    }
+
+    /// Send a request of type `Req` to the mediator without waiting for it to be handled.
+    ///
+    /// See [`BasicAsyncMediator::send_detached()`] for more info.
+    ///
+    fn send_detached<Req>(&self, req: Req) -> JoinHandle<()>
+    where
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev> + Send + Sync + 'static,
+        Req: Send + 'static,
+    {
+        let mediator = CxAwareMediatorHandle::new(self);
+        JoinHandle::spawn(async move { mediator.send(req).await })
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously
+    /// and correlate it with the resulting events.
+    ///
+    /// See [`BasicAsyncMediator::send_traced()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn send_traced<Req>(&self, req: Req) -> CorrelationId
+    where
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev>,
+        Req: Send + 'static,
+    {
+        let id = CorrelationId::next();
+        correlation::scope_async(Trace::current().traced(id), self.send(req)).await;
+        id
+    }
+
+    /// Send a request of unknown type to the mediator asynchronously.
+    ///
+    /// The request is processed by the handler registered for its type
+    /// via [`CxAwareAsyncBuilder::register_handler()`](super::CxAwareAsyncBuilder::register_handler()).
+    ///
+    /// See [`BasicMediator::send_dyn()`](crate::synchronous::basic::BasicMediator::send_dyn()) for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn send_dyn(&self, req: Box<dyn Any + Send>) -> Result<(), Box<dyn Any + Send>>
+    where
+        Self: Sync + 'static,
+    {
+        match self.basic.inner.handlers.get(&*req) {
+            Some(handler) => {
+                handler(self, req).await;
+                Ok(())
+            }
+            None => Err(req),
+        }
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously
+    /// and receive a response of type `Resp`.
+    ///
+    /// The request will be processed internally by [`CxAwareAsyncRequestHandler::handle()`],
+    /// whose return value is handed back to the caller.
+    /// A `Mutex` will be locked in order to gain access to the context `Cx`.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReentrantSendError`] if called from within a handler of the mediator
+    /// while it holds the context, which would wait for itself forever.
+    /// Use [`CxAwareAsyncMediator::defer_send()`] instead.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Queried
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct MyContext(u32);
+    ///
+    /// struct Offset(u32);
+    ///
+    /// #[async_trait]
+    /// impl CxAwareAsyncRequestHandler<MyContext, Offset, MyEvent, u32> for CxAwareAsyncMediator<MyContext, MyEvent> {
+    ///     async fn handle(&self, req: Offset, cx: &MyContext) -> u32 {
+    ///         self.publish(MyEvent::Queried).await;
+    ///         cx.0 + req.0
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = CxAwareAsyncMediator::<MyContext, MyEvent>::builder()
+    ///         .add_context(MyContext(40))
+    ///         .build()
+    ///         .unwrap();
+    ///
+    ///     let res: u32 = mediator.send_and_receive(Offset(2)).await.unwrap();
+    ///     assert_eq!(res, 42);
+    /// });
+    ///
+    async fn send_and_receive<Req, Resp>(&self, req: Req) -> Result<Resp, ReentrantSendError>
+    where
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev, Resp>,
         Req: Send,
+        Resp: Send,
     {
-        let m = self.cx.lock().await;
-        <Self as CxAwareAsyncRequestHandler<Cx, Req, Ev>>::handle(self, req, &m).await
+        self.cx.check_not_held()?;
+        let deferral = self.deferred.begin();
+        let request = self.basic.inner.observer.request_async::<Req, _>(
+            "send_and_receive",
+            self.cx.hold(deferral.token, async {
+                let m = self.cx.for_request().await;
+                <Self as CxAwareAsyncRequestHandler<Cx, Req, Ev, Resp>>::handle(self, req, &m).await
+            }),
+        );
+        let resp = self
+            .basic
+            .report::<Req, _>(system::completed, request)
+            .await;
+        deferral.run(self).await;
+        Ok(resp)
+    }
+
+    /// Send a request of type `Req`, which mutates the context `Cx`, to the mediator asynchronously.
+    ///
+    /// The request will be processed internally by [`CxAwareAsyncMutRequestHandler::handle()`],
+    /// which receives the context mutably.
+    /// The context is always locked exclusively, so this waits for all other requests
+    /// to release it, even if the mediator was built with
+    /// [`CxAwareAsyncBuilder::read_write_context()`].
+    /// If any [`AsyncBehavior`](crate::behavior::AsyncBehavior) was added, the request passes through them first.
+    /// Sent from within a handler while the context is held, the request is deferred
+    /// until the outermost request completes, see [`CxAwareAsyncMediator::defer_send()`].
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the context was added with [`CxAwareAsyncBuilder::add_shared_context()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Renamed
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct Settings {
+    ///     name: String
+    /// }
+    ///
+    /// struct Rename(String);
+    ///
+    /// #[async_trait]
+    /// impl CxAwareAsyncMutRequestHandler<Settings, Rename, MyEvent> for CxAwareAsyncMediator<Settings, MyEvent> {
+    ///     async fn handle(&self, req: Rename, cx: &mut Settings) {
+    ///         cx.name = req.0;
+    ///         self.publish(MyEvent::Renamed).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = CxAwareAsyncMediator::<Settings, MyEvent>::builder()
+    ///         .add_context(Settings::default())
+    ///         .read_write_context()
+    ///         .build()
+    ///         .unwrap();
+    ///
+    ///     mediator.send_mut(Rename("mediatrix".into())).await;
+    ///     assert_eq!(mediator.next_all().await, 1);
+    /// });
+    ///
+    async fn send_mut<Req>(&self, req: Req)
+    where
+        Req: Send + 'static,
+        Self: CxAwareAsyncMutRequestHandler<Cx, Req, Ev>,
+    {
+        if let Some(token) = self.cx.holder() {
+            return self.deferred.push(token, move |m: &Self| m.send_mut(req));
+        }
+        let deferral = self.deferred.begin();
+        let request = self.basic.inner.observer.request_async::<Req, _>(
+            "send_mut",
+            self.cx.hold(deferral.token, async {
+                let mut m = self.cx.lock().await;
+                if self.basic.inner.behavior.is_empty() {
+                    return <Self as CxAwareAsyncMutRequestHandler<Cx, Req, Ev>>::handle(
+                        self, req, &mut m,
+                    )
+                    .await;
+                }
+                run_async_pipeline(&self.basic.inner.behavior, AnyRequest::new(req), |req| {
+                    <Self as CxAwareAsyncMutRequestHandler<Cx, Req, Ev>>::handle(
+                        self,
+                        req.into_inner(),
+                        &mut m,
+                    )
+                })
+                .await
+            }),
+        );
+        self.basic
+            .report::<Req, _>(system::completed, request)
+            .await;
+        deferral.run(self).await;
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously
+    /// together with a per-request `scope`.
+    ///
+    /// The request will be processed internally by [`ScopedCxAwareAsyncRequestHandler::handle()`],
+    /// which receives the scope next to the long-lived context `Cx`.
+    /// This keeps per-request data, e.g. the authenticated user or a request ID,
+    /// out of the request itself.
+    /// A `Mutex` will be locked in order to gain access to the context `Cx`.
+    /// Sent from within a handler while the context is held, the request is deferred
+    /// until the outermost request completes, see [`CxAwareAsyncMediator::defer_send()`].
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyEvent {
+    ///     Deleted { id: u32, by: String }
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct MyContext;
+    ///
+    /// struct User(String);
+    ///
+    /// struct Delete(u32);
+    ///
+    /// #[async_trait]
+    /// impl ScopedCxAwareAsyncRequestHandler<MyContext, User, Delete, MyEvent> for CxAwareAsyncMediator<MyContext, MyEvent> {
+    ///     async fn handle(&self, req: Delete, _cx: &MyContext, user: &User) {
+    ///         self.publish(MyEvent::Deleted { id: req.0, by: user.0.clone() }).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = CxAwareAsyncMediator::<MyContext, MyEvent>::builder()
+    ///         .add_context(MyContext)
+    ///         .build()
+    ///         .unwrap();
+    ///
+    ///     mediator.send_with_scope(Delete(7), User("admin".into())).await;
+    ///     assert_eq!(mediator.next_all().await, 1);
+    /// });
+    ///
+    async fn send_with_scope<Req, Scope>(&self, req: Req, scope: Scope)
+    where
+        Req: Send + 'static,
+        Scope: Send + Sync + 'static,
+        Self: ScopedCxAwareAsyncRequestHandler<Cx, Scope, Req, Ev>,
+    {
+        if let Some(token) = self.cx.holder() {
+            return self
+                .deferred
+                .push(token, move |m: &Self| m.send_with_scope(req, scope));
+        }
+        let deferral = self.deferred.begin();
+        let request = self.basic.inner.observer.request_async::<Req, _>(
+            "send_with_scope",
+            self.cx.hold(deferral.token, async {
+                let m = self.cx.for_request().await;
+                if self.basic.inner.behavior.is_empty() {
+                    return <Self as ScopedCxAwareAsyncRequestHandler<Cx, Scope, Req, Ev>>::handle(
+                        self, req, &m, &scope,
+                    )
+                    .await;
+                }
+                run_async_pipeline(&self.basic.inner.behavior, AnyRequest::new(req), |req| {
+                    <Self as ScopedCxAwareAsyncRequestHandler<Cx, Scope, Req, Ev>>::handle(
+                        self,
+                        req.into_inner(),
+                        &m,
+                        &scope,
+                    )
+                })
+                .await
+            }),
+        );
+        self.basic
+            .report::<Req, _>(system::completed, request)
+            .await;
+        deferral.run(self).await;
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously,
+    /// which may fail with an error of type `Err`.
+    ///
+    /// The request will be processed internally by [`TryCxAwareAsyncRequestHandler::handle()`],
+    /// whose error is handed back to the caller.
+    /// A `Mutex` will be locked in order to gain access to the context `Cx`.
+    ///
+    /// See [`BasicAsyncMediator::try_send()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReentrantSendError`], converted into `Err`, if called from within a handler
+    /// of the mediator while it holds the context, which would wait for itself forever.
+    /// Use [`CxAwareAsyncMediator::defer_send()`] instead.
+    ///
+    async fn try_send<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Self: TryCxAwareAsyncRequestHandler<Cx, Req, Ev, Err>,
+        Req: Send + 'static,
+        Err: From<ReentrantSendError> + Send,
+    {
+        self.cx.check_not_held()?;
+        let deferral = self.deferred.begin();
+        let request = self.basic.inner.observer.request_async::<Req, _>(
+            "try_send",
+            self.cx.hold(deferral.token, async {
+                let m = self.cx.for_request().await;
+                if self.basic.inner.behavior.is_empty() {
+                    return <Self as TryCxAwareAsyncRequestHandler<Cx, Req, Ev, Err>>::handle(
+                        self, req, &m,
+                    )
+                    .await;
+                }
+                let mut res = Ok(());
+                run_async_pipeline(
+                    &self.basic.inner.behavior,
+                    AnyRequest::new(req),
+                    |req| async {
+                        res = <Self as TryCxAwareAsyncRequestHandler<Cx, Req, Ev, Err>>::handle(
+                            self,
+                            req.into_inner(),
+                            &m,
+                        )
+                        .await
+                    },
+                )
+                .await;
+                res
+            }),
+        );
+        let res = self.basic.report::<Req, _>(system::failed, request).await;
+        deferral.run(self).await;
+        res
+    }
+
+    /// Try to send a request of type `Req` to the mediator asynchronously,
+    /// retrying it according to the configured [`RetryPolicy`].
+    ///
+    /// Every attempt is processed like in [`CxAwareAsyncMediator::try_send()`],
+    /// so the `Mutex` of the context `Cx` is released during the backoff.
+    ///
+    /// See [`BasicAsyncMediator::try_send_with_retry()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn try_send_with_retry<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Self: TryCxAwareAsyncRequestHandler<Cx, Req, Ev, Err>,
+        Req: Clone + Send + Sync + 'static,
+        Ev: Send,
+        Err: From<ReentrantSendError> + Send,
+    {
+        match self
+            .basic
+            .inner
+            .retry
+            .run::<Req, _, _>(|| self.try_send(req.clone()))
+            .await
+        {
+            Ok(()) => Ok(()),
+            Err((err, exhausted)) => {
+                if let Some(ev) = exhausted {
+                    self.publish(ev).await;
+                }
+                Err(err)
+            }
+        }
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously,
+    /// which can be cancelled through the given [`CancellationToken`].
+    ///
+    /// The request will be processed internally by [`CxAwareAsyncRequestHandler::handle()`].
+    /// Waiting for the `Mutex` of the context `Cx` can be cancelled as well.
+    ///
+    /// See [`BasicAsyncMediator::send_with_cancellation()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn send_with_cancellation<Req>(
+        &self,
+        req: Req,
+        token: &CancellationToken,
+    ) -> Result<(), CancelledError>
+    where
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev>,
+        Req: Send + 'static,
+    {
+        cancellable(self.send(req), token).await
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously,
+    /// which has to complete within the given `duration`.
+    ///
+    /// The request will be processed internally by [`CxAwareAsyncRequestHandler::handle()`].
+    /// Waiting for the `Mutex` of the context `Cx` counts towards the timeout as well.
+    ///
+    /// See [`BasicAsyncMediator::send_with_timeout()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn send_with_timeout<Req>(&self, req: Req, duration: Duration) -> Result<(), TimeoutError>
+    where
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev>,
+        Req: Send + 'static,
+    {
+        timeout(self.send(req), duration).await
+    }
+
+    /// Queue a request of type `Req` with the given [`Priority`]
+    /// and wait until the running mediator handled it.
+    ///
+    /// The request will be processed internally by [`CxAwareAsyncRequestHandler::handle()`].
+    ///
+    /// See [`BasicAsyncMediator::send_with_priority()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn send_with_priority<Req>(&self, req: Req, priority: Priority)
+    where
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev> + Send + Sync + 'static,
+        Req: Send + 'static,
+    {
+        let (handled, done) = async_std::channel::bounded(1);
+        let (cx, deferred) = (self.cx.clone(), self.deferred.clone());
+        self.basic.inner.requests.push(
+            priority,
+            Box::new(move |basic| {
+                Box::pin(async move {
+                    Self {
+                        basic,
+                        cx,
+                        deferred,
+                    }
+                    .send(req)
+                    .await;
+                    handled.try_send(()).ok();
+                })
+            }),
+        );
+        self.basic.inner.published.notify(usize::MAX);
+        done.recv().await.ok();
+    }
+
+    /// Queue a request of type `Req` to be handled by the running mediator
+    /// and return immediately.
+    ///
+    /// The request will be processed internally by [`CxAwareAsyncRequestHandler::handle()`].
+    ///
+    /// See [`BasicAsyncMediator::queue_request()`] for more info.
+    ///
+    fn queue_request<Req>(&self, req: Req)
+    where
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev> + Send + Sync + 'static,
+        Req: Send + 'static,
+    {
+        let (cx, deferred) = (self.cx.clone(), self.deferred.clone());
+        self.basic.inner.requests.push(
+            Priority::Normal,
+            Box::new(move |basic| {
+                Box::pin(async move {
+                    Self {
+                        basic,
+                        cx,
+                        deferred,
+                    }
+                    .send(req)
+                    .await
+                })
+            }),
+        );
+        self.basic.inner.published.notify(usize::MAX);
+    }
+
+    /// Send a request of type `Req` to the mediator once the request being handled completes.
+    ///
+    /// The context `Cx` stays locked while a request is handled,
+    /// so a handler can't send another request that needs it without waiting for itself.
+    /// From within a handler, the request is deferred instead:
+    /// it is handled by [`CxAwareAsyncRequestHandler::handle()`] right after
+    /// the outermost request completes and released the context, in the order it was deferred.
+    /// Only the task handling that request handles it,
+    /// requests sent concurrently through clones or handles of the mediator never do.
+    /// Outside of a handler, the request is sent right away like with [`CxAwareAsyncMediator::send()`].
+    ///
+    /// [`CxAwareAsyncMediator::send()`], [`CxAwareAsyncMediator::send_mut()`] and
+    /// [`CxAwareAsyncMediator::send_with_scope()`] defer the request on their own
+    /// if the context is held, while requests waiting for their outcome,
+    /// e.g. [`CxAwareAsyncMediator::send_and_receive()`], return a [`ReentrantSendError`] instead.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyEvent {
+    ///     Ordered(u32),
+    ///     Shipped(u32)
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct MyContext;
+    ///
+    /// struct Order(u32);
+    /// struct Ship(u32);
+    ///
+    /// #[async_trait]
+    /// impl CxAwareAsyncRequestHandler<MyContext, Order, MyEvent> for CxAwareAsyncMediator<MyContext, MyEvent> {
+    ///     async fn handle(&self, req: Order, _cx: &MyContext) {
+    ///         self.defer_send(Ship(req.0)).await;
+    ///         self.publish(MyEvent::Ordered(req.0)).await;
+    ///     }
+    /// }
+    ///
+    /// #[async_trait]
+    /// impl CxAwareAsyncRequestHandler<MyContext, Ship, MyEvent> for CxAwareAsyncMediator<MyContext, MyEvent> {
+    ///     async fn handle(&self, req: Ship, _cx: &MyContext) {
+    ///         self.publish(MyEvent::Shipped(req.0)).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let mediator = CxAwareAsyncMediator::<MyContext, MyEvent>::builder()
+    ///         .add_context(MyContext)
+    ///         .build()
+    ///         .unwrap();
+    ///
+    ///     mediator.send(Order(1)).await;
+    ///     assert_eq!(mediator.next_all().await, 2);
+    /// });
+    ///
+    async fn defer_send<Req>(&self, req: Req)
+    where
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev>,
+        Req: Send + 'static,
+    {
+        let Some(token) = self.cx.holder() else {
+            return self.send(req).await;
+        };
+        self.deferred.push(token, move |m: &Self| m.send(req));
     }
 }
 
 #[async_trait]
 impl<Cx, Ev> AsyncMediatorInternalNext for CxAwareAsyncMediator<Cx, Ev>
 where
-    Cx: Debug + Send,
-    Ev: Debug + Send,
+    Cx: Send + Sync,
+    Ev: MaybeDebug + Send + Sync,
 {
     /// Process the next published event `Ev` asynchronously.
     ///
@@ -164,7 +851,400 @@ where
     ///
     /// You need to await the `Future` using `.await`.
     ///
-    async fn next(&self) -> Result<(), TryRecvError> {
+    async fn next(&self) -> Result<(), NextError> {
         self.basic.next().await
     }
+
+    /// Process all pending published events `Ev` asynchronously.
+    ///
+    /// See [`BasicAsyncMediator::next_all()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn next_all(&self) -> usize {
+        self.basic.next_all().await
+    }
+
+    /// Process the next published event `Ev` asynchronously, waiting until one is available.
+    ///
+    /// See [`BasicAsyncMediator::next_async()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn next_async(&self) -> Result<(), NextError> {
+        self.basic.next_async().await
+    }
+}
+
+#[async_trait]
+impl<Cx, Ev> AsyncMediatorInternalRun for CxAwareAsyncMediator<Cx, Ev>
+where
+    Cx: Send + Sync,
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Processes published events `Ev` asynchronously until `shutdown` is cancelled.
+    ///
+    /// See [`BasicAsyncMediator::run()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn run(&self, shutdown: &CancellationToken) -> usize {
+        self.basic.run(shutdown).await
+    }
+
+    /// Spawns a [`Worker`] processing published events `Ev` on a background task.
+    ///
+    /// See [`BasicAsyncMediator::spawn_worker()`] for more info.
+    ///
+    fn spawn_worker(self: Arc<Self>) -> Worker
+    where
+        Self: 'static,
+    {
+        Worker::spawn(|shutdown| async move { self.run(&shutdown).await })
+    }
+}
+
+impl<Cx, Ev> AsyncMediatorInternalShare for CxAwareAsyncMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    type Handle = CxAwareMediatorHandle<Cx, Ev>;
+
+    /// Returns a [`CxAwareMediatorHandle`] to send requests and publish events from other tasks.
+    ///
+    /// Handles share the context `Cx` with the mediator.
+    ///
+    /// See [`BasicAsyncMediator::new_handle()`] for more info.
+    ///
+    fn new_handle(&self) -> CxAwareMediatorHandle<Cx, Ev> {
+        CxAwareMediatorHandle::new(self)
+    }
+}
+
+#[async_trait]
+impl<Cx, Ev> AsyncMediatorInternalSubscribe<Ev> for CxAwareAsyncMediator<Cx, Ev>
+where
+    Cx: Send + Sync,
+    Ev: MaybeDebug + Send,
+{
+    /// Subscribes a user-defined listener to the already built [`CxAwareAsyncMediator`] asynchronously.
+    ///
+    /// This method instructs the underlying [`BasicAsyncMediator`]
+    /// to subscribe the listener.
+    ///
+    /// See [`BasicAsyncMediator::subscribe()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn subscribe<F>(&self, f: F) -> ListenerHandle
+    where
+        F: Listener<Ev>,
+    {
+        self.basic.subscribe(f).await
+    }
+
+    /// Subscribes a user-defined async listener to the already built [`CxAwareAsyncMediator`] asynchronously.
+    ///
+    /// See [`BasicAsyncMediator::subscribe_async()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn subscribe_async<F>(&self, f: F) -> ListenerHandle
+    where
+        F: AsyncListener<Ev>,
+    {
+        self.basic.subscribe_async(f).await
+    }
+
+    /// Unsubscribes the listener identified by `handle` asynchronously.
+    ///
+    /// This works for both a [`Listener`] and an [`AsyncListener`].
+    /// See [`BasicAsyncMediator::unsubscribe()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn unsubscribe(&self, handle: ListenerHandle) -> bool {
+        self.basic.unsubscribe(handle).await
+    }
+
+    /// Subscribes a user-defined listener to the already built [`CxAwareAsyncMediator`]
+    /// and replays the last processed events to it asynchronously.
+    ///
+    /// See [`BasicMediator::add_listener_with_replay()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn add_listener_with_replay<F>(&self, f: F) -> ListenerHandle
+    where
+        F: Listener<Ev>,
+    {
+        self.basic.add_listener_with_replay(f).await
+    }
+}
+
+#[async_trait]
+impl<Cx, Ev> CxAwareMediatorInternalShutdown for CxAwareAsyncMediator<Cx, Ev>
+where
+    Cx: Send + Sync,
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Shuts down the [`CxAwareAsyncMediator`].
+    ///
+    /// If the mediator was built with
+    /// [`CxAwareAsyncBuilder::with_lifecycle()`](super::CxAwareAsyncBuilder::with_lifecycle()),
+    /// this calls [`CxLifecycle::on_shutdown()`](crate::lifecycle::CxLifecycle::on_shutdown())
+    /// on the context, after waiting for the requests holding it.
+    /// The hook is called only once, shutting down again does nothing.
+    /// A context added with `add_context_with()` that was never created
+    /// isn't created to shut it down.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn shutdown(&self) {
+        self.cx.shutdown().await
+    }
+}
+
+impl<Cx, Ev> AsyncMediatorInternalPause for CxAwareAsyncMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Pauses the delivery of events to listeners.
+    ///
+    /// See [`BasicAsyncMediator::pause()`] for more info.
+    ///
+    fn pause(&self) {
+        self.basic.pause()
+    }
+
+    /// Resumes the delivery of events paused with [`CxAwareAsyncMediator::pause()`].
+    ///
+    /// See [`BasicAsyncMediator::resume()`] for more info.
+    ///
+    fn resume(&self) {
+        self.basic.resume()
+    }
+
+    /// Returns whether the delivery of events is paused.
+    ///
+    /// See [`BasicAsyncMediator::is_paused()`] for more info.
+    ///
+    fn is_paused(&self) -> bool {
+        self.basic.is_paused()
+    }
+}
+
+impl<Cx, Ev> AsyncMediatorInternalStats for CxAwareAsyncMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Returns a snapshot of the counters of the [`CxAwareAsyncMediator`].
+    ///
+    /// See [`BasicMediator::stats()`](crate::synchronous::basic::BasicMediator::stats()) for more info.
+    ///
+    fn stats(&self) -> MediatorStats {
+        self.basic.stats()
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<Cx, Ev> AsyncMediatorInternalDescribe for CxAwareAsyncMediator<Cx, Ev>
+where
+    Ev: MaybeDebug + schemars::JsonSchema,
+{
+    /// Returns a catalog of the events and listeners of the [`CxAwareAsyncMediator`].
+    ///
+    /// See [`BasicMediator::describe()`](crate::synchronous::basic::BasicMediator::describe()) for more info.
+    ///
+    fn describe(&self) -> MediatorDescription {
+        self.basic.describe()
+    }
+}
+
+#[cfg(feature = "causality")]
+impl<Cx, Ev> AsyncMediatorInternalCausality for CxAwareAsyncMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Returns the flows of requests, events and listeners recorded by the [`CxAwareAsyncMediator`].
+    ///
+    /// See [`BasicAsyncMediator::causality_graph()`] for more info.
+    ///
+    fn causality_graph(&self) -> CausalityGraph {
+        self.basic.causality_graph()
+    }
+}
+
+impl<Cx, Ev> AsyncMediatorInternalStream<Ev> for CxAwareAsyncMediator<Cx, Ev>
+where
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Returns an [`EventStream`] of all published events `Ev`.
+    ///
+    /// The stream is backed by the underlying [`BasicAsyncMediator`].
+    ///
+    /// See [`BasicAsyncMediator::event_stream()`] for more info.
+    ///
+    fn event_stream(&self) -> EventStream<'_, Ev> {
+        self.basic.event_stream()
+    }
+}
+
+#[async_trait]
+impl<Cx, Ev> AsyncMediatorInternalRegistered for CxAwareAsyncMediator<Cx, Ev>
+where
+    Cx: Send + Sync,
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Send a request of type `Req` asynchronously to the closure added for it.
+    ///
+    /// The closure is added via
+    /// [`CxAwareAsyncBuilder::add_request_handler()`](super::CxAwareAsyncBuilder::add_request_handler())
+    /// and has access to the context `Cx`.
+    ///
+    /// See [`BasicMediator::send_registered()`](crate::synchronous::basic::BasicMediator::send_registered()) for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn send_registered<Req>(&self, req: Req) -> Result<(), Req>
+    where
+        Self: Sync + 'static,
+        Req: Send + 'static,
+    {
+        let closures = &self.basic.inner.basic.closures;
+        let Some(handler) = closures.closure::<Self, Req, BoxFuture<()>>() else {
+            return Err(req);
+        };
+        self.basic
+            .run_request("send_registered", req, |req| handler(self, req))
+            .await;
+        Ok(())
+    }
+
+    /// Send a request of type `Req` asynchronously to the closure added for it
+    /// and receive a response of type `Resp`.
+    ///
+    /// See [`BasicMediator::send_registered_and_receive()`](crate::synchronous::basic::BasicMediator::send_registered_and_receive()) for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn send_registered_and_receive<Req, Resp>(&self, req: Req) -> Result<Resp, Req>
+    where
+        Self: Sync + 'static,
+        Req: Send + 'static,
+        Resp: Send + 'static,
+    {
+        let closures = &self.basic.inner.basic.closures;
+        let Some(handler) = closures.closure::<Self, Req, BoxFuture<Resp>>() else {
+            return Err(req);
+        };
+        let request = self
+            .basic
+            .inner
+            .observer
+            .request_async::<Req, _>("send_registered_and_receive", handler(self, req));
+        Ok(self
+            .basic
+            .report::<Req, _>(system::completed, request)
+            .await)
+    }
+
+    /// Send a request of type `Req` asynchronously to all closures added for it.
+    ///
+    /// The closures are added via
+    /// [`CxAwareAsyncBuilder::add_fan_out_handler()`](super::CxAwareAsyncBuilder::add_fan_out_handler())
+    /// and have access to the context `Cx`.
+    ///
+    /// See [`BasicAsyncMediator::send_fan_out()`](crate::asynchronous::basic::BasicAsyncMediator::send_fan_out()) for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn send_fan_out<Req>(&self, req: Req) -> usize
+    where
+        Self: Sync + 'static,
+        Req: Send + Sync + 'static,
+    {
+        let fan_out = &self.basic.inner.basic.fan_out;
+        let handlers = fan_out.fan_out::<Req, AsyncFanOutHandler<Self, Req>>();
+        let concurrency = self.basic.inner.concurrency;
+        self.basic
+            .run_request("send_fan_out", req, |req| async move {
+                let req = Arc::new(req);
+                let handled: Vec<_> = handlers
+                    .iter()
+                    .map(|handler| handler(self, req.clone()))
+                    .collect();
+                join_limited(handled, concurrency).await;
+            })
+            .await;
+        handlers.len()
+    }
+}
+
+#[async_trait]
+impl<Cx, Ev> AsyncMediatorInternalDeadLetters<Ev> for CxAwareAsyncMediator<Cx, Ev>
+where
+    Cx: Send + Sync,
+    Ev: MaybeDebug + Send,
+{
+    /// Takes all events out of the dead-letter queue asynchronously, oldest first.
+    ///
+    /// See [`BasicMediator::dead_letters()`](crate::synchronous::basic::BasicMediator::dead_letters()) for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn dead_letters(&self) -> DeadLetters<Ev> {
+        self.basic.dead_letters().await
+    }
+
+    /// Publishes all events in the dead-letter queue again asynchronously, oldest first.
+    ///
+    /// See [`BasicMediator::redeliver_all()`](crate::synchronous::basic::BasicMediator::redeliver_all()) for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn redeliver_all(&self) -> usize {
+        self.basic.redeliver_all().await
+    }
+
+    /// Publishes the events in the dead-letter queue that are due according to the `policy` asynchronously.
+    ///
+    /// See [`BasicMediator::redeliver_with()`](crate::synchronous::basic::BasicMediator::redeliver_with()) for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn redeliver_with(&self, policy: RedeliveryPolicy) -> usize {
+        self.basic.redeliver_with(policy).await
+    }
+}
+
+#[cfg(feature = "persistence")]
+#[async_trait]
+impl<Cx, Ev> AsyncMediatorInternalJournal<Ev> for CxAwareAsyncMediator<Cx, Ev>
+where
+    Cx: Send + Sync,
+    Ev: MaybeDebug + Send + DeserializeOwned,
+{
+    /// Publishes all events recorded in the journal at `path` asynchronously, oldest first.
+    ///
+    /// See [`BasicMediator::replay_from()`](crate::synchronous::basic::BasicMediator::replay_from()) for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    async fn replay_from(&self, path: impl AsRef<Path> + Send) -> io::Result<usize> {
+        self.basic.replay_from(path).await
+    }
+
+    /// Publishes all events recorded in the journal at `path` in the format of the `codec`
+    /// asynchronously, oldest first.
+    ///
+    /// See [`BasicMediator::replay_from_with()`] for more info.
+    ///
+    async fn replay_from_with(
+        &self,
+        path: impl AsRef<Path> + Send,
+        codec: impl Codec,
+    ) -> io::Result<usize> {
+        self.basic.replay_from_with(path, codec).await
+    }
 }
@@ -0,0 +1,136 @@
+use std::fmt::Debug;
+
+use super::context::LazyContext;
+
+/// A tuple of contexts `T`, e.g. `Contexts<(Db, Cache, Config)>`, each locked independently.
+///
+/// A context aware async mediator locks its context `Cx` for every request,
+/// so bundling several dependencies into one tuple makes all requests contend on a single lock,
+/// even if they use different dependencies.
+/// Added with [`CxAwareAsyncBuilder::add_contexts()`](super::CxAwareAsyncBuilder::add_contexts()),
+/// the tuple itself is shared without locking and every element is a [`LazyContext`] of its own.
+/// Handlers lock only the elements they use, so requests using different elements are
+/// handled concurrently.
+///
+/// The elements are accessed with [`Contexts::split()`], which can be destructured,
+/// or one at a time with [`Contexts::get()`].
+///
+/// Note that locking an element while the same task holds it never completes,
+/// e.g. when a handler holding an element sends a request locking it again.
+///
+/// See [`CxAwareAsyncBuilder::add_contexts()`](super::CxAwareAsyncBuilder::add_contexts())
+/// for an example.
+///
+pub struct Contexts<T>(T::Contexts)
+where
+    T: ContextTuple;
+
+/// A tuple of contexts, which can be used in [`Contexts`].
+///
+/// Implemented for tuples with 2 to 6 elements.
+pub trait ContextTuple {
+    /// The tuple with a [`LazyContext`] for every element.
+    type Contexts;
+
+    #[allow(missing_docs)]
+    fn into_contexts(self) -> Self::Contexts;
+}
+
+/// The element at index `I` of [`Contexts`], see [`Contexts::get()`].
+pub trait ContextAt<const I: usize> {
+    /// The type of the element.
+    type Output;
+
+    #[allow(missing_docs)]
+    fn context_at(&self) -> &LazyContext<Self::Output>;
+}
+
+impl<T> Contexts<T>
+where
+    T: ContextTuple,
+{
+    /// Wraps every element of the tuple `cx` in a [`LazyContext`].
+    pub fn new(cx: T) -> Self {
+        Self(cx.into_contexts())
+    }
+
+    /// Returns the tuple of [`LazyContext`]s, one for every element.
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    ///
+    /// let cx = Contexts::new((1, "config"));
+    ///
+    /// async_std::task::block_on(async {
+    ///     let (counter, config) = cx.split();
+    ///     *counter.lock().await += 1;
+    ///     assert_eq!(*counter.read().await, 2);
+    ///     assert_eq!(*config.read().await, "config");
+    /// });
+    ///
+    pub fn split(&self) -> &T::Contexts {
+        &self.0
+    }
+
+    /// Returns the [`LazyContext`] of the element at index `I`.
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    ///
+    /// let cx = Contexts::new((1, "config"));
+    ///
+    /// async_std::task::block_on(async {
+    ///     *cx.get::<0>().lock().await += 1;
+    ///     assert_eq!(*cx.get::<0>().read().await, 2);
+    ///     assert_eq!(*cx.get::<1>().read().await, "config");
+    /// });
+    ///
+    pub fn get<const I: usize>(&self) -> &LazyContext<<Self as ContextAt<I>>::Output>
+    where
+        Self: ContextAt<I>,
+    {
+        self.context_at()
+    }
+}
+
+impl<T> Debug for Contexts<T>
+where
+    T: ContextTuple,
+    T::Contexts: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Contexts").field(&self.0).finish()
+    }
+}
+
+macro_rules! context_tuple {
+    ($($I:tt $T:ident),+) => {
+        impl<$($T),+> ContextTuple for ($($T,)+) {
+            type Contexts = ($(LazyContext<$T>,)+);
+
+            fn into_contexts(self) -> Self::Contexts {
+                ($(LazyContext::ready(self.$I),)+)
+            }
+        }
+
+        context_tuple!(@at ($($T),+) $($I $T),+);
+    };
+    (@at $all:tt $($I:tt $T:ident),+) => {
+        $(context_tuple!(@impl $all $I $T);)+
+    };
+    (@impl ($($T:ident),+) $I:tt $Output:ident) => {
+        impl<$($T),+> ContextAt<$I> for Contexts<($($T,)+)> {
+            type Output = $Output;
+
+            fn context_at(&self) -> &LazyContext<$Output> {
+                &self.0.$I
+            }
+        }
+    };
+}
+
+context_tuple!(0 A, 1 B);
+context_tuple!(0 A, 1 B, 2 C);
+context_tuple!(0 A, 1 B, 2 C, 3 D);
+context_tuple!(0 A, 1 B, 2 C, 3 D, 4 E);
+context_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
@@ -0,0 +1,193 @@
+use std::{fmt::Debug, time::Duration};
+
+use crate::mediator::channel::TrySendError;
+
+use crate::asynchronous::basic::BasicAsyncMediator;
+
+use super::*;
+
+/// A cheaply cloneable handle to a [`CxAwareAsyncMediator`]
+/// with a context `Cx` and events of type `Ev`.
+///
+/// Returned by [`CxAwareAsyncMediator::new_handle()`].
+/// All clones share the mediator and its context.
+///
+/// See [`crate::asynchronous::basic::MediatorHandle`] for more info.
+///
+#[derive(Debug)]
+pub struct CxAwareMediatorHandle<Cx, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    mediator: CxAwareAsyncMediator<Cx, Ev>,
+}
+
+impl<Cx, Ev> Clone for CxAwareMediatorHandle<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn clone(&self) -> Self {
+        Self::new(&self.mediator)
+    }
+}
+
+impl<Cx, Ev> CxAwareMediatorHandle<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    pub(crate) fn new(mediator: &CxAwareAsyncMediator<Cx, Ev>) -> Self {
+        Self {
+            mediator: CxAwareAsyncMediator {
+                basic: BasicAsyncMediator {
+                    inner: mediator.basic.inner.clone(),
+                },
+                cx: mediator.cx.clone(),
+                deferred: mediator.deferred.clone(),
+            },
+        }
+    }
+}
+
+impl<Cx, Ev> CxAwareMediatorHandle<Cx, Ev>
+where
+    Cx: Send + Sync,
+    Ev: MaybeDebug + Send,
+{
+    /// Send a request of type `Req` to the mediator asynchronously.
+    ///
+    /// See [`CxAwareAsyncMediator::send()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn send<Req>(&self, req: Req)
+    where
+        CxAwareAsyncMediator<Cx, Ev>: CxAwareAsyncRequestHandler<Cx, Req, Ev>,
+        Req: Send + 'static,
+    {
+        self.mediator.send(req).await
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously
+    /// and receive a response of type `Resp`.
+    ///
+    /// See [`CxAwareAsyncMediator::send_and_receive()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn send_and_receive<Req, Resp>(&self, req: Req) -> Result<Resp, ReentrantSendError>
+    where
+        CxAwareAsyncMediator<Cx, Ev>: CxAwareAsyncRequestHandler<Cx, Req, Ev, Resp>,
+        Req: Send,
+        Resp: Send,
+    {
+        self.mediator.send_and_receive(req).await
+    }
+
+    /// Queue a request of type `Req` to be handled by the running mediator.
+    ///
+    /// See [`CxAwareAsyncMediator::queue_request()`] for more info.
+    ///
+    pub fn queue_request<Req>(&self, req: Req)
+    where
+        CxAwareAsyncMediator<Cx, Ev>:
+            CxAwareAsyncRequestHandler<Cx, Req, Ev> + Send + Sync + 'static,
+        Req: Send + 'static,
+    {
+        self.mediator.queue_request(req)
+    }
+
+    /// Send a request of type `Req`, which mutates the context `Cx`, to the mediator asynchronously.
+    ///
+    /// See [`CxAwareAsyncMediator::send_mut()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn send_mut<Req>(&self, req: Req)
+    where
+        CxAwareAsyncMediator<Cx, Ev>: CxAwareAsyncMutRequestHandler<Cx, Req, Ev>,
+        Req: Send + 'static,
+    {
+        self.mediator.send_mut(req).await
+    }
+
+    /// Send a request of type `Req` to the mediator asynchronously
+    /// together with a per-request `scope`.
+    ///
+    /// See [`CxAwareAsyncMediator::send_with_scope()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn send_with_scope<Req, Scope>(&self, req: Req, scope: Scope)
+    where
+        CxAwareAsyncMediator<Cx, Ev>: ScopedCxAwareAsyncRequestHandler<Cx, Scope, Req, Ev>,
+        Req: Send + 'static,
+        Scope: Send + Sync + 'static,
+    {
+        self.mediator.send_with_scope(req, scope).await
+    }
+
+    /// Try to send a request of type `Req` to the mediator asynchronously.
+    ///
+    /// See [`CxAwareAsyncMediator::try_send()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn try_send<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        CxAwareAsyncMediator<Cx, Ev>: TryCxAwareAsyncRequestHandler<Cx, Req, Ev, Err>,
+        Req: Send + 'static,
+        Err: From<ReentrantSendError> + Send,
+    {
+        self.mediator.try_send(req).await
+    }
+
+    /// Publishes an event `Ev` asynchronously.
+    ///
+    /// See [`CxAwareAsyncMediator::publish()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn publish(&self, event: Ev) {
+        self.mediator.publish(event).await
+    }
+
+    /// Tries to publish an event `Ev` asynchronously.
+    ///
+    /// See [`CxAwareAsyncMediator::try_publish()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn try_publish(&self, event: Ev) -> Result<(), TrySendError<Ev>> {
+        self.mediator.try_publish(event).await
+    }
+
+    /// Publishes an event `Ev` to the given `topic` asynchronously.
+    ///
+    /// See [`CxAwareAsyncMediator::publish_to()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn publish_to(&self, topic: &str, event: Ev) {
+        self.mediator.publish_to(topic, event).await
+    }
+
+    /// Publishes an event `Ev` asynchronously that expires once the `ttl` elapsed.
+    ///
+    /// See [`CxAwareAsyncMediator::publish_with_ttl()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn publish_with_ttl(&self, event: Ev, ttl: Duration) {
+        self.mediator.publish_with_ttl(event, ttl).await
+    }
+
+    /// Publishes an urgent event `Ev` asynchronously, which is delivered before all regular events.
+    ///
+    /// See [`CxAwareAsyncMediator::publish_urgent()`] for more info.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn publish_urgent(&self, event: Ev) {
+        self.mediator.publish_urgent(event).await
+    }
+}
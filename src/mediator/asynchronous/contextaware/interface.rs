@@ -1,32 +1,224 @@
 use async_trait::async_trait;
-use std::fmt::Debug;
+use std::{any::Any, future::Future, sync::Arc, time::Duration};
+
+use crate::mediator::{
+    asynchronous::{
+        basic::interface::AsyncMediatorInternalShare,
+        contextaware::context::{LazyContext, ReentrantSendError},
+    },
+    cancellation::{CancellationToken, CancelledError, TimeoutError},
+    correlation::CorrelationId,
+    listener::MaybeDebug,
+    queue::Priority,
+    worker::JoinHandle,
+};
 
 /// Send a request `Req` asynchronously for processing to the mediator.
 /// This will call the handler.
 /// The handler here is context-dependent.
 #[async_trait]
-pub trait CxAwareAsyncMediatorInternalHandle<Cx, Ev: Debug> {
+pub trait CxAwareAsyncMediatorInternalHandle<Cx, Ev: MaybeDebug> {
     #[allow(missing_docs)]
     async fn send<Req>(&self, req: Req)
+    where
+        Req: Send + 'static,
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev>;
+
+    #[allow(missing_docs)]
+    fn send_detached<Req>(&self, req: Req) -> JoinHandle<()>
+    where
+        Req: Send + 'static,
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev> + Send + Sync + 'static;
+
+    #[allow(missing_docs)]
+    async fn send_traced<Req>(&self, req: Req) -> CorrelationId
+    where
+        Req: Send + 'static,
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev>;
+
+    #[allow(missing_docs)]
+    async fn send_and_receive<Req, Resp>(&self, req: Req) -> Result<Resp, ReentrantSendError>
     where
         Req: Send,
+        Resp: Send,
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev, Resp>;
+
+    #[allow(missing_docs)]
+    async fn send_dyn(&self, req: Box<dyn Any + Send>) -> Result<(), Box<dyn Any + Send>>
+    where
+        Self: Sync + 'static;
+
+    #[allow(missing_docs)]
+    async fn try_send<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Req: Send + 'static,
+        Err: From<ReentrantSendError> + Send,
+        Self: TryCxAwareAsyncRequestHandler<Cx, Req, Ev, Err>;
+
+    #[allow(missing_docs)]
+    async fn send_mut<Req>(&self, req: Req)
+    where
+        Req: Send + 'static,
+        Self: CxAwareAsyncMutRequestHandler<Cx, Req, Ev>;
+
+    #[allow(missing_docs)]
+    async fn send_with_scope<Req, Scope>(&self, req: Req, scope: Scope)
+    where
+        Req: Send + 'static,
+        Scope: Send + Sync + 'static,
+        Self: ScopedCxAwareAsyncRequestHandler<Cx, Scope, Req, Ev>;
+
+    #[allow(missing_docs)]
+    async fn try_send_with_retry<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Req: Clone + Send + Sync + 'static,
+        Ev: Send,
+        Err: From<ReentrantSendError> + Send,
+        Self: TryCxAwareAsyncRequestHandler<Cx, Req, Ev, Err>;
+
+    #[allow(missing_docs)]
+    async fn send_with_cancellation<Req>(
+        &self,
+        req: Req,
+        token: &CancellationToken,
+    ) -> Result<(), CancelledError>
+    where
+        Req: Send + 'static,
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev>;
+
+    #[allow(missing_docs)]
+    async fn send_with_timeout<Req>(
+        &self,
+        req: Req,
+        duration: Duration,
+    ) -> Result<(), TimeoutError>
+    where
+        Req: Send + 'static,
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev>;
+
+    #[allow(missing_docs)]
+    async fn send_with_priority<Req>(&self, req: Req, priority: Priority)
+    where
+        Req: Send + 'static,
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev> + Send + Sync + 'static;
+
+    #[allow(missing_docs)]
+    fn queue_request<Req>(&self, req: Req)
+    where
+        Req: Send + 'static,
+        Self: CxAwareAsyncRequestHandler<Cx, Req, Ev> + Send + Sync + 'static;
+
+    #[allow(missing_docs)]
+    async fn defer_send<Req>(&self, req: Req)
+    where
+        Req: Send + 'static,
         Self: CxAwareAsyncRequestHandler<Cx, Req, Ev>;
 }
 
 /// Handles the request `Req` asynchronously.
 /// Implemented by the user.
 /// Gives access to the context `Cx`.
+/// Returns a response of type `Resp`, which defaults to `()`.
+#[async_trait]
+pub trait CxAwareAsyncRequestHandler<Cx, Req, Ev, Resp = ()> {
+    #[allow(missing_docs)]
+    async fn handle(&self, req: Req, cx: &Cx) -> Resp;
+}
+
+/// Handles the request `Req` asynchronously, sent with `send_mut()`.
+/// Implemented by the user.
+/// Gives exclusive access to the context `Cx`, which may be mutated.
+#[async_trait]
+pub trait CxAwareAsyncMutRequestHandler<Cx, Req, Ev> {
+    #[allow(missing_docs)]
+    async fn handle(&self, req: Req, cx: &mut Cx);
+}
+
+/// Handles the request `Req` asynchronously, sent with a per-request scope of type `Scope`.
+/// Implemented by the user.
+/// Gives access to the context `Cx` and the scope, e.g. the authenticated user.
+#[async_trait]
+pub trait ScopedCxAwareAsyncRequestHandler<Cx, Scope, Req, Ev> {
+    #[allow(missing_docs)]
+    async fn handle(&self, req: Req, cx: &Cx, scope: &Scope);
+}
+
+/// Handles the request `Req` asynchronously, which may fail with an error of type `Err`.
+/// Implemented by the user.
+/// Gives access to the context `Cx`.
 #[async_trait]
-pub trait CxAwareAsyncRequestHandler<Cx, Req, Res> {
+pub trait TryCxAwareAsyncRequestHandler<Cx, Req, Ev, Err> {
     #[allow(missing_docs)]
-    async fn handle(&self, req: Req, cx: &Cx);
+    async fn handle(&self, req: Req, cx: &Cx) -> Result<(), Err>;
 }
 
-/// Advanced builder fuctionality:
-/// Adding a context `cx` to the builder.
-pub trait CxAwareMediatorBuilderInterface<M, Cx, Ev> {
+/// Lazy context builder fuctionality:
+/// Adding a factory creating the context `Cx` on first use.
+pub trait CxAwareAsyncLazyContextBuilderInterface<M, Cx> {
+    #[allow(missing_docs)]
+    fn add_context_with<F, Fut>(self, f: F) -> Self
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Cx> + Send + 'static;
+}
+
+/// Shared context builder fuctionality:
+/// Adding an immutable context `Cx` shared without locking.
+pub trait CxAwareAsyncSharedContextBuilderInterface<M, Cx> {
+    #[allow(missing_docs)]
+    fn add_shared_context(self, cx: Arc<Cx>) -> Self;
+}
+
+/// Contexts builder fuctionality:
+/// Adding a tuple of contexts `T` to a [`crate::asynchronous::contextaware::Contexts`] context,
+/// each locked independently.
+pub trait CxAwareAsyncContextsBuilderInterface<M, T> {
+    #[allow(missing_docs)]
+    fn add_contexts(self, cx: T) -> Self;
+}
+
+/// Read-write context builder fuctionality:
+/// Letting requests that only read the context `Cx` share it.
+pub trait CxAwareAsyncReadWriteContextBuilderInterface<M> {
+    #[allow(missing_docs)]
+    fn read_write_context(self) -> Self;
+}
+
+/// Register a handler for requests `Req` sent to the mediator `M` via `send_dyn()`.
+/// The handler receives a handle to the mediator, see [`AsyncMediatorInternalShare`],
+/// and the shared context `Cx`.
+pub trait CxAwareAsyncDynamicHandlerBuilderInterface<M: AsyncMediatorInternalShare, Cx, Ev> {
+    #[allow(missing_docs)]
+    fn register_handler<Req, Fut>(
+        self,
+        handler: impl Fn(M::Handle, Req, Arc<LazyContext<Cx>>) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static;
+}
+
+/// Closure handler builder fuctionality:
+/// Adding a closure handling requests `Req` sent to the mediator `M` via `send_registered()`.
+/// The closure receives a handle to the mediator, see [`AsyncMediatorInternalShare`],
+/// and the shared context `Cx`.
+pub trait CxAwareAsyncClosureHandlerBuilderInterface<M: AsyncMediatorInternalShare, Cx, Ev> {
+    #[allow(missing_docs)]
+    fn add_request_handler<Req, Resp, Fut>(
+        self,
+        handler: impl Fn(M::Handle, Req, Arc<LazyContext<Cx>>) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Resp: Send + 'static,
+        Fut: Future<Output = Resp> + Send + 'static;
+
     #[allow(missing_docs)]
-    fn add_context(self, cx: Cx) -> Self
+    fn add_fan_out_handler<Req, Fut>(
+        self,
+        handler: impl Fn(M::Handle, Arc<Req>, Arc<LazyContext<Cx>>) -> Fut + Send + Sync + 'static,
+    ) -> Self
     where
-        Ev: Debug;
+        Req: Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static;
 }
@@ -1,13 +1,76 @@
 pub(crate) mod builder;
+pub(crate) mod context;
+#[allow(clippy::module_inception)]
 pub(crate) mod contextaware;
+pub(crate) mod contexts;
+pub(crate) mod handle;
 pub(crate) mod interface;
+pub(crate) mod scope;
 
 pub use builder::*;
+pub use context::{ContextGuard, ContextReadGuard, LazyContext, ReentrantSendError};
 pub use contextaware::*;
+pub use contexts::{ContextAt, ContextTuple, Contexts};
+pub use handle::*;
 pub use interface::*;
+pub use scope::*;
 
-pub use crate::builder::{TryBuilderFlow, TryBuilderInternal};
+pub use crate::behavior::*;
+pub use crate::builder::{BuildError, TryBuilderFlow, TryBuilderInternal};
+pub use crate::cancellation::{CancellationToken, CancelledError, TimeoutError};
+#[cfg(feature = "causality")]
+pub use crate::causality::{CausalityGraph, CausalityNode};
+pub use crate::channel::OverflowPolicy;
+pub use crate::correlation::{CorrelationId, Enveloped, EventMeta};
+pub use crate::deadletter::{DeadLetter, DeadLetterReason, DeadLetters, RedeliveryPolicy};
+pub use crate::dependencies::*;
+pub use crate::lifecycle::CxLifecycle;
 pub use crate::listener::*;
+pub use crate::mediator::asynchronous::basic::basic::EventStream;
+#[cfg(feature = "causality")]
+pub use crate::mediator::asynchronous::basic::interface::AsyncMediatorInternalCausality;
+#[cfg(feature = "schema")]
+pub use crate::mediator::asynchronous::basic::interface::AsyncMediatorInternalDescribe;
+#[cfg(feature = "persistence")]
+pub use crate::mediator::asynchronous::basic::interface::AsyncMediatorInternalJournal;
 pub use crate::mediator::asynchronous::basic::interface::{
-    AsyncMediatorInternal, AsyncMediatorInternalNext,
+    AsyncBehaviorMediatorBuilderInterface, AsyncDebounceBuilderInterface,
+    AsyncFilterMediatorBuilderInterface, AsyncMediatorBuilderInterface, AsyncMediatorInternal,
+    AsyncMediatorInternalDeadLetters, AsyncMediatorInternalNext, AsyncMediatorInternalPause,
+    AsyncMediatorInternalRegistered, AsyncMediatorInternalRun, AsyncMediatorInternalShare,
+    AsyncMediatorInternalStats, AsyncMediatorInternalStream, AsyncMediatorInternalSubscribe,
+    AsyncNamedListenerBuilderInterface, AsyncPriorityMediatorBuilderInterface,
+    AsyncRetryMediatorBuilderInterface, AsyncSpawnedListenerBuilderInterface,
+    AsyncTickerBuilderInterface, AsyncTopicMediatorBuilderInterface,
 };
+#[cfg(feature = "causality")]
+pub use crate::mediator::synchronous::basic::interface::CausalityBuilderInterface;
+#[cfg(feature = "persistence")]
+pub use crate::mediator::synchronous::basic::interface::UpcasterBuilderInterface;
+pub use crate::mediator::synchronous::basic::interface::{
+    CatchAllMediatorBuilderInterface, ChannelBackendBuilderInterface,
+    ChannelMediatorBuilderInterface, DeadLetterMediatorBuilderInterface,
+    EnvelopedMediatorBuilderInterface, FilterMediatorBuilderInterface,
+    HandlerMediatorBuilderInterface, InterceptorBuilderInterface,
+    ListenerIsolationBuilderInterface, NamedListenerBuilderInterface,
+    ObserverMediatorBuilderInterface, PriorityMediatorBuilderInterface, ProfileBuilderInterface,
+    RateLimitBuilderInterface, ReplayMediatorBuilderInterface, RequestEventsBuilderInterface,
+    TopicMediatorBuilderInterface,
+};
+pub use crate::mediator::synchronous::contextaware::interface::{
+    CxAwareDependencyBuilderInterface, CxAwareLifecycleBuilderInterface,
+    CxAwareMediatorBuilderInterface, CxAwareMediatorInternalShutdown, StrictBuilderInterface,
+};
+pub use crate::observer::{MediatorObserver, RequestMeta};
+pub use crate::ordering::Ordering;
+#[cfg(feature = "persistence")]
+pub use crate::persistence::{Journal, JournalEntry};
+pub use crate::profile::Profile;
+pub use crate::queue::Priority;
+#[cfg(feature = "std")]
+pub use crate::ratelimit::{RateLimit, ThrottlePolicy};
+pub use crate::retry::{RetryExhausted, RetryPolicy};
+#[cfg(feature = "schema")]
+pub use crate::schema::{ListenerDescription, MediatorDescription};
+pub use crate::stats::{ListenerStats, MediatorStats};
+pub use crate::worker::{JoinHandle, Worker};
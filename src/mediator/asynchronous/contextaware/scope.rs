@@ -0,0 +1,216 @@
+use std::{fmt::Debug, future::Future, mem, ops::Deref, sync::Arc};
+
+use crate::mediator::{sync::Mutex, worker::JoinHandle};
+
+use super::*;
+
+/// The background tasks started through a [`MediatorScope`].
+#[derive(Debug, Default)]
+struct Tasks {
+    workers: Vec<Worker>,
+    detached: Vec<JoinHandle<()>>,
+    closed: bool,
+}
+
+/// A [`CxAwareAsyncMediator`] owned by a scope, created with [`CxAwareAsyncMediator::scope()`].
+///
+/// Dereferences to the mediator, so requests are sent and events are published as usual.
+/// Workers spawned with [`MediatorScope::spawn_worker()`] and requests sent with
+/// [`MediatorScope::send_detached()`] are tied to the scope:
+/// before the scope returns, all detached requests are awaited
+/// and all workers are shut down, processing the pending events and stopping their tickers.
+/// Lastly, the mediator is shut down, see `shutdown()`.
+///
+/// Clones of a [`MediatorScope`] refer to the same scope, e.g. to move them into other tasks.
+/// Once the scope returned, starting further background tasks through it panics.
+///
+pub struct MediatorScope<Cx, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    mediator: Arc<CxAwareAsyncMediator<Cx, Ev>>,
+    tasks: Arc<Mutex<Tasks>>,
+}
+
+impl<Cx, Ev> MediatorScope<Cx, Ev>
+where
+    Cx: Send + Sync + 'static,
+    Ev: MaybeDebug + Send + Sync + 'static,
+{
+    /// Spawns a [`Worker`] processing published events `Ev` on a background task,
+    /// which is shut down before the scope returns.
+    ///
+    /// See [`CxAwareAsyncMediator::spawn_worker()`] for more info.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scope already returned.
+    ///
+    pub fn spawn_worker(&self) {
+        let worker = self.mediator.clone().spawn_worker();
+        self.track(|tasks| tasks.workers.push(worker));
+    }
+
+    /// Send a request of type `Req` to the mediator without waiting for it to be handled.
+    /// The request is handled before the scope returns.
+    ///
+    /// See [`CxAwareAsyncMediator::send_detached()`] for more info.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scope already returned.
+    ///
+    pub fn send_detached<Req>(&self, req: Req)
+    where
+        CxAwareAsyncMediator<Cx, Ev>: CxAwareAsyncRequestHandler<Cx, Req, Ev>,
+        Req: Send + 'static,
+    {
+        let handle = self.mediator.send_detached(req);
+        self.track(|tasks| tasks.detached.push(handle));
+    }
+
+    fn track(&self, f: impl FnOnce(&mut Tasks)) {
+        let mut tasks = self.tasks.lock();
+        assert!(!tasks.closed, "the scope of the mediator already returned");
+        f(&mut tasks);
+    }
+
+    /// Awaits all detached requests, shuts down all workers and lastly the mediator.
+    async fn close(&self) {
+        // Detached requests may publish events or start further tasks, so they go first.
+        let workers = loop {
+            let detached = {
+                let mut tasks = self.tasks.lock();
+                if tasks.detached.is_empty() {
+                    tasks.closed = true;
+                    break mem::take(&mut tasks.workers);
+                }
+                mem::take(&mut tasks.detached)
+            };
+            for handle in detached {
+                handle.await;
+            }
+        };
+        for worker in workers {
+            worker.shutdown().await;
+        }
+        self.mediator.shutdown().await;
+    }
+}
+
+impl<Cx, Ev> CxAwareAsyncMediator<Cx, Ev>
+where
+    Cx: Send + Sync + 'static,
+    Ev: MaybeDebug + Send + Sync + 'static,
+{
+    /// Runs the future returned by `f` with a [`CxAwareAsyncMediator`]
+    /// holding the context `cx`, owned by a [`MediatorScope`].
+    ///
+    /// All background tasks started through the scope finish
+    /// before this returns the output of the future, so none of them leak.
+    /// Use [`CxAwareAsyncMediator::scoped()`] for a mediator configured through its builder.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::asynchronous::contextaware::*;
+    /// use async_trait::async_trait;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyEvent {
+    ///     Saved(u32)
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct Db;
+    ///
+    /// struct Save(u32);
+    ///
+    /// #[async_trait]
+    /// impl CxAwareAsyncRequestHandler<Db, Save, MyEvent> for CxAwareAsyncMediator<Db, MyEvent> {
+    ///     async fn handle(&self, req: Save, _db: &Db) {
+    ///         self.publish(MyEvent::Saved(req.0)).await;
+    ///     }
+    /// }
+    ///
+    /// async_std::task::block_on(async {
+    ///     let saved = CxAwareAsyncMediator::<Db, MyEvent>::scope(Db, |scope| async move {
+    ///         scope.spawn_worker();
+    ///         scope.send_detached(Save(1));
+    ///         scope.send_detached(Save(2));
+    ///         2
+    ///     })
+    ///     .await;
+    ///
+    ///     // Both requests were handled and their events processed by now.
+    ///     assert_eq!(saved, 2);
+    /// });
+    ///
+    pub async fn scope<F, Fut>(cx: Cx, f: F) -> Fut::Output
+    where
+        F: FnOnce(MediatorScope<Cx, Ev>) -> Fut,
+        Fut: Future,
+    {
+        let mediator = Self::builder()
+            .add_context(cx)
+            .build()
+            .expect("a mediator with a context always builds");
+        mediator.scoped(f).await
+    }
+
+    /// Same as [`CxAwareAsyncMediator::scope()`], but takes ownership of an already built mediator.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn scoped<F, Fut>(self, f: F) -> Fut::Output
+    where
+        F: FnOnce(MediatorScope<Cx, Ev>) -> Fut,
+        Fut: Future,
+    {
+        let scope = MediatorScope {
+            mediator: Arc::new(self),
+            tasks: Arc::default(),
+        };
+        let output = f(scope.clone()).await;
+        scope.close().await;
+        output
+    }
+}
+
+impl<Cx, Ev> Clone for MediatorScope<Cx, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            mediator: self.mediator.clone(),
+            tasks: self.tasks.clone(),
+        }
+    }
+}
+
+impl<Cx, Ev> Deref for MediatorScope<Cx, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    type Target = CxAwareAsyncMediator<Cx, Ev>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.mediator
+    }
+}
+
+impl<Cx, Ev> Debug for MediatorScope<Cx, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MediatorScope")
+            .field("tasks", &self.tasks)
+            .finish_non_exhaustive()
+    }
+}
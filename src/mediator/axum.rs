@@ -0,0 +1,216 @@
+use ::axum::{
+    extract::FromRequestParts,
+    http::{request::Parts, Request, StatusCode},
+    response::{IntoResponse, Response},
+};
+use std::{
+    fmt::Debug,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::mediator::{asynchronous::contextaware::CxAwareAsyncMediator, listener::MaybeDebug};
+
+/// A tower [`Layer`] injecting a shared [`CxAwareAsyncMediator`] into the extensions of every request,
+/// to be taken by HTTP handlers with the [`Mediator`] extractor.
+///
+/// The layer only shares the mediator, events still have to be processed,
+/// e.g. with `run()` on a spawned task.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::asynchronous::contextaware::*;
+/// use mediatrix::axum::{Mediator, MediatorLayer};
+/// use async_trait::async_trait;
+/// use axum::{routing::post, Router};
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     Created(u32)
+/// }
+///
+/// struct Database;
+///
+/// struct Create(u32);
+///
+/// #[async_trait]
+/// impl CxAwareAsyncRequestHandler<Database, Create, MyEvent> for CxAwareAsyncMediator<Database, MyEvent> {
+///     async fn handle(&self, req: Create, _db: &Database) {
+///         self.publish(MyEvent::Created(req.0)).await;
+///     }
+/// }
+///
+/// async fn create(Mediator(m): Mediator<Database, MyEvent>) {
+///     m.send(Create(1)).await;
+/// }
+///
+/// let mediator = CxAwareAsyncMediator::<Database, MyEvent>::builder()
+///     .add_context(Database)
+///     .build()
+///     .unwrap();
+///
+/// let app: Router = Router::new()
+///     .route("/create", post(create))
+///     .layer(MediatorLayer::new(mediator));
+///
+pub struct MediatorLayer<Cx, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    mediator: Arc<CxAwareAsyncMediator<Cx, Ev>>,
+}
+
+impl<Cx, Ev> MediatorLayer<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Creates a [`MediatorLayer`] sharing the `mediator` with all requests.
+    pub fn new(mediator: impl Into<Arc<CxAwareAsyncMediator<Cx, Ev>>>) -> Self {
+        Self {
+            mediator: mediator.into(),
+        }
+    }
+}
+
+impl<Cx, Ev> Clone for MediatorLayer<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn clone(&self) -> Self {
+        Self {
+            mediator: self.mediator.clone(),
+        }
+    }
+}
+
+impl<Cx, Ev> Debug for MediatorLayer<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MediatorLayer").finish_non_exhaustive()
+    }
+}
+
+impl<S, Cx, Ev> Layer<S> for MediatorLayer<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    type Service = MediatorService<S, Cx, Ev>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MediatorService {
+            inner,
+            mediator: self.mediator.clone(),
+        }
+    }
+}
+
+/// The tower [`Service`] returned by [`MediatorLayer`].
+pub struct MediatorService<S, Cx, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    inner: S,
+    mediator: Arc<CxAwareAsyncMediator<Cx, Ev>>,
+}
+
+impl<S, Cx, Ev> Clone for MediatorService<S, Cx, Ev>
+where
+    S: Clone,
+    Ev: MaybeDebug,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            mediator: self.mediator.clone(),
+        }
+    }
+}
+
+impl<S, Cx, Ev> Debug for MediatorService<S, Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MediatorService").finish_non_exhaustive()
+    }
+}
+
+impl<S, Cx, Ev, B> Service<Request<B>> for MediatorService<S, Cx, Ev>
+where
+    S: Service<Request<B>>,
+    Cx: Send + Sync + 'static,
+    Ev: MaybeDebug + Send + Sync,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<B>) -> Self::Future {
+        req.extensions_mut().insert(self.mediator.clone());
+        self.inner.call(req)
+    }
+}
+
+/// An axum extractor taking the [`CxAwareAsyncMediator`] injected by a [`MediatorLayer`].
+///
+/// Rejects the request with [`MissingMediator`] if no [`MediatorLayer`]
+/// with the same context `Cx` and events of type `Ev` was added.
+///
+/// See [`MediatorLayer`] for an example.
+///
+pub struct Mediator<Cx, Ev>(pub Arc<CxAwareAsyncMediator<Cx, Ev>>)
+where
+    Ev: MaybeDebug + 'static;
+
+impl<Cx, Ev> Debug for Mediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Mediator").finish_non_exhaustive()
+    }
+}
+
+impl<S, Cx, Ev> FromRequestParts<S> for Mediator<Cx, Ev>
+where
+    S: Send + Sync,
+    Cx: Send + Sync + 'static,
+    Ev: MaybeDebug + Send + Sync,
+{
+    type Rejection = MissingMediator;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<Arc<CxAwareAsyncMediator<Cx, Ev>>>()
+            .cloned()
+            .map(Mediator)
+            .ok_or(MissingMediator)
+    }
+}
+
+/// The rejection of the [`Mediator`] extractor if no [`MediatorLayer`] was added,
+/// responding with `500 Internal Server Error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingMediator;
+
+impl IntoResponse for MissingMediator {
+    fn into_response(self) -> Response {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Missing mediator, add a MediatorLayer to the router",
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,187 @@
+use alloc::boxed::Box;
+use core::any::{type_name, Any};
+
+#[cfg(feature = "async")]
+use async_trait::async_trait;
+#[cfg(feature = "async")]
+use std::{future::Future, pin::Pin};
+
+/// A type-erased request passing through the pipeline of [`Behavior`]s.
+///
+/// The original request can be inspected with [`AnyRequest::downcast_ref()`]
+/// and modified with [`AnyRequest::downcast_mut()`].
+#[derive(Debug)]
+pub struct AnyRequest {
+    type_name: &'static str,
+    inner: Box<dyn Any + Send>,
+}
+
+impl AnyRequest {
+    pub(crate) fn new<Req>(req: Req) -> Self
+    where
+        Req: Send + 'static,
+    {
+        Self {
+            type_name: type_name::<Req>(),
+            inner: Box::new(req),
+        }
+    }
+
+    pub(crate) fn into_inner<Req>(self) -> Req
+    where
+        Req: 'static,
+    {
+        *self
+            .inner
+            .downcast()
+            .expect("request type changed within the pipeline")
+    }
+
+    /// Returns the type name of the original request.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns a reference to the original request, if it is of type `Req`.
+    pub fn downcast_ref<Req>(&self) -> Option<&Req>
+    where
+        Req: 'static,
+    {
+        self.inner.downcast_ref()
+    }
+
+    /// Returns a mutable reference to the original request, if it is of type `Req`.
+    pub fn downcast_mut<Req>(&mut self) -> Option<&mut Req>
+    where
+        Req: 'static,
+    {
+        self.inner.downcast_mut()
+    }
+}
+
+/// The remaining pipeline after a [`Behavior`].
+///
+/// Running it passes the request on to the next [`Behavior`]
+/// or, at the end of the pipeline, to the request handler.
+pub struct Next<'a> {
+    f: Box<dyn FnOnce(AnyRequest) + 'a>,
+}
+
+impl Next<'_> {
+    /// Passes the request on to the rest of the pipeline.
+    pub fn run(self, req: AnyRequest) {
+        (self.f)(req)
+    }
+}
+
+/// A [`Behavior`] wraps the handling of every request sent to a mediator.
+///
+/// Behaviors are invoked in the order they were added to the builder.
+/// Each behavior decides whether to continue by calling [`Next::run()`],
+/// or to short-circuit the request by not calling it.
+/// This is useful for cross-cutting concerns like logging, validation, timing or authorization.
+///
+/// Behaviors wrap requests sent via `send()`.
+/// Requests sent via `send_and_receive()` are not wrapped,
+/// because a short-circuited request could not produce a response.
+pub trait Behavior: Send + Sync + 'static {
+    #[allow(missing_docs)]
+    fn handle(&self, req: AnyRequest, next: Next<'_>);
+}
+
+impl core::fmt::Debug for dyn Behavior {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Behavior")
+    }
+}
+
+pub(crate) fn run_pipeline<'a>(
+    behaviors: &'a [Box<dyn Behavior>],
+    req: AnyRequest,
+    handler: impl FnOnce(AnyRequest) + 'a,
+) {
+    run_boxed_pipeline(behaviors, req, Box::new(handler))
+}
+
+fn run_boxed_pipeline<'a>(
+    behaviors: &'a [Box<dyn Behavior>],
+    req: AnyRequest,
+    handler: Box<dyn FnOnce(AnyRequest) + 'a>,
+) {
+    match behaviors.split_first() {
+        Some((behavior, rest)) => behavior.handle(
+            req,
+            Next {
+                f: Box::new(move |req| run_boxed_pipeline(rest, req, handler)),
+            },
+        ),
+        None => handler(req),
+    }
+}
+
+#[cfg(feature = "async")]
+type BoxFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+/// The remaining pipeline after an [`AsyncBehavior`].
+///
+/// Running it passes the request on to the next [`AsyncBehavior`]
+/// or, at the end of the pipeline, to the request handler.
+#[cfg(feature = "async")]
+pub struct AsyncNext<'a> {
+    f: Box<dyn FnOnce(AnyRequest) -> BoxFuture<'a> + Send + 'a>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncNext<'_> {
+    /// Passes the request on to the rest of the pipeline asynchronously.
+    pub async fn run(self, req: AnyRequest) {
+        (self.f)(req).await
+    }
+}
+
+/// An [`AsyncBehavior`] wraps the asynchronous handling of every request sent to a mediator.
+///
+/// This is the asynchronous counterpart to [`Behavior`].
+#[cfg(feature = "async")]
+#[async_trait]
+pub trait AsyncBehavior: Send + Sync + 'static {
+    #[allow(missing_docs)]
+    async fn handle(&self, req: AnyRequest, next: AsyncNext<'_>);
+}
+
+#[cfg(feature = "async")]
+impl std::fmt::Debug for dyn AsyncBehavior {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AsyncBehavior")
+    }
+}
+
+#[cfg(feature = "async")]
+pub(crate) fn run_async_pipeline<'a, F, Fut>(
+    behaviors: &'a [Box<dyn AsyncBehavior>],
+    req: AnyRequest,
+    handler: F,
+) -> BoxFuture<'a>
+where
+    F: FnOnce(AnyRequest) -> Fut + Send + 'a,
+    Fut: Future<Output = ()> + Send + 'a,
+{
+    run_boxed_async_pipeline(behaviors, req, Box::new(move |req| Box::pin(handler(req))))
+}
+
+#[cfg(feature = "async")]
+fn run_boxed_async_pipeline<'a>(
+    behaviors: &'a [Box<dyn AsyncBehavior>],
+    req: AnyRequest,
+    handler: Box<dyn FnOnce(AnyRequest) -> BoxFuture<'a> + Send + 'a>,
+) -> BoxFuture<'a> {
+    match behaviors.split_first() {
+        Some((behavior, rest)) => behavior.handle(
+            req,
+            AsyncNext {
+                f: Box::new(move |req| run_boxed_async_pipeline(rest, req, handler)),
+            },
+        ),
+        None => handler(req),
+    }
+}
@@ -0,0 +1,143 @@
+use alloc::sync::{Arc, Weak};
+
+use crate::mediator::{
+    listener::{ListenerHandle, MaybeDebug},
+    synchronous::basic::interface::{SyncMediatorInternal, SyncMediatorInternalSubscribe},
+};
+
+/// Connects two mediators: every event `EvA` processed by the `source` mediator
+/// is transformed into an `EvB` by `map` and published on the `target` mediator.
+///
+/// The mapping runs in a listener of the `source`, so during its `next()`.
+/// The mapped events are processed once the `target` processes its own events.
+/// This is useful for layering domain mediators under an application-level mediator.
+/// The `target` is referenced weakly, the bridge doesn't keep it alive.
+/// Unsubscribe the returned [`ListenerHandle`] from the `source` to remove the bridge.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use mediatrix::bridge::bridge;
+/// use std::sync::Arc;
+///
+/// #[derive(Debug)]
+/// enum OrderEvent {
+///     Placed(u32)
+/// }
+///
+/// #[derive(Debug)]
+/// enum AppEvent {
+///     Order(u32)
+/// }
+///
+/// let orders = BasicMediator::<OrderEvent>::builder().build();
+/// let app = Arc::new(BasicMediator::<AppEvent>::builder().build());
+///
+/// bridge(&orders, &app, |ev: &OrderEvent| match ev {
+///     OrderEvent::Placed(id) => AppEvent::Order(*id),
+/// });
+///
+/// orders.publish(OrderEvent::Placed(7));
+/// assert_eq!(orders.next_all(), 1);
+/// assert_eq!(app.next_all(), 1);
+///
+pub fn bridge<A, B, EvA, EvB>(
+    source: &A,
+    target: &Arc<B>,
+    map: impl Fn(&EvA) -> EvB + Send + Sync + 'static,
+) -> ListenerHandle
+where
+    A: SyncMediatorInternalSubscribe<EvA>,
+    B: SyncMediatorInternal<EvB> + Send + Sync + 'static,
+    EvA: MaybeDebug,
+    EvB: MaybeDebug,
+{
+    let target: Weak<B> = Arc::downgrade(target);
+    source.subscribe(move |ev: &EvA| {
+        if let Some(target) = target.upgrade() {
+            target.publish(map(ev));
+        }
+    })
+}
+
+/// Nests mediators: a parent mediator with events `Ev` receives the events of its children.
+///
+/// Every event `EvC` processed by a child is passed to the mapper,
+/// the event returned by it, if any, bubbles up and is published on the parent.
+/// This supports modular applications with a mediator per module and a global bus.
+/// Like a [`bridge()`], the child references the parent weakly.
+/// Unsubscribe the returned [`ListenerHandle`] from the child to detach it.
+///
+/// Implemented for every mediator, so importing the trait suffices.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use mediatrix::bridge::ParentMediator;
+/// use std::sync::Arc;
+///
+/// #[derive(Debug)]
+/// enum OrderEvent {
+///     Placed(u32),
+///     Validated(u32)
+/// }
+///
+/// #[derive(Debug)]
+/// enum AppEvent {
+///     Order(u32)
+/// }
+///
+/// let app = Arc::new(BasicMediator::<AppEvent>::builder().build());
+/// let orders = BasicMediator::<OrderEvent>::builder().build();
+///
+/// // Only placed orders bubble up to the global bus.
+/// app.attach_child(&orders, |ev: &OrderEvent| match ev {
+///     OrderEvent::Placed(id) => Some(AppEvent::Order(*id)),
+///     OrderEvent::Validated(_) => None,
+/// });
+///
+/// orders.publish(OrderEvent::Placed(7));
+/// orders.publish(OrderEvent::Validated(7));
+/// assert_eq!(orders.next_all(), 2);
+/// assert_eq!(app.next_all(), 1);
+///
+pub trait ParentMediator<Ev: MaybeDebug> {
+    #[allow(missing_docs)]
+    fn attach_child<C, EvC>(
+        self: &Arc<Self>,
+        child: &C,
+        map: impl Fn(&EvC) -> Option<Ev> + Send + Sync + 'static,
+    ) -> ListenerHandle
+    where
+        C: SyncMediatorInternalSubscribe<EvC>,
+        EvC: MaybeDebug;
+}
+
+impl<M, Ev> ParentMediator<Ev> for M
+where
+    M: SyncMediatorInternal<Ev> + Send + Sync + 'static,
+    Ev: MaybeDebug,
+{
+    fn attach_child<C, EvC>(
+        self: &Arc<Self>,
+        child: &C,
+        map: impl Fn(&EvC) -> Option<Ev> + Send + Sync + 'static,
+    ) -> ListenerHandle
+    where
+        C: SyncMediatorInternalSubscribe<EvC>,
+        EvC: MaybeDebug,
+    {
+        let parent: Weak<M> = Arc::downgrade(self);
+        child.subscribe(move |ev: &EvC| {
+            if let (Some(parent), Some(ev)) = (parent.upgrade(), map(ev)) {
+                parent.publish(ev);
+            }
+        })
+    }
+}
@@ -0,0 +1,404 @@
+use async_std::channel::{self, Receiver, Sender};
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fmt::Debug, io, sync::Arc};
+
+#[cfg(feature = "brokers-amqp")]
+use core::{future::poll_fn, pin::Pin};
+#[cfg(feature = "brokers-amqp")]
+use futures_core::Stream;
+#[cfg(feature = "brokers-amqp")]
+use lapin::{
+    options::{BasicAckOptions, BasicPublishOptions},
+    BasicProperties, Channel, Consumer,
+};
+
+use crate::mediator::{
+    asynchronous::basic::interface::AsyncMediatorInternal,
+    cancellation::{cancellable, CancellationToken},
+    codec::{Codec, JsonCodec},
+    listener::MaybeDebug,
+    observer::MediatorObserver,
+    sync::Mutex,
+    worker::Worker,
+};
+
+/// A message exchanged with a message broker:
+/// an event encoded by a [`Codec`] and the topic it was published to, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokerMessage {
+    /// The topic of the event, which brokers may use to route the message,
+    /// e.g. as the routing key of an AMQP exchange or as a Kafka topic.
+    pub topic: Option<String>,
+    /// The encoded event.
+    pub payload: Vec<u8>,
+}
+
+/// The outgoing half of a broker adapter, sending messages to the broker.
+///
+/// Mirror the events published by a mediator to a broker with a [`BrokerForwarder`].
+/// Implement this trait to plug in the client of a broker, e.g. Kafka,
+/// or use the `AmqpPublisher` of the `brokers-amqp` feature.
+#[async_trait]
+pub trait OutboundBridge: Send + Sync + 'static {
+    /// Sends the `message` to the broker.
+    ///
+    /// Reconnecting to the broker is up to the adapter,
+    /// a message that couldn't be sent is dropped.
+    async fn send(&self, message: BrokerMessage) -> io::Result<()>;
+}
+
+/// The incoming half of a broker adapter, receiving messages from the broker.
+///
+/// Publish the messages as events on a mediator with [`inject()`].
+/// Implement this trait to plug in the client of a broker, e.g. Kafka,
+/// or use the `AmqpConsumer` of the `brokers-amqp` feature.
+#[async_trait]
+pub trait InboundBridge: Send + 'static {
+    /// Receives the next message from the broker,
+    /// or `None` once the subscription ended.
+    async fn recv(&mut self) -> Option<BrokerMessage>;
+}
+
+/// Mirrors every event published by a mediator to a broker through an [`OutboundBridge`].
+///
+/// A [`BrokerForwarder`] is a [`MediatorObserver`], add it to a mediator with `with_observer()`.
+/// Events are encoded as JSON or in the format of another [`Codec`],
+/// see [`BrokerForwarder::spawn_with_codec()`], and queued, so publishing never waits for the broker.
+/// The returned [`Worker`] hands them to the bridge in publishing order.
+///
+/// Forwarding the events of a mediator to a broker and injecting them back
+/// into the same mediator mirrors them back and forth endlessly.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::asynchronous::basic::*;
+/// use mediatrix::brokers::{inject, BrokerForwarder, MemoryBroker};
+/// use serde::{Deserialize, Serialize};
+/// use std::sync::Arc;
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// enum MyEvent {
+///     One,
+///     Two
+/// }
+///
+/// async_std::task::block_on(async {
+///     let broker = MemoryBroker::new();
+///
+///     // At the edge of one service:
+///     let (forwarder, outbound) = BrokerForwarder::spawn(broker.clone());
+///     let local = BasicAsyncMediator::<MyEvent>::builder()
+///         .with_observer(forwarder)
+///         .build();
+///
+///     // At the edge of another service:
+///     let remote = Arc::new(BasicAsyncMediator::<MyEvent>::builder().build());
+///     let inbound = inject(broker.subscribe(), remote.clone());
+///
+///     local.publish_to("orders", MyEvent::One).await;
+///     remote.next_async().await.unwrap();
+///
+///     assert_eq!(outbound.shutdown().await, 1);
+///     assert_eq!(inbound.shutdown().await, 1);
+/// });
+///
+#[derive(Debug, Clone)]
+pub struct BrokerForwarder<C = JsonCodec> {
+    queue: Sender<BrokerMessage>,
+    codec: C,
+}
+
+impl BrokerForwarder {
+    /// Creates a [`BrokerForwarder`] and spawns the [`Worker`] sending its events through the `bridge`.
+    ///
+    /// Once the worker is shut down, the remaining events are still sent.
+    pub fn spawn(bridge: impl OutboundBridge) -> (Self, Worker) {
+        Self::spawn_with_codec(bridge, JsonCodec)
+    }
+}
+
+impl<C: Codec> BrokerForwarder<C> {
+    /// Creates a [`BrokerForwarder`] encoding its events in the format of the `codec`
+    /// and spawns the [`Worker`] sending them through the `bridge`.
+    ///
+    /// The receiving side has to inject them with [`inject_with_codec()`] and the same codec.
+    pub fn spawn_with_codec(bridge: impl OutboundBridge, codec: C) -> (Self, Worker) {
+        let (queue, messages) = channel::unbounded();
+        let worker = Worker::spawn(|shutdown| forward(bridge, messages, shutdown));
+        (Self { queue, codec }, worker)
+    }
+}
+
+impl<Ev, C> MediatorObserver<Ev> for BrokerForwarder<C>
+where
+    Ev: MaybeDebug + Serialize,
+    C: Codec,
+{
+    fn on_event_published(&self, ev: &Ev, topic: Option<&str>) {
+        if let Ok(payload) = self.codec.encode(ev) {
+            let message = BrokerMessage {
+                topic: topic.map(str::to_owned),
+                payload,
+            };
+            self.queue.try_send(message).ok();
+        }
+    }
+}
+
+/// The forwarding task, returns the number of forwarded events.
+async fn forward(
+    bridge: impl OutboundBridge,
+    messages: Receiver<BrokerMessage>,
+    shutdown: CancellationToken,
+) -> usize {
+    let mut forwarded = 0;
+    // Stops once all forwarders are dropped or the shutdown is signalled.
+    while let Ok(Ok(message)) = cancellable(messages.recv(), &shutdown).await {
+        forwarded += usize::from(bridge.send(message).await.is_ok());
+    }
+    while let Ok(message) = messages.try_recv() {
+        forwarded += usize::from(bridge.send(message).await.is_ok());
+    }
+    forwarded
+}
+
+/// Publishes all messages received through the `bridge` as events into the `mediator`.
+///
+/// Every event is published to the topic of its message, if any.
+/// Malformed messages are skipped.
+/// The returned [`Worker`] receives messages until it is shut down or the subscription ended
+/// and reports the number of injected events.
+///
+/// See [`BrokerForwarder`] for an example.
+///
+pub fn inject<M, Ev>(bridge: impl InboundBridge, mediator: Arc<M>) -> Worker
+where
+    M: AsyncMediatorInternal<Ev> + Send + Sync + 'static,
+    Ev: MaybeDebug + Send + DeserializeOwned + 'static,
+{
+    inject_with_codec(bridge, mediator, JsonCodec)
+}
+
+/// Same as [`inject()`], but decodes events in the format of the `codec`,
+/// encoded by [`BrokerForwarder`]s created with [`BrokerForwarder::spawn_with_codec()`].
+pub fn inject_with_codec<M, Ev, C>(bridge: impl InboundBridge, mediator: Arc<M>, codec: C) -> Worker
+where
+    M: AsyncMediatorInternal<Ev> + Send + Sync + 'static,
+    Ev: MaybeDebug + Send + DeserializeOwned + 'static,
+    C: Codec,
+{
+    Worker::spawn(|shutdown| receive(bridge, mediator, codec, shutdown))
+}
+
+/// The injecting task, returns the number of injected events.
+async fn receive<M, Ev, C>(
+    mut bridge: impl InboundBridge,
+    mediator: Arc<M>,
+    codec: C,
+    shutdown: CancellationToken,
+) -> usize
+where
+    M: AsyncMediatorInternal<Ev> + Send + Sync,
+    Ev: MaybeDebug + Send + DeserializeOwned,
+    C: Codec,
+{
+    let mut injected = 0;
+    while let Ok(Some(message)) = cancellable(bridge.recv(), &shutdown).await {
+        let Ok(event) = codec.decode::<Ev>(&message.payload) else {
+            continue;
+        };
+        match message.topic {
+            Some(topic) => mediator.publish_to(&topic, event).await,
+            None => mediator.publish(event).await,
+        }
+        injected += 1;
+    }
+    injected
+}
+
+/// An in-process message broker, implementing both halves of a broker adapter.
+///
+/// Every message sent to the broker is delivered to all of its subscriptions
+/// created with [`MemoryBroker::subscribe()`] before.
+/// Clones of a [`MemoryBroker`] share their subscriptions.
+/// This is useful to test broker adapters or distributed setups within a single process.
+///
+/// See [`BrokerForwarder`] for an example.
+///
+#[derive(Debug, Clone, Default)]
+pub struct MemoryBroker {
+    subscribers: Arc<Mutex<Vec<Sender<BrokerMessage>>>>,
+}
+
+impl MemoryBroker {
+    /// Creates a [`MemoryBroker`] without any subscriptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`MemorySubscription`] receiving all messages sent to the broker from now on.
+    pub fn subscribe(&self) -> MemorySubscription {
+        let (sender, receiver) = channel::unbounded();
+        self.subscribers.lock().push(sender);
+        MemorySubscription(receiver)
+    }
+}
+
+#[async_trait]
+impl OutboundBridge for MemoryBroker {
+    async fn send(&self, message: BrokerMessage) -> io::Result<()> {
+        // Dropped subscriptions are removed along the way.
+        self.subscribers
+            .lock()
+            .retain(|subscriber| subscriber.try_send(message.clone()).is_ok());
+        Ok(())
+    }
+}
+
+/// A subscription to a [`MemoryBroker`], created with [`MemoryBroker::subscribe()`].
+#[derive(Debug)]
+pub struct MemorySubscription(Receiver<BrokerMessage>);
+
+#[async_trait]
+impl InboundBridge for MemorySubscription {
+    async fn recv(&mut self) -> Option<BrokerMessage> {
+        self.0.recv().await.ok()
+    }
+}
+
+/// Sends messages to an AMQP exchange through a `lapin` [`Channel`].
+///
+/// Every message is published to the exchange with its topic as the routing key,
+/// or an empty routing key if it has none.
+/// If publisher confirms are enabled on the channel, sending waits for the broker to confirm
+/// and fails if it didn't.
+/// Connecting to the broker, declaring the exchange and choosing the runtime of `lapin`
+/// is up to the application.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```no_run
+/// use lapin::{options::BasicConsumeOptions, types::FieldTable, Connection, ConnectionProperties};
+/// use mediatrix::asynchronous::basic::*;
+/// use mediatrix::brokers::{inject, AmqpConsumer, AmqpPublisher, BrokerForwarder};
+/// use serde::{Deserialize, Serialize};
+/// use std::sync::Arc;
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// enum MyEvent {
+///     One,
+///     Two
+/// }
+///
+/// async_std::task::block_on(async {
+///     let connection = Connection::connect("amqp://127.0.0.1:5672", ConnectionProperties::default())
+///         .await
+///         .unwrap();
+///     let channel = connection.create_channel().await.unwrap();
+///
+///     // At the edge of one service:
+///     let (forwarder, outbound) = BrokerForwarder::spawn(AmqpPublisher::new(channel.clone(), "events"));
+///     let local = BasicAsyncMediator::<MyEvent>::builder()
+///         .with_observer(forwarder)
+///         .build();
+///
+///     // At the edge of another service, consuming a queue bound to the exchange:
+///     let consumer = channel
+///         .basic_consume(
+///             "orders".into(),
+///             "mediatrix".into(),
+///             BasicConsumeOptions::default(),
+///             FieldTable::default(),
+///         )
+///         .await
+///         .unwrap();
+///     let remote = Arc::new(BasicAsyncMediator::<MyEvent>::builder().build());
+///     let inbound = inject(AmqpConsumer::new(consumer), remote.clone());
+///
+///     local.publish_to("orders", MyEvent::One).await;
+///     remote.next_async().await.unwrap();
+/// });
+///
+#[cfg(feature = "brokers-amqp")]
+#[derive(Debug, Clone)]
+pub struct AmqpPublisher {
+    channel: Channel,
+    exchange: String,
+}
+
+#[cfg(feature = "brokers-amqp")]
+impl AmqpPublisher {
+    /// Creates an [`AmqpPublisher`] publishing to the `exchange` through the `channel`.
+    pub fn new(channel: Channel, exchange: impl Into<String>) -> Self {
+        Self {
+            channel,
+            exchange: exchange.into(),
+        }
+    }
+}
+
+#[cfg(feature = "brokers-amqp")]
+#[async_trait]
+impl OutboundBridge for AmqpPublisher {
+    async fn send(&self, message: BrokerMessage) -> io::Result<()> {
+        let confirmation = self
+            .channel
+            .basic_publish(
+                self.exchange.as_str().into(),
+                message.topic.unwrap_or_default().into(),
+                BasicPublishOptions::default(),
+                &message.payload,
+                BasicProperties::default(),
+            )
+            .await
+            .map_err(io::Error::other)?
+            .await
+            .map_err(io::Error::other)?;
+        if confirmation.is_nack() {
+            return Err(io::Error::other("the broker rejected the message"));
+        }
+        Ok(())
+    }
+}
+
+/// Receives messages from an AMQP queue through a `lapin` [`Consumer`].
+///
+/// The routing key of a delivery becomes the topic of its message, unless it is empty.
+/// Deliveries are acknowledged as soon as they are received.
+/// The subscription ends once the consumer is canceled or fails.
+///
+/// See [`AmqpPublisher`] for an example.
+///
+#[cfg(feature = "brokers-amqp")]
+#[derive(Debug)]
+pub struct AmqpConsumer(Consumer);
+
+#[cfg(feature = "brokers-amqp")]
+impl AmqpConsumer {
+    /// Creates an [`AmqpConsumer`] receiving the deliveries of the `consumer`.
+    pub fn new(consumer: Consumer) -> Self {
+        Self(consumer)
+    }
+}
+
+#[cfg(feature = "brokers-amqp")]
+#[async_trait]
+impl InboundBridge for AmqpConsumer {
+    async fn recv(&mut self) -> Option<BrokerMessage> {
+        let delivery = poll_fn(|cx| Pin::new(&mut self.0).poll_next(cx))
+            .await?
+            .ok()?;
+        delivery.ack(BasicAckOptions::default()).await.ok();
+        let topic = delivery.routing_key.as_str();
+        Some(BrokerMessage {
+            topic: (!topic.is_empty()).then(|| topic.to_owned()),
+            payload: delivery.data,
+        })
+    }
+}
@@ -1,3 +1,8 @@
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::fmt::Display;
+
+use crate::mediator::topics::TopicPattern;
+
 /// Trait for creating a builder
 /// that implements [`BuilderFlow`]
 /// for a mediator `M`.
@@ -45,3 +50,51 @@ where
     #[allow(missing_docs)]
     fn build(self) -> Result<M, Self::Error>;
 }
+
+/// An error returned from [`TryBuilderFlow::build()`]
+/// if the builder was configured in an invalid way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum BuildError {
+    /// No context was added while building.
+    NoCxAvailable,
+    /// The channel was bounded to a capacity of `0`.
+    InvalidCapacity,
+    /// No listener was added to a strict builder.
+    NoListeners,
+    /// The topic pattern was subscribed to more than once on a strict builder.
+    DuplicateTopicSubscription(String),
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BuildError::NoCxAvailable => write!(f, "no context was added"),
+            BuildError::InvalidCapacity => write!(f, "channel capacity must be greater than 0"),
+            BuildError::NoListeners => write!(f, "no listener was added"),
+            BuildError::DuplicateTopicSubscription(topic) => {
+                write!(f, "topic `{}` was subscribed to more than once", topic)
+            }
+        }
+    }
+}
+
+impl core::error::Error for BuildError {}
+
+/// Validates the listeners of a strict builder, given the topic each one is restricted to.
+pub(crate) fn validate_strict(topics: Vec<Option<TopicPattern>>) -> Result<(), BuildError> {
+    if topics.is_empty() {
+        return Err(BuildError::NoListeners);
+    }
+    let mut seen = Vec::new();
+    for topic in topics.into_iter().flatten() {
+        if seen.contains(&topic) {
+            return Err(BuildError::DuplicateTopicSubscription(
+                topic.as_str().to_owned(),
+            ));
+        }
+        seen.push(topic);
+    }
+    Ok(())
+}
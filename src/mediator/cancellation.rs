@@ -0,0 +1,138 @@
+use event_listener::Event;
+use std::{
+    fmt::{Debug, Display},
+    future::{poll_fn, Future},
+    pin::pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::Poll,
+    time::Duration,
+};
+
+use crate::mediator::runtime;
+
+/// A token to cancel a request sent with `send_with_cancellation()`.
+///
+/// Clones share the same cancellation state,
+/// so a clone can be moved into a request to observe the cancellation
+/// from within the handler via [`CancellationToken::is_cancelled()`]
+/// or [`CancellationToken::cancelled()`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::cancellation::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let cloned = token.clone();
+///
+/// token.cancel();
+/// assert!(cloned.is_cancelled());
+///
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    event: Event,
+}
+
+impl CancellationToken {
+    /// Creates a new, not yet cancelled [`CancellationToken`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the token and wakes everyone waiting on [`CancellationToken::cancelled()`].
+    ///
+    /// Cancelling an already cancelled token has no effect.
+    pub fn cancel(&self) {
+        if !self.inner.cancelled.swap(true, Ordering::SeqCst) {
+            self.inner.event.notify(usize::MAX);
+        }
+    }
+
+    /// Returns `true` if the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Waits until the token is cancelled.
+    ///
+    /// You need to await the `Future` using `.await`.
+    pub async fn cancelled(&self) {
+        loop {
+            if self.is_cancelled() {
+                return;
+            }
+            let listener = self.inner.event.listen();
+            if self.is_cancelled() {
+                return;
+            }
+            listener.await;
+        }
+    }
+}
+
+/// Error returned if a request was cancelled through its [`CancellationToken`]
+/// before it completed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CancelledError;
+
+impl Display for CancelledError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "request was cancelled")
+    }
+}
+
+impl std::error::Error for CancelledError {}
+
+/// Error returned if a request did not complete within its timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeoutError;
+
+impl Display for TimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "request timed out")
+    }
+}
+
+impl std::error::Error for TimeoutError {}
+
+/// Runs `fut` to completion unless `duration` elapses first,
+/// in which case `fut` is dropped at its current await point.
+pub(crate) async fn timeout<T>(
+    fut: impl Future<Output = T>,
+    duration: Duration,
+) -> Result<T, TimeoutError> {
+    runtime::timeout(duration, fut).await.ok_or(TimeoutError)
+}
+
+/// Runs `fut` to completion unless `token` is cancelled first,
+/// in which case `fut` is dropped at its current await point.
+pub(crate) async fn cancellable<T>(
+    fut: impl Future<Output = T>,
+    token: &CancellationToken,
+) -> Result<T, CancelledError> {
+    if token.is_cancelled() {
+        return Err(CancelledError);
+    }
+    let mut fut = pin!(fut);
+    let mut cancelled = pin!(token.cancelled());
+    // The token is polled first, so a handler returning early
+    // because it observed the cancellation still counts as cancelled.
+    poll_fn(|cx| {
+        if cancelled.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(Err(CancelledError));
+        }
+        fut.as_mut().poll(cx).map(Ok)
+    })
+    .await
+}
@@ -0,0 +1,272 @@
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{Debug, Write},
+    sync::Mutex,
+};
+
+use crate::mediator::{listener::ListenerHandle, topics::Envelope};
+
+/// What caused the work currently running on a thread, see [`Trace`](crate::mediator::correlation::Trace).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Cause {
+    /// The handler of a request, by the type name of the request.
+    Request(&'static str),
+    /// A listener, invoked for an event.
+    Listener(ListenerHandle),
+}
+
+/// A node of the recorded graph, either a [`Cause`] or an event by its sequence number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Node {
+    Request(&'static str),
+    Event(u64),
+    Listener(ListenerHandle),
+}
+
+impl From<Cause> for Node {
+    fn from(cause: Cause) -> Self {
+        match cause {
+            Cause::Request(request) => Node::Request(request),
+            Cause::Listener(handle) => Node::Listener(handle),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Recorded {
+    nodes: BTreeSet<Node>,
+    events: BTreeMap<u64, (String, Option<String>)>,
+    edges: BTreeSet<(Node, Node)>,
+}
+
+/// Records which request or listener caused which event
+/// and which listeners were invoked for it, added with `record_causality()`.
+pub(crate) struct CausalityRecorder<Ev> {
+    label: fn(&Ev) -> String,
+    recorded: Mutex<Recorded>,
+}
+
+impl<Ev> CausalityRecorder<Ev> {
+    /// Creates a recorder labeling events with their [`Debug`] representation.
+    pub(crate) fn new() -> Self
+    where
+        Ev: Debug,
+    {
+        Self {
+            label: |ev| format!("{ev:?}"),
+            recorded: Mutex::default(),
+        }
+    }
+
+    fn record(&self, node: Node, cause: Option<Node>) {
+        let mut recorded = self.recorded.lock().unwrap_or_else(|err| err.into_inner());
+        recorded.nodes.insert(node);
+        if let Some(cause) = cause {
+            recorded.nodes.insert(cause);
+            recorded.edges.insert((cause, node));
+        }
+    }
+
+    /// Records that the `request` is handled, caused by `cause`, if any.
+    pub(crate) fn request(&self, request: &'static str, cause: Option<Cause>) {
+        self.record(Node::Request(request), cause.map(Node::from));
+    }
+
+    /// Records that the event of the `envelope` was published by the cause of its trace, if any.
+    pub(crate) fn published(&self, envelope: &Envelope<Ev>) {
+        let Some(meta) = envelope.trace.event else {
+            return;
+        };
+        let label = (self.label)(&envelope.ev);
+        self.recorded
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .events
+            .insert(meta.sequence, (label, envelope.topic.clone()));
+        self.record(
+            Node::Event(meta.sequence),
+            envelope.trace.cause.map(Node::from),
+        );
+    }
+
+    /// Records that the listener with the given `handle` was invoked for the `event`, if any.
+    pub(crate) fn listener(&self, handle: ListenerHandle, event: Option<u64>) {
+        self.record(Node::Listener(handle), event.map(Node::Event));
+    }
+
+    /// Returns the recorded graph, listing listeners with their `names`, if known.
+    pub(crate) fn graph(&self, names: &BTreeMap<ListenerHandle, &'static str>) -> CausalityGraph {
+        let recorded = self.recorded.lock().unwrap_or_else(|err| err.into_inner());
+        let index: BTreeMap<Node, usize> = recorded
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (*node, i))
+            .collect();
+        let nodes = recorded
+            .nodes
+            .iter()
+            .map(|node| match *node {
+                Node::Request(name) => CausalityNode::Request { name },
+                Node::Event(sequence) => {
+                    let (label, topic) =
+                        recorded.events.get(&sequence).cloned().unwrap_or_default();
+                    CausalityNode::Event {
+                        sequence,
+                        label,
+                        topic,
+                    }
+                }
+                Node::Listener(handle) => CausalityNode::Listener {
+                    handle,
+                    name: names.get(&handle).copied(),
+                },
+            })
+            .collect();
+        let edges = recorded
+            .edges
+            .iter()
+            .map(|(from, to)| (index[from], index[to]))
+            .collect();
+        CausalityGraph { nodes, edges }
+    }
+}
+
+impl<Ev> Debug for CausalityRecorder<Ev> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CausalityRecorder").finish_non_exhaustive()
+    }
+}
+
+/// The flows of requests, events and listeners observed by a mediator,
+/// returned by `causality_graph()` if the mediator was built with `record_causality()`.
+///
+/// Every event is linked to what caused it: the handler of a request
+/// or a listener processing another event. Every listener is linked to the events
+/// it was invoked for, and every request sent from within a listener or handler to its sender.
+/// The graph can be exported to Graphviz with [`CausalityGraph::to_dot()`] or to JSON
+/// with [`CausalityGraph::to_json()`].
+///
+/// Events are recorded one by one, so the graph grows with the traffic of the mediator.
+/// It is meant to understand an event-driven code base in development and tests.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     Ordered(u32),
+///     Shipped(u32)
+/// }
+///
+/// struct Order(u32);
+///
+/// impl RequestHandler<Order, MyEvent> for BasicMediator<MyEvent> {
+///     fn handle(&self, req: Order) {
+///         self.publish(MyEvent::Ordered(req.0));
+///     }
+/// }
+///
+/// let mediator = BasicMediator::<MyEvent>::builder()
+///     .record_causality()
+///     .build();
+///
+/// mediator.send(Order(1));
+/// mediator.next().ok();
+///
+/// let graph = mediator.causality_graph();
+/// assert_eq!(graph.nodes().len(), 2);
+/// assert_eq!(graph.edges().len(), 1);
+///
+/// println!("{}", graph.to_dot());
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CausalityGraph {
+    nodes: Vec<CausalityNode>,
+    edges: Vec<(usize, usize)>,
+}
+
+impl CausalityGraph {
+    /// Returns the requests, events and listeners of the graph.
+    pub fn nodes(&self) -> &[CausalityNode] {
+        &self.nodes
+    }
+
+    /// Returns the edges of the graph, from cause to effect,
+    /// as indices into [`CausalityGraph::nodes()`].
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// Exports the graph in the DOT language of Graphviz.
+    ///
+    /// Requests are drawn as boxes, events as ellipses and listeners as hexagons.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph causality {\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let (shape, label) = match node {
+                CausalityNode::Request { name } => ("box", (*name).to_owned()),
+                CausalityNode::Event {
+                    sequence,
+                    label,
+                    topic: Some(topic),
+                } => ("ellipse", format!("#{sequence} {label} @ {topic}")),
+                CausalityNode::Event {
+                    sequence, label, ..
+                } => ("ellipse", format!("#{sequence} {label}")),
+                CausalityNode::Listener {
+                    name: Some(name), ..
+                } => ("hexagon", (*name).to_owned()),
+                CausalityNode::Listener { handle, name: None } => {
+                    ("hexagon", format!("{handle:?}"))
+                }
+            };
+            let label = label.replace('\\', "\\\\").replace('"', "\\\"");
+            writeln!(dot, "    n{i} [shape={shape}, label=\"{label}\"];").unwrap();
+        }
+        for (from, to) in &self.edges {
+            writeln!(dot, "    n{from} -> n{to};").unwrap();
+        }
+        dot.push('}');
+        dot
+    }
+
+    /// Serializes the graph into pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a causality graph always serializes")
+    }
+}
+
+/// A node of the [`CausalityGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CausalityNode {
+    /// The handler of a request.
+    Request {
+        /// The type name of the request.
+        name: &'static str,
+    },
+    /// A published event.
+    Event {
+        /// The sequence number of the event, see
+        /// [`Enveloped::sequence()`](crate::correlation::Enveloped::sequence()).
+        sequence: u64,
+        /// The [`Debug`] representation of the event.
+        label: String,
+        /// The topic the event was published to, if any.
+        topic: Option<String>,
+    },
+    /// A listener.
+    Listener {
+        /// The [`ListenerHandle`] of the listener.
+        handle: ListenerHandle,
+        /// The name of the listener, i.e. its type name as it was added,
+        /// `None` if it was unsubscribed since.
+        name: Option<&'static str>,
+    },
+}
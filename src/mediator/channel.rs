@@ -0,0 +1,744 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt::{self, Debug, Display, Formatter};
+
+use crate::mediator::{stats::Queued, sync::Mutex};
+use alloc::collections::VecDeque;
+#[cfg(feature = "async")]
+use core::{
+    future::{poll_fn, Future},
+    pin::pin,
+    task::Poll,
+};
+#[cfg(feature = "std")]
+use std::sync::mpsc::{self, channel, sync_channel, Receiver, Sender, SyncSender};
+
+/// Decides what happens when an event is published to a full bounded channel.
+///
+/// The policy only applies if a capacity was configured on the builder.
+/// An unbounded channel never overflows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Wait until there is space in the channel.
+    ///
+    /// A synchronous mediator blocks the current thread, so make sure
+    /// that events are processed elsewhere. Asynchronous mediators wait
+    /// without blocking the thread.
+    /// Without the `std` feature, nothing can wait, so the event
+    /// is handed back like with [`OverflowPolicy::Error`].
+    #[default]
+    Block,
+    /// Drop the oldest pending event to make space for the new one.
+    DropOldest,
+    /// Drop the newly published event.
+    DropNewest,
+    /// Hand the event back to the publisher as a [`TrySendError::Full`].
+    Error,
+}
+
+/// A channel carrying events `Ev` from the publishers to the mediator.
+///
+/// Implemented for the sender and receiver pairs of `crossbeam-channel`, `flume` and `async-std`
+/// with the `crossbeam`, `flume` and `async` features respectively.
+/// A mediator creates its backend through a [`ChannelFlavor`],
+/// which is picked with `with_channel_backend()` on its builder.
+pub trait ChannelBackend<Ev> {
+    /// Sends an event, waiting for space if the channel is bounded and full.
+    ///
+    /// Backends that can't wait hand the event back in a [`TrySendError::Full`].
+    fn send(&self, ev: Ev) -> Result<(), TrySendError<Ev>>;
+
+    /// Sends an event without ever waiting.
+    fn try_send(&self, ev: Ev) -> Result<(), TrySendError<Ev>>;
+
+    /// Receives an event without ever waiting.
+    fn try_recv(&self) -> Result<Ev, TryRecvError>;
+
+    /// Receives an event, blocking the current thread until one is available.
+    #[cfg(feature = "std")]
+    fn recv(&self) -> Result<Ev, RecvError>;
+}
+
+/// A kind of channel, creating the [`ChannelBackend`] of a mediator.
+///
+/// Synchronous mediators use `std::sync::mpsc` by default, which is what [`StdChannel`] creates,
+/// while async mediators use the channels of `async-std`, see [`AsyncChannel`].
+/// Implement this trait to plug in a channel of your own.
+pub trait ChannelFlavor {
+    /// Creates an unbounded channel.
+    fn unbounded<Ev: Send + 'static>() -> Box<dyn ChannelBackend<Ev> + Send + Sync>;
+
+    /// Creates a channel that holds at most `capacity` events.
+    fn bounded<Ev: Send + 'static>(capacity: usize) -> Box<dyn ChannelBackend<Ev> + Send + Sync>;
+}
+
+/// The channels of `std::sync::mpsc`, used by default.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StdChannel;
+
+#[cfg(feature = "std")]
+impl ChannelFlavor for StdChannel {
+    fn unbounded<Ev: Send + 'static>() -> Box<dyn ChannelBackend<Ev> + Send + Sync> {
+        Box::new(Mpsc::from(channel()))
+    }
+
+    fn bounded<Ev: Send + 'static>(capacity: usize) -> Box<dyn ChannelBackend<Ev> + Send + Sync> {
+        Box::new(Mpsc::from(sync_channel(capacity)))
+    }
+}
+
+/// A channel of `std::sync::mpsc`, whose receiver is guarded by a lock.
+///
+/// Only one thread at a time can receive, others find the channel empty meanwhile.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct Mpsc<S, Ev> {
+    sender: S,
+    receiver: Mutex<Receiver<Ev>>,
+}
+
+#[cfg(feature = "std")]
+impl<S, Ev> From<(S, Receiver<Ev>)> for Mpsc<S, Ev> {
+    fn from((sender, receiver): (S, Receiver<Ev>)) -> Self {
+        Self {
+            sender,
+            receiver: Mutex::new(receiver),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S, Ev> Mpsc<S, Ev> {
+    fn try_recv(&self) -> Result<Ev, TryRecvError> {
+        match self.receiver.try_lock() {
+            Some(receiver) => receiver.try_recv().map_err(TryRecvError::from),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    fn recv(&self) -> Result<Ev, RecvError> {
+        self.receiver.lock().recv().map_err(|_| RecvError)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Ev> ChannelBackend<Ev> for Mpsc<Sender<Ev>, Ev> {
+    fn send(&self, ev: Ev) -> Result<(), TrySendError<Ev>> {
+        self.sender
+            .send(ev)
+            .map_err(|err| TrySendError::Disconnected(err.0))
+    }
+
+    fn try_send(&self, ev: Ev) -> Result<(), TrySendError<Ev>> {
+        self.send(ev)
+    }
+
+    fn try_recv(&self) -> Result<Ev, TryRecvError> {
+        Mpsc::try_recv(self)
+    }
+
+    fn recv(&self) -> Result<Ev, RecvError> {
+        Mpsc::recv(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Ev> ChannelBackend<Ev> for Mpsc<SyncSender<Ev>, Ev> {
+    fn send(&self, ev: Ev) -> Result<(), TrySendError<Ev>> {
+        self.sender
+            .send(ev)
+            .map_err(|err| TrySendError::Disconnected(err.0))
+    }
+
+    fn try_send(&self, ev: Ev) -> Result<(), TrySendError<Ev>> {
+        self.sender.try_send(ev).map_err(TrySendError::from)
+    }
+
+    fn try_recv(&self) -> Result<Ev, TryRecvError> {
+        Mpsc::try_recv(self)
+    }
+
+    fn recv(&self) -> Result<Ev, RecvError> {
+        Mpsc::recv(self)
+    }
+}
+
+/// The channels of `crossbeam-channel`.
+#[cfg(feature = "crossbeam")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CrossbeamChannel;
+
+#[cfg(feature = "crossbeam")]
+impl ChannelFlavor for CrossbeamChannel {
+    fn unbounded<Ev: Send + 'static>() -> Box<dyn ChannelBackend<Ev> + Send + Sync> {
+        Box::new(crossbeam_channel::unbounded())
+    }
+
+    fn bounded<Ev: Send + 'static>(capacity: usize) -> Box<dyn ChannelBackend<Ev> + Send + Sync> {
+        Box::new(crossbeam_channel::bounded(capacity))
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+impl<Ev> ChannelBackend<Ev>
+    for (
+        crossbeam_channel::Sender<Ev>,
+        crossbeam_channel::Receiver<Ev>,
+    )
+{
+    fn send(&self, ev: Ev) -> Result<(), TrySendError<Ev>> {
+        self.0
+            .send(ev)
+            .map_err(|err| TrySendError::Disconnected(err.0))
+    }
+
+    fn try_send(&self, ev: Ev) -> Result<(), TrySendError<Ev>> {
+        self.0.try_send(ev).map_err(|err| match err {
+            crossbeam_channel::TrySendError::Full(ev) => TrySendError::Full(ev),
+            crossbeam_channel::TrySendError::Disconnected(ev) => TrySendError::Disconnected(ev),
+        })
+    }
+
+    fn try_recv(&self) -> Result<Ev, TryRecvError> {
+        self.1.try_recv().map_err(|err| match err {
+            crossbeam_channel::TryRecvError::Empty => TryRecvError::Empty,
+            crossbeam_channel::TryRecvError::Disconnected => TryRecvError::Disconnected,
+        })
+    }
+
+    fn recv(&self) -> Result<Ev, RecvError> {
+        self.1.recv().map_err(|_| RecvError)
+    }
+}
+
+/// The channels of `flume`.
+#[cfg(feature = "flume")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FlumeChannel;
+
+#[cfg(feature = "flume")]
+impl ChannelFlavor for FlumeChannel {
+    fn unbounded<Ev: Send + 'static>() -> Box<dyn ChannelBackend<Ev> + Send + Sync> {
+        Box::new(flume::unbounded())
+    }
+
+    fn bounded<Ev: Send + 'static>(capacity: usize) -> Box<dyn ChannelBackend<Ev> + Send + Sync> {
+        Box::new(flume::bounded(capacity))
+    }
+}
+
+#[cfg(feature = "flume")]
+impl<Ev> ChannelBackend<Ev> for (flume::Sender<Ev>, flume::Receiver<Ev>) {
+    fn send(&self, ev: Ev) -> Result<(), TrySendError<Ev>> {
+        self.0
+            .send(ev)
+            .map_err(|err| TrySendError::Disconnected(err.0))
+    }
+
+    fn try_send(&self, ev: Ev) -> Result<(), TrySendError<Ev>> {
+        self.0.try_send(ev).map_err(|err| match err {
+            flume::TrySendError::Full(ev) => TrySendError::Full(ev),
+            flume::TrySendError::Disconnected(ev) => TrySendError::Disconnected(ev),
+        })
+    }
+
+    fn try_recv(&self) -> Result<Ev, TryRecvError> {
+        self.1.try_recv().map_err(|err| match err {
+            flume::TryRecvError::Empty => TryRecvError::Empty,
+            flume::TryRecvError::Disconnected => TryRecvError::Disconnected,
+        })
+    }
+
+    fn recv(&self) -> Result<Ev, RecvError> {
+        self.1.recv().map_err(|_| RecvError)
+    }
+}
+
+/// The channels of `async-std`, used by default by async mediators.
+///
+/// Blocking on them doesn't require an executor,
+/// which makes them a fit for mediators shared between synchronous and asynchronous code.
+///
+/// As the default backend, async mediators await them directly when receiving events
+/// and when publishing into a full channel with [`OverflowPolicy::Block`].
+/// Passed to `with_channel_backend()`, they are polled like any other backend.
+#[cfg(feature = "async")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AsyncChannel;
+
+#[cfg(feature = "async")]
+impl ChannelFlavor for AsyncChannel {
+    fn unbounded<Ev: Send + 'static>() -> Box<dyn ChannelBackend<Ev> + Send + Sync> {
+        Box::new(async_std::channel::unbounded())
+    }
+
+    fn bounded<Ev: Send + 'static>(capacity: usize) -> Box<dyn ChannelBackend<Ev> + Send + Sync> {
+        Box::new(async_std::channel::bounded(capacity))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Ev> ChannelBackend<Ev>
+    for (
+        async_std::channel::Sender<Ev>,
+        async_std::channel::Receiver<Ev>,
+    )
+{
+    fn send(&self, ev: Ev) -> Result<(), TrySendError<Ev>> {
+        self.0
+            .send_blocking(ev)
+            .map_err(|err| TrySendError::Disconnected(err.0))
+    }
+
+    fn try_send(&self, ev: Ev) -> Result<(), TrySendError<Ev>> {
+        self.0.try_send(ev).map_err(|err| match err {
+            async_std::channel::TrySendError::Full(ev) => TrySendError::Full(ev),
+            async_std::channel::TrySendError::Closed(ev) => TrySendError::Disconnected(ev),
+        })
+    }
+
+    fn try_recv(&self) -> Result<Ev, TryRecvError> {
+        self.1.try_recv().map_err(|err| match err {
+            async_std::channel::TryRecvError::Empty => TryRecvError::Empty,
+            async_std::channel::TryRecvError::Closed => TryRecvError::Disconnected,
+        })
+    }
+
+    fn recv(&self) -> Result<Ev, RecvError> {
+        self.1.recv_blocking().map_err(|_| RecvError)
+    }
+}
+
+/// An error returned from sending an event into a channel without blocking,
+/// no matter which [`ChannelBackend`] the channel uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrySendError<T> {
+    /// The channel is full, the event is handed back.
+    Full(T),
+    /// The channel is disconnected, the event is handed back.
+    Disconnected(T),
+}
+
+impl<T> TrySendError<T> {
+    /// Returns the event that could not be sent.
+    pub fn into_inner(self) -> T {
+        match self {
+            TrySendError::Full(ev) | TrySendError::Disconnected(ev) => ev,
+        }
+    }
+}
+
+impl<T> Display for TrySendError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TrySendError::Full(_) => write!(f, "sending on a full channel"),
+            TrySendError::Disconnected(_) => write!(f, "sending on a closed channel"),
+        }
+    }
+}
+
+impl<T: Debug> core::error::Error for TrySendError<T> {}
+
+#[cfg(feature = "std")]
+impl<T> From<mpsc::TrySendError<T>> for TrySendError<T> {
+    fn from(err: mpsc::TrySendError<T>) -> Self {
+        match err {
+            mpsc::TrySendError::Full(ev) => TrySendError::Full(ev),
+            mpsc::TrySendError::Disconnected(ev) => TrySendError::Disconnected(ev),
+        }
+    }
+}
+
+/// An error returned from receiving an event from a channel without blocking,
+/// no matter which [`ChannelBackend`] the channel uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// The channel is empty.
+    Empty,
+    /// The channel is disconnected.
+    Disconnected,
+}
+
+impl Display for TryRecvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TryRecvError::Empty => write!(f, "receiving on an empty channel"),
+            TryRecvError::Disconnected => write!(f, "receiving on a closed channel"),
+        }
+    }
+}
+
+impl core::error::Error for TryRecvError {}
+
+#[cfg(feature = "std")]
+impl From<mpsc::TryRecvError> for TryRecvError {
+    fn from(err: mpsc::TryRecvError) -> Self {
+        match err {
+            mpsc::TryRecvError::Empty => TryRecvError::Empty,
+            mpsc::TryRecvError::Disconnected => TryRecvError::Disconnected,
+        }
+    }
+}
+
+/// An error returned from receiving an event from a channel while blocking:
+/// the channel is disconnected, no more events will arrive.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecvError;
+
+#[cfg(feature = "std")]
+impl Display for RecvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "receiving on a closed channel")
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for RecvError {}
+
+/// Without the `std` feature, the default backend: a queue behind a spin lock.
+///
+/// Nothing can wait for space in a full queue,
+/// so [`OverflowPolicy::Block`] behaves like [`OverflowPolicy::Error`].
+#[cfg(not(feature = "std"))]
+#[derive(Debug)]
+struct Queue<Ev> {
+    events: Mutex<VecDeque<Ev>>,
+    capacity: Option<usize>,
+}
+
+#[cfg(not(feature = "std"))]
+impl<Ev> ChannelBackend<Ev> for Queue<Ev> {
+    fn send(&self, ev: Ev) -> Result<(), TrySendError<Ev>> {
+        self.try_send(ev)
+    }
+
+    fn try_send(&self, ev: Ev) -> Result<(), TrySendError<Ev>> {
+        let mut events = self.events.lock();
+        if self
+            .capacity
+            .is_some_and(|capacity| events.len() >= capacity)
+        {
+            return Err(TrySendError::Full(ev));
+        }
+        events.push_back(ev);
+        Ok(())
+    }
+
+    fn try_recv(&self) -> Result<Ev, TryRecvError> {
+        self.events.lock().pop_front().ok_or(TryRecvError::Empty)
+    }
+}
+
+/// Creates the backend of an [`EventChannel`] holding at most `capacity` events, if any.
+type MakeBackend<Ev> = fn(Option<usize>) -> Box<dyn ChannelBackend<Ev> + Send + Sync>;
+
+/// The backend of an [`EventChannel`].
+enum Backend<Ev> {
+    #[cfg(feature = "std")]
+    Unbounded(Mpsc<Sender<Ev>, Ev>),
+    #[cfg(feature = "std")]
+    Bounded(Mpsc<SyncSender<Ev>, Ev>),
+    /// The default backend of async mediators.
+    #[cfg(feature = "async")]
+    Async(
+        (
+            async_std::channel::Sender<Ev>,
+            async_std::channel::Receiver<Ev>,
+        ),
+    ),
+    #[cfg(not(feature = "std"))]
+    Queue(Queue<Ev>),
+    /// Created through a [`ChannelFlavor`].
+    Flavored(Box<dyn ChannelBackend<Ev> + Send + Sync>, MakeBackend<Ev>),
+}
+
+impl<Ev> Backend<Ev> {
+    /// Creates the default backend, which is an [`AsyncChannel`] for async mediators.
+    fn new(capacity: Option<usize>, #[cfg(feature = "async")] asynchronous: bool) -> Self {
+        #[cfg(feature = "async")]
+        if asynchronous {
+            return Backend::Async(match capacity {
+                None => async_std::channel::unbounded(),
+                Some(capacity) => async_std::channel::bounded(capacity),
+            });
+        }
+        #[cfg(feature = "std")]
+        return match capacity {
+            None => Backend::Unbounded(Mpsc::from(channel())),
+            Some(capacity) => Backend::Bounded(Mpsc::from(sync_channel(capacity))),
+        };
+        #[cfg(not(feature = "std"))]
+        return Backend::Queue(Queue {
+            events: Mutex::new(VecDeque::new()),
+            capacity,
+        });
+    }
+
+    fn get(&self) -> &dyn ChannelBackend<Ev> {
+        match self {
+            #[cfg(feature = "std")]
+            Backend::Unbounded(backend) => backend,
+            #[cfg(feature = "std")]
+            Backend::Bounded(backend) => backend,
+            #[cfg(feature = "async")]
+            Backend::Async(backend) => backend,
+            #[cfg(not(feature = "std"))]
+            Backend::Queue(backend) => backend,
+            Backend::Flavored(backend, _) => backend.as_ref(),
+        }
+    }
+}
+
+impl<Ev> Debug for Backend<Ev> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            Backend::Unbounded(_) => write!(f, "Unbounded"),
+            #[cfg(feature = "std")]
+            Backend::Bounded(_) => write!(f, "Bounded"),
+            #[cfg(feature = "async")]
+            Backend::Async(..) => write!(f, "Async"),
+            #[cfg(not(feature = "std"))]
+            Backend::Queue(_) => write!(f, "Queue"),
+            Backend::Flavored(..) => write!(f, "Flavored"),
+        }
+    }
+}
+
+/// The channel carrying published events to the listeners.
+#[derive(Debug)]
+pub(crate) struct EventChannel<Ev> {
+    backend: Backend<Ev>,
+    capacity: Option<usize>,
+    pub(crate) policy: OverflowPolicy,
+    queued: Queued,
+    /// The lane of urgent events, received before all others, see [`EventChannel::send_urgent()`].
+    urgent: Mutex<VecDeque<Ev>>,
+    /// Whether the default backend is an [`AsyncChannel`].
+    #[cfg(feature = "async")]
+    asynchronous: bool,
+}
+
+impl<Ev> Default for EventChannel<Ev> {
+    fn default() -> Self {
+        Self {
+            backend: Backend::new(
+                None,
+                #[cfg(feature = "async")]
+                false,
+            ),
+            capacity: None,
+            policy: OverflowPolicy::default(),
+            queued: Queued::default(),
+            urgent: Mutex::new(VecDeque::new()),
+            #[cfg(feature = "async")]
+            asynchronous: false,
+        }
+    }
+}
+
+impl<Ev> EventChannel<Ev> {
+    /// Creates an unbounded channel of an async mediator, whose default backend is an [`AsyncChannel`].
+    #[cfg(feature = "async")]
+    pub(crate) fn asynchronous() -> Self {
+        Self {
+            backend: Backend::new(None, true),
+            asynchronous: true,
+            ..Self::default()
+        }
+    }
+
+    /// Creates the default backend of the channel.
+    fn default_backend(&self) -> Backend<Ev> {
+        Backend::new(
+            self.capacity,
+            #[cfg(feature = "async")]
+            self.asynchronous,
+        )
+    }
+
+    /// Bounds the channel to `capacity` events, or unbounds it if `None`,
+    /// re-creating its backend.
+    pub(crate) fn set_capacity(&mut self, capacity: Option<usize>) {
+        self.capacity = capacity;
+        self.backend = match &self.backend {
+            Backend::Flavored(_, make) => Backend::Flavored(make(self.capacity), *make),
+            _ => self.default_backend(),
+        };
+    }
+
+    /// Replaces the backend of the channel with the default one.
+    pub(crate) fn set_default_flavor(&mut self) {
+        self.backend = self.default_backend();
+    }
+
+    /// Replaces the backend of the channel with one created through `F`.
+    pub(crate) fn set_flavor<F: ChannelFlavor>(&mut self)
+    where
+        Ev: Send + 'static,
+    {
+        let make: MakeBackend<Ev> = |capacity| match capacity {
+            Some(capacity) => F::bounded(capacity),
+            None => F::unbounded(),
+        };
+        self.backend = Backend::Flavored(make(self.capacity), make);
+    }
+
+    /// Sends an event, applying the [`OverflowPolicy`] if the channel is full.
+    /// Returns the events dropped by the policy, if any.
+    ///
+    /// With [`OverflowPolicy::Block`], this blocks the current thread.
+    pub(crate) fn send(&self, ev: Ev) -> Result<Vec<Ev>, TrySendError<Ev>> {
+        match self.policy {
+            OverflowPolicy::Block => {
+                let reserved = self.queued.reserve();
+                self.backend.get().send(ev).map(|()| {
+                    reserved.keep();
+                    vec![]
+                })
+            }
+            _ => self.try_send(ev),
+        }
+    }
+
+    /// Same as [`EventChannel::send()`], but awaits space in the channel
+    /// instead of blocking the thread.
+    ///
+    /// Only the default backend of async mediators, an [`AsyncChannel`], can be awaited.
+    /// Other backends never wait, like [`EventChannel::try_send()`].
+    #[cfg(feature = "async")]
+    pub(crate) async fn send_async(&self, ev: Ev) -> Result<Vec<Ev>, TrySendError<Ev>> {
+        let (Backend::Async((sender, _)), OverflowPolicy::Block) = (&self.backend, self.policy)
+        else {
+            return self.try_send(ev);
+        };
+        // Uncounted again if the send fails or this future is dropped while waiting for space.
+        let reserved = self.queued.reserve();
+        sender
+            .send(ev)
+            .await
+            .map(|()| {
+                reserved.keep();
+                vec![]
+            })
+            .map_err(|err| TrySendError::Disconnected(err.0))
+    }
+
+    /// Sends an event without ever blocking.
+    /// Returns the events dropped by the [`OverflowPolicy`], if any.
+    ///
+    /// With [`OverflowPolicy::Block`], a full channel results in a [`TrySendError::Full`].
+    pub(crate) fn try_send(&self, ev: Ev) -> Result<Vec<Ev>, TrySendError<Ev>> {
+        let backend = self.backend.get();
+        let mut ev = ev;
+        let mut dropped = vec![];
+        // Counted up front, so a receiver never sees the event before it was counted.
+        self.queued.add();
+        loop {
+            match (backend.try_send(ev), self.policy) {
+                (Err(TrySendError::Full(rejected)), OverflowPolicy::DropOldest) => {
+                    if let Ok(oldest) = backend.try_recv() {
+                        self.queued.remove();
+                        dropped.push(oldest);
+                    }
+                    ev = rejected;
+                }
+                (Err(TrySendError::Full(rejected)), OverflowPolicy::DropNewest) => {
+                    self.queued.remove();
+                    return Ok(vec![rejected]);
+                }
+                (res, _) => {
+                    return res.map(|()| dropped).inspect_err(|_| self.queued.remove());
+                }
+            }
+        }
+    }
+
+    /// Sends an event through the urgent lane, which is unbounded,
+    /// so the [`OverflowPolicy`] never applies.
+    pub(crate) fn send_urgent(&self, ev: Ev) {
+        self.queued.add();
+        self.urgent.lock().push_back(ev);
+    }
+
+    /// Receives the oldest urgent event, if any.
+    fn try_recv_urgent(&self) -> Option<Ev> {
+        let ev = self.urgent.lock().pop_front()?;
+        self.queued.remove();
+        Some(ev)
+    }
+
+    pub(crate) fn try_recv(&self) -> Result<Ev, TryRecvError> {
+        if let Some(ev) = self.try_recv_urgent() {
+            return Ok(ev);
+        }
+        let ev = self.backend.get().try_recv()?;
+        self.queued.remove();
+        Ok(ev)
+    }
+
+    /// Blocks until an event is received.
+    ///
+    /// Urgent events sent while blocking are received after the next regular event.
+    #[cfg(feature = "std")]
+    pub(crate) fn recv(&self) -> Result<Ev, RecvError> {
+        if let Some(ev) = self.try_recv_urgent() {
+            return Ok(ev);
+        }
+        let ev = self.backend.get().recv()?;
+        self.queued.remove();
+        Ok(ev)
+    }
+
+    /// Awaits the next event of the default backend of async mediators, an [`AsyncChannel`],
+    /// unless `woken` completes first, which returns `None`.
+    ///
+    /// Other backends can't be awaited, so this only awaits `woken`.
+    /// Urgent events aren't received, see [`EventChannel::urgent_before()`].
+    #[cfg(feature = "async")]
+    pub(crate) async fn recv_or(
+        &self,
+        woken: impl Future<Output = ()>,
+    ) -> Option<Result<Ev, TryRecvError>> {
+        let Backend::Async((_, receiver)) = &self.backend else {
+            woken.await;
+            return None;
+        };
+        let mut recv = pin!(receiver.recv());
+        let mut woken = pin!(woken);
+        // A received event wins over being woken up at the same time.
+        let received = poll_fn(|cx| match recv.as_mut().poll(cx) {
+            Poll::Ready(received) => Poll::Ready(Some(received)),
+            Poll::Pending => woken.as_mut().poll(cx).map(|()| None),
+        })
+        .await?;
+        Some(match received {
+            Ok(ev) => {
+                self.queued.remove();
+                Ok(ev)
+            }
+            Err(_) => Err(TryRecvError::Disconnected),
+        })
+    }
+
+    /// Receives the oldest urgent event in place of `ev`, an event already received
+    /// by [`EventChannel::recv_or()`], which is then received after all other urgent events.
+    /// Returns `ev` if there is no urgent event.
+    #[cfg(feature = "async")]
+    pub(crate) fn urgent_before(&self, ev: Ev) -> Ev {
+        let mut urgent = self.urgent.lock();
+        match urgent.pop_front() {
+            Some(oldest) => {
+                urgent.push_back(ev);
+                oldest
+            }
+            None => ev,
+        }
+    }
+
+    /// Returns the number of events waiting in the channel.
+    pub(crate) fn len(&self) -> usize {
+        self.queued.get()
+    }
+}
@@ -0,0 +1,191 @@
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    fmt::Debug,
+    io::{self, BufRead},
+};
+
+/// The format events are serialized in whenever they cross a process or disk boundary,
+/// i.e. in a [`Journal`](crate::persistence::Journal) and, with the `remote` feature,
+/// between the remote bridges.
+///
+/// Every event is encoded into a frame of its own.
+/// Line-delimited codecs write one frame per line, all others prefix every frame
+/// with its length as a little-endian `u32`. Both sides must use the same codec.
+///
+/// | Codec | Feature | Line-delimited | Upcasting |
+/// |-|-|-|-|
+/// | [`JsonCodec`] | `persistence` | yes | yes |
+/// | `BincodeCodec` | `bincode` | no | no |
+/// | `MessagePackCodec` | `msgpack` | no | yes |
+/// | `CborCodec` | `cbor` | no | yes |
+///
+/// Upcasters, see [`Upcasters`](crate::persistence::Upcasters), receive older events
+/// as a JSON [`Value`](serde_json::Value) decoded by the codec,
+/// which requires a self-describing format.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use mediatrix::codec::JsonCodec;
+/// use mediatrix::persistence::Journal;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// enum MyEvent {
+///     One
+/// }
+///
+/// let path = std::env::temp_dir().join("mediatrix-codec-doctest.jsonl");
+/// # std::fs::remove_file(&path).ok();
+/// let journal = Journal::open_with_codec(&path, JsonCodec).unwrap();
+///
+/// let mediator = BasicMediator::<MyEvent>::builder()
+///     .with_observer(journal)
+///     .build();
+///
+/// mediator.publish(MyEvent::One);
+///
+/// let fresh = BasicMediator::<MyEvent>::builder().build();
+/// assert_eq!(fresh.replay_from_with(&path, JsonCodec).unwrap(), 1);
+/// # std::fs::remove_file(&path).ok();
+///
+pub trait Codec: Debug + Clone + Send + Sync + 'static {
+    /// Serializes the `value` into a single frame.
+    fn encode<T: Serialize + ?Sized>(&self, value: &T) -> io::Result<Vec<u8>>;
+
+    /// Deserializes a value from a single `frame`.
+    fn decode<T: DeserializeOwned>(&self, frame: &[u8]) -> io::Result<T>;
+
+    /// Returns `true` if the encoded frames never contain a newline,
+    /// so they are written one per line instead of length-prefixed.
+    fn is_line_delimited(&self) -> bool {
+        false
+    }
+}
+
+/// The [`Codec`] of JSON, writing one JSON object per line.
+///
+/// The default codec, journals written before codecs were introduced are JSON.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JsonCodec;
+
+impl Codec for JsonCodec {
+    fn encode<T: Serialize + ?Sized>(&self, value: &T) -> io::Result<Vec<u8>> {
+        Ok(serde_json::to_vec(value)?)
+    }
+
+    fn decode<T: DeserializeOwned>(&self, frame: &[u8]) -> io::Result<T> {
+        Ok(serde_json::from_slice(frame)?)
+    }
+
+    fn is_line_delimited(&self) -> bool {
+        true
+    }
+}
+
+/// The [`Codec`] of [`bincode`], a compact binary format.
+///
+/// The format isn't self-describing, so events can't be upcasted.
+#[cfg(feature = "bincode")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BincodeCodec;
+
+#[cfg(feature = "bincode")]
+impl Codec for BincodeCodec {
+    fn encode<T: Serialize + ?Sized>(&self, value: &T) -> io::Result<Vec<u8>> {
+        bincode::serialize(value).map_err(invalid_data)
+    }
+
+    fn decode<T: DeserializeOwned>(&self, frame: &[u8]) -> io::Result<T> {
+        bincode::deserialize(frame).map_err(invalid_data)
+    }
+}
+
+/// The [`Codec`] of MessagePack, through [`rmp_serde`].
+///
+/// Structs are written as maps, so their field names are kept for upcasting.
+#[cfg(feature = "msgpack")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MessagePackCodec;
+
+#[cfg(feature = "msgpack")]
+impl Codec for MessagePackCodec {
+    fn encode<T: Serialize + ?Sized>(&self, value: &T) -> io::Result<Vec<u8>> {
+        rmp_serde::to_vec_named(value).map_err(invalid_data)
+    }
+
+    fn decode<T: DeserializeOwned>(&self, frame: &[u8]) -> io::Result<T> {
+        rmp_serde::from_slice(frame).map_err(invalid_data)
+    }
+}
+
+/// The [`Codec`] of CBOR, through [`ciborium`].
+#[cfg(feature = "cbor")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CborCodec;
+
+#[cfg(feature = "cbor")]
+impl Codec for CborCodec {
+    fn encode<T: Serialize + ?Sized>(&self, value: &T) -> io::Result<Vec<u8>> {
+        let mut frame = Vec::new();
+        ciborium::into_writer(value, &mut frame).map_err(invalid_data)?;
+        Ok(frame)
+    }
+
+    fn decode<T: DeserializeOwned>(&self, frame: &[u8]) -> io::Result<T> {
+        ciborium::from_reader(frame).map_err(invalid_data)
+    }
+}
+
+#[cfg(any(feature = "bincode", feature = "msgpack", feature = "cbor"))]
+fn invalid_data(err: impl std::error::Error + Send + Sync + 'static) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Encodes the `value` into a frame, terminated by a newline or prefixed with its length.
+pub(crate) fn encode_frame<C: Codec, T: Serialize + ?Sized>(
+    codec: &C,
+    value: &T,
+) -> io::Result<Vec<u8>> {
+    let mut frame = codec.encode(value)?;
+    if codec.is_line_delimited() {
+        frame.push(b'\n');
+        return Ok(frame);
+    }
+    let len = u32::try_from(frame.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "frame too large"))?;
+    frame.splice(0..0, len.to_le_bytes());
+    Ok(frame)
+}
+
+/// Reads the next frame written by [`encode_frame()`], skipping blank lines,
+/// or returns `None` at the end of the `reader`.
+pub(crate) fn read_frame<C: Codec>(
+    codec: &C,
+    reader: &mut impl BufRead,
+) -> io::Result<Option<Vec<u8>>> {
+    if codec.is_line_delimited() {
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            if reader.read_until(b'\n', &mut line)? == 0 {
+                return Ok(None);
+            }
+            if !line.trim_ascii().is_empty() {
+                return Ok(Some(line));
+            }
+        }
+    }
+    let mut len = [0; 4];
+    match reader.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err),
+    }
+    let mut frame = vec![0; u32::from_le_bytes(len) as usize];
+    reader.read_exact(&mut frame)?;
+    Ok(Some(frame))
+}
@@ -0,0 +1,44 @@
+use std::{
+    future::{poll_fn, Future},
+    pin::Pin,
+    task::Poll,
+};
+
+/// Awaits all `futures`, keeping at most `limit` of them in flight at once.
+///
+/// Futures are started in iteration order, their outputs are returned in completion order.
+/// With a `limit` of `1`, the futures are awaited one after another.
+pub(crate) async fn join_limited<I>(futures: I, limit: usize) -> Vec<<I::Item as Future>::Output>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    let mut pending = futures.into_iter();
+    let mut running: Vec<Pin<Box<I::Item>>> = vec![];
+    let mut outputs = vec![];
+    poll_fn(|cx| loop {
+        while running.len() < limit.max(1) {
+            match pending.next() {
+                Some(fut) => running.push(Box::pin(fut)),
+                None => break,
+            }
+        }
+        if running.is_empty() {
+            return Poll::Ready(());
+        }
+        let in_flight = running.len();
+        running.retain_mut(|fut| match fut.as_mut().poll(cx) {
+            Poll::Ready(output) => {
+                outputs.push(output);
+                false
+            }
+            Poll::Pending => true,
+        });
+        // Start the next futures in place of the completed ones.
+        if running.len() == in_flight {
+            return Poll::Pending;
+        }
+    })
+    .await;
+    outputs
+}
@@ -0,0 +1,359 @@
+use alloc::{borrow::ToOwned, string::String};
+use core::{
+    any::type_name,
+    fmt::{Debug, Display},
+    sync::atomic::{AtomicU64, Ordering},
+};
+#[cfg(feature = "std")]
+use std::cell::{Cell, RefCell};
+
+#[cfg(feature = "async")]
+use core::{
+    future::{poll_fn, Future},
+    pin::pin,
+};
+
+#[cfg(not(feature = "std"))]
+use crate::mediator::sync::Mutex;
+#[cfg(feature = "std")]
+use crate::mediator::time::SystemTime;
+#[cfg(feature = "causality")]
+use crate::mediator::{causality::Cause, listener::ListenerHandle};
+
+const NONE: Trace = Trace {
+    correlation: None,
+    source: None,
+    event: None,
+    #[cfg(feature = "causality")]
+    cause: None,
+};
+
+#[cfg(feature = "std")]
+thread_local! {
+    static CURRENT: Cell<Trace> = const { Cell::new(NONE) };
+    static TOPIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Without threads, there is a single, global [`Trace`].
+#[cfg(not(feature = "std"))]
+static CURRENT: Mutex<Trace> = Mutex::new(NONE);
+/// Without threads, there is a single, global topic.
+#[cfg(not(feature = "std"))]
+static TOPIC: Mutex<Option<String>> = Mutex::new(None);
+
+/// Sets the current [`Trace`], returning the previous one.
+fn replace_current(trace: Trace) -> Trace {
+    #[cfg(feature = "std")]
+    return CURRENT.with(|current| current.replace(trace));
+    #[cfg(not(feature = "std"))]
+    return core::mem::replace(&mut *CURRENT.lock(), trace);
+}
+
+/// Sets the topic of the event being processed, returning the previous one.
+fn replace_topic(topic: Option<String>) -> Option<String> {
+    #[cfg(feature = "std")]
+    return TOPIC.with(|current| current.replace(topic));
+    #[cfg(not(feature = "std"))]
+    return core::mem::replace(&mut *TOPIC.lock(), topic);
+}
+
+/// Returns the topic of the event being processed, if any.
+fn current_topic() -> Option<String> {
+    #[cfg(feature = "std")]
+    return TOPIC.with(|current| current.borrow().clone());
+    #[cfg(not(feature = "std"))]
+    return TOPIC.lock().clone();
+}
+
+/// Identifies a request sent with `send_traced()`
+/// and ties all events published while handling it back to that request.
+///
+/// The ID is attached to every event published from within the handler,
+/// as well as from within listeners processing such an event.
+/// Listeners access it through [`Enveloped`] or [`CorrelationId::current()`].
+/// Work moved to other threads or spawned tasks doesn't carry the ID along.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use std::sync::{Arc, Mutex};
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     One
+/// }
+///
+/// struct Request;
+///
+/// impl RequestHandler<Request, MyEvent> for BasicMediator<MyEvent> {
+///     fn handle(&self, _req: Request) {
+///         self.publish(MyEvent::One);
+///     }
+/// }
+///
+/// let seen = Arc::new(Mutex::new(None));
+/// let cloned = seen.clone();
+///
+/// let mediator = BasicMediator::<MyEvent>::builder()
+///     .add_enveloped_listener(move |ev: &Enveloped<'_, MyEvent>| {
+///         *cloned.lock().unwrap() = ev.correlation_id();
+///     })
+///     .build();
+///
+/// let id = mediator.send_traced(Request);
+/// mediator.next().ok();
+///
+/// assert_eq!(*seen.lock().unwrap(), Some(id));
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CorrelationId(u64);
+
+impl CorrelationId {
+    /// Creates a new, unique [`CorrelationId`].
+    pub(crate) fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        CorrelationId(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Returns the [`CorrelationId`] of the request currently being handled
+    /// or of the event currently being processed on this thread, if any.
+    pub fn current() -> Option<Self> {
+        Trace::current().correlation
+    }
+
+    /// Returns the numeric value of the ID.
+    pub fn get(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for CorrelationId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// Everything known about the origin of the work currently running on this thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Trace {
+    /// The [`CorrelationId`] of the traced request, if any.
+    pub(crate) correlation: Option<CorrelationId>,
+    /// The type name of the request being handled, if any.
+    ///
+    /// Type names can't be deserialized, so they are lost when serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) source: Option<&'static str>,
+    /// The metadata of the event being processed, if any.
+    pub(crate) event: Option<Stamp>,
+    /// The request handler or listener currently running, if any.
+    #[cfg(feature = "causality")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) cause: Option<Cause>,
+}
+
+/// The metadata an event is stamped with when it is published.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Stamp {
+    pub(crate) sequence: u64,
+    #[cfg(feature = "std")]
+    pub(crate) timestamp: SystemTime,
+}
+
+impl Trace {
+    /// Returns the [`Trace`] of the current thread.
+    pub(crate) fn current() -> Self {
+        #[cfg(feature = "std")]
+        return CURRENT.with(Cell::get);
+        #[cfg(not(feature = "std"))]
+        return *CURRENT.lock();
+    }
+
+    /// Returns this [`Trace`] for handling a request `Req`.
+    pub(crate) fn request<Req>(self) -> Self {
+        Trace {
+            source: Some(type_name::<Req>()),
+            #[cfg(feature = "causality")]
+            cause: Some(Cause::Request(type_name::<Req>())),
+            ..self
+        }
+    }
+
+    /// Returns this [`Trace`] for invoking the listener with the given `handle`.
+    #[cfg(feature = "causality")]
+    pub(crate) fn listener(self, handle: ListenerHandle) -> Self {
+        Trace {
+            cause: Some(Cause::Listener(handle)),
+            ..self
+        }
+    }
+
+    /// Returns this [`Trace`] for handling a request traced by `id`.
+    pub(crate) fn traced(self, id: CorrelationId) -> Self {
+        Trace {
+            correlation: Some(id),
+            ..self
+        }
+    }
+
+    /// Returns the [`Trace`] an event published right now is stamped with.
+    ///
+    /// The sequence number is unique across all mediators
+    /// and increases in publishing order.
+    pub(crate) fn publish() -> Self {
+        static NEXT_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+        Trace {
+            event: Some(Stamp {
+                sequence: NEXT_SEQUENCE.fetch_add(1, Ordering::Relaxed),
+                #[cfg(feature = "std")]
+                timestamp: SystemTime::now(),
+            }),
+            ..Self::current()
+        }
+    }
+}
+
+/// Restores the previous [`Trace`] when dropped,
+/// even if the scope is left by a panic.
+struct Restore(Trace);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        replace_current(self.0);
+    }
+}
+
+/// Runs `f` with `trace` as the current [`Trace`].
+pub(crate) fn scope<R>(trace: Trace, f: impl FnOnce() -> R) -> R {
+    let _restore = Restore(replace_current(trace));
+    f()
+}
+
+/// Restores the previous topic when dropped,
+/// even if the scope is left by a panic.
+struct RestoreTopic(Option<String>);
+
+impl Drop for RestoreTopic {
+    fn drop(&mut self) {
+        replace_topic(self.0.take());
+    }
+}
+
+/// Runs `f` with `topic` as the topic of the event being processed,
+/// which is what [`EventMeta::topic()`] returns.
+pub(crate) fn scope_topic<R>(topic: Option<&str>, f: impl FnOnce() -> R) -> R {
+    let _restore = RestoreTopic(replace_topic(topic.map(ToOwned::to_owned)));
+    f()
+}
+
+/// Awaits `fut` with `trace` as the current [`Trace`].
+///
+/// The trace is set around every poll, so it holds
+/// no matter which thread the future is polled on.
+#[cfg(feature = "async")]
+pub(crate) async fn scope_async<F: Future>(trace: Trace, fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    poll_fn(|cx| scope(trace, || fut.as_mut().poll(cx))).await
+}
+
+/// An event `Ev` together with its metadata: the [`CorrelationId`]
+/// and type name of the request it originates from, if any,
+/// as well as its sequence number and the time it was published.
+///
+/// Received by listeners added with `add_enveloped_listener()`.
+/// This is useful to audit events or to debug their ordering.
+#[derive(Debug)]
+pub struct Enveloped<'a, Ev> {
+    event: &'a Ev,
+    trace: Trace,
+}
+
+impl<'a, Ev> Enveloped<'a, Ev> {
+    /// Wraps the event currently being processed.
+    pub(crate) fn current(event: &'a Ev) -> Self {
+        Self {
+            event,
+            trace: Trace::current(),
+        }
+    }
+
+    /// Returns the event.
+    pub fn event(&self) -> &'a Ev {
+        self.event
+    }
+
+    /// Returns the [`CorrelationId`] of the request the event originates from, if any.
+    pub fn correlation_id(&self) -> Option<CorrelationId> {
+        self.trace.correlation
+    }
+
+    /// Returns the type name of the request whose handler published the event, if any.
+    ///
+    /// Events published from within listeners inherit the source of the event being processed.
+    pub fn source(&self) -> Option<&'static str> {
+        self.trace.source
+    }
+
+    /// Returns the sequence number of the event.
+    ///
+    /// Sequence numbers are unique across all mediators and increase in publishing order.
+    /// Returns `None` if the event wasn't delivered through the channel of a mediator.
+    pub fn sequence(&self) -> Option<u64> {
+        self.trace.event.map(|meta| meta.sequence)
+    }
+
+    /// Returns the time the event was published.
+    ///
+    /// Returns `None` if the event wasn't delivered through the channel of a mediator.
+    #[cfg(feature = "std")]
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        self.trace.event.map(|meta| meta.timestamp)
+    }
+}
+
+/// The metadata of an event: its sequence number,
+/// the time it was published and the topic it was published to.
+///
+/// Received by listeners added with `add_catch_all_listener()`,
+/// along with every event the mediator processes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventMeta {
+    stamp: Option<Stamp>,
+    topic: Option<String>,
+}
+
+impl EventMeta {
+    /// Returns the metadata of the event currently being processed.
+    pub(crate) fn current() -> Self {
+        Self {
+            stamp: Trace::current().event,
+            topic: current_topic(),
+        }
+    }
+
+    /// Returns the sequence number of the event.
+    ///
+    /// Sequence numbers are unique across all mediators and increase in publishing order.
+    /// Returns `None` if the event wasn't delivered through the channel of a mediator.
+    pub fn sequence(&self) -> Option<u64> {
+        self.stamp.map(|stamp| stamp.sequence)
+    }
+
+    /// Returns the time the event was published.
+    ///
+    /// Returns `None` if the event wasn't delivered through the channel of a mediator.
+    #[cfg(feature = "std")]
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        self.stamp.map(|stamp| stamp.timestamp)
+    }
+
+    /// Returns the topic the event was published to with `publish_to()`, if any.
+    pub fn topic(&self) -> Option<&str> {
+        self.topic.as_deref()
+    }
+}
@@ -0,0 +1,206 @@
+use alloc::{string::String, vec, vec::Vec};
+use core::time::Duration;
+
+use crate::mediator::{
+    channel::EventChannel,
+    listener::{ListenerHandle, ListenerPanic},
+    time::Stopwatch,
+    topics::Envelope,
+};
+
+/// The reason why an event ended up in the dead-letter queue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeadLetterReason {
+    /// The event was dropped because the channel was full,
+    /// either by the [`OverflowPolicy`](crate::channel::OverflowPolicy)
+    /// or because it was rejected and `publish()` can't hand it back.
+    Overflow,
+    /// The listeners identified by the handles panicked while processing the event.
+    ///
+    /// Only caught panics lead to a dead letter, which requires isolated listeners.
+    ListenerPanicked(Vec<ListenerHandle>),
+    /// The event was dropped because events were published faster
+    /// than the rate limit configured with `with_rate_limit()` allows.
+    RateLimited,
+    /// The event was dropped because its time-to-live elapsed before it was processed,
+    /// see `publish_with_ttl()`.
+    Expired,
+}
+
+impl DeadLetterReason {
+    /// Returns [`DeadLetterReason::ListenerPanicked`] if any listener panicked.
+    fn from_panics(panics: &[ListenerPanic]) -> Option<Self> {
+        (!panics.is_empty())
+            .then(|| Self::ListenerPanicked(panics.iter().map(ListenerPanic::handle).collect()))
+    }
+}
+
+/// An event `Ev` that could not be processed, together with the [`DeadLetterReason`].
+///
+/// Use [`DeadLetter::into_event()`] to get the event back, e.g. to publish it again,
+/// or redeliver it with `redeliver_all()` or `redeliver_with()`.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeadLetter<Ev> {
+    envelope: Envelope<Ev>,
+    reason: DeadLetterReason,
+    #[cfg_attr(feature = "serde", serde(default))]
+    listeners: Vec<String>,
+    /// Measures the cool-down, restarted when deserialized.
+    #[cfg_attr(feature = "serde", serde(skip, default = "Stopwatch::start"))]
+    since: Stopwatch,
+}
+
+impl<Ev> DeadLetter<Ev> {
+    pub(crate) fn new(envelope: Envelope<Ev>, reason: DeadLetterReason) -> Self {
+        Self {
+            envelope,
+            reason,
+            listeners: vec![],
+            since: Stopwatch::start(),
+        }
+    }
+
+    /// Returns a [`DeadLetter`] naming the listeners that panicked, if any did.
+    pub(crate) fn panicked(envelope: Envelope<Ev>, panics: &[ListenerPanic]) -> Option<Self> {
+        let reason = DeadLetterReason::from_panics(panics)?;
+        Some(Self {
+            listeners: panics.iter().map(|panic| panic.name().into()).collect(),
+            ..Self::new(envelope, reason)
+        })
+    }
+
+    /// Returns the event that could not be processed.
+    pub fn event(&self) -> &Ev {
+        &self.envelope.ev
+    }
+
+    /// Returns the topic the event was published to, if any.
+    pub fn topic(&self) -> Option<&str> {
+        self.envelope.topic.as_deref()
+    }
+
+    /// Returns why the event could not be processed.
+    pub fn reason(&self) -> &DeadLetterReason {
+        &self.reason
+    }
+
+    /// Returns the names of the listeners that panicked while processing the event,
+    /// in the order of the handles in [`DeadLetterReason::ListenerPanicked`].
+    ///
+    /// Listeners are named with `add_listener_named()`, or else by their type name.
+    /// Empty for any other [`DeadLetterReason`].
+    pub fn listener_names(&self) -> &[String] {
+        &self.listeners
+    }
+
+    /// Returns how often the event was redelivered from the dead-letter queue before,
+    /// `0` if it failed on its first delivery.
+    pub fn attempts(&self) -> u32 {
+        self.envelope.attempts
+    }
+
+    /// Consumes the [`DeadLetter`] and returns the event.
+    pub fn into_event(self) -> Ev {
+        self.envelope.ev
+    }
+}
+
+/// Configures which dead letters are redelivered by `redeliver_with()`.
+///
+/// A dead letter is redelivered once it waited for the `cool_down` in the dead-letter queue,
+/// doubled for every earlier redelivery of the event.
+/// Events redelivered `max_attempts` times stay in the queue for good,
+/// to be taken out with `dead_letters()`.
+/// The default policy redelivers all dead letters immediately.
+///
+/// Without the `std` feature, there is no clock, so the cool-down is ignored.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::deadletter::RedeliveryPolicy;
+/// use std::time::Duration;
+///
+/// // Redelivers an event after 100ms, 200ms and 400ms in the dead-letter queue, then keeps it.
+/// let policy = RedeliveryPolicy {
+///     max_attempts: Some(3),
+///     cool_down: Duration::from_millis(100),
+/// };
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RedeliveryPolicy {
+    /// The maximum number of redeliveries of an event, unlimited if `None`.
+    pub max_attempts: Option<u32>,
+    /// The time a dead letter waits before its first redelivery,
+    /// doubled for every further redelivery.
+    pub cool_down: Duration,
+}
+
+impl RedeliveryPolicy {
+    /// Returns whether the `letter` is to be redelivered now.
+    fn is_due<Ev>(&self, letter: &DeadLetter<Ev>) -> bool {
+        let attempts = letter.attempts();
+        if self.max_attempts.is_some_and(|max| attempts >= max) {
+            return false;
+        }
+        let cool_down = self.cool_down.saturating_mul(2u32.saturating_pow(attempts));
+        !cfg!(feature = "std") || letter.since.elapsed() >= cool_down
+    }
+
+    /// Takes the dead letters that are due out of the `queue`,
+    /// returning their envelopes with one more attempt.
+    ///
+    /// All other letters are put back into the `queue`.
+    pub(crate) fn take_due<Ev>(
+        &self,
+        queue: Option<&EventChannel<DeadLetter<Ev>>>,
+    ) -> Vec<Envelope<Ev>> {
+        let Some(queue) = queue else {
+            return vec![];
+        };
+        let mut due = vec![];
+        for letter in DeadLetters::drain(Some(queue)) {
+            if self.is_due(&letter) {
+                let mut envelope = letter.envelope;
+                envelope.attempts += 1;
+                due.push(envelope);
+            } else {
+                queue.send(letter).ok();
+            }
+        }
+        due
+    }
+}
+
+/// An iterator over the [`DeadLetter`]s taken out of the dead-letter queue, oldest first.
+#[derive(Debug)]
+pub struct DeadLetters<Ev>(alloc::vec::IntoIter<DeadLetter<Ev>>);
+
+impl<Ev> DeadLetters<Ev> {
+    /// Takes all dead letters currently in the `queue`.
+    pub(crate) fn drain(queue: Option<&EventChannel<DeadLetter<Ev>>>) -> Self {
+        let letters = match queue {
+            Some(queue) => core::iter::from_fn(|| queue.try_recv().ok()).collect(),
+            None => vec![],
+        };
+        Self(letters.into_iter())
+    }
+}
+
+impl<Ev> Iterator for DeadLetters<Ev> {
+    type Item = DeadLetter<Ev>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<Ev> ExactSizeIterator for DeadLetters<Ev> {}
@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::mediator::{
+    correlation::{self, Trace},
+    listener::{AsyncListener, MaybeDebug},
+    runtime,
+};
+
+type KeyFn<Ev, K> = dyn Fn(&Ev) -> K + Send + Sync;
+
+/// An [`AsyncListener`] receiving only the latest event per key within a time window,
+/// added with `add_debounced_listener()`.
+///
+/// The first event of a key opens a window of that key.
+/// Events of the same key arriving while the window is open replace the pending one.
+/// Once the window closes, the pending event is delivered on a spawned task,
+/// together with the [`CorrelationId`](crate::correlation::CorrelationId) it was published with.
+pub(crate) struct Debouncer<Ev, K, F> {
+    window: Duration,
+    key_fn: Box<KeyFn<Ev, K>>,
+    shared: Arc<Shared<Ev, K, F>>,
+}
+
+struct Shared<Ev, K, F> {
+    listener: F,
+    pending: Mutex<BTreeMap<K, (Ev, Trace)>>,
+}
+
+impl<Ev, K, F> Debouncer<Ev, K, F> {
+    pub(crate) fn new(
+        window: Duration,
+        key_fn: impl Fn(&Ev) -> K + Send + Sync + 'static,
+        listener: F,
+    ) -> Self {
+        Self {
+            window,
+            key_fn: Box::new(key_fn),
+            shared: Arc::new(Shared {
+                listener,
+                pending: Mutex::new(BTreeMap::new()),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl<Ev, K, F> AsyncListener<Ev> for Debouncer<Ev, K, F>
+where
+    Ev: MaybeDebug + Clone + Send + Sync + 'static,
+    K: Ord + Clone + Send + Sync + 'static,
+    F: AsyncListener<Ev>,
+{
+    async fn on_event(&self, ev: &Ev) {
+        let key = (self.key_fn)(ev);
+        let opened = {
+            let mut pending = self
+                .shared
+                .pending
+                .lock()
+                .unwrap_or_else(|err| err.into_inner());
+            pending
+                .insert(key.clone(), (ev.clone(), Trace::current()))
+                .is_none()
+        };
+        if !opened {
+            return;
+        }
+        let (shared, window) = (self.shared.clone(), self.window);
+        drop(runtime::spawn(async move {
+            runtime::sleep(window).await;
+            let due = {
+                let mut pending = shared.pending.lock().unwrap_or_else(|err| err.into_inner());
+                pending.remove(&key)
+            };
+            if let Some((ev, trace)) = due {
+                correlation::scope_async(trace, shared.listener.on_event(&ev)).await;
+            }
+        }));
+    }
+}
@@ -0,0 +1,54 @@
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::{
+    any::{type_name, Any, TypeId},
+    fmt::Debug,
+};
+
+/// A type-map based container of dependencies.
+///
+/// [`Dependencies`] can be used as the context `Cx` of a context aware mediator.
+/// Instead of bundling every dependency into one big context struct,
+/// each dependency is added separately with `add_dependency()` on the builder
+/// and resolved by its type with [`Dependencies::get()`] from within the handler.
+/// At most one value per type is stored.
+#[derive(Default)]
+pub struct Dependencies {
+    map: BTreeMap<TypeId, (&'static str, Box<dyn Any + Send + Sync>)>,
+}
+
+impl Dependencies {
+    /// Inserts a dependency of type `T`, replacing a previous one of the same type.
+    pub fn insert<T>(&mut self, value: T)
+    where
+        T: Send + Sync + 'static,
+    {
+        self.map
+            .insert(TypeId::of::<T>(), (type_name::<T>(), Box::new(value)));
+    }
+
+    /// Returns a reference to the dependency of type `T`, if there is one.
+    pub fn get<T>(&self) -> Option<&T>
+    where
+        T: 'static,
+    {
+        self.map
+            .get(&TypeId::of::<T>())
+            .and_then(|(_, value)| value.downcast_ref())
+    }
+
+    /// Returns `true` if a dependency of type `T` is available.
+    pub fn contains<T>(&self) -> bool
+    where
+        T: 'static,
+    {
+        self.map.contains_key(&TypeId::of::<T>())
+    }
+}
+
+impl Debug for Dependencies {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set()
+            .entries(self.map.values().map(|(name, _)| name))
+            .finish()
+    }
+}
@@ -0,0 +1,133 @@
+use core::fmt::Display;
+
+#[cfg(feature = "async")]
+use crate::mediator::{
+    asynchronous::contextaware::context::ReentrantSendError,
+    cancellation::{CancelledError, TimeoutError},
+};
+use crate::mediator::{builder::BuildError, channel::TrySendError, listener::NextError};
+
+/// The error of any fallible operation of a mediator.
+///
+/// Every operation returns its own, more specific error,
+/// e.g. a [`BuildError`] from building or a [`NextError`] from `next()`.
+/// Each of them converts into a [`MediatorError`],
+/// so errors of different operations can be propagated together with `?`.
+/// The specific error is available as the [`source`](core::error::Error::source()).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use mediatrix::error::MediatorError;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     One
+/// }
+///
+/// fn run(mediator: &BasicMediator<MyEvent>) -> Result<(), MediatorError> {
+///     mediator.try_publish(MyEvent::One)?;
+///     mediator.next()?;
+///     Ok(())
+/// }
+///
+/// let mediator = BasicMediator::<MyEvent>::builder().build();
+///
+/// assert!(run(&mediator).is_ok());
+///
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MediatorError {
+    /// Building the mediator failed.
+    Build(BuildError),
+    /// Processing the next event failed.
+    Next(NextError),
+    /// Publishing an event failed, the event was dropped.
+    Publish(TrySendError<()>),
+    /// A request was cancelled.
+    #[cfg(feature = "async")]
+    Cancelled(CancelledError),
+    /// A request timed out.
+    #[cfg(feature = "async")]
+    Timeout(TimeoutError),
+    /// A request was sent from within a handler holding the context it needs.
+    #[cfg(feature = "async")]
+    ReentrantSend(ReentrantSendError),
+}
+
+impl Display for MediatorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MediatorError::Build(_) => write!(f, "building the mediator failed"),
+            MediatorError::Next(_) => write!(f, "processing the next event failed"),
+            MediatorError::Publish(_) => write!(f, "publishing the event failed"),
+            #[cfg(feature = "async")]
+            MediatorError::Cancelled(_) => write!(f, "the request was cancelled"),
+            #[cfg(feature = "async")]
+            MediatorError::Timeout(_) => write!(f, "the request timed out"),
+            #[cfg(feature = "async")]
+            MediatorError::ReentrantSend(_) => write!(f, "the request would wait for itself"),
+        }
+    }
+}
+
+impl core::error::Error for MediatorError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            MediatorError::Build(err) => Some(err),
+            MediatorError::Next(err) => Some(err),
+            MediatorError::Publish(err) => Some(err),
+            #[cfg(feature = "async")]
+            MediatorError::Cancelled(err) => Some(err),
+            #[cfg(feature = "async")]
+            MediatorError::Timeout(err) => Some(err),
+            #[cfg(feature = "async")]
+            MediatorError::ReentrantSend(err) => Some(err),
+        }
+    }
+}
+
+impl From<BuildError> for MediatorError {
+    fn from(err: BuildError) -> Self {
+        MediatorError::Build(err)
+    }
+}
+
+impl From<NextError> for MediatorError {
+    fn from(err: NextError) -> Self {
+        MediatorError::Next(err)
+    }
+}
+
+impl<T> From<TrySendError<T>> for MediatorError {
+    fn from(err: TrySendError<T>) -> Self {
+        MediatorError::Publish(match err {
+            TrySendError::Full(_) => TrySendError::Full(()),
+            TrySendError::Disconnected(_) => TrySendError::Disconnected(()),
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+impl From<CancelledError> for MediatorError {
+    fn from(err: CancelledError) -> Self {
+        MediatorError::Cancelled(err)
+    }
+}
+
+#[cfg(feature = "async")]
+impl From<TimeoutError> for MediatorError {
+    fn from(err: TimeoutError) -> Self {
+        MediatorError::Timeout(err)
+    }
+}
+
+#[cfg(feature = "async")]
+impl From<ReentrantSendError> for MediatorError {
+    fn from(err: ReentrantSendError) -> Self {
+        MediatorError::ReentrantSend(err)
+    }
+}
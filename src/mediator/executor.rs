@@ -0,0 +1,207 @@
+use std::{
+    fmt::{Debug, Display},
+    future::Future,
+    pin::Pin,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use crate::mediator::{cancellation::TimeoutError, runtime};
+
+/// A boxed future, as spawned and returned by an [`Executor`].
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// The async runtime the async mediators spawn their tasks and timers on,
+/// e.g. for `spawn_worker()`, tickers, retries and timeouts.
+///
+/// By default, everything runs on `async-std`, see [`AsyncStdExecutor`],
+/// or on the browser's event loop on `wasm32` targets with the `wasm` feature.
+/// Another runtime is plugged in once per process with [`set_executor()`],
+/// e.g. the [`TokioExecutor`] of the `tokio` feature or a custom executor
+/// for runtimes like `smol`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::asynchronous::basic::*;
+/// use mediatrix::executor::{set_executor, AsyncStdExecutor, BoxFuture, Executor};
+/// use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+/// use std::time::Duration;
+///
+/// static SPAWNED: AtomicUsize = AtomicUsize::new(0);
+///
+/// #[derive(Debug)]
+/// struct CountingExecutor;
+///
+/// impl Executor for CountingExecutor {
+///     fn spawn(&self, fut: BoxFuture<()>) {
+///         SPAWNED.fetch_add(1, Ordering::SeqCst);
+///         AsyncStdExecutor.spawn(fut);
+///     }
+///
+///     fn sleep(&self, duration: Duration) -> BoxFuture<()> {
+///         AsyncStdExecutor.sleep(duration)
+///     }
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum MyEvent {
+///     One
+/// }
+///
+/// set_executor(CountingExecutor).unwrap();
+///
+/// let mediator = Arc::new(BasicAsyncMediator::<MyEvent>::builder().build());
+///
+/// async_std::task::block_on(async {
+///     let worker = mediator.clone().spawn_worker();
+///     mediator.publish(MyEvent::One).await;
+///     worker.shutdown().await;
+/// });
+///
+/// assert_eq!(SPAWNED.load(Ordering::SeqCst), 1);
+///
+pub trait Executor: Debug + Send + Sync + 'static {
+    /// Spawns `fut`, which runs to completion in the background.
+    fn spawn(&self, fut: BoxFuture<()>);
+
+    /// Returns a future completing once `duration` elapsed.
+    fn sleep(&self, duration: Duration) -> BoxFuture<()>;
+
+    /// Runs `fut` to completion unless `duration` elapses first,
+    /// in which case `fut` is dropped at its current await point.
+    fn timeout<F: Future>(
+        &self,
+        duration: Duration,
+        fut: F,
+    ) -> impl Future<Output = Result<F::Output, TimeoutError>>
+    where
+        Self: Sized,
+    {
+        let elapsed = self.sleep(duration);
+        async move { runtime::race(fut, elapsed).await.ok_or(TimeoutError) }
+    }
+}
+
+static EXECUTOR: OnceLock<Box<dyn Executor>> = OnceLock::new();
+
+/// Sets the [`Executor`] of all async mediators of the process.
+///
+/// Must be called before the first task or timer is started,
+/// i.e. before any async mediator is used.
+/// Fails if the executor was already set, or the default executor is already in use.
+pub fn set_executor(executor: impl Executor) -> Result<(), SetExecutorError> {
+    let mut executor = Some(executor);
+    EXECUTOR.get_or_init(|| Box::new(executor.take().expect("initialized once")));
+    match executor {
+        Some(_) => Err(SetExecutorError),
+        None => Ok(()),
+    }
+}
+
+/// Returns the [`Executor`] set via [`set_executor()`], or the default one.
+pub(crate) fn current() -> &'static dyn Executor {
+    EXECUTOR
+        .get_or_init(|| {
+            #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+            return Box::new(AsyncStdExecutor);
+            #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+            return Box::new(WasmExecutor);
+        })
+        .as_ref()
+}
+
+/// Error returned by [`set_executor()`] if an executor is already in use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetExecutorError;
+
+impl Display for SetExecutorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "an executor is already in use")
+    }
+}
+
+impl std::error::Error for SetExecutorError {}
+
+/// The [`Executor`] of `async-std`, the default one.
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsyncStdExecutor;
+
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+impl Executor for AsyncStdExecutor {
+    fn spawn(&self, fut: BoxFuture<()>) {
+        drop(async_std::task::spawn(fut));
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<()> {
+        Box::pin(async_std::task::sleep(duration))
+    }
+}
+
+/// The [`Executor`] of the browser, spawning onto its event loop
+/// with timers backed by `setTimeout`.
+///
+/// The default one on `wasm32` targets with the `wasm` feature.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WasmExecutor;
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+impl Executor for WasmExecutor {
+    fn spawn(&self, fut: BoxFuture<()>) {
+        wasm_bindgen_futures::spawn_local(fut);
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<()> {
+        // The timer of the browser isn't `Send`, so it runs on the event loop instead.
+        let (elapsed, received) = async_std::channel::bounded(1);
+        wasm_bindgen_futures::spawn_local(async move {
+            futures_timer::Delay::new(duration).await;
+            elapsed.send(()).await.ok();
+        });
+        Box::pin(async move {
+            received.recv().await.ok();
+        })
+    }
+}
+
+/// The [`Executor`] of a `tokio` runtime.
+///
+/// The runtime has to be built with its time driver enabled.
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone)]
+pub struct TokioExecutor {
+    handle: tokio::runtime::Handle,
+}
+
+#[cfg(feature = "tokio")]
+impl TokioExecutor {
+    /// Creates a [`TokioExecutor`] running on the runtime of the `handle`.
+    pub fn new(handle: tokio::runtime::Handle) -> Self {
+        Self { handle }
+    }
+
+    /// Creates a [`TokioExecutor`] running on the current runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a `tokio` runtime.
+    pub fn current() -> Self {
+        Self::new(tokio::runtime::Handle::current())
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Executor for TokioExecutor {
+    fn spawn(&self, fut: BoxFuture<()>) {
+        drop(self.handle.spawn(fut));
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<()> {
+        let _runtime = self.handle.enter();
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
@@ -0,0 +1,195 @@
+//! Instrumentation of requests, events and listeners.
+//!
+//! With the `tracing` feature, requests and processed events are wrapped in spans
+//! and every published event and listener invocation emits an event.
+//! Without it, all functions simply run what they wrap.
+
+#[cfg(feature = "tracing")]
+pub(crate) use enabled::*;
+
+/// The bound on events `Ev` required by the instrumentation.
+///
+/// With the `tracing` feature, events are recorded with their [`Debug`](core::fmt::Debug)
+/// representation, so they must implement it.
+/// Without it, every type implements [`MaybeDebug`], so events and contexts
+/// may contain fields that aren't `Debug`, e.g. database pools or FFI handles.
+#[cfg(feature = "tracing")]
+pub trait MaybeDebug: core::fmt::Debug {}
+
+#[cfg(feature = "tracing")]
+impl<T: core::fmt::Debug + ?Sized> MaybeDebug for T {}
+
+/// The bound on events `Ev` required by the instrumentation.
+///
+/// Without the `tracing` feature, every type implements [`MaybeDebug`],
+/// so events and contexts may contain fields that aren't `Debug`.
+#[cfg(not(feature = "tracing"))]
+pub trait MaybeDebug {}
+
+#[cfg(not(feature = "tracing"))]
+impl<T: ?Sized> MaybeDebug for T {}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) use disabled::*;
+
+#[cfg(feature = "tracing")]
+mod enabled {
+    use super::MaybeDebug;
+    use crate::mediator::time::Instant;
+    use std::any::type_name;
+    use tracing::Span;
+
+    #[cfg(feature = "async")]
+    use std::future::Future;
+    #[cfg(feature = "async")]
+    use tracing::Instrument;
+
+    use crate::mediator::listener::ListenerHandle;
+
+    /// Runs `f` within `span` and records how long it took.
+    fn traced<R>(span: Span, f: impl FnOnce() -> R) -> R {
+        let _enter = span.enter();
+        let start = Instant::now();
+        let res = f();
+        tracing::debug!(target: "mediatrix", elapsed = ?start.elapsed(), "completed");
+        res
+    }
+
+    /// Awaits `fut` within `span` and records how long it took.
+    #[cfg(feature = "async")]
+    async fn traced_async<F: Future>(span: Span, fut: F) -> F::Output {
+        let start = Instant::now();
+        let res = fut.instrument(span.clone()).await;
+        span.in_scope(
+            || tracing::debug!(target: "mediatrix", elapsed = ?start.elapsed(), "completed"),
+        );
+        res
+    }
+
+    fn request_span<Req>(op: &'static str) -> Span {
+        tracing::debug_span!(target: "mediatrix", "request", op, request = type_name::<Req>())
+    }
+
+    fn next_span<Ev: MaybeDebug>(ev: &Ev, topic: Option<&str>) -> Span {
+        tracing::debug_span!(target: "mediatrix", "next", event = ?ev, topic)
+    }
+
+    pub(crate) fn request<Req, R>(op: &'static str, f: impl FnOnce() -> R) -> R {
+        traced(request_span::<Req>(op), f)
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn request_async<Req, F: Future>(op: &'static str, fut: F) -> F::Output {
+        traced_async(request_span::<Req>(op), fut).await
+    }
+
+    pub(crate) fn published<Ev: MaybeDebug>(ev: &Ev, topic: Option<&str>) {
+        tracing::debug!(target: "mediatrix", event = ?ev, topic, "published");
+    }
+
+    pub(crate) fn next<Ev: MaybeDebug, R>(
+        ev: &Ev,
+        topic: Option<&str>,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        traced(next_span(ev, topic), f)
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn next_async<Ev: MaybeDebug, F: Future>(
+        ev: &Ev,
+        topic: Option<&str>,
+        fut: F,
+    ) -> F::Output {
+        let span = next_span(ev, topic);
+        traced_async(span, fut).await
+    }
+
+    fn listener_span(handle: ListenerHandle, name: &'static str) -> Span {
+        tracing::trace_span!(target: "mediatrix", "listener", listener = ?handle, name)
+    }
+
+    pub(crate) fn listener<R>(
+        handle: ListenerHandle,
+        name: &'static str,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        let _enter = listener_span(handle, name).entered();
+        let start = Instant::now();
+        let res = f();
+        tracing::trace!(target: "mediatrix", listener = ?handle, name, elapsed = ?start.elapsed(), "listener invoked");
+        res
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn listener_async<F: Future>(
+        handle: ListenerHandle,
+        name: &'static str,
+        fut: F,
+    ) -> F::Output {
+        let span = listener_span(handle, name);
+        let start = Instant::now();
+        let res = fut.instrument(span.clone()).await;
+        span.in_scope(
+            || tracing::trace!(target: "mediatrix", listener = ?handle, name, elapsed = ?start.elapsed(), "listener invoked"),
+        );
+        res
+    }
+}
+
+// Signatures mirror the `enabled` module, so `Req` stays unused here.
+#[cfg(not(feature = "tracing"))]
+#[allow(clippy::extra_unused_type_parameters)]
+mod disabled {
+    use super::MaybeDebug;
+
+    #[cfg(feature = "async")]
+    use std::future::Future;
+
+    use crate::mediator::listener::ListenerHandle;
+
+    pub(crate) fn request<Req, R>(_op: &'static str, f: impl FnOnce() -> R) -> R {
+        f()
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn request_async<Req, F: Future>(_op: &'static str, fut: F) -> F::Output {
+        fut.await
+    }
+
+    pub(crate) fn published<Ev: MaybeDebug>(_ev: &Ev, _topic: Option<&str>) {}
+
+    pub(crate) fn next<Ev: MaybeDebug, R>(
+        _ev: &Ev,
+        _topic: Option<&str>,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        f()
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn next_async<Ev: MaybeDebug, F: Future>(
+        _ev: &Ev,
+        _topic: Option<&str>,
+        fut: F,
+    ) -> F::Output {
+        fut.await
+    }
+
+    pub(crate) fn listener<R>(
+        _handle: ListenerHandle,
+        _name: &'static str,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        f()
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn listener_async<F: Future>(
+        _handle: ListenerHandle,
+        _name: &'static str,
+        fut: F,
+    ) -> F::Output {
+        fut.await
+    }
+}
@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use std::{fmt::Debug, future::Future, pin::Pin};
+
+/// Lifecycle hooks of a context `Cx`, for contexts owning resources
+/// such as connection pools or file handles.
+///
+/// The hooks are opt-in: a context aware mediator only calls them
+/// if it was built with `with_lifecycle()`.
+/// [`CxLifecycle::on_start()`] is called once the context exists,
+/// i.e. when the mediator is built or, for a context added with `add_context_with()`,
+/// when the context is created on first use.
+/// [`CxLifecycle::on_shutdown()`] is called by the `shutdown()` of the mediator,
+/// at most once and only if the context was created.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::asynchronous::contextaware::*;
+/// use async_trait::async_trait;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// #[derive(Debug, Clone)]
+/// enum MyEvent {
+///     One
+/// }
+///
+/// struct Pool {
+///     open: AtomicBool,
+/// }
+///
+/// #[async_trait]
+/// impl CxLifecycle for Pool {
+///     fn on_start(&self) {
+///         self.open.store(true, Ordering::SeqCst);
+///     }
+///
+///     async fn on_shutdown(&self) {
+///         self.open.store(false, Ordering::SeqCst);
+///     }
+/// }
+///
+/// async_std::task::block_on(async {
+///     let mediator = CxAwareAsyncMediator::<Pool, MyEvent>::builder()
+///         .add_context(Pool { open: AtomicBool::new(false) })
+///         .with_lifecycle()
+///         .build()
+///         .unwrap();
+///
+///     mediator.shutdown().await;
+/// });
+///
+#[async_trait]
+pub trait CxLifecycle: Send + Sync {
+    /// Called once the context was created, before it is used by any request.
+    fn on_start(&self) {}
+
+    /// Called when the mediator is shut down.
+    async fn on_shutdown(&self) {}
+}
+
+/// The future returned by [`CxLifecycle::on_shutdown()`].
+type ShutdownFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+/// The [`CxLifecycle`] hooks of a context `Cx`,
+/// captured by the builder while `Cx: CxLifecycle` is known.
+pub(crate) struct Lifecycle<Cx> {
+    on_start: fn(&Cx),
+    on_shutdown: for<'a> fn(&'a Cx) -> ShutdownFuture<'a>,
+}
+
+impl<Cx> Lifecycle<Cx>
+where
+    Cx: CxLifecycle,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            on_start: |cx| cx.on_start(),
+            on_shutdown: |cx| cx.on_shutdown(),
+        }
+    }
+}
+
+impl<Cx> Lifecycle<Cx> {
+    pub(crate) fn start(&self, cx: &Cx) {
+        (self.on_start)(cx)
+    }
+
+    pub(crate) async fn shutdown(&self, cx: &Cx) {
+        (self.on_shutdown)(cx).await
+    }
+}
+
+impl<Cx> Clone for Lifecycle<Cx> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Cx> Copy for Lifecycle<Cx> {}
+
+impl<Cx> Debug for Lifecycle<Cx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Lifecycle").finish_non_exhaustive()
+    }
+}
@@ -1,14 +1,49 @@
-use core::fmt::Debug;
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::{
+    any::{type_name, Any},
+    fmt::{Debug, Display},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::mediator::{
+    channel::TryRecvError,
+    observer::Observers,
+    stats::{Invocations, ListenerStats},
+    sync::{Mutex, RwLock},
+    topics::TopicPattern,
+};
+
+pub use crate::mediator::instrument::MaybeDebug;
+
+#[cfg(feature = "std")]
+use crate::mediator::channel::RecvError;
+#[cfg(feature = "schema")]
+use crate::mediator::schema::ListenerDescription;
+#[cfg(feature = "causality")]
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::panic::{self, AssertUnwindSafe};
+
+#[cfg(feature = "async")]
+use crate::mediator::concurrency::join_limited;
+#[cfg(feature = "async")]
+use async_trait::async_trait;
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
 /// A [`Listener`] is a user-defined closure that is generic over its received event `Ev`.
 /// The closure handles the event and may act upon an event.
-pub trait Listener<Ev: Debug>: Fn(&Ev) + Send + 'static {}
+pub trait Listener<Ev: MaybeDebug>: Fn(&Ev) + Send + 'static {}
 
 impl<Ev> Debug for dyn Listener<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Listener Closure")
     }
 }
@@ -16,6 +51,694 @@ where
 impl<Ev, F> Listener<Ev> for F
 where
     F: Fn(&Ev) + Send + 'static,
-    Ev: Debug,
+    Ev: MaybeDebug,
+{
+}
+
+/// A boxed [`Listener`], invoked by one thread at a time.
+///
+/// Listeners aren't required to be `Sync`, so a mediator processing events
+/// on several threads, e.g. with `worker()`, serializes the invocations of each listener.
+pub(crate) struct ExclusiveListener<Ev: MaybeDebug>(Mutex<Box<dyn Listener<Ev>>>);
+
+impl<Ev: MaybeDebug> ExclusiveListener<Ev> {
+    pub(crate) fn new(f: impl Listener<Ev>) -> Self {
+        Self(Mutex::new(Box::new(f)))
+    }
+
+    fn call(&self, ev: &Ev) {
+        (self.0.lock())(ev)
+    }
+}
+
+impl<Ev: MaybeDebug> Debug for ExclusiveListener<Ev> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Listener Closure")
+    }
+}
+
+/// A [`SharedListener`] is a user-defined closure receiving the event `Ev` in an [`Arc`].
+///
+/// Unlike a [`Listener`], it can keep the event beyond its invocation,
+/// e.g. by handing it to another thread, without cloning the event itself.
+/// The event is moved into the [`Arc`] once and shared by all shared listeners.
+pub trait SharedListener<Ev: MaybeDebug>: Fn(Arc<Ev>) + Send + Sync + 'static {}
+
+impl<Ev, F> SharedListener<Ev> for F
+where
+    F: Fn(Arc<Ev>) + Send + Sync + 'static,
+    Ev: MaybeDebug,
+{
+}
+
+/// Takes the event back out of the [`Arc`] after all shared listeners were invoked,
+/// cloning it only if a listener kept a reference.
+pub(crate) fn recover<Ev: Clone>(ev: Arc<Ev>) -> Ev {
+    Arc::try_unwrap(ev).unwrap_or_else(|ev| (*ev).clone())
+}
+
+/// A [`NotificationHandler`] is a user-defined type that is generic over its received event `Ev`.
+///
+/// Other than a [`Listener`] closure, it may be a stateful struct,
+/// which can be shared behind an `Arc` and still be accessed from elsewhere.
+/// Closures `Fn(&Ev) + Send + Sync` are notification handlers as well.
+pub trait NotificationHandler<Ev: MaybeDebug>: Send + Sync + 'static {
+    #[allow(missing_docs)]
+    fn on_event(&self, ev: &Ev);
+
+    /// Returns the name the handler is listed with, defaults to the name of its type.
+    fn name(&self) -> &'static str {
+        type_name::<Self>()
+    }
+}
+
+impl<Ev> Debug for dyn NotificationHandler<Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NotificationHandler")
+    }
+}
+
+impl<Ev, F> NotificationHandler<Ev> for F
+where
+    F: Fn(&Ev) + Send + Sync + 'static,
+    Ev: MaybeDebug,
+{
+    fn on_event(&self, ev: &Ev) {
+        self(ev)
+    }
+}
+
+/// A [`Filter`] is a user-defined predicate that is generic over the received event `Ev`.
+/// A filtered listener is only invoked if its filter returns `true` for an event.
+pub trait Filter<Ev: MaybeDebug>: Fn(&Ev) -> bool + Send + Sync + 'static {}
+
+impl<Ev, F> Filter<Ev> for F
+where
+    F: Fn(&Ev) -> bool + Send + Sync + 'static,
+    Ev: MaybeDebug,
+{
+}
+
+/// A [`PublishInterceptor`] is a user-defined closure that receives every published event `Ev`
+/// before it is enqueued, and returns the event to enqueue, or `None` to drop it.
+/// This allows to rewrite events, e.g. to redact personal data or to normalize variants.
+pub trait PublishInterceptor<Ev: MaybeDebug>: Fn(Ev) -> Option<Ev> + Send + Sync + 'static {}
+
+impl<Ev> Debug for dyn PublishInterceptor<Ev>
+where
+    Ev: MaybeDebug,
 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PublishInterceptor Closure")
+    }
+}
+
+impl<Ev, F> PublishInterceptor<Ev> for F
+where
+    F: Fn(Ev) -> Option<Ev> + Send + Sync + 'static,
+    Ev: MaybeDebug,
+{
+}
+
+/// Returns a [`Filter`] matching events whose variant name is one of `variants`.
+pub(crate) fn variant_filter<Ev>(
+    variants: impl IntoIterator<Item = &'static str>,
+) -> impl Filter<Ev>
+where
+    Ev: MaybeDebug,
+    for<'a> &'a Ev: Into<&'static str>,
+{
+    let variants: Vec<&'static str> = variants.into_iter().collect();
+    move |ev: &Ev| variants.contains(&ev.into())
+}
+
+/// Adds a listener that is only invoked for events matching a pattern to a builder.
+///
+/// `add_listener_matching!(builder, pattern, listener)` expands to
+/// `builder.add_filtered_listener(|ev| matches!(ev, pattern), listener)`,
+/// so it works with every builder offering `add_filtered_listener()`.
+/// The pattern may combine several variants with `|`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::add_listener_matching;
+/// use mediatrix::synchronous::basic::*;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     One,
+///     Two,
+///     Three(u32)
+/// }
+///
+/// let builder = BasicMediator::<MyEvent>::builder();
+/// let mediator = add_listener_matching!(builder, MyEvent::One | MyEvent::Three(1..), |_: &MyEvent| {
+///     /* Not invoked for MyEvent::Two or MyEvent::Three(0) */
+/// })
+/// .build();
+///
+#[macro_export]
+macro_rules! add_listener_matching {
+    ($builder:expr, $pattern:pat, $listener:expr $(,)?) => {
+        $builder.add_filtered_listener(|ev| ::core::matches!(ev, $pattern), $listener)
+    };
+}
+
+/// An [`AsyncListener`] is a user-defined asynchronous listener that is generic over its received event `Ev`.
+///
+/// It is either a type implementing [`AsyncListener::on_event()`]
+/// or a closure `Fn(&Ev) -> Fut`, where `Fut` is a `Future` with an output of `()`.
+/// Because the returned `Future` may not borrow the event,
+/// a closure has to copy or clone what it needs before entering its `async` block.
+#[cfg(feature = "async")]
+#[async_trait]
+pub trait AsyncListener<Ev: MaybeDebug>: Send + Sync + 'static {
+    #[allow(missing_docs)]
+    async fn on_event(&self, ev: &Ev);
+}
+
+#[cfg(feature = "async")]
+impl<Ev> Debug for dyn AsyncListener<Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AsyncListener")
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait]
+impl<Ev, F, Fut> AsyncListener<Ev> for F
+where
+    F: Fn(&Ev) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send,
+    Ev: MaybeDebug + Sync,
+{
+    async fn on_event(&self, ev: &Ev) {
+        self(ev).await
+    }
+}
+
+/// An [`AsyncSharedListener`] is the asynchronous counterpart to a [`SharedListener`].
+///
+/// It is either a type implementing [`AsyncSharedListener::on_event()`]
+/// or a closure `Fn(Arc<Ev>) -> Fut`, where `Fut` is a `Future` with an output of `()`.
+/// Since the event is owned by the [`Arc`], the returned `Future` can move it
+/// into its `async` block without cloning the event.
+#[cfg(feature = "async")]
+#[async_trait]
+pub trait AsyncSharedListener<Ev: MaybeDebug>: Send + Sync + 'static {
+    #[allow(missing_docs)]
+    async fn on_event(&self, ev: Arc<Ev>);
+}
+
+#[cfg(feature = "async")]
+#[async_trait]
+impl<Ev, F, Fut> AsyncSharedListener<Ev> for F
+where
+    F: Fn(Arc<Ev>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send,
+    Ev: MaybeDebug + Send + Sync + 'static,
+{
+    async fn on_event(&self, ev: Arc<Ev>) {
+        self(ev).await
+    }
+}
+
+/// Adapts an [`AsyncSharedListener`] to an [`AsyncListener`] of the shared event.
+#[cfg(feature = "async")]
+pub(crate) struct SharedAsyncListener<L>(pub(crate) L);
+
+#[cfg(feature = "async")]
+#[async_trait]
+impl<Ev, L> AsyncListener<Arc<Ev>> for SharedAsyncListener<L>
+where
+    L: AsyncSharedListener<Ev>,
+    Ev: MaybeDebug + Send + Sync,
+{
+    async fn on_event(&self, ev: &Arc<Ev>) {
+        self.0.on_event(ev.clone()).await
+    }
+}
+
+/// An [`AsyncListener`] that is only awaited if its [`Filter`] returns `true`.
+#[cfg(feature = "async")]
+pub(crate) struct FilteredAsyncListener<P, L> {
+    pub(crate) filter: P,
+    pub(crate) listener: L,
+}
+
+#[cfg(feature = "async")]
+#[async_trait]
+impl<Ev, P, L> AsyncListener<Ev> for FilteredAsyncListener<P, L>
+where
+    P: Filter<Ev>,
+    L: AsyncListener<Ev>,
+    Ev: MaybeDebug + Sync,
+{
+    async fn on_event(&self, ev: &Ev) {
+        if (self.filter)(ev) {
+            self.listener.on_event(ev).await
+        }
+    }
+}
+
+/// A [`ListenerHandle`] identifies a [`Listener`] that was subscribed to a mediator.
+///
+/// It is returned when subscribing a listener at runtime
+/// and can be used to unsubscribe that listener again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListenerHandle(pub(crate) usize);
+
+impl ListenerHandle {
+    /// Returns a handle that is unique across all mediators.
+    fn next() -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        ListenerHandle(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// A panic that was caught while invoking a listener.
+///
+/// Panics are only caught if the mediator was built with listener isolation,
+/// e.g. [`crate::synchronous::basic::BasicBuilder::isolate_listeners()`].
+#[derive(Debug)]
+pub struct ListenerPanic {
+    handle: ListenerHandle,
+    name: &'static str,
+    payload: Box<dyn Any + Send>,
+}
+
+impl ListenerPanic {
+    /// Returns the [`ListenerHandle`] of the listener that panicked.
+    pub fn handle(&self) -> ListenerHandle {
+        self.handle
+    }
+
+    /// Returns the name of the listener that panicked.
+    ///
+    /// This is the name given with `add_listener_named()`,
+    /// or else the type name of the listener.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the panic message, if the payload is a string.
+    pub fn message(&self) -> Option<&str> {
+        self.payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| self.payload.downcast_ref::<String>().map(String::as_str))
+    }
+
+    /// Returns the payload of the panic, e.g. to resume unwinding.
+    pub fn into_payload(self) -> Box<dyn Any + Send> {
+        self.payload
+    }
+}
+
+/// Error returned when processing the next event.
+#[derive(Debug)]
+pub enum NextError {
+    /// There was no pending event.
+    Empty,
+    /// The channel is disconnected, no more events will arrive.
+    Disconnected,
+    /// The event was processed, but at least one listener panicked.
+    ///
+    /// All other listeners were still invoked.
+    ListenerPanicked(Vec<ListenerPanic>),
+}
+
+impl NextError {
+    /// Turns the panics caught while processing an event into a result.
+    pub(crate) fn from_panics(panics: Vec<ListenerPanic>) -> Result<(), NextError> {
+        if panics.is_empty() {
+            Ok(())
+        } else {
+            Err(NextError::ListenerPanicked(panics))
+        }
+    }
+}
+
+impl From<TryRecvError> for NextError {
+    fn from(err: TryRecvError) -> Self {
+        match err {
+            TryRecvError::Empty => NextError::Empty,
+            TryRecvError::Disconnected => NextError::Disconnected,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<RecvError> for NextError {
+    fn from(_: RecvError) -> Self {
+        NextError::Disconnected
+    }
+}
+
+impl Display for NextError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NextError::Empty => write!(f, "no pending event"),
+            NextError::Disconnected => write!(f, "channel is disconnected"),
+            NextError::ListenerPanicked(panics) => {
+                write!(f, "{} listener(s) panicked:", panics.len())?;
+                for (i, panic) in panics.iter().enumerate() {
+                    let sep = if i == 0 { " " } else { ", " };
+                    write!(f, "{sep}{}", panic.name)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl core::error::Error for NextError {}
+
+/// A registered listener `L` together with its [`ListenerHandle`], its name,
+/// its priority, the [`TopicPattern`] it is restricted to, if any,
+/// and the [`Invocations`] counted for its [`ListenerStats`].
+///
+/// The name is the one given when the listener was added, e.g. with `add_listener_named()`,
+/// or else the type name of the listener as it was added, before it was boxed or wrapped.
+#[derive(Debug)]
+pub(crate) struct ListenerEntry<L> {
+    pub(crate) handle: ListenerHandle,
+    pub(crate) name: &'static str,
+    pub(crate) listener: L,
+    pub(crate) priority: i32,
+    pub(crate) topic: Option<TopicPattern>,
+    pub(crate) invocations: Invocations,
+}
+
+impl<L> ListenerEntry<L> {
+    /// Listeners without a topic receive every event,
+    /// listeners with a topic only receive events published to a matching topic.
+    fn accepts(&self, topic: Option<&str>) -> bool {
+        match (&self.topic, topic) {
+            (None, _) => true,
+            (Some(pattern), Some(topic)) => pattern.matches(topic),
+            (Some(_), None) => false,
+        }
+    }
+}
+
+/// Holds all listeners of type `L` of a mediator.
+///
+/// Listeners can be added and removed through a shared reference,
+/// which allows subscribing to an already built mediator.
+/// Entries are kept in descending priority, listeners
+/// with equal priority stay in the order they were added.
+///
+/// The entries are copied on write, so listeners are invoked on a snapshot
+/// without holding the lock, and adding or removing a listener
+/// never waits for listeners that are currently running.
+#[derive(Debug)]
+pub(crate) struct ListenerRegistry<L> {
+    entries: RwLock<Arc<[Arc<ListenerEntry<L>>]>>,
+}
+
+impl<L> Default for ListenerRegistry<L> {
+    fn default() -> Self {
+        Self {
+            entries: RwLock::new(Arc::new([])),
+        }
+    }
+}
+
+impl<L> ListenerRegistry<L> {
+    pub(crate) fn add(&self, name: &'static str, listener: L) -> ListenerHandle {
+        self.insert(name, listener, 0, None)
+    }
+
+    pub(crate) fn add_for_topic(
+        &self,
+        name: &'static str,
+        topic: Option<TopicPattern>,
+        listener: L,
+    ) -> ListenerHandle {
+        self.insert(name, listener, 0, topic)
+    }
+
+    pub(crate) fn add_with_priority(
+        &self,
+        name: &'static str,
+        priority: i32,
+        listener: L,
+    ) -> ListenerHandle {
+        self.insert(name, listener, priority, None)
+    }
+
+    fn insert(
+        &self,
+        name: &'static str,
+        listener: L,
+        priority: i32,
+        topic: Option<TopicPattern>,
+    ) -> ListenerHandle {
+        let handle = ListenerHandle::next();
+        let mut entries = self.entries.write();
+        let index = entries.partition_point(|entry| entry.priority >= priority);
+        let mut next = entries.to_vec();
+        next.insert(
+            index,
+            Arc::new(ListenerEntry {
+                handle,
+                name,
+                listener,
+                priority,
+                topic,
+                invocations: Invocations::default(),
+            }),
+        );
+        *entries = next.into();
+        handle
+    }
+
+    pub(crate) fn remove(&self, handle: ListenerHandle) -> bool {
+        let mut entries = self.entries.write();
+        if !entries.iter().any(|entry| entry.handle == handle) {
+            return false;
+        }
+        *entries = entries
+            .iter()
+            .filter(|entry| entry.handle != handle)
+            .cloned()
+            .collect();
+        true
+    }
+
+    /// Returns the current entries, which stay untouched by later changes.
+    fn load(&self) -> Arc<[Arc<ListenerEntry<L>>]> {
+        self.entries.read().clone()
+    }
+
+    /// Returns whether a panic occurred while the entries were locked.
+    pub(crate) fn is_poisoned(&self) -> bool {
+        self.entries.is_poisoned()
+    }
+
+    pub(crate) fn clear_poison(&self) {
+        self.entries.clear_poison();
+    }
+
+    /// Returns the topic every listener is restricted to, if any.
+    pub(crate) fn topics(&self) -> Vec<Option<TopicPattern>> {
+        self.entries
+            .read()
+            .iter()
+            .map(|entry| entry.topic.clone())
+            .collect()
+    }
+
+    /// Returns the [`ListenerStats`] of every listener.
+    pub(crate) fn stats(&self) -> Vec<ListenerStats> {
+        self.load()
+            .iter()
+            .map(|entry| entry.invocations.snapshot(entry.handle, entry.name))
+            .collect()
+    }
+
+    /// Adds the name of every listener to `names`.
+    #[cfg(feature = "causality")]
+    pub(crate) fn names(&self, names: &mut BTreeMap<ListenerHandle, &'static str>) {
+        names.extend(self.load().iter().map(|entry| (entry.handle, entry.name)));
+    }
+
+    /// Returns the [`ListenerDescription`] of every listener.
+    #[cfg(feature = "schema")]
+    pub(crate) fn describe(&self, asynchronous: bool) -> Vec<ListenerDescription> {
+        self.load()
+            .iter()
+            .map(|entry| {
+                ListenerDescription::new(
+                    entry.handle,
+                    entry.name,
+                    entry.topic.as_ref().map(|topic| topic.as_str().into()),
+                    entry.priority,
+                    asynchronous,
+                )
+            })
+            .collect()
+    }
+}
+
+impl<Ev> ListenerRegistry<ExclusiveListener<Ev>>
+where
+    Ev: MaybeDebug,
+{
+    pub(crate) fn notify<O: MaybeDebug>(
+        &self,
+        ev: &Ev,
+        topic: Option<&str>,
+        observer: &Observers<O>,
+    ) {
+        for entry in self.load().iter() {
+            if entry.accepts(topic) {
+                observer.listener(entry.handle, entry.name, &entry.invocations, || {
+                    entry.listener.call(ev)
+                })
+            }
+        }
+    }
+
+    /// Same as [`ListenerRegistry::notify()`], but catches panics of each listener.
+    #[cfg(feature = "std")]
+    pub(crate) fn notify_isolated<O: MaybeDebug>(
+        &self,
+        ev: &Ev,
+        topic: Option<&str>,
+        observer: &Observers<O>,
+    ) -> Vec<ListenerPanic> {
+        self.load()
+            .iter()
+            .filter(|entry| entry.accepts(topic))
+            .filter_map(|entry| {
+                panic::catch_unwind(AssertUnwindSafe(|| {
+                    observer.listener(entry.handle, entry.name, &entry.invocations, || {
+                        entry.listener.call(ev)
+                    })
+                }))
+                .err()
+                .map(|payload| ListenerPanic {
+                    handle: entry.handle,
+                    name: entry.name,
+                    payload,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Ev> ListenerRegistry<Arc<dyn AsyncListener<Ev>>>
+where
+    Ev: MaybeDebug + 'static,
+{
+    /// Awaits all listeners without holding the lock,
+    /// at most `limit` of them concurrently.
+    pub(crate) async fn notify<O: MaybeDebug>(
+        &self,
+        ev: &Ev,
+        topic: Option<&str>,
+        observer: &Observers<O>,
+        limit: usize,
+    ) {
+        let snapshot = self.snapshot(topic);
+        let invocations: Vec<_> = snapshot
+            .iter()
+            .map(|entry| {
+                observer.listener_async(
+                    entry.handle,
+                    entry.name,
+                    &entry.invocations,
+                    entry.listener.on_event(ev),
+                )
+            })
+            .collect();
+        join_limited(invocations, limit).await;
+    }
+
+    /// Same as [`ListenerRegistry::notify()`], but catches panics of each listener.
+    pub(crate) async fn notify_isolated<O: MaybeDebug>(
+        &self,
+        ev: &Ev,
+        topic: Option<&str>,
+        observer: &Observers<O>,
+        limit: usize,
+    ) -> Vec<ListenerPanic> {
+        let snapshot = self.snapshot(topic);
+        let invocations: Vec<_> = snapshot
+            .iter()
+            .map(|entry| async move {
+                let res =
+                    match panic::catch_unwind(AssertUnwindSafe(|| entry.listener.on_event(ev))) {
+                        Ok(fut) => {
+                            observer
+                                .listener_async(
+                                    entry.handle,
+                                    entry.name,
+                                    &entry.invocations,
+                                    CatchUnwind(fut),
+                                )
+                                .await
+                        }
+                        Err(payload) => Err(payload),
+                    };
+                res.err().map(|payload| ListenerPanic {
+                    handle: entry.handle,
+                    name: entry.name,
+                    payload,
+                })
+            })
+            .collect();
+        join_limited(invocations, limit)
+            .await
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<L> ListenerRegistry<L> {
+    /// Returns all entries accepting the `topic`,
+    /// so listeners can be invoked without holding the lock.
+    pub(crate) fn snapshot(&self, topic: Option<&str>) -> Vec<Arc<ListenerEntry<L>>> {
+        self.load()
+            .iter()
+            .filter(|entry| entry.accepts(topic))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Catches a panic while polling the inner `Future`.
+#[cfg(feature = "async")]
+struct CatchUnwind<F>(F);
+
+#[cfg(feature = "async")]
+impl<F> Future for CatchUnwind<F>
+where
+    F: Future + Unpin,
+{
+    type Output = Result<F::Output, Box<dyn Any + Send>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = &mut self.0;
+        match panic::catch_unwind(AssertUnwindSafe(|| Pin::new(inner).poll(cx))) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(out)) => Poll::Ready(Ok(out)),
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    }
 }
@@ -0,0 +1,122 @@
+use std::fmt::Debug;
+use tracing::Level;
+
+use crate::mediator::{correlation::Enveloped, listener::MaybeDebug};
+
+type Formatter<Ev> = dyn Fn(&Ev) -> String + Send + Sync;
+
+/// A built-in listener logging every event `Ev` through `tracing`,
+/// e.g. to keep an audit trail of all events.
+///
+/// Add it with `add_logging_listener()`, either by passing a [`Level`],
+/// which logs the [`Debug`] representation of the events,
+/// or a [`LoggingListener`] with a custom formatter.
+/// Events are logged with the target `mediatrix::events`,
+/// together with the sequence number of the event and
+/// the [`CorrelationId`](crate::correlation::CorrelationId) and type name
+/// of the request it originates from, if any.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use mediatrix::logging::LoggingListener;
+/// use tracing::Level;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     Created(u32),
+///     Deleted(u32)
+/// }
+///
+/// let mediator = BasicMediator::<MyEvent>::builder()
+///     .add_logging_listener(Level::DEBUG)
+///     .add_logging_listener(
+///         LoggingListener::new(Level::INFO).with_formatter(|ev: &MyEvent| match ev {
+///             MyEvent::Created(id) => format!("created #{id}"),
+///             MyEvent::Deleted(id) => format!("deleted #{id}"),
+///         }),
+///     )
+///     .build();
+///
+/// mediator.publish(MyEvent::Created(1));
+/// mediator.next().ok();
+///
+pub struct LoggingListener<Ev> {
+    level: Level,
+    formatter: Box<Formatter<Ev>>,
+}
+
+impl<Ev> LoggingListener<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Creates a [`LoggingListener`] logging the [`Debug`] representation of events at `level`.
+    pub fn new(level: Level) -> Self {
+        Self {
+            level,
+            formatter: Box::new(|ev: &Ev| format!("{ev:?}")),
+        }
+    }
+}
+
+impl<Ev> LoggingListener<Ev> {
+    /// Replaces the formatter turning an event into the logged message.
+    pub fn with_formatter(self, f: impl Fn(&Ev) -> String + Send + Sync + 'static) -> Self {
+        Self {
+            formatter: Box::new(f),
+            ..self
+        }
+    }
+
+    /// Returns the level events are logged at.
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Logs the event currently being processed.
+    pub(crate) fn log(&self, ev: &Enveloped<'_, Ev>) {
+        let event = (self.formatter)(ev.event());
+        let correlation = ev.correlation_id().map(|id| id.get());
+        let (source, sequence) = (ev.source(), ev.sequence());
+        macro_rules! log {
+            ($level:expr) => {
+                tracing::event!(
+                    target: "mediatrix::events",
+                    $level,
+                    event,
+                    sequence,
+                    correlation,
+                    source,
+                    "event"
+                )
+            };
+        }
+        match self.level {
+            Level::ERROR => log!(Level::ERROR),
+            Level::WARN => log!(Level::WARN),
+            Level::INFO => log!(Level::INFO),
+            Level::DEBUG => log!(Level::DEBUG),
+            Level::TRACE => log!(Level::TRACE),
+        }
+    }
+}
+
+impl<Ev> From<Level> for LoggingListener<Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn from(level: Level) -> Self {
+        Self::new(level)
+    }
+}
+
+impl<Ev> Debug for LoggingListener<Ev> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoggingListener")
+            .field("level", &self.level)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,9 +1,107 @@
+#[cfg(feature = "actix")]
+/// Actix actor integration
+pub mod actix;
 #[cfg(feature = "async")]
 /// Asynchronous mediators
 pub mod asynchronous;
+#[cfg(feature = "axum")]
+/// Axum integration
+pub mod axum;
+/// Pipeline behaviors
+pub mod behavior;
+/// Bridges between mediators
+pub mod bridge;
+#[cfg(feature = "brokers")]
+/// Message broker adapters
+pub mod brokers;
 /// Builder traits
 pub mod builder;
+#[cfg(feature = "async")]
+/// Request cancellation and timeouts
+pub mod cancellation;
+#[cfg(feature = "causality")]
+/// Causality graphs of requests and events
+pub mod causality;
+/// Channel configuration
+pub mod channel;
+#[cfg(feature = "persistence")]
+/// Serialization codecs
+pub mod codec;
+#[cfg(feature = "async")]
+pub(crate) mod concurrency;
+/// Request correlation
+pub mod correlation;
+/// Dead-letter queue
+pub mod deadletter;
+#[cfg(feature = "async")]
+pub(crate) mod debounce;
+/// Dependency container
+pub mod dependencies;
+/// Error types
+pub mod error;
+#[cfg(feature = "async")]
+/// Pluggable async runtimes
+pub mod executor;
+pub(crate) mod instrument;
+#[cfg(feature = "async")]
+/// Context lifecycle hooks
+pub mod lifecycle;
 /// Listener traits
 pub mod listener;
+#[cfg(feature = "tracing")]
+/// Event logging
+pub mod logging;
+/// Metrics hooks
+pub mod observer;
+#[cfg(feature = "async")]
+/// Event ordering guarantees
+pub mod ordering;
+pub(crate) mod pause;
+#[cfg(feature = "persistence")]
+/// Event journaling and replay
+pub mod persistence;
+/// Builder presets
+pub mod profile;
+#[cfg(feature = "async")]
+/// Prioritized request queue
+pub mod queue;
+#[cfg(feature = "std")]
+/// Rate limiting of event delivery
+pub mod ratelimit;
+pub(crate) mod registry;
+#[cfg(feature = "remote")]
+/// Cross-process bridges
+pub mod remote;
+pub(crate) mod replay;
+#[cfg(feature = "async")]
+/// Retry policies for fallible requests
+pub mod retry;
+#[cfg(feature = "async")]
+pub(crate) mod runtime;
+/// Sagas coordinating multi-step workflows
+pub mod saga;
+#[cfg(feature = "async")]
+pub(crate) mod scheduler;
+#[cfg(feature = "schema")]
+/// Event schema export
+pub mod schema;
+#[cfg(feature = "async")]
+pub(crate) mod spawned;
+/// Runtime statistics
+pub mod stats;
+pub(crate) mod sync;
 /// Synchronous mediators
 pub mod synchronous;
+/// System events of the mediators
+pub mod system;
+#[cfg(feature = "async")]
+/// Test utilities
+pub mod testing;
+#[cfg(feature = "async")]
+pub(crate) mod ticker;
+pub(crate) mod time;
+/// Topic-based routing
+pub mod topics;
+#[cfg(feature = "async")]
+/// Background event processing
+pub mod worker;
@@ -0,0 +1,430 @@
+use alloc::{sync::Arc, vec, vec::Vec};
+use core::{any::type_name, fmt::Debug, time::Duration};
+
+#[cfg(feature = "async")]
+use futures_core::Stream;
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::mediator::{
+    correlation::{self, CorrelationId, Trace},
+    instrument,
+    listener::{ListenerHandle, MaybeDebug},
+    stats::{Counters, Invocations},
+    time::Stopwatch,
+    topics::Envelope,
+};
+
+#[cfg(feature = "causality")]
+use crate::mediator::causality::CausalityRecorder;
+
+/// A [`MediatorObserver`] is notified about the traffic of a mediator with events of type `Ev`.
+///
+/// All callbacks default to doing nothing,
+/// so an observer only implements what it is interested in,
+/// e.g. to feed counters and histograms of a metrics exporter.
+/// Observers are invoked synchronously and should return quickly.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     One,
+///     Two
+/// }
+///
+/// #[derive(Default)]
+/// struct PublishCounter(AtomicUsize);
+///
+/// impl MediatorObserver<MyEvent> for PublishCounter {
+///     fn on_event_published(&self, _: &MyEvent, _: Option<&str>) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let counter = Arc::new(PublishCounter::default());
+///
+/// let mediator = BasicMediator::<MyEvent>::builder()
+///     .with_observer(counter.clone())
+///     .build();
+///
+/// mediator.publish(MyEvent::One);
+/// mediator.publish(MyEvent::Two);
+///
+/// assert_eq!(counter.0.load(Ordering::Relaxed), 2);
+///
+pub trait MediatorObserver<Ev: MaybeDebug>: Send + Sync {
+    /// Called before a request is handled,
+    /// `request` is the type name of the request.
+    fn on_request_start(&self, request: &'static str) {
+        let _ = request;
+    }
+
+    /// Called after a request was handled,
+    /// `request` is the type name of the request.
+    ///
+    /// Without the `std` feature, `elapsed` is always zero.
+    fn on_request_finish(&self, request: &'static str, elapsed: Duration) {
+        let _ = (request, elapsed);
+    }
+
+    /// Called when an event is published, together with its topic, if any.
+    fn on_event_published(&self, ev: &Ev, topic: Option<&str>) {
+        let _ = (ev, topic);
+    }
+
+    /// Called after the listener identified by `handle` was invoked.
+    fn on_listener_invoked(&self, handle: ListenerHandle, elapsed: Duration) {
+        let _ = (handle, elapsed);
+    }
+}
+
+impl<Ev> Debug for dyn MediatorObserver<Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "MediatorObserver")
+    }
+}
+
+impl<Ev, O> MediatorObserver<Ev> for Arc<O>
+where
+    Ev: MaybeDebug,
+    O: MediatorObserver<Ev>,
+{
+    fn on_request_start(&self, request: &'static str) {
+        (**self).on_request_start(request)
+    }
+
+    fn on_request_finish(&self, request: &'static str, elapsed: Duration) {
+        (**self).on_request_finish(request, elapsed)
+    }
+
+    fn on_event_published(&self, ev: &Ev, topic: Option<&str>) {
+        (**self).on_event_published(ev, topic)
+    }
+
+    fn on_listener_invoked(&self, handle: ListenerHandle, elapsed: Duration) {
+        (**self).on_listener_invoked(handle, elapsed)
+    }
+}
+
+/// Describes a request passed to the hooks added with `on_before_send()` and `on_after_send()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestMeta {
+    request: &'static str,
+    op: &'static str,
+    correlation: Option<CorrelationId>,
+}
+
+impl RequestMeta {
+    fn new<Req>(op: &'static str) -> Self {
+        Self {
+            request: type_name::<Req>(),
+            op,
+            correlation: CorrelationId::current(),
+        }
+    }
+
+    /// Returns the type name of the request.
+    pub fn request(&self) -> &'static str {
+        self.request
+    }
+
+    /// Returns the name of the method the request was sent with, e.g. `"send"`.
+    pub fn op(&self) -> &'static str {
+        self.op
+    }
+
+    /// Returns the [`CorrelationId`] of the request, if it was sent with `send_traced()`.
+    pub fn correlation_id(&self) -> Option<CorrelationId> {
+        self.correlation
+    }
+}
+
+type BeforeSend = dyn Fn(&RequestMeta) + Send + Sync;
+type AfterSend = dyn Fn(&RequestMeta, Duration) + Send + Sync;
+
+/// The hooks added with `on_before_send()` and `on_after_send()`.
+#[derive(Default, Clone)]
+struct RequestHooks {
+    before: Vec<Arc<BeforeSend>>,
+    after: Vec<Arc<AfterSend>>,
+}
+
+impl Debug for RequestHooks {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RequestHooks")
+            .field("before", &self.before.len())
+            .field("after", &self.after.len())
+            .finish()
+    }
+}
+
+/// All [`MediatorObserver`]s and request hooks of a mediator.
+///
+/// Besides notifying the observers, this also instruments
+/// what it observes, see [`instrument`], and counts it
+/// for the [`MediatorStats`](crate::mediator::stats::MediatorStats).
+#[derive(Debug)]
+pub(crate) struct Observers<Ev: MaybeDebug> {
+    observers: Vec<Arc<dyn MediatorObserver<Ev>>>,
+    hooks: RequestHooks,
+    pub(crate) counters: Arc<Counters>,
+    #[cfg(feature = "causality")]
+    pub(crate) causality: Option<Arc<CausalityRecorder<Ev>>>,
+}
+
+impl<Ev> Default for Observers<Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn default() -> Self {
+        Self {
+            observers: vec![],
+            hooks: RequestHooks::default(),
+            counters: Arc::default(),
+            #[cfg(feature = "causality")]
+            causality: None,
+        }
+    }
+}
+
+impl<Ev> Clone for Observers<Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn clone(&self) -> Self {
+        Self {
+            observers: self.observers.clone(),
+            hooks: self.hooks.clone(),
+            counters: self.counters.clone(),
+            #[cfg(feature = "causality")]
+            causality: self.causality.clone(),
+        }
+    }
+}
+
+impl<Ev> Observers<Ev>
+where
+    Ev: MaybeDebug,
+{
+    pub(crate) fn add(&mut self, observer: impl MediatorObserver<Ev> + 'static) {
+        self.observers.push(Arc::new(observer));
+    }
+
+    pub(crate) fn before_send(&mut self, f: impl Fn(&RequestMeta) + Send + Sync + 'static) {
+        self.hooks.before.push(Arc::new(f));
+    }
+
+    pub(crate) fn after_send(
+        &mut self,
+        f: impl Fn(&RequestMeta, Duration) + Send + Sync + 'static,
+    ) {
+        self.hooks.after.push(Arc::new(f));
+    }
+
+    /// Whether requests must be timed for any observer or hook.
+    fn observes_requests(&self) -> bool {
+        !(self.observers.is_empty() && self.hooks.before.is_empty() && self.hooks.after.is_empty())
+    }
+
+    fn request_started(&self, meta: &RequestMeta) {
+        self.observers
+            .iter()
+            .for_each(|o| o.on_request_start(meta.request));
+        self.hooks.before.iter().for_each(|f| f(meta));
+    }
+
+    fn request_finished(&self, meta: &RequestMeta, elapsed: Duration) {
+        self.observers
+            .iter()
+            .for_each(|o| o.on_request_finish(meta.request, elapsed));
+        self.hooks.after.iter().for_each(|f| f(meta, elapsed));
+    }
+
+    /// Returns the [`Trace`] of the request `Req`, recording what caused it.
+    fn request_trace<Req>(&self) -> Trace {
+        let trace = Trace::current();
+        #[cfg(feature = "causality")]
+        if let Some(recorder) = &self.causality {
+            recorder.request(type_name::<Req>(), trace.cause);
+        }
+        trace.request::<Req>()
+    }
+
+    /// Runs the request `Req`, marking all events published by it with `Req` as their source.
+    pub(crate) fn request<Req, R>(&self, op: &'static str, f: impl FnOnce() -> R) -> R {
+        let trace = self.request_trace::<Req>();
+        correlation::scope(trace, || self.observe_request::<Req, _>(op, f))
+    }
+
+    fn observe_request<Req, R>(&self, op: &'static str, f: impl FnOnce() -> R) -> R {
+        instrument::request::<Req, _>(op, || {
+            if !self.observes_requests() {
+                return f();
+            }
+            let meta = RequestMeta::new::<Req>(op);
+            self.request_started(&meta);
+            let start = Stopwatch::start();
+            let res = f();
+            self.request_finished(&meta, start.elapsed());
+            res
+        })
+    }
+
+    /// Runs the request `Req`, marking all events published by it with `Req` as their source.
+    #[cfg(feature = "async")]
+    pub(crate) async fn request_async<Req, F: Future>(
+        &self,
+        op: &'static str,
+        fut: F,
+    ) -> F::Output {
+        let trace = self.request_trace::<Req>();
+        correlation::scope_async(trace, self.observe_request_async::<Req, _>(op, fut)).await
+    }
+
+    #[cfg(feature = "async")]
+    async fn observe_request_async<Req, F: Future>(&self, op: &'static str, fut: F) -> F::Output {
+        instrument::request_async::<Req, _>(op, async {
+            if !self.observes_requests() {
+                return fut.await;
+            }
+            let meta = RequestMeta::new::<Req>(op);
+            self.request_started(&meta);
+            let start = Stopwatch::start();
+            let res = fut.await;
+            self.request_finished(&meta, start.elapsed());
+            res
+        })
+        .await
+    }
+
+    /// Observes the streaming request `Req` until its `stream` of responses ends,
+    /// marking all events published while polling it with `Req` as their source.
+    #[cfg(feature = "async")]
+    pub(crate) fn request_stream<Req, S: Stream>(&self, stream: S) -> RequestStream<'_, Ev, S> {
+        let meta = RequestMeta::new::<Req>("send_stream");
+        self.request_started(&meta);
+        RequestStream {
+            observers: self,
+            stream: Box::pin(stream),
+            trace: self.request_trace::<Req>(),
+            meta,
+            start: Some(Stopwatch::start()),
+        }
+    }
+
+    pub(crate) fn published(&self, envelope: &Envelope<Ev>) {
+        let (ev, topic) = (&envelope.ev, envelope.topic.as_deref());
+        self.counters.published();
+        instrument::published(ev, topic);
+        self.observers
+            .iter()
+            .for_each(|o| o.on_event_published(ev, topic));
+        #[cfg(feature = "causality")]
+        if let Some(recorder) = &self.causality {
+            recorder.published(envelope);
+        }
+    }
+
+    /// Returns the [`Trace`] of the listener with the given `handle`,
+    /// recording the event it is invoked for.
+    #[cfg(feature = "causality")]
+    fn listener_trace(&self, handle: ListenerHandle) -> Trace {
+        let trace = Trace::current();
+        if let Some(recorder) = &self.causality {
+            recorder.listener(handle, trace.event.map(|meta| meta.sequence));
+        }
+        trace.listener(handle)
+    }
+
+    /// Runs the listener with the given `handle`, counting the invocation in `invocations`.
+    pub(crate) fn listener<R>(
+        &self,
+        handle: ListenerHandle,
+        name: &'static str,
+        invocations: &Invocations,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        #[cfg(feature = "causality")]
+        let f = {
+            let trace = self.listener_trace(handle);
+            move || correlation::scope(trace, f)
+        };
+        instrument::listener(handle, name, || {
+            let start = Stopwatch::start();
+            let res = f();
+            let elapsed = start.elapsed();
+            invocations.record(elapsed);
+            self.observers
+                .iter()
+                .for_each(|o| o.on_listener_invoked(handle, elapsed));
+            res
+        })
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn listener_async<F: Future>(
+        &self,
+        handle: ListenerHandle,
+        name: &'static str,
+        invocations: &Invocations,
+        fut: F,
+    ) -> F::Output {
+        #[cfg(feature = "causality")]
+        let fut = correlation::scope_async(self.listener_trace(handle), fut);
+        instrument::listener_async(handle, name, async {
+            let start = Stopwatch::start();
+            let res = fut.await;
+            let elapsed = start.elapsed();
+            invocations.record(elapsed);
+            self.observers
+                .iter()
+                .for_each(|o| o.on_listener_invoked(handle, elapsed));
+            res
+        })
+        .await
+    }
+}
+
+/// A stream of responses to a request, created by [`Observers::request_stream()`].
+#[cfg(feature = "async")]
+pub(crate) struct RequestStream<'a, Ev: MaybeDebug, S> {
+    observers: &'a Observers<Ev>,
+    stream: Pin<Box<S>>,
+    trace: Trace,
+    meta: RequestMeta,
+    /// When the request started, `None` once the stream has ended.
+    start: Option<Stopwatch>,
+}
+
+#[cfg(feature = "async")]
+impl<Ev, S> Stream for RequestStream<'_, Ev, S>
+where
+    Ev: MaybeDebug,
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let poll = correlation::scope(this.trace, || this.stream.as_mut().poll_next(cx));
+        if let (Poll::Ready(None), Some(start)) = (&poll, this.start) {
+            this.start = None;
+            this.observers.request_finished(&this.meta, start.elapsed());
+        }
+        poll
+    }
+}
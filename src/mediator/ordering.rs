@@ -0,0 +1,194 @@
+use event_listener::Event;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Mutex,
+};
+
+/// The order in which an async mediator invokes the async listeners of published events,
+/// configured with `with_ordering()`.
+///
+/// Ordering only matters once processing is concurrent, i.e. if events are processed
+/// by multiple tasks or the mediator was built with `with_concurrency()`.
+/// Synchronous listeners are always invoked in publishing order.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::asynchronous::basic::*;
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+///
+/// #[derive(Debug, Clone, Copy)]
+/// enum MyEvent {
+///     Slow,
+///     Fast
+/// }
+///
+/// async_std::task::block_on(async {
+///     let seen = Arc::new(Mutex::new(vec![]));
+///     let cloned = seen.clone();
+///
+///     let mediator = BasicAsyncMediator::<MyEvent>::builder()
+///         .add_async_listener(move |ev: &MyEvent| {
+///             let (seen, ev) = (cloned.clone(), *ev);
+///             async move {
+///                 if let MyEvent::Slow = ev {
+///                     async_std::task::sleep(Duration::from_millis(10)).await;
+///                 }
+///                 seen.lock().unwrap().push(format!("{ev:?}"));
+///             }
+///         })
+///         .with_concurrency(4)
+///         .with_ordering(Ordering::StrictFifo)
+///         .build();
+///
+///     mediator.publish(MyEvent::Slow).await;
+///     mediator.publish(MyEvent::Fast).await;
+///     mediator.next_all().await;
+///
+///     assert_eq!(*seen.lock().unwrap(), vec!["Slow", "Fast"]);
+/// });
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Ordering {
+    /// Events are processed one after another in publishing order,
+    /// invoking their listeners in the order they were added.
+    StrictFifo,
+    /// Events published to the same topic are processed one after another in publishing order,
+    /// invoking their listeners in the order they were added.
+    /// Events of distinct topics are processed concurrently.
+    /// Events published without a topic are ordered among themselves.
+    PerTopicFifo,
+    /// Events and their listeners are processed concurrently,
+    /// neither the order of events nor the order of listeners is preserved.
+    #[default]
+    Unordered,
+}
+
+impl Ordering {
+    /// Returns how many listeners of a single event may be invoked concurrently,
+    /// given the concurrency `limit` of the mediator.
+    pub(crate) fn listener_limit(&self, limit: usize) -> usize {
+        match self {
+            Ordering::Unordered => limit,
+            Ordering::StrictFifo | Ordering::PerTopicFifo => 1,
+        }
+    }
+}
+
+/// Hands out turns to process events in the order of an [`Ordering`].
+///
+/// A turn is taken in the same step as receiving an event from the channel,
+/// before the event is rate limited, checked for expiry or passed to any listener,
+/// so turns are handed out in publishing order.
+#[derive(Debug, Default)]
+pub(crate) struct Sequencer {
+    pub(crate) ordering: Ordering,
+    queues: Mutex<BTreeMap<Option<String>, Queue>>,
+    released: Event,
+}
+
+/// The turns of all events sharing a key.
+#[derive(Debug, Default)]
+struct Queue {
+    next: u64,
+    serving: u64,
+    /// Turns released before all preceding turns were released.
+    done: BTreeSet<u64>,
+}
+
+impl Sequencer {
+    pub(crate) fn new(ordering: Ordering) -> Self {
+        Self {
+            ordering,
+            ..Default::default()
+        }
+    }
+
+    /// Takes the next turn for an event published to `topic`,
+    /// or `None` if events are unordered.
+    pub(crate) fn turn(&self, topic: Option<&str>) -> Option<Turn<'_>> {
+        let key = match self.ordering {
+            Ordering::Unordered => return None,
+            Ordering::StrictFifo => None,
+            Ordering::PerTopicFifo => topic.map(str::to_string),
+        };
+        let mut queues = self.queues.lock().unwrap_or_else(|err| err.into_inner());
+        let queue = queues.entry(key.clone()).or_default();
+        let ticket = queue.next;
+        queue.next += 1;
+        Some(Turn {
+            sequencer: self,
+            key,
+            ticket,
+        })
+    }
+
+    /// Keeps `turn` if it was taken for the events of `topic`,
+    /// otherwise releases it and takes a new turn,
+    /// e.g. after rate limiting replaced the received event by a later one.
+    pub(crate) fn retake<'a>(
+        &'a self,
+        turn: Option<Turn<'a>>,
+        topic: Option<&str>,
+    ) -> Option<Turn<'a>> {
+        match turn {
+            Some(turn)
+                if self.ordering != Ordering::PerTopicFifo || turn.key.as_deref() == topic =>
+            {
+                Some(turn)
+            }
+            _ => self.turn(topic),
+        }
+    }
+
+    fn is_serving(&self, key: &Option<String>, ticket: u64) -> bool {
+        let queues = self.queues.lock().unwrap_or_else(|err| err.into_inner());
+        queues.get(key).is_some_and(|queue| queue.serving == ticket)
+    }
+
+    fn release(&self, key: &Option<String>, ticket: u64) {
+        let mut queues = self.queues.lock().unwrap_or_else(|err| err.into_inner());
+        let Some(queue) = queues.get_mut(key) else {
+            return;
+        };
+        queue.done.insert(ticket);
+        while queue.done.remove(&queue.serving) {
+            queue.serving += 1;
+        }
+        if queue.serving == queue.next {
+            queues.remove(key);
+        }
+        self.released.notify(usize::MAX);
+    }
+}
+
+/// A turn taken from a [`Sequencer`], which is released when dropped,
+/// even if processing the event panicked or was cancelled.
+#[derive(Debug)]
+pub(crate) struct Turn<'a> {
+    sequencer: &'a Sequencer,
+    key: Option<String>,
+    ticket: u64,
+}
+
+impl Turn<'_> {
+    /// Waits until all preceding turns were released.
+    pub(crate) async fn wait(&self) {
+        loop {
+            let released = self.sequencer.released.listen();
+            if self.sequencer.is_serving(&self.key, self.ticket) {
+                return;
+            }
+            released.await;
+        }
+    }
+}
+
+impl Drop for Turn<'_> {
+    fn drop(&mut self) {
+        self.sequencer.release(&self.key, self.ticket);
+    }
+}
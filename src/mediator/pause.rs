@@ -0,0 +1,42 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether the delivery of events of a mediator is paused, see `pause()`.
+#[derive(Debug, Default)]
+pub(crate) struct Pause {
+    paused: AtomicBool,
+    /// Wakes the threads waiting in [`Pause::wait()`] on resume.
+    #[cfg(feature = "std")]
+    resumed: (std::sync::Mutex<()>, std::sync::Condvar),
+}
+
+impl Pause {
+    pub(crate) fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    pub(crate) fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+        #[cfg(feature = "std")]
+        {
+            let _resumed = self.resumed.0.lock();
+            self.resumed.1.notify_all();
+        }
+    }
+
+    pub(crate) fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Blocks the current thread while paused.
+    #[cfg(feature = "std")]
+    pub(crate) fn wait(&self) {
+        let mut resumed = self.resumed.0.lock().unwrap_or_else(|err| err.into_inner());
+        while self.is_paused() {
+            resumed = self
+                .resumed
+                .1
+                .wait(resumed)
+                .unwrap_or_else(|err| err.into_inner());
+        }
+    }
+}
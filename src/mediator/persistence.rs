@@ -0,0 +1,381 @@
+use serde::{de::DeserializeOwned, ser::SerializeStruct, Deserialize, Serialize, Serializer};
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    fs::{File, OpenOptions},
+    io::{self, BufReader, Write},
+    path::Path,
+    sync::Arc,
+};
+
+use crate::mediator::{
+    codec::{self, Codec, JsonCodec},
+    listener::MaybeDebug,
+    observer::MediatorObserver,
+    sync::Mutex,
+};
+
+/// A single line of a journal: a published event `Ev`
+/// together with the topic it was published to, if any.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry<Ev> {
+    /// The topic the event was published to, if any.
+    pub topic: Option<String>,
+    /// The published event.
+    pub event: Ev,
+}
+
+/// The borrowed counterpart of a [`JournalEntry`], written without cloning the event.
+///
+/// In human-readable formats, the version of the event is only written if it isn't 0,
+/// so journals written before versioning was introduced read as version 0.
+/// Other formats may not support missing fields, so it's always written.
+pub(crate) struct JournalLine<'a, Ev> {
+    pub(crate) topic: Option<&'a str>,
+    pub(crate) version: u32,
+    pub(crate) event: &'a Ev,
+}
+
+impl<Ev: Serialize> Serialize for JournalLine<'_, Ev> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let versioned = self.version != 0 || !serializer.is_human_readable();
+        let mut line = serializer.serialize_struct("JournalLine", 2 + usize::from(versioned))?;
+        line.serialize_field("topic", &self.topic)?;
+        if versioned {
+            line.serialize_field("version", &self.version)?;
+        } else {
+            line.skip_field("version")?;
+        }
+        line.serialize_field("event", self.event)?;
+        line.end()
+    }
+}
+
+/// A line of a journal, read back into a [`JournalEntry`].
+#[derive(Deserialize)]
+pub(crate) struct Line<Ev> {
+    topic: Option<String>,
+    #[serde(default, rename = "version")]
+    _version: u32,
+    event: Ev,
+}
+
+impl<Ev> From<Line<Ev>> for JournalEntry<Ev> {
+    fn from(line: Line<Ev>) -> Self {
+        Self {
+            topic: line.topic,
+            event: line.event,
+        }
+    }
+}
+
+/// A line of a journal whose event is yet to be upcasted.
+#[derive(Deserialize)]
+struct VersionedLine {
+    topic: Option<String>,
+    #[serde(default)]
+    version: u32,
+    event: Value,
+}
+
+/// An append-only file every published event is written to, one JSON object per line
+/// or in the format of another [`Codec`], see [`Journal::open_with_codec()`].
+///
+/// A [`Journal`] is a [`MediatorObserver`], add it to a mediator with `with_observer()`.
+/// It is cheaply cloneable, so a clone can be kept to check for write errors.
+/// Use `replay_from()` to publish the journaled events into a fresh mediator,
+/// e.g. to rebuild state on startup.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use mediatrix::persistence::Journal;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// enum MyEvent {
+///     One,
+///     Two
+/// }
+///
+/// let path = std::env::temp_dir().join("mediatrix-journal-doctest.jsonl");
+/// # std::fs::remove_file(&path).ok();
+/// let journal = Journal::open(&path).unwrap();
+///
+/// let mediator = BasicMediator::<MyEvent>::builder()
+///     .with_observer(journal.clone())
+///     .build();
+///
+/// mediator.publish(MyEvent::One);
+/// mediator.publish_to("numbers", MyEvent::Two);
+/// assert!(journal.take_error().is_none());
+///
+/// let fresh = BasicMediator::<MyEvent>::builder().build();
+/// assert_eq!(fresh.replay_from(&path).unwrap(), 2);
+/// assert_eq!(fresh.next_all(), 2);
+/// # std::fs::remove_file(&path).ok();
+///
+#[derive(Debug, Clone)]
+pub struct Journal<C = JsonCodec> {
+    inner: Arc<Mutex<JournalInner>>,
+    version: u32,
+    codec: C,
+}
+
+#[derive(Debug)]
+struct JournalInner {
+    file: File,
+    error: Option<io::Error>,
+}
+
+impl Journal {
+    /// Opens the journal at `path` for appending, creating the file if it doesn't exist.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::open_with_codec(path, JsonCodec)
+    }
+}
+
+impl<C: Codec> Journal<C> {
+    /// Opens the journal at `path` for appending events in the format of the `codec`,
+    /// creating the file if it doesn't exist.
+    ///
+    /// Read it back with the same codec, e.g. with `replay_from_with()`.
+    ///
+    /// See [`Codec`] for an example.
+    pub fn open_with_codec(path: impl AsRef<Path>, codec: C) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            inner: Arc::new(Mutex::new(JournalInner { file, error: None })),
+            version: 0,
+            codec,
+        })
+    }
+
+    /// Writes all events with the given `version`, 0 by default.
+    ///
+    /// Bump the version whenever the event type changes shape,
+    /// and register an upcaster for the previous version with `register_upcaster()`,
+    /// so journals written by older releases remain loadable.
+    ///
+    /// See [`Upcasters`] for an example.
+    pub fn with_version(self, version: u32) -> Self {
+        Self { version, ..self }
+    }
+
+    /// Takes the first error that occurred while writing to the journal, if any.
+    ///
+    /// Events failing to be written are skipped,
+    /// so the journal is incomplete if this returns an error.
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.inner.lock().error.take()
+    }
+
+    fn append<Ev: Serialize>(&self, topic: Option<&str>, event: &Ev) {
+        let entry = JournalLine {
+            topic,
+            version: self.version,
+            event,
+        };
+        let mut inner = self.inner.lock();
+        // Every frame is written at once, so a crash can only truncate the last one.
+        let res =
+            codec::encode_frame(&self.codec, &entry).and_then(|frame| inner.file.write_all(&frame));
+        if let Err(err) = res {
+            inner.error.get_or_insert(err);
+        }
+    }
+}
+
+impl<Ev, C> MediatorObserver<Ev> for Journal<C>
+where
+    C: Codec,
+    Ev: MaybeDebug + Serialize,
+{
+    fn on_event_published(&self, ev: &Ev, topic: Option<&str>) {
+        self.append(topic, ev);
+    }
+}
+
+type Upcaster<Ev> = dyn Fn(Value) -> Ev + Send + Sync;
+
+/// The upcasters of a mediator, turning events of older versions into the current event `Ev`,
+/// registered with `register_upcaster()`.
+///
+/// Every event of a journal is written with the version of its [`Journal`],
+/// see [`Journal::with_version()`]. When replaying a journal,
+/// events of a version with a registered upcaster are handed to it as JSON,
+/// while events of all other versions are deserialized into `Ev` right away.
+/// An upcaster turns an old event into the current `Ev` in one step,
+/// so there's one upcaster for every outdated version.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use mediatrix::persistence::Journal;
+/// use serde::{Deserialize, Serialize};
+///
+/// // The first release journaled `Deposited(u32)` without a currency.
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// enum MyEvent {
+///     Deposited { amount: u32, currency: String }
+/// }
+///
+/// let path = std::env::temp_dir().join("mediatrix-upcaster-doctest.jsonl");
+/// std::fs::write(&path, "{\"topic\":null,\"event\":{\"Deposited\":50}}\n").unwrap();
+///
+/// let journal = Journal::open(&path).unwrap().with_version(1);
+/// let mediator = BasicMediator::<MyEvent>::builder()
+///     .with_observer(journal)
+///     .build();
+///
+/// mediator.publish(MyEvent::Deposited { amount: 20, currency: "USD".to_string() });
+///
+/// let fresh = BasicMediator::<MyEvent>::builder()
+///     .register_upcaster(0, |old| MyEvent::Deposited {
+///         amount: old["Deposited"].as_u64().unwrap_or_default() as u32,
+///         currency: "EUR".to_string(),
+///     })
+///     .build();
+///
+/// assert_eq!(fresh.replay_from(&path).unwrap(), 2);
+/// assert_eq!(fresh.next_all(), 2);
+/// # std::fs::remove_file(&path).ok();
+///
+pub struct Upcasters<Ev> {
+    upcasters: BTreeMap<u32, Arc<Upcaster<Ev>>>,
+}
+
+impl<Ev> Default for Upcasters<Ev> {
+    fn default() -> Self {
+        Self {
+            upcasters: BTreeMap::new(),
+        }
+    }
+}
+
+impl<Ev> Clone for Upcasters<Ev> {
+    fn clone(&self) -> Self {
+        Self {
+            upcasters: self.upcasters.clone(),
+        }
+    }
+}
+
+impl<Ev> Debug for Upcasters<Ev> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.upcasters.keys()).finish()
+    }
+}
+
+impl<Ev> Upcasters<Ev> {
+    /// Registers the upcaster `f` for events written with the version `from_version`,
+    /// replacing the one registered before, if any.
+    pub fn register(&mut self, from_version: u32, f: impl Fn(Value) -> Ev + Send + Sync + 'static) {
+        self.upcasters.insert(from_version, Arc::new(f));
+    }
+
+    fn is_empty(&self) -> bool {
+        self.upcasters.is_empty()
+    }
+}
+
+impl<Ev> Upcasters<Ev>
+where
+    Ev: DeserializeOwned,
+{
+    /// Turns the `event` written with `version` into the current `Ev`.
+    fn upcast(&self, version: u32, event: Value) -> io::Result<Ev> {
+        match self.upcasters.get(&version) {
+            Some(upcaster) => Ok(upcaster(event)),
+            None => Ok(serde_json::from_value(event)?),
+        }
+    }
+}
+
+/// Reads the journal at `path`, yielding its entries oldest first.
+///
+/// The file is read lazily, a malformed line yields an error.
+pub fn read_journal<Ev>(path: impl AsRef<Path>) -> io::Result<JournalReader<Ev>>
+where
+    Ev: DeserializeOwned,
+{
+    read_journal_upcasted(path, Upcasters::default())
+}
+
+/// Same as [`read_journal()`], but events of older versions are turned
+/// into the current `Ev` by the [`Upcasters`].
+pub fn read_journal_upcasted<Ev>(
+    path: impl AsRef<Path>,
+    upcasters: Upcasters<Ev>,
+) -> io::Result<JournalReader<Ev>>
+where
+    Ev: DeserializeOwned,
+{
+    read_journal_with(path, JsonCodec, upcasters)
+}
+
+/// Same as [`read_journal_upcasted()`], but for a journal written in the format of the `codec`.
+pub fn read_journal_with<Ev, C>(
+    path: impl AsRef<Path>,
+    codec: C,
+    upcasters: Upcasters<Ev>,
+) -> io::Result<JournalReader<Ev, C>>
+where
+    Ev: DeserializeOwned,
+    C: Codec,
+{
+    Ok(JournalReader {
+        reader: BufReader::new(File::open(path)?),
+        codec,
+        upcasters,
+    })
+}
+
+/// An iterator over the entries of a journal,
+/// created by [`read_journal()`], [`read_journal_upcasted()`] or [`read_journal_with()`].
+#[derive(Debug)]
+pub struct JournalReader<Ev, C = JsonCodec> {
+    reader: BufReader<File>,
+    codec: C,
+    upcasters: Upcasters<Ev>,
+}
+
+impl<Ev, C> JournalReader<Ev, C>
+where
+    Ev: DeserializeOwned,
+    C: Codec,
+{
+    fn parse(&self, frame: &[u8]) -> io::Result<JournalEntry<Ev>> {
+        if self.upcasters.is_empty() {
+            return Ok(self.codec.decode::<Line<Ev>>(frame)?.into());
+        }
+        let line: VersionedLine = self.codec.decode(frame)?;
+        Ok(JournalEntry {
+            topic: line.topic,
+            event: self.upcasters.upcast(line.version, line.event)?,
+        })
+    }
+}
+
+impl<Ev, C> Iterator for JournalReader<Ev, C>
+where
+    Ev: DeserializeOwned,
+    C: Codec,
+{
+    type Item = io::Result<JournalEntry<Ev>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match codec::read_frame(&self.codec, &mut self.reader) {
+            Ok(Some(frame)) => Some(self.parse(&frame)),
+            Ok(None) => None,
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(feature = "crossbeam")]
+use crate::mediator::channel::CrossbeamChannel;
+#[cfg(all(feature = "flume", not(feature = "crossbeam")))]
+use crate::mediator::channel::FlumeChannel;
+use crate::mediator::channel::{EventChannel, OverflowPolicy};
+#[cfg(feature = "async")]
+use crate::mediator::ordering::Ordering;
+
+/// A preset of the channel and processing options of a mediator,
+/// configured with `with_profile()`.
+///
+/// A profile is a starting point: options configured after `with_profile()`
+/// override the ones of the profile, e.g. `with_capacity()`.
+///
+/// | | [`Profile::LowLatency`] | [`Profile::HighThroughput`] | [`Profile::Deterministic`] |
+/// |-|-|-|-|
+/// | Channel backend | fastest enabled | fastest enabled | default |
+/// | Capacity | 64 | 1024 | unbounded |
+/// | [`OverflowPolicy`] | `DropOldest` | `Block` | `Block` |
+/// | Concurrency (async) | 8 | 32 | 1 |
+/// | Ordering (async) | `Unordered` | `Unordered` | `StrictFifo` |
+///
+/// The fastest enabled channel backend is the one of `crossbeam-channel`
+/// with the `crossbeam` feature, otherwise the one of `flume` with the `flume` feature,
+/// otherwise the default one.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     Tick(u32)
+/// }
+///
+/// let mediator = BasicMediator::<MyEvent>::builder()
+///     .add_listener(|ev: &MyEvent| {
+///         /* Your listening logic */
+///     })
+///     .with_profile(Profile::LowLatency)
+///     .build();
+///
+/// for tick in 0..100 {
+///     mediator.publish(MyEvent::Tick(tick));
+/// }
+///
+/// // Only the newest events are kept.
+/// assert_eq!(mediator.next_all(), 64);
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Profile {
+    /// Keeps the delay between publishing and processing an event low.
+    ///
+    /// The channel is small and drops the oldest events once full,
+    /// so listeners never lag behind on a backlog of stale events.
+    /// Async listeners are invoked concurrently.
+    LowLatency,
+    /// Processes as many events as possible.
+    ///
+    /// The channel is large and makes publishers wait once full,
+    /// so no event is lost. Events and their async listeners
+    /// are processed with a high concurrency.
+    HighThroughput,
+    /// Processes events and invokes listeners in a reproducible order,
+    /// e.g. for tests or event sourcing.
+    ///
+    /// The channel is unbounded, so no event is lost and publishers never wait.
+    /// Events are processed one after another in publishing order.
+    Deterministic,
+}
+
+impl Profile {
+    /// Returns the capacity of the channel, or `None` if it is unbounded.
+    pub fn capacity(&self) -> Option<usize> {
+        match self {
+            Profile::LowLatency => Some(64),
+            Profile::HighThroughput => Some(1024),
+            Profile::Deterministic => None,
+        }
+    }
+
+    /// Returns the [`OverflowPolicy`] of the channel.
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        match self {
+            Profile::LowLatency => OverflowPolicy::DropOldest,
+            Profile::HighThroughput | Profile::Deterministic => OverflowPolicy::Block,
+        }
+    }
+
+    /// Returns how many listeners and events an async mediator processes concurrently.
+    pub fn concurrency(&self) -> usize {
+        match self {
+            Profile::LowLatency => 8,
+            Profile::HighThroughput => 32,
+            Profile::Deterministic => 1,
+        }
+    }
+
+    /// Returns the [`Ordering`] in which an async mediator processes events and their listeners.
+    #[cfg(feature = "async")]
+    pub fn ordering(&self) -> Ordering {
+        match self {
+            Profile::LowLatency | Profile::HighThroughput => Ordering::Unordered,
+            Profile::Deterministic => Ordering::StrictFifo,
+        }
+    }
+
+    /// Configures the backend, capacity and [`OverflowPolicy`] of the `channel`.
+    pub(crate) fn configure<Ev: Send + 'static>(&self, channel: &mut EventChannel<Ev>) {
+        channel.set_capacity(self.capacity());
+        channel.policy = self.overflow_policy();
+        match self {
+            Profile::LowLatency | Profile::HighThroughput => {
+                #[cfg(feature = "crossbeam")]
+                channel.set_flavor::<CrossbeamChannel>();
+                #[cfg(all(feature = "flume", not(feature = "crossbeam")))]
+                channel.set_flavor::<FlumeChannel>();
+                #[cfg(not(any(feature = "crossbeam", feature = "flume")))]
+                channel.set_default_flavor();
+            }
+            Profile::Deterministic => channel.set_default_flavor(),
+        }
+    }
+}
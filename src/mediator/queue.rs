@@ -0,0 +1,132 @@
+use std::{cmp::Ordering, collections::BinaryHeap, fmt::Debug, sync::Mutex};
+
+use crate::mediator::{
+    asynchronous::basic::BasicAsyncMediator, listener::MaybeDebug, registry::BoxFuture,
+};
+
+/// The priority of a request queued with `send_with_priority()`.
+///
+/// Queued requests are handled by the running mediator, see `run()`,
+/// with higher priorities first and requests of the same priority in queuing order.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::asynchronous::basic::*;
+/// use async_trait::async_trait;
+/// use std::sync::Arc;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     Stopped
+/// }
+///
+/// struct Stop;
+///
+/// #[async_trait]
+/// impl AsyncRequestHandler<Stop, MyEvent> for BasicAsyncMediator<MyEvent> {
+///     async fn handle(&self, _req: Stop) {
+///         self.publish(MyEvent::Stopped).await;
+///     }
+/// }
+///
+/// async_std::task::block_on(async {
+///     let mediator = Arc::new(BasicAsyncMediator::<MyEvent>::builder().build());
+///     let worker = mediator.clone().spawn_worker();
+///
+///     mediator.send_with_priority(Stop, Priority::High).await;
+///
+///     assert_eq!(worker.shutdown().await, 1);
+/// });
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Handled after all other queued requests, e.g. bulk data processing.
+    Low,
+    /// The default priority, also used by `queue_request()`.
+    #[default]
+    Normal,
+    /// Handled before all other queued requests, e.g. control commands.
+    High,
+}
+
+/// A queued request, handled by the mediator passed to it.
+pub(crate) type Job<Ev> = Box<dyn FnOnce(BasicAsyncMediator<Ev>) -> BoxFuture<()> + Send>;
+
+/// Requests waiting to be handled by the running mediator, highest [`Priority`] first.
+pub(crate) struct RequestQueue<Ev: MaybeDebug + 'static> {
+    jobs: Mutex<Jobs<Ev>>,
+}
+
+struct Jobs<Ev: MaybeDebug + 'static> {
+    heap: BinaryHeap<Queued<Ev>>,
+    seq: u64,
+}
+
+/// A queued [`Job`], ordered such that the [`BinaryHeap`]
+/// yields the highest priority first and jobs of the same priority in queuing order.
+struct Queued<Ev: MaybeDebug + 'static> {
+    priority: Priority,
+    seq: u64,
+    job: Job<Ev>,
+}
+
+impl<Ev: MaybeDebug> PartialEq for Queued<Ev> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<Ev: MaybeDebug> Eq for Queued<Ev> {}
+
+impl<Ev: MaybeDebug> PartialOrd for Queued<Ev> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Ev: MaybeDebug> Ord for Queued<Ev> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.priority, other.seq).cmp(&(other.priority, self.seq))
+    }
+}
+
+impl<Ev: MaybeDebug> Default for RequestQueue<Ev> {
+    fn default() -> Self {
+        Self {
+            jobs: Mutex::new(Jobs {
+                heap: BinaryHeap::new(),
+                seq: 0,
+            }),
+        }
+    }
+}
+
+impl<Ev: MaybeDebug> RequestQueue<Ev> {
+    pub(crate) fn push(&self, priority: Priority, job: Job<Ev>) {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|err| err.into_inner());
+        let seq = jobs.seq;
+        jobs.seq += 1;
+        jobs.heap.push(Queued { priority, seq, job });
+    }
+
+    pub(crate) fn pop(&self) -> Option<Job<Ev>> {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|err| err.into_inner());
+        jobs.heap.pop().map(|queued| queued.job)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        let jobs = self.jobs.lock().unwrap_or_else(|err| err.into_inner());
+        jobs.heap.len()
+    }
+}
+
+impl<Ev: MaybeDebug> Debug for RequestQueue<Ev> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestQueue")
+            .field("len", &self.len())
+            .finish()
+    }
+}
@@ -0,0 +1,143 @@
+use std::{collections::VecDeque, time::Duration};
+
+use crate::mediator::{sync::Mutex, time::Instant};
+
+/// Decides what happens to events published faster than the [`RateLimit`] allows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ThrottlePolicy {
+    /// Wait until the next event may be delivered.
+    ///
+    /// A synchronous mediator blocks the thread processing the events.
+    /// Asynchronous mediators wait without blocking the thread.
+    #[default]
+    Queue,
+    /// Drop the events until the next event may be delivered.
+    Drop,
+    /// Wait until the next event may be delivered, then deliver only
+    /// the newest pending event and drop all events published in the meantime.
+    ///
+    /// This suits streams of events superseding each other, e.g. sensor readings or ticks.
+    Coalesce,
+}
+
+/// Limits how many events a mediator delivers to its listeners within a time window,
+/// configured with `with_rate_limit()`.
+///
+/// The limit applies when events are processed, so publishers are never slowed down.
+/// Events exceeding the limit are handled according to the [`ThrottlePolicy`].
+/// Dropped events become dead letters with
+/// [`DeadLetterReason::RateLimited`](crate::deadletter::DeadLetterReason::RateLimited).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     Tick(u32)
+/// }
+///
+/// let mediator = BasicMediator::<MyEvent>::builder()
+///     .add_listener(|ev: &MyEvent| {
+///         /* Your expensive listening logic */
+///     })
+///     .with_rate_limit(RateLimit::per_second(2).with_policy(ThrottlePolicy::Drop))
+///     .build();
+///
+/// for tick in 0..5 {
+///     mediator.publish(MyEvent::Tick(tick));
+/// }
+///
+/// assert_eq!(mediator.next_all(), 2);
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    events: usize,
+    window: Duration,
+    policy: ThrottlePolicy,
+}
+
+impl RateLimit {
+    /// Allows `events` within every `window`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `events` is 0.
+    pub fn new(events: usize, window: Duration) -> Self {
+        assert!(events > 0, "rate limit must allow at least 1 event");
+        Self {
+            events,
+            window,
+            policy: ThrottlePolicy::default(),
+        }
+    }
+
+    /// Allows `events` per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `events` is 0.
+    pub fn per_second(events: usize) -> Self {
+        Self::new(events, Duration::from_secs(1))
+    }
+
+    /// Sets the [`ThrottlePolicy`] for events exceeding the limit.
+    pub fn with_policy(self, policy: ThrottlePolicy) -> Self {
+        Self { policy, ..self }
+    }
+
+    /// Returns the [`ThrottlePolicy`] for events exceeding the limit.
+    pub fn policy(&self) -> ThrottlePolicy {
+        self.policy
+    }
+}
+
+/// What to do with an event subject to a [`RateLimit`].
+#[derive(Debug)]
+pub(crate) enum Throttled<T> {
+    /// Deliver the event right away.
+    Deliver(T),
+    /// Wait for the given duration before trying to deliver the event again.
+    Wait(T, Duration),
+}
+
+/// Tracks the events delivered within the window of a [`RateLimit`].
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    pub(crate) limit: RateLimit,
+    delivered: Mutex<VecDeque<Instant>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            delivered: Mutex::new(VecDeque::with_capacity(limit.events)),
+        }
+    }
+
+    /// Takes a slot to deliver an event right now,
+    /// or returns how long to wait until the next slot is free.
+    pub(crate) fn acquire(&self) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut delivered = self.delivered.lock();
+        while let Some(&oldest) = delivered.front() {
+            if now.duration_since(oldest) < self.limit.window {
+                break;
+            }
+            delivered.pop_front();
+        }
+        match delivered.front() {
+            Some(&oldest) if delivered.len() >= self.limit.events => {
+                Err(self.limit.window - now.duration_since(oldest))
+            }
+            _ => {
+                delivered.push_back(now);
+                Ok(())
+            }
+        }
+    }
+}
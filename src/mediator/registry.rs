@@ -0,0 +1,164 @@
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+use core::{
+    any::{Any, TypeId},
+    fmt::Debug,
+};
+
+#[cfg(feature = "async")]
+use std::{future::Future, pin::Pin, sync::Arc};
+
+/// A type-erased request handler registered with `register_handler()`.
+///
+/// It is invoked with the mediator it was registered for and the boxed request.
+pub(crate) type DynHandler = dyn Fn(&dyn Any, Box<dyn Any + Send>) + Send + Sync;
+
+/// The asynchronous counterpart to [`DynHandler`].
+#[cfg(feature = "async")]
+pub(crate) type AsyncDynHandler = dyn for<'a> Fn(&'a dyn Any, Box<dyn Any + Send>) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>>
+    + Send
+    + Sync;
+
+/// A type-erased [`ClosureHandler`] added with `add_request_handler()`.
+pub(crate) type AnyHandler = dyn Any + Send + Sync;
+
+/// A closure handling requests `Req` sent to the mediator `M`, returning a response `Resp`.
+pub(crate) type ClosureHandler<M, Req, Resp> = dyn Fn(&M, Req) -> Resp + Send + Sync;
+
+/// A closure handling requests `Req` sent to the mediator `M` together with other closures.
+pub(crate) type FanOutHandler<M, Req> = dyn Fn(&M, &Req) + Send + Sync;
+
+/// The asynchronous counterpart to [`FanOutHandler`], which shares the request.
+#[cfg(feature = "async")]
+pub(crate) type AsyncFanOutHandler<M, Req> = dyn Fn(&M, Arc<Req>) -> BoxFuture<()> + Send + Sync;
+
+/// The response of an asynchronous [`ClosureHandler`].
+#[cfg(feature = "async")]
+pub(crate) type BoxFuture<Resp> = Pin<Box<dyn Future<Output = Resp> + Send>>;
+
+/// Request handlers `H`, keyed by the [`TypeId`] of the request they handle.
+pub(crate) struct HandlerRegistry<H: ?Sized> {
+    handlers: BTreeMap<TypeId, Box<H>>,
+}
+
+impl<H: ?Sized> Default for HandlerRegistry<H> {
+    fn default() -> Self {
+        Self {
+            handlers: BTreeMap::new(),
+        }
+    }
+}
+
+impl<H: ?Sized> Debug for HandlerRegistry<H> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HandlerRegistry")
+            .field("handlers", &self.handlers.len())
+            .finish()
+    }
+}
+
+impl<H: ?Sized> HandlerRegistry<H> {
+    /// Registers the handler for requests of type `Req`,
+    /// replacing the previously registered one, if any.
+    pub(crate) fn insert<Req: 'static>(&mut self, handler: Box<H>) {
+        self.handlers.insert(TypeId::of::<Req>(), handler);
+    }
+
+    /// Returns the handler registered for the type of `req`, if any.
+    pub(crate) fn get(&self, req: &(dyn Any + Send)) -> Option<&H> {
+        self.handlers.get(&req.type_id()).map(Box::as_ref)
+    }
+}
+
+impl HandlerRegistry<AnyHandler> {
+    /// Adds the closure handling requests `Req` with a response `Resp`,
+    /// replacing the previously added one, if any.
+    pub(crate) fn insert_closure<M, Req, Resp>(
+        &mut self,
+        handler: Box<ClosureHandler<M, Req, Resp>>,
+    ) where
+        M: 'static,
+        Req: 'static,
+        Resp: 'static,
+    {
+        self.handlers
+            .insert(TypeId::of::<(Req, Resp)>(), Box::new(handler));
+    }
+
+    /// Returns the closure handling requests `Req` with a response `Resp`, if any.
+    pub(crate) fn closure<M, Req, Resp>(&self) -> Option<&ClosureHandler<M, Req, Resp>>
+    where
+        M: 'static,
+        Req: 'static,
+        Resp: 'static,
+    {
+        self.handlers
+            .get(&TypeId::of::<(Req, Resp)>())?
+            .downcast_ref::<Box<ClosureHandler<M, Req, Resp>>>()
+            .map(Box::as_ref)
+    }
+
+    /// Adds the fan-out handler `H` for requests `Req` after the previously added ones.
+    pub(crate) fn push_fan_out<Req, H>(&mut self, handler: Box<H>)
+    where
+        Req: 'static,
+        H: ?Sized + Send + Sync + 'static,
+    {
+        self.handlers
+            .entry(TypeId::of::<Req>())
+            .or_insert_with(|| Box::new(Vec::<Box<H>>::new()))
+            .downcast_mut::<Vec<Box<H>>>()
+            .expect("fan-out handlers added for another mediator")
+            .push(handler);
+    }
+
+    /// Returns the fan-out handlers `H` for requests `Req` in the order they were added.
+    pub(crate) fn fan_out<Req, H>(&self) -> &[Box<H>]
+    where
+        Req: 'static,
+        H: ?Sized + 'static,
+    {
+        self.handlers
+            .get(&TypeId::of::<Req>())
+            .and_then(|handlers| handlers.downcast_ref::<Vec<Box<H>>>())
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Erases the type of a handler for requests `Req` sent to the mediator `M`.
+pub(crate) fn erase<M, Req>(handler: impl Fn(&M, Req) + Send + Sync + 'static) -> Box<DynHandler>
+where
+    M: 'static,
+    Req: 'static,
+{
+    Box::new(move |m, req| {
+        let m = m
+            .downcast_ref::<M>()
+            .expect("handler invoked with another mediator");
+        let req = req
+            .downcast::<Req>()
+            .expect("handler invoked with another request");
+        handler(m, *req)
+    })
+}
+
+/// Erases the type of an asynchronous handler for requests `Req` sent to the mediator `M`.
+#[cfg(feature = "async")]
+pub(crate) fn erase_async<M, Req, F>(handler: F) -> Box<AsyncDynHandler>
+where
+    M: 'static,
+    Req: 'static,
+    F: for<'a> Fn(&'a M, Req) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>>
+        + Send
+        + Sync
+        + 'static,
+{
+    Box::new(move |m, req| {
+        let m = m
+            .downcast_ref::<M>()
+            .expect("handler invoked with another mediator");
+        let req = req
+            .downcast::<Req>()
+            .expect("handler invoked with another request");
+        handler(m, *req)
+    })
+}
@@ -0,0 +1,360 @@
+use async_std::{
+    channel::{self, Receiver, Sender},
+    io::{prelude::*, BufReader},
+    net::{TcpListener, TcpStream},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    fmt::Debug,
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+#[cfg(unix)]
+use async_std::os::unix::net::{UnixListener, UnixStream};
+#[cfg(unix)]
+use std::path::PathBuf;
+
+use crate::mediator::{
+    asynchronous::basic::interface::AsyncMediatorInternal,
+    cancellation::{cancellable, CancellationToken},
+    codec::{self, Codec, JsonCodec},
+    listener::MaybeDebug,
+    observer::MediatorObserver,
+    persistence::{JournalEntry, JournalLine, Line},
+    runtime,
+    worker::Worker,
+};
+
+/// The longest delay between two attempts to reconnect.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// The address a bridge connects to or listens on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Endpoint {
+    /// A TCP socket address, e.g. `127.0.0.1:7000`.
+    Tcp(String),
+    /// A Unix domain socket at the given path.
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+type Outgoing = Box<dyn Write + Send + Unpin>;
+type Incoming = Box<dyn Read + Send + Unpin>;
+
+impl Endpoint {
+    async fn connect(&self) -> io::Result<Outgoing> {
+        match self {
+            Endpoint::Tcp(addr) => Ok(Box::new(TcpStream::connect(addr.as_str()).await?)),
+            #[cfg(unix)]
+            Endpoint::Unix(path) => Ok(Box::new(UnixStream::connect(path).await?)),
+        }
+    }
+
+    async fn bind(&self) -> io::Result<Acceptor> {
+        match self {
+            Endpoint::Tcp(addr) => Ok(Acceptor::Tcp(TcpListener::bind(addr.as_str()).await?)),
+            #[cfg(unix)]
+            Endpoint::Unix(path) => Ok(Acceptor::Unix(UnixListener::bind(path).await?)),
+        }
+    }
+}
+
+/// A bound [`Endpoint`] accepting incoming connections.
+enum Acceptor {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(UnixListener),
+}
+
+impl Acceptor {
+    async fn accept(&self) -> io::Result<Incoming> {
+        match self {
+            Acceptor::Tcp(listener) => Ok(Box::new(listener.accept().await?.0)),
+            #[cfg(unix)]
+            Acceptor::Unix(listener) => Ok(Box::new(listener.accept().await?.0)),
+        }
+    }
+}
+
+/// Forwards every event published by a mediator to a remote mediator listening with [`serve()`].
+///
+/// A [`RemoteForwarder`] is a [`MediatorObserver`], add it to a mediator with `with_observer()`.
+/// Events are serialized like in a [`Journal`](crate::persistence::Journal),
+/// as JSON or in the format of another [`Codec`], see [`RemoteForwarder::spawn_with_codec()`],
+/// and queued, so publishing never waits for the network.
+/// The returned [`Worker`] sends them over the socket in publishing order.
+/// If the connection can't be established or breaks, the worker reconnects,
+/// waiting `backoff` before the first retry and doubling the delay for every further retry.
+/// Events written right before the connection breaks may be lost.
+///
+/// Bridging two mediators in both directions forwards events back and forth endlessly,
+/// unless the remote mediators use distinct event types.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```no_run
+/// use mediatrix::asynchronous::basic::*;
+/// use mediatrix::remote::{serve, Endpoint, RemoteForwarder};
+/// use serde::{Deserialize, Serialize};
+/// use std::{sync::Arc, time::Duration};
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// enum MyEvent {
+///     One,
+///     Two
+/// }
+///
+/// async_std::task::block_on(async {
+///     // In the receiving process:
+///     let remote = Arc::new(BasicAsyncMediator::<MyEvent>::builder().build());
+///     let server = serve(Endpoint::Tcp("127.0.0.1:7000".into()), remote.clone())
+///         .await
+///         .unwrap();
+///
+///     // In the sending process:
+///     let (forwarder, worker) = RemoteForwarder::spawn(
+///         Endpoint::Tcp("127.0.0.1:7000".into()),
+///         Duration::from_millis(100),
+///     );
+///     let local = BasicAsyncMediator::<MyEvent>::builder()
+///         .with_observer(forwarder)
+///         .build();
+///     local.publish(MyEvent::One).await;
+/// });
+///
+#[derive(Debug, Clone)]
+pub struct RemoteForwarder<C = JsonCodec> {
+    queue: Sender<Vec<u8>>,
+    codec: C,
+}
+
+impl RemoteForwarder {
+    /// Creates a [`RemoteForwarder`] and spawns the [`Worker`] sending its events to `endpoint`.
+    ///
+    /// Once the worker is shut down, the remaining events are only sent if it is connected.
+    pub fn spawn(endpoint: Endpoint, backoff: Duration) -> (Self, Worker) {
+        Self::spawn_with_codec(endpoint, backoff, JsonCodec)
+    }
+}
+
+impl<C: Codec> RemoteForwarder<C> {
+    /// Creates a [`RemoteForwarder`] sending its events in the format of the `codec`
+    /// and spawns the [`Worker`] sending them to `endpoint`.
+    ///
+    /// The remote mediator has to listen with [`serve_with_codec()`] and the same codec.
+    pub fn spawn_with_codec(endpoint: Endpoint, backoff: Duration, codec: C) -> (Self, Worker) {
+        let (queue, frames) = channel::unbounded();
+        let worker = Worker::spawn(|shutdown| forward(endpoint, backoff, frames, shutdown));
+        (Self { queue, codec }, worker)
+    }
+}
+
+impl<Ev, C> MediatorObserver<Ev> for RemoteForwarder<C>
+where
+    Ev: MaybeDebug + Serialize,
+    C: Codec,
+{
+    fn on_event_published(&self, ev: &Ev, topic: Option<&str>) {
+        let line = JournalLine {
+            topic,
+            version: 0,
+            event: ev,
+        };
+        if let Ok(frame) = codec::encode_frame(&self.codec, &line) {
+            self.queue.try_send(frame).ok();
+        }
+    }
+}
+
+/// The forwarding task, returns the number of forwarded events.
+async fn forward(
+    endpoint: Endpoint,
+    backoff: Duration,
+    frames: Receiver<Vec<u8>>,
+    shutdown: CancellationToken,
+) -> usize {
+    let mut conn = None;
+    let mut forwarded = 0;
+    // Stops once all forwarders are dropped or the shutdown is signalled.
+    while let Ok(Ok(frame)) = cancellable(frames.recv(), &shutdown).await {
+        if !deliver(&endpoint, backoff, &mut conn, &frame, &shutdown).await {
+            return forwarded;
+        }
+        forwarded += 1;
+    }
+    if let Some(conn) = conn.as_mut() {
+        while let Ok(frame) = frames.try_recv() {
+            if write_frame(conn, &frame).await.is_err() {
+                break;
+            }
+            forwarded += 1;
+        }
+    }
+    forwarded
+}
+
+/// Writes `frame`, (re)connecting until it succeeds.
+///
+/// Returns `false` if the shutdown was signalled before.
+async fn deliver(
+    endpoint: &Endpoint,
+    backoff: Duration,
+    conn: &mut Option<Outgoing>,
+    frame: &[u8],
+    shutdown: &CancellationToken,
+) -> bool {
+    let mut delay = backoff;
+    loop {
+        let out = match conn {
+            Some(out) => out,
+            None => match cancellable(endpoint.connect(), shutdown).await {
+                Ok(Ok(out)) => conn.insert(out),
+                Ok(Err(_)) => {
+                    if cancellable(runtime::sleep(delay), shutdown).await.is_err() {
+                        return false;
+                    }
+                    delay = delay.saturating_mul(2).min(MAX_BACKOFF);
+                    continue;
+                }
+                Err(_) => return false,
+            },
+        };
+        if write_frame(out, frame).await.is_ok() {
+            return true;
+        }
+        *conn = None;
+    }
+}
+
+async fn write_frame(out: &mut Outgoing, frame: &[u8]) -> io::Result<()> {
+    out.write_all(frame).await?;
+    out.flush().await
+}
+
+/// Listens on `endpoint` and publishes all events received
+/// from [`RemoteForwarder`]s into the `mediator`.
+///
+/// Every event is published to the topic it was originally published to, if any.
+/// Malformed events are skipped.
+/// The returned [`Worker`] accepts connections until it is shut down
+/// and reports the number of received events.
+///
+/// See [`RemoteForwarder`] for an example.
+///
+pub async fn serve<M, Ev>(endpoint: Endpoint, mediator: Arc<M>) -> io::Result<Worker>
+where
+    M: AsyncMediatorInternal<Ev> + Send + Sync + 'static,
+    Ev: MaybeDebug + Send + DeserializeOwned + 'static,
+{
+    serve_with_codec(endpoint, mediator, JsonCodec).await
+}
+
+/// Same as [`serve()`], but receives events in the format of the `codec`,
+/// sent by [`RemoteForwarder`]s created with [`RemoteForwarder::spawn_with_codec()`].
+pub async fn serve_with_codec<M, Ev, C>(
+    endpoint: Endpoint,
+    mediator: Arc<M>,
+    codec: C,
+) -> io::Result<Worker>
+where
+    M: AsyncMediatorInternal<Ev> + Send + Sync + 'static,
+    Ev: MaybeDebug + Send + DeserializeOwned + 'static,
+    C: Codec,
+{
+    let acceptor = endpoint.bind().await?;
+    Ok(Worker::spawn(|shutdown| {
+        accept(acceptor, mediator, codec, shutdown)
+    }))
+}
+
+/// The accepting task, returns the number of received events.
+async fn accept<M, Ev, C>(
+    acceptor: Acceptor,
+    mediator: Arc<M>,
+    codec: C,
+    shutdown: CancellationToken,
+) -> usize
+where
+    M: AsyncMediatorInternal<Ev> + Send + Sync + 'static,
+    Ev: MaybeDebug + Send + DeserializeOwned + 'static,
+    C: Codec,
+{
+    let received = Arc::new(AtomicUsize::new(0));
+    let mut connections = Vec::new();
+    while let Ok(accepted) = cancellable(acceptor.accept(), &shutdown).await {
+        // A failed connection attempt doesn't affect the others.
+        if let Ok(incoming) = accepted {
+            connections.push(runtime::spawn(receive(
+                incoming,
+                mediator.clone(),
+                codec.clone(),
+                received.clone(),
+                shutdown.clone(),
+            )));
+        }
+    }
+    for connection in connections {
+        connection.await;
+    }
+    received.load(Ordering::SeqCst)
+}
+
+/// Publishes the events received over a single connection until it is closed.
+async fn receive<M, Ev, C>(
+    incoming: Incoming,
+    mediator: Arc<M>,
+    codec: C,
+    received: Arc<AtomicUsize>,
+    shutdown: CancellationToken,
+) where
+    M: AsyncMediatorInternal<Ev> + Send + Sync,
+    Ev: MaybeDebug + Send + DeserializeOwned,
+    C: Codec,
+{
+    let mut reader = BufReader::new(incoming);
+    let mut frame = Vec::new();
+    loop {
+        match cancellable(read_frame(&codec, &mut reader, &mut frame), &shutdown).await {
+            Ok(Ok(true)) => {}
+            Ok(Ok(false)) | Ok(Err(_)) | Err(_) => return,
+        }
+        let Ok(line) = codec.decode::<Line<Ev>>(&frame) else {
+            continue;
+        };
+        let entry = JournalEntry::from(line);
+        match entry.topic {
+            Some(topic) => mediator.publish_to(&topic, entry.event).await,
+            None => mediator.publish(entry.event).await,
+        }
+        received.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Reads the next frame written by [`codec::encode_frame()`] into `frame`,
+/// returns `false` once the connection is closed.
+async fn read_frame<C: Codec>(
+    codec: &C,
+    reader: &mut BufReader<Incoming>,
+    frame: &mut Vec<u8>,
+) -> io::Result<bool> {
+    frame.clear();
+    if codec.is_line_delimited() {
+        return Ok(reader.read_until(b'\n', frame).await? > 0);
+    }
+    let mut len = [0; 4];
+    match reader.read_exact(&mut len).await {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(false),
+        Err(err) => return Err(err),
+    }
+    frame.resize(u32::from_le_bytes(len) as usize, 0);
+    reader.read_exact(frame).await?;
+    Ok(true)
+}
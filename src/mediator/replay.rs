@@ -0,0 +1,63 @@
+use alloc::collections::VecDeque;
+use core::fmt::Debug;
+
+use crate::mediator::sync::Mutex;
+
+/// Ring buffer of the last processed events `Ev`,
+/// which are replayed to listeners attached later on.
+///
+/// Events are cloned via the `clone` function captured on creation,
+/// so only configuring a replay buffer requires `Ev: Clone`.
+#[derive(Debug)]
+pub(crate) struct ReplayBuffer<Ev> {
+    capacity: usize,
+    clone: fn(&Ev) -> Ev,
+    events: Mutex<VecDeque<Ev>>,
+}
+
+impl<Ev> ReplayBuffer<Ev> {
+    pub(crate) fn new(capacity: usize) -> Self
+    where
+        Ev: Clone,
+    {
+        Self {
+            capacity,
+            clone: Ev::clone,
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Runs `notify` and records a clone of `ev` afterwards.
+    ///
+    /// The lock is held throughout, so a listener attached concurrently
+    /// either gets notified or receives the event as replay, but never both.
+    pub(crate) fn record<R>(&self, ev: &Ev, notify: impl FnOnce() -> R) -> R {
+        let mut events = self.events.lock();
+        let res = notify();
+        if events.len() == self.capacity {
+            events.pop_front();
+        }
+        events.push_back((self.clone)(ev));
+        res
+    }
+
+    /// Returns whether a listener panicked while the buffer was locked.
+    pub(crate) fn is_poisoned(&self) -> bool {
+        self.events.is_poisoned()
+    }
+
+    pub(crate) fn clear_poison(&self) {
+        self.events.clear_poison();
+    }
+
+    /// Replays all recorded events, oldest first, to `listener`
+    /// and hands it to `add` afterwards.
+    pub(crate) fn attach<L, R>(&self, listener: L, add: impl FnOnce(L) -> R) -> R
+    where
+        L: Fn(&Ev),
+    {
+        let events = self.events.lock();
+        events.iter().for_each(&listener);
+        add(listener)
+    }
+}
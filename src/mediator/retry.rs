@@ -0,0 +1,115 @@
+use std::{any::type_name, fmt::Debug, future::Future, time::Duration};
+
+use crate::mediator::runtime;
+
+/// Configures how often a failing request is attempted by `try_send_with_retry()`.
+///
+/// After a failed attempt, the mediator waits for `backoff` before trying again
+/// and doubles the delay for every further attempt.
+/// The default policy makes a single attempt without any retries.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::retry::RetryPolicy;
+/// use std::time::Duration;
+///
+/// // Attempts after 0ms, 10ms, 30ms and 70ms.
+/// let policy = RetryPolicy {
+///     max_attempts: 4,
+///     backoff: Duration::from_millis(10),
+/// };
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled for every further retry.
+    pub backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            backoff: Duration::ZERO,
+        }
+    }
+}
+
+/// Describes a request that failed on every attempt allowed by its [`RetryPolicy`].
+///
+/// Handed to the function configured with `on_retry_exhausted()`
+/// to create the event that is published in that case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryExhausted {
+    /// The type name of the request.
+    pub request: &'static str,
+    /// The number of attempts made.
+    pub attempts: u32,
+}
+
+type ExhaustedFn<Ev> = Box<dyn Fn(&RetryExhausted) -> Ev + Send + Sync>;
+
+/// The [`RetryPolicy`] of a mediator together with the
+/// function creating its "exhausted" event `Ev`, if any.
+pub(crate) struct Retry<Ev> {
+    pub(crate) policy: RetryPolicy,
+    pub(crate) exhausted: Option<ExhaustedFn<Ev>>,
+}
+
+impl<Ev> Default for Retry<Ev> {
+    fn default() -> Self {
+        Self {
+            policy: RetryPolicy::default(),
+            exhausted: None,
+        }
+    }
+}
+
+impl<Ev> Debug for Retry<Ev> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Retry")
+            .field("policy", &self.policy)
+            .field("exhausted", &self.exhausted.is_some())
+            .finish()
+    }
+}
+
+impl<Ev> Retry<Ev> {
+    /// Runs `attempt` for the request `Req` until it succeeds or the policy is exhausted.
+    ///
+    /// In the latter case, the last error is returned
+    /// together with the "exhausted" event, if configured.
+    pub(crate) async fn run<Req, Err, Fut>(
+        &self,
+        mut attempt: impl FnMut() -> Fut,
+    ) -> Result<(), (Err, Option<Ev>)>
+    where
+        Fut: Future<Output = Result<(), Err>>,
+    {
+        let mut delay = self.policy.backoff;
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            match attempt().await {
+                Ok(()) => return Ok(()),
+                Err(err) if attempts >= self.policy.max_attempts => {
+                    let ev = self.exhausted.as_ref().map(|f| {
+                        f(&RetryExhausted {
+                            request: type_name::<Req>(),
+                            attempts,
+                        })
+                    });
+                    return Err((err, ev));
+                }
+                Err(_) => {
+                    runtime::sleep(delay).await;
+                    delay = delay.saturating_mul(2);
+                }
+            }
+        }
+    }
+}
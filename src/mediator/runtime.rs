@@ -0,0 +1,87 @@
+//! Spawning and timers of the async mediators,
+//! delegated to the [`Executor`](crate::executor::Executor) of the process.
+
+use std::{
+    fmt::Debug,
+    future::{poll_fn, Future},
+    panic::{self, AssertUnwindSafe},
+    pin::{pin, Pin},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use crate::mediator::executor;
+
+/// A spawned task, awaiting it returns the output of the task.
+pub(crate) struct Task<T>(Pin<Box<dyn Future<Output = T> + Send>>);
+
+impl<T> Debug for Task<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Task").finish_non_exhaustive()
+    }
+}
+
+impl<T> Future for Task<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+/// Spawns `fut` onto the [`Executor`](crate::executor::Executor).
+///
+/// A panic of `fut` is caught and resumed when the [`Task`] is awaited.
+pub(crate) fn spawn<F>(fut: F) -> Task<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    let (output, received) = async_std::channel::bounded(1);
+    executor::current().spawn(Box::pin(async move {
+        let mut fut = pin!(fut);
+        let res =
+            poll_fn(
+                |cx| match panic::catch_unwind(AssertUnwindSafe(|| fut.as_mut().poll(cx))) {
+                    Ok(poll) => poll.map(Ok),
+                    Err(panic) => Poll::Ready(Err(panic)),
+                },
+            )
+            .await;
+        output.send(res).await.ok();
+    }));
+    Task(Box::pin(async move {
+        match received.recv().await.expect("spawned task was dropped") {
+            Ok(output) => output,
+            Err(panic) => panic::resume_unwind(panic),
+        }
+    }))
+}
+
+/// Waits until `duration` elapsed.
+pub(crate) async fn sleep(duration: Duration) {
+    executor::current().sleep(duration).await
+}
+
+/// Runs `fut` to completion unless `duration` elapses first,
+/// in which case `fut` is dropped at its current await point and `None` is returned.
+pub(crate) async fn timeout<T>(duration: Duration, fut: impl Future<Output = T>) -> Option<T> {
+    race(fut, sleep(duration)).await
+}
+
+/// Runs `fut` to completion unless `elapsed` completes first,
+/// in which case `fut` is dropped at its current await point and `None` is returned.
+pub(crate) async fn race<T>(
+    fut: impl Future<Output = T>,
+    elapsed: impl Future<Output = ()>,
+) -> Option<T> {
+    let mut fut = pin!(fut);
+    let mut elapsed = pin!(elapsed);
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = fut.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+        elapsed.as_mut().poll(cx).map(|()| None)
+    })
+    .await
+}
@@ -0,0 +1,202 @@
+use alloc::{
+    collections::BTreeMap,
+    sync::{Arc, Weak},
+    vec::Vec,
+};
+use core::fmt::Debug;
+
+use crate::mediator::{
+    correlation::CorrelationId,
+    listener::{ListenerHandle, MaybeDebug},
+    sync::Mutex,
+    synchronous::basic::interface::{
+        RequestHandler, SyncMediatorInternalHandle, SyncMediatorInternalSubscribe,
+    },
+};
+
+/// A saga, or process manager, coordinating a multi-step workflow
+/// by reacting to the published events `Ev`.
+///
+/// Every request sent with `send_traced()` runs its own instance of the saga,
+/// keyed by the [`CorrelationId`] of the request, with its own `State`.
+/// Every event carrying that ID is applied to the state,
+/// and the resulting commands are sent to the mediator.
+/// As the events published while handling the commands carry the ID as well,
+/// the workflow continues until [`Saga::is_complete()`] returns `true`.
+/// Events without a correlation ID are ignored.
+///
+/// Add a saga to a mediator with [`add_saga()`].
+pub trait Saga<State, Ev>: Send + Sync + 'static {
+    /// The requests the saga sends to the mediator.
+    type Command;
+
+    /// Applies the event to the state of a saga instance,
+    /// returning the commands to send next.
+    fn apply(&self, state: &mut State, ev: &Ev) -> Vec<Self::Command>;
+
+    /// Returns `true` once a saga instance is finished, its state is dropped then.
+    ///
+    /// By default, saga instances never finish.
+    fn is_complete(&self, state: &State) -> bool {
+        let _ = state;
+        false
+    }
+}
+
+/// The states of the running instances of a [`Saga`], returned by [`add_saga()`].
+///
+/// It is cheaply cloneable and shares the states with the saga.
+#[derive(Debug)]
+pub struct SagaStates<State> {
+    states: Arc<Mutex<BTreeMap<CorrelationId, State>>>,
+    handle: ListenerHandle,
+}
+
+impl<State> Clone for SagaStates<State> {
+    fn clone(&self) -> Self {
+        Self {
+            states: self.states.clone(),
+            handle: self.handle,
+        }
+    }
+}
+
+impl<State> SagaStates<State> {
+    /// Returns the [`ListenerHandle`] of the saga,
+    /// which removes the saga from the mediator when unsubscribed.
+    pub fn handle(&self) -> ListenerHandle {
+        self.handle
+    }
+
+    /// Returns the state of the saga instance for the request traced by `id`,
+    /// if it is running.
+    pub fn get(&self, id: CorrelationId) -> Option<State>
+    where
+        State: Clone,
+    {
+        self.states.lock().get(&id).cloned()
+    }
+
+    /// Returns the number of running saga instances.
+    pub fn len(&self) -> usize {
+        self.states.lock().len()
+    }
+
+    /// Returns `true` if no saga instance is running.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Subscribes the `saga` to the `mediator`, which handles the commands of the saga.
+///
+/// Commands are sent from within the listener of the saga, so while an event is processed.
+/// The mediator is referenced weakly, the saga doesn't keep it alive.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use mediatrix::saga::{add_saga, Saga};
+/// use std::sync::Arc;
+///
+/// #[derive(Debug)]
+/// enum OrderEvent {
+///     Placed(u32),
+///     Paid(u32),
+///     Shipped(u32)
+/// }
+///
+/// struct PlaceOrder(u32);
+///
+/// enum OrderCommand {
+///     Pay(u32),
+///     Ship(u32)
+/// }
+///
+/// impl RequestHandler<PlaceOrder, OrderEvent> for BasicMediator<OrderEvent> {
+///     fn handle(&self, req: PlaceOrder) {
+///         self.publish(OrderEvent::Placed(req.0));
+///     }
+/// }
+///
+/// impl RequestHandler<OrderCommand, OrderEvent> for BasicMediator<OrderEvent> {
+///     fn handle(&self, cmd: OrderCommand) {
+///         match cmd {
+///             OrderCommand::Pay(order) => self.publish(OrderEvent::Paid(order)),
+///             OrderCommand::Ship(order) => self.publish(OrderEvent::Shipped(order)),
+///         }
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct Fulfillment {
+///     shipped: bool
+/// }
+///
+/// struct FulfillmentSaga;
+///
+/// impl Saga<Fulfillment, OrderEvent> for FulfillmentSaga {
+///     type Command = OrderCommand;
+///
+///     fn apply(&self, state: &mut Fulfillment, ev: &OrderEvent) -> Vec<OrderCommand> {
+///         match ev {
+///             OrderEvent::Placed(order) => vec![OrderCommand::Pay(*order)],
+///             OrderEvent::Paid(order) => vec![OrderCommand::Ship(*order)],
+///             OrderEvent::Shipped(_) => {
+///                 state.shipped = true;
+///                 vec![]
+///             }
+///         }
+///     }
+///
+///     fn is_complete(&self, state: &Fulfillment) -> bool {
+///         state.shipped
+///     }
+/// }
+///
+/// let mediator = Arc::new(BasicMediator::<OrderEvent>::builder().build());
+/// let sagas = add_saga(&mediator, FulfillmentSaga);
+///
+/// mediator.send_traced(PlaceOrder(7));
+///
+/// assert_eq!(mediator.next_all(), 3);
+/// assert!(sagas.is_empty());
+///
+pub fn add_saga<M, S, State, Ev>(mediator: &Arc<M>, saga: S) -> SagaStates<State>
+where
+    M: SyncMediatorInternalHandle<Ev>
+        + SyncMediatorInternalSubscribe<Ev>
+        + RequestHandler<S::Command, Ev>
+        + Send
+        + Sync
+        + 'static,
+    S: Saga<State, Ev>,
+    S::Command: Send + 'static,
+    State: Default + Send + 'static,
+    Ev: MaybeDebug,
+{
+    let states = Arc::new(Mutex::new(BTreeMap::new()));
+    let shared = states.clone();
+    let weak: Weak<M> = Arc::downgrade(mediator);
+    let handle = mediator.subscribe(move |ev: &Ev| {
+        let Some(id) = CorrelationId::current() else {
+            return;
+        };
+        let commands = {
+            let mut states = shared.lock();
+            let state = states.entry(id).or_default();
+            let commands = saga.apply(state, ev);
+            if saga.is_complete(state) {
+                states.remove(&id);
+            }
+            commands
+        };
+        if let Some(mediator) = weak.upgrade() {
+            commands.into_iter().for_each(|cmd| mediator.send(cmd));
+        }
+    });
+    SagaStates { states, handle }
+}
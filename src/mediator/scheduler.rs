@@ -0,0 +1,173 @@
+use event_listener::Event;
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, VecDeque},
+    fmt::Debug,
+    sync::Arc,
+};
+
+use crate::mediator::{
+    listener::MaybeDebug, observer::Observers, runtime, sync::Mutex, time::Instant,
+    topics::Envelope,
+};
+
+/// Events `Ev` scheduled for publishing at a later point in time.
+///
+/// A timer task is spawned on the first scheduled event and lives as long as the [`Scheduler`].
+/// It moves every event whose deadline passed into a queue of due events,
+/// which the mediator moves into its channel when processing events.
+/// This way, the [`OverflowPolicy`](crate::channel::OverflowPolicy) applies as usual.
+#[derive(Debug)]
+pub(crate) struct Scheduler<Ev: MaybeDebug> {
+    shared: Arc<Shared<Ev>>,
+}
+
+#[derive(Debug)]
+struct Shared<Ev: MaybeDebug> {
+    timers: Mutex<Timers<Ev>>,
+    changed: Event,
+    published: Arc<Event>,
+    observer: Observers<Ev>,
+}
+
+#[derive(Debug)]
+struct Timers<Ev> {
+    pending: BinaryHeap<Timer<Ev>>,
+    due: VecDeque<Envelope<Ev>>,
+    seq: u64,
+    running: bool,
+    closed: bool,
+}
+
+/// A scheduled [`Envelope`], ordered such that
+/// the [`BinaryHeap`] yields the earliest deadline first
+/// and events with the same deadline in scheduling order.
+#[derive(Debug)]
+struct Timer<Ev> {
+    deadline: Instant,
+    seq: u64,
+    envelope: Envelope<Ev>,
+}
+
+impl<Ev> PartialEq for Timer<Ev> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<Ev> Eq for Timer<Ev> {}
+
+impl<Ev> PartialOrd for Timer<Ev> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Ev> Ord for Timer<Ev> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.deadline, other.seq).cmp(&(self.deadline, self.seq))
+    }
+}
+
+impl<Ev> Scheduler<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Creates a [`Scheduler`] that wakes everyone waiting on `published`
+    /// and notifies the `observer` once a scheduled event is due.
+    pub(crate) fn new(published: Arc<Event>, observer: Observers<Ev>) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                timers: Mutex::new(Timers {
+                    pending: BinaryHeap::new(),
+                    due: VecDeque::new(),
+                    seq: 0,
+                    running: false,
+                    closed: false,
+                }),
+                changed: Event::new(),
+                published,
+                observer,
+            }),
+        }
+    }
+
+    /// Schedules the [`Envelope`] for the `deadline`,
+    /// spawning the timer task if it isn't running yet.
+    pub(crate) fn schedule(&self, envelope: Envelope<Ev>, deadline: Instant)
+    where
+        Ev: Send + 'static,
+    {
+        let mut timers = self.shared.timers.lock();
+        let seq = timers.seq;
+        timers.seq += 1;
+        timers.pending.push(Timer {
+            deadline,
+            seq,
+            envelope,
+        });
+        if !timers.running {
+            timers.running = true;
+            runtime::spawn(run(self.shared.clone()));
+        }
+        drop(timers);
+        self.shared.changed.notify(usize::MAX);
+    }
+
+    /// Hands all due events, oldest first, to `dispatch`.
+    ///
+    /// If `dispatch` gives an event back, it stays due
+    /// and is handed out again on the next call.
+    pub(crate) fn flush(&self, mut dispatch: impl FnMut(Envelope<Ev>) -> Result<(), Envelope<Ev>>) {
+        let mut timers = self.shared.timers.lock();
+        while let Some(envelope) = timers.due.pop_front() {
+            if let Err(envelope) = dispatch(envelope) {
+                timers.due.push_front(envelope);
+                break;
+            }
+        }
+    }
+}
+
+impl<Ev> Drop for Scheduler<Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn drop(&mut self) {
+        self.shared.timers.lock().closed = true;
+        self.shared.changed.notify(usize::MAX);
+    }
+}
+
+/// The timer task, which sleeps until the earliest deadline
+/// or until the pending timers changed.
+async fn run<Ev: MaybeDebug>(shared: Arc<Shared<Ev>>) {
+    loop {
+        let changed = shared.changed.listen();
+        let next = {
+            let mut timers = shared.timers.lock();
+            if timers.closed {
+                return;
+            }
+            let now = Instant::now();
+            let mut fired = false;
+            while timers.pending.peek().is_some_and(|t| t.deadline <= now) {
+                let Timer { envelope, .. } = timers.pending.pop().unwrap();
+                shared.observer.published(&envelope);
+                timers.due.push_back(envelope);
+                fired = true;
+            }
+            if fired {
+                shared.published.notify(usize::MAX);
+            }
+            timers.pending.peek().map(|t| t.deadline)
+        };
+        match next {
+            Some(deadline) => {
+                let delay = deadline.saturating_duration_since(Instant::now());
+                runtime::timeout(delay, changed).await;
+            }
+            None => changed.await,
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+use serde::Serialize;
+
+use crate::mediator::listener::ListenerHandle;
+
+/// A machine-readable catalog of a mediator, returned by `describe()`:
+/// the JSON Schema of its events `Ev` and the listeners currently subscribed to it.
+///
+/// The catalog is meant for documentation tooling, e.g. to generate
+/// a page listing every event of a service together with its consumers.
+/// It serializes to JSON, see [`MediatorDescription::to_json()`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use schemars::JsonSchema;
+///
+/// #[derive(Debug, JsonSchema)]
+/// enum MyEvent {
+///     Created { id: u32 },
+///     Deleted { id: u32 }
+/// }
+///
+/// fn audit(ev: &MyEvent) {
+///     /* Your listening logic */
+/// }
+///
+/// let mediator = BasicMediator::<MyEvent>::builder()
+///     .add_listener(audit)
+///     .add_listener_for_topic("orders", |ev: &MyEvent| {
+///         /* Your listening logic */
+///     })
+///     .build();
+///
+/// let description = mediator.describe();
+/// assert_eq!(description.event(), "MyEvent");
+/// assert_eq!(description.listeners().len(), 2);
+/// assert!(description.listeners()[0].name().ends_with("audit"));
+/// assert_eq!(description.listeners()[1].topic(), Some("orders"));
+///
+/// println!("{}", description.to_json());
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MediatorDescription {
+    event: String,
+    schema: Schema,
+    listeners: Vec<ListenerDescription>,
+}
+
+impl MediatorDescription {
+    /// Describes the events `Ev` together with the given `listeners`.
+    pub(crate) fn new<Ev: JsonSchema>(listeners: Vec<ListenerDescription>) -> Self {
+        Self {
+            event: Ev::schema_name().into_owned(),
+            schema: SchemaGenerator::default().into_root_schema_for::<Ev>(),
+            listeners,
+        }
+    }
+
+    /// Returns the name of the event type, as given by its [`JsonSchema`] implementation.
+    pub fn event(&self) -> &str {
+        &self.event
+    }
+
+    /// Returns the JSON Schema of the event type,
+    /// with the types it refers to in its definitions.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Returns the [`ListenerDescription`] of every listener currently subscribed to the mediator.
+    pub fn listeners(&self) -> &[ListenerDescription] {
+        &self.listeners
+    }
+
+    /// Serializes the catalog into pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a catalog always serializes")
+    }
+}
+
+/// A single listener, part of the [`MediatorDescription`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ListenerDescription {
+    handle: ListenerHandle,
+    name: &'static str,
+    topic: Option<String>,
+    priority: i32,
+    asynchronous: bool,
+}
+
+impl ListenerDescription {
+    pub(crate) fn new(
+        handle: ListenerHandle,
+        name: &'static str,
+        topic: Option<String>,
+        priority: i32,
+        asynchronous: bool,
+    ) -> Self {
+        Self {
+            handle,
+            name,
+            topic,
+            priority,
+            asynchronous,
+        }
+    }
+
+    /// Returns the [`ListenerHandle`] of the listener.
+    pub fn handle(&self) -> ListenerHandle {
+        self.handle
+    }
+
+    /// Returns the name of the listener.
+    ///
+    /// This is the type name of the listener as it was added,
+    /// i.e. the path of a function, or of the function a closure was defined in,
+    /// suffixed with `{{closure}}`.
+    /// Notification handlers are listed with their
+    /// [`NotificationHandler::name()`](crate::listener::NotificationHandler::name()).
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the topic pattern the listener is restricted to, if any.
+    pub fn topic(&self) -> Option<&str> {
+        self.topic.as_deref()
+    }
+
+    /// Returns the priority of the listener, `0` unless it was added with a priority.
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Returns whether the listener is an async listener.
+    pub fn is_async(&self) -> bool {
+        self.asynchronous
+    }
+}
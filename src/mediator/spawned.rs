@@ -0,0 +1,77 @@
+use async_std::channel::{self, Receiver, Sender};
+use async_trait::async_trait;
+use std::{
+    future::{poll_fn, Future},
+    panic::{self, AssertUnwindSafe},
+    pin::pin,
+    sync::Mutex,
+    task::Poll,
+};
+
+use crate::mediator::{
+    correlation::{self, Trace},
+    listener::{AsyncListener, MaybeDebug},
+    runtime,
+};
+
+/// An event queued for a [`Spawned`] listener, with the trace it was published with.
+type Queued<Ev> = (Ev, Trace);
+
+/// An [`AsyncListener`] running on its own spawned task, added with `add_spawned_listener()`.
+///
+/// Events are cloned into a dedicated unbounded channel, so delivering them never waits
+/// for the listener. The task is spawned with the first event and delivers the events one at a time
+/// in publishing order, together with the [`CorrelationId`](crate::correlation::CorrelationId)
+/// they were published with. A panic of the listener skips the event.
+/// Once the mediator is dropped, the task delivers the remaining events and ends.
+pub(crate) struct Spawned<Ev, F> {
+    queue: Sender<Queued<Ev>>,
+    pending: Mutex<Option<(Receiver<Queued<Ev>>, F)>>,
+}
+
+impl<Ev, F> Spawned<Ev, F> {
+    pub(crate) fn new(listener: F) -> Self {
+        let (queue, events) = channel::unbounded();
+        Self {
+            queue,
+            pending: Mutex::new(Some((events, listener))),
+        }
+    }
+}
+
+#[async_trait]
+impl<Ev, F> AsyncListener<Ev> for Spawned<Ev, F>
+where
+    Ev: MaybeDebug + Clone + Send + Sync + 'static,
+    F: AsyncListener<Ev>,
+{
+    async fn on_event(&self, ev: &Ev) {
+        let pending = self
+            .pending
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .take();
+        if let Some((events, listener)) = pending {
+            drop(runtime::spawn(deliver(events, listener)));
+        }
+        self.queue.try_send((ev.clone(), Trace::current())).ok();
+    }
+}
+
+/// The delivering task, ends once the [`Spawned`] listener is dropped and all events are delivered.
+async fn deliver<Ev, F>(events: Receiver<Queued<Ev>>, listener: F)
+where
+    Ev: MaybeDebug + Send + Sync,
+    F: AsyncListener<Ev>,
+{
+    while let Ok((ev, trace)) = events.recv().await {
+        let mut invocation = pin!(correlation::scope_async(trace, listener.on_event(&ev)));
+        poll_fn(|cx| {
+            match panic::catch_unwind(AssertUnwindSafe(|| invocation.as_mut().poll(cx))) {
+                Ok(poll) => poll,
+                Err(_) => Poll::Ready(()),
+            }
+        })
+        .await;
+    }
+}
@@ -0,0 +1,215 @@
+use alloc::vec::Vec;
+use core::{
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use crate::mediator::listener::ListenerHandle;
+
+/// A snapshot of the counters of a mediator, returned by `stats()`.
+///
+/// The counters are kept by every mediator, so no observer is needed to read them.
+/// Without the `std` feature, durations can't be measured and stay zero.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     One
+/// }
+///
+/// let mediator = BasicMediator::<MyEvent>::builder()
+///     .add_listener(|ev: &MyEvent| {
+///         /* Your listening logic */
+///     })
+///     .build();
+///
+/// mediator.publish(MyEvent::One);
+/// mediator.publish(MyEvent::One);
+/// mediator.next().ok();
+///
+/// let stats = mediator.stats();
+/// assert_eq!(stats.published(), 2);
+/// assert_eq!(stats.processed(), 1);
+/// assert_eq!(stats.queued(), 1);
+/// assert_eq!(stats.listener_count(), 1);
+/// assert_eq!(stats.listeners()[0].invocations(), 1);
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediatorStats {
+    queued: usize,
+    published: u64,
+    processed: u64,
+    listeners: Vec<ListenerStats>,
+}
+
+impl MediatorStats {
+    /// Returns the number of events waiting in the channel to be processed.
+    pub fn queued(&self) -> usize {
+        self.queued
+    }
+
+    /// Returns the number of events published since the mediator was built.
+    ///
+    /// Scheduled events are only counted once they are due.
+    pub fn published(&self) -> u64 {
+        self.published
+    }
+
+    /// Returns the number of events processed since the mediator was built,
+    /// including events whose listeners panicked.
+    pub fn processed(&self) -> u64 {
+        self.processed
+    }
+
+    /// Returns the number of listeners currently subscribed to the mediator.
+    pub fn listener_count(&self) -> usize {
+        self.listeners.len()
+    }
+
+    /// Returns the [`ListenerStats`] of every listener currently subscribed to the mediator.
+    pub fn listeners(&self) -> &[ListenerStats] {
+        &self.listeners
+    }
+
+    /// Returns the [`ListenerStats`] of the listener with the given [`ListenerHandle`], if subscribed.
+    pub fn listener(&self, handle: ListenerHandle) -> Option<&ListenerStats> {
+        self.listeners.iter().find(|stats| stats.handle == handle)
+    }
+}
+
+/// The counters of a single listener, part of the [`MediatorStats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListenerStats {
+    handle: ListenerHandle,
+    name: &'static str,
+    invocations: u64,
+    duration: Duration,
+}
+
+impl ListenerStats {
+    /// Returns the [`ListenerHandle`] of the listener.
+    pub fn handle(&self) -> ListenerHandle {
+        self.handle
+    }
+
+    /// Returns the name of the listener.
+    ///
+    /// This is the name given with `add_listener_named()`,
+    /// or else the type name of the listener.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns how often the listener was invoked.
+    pub fn invocations(&self) -> u64 {
+        self.invocations
+    }
+
+    /// Returns the time spent in the listener across all invocations.
+    pub fn total_duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// The counters of a single listener, kept next to it in its registry.
+#[derive(Debug, Default)]
+pub(crate) struct Invocations {
+    count: AtomicU64,
+    nanos: AtomicU64,
+}
+
+impl Invocations {
+    pub(crate) fn record(&self, elapsed: Duration) {
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.nanos.fetch_add(nanos, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self, handle: ListenerHandle, name: &'static str) -> ListenerStats {
+        ListenerStats {
+            handle,
+            name,
+            invocations: self.count.load(Ordering::Relaxed),
+            duration: Duration::from_nanos(self.nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// The counters of a mediator, shared by all clones of its observers.
+#[derive(Debug, Default)]
+pub(crate) struct Counters {
+    published: AtomicU64,
+    processed: AtomicU64,
+}
+
+impl Counters {
+    pub(crate) fn published(&self) {
+        self.published.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn processed(&self) {
+        self.processed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Takes a snapshot of the counters together with the `queued` events
+    /// and the stats of all `listeners`.
+    pub(crate) fn snapshot(&self, queued: usize, listeners: Vec<ListenerStats>) -> MediatorStats {
+        MediatorStats {
+            queued,
+            published: self.published.load(Ordering::Relaxed),
+            processed: self.processed.load(Ordering::Relaxed),
+            listeners,
+        }
+    }
+}
+
+/// Counts the events in an [`EventChannel`](crate::mediator::channel::EventChannel),
+/// since not every backend can tell its length.
+#[derive(Debug, Default)]
+pub(crate) struct Queued(AtomicUsize);
+
+impl Queued {
+    pub(crate) fn add(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn remove(&self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn get(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Counts an event about to be sent, which is uncounted again
+    /// unless the returned [`Reserved`] is kept, e.g. if sending was cancelled.
+    pub(crate) fn reserve(&self) -> Reserved<'_> {
+        self.add();
+        Reserved(Some(self))
+    }
+}
+
+/// An event counted by [`Queued::reserve()`] before it was sent.
+#[derive(Debug)]
+pub(crate) struct Reserved<'a>(Option<&'a Queued>);
+
+impl Reserved<'_> {
+    /// Keeps the event counted, once it was sent.
+    pub(crate) fn keep(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for Reserved<'_> {
+    fn drop(&mut self) {
+        if let Some(queued) = self.0 {
+            queued.remove();
+        }
+    }
+}
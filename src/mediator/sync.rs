@@ -0,0 +1,121 @@
+//! Locks of the synchronous core, backed by `std`
+//! or, without the `std` feature, by spin locks.
+
+#[cfg(feature = "std")]
+use std::sync::PoisonError;
+
+/// A mutual exclusion lock.
+///
+/// Unlike the lock of `std`, [`Mutex::lock()`] recovers a poisoned lock,
+/// instead of returning an error. The poisoning is kept until
+/// [`Mutex::clear_poison()`] is called. Spin locks are never poisoned.
+#[derive(Debug, Default)]
+pub(crate) struct Mutex<T>(
+    #[cfg(feature = "std")] std::sync::Mutex<T>,
+    #[cfg(not(feature = "std"))] spin::Mutex<T>,
+);
+
+/// The guard returned by [`Mutex::lock()`].
+#[cfg(feature = "std")]
+pub(crate) type MutexGuard<'a, T> = std::sync::MutexGuard<'a, T>;
+/// The guard returned by [`Mutex::lock()`].
+#[cfg(not(feature = "std"))]
+pub(crate) type MutexGuard<'a, T> = spin::MutexGuard<'a, T>;
+
+impl<T> Mutex<T> {
+    pub(crate) const fn new(value: T) -> Self {
+        #[cfg(feature = "std")]
+        return Self(std::sync::Mutex::new(value));
+        #[cfg(not(feature = "std"))]
+        return Self(spin::Mutex::new(value));
+    }
+
+    /// Acquires the lock, blocking (or spinning) until it is available.
+    pub(crate) fn lock(&self) -> MutexGuard<'_, T> {
+        #[cfg(feature = "std")]
+        return self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        #[cfg(not(feature = "std"))]
+        return self.0.lock();
+    }
+
+    /// Acquires the lock if it is available right away.
+    #[cfg(feature = "std")]
+    pub(crate) fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        match self.0.try_lock() {
+            Ok(guard) => Some(guard),
+            Err(std::sync::TryLockError::WouldBlock) => None,
+            Err(std::sync::TryLockError::Poisoned(err)) => Some(err.into_inner()),
+        }
+    }
+
+    /// Returns whether a thread panicked while holding the lock.
+    pub(crate) fn is_poisoned(&self) -> bool {
+        #[cfg(feature = "std")]
+        return self.0.is_poisoned();
+        #[cfg(not(feature = "std"))]
+        return false;
+    }
+
+    /// Clears the poisoning of the lock.
+    pub(crate) fn clear_poison(&self) {
+        #[cfg(feature = "std")]
+        self.0.clear_poison();
+    }
+}
+
+/// A reader-writer lock, allowing any number of readers at a time.
+///
+/// Like [`Mutex`], it recovers a poisoned lock.
+#[derive(Debug, Default)]
+pub(crate) struct RwLock<T>(
+    #[cfg(feature = "std")] std::sync::RwLock<T>,
+    #[cfg(not(feature = "std"))] spin::RwLock<T>,
+);
+
+#[cfg(feature = "std")]
+type ReadGuard<'a, T> = std::sync::RwLockReadGuard<'a, T>;
+#[cfg(not(feature = "std"))]
+type ReadGuard<'a, T> = spin::RwLockReadGuard<'a, T>;
+#[cfg(feature = "std")]
+type WriteGuard<'a, T> = std::sync::RwLockWriteGuard<'a, T>;
+#[cfg(not(feature = "std"))]
+type WriteGuard<'a, T> = spin::RwLockWriteGuard<'a, T>;
+
+impl<T> RwLock<T> {
+    pub(crate) const fn new(value: T) -> Self {
+        #[cfg(feature = "std")]
+        return Self(std::sync::RwLock::new(value));
+        #[cfg(not(feature = "std"))]
+        return Self(spin::RwLock::new(value));
+    }
+
+    /// Acquires shared read access.
+    pub(crate) fn read(&self) -> ReadGuard<'_, T> {
+        #[cfg(feature = "std")]
+        return self.0.read().unwrap_or_else(PoisonError::into_inner);
+        #[cfg(not(feature = "std"))]
+        return self.0.read();
+    }
+
+    /// Acquires exclusive write access.
+    pub(crate) fn write(&self) -> WriteGuard<'_, T> {
+        #[cfg(feature = "std")]
+        return self.0.write().unwrap_or_else(PoisonError::into_inner);
+        #[cfg(not(feature = "std"))]
+        return self.0.write();
+    }
+
+    /// Returns whether a thread panicked while holding write access.
+    pub(crate) fn is_poisoned(&self) -> bool {
+        #[cfg(feature = "std")]
+        return self.0.is_poisoned();
+        #[cfg(not(feature = "std"))]
+        return false;
+    }
+
+    /// Clears the poisoning of the lock.
+    pub(crate) fn clear_poison(&self) {
+        #[cfg(feature = "std")]
+        self.0.clear_poison();
+    }
+}
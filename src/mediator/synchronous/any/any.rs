@@ -0,0 +1,338 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{any::Any, fmt::Debug};
+
+use super::*;
+use crate::mediator::{
+    behavior::{run_pipeline, AnyRequest},
+    correlation::{self, CorrelationId, Trace},
+    listener::{Listener, ListenerHandle, NextError},
+    synchronous::basic::{
+        basic::BasicMediator,
+        interface::{SyncMediatorInternal, SyncMediatorInternalSubscribe},
+    },
+    system,
+};
+
+/// An event of any type, as published to an [`AnyMediator`].
+trait DynEvent: Any + Debug + Send {
+    fn as_any(&self) -> &dyn Any;
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send>;
+}
+
+impl<Ev> DynEvent for Ev
+where
+    Ev: Any + Debug + Send,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send> {
+        self
+    }
+}
+
+/// A type-erased event, the event type of the [`BasicMediator`] underlying an [`AnyMediator`].
+///
+/// Its [`Debug`] output is the one of the wrapped event.
+pub struct AnyEvent(Box<dyn DynEvent>);
+
+impl Debug for AnyEvent {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl AnyEvent {
+    /// Wraps the event `ev`.
+    pub fn new<Ev>(ev: Ev) -> Self
+    where
+        Ev: Any + Debug + Send,
+    {
+        Self(Box::new(ev))
+    }
+
+    /// Returns `true` if the wrapped event is of type `Ev`.
+    pub fn is<Ev: Any>(&self) -> bool {
+        (*self.0).as_any().is::<Ev>()
+    }
+
+    /// Returns the wrapped event if it is of type `Ev`.
+    pub fn downcast_ref<Ev: Any>(&self) -> Option<&Ev> {
+        (*self.0).as_any().downcast_ref()
+    }
+
+    /// Unwraps the event if it is of type `Ev`, otherwise returns it unchanged.
+    pub fn downcast<Ev: Any>(self) -> Result<Ev, Self> {
+        if !self.is::<Ev>() {
+            return Err(self);
+        }
+        match self.0.into_any().downcast() {
+            Ok(ev) => Ok(*ev),
+            Err(_) => unreachable!("type checked above"),
+        }
+    }
+}
+
+/// Mediator for synchronous environments with events of any type.
+///
+/// Unlike a [`BasicMediator`], which handles exactly one event type,
+/// an [`AnyMediator`] accepts every event that is [`Any`] + [`Debug`] + [`Send`].
+/// Listeners are added for a concrete event type
+/// and only invoked for events of that type,
+/// so events don't have to be wrapped in a single enum.
+///
+/// Uses an underlying [`BasicMediator`] of [`AnyEvent`]s for base functionality.
+/// Requests are handled by implementing [`RequestHandler`] with the event type [`AnyEvent`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::any::*;
+///
+/// #[derive(Debug)]
+/// struct OrderCreated(u32);
+///
+/// #[derive(Debug)]
+/// struct UserRegistered(String);
+///
+/// struct CreateOrder(u32);
+///
+/// impl RequestHandler<CreateOrder, AnyEvent> for AnyMediator {
+///     fn handle(&self, req: CreateOrder) {
+///         self.publish(OrderCreated(req.0));
+///     }
+/// }
+///
+/// let mediator = AnyMediator::builder()
+///     .add_listener(|ev: &OrderCreated| {
+///         /* Your listening logic */
+///     })
+///     .add_listener(|ev: &UserRegistered| {
+///         /* Your listening logic */
+///     })
+///     .build();
+///
+/// mediator.send(CreateOrder(1));
+/// mediator.publish(UserRegistered("Anna".into()));
+///
+/// assert_eq!(mediator.next_all(), 2);
+///
+#[derive(Debug)]
+pub struct AnyMediator {
+    pub(crate) basic: BasicMediator<AnyEvent>,
+}
+
+/// Wraps a listener for events `Ev` into one for [`AnyEvent`]s,
+/// which ignores events of other types.
+pub(crate) fn typed<Ev>(f: impl Listener<Ev>) -> impl Listener<AnyEvent>
+where
+    Ev: Any + Debug,
+{
+    move |ev: &AnyEvent| {
+        if let Some(ev) = ev.downcast_ref::<Ev>() {
+            f(ev)
+        }
+    }
+}
+
+impl AnyMediatorInternal for AnyMediator {
+    /// Publishes an event of any type `Ev`.
+    ///
+    /// Only the listeners added for `Ev` are invoked with it.
+    ///
+    /// See [`BasicMediator::publish()`] for more info.
+    ///
+    fn publish<Ev>(&self, event: Ev)
+    where
+        Ev: Any + Debug + Send,
+    {
+        self.basic.publish(AnyEvent::new(event))
+    }
+
+    /// Publishes an event of any type `Ev` to the given `topic`.
+    ///
+    /// See [`BasicMediator::publish_to()`] for more info.
+    ///
+    fn publish_to<Ev>(&self, topic: &str, event: Ev)
+    where
+        Ev: Any + Debug + Send,
+    {
+        self.basic.publish_to(topic, AnyEvent::new(event))
+    }
+}
+
+impl SyncMediatorInternalHandle<AnyEvent> for AnyMediator {
+    /// Send a request of type `Req` to the mediator.
+    ///
+    /// The request will be processed internally by [`RequestHandler::handle()`].
+    /// This is why it is required to implement [`RequestHandler`] for [`AnyMediator`].
+    ///
+    /// See [`BasicMediator::send()`] for more info.
+    ///
+    fn send<Req>(&self, req: Req)
+    where
+        Self: RequestHandler<Req, AnyEvent>,
+        Req: Send + 'static,
+    {
+        self.basic.run_request("send", req, |req| {
+            <Self as RequestHandler<Req, AnyEvent>>::handle(self, req)
+        })
+    }
+
+    /// Send a request of type `Req` to the mediator and correlate it with the resulting events.
+    ///
+    /// See [`BasicMediator::send_traced()`] for more info.
+    ///
+    fn send_traced<Req>(&self, req: Req) -> CorrelationId
+    where
+        Self: RequestHandler<Req, AnyEvent>,
+        Req: Send + 'static,
+    {
+        let id = CorrelationId::next();
+        correlation::scope(Trace::current().traced(id), || self.send(req));
+        id
+    }
+
+    /// Send a request of type `Req` to the mediator and receive a response of type `Resp`.
+    ///
+    /// See [`BasicMediator::send_and_receive()`] for more info.
+    ///
+    fn send_and_receive<Req, Resp>(&self, req: Req) -> Resp
+    where
+        Self: RequestHandler<Req, AnyEvent, Resp>,
+    {
+        self.basic.report::<Req, _>(system::completed, || {
+            self.basic
+                .observer
+                .request::<Req, _>("send_and_receive", || {
+                    <Self as RequestHandler<Req, AnyEvent, Resp>>::handle(self, req)
+                })
+        })
+    }
+
+    /// Send a batch of requests of type `Req` to the mediator.
+    ///
+    /// See [`BasicMediator::send_batch()`] for more info.
+    ///
+    fn send_batch<Req>(&self, reqs: Vec<Req>)
+    where
+        Self: BatchRequestHandler<Req, AnyEvent>,
+        Req: Send + 'static,
+    {
+        self.basic.run_request("send_batch", reqs, |reqs| {
+            <Self as BatchRequestHandler<Req, AnyEvent>>::handle_batch(self, reqs)
+        })
+    }
+
+    /// Send a request of unknown type to the mediator.
+    ///
+    /// The request is processed by the handler registered for its type
+    /// via [`AnyBuilder::register_handler()`](super::AnyBuilder::register_handler()).
+    ///
+    /// See [`BasicMediator::send_dyn()`] for more info.
+    ///
+    fn send_dyn(&self, req: Box<dyn Any + Send>) -> Result<(), Box<dyn Any + Send>>
+    where
+        Self: 'static,
+    {
+        match self.basic.handlers.get(&*req) {
+            Some(handler) => {
+                handler(self, req);
+                Ok(())
+            }
+            None => Err(req),
+        }
+    }
+
+    /// Send a request of type `Req` to the mediator, which may fail with an error `Err`.
+    ///
+    /// See [`BasicMediator::try_send()`] for more info.
+    ///
+    fn try_send<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Self: TryRequestHandler<Req, AnyEvent, Err>,
+        Req: Send + 'static,
+    {
+        self.basic.report::<Req, _>(system::failed, || {
+            self.basic.observer.request::<Req, _>("try_send", || {
+                if self.basic.behavior.is_empty() {
+                    return <Self as TryRequestHandler<Req, AnyEvent, Err>>::handle(self, req);
+                }
+                let mut res = Ok(());
+                run_pipeline(&self.basic.behavior, AnyRequest::new(req), |req| {
+                    res = <Self as TryRequestHandler<Req, AnyEvent, Err>>::handle(
+                        self,
+                        req.into_inner(),
+                    )
+                });
+                res
+            })
+        })
+    }
+}
+
+impl SyncMediatorInternalNext for AnyMediator {
+    /// Process the next published event.
+    ///
+    /// Invokes the listeners added for the type of the event.
+    ///
+    /// See [`BasicMediator::next()`] for more info.
+    ///
+    fn next(&self) -> Result<(), NextError> {
+        self.basic.next()
+    }
+
+    /// Process all pending published events.
+    ///
+    /// See [`BasicMediator::next_all()`] for more info.
+    ///
+    fn next_all(&self) -> usize {
+        self.basic.next_all()
+    }
+
+    #[cfg(feature = "std")]
+    /// Process the next published event, blocking until one is available.
+    ///
+    /// See [`BasicMediator::next_blocking()`] for more info.
+    ///
+    fn next_blocking(&self) -> Result<(), NextError> {
+        self.basic.next_blocking()
+    }
+
+    /// Returns an [`EventWorker`] to process events from another thread.
+    ///
+    /// See [`BasicMediator::worker()`] for more info.
+    ///
+    fn worker(&self) -> EventWorker<'_, Self>
+    where
+        Self: Sync,
+    {
+        EventWorker::new(self)
+    }
+}
+
+impl AnyMediatorInternalSubscribe for AnyMediator {
+    /// Subscribes a user-defined listener for events of type `Ev`
+    /// to the already built [`AnyMediator`].
+    ///
+    /// See [`BasicMediator::subscribe()`] for more info.
+    ///
+    fn subscribe<Ev>(&self, f: impl Listener<Ev>) -> ListenerHandle
+    where
+        Ev: Any + Debug,
+    {
+        self.basic.subscribe(typed(f))
+    }
+
+    /// Unsubscribes the listener identified by `handle`.
+    ///
+    /// See [`BasicMediator::unsubscribe()`] for more info.
+    ///
+    fn unsubscribe(&self, handle: ListenerHandle) -> bool {
+        self.basic.unsubscribe(handle)
+    }
+}
@@ -0,0 +1,198 @@
+use super::{
+    any::{typed, AnyEvent, AnyMediator},
+    interface::AnyMediatorBuilderInterface,
+};
+use crate::mediator::{
+    behavior::Behavior,
+    builder::{BuilderFlow, BuilderInternal},
+    channel::OverflowPolicy,
+    listener::{ExclusiveListener, Listener},
+    registry::erase,
+    synchronous::basic::{
+        basic::BasicMediator,
+        interface::{
+            BehaviorMediatorBuilderInterface, ChannelMediatorBuilderInterface,
+            DynamicHandlerBuilderInterface,
+        },
+    },
+};
+use alloc::boxed::Box;
+use core::{
+    any::{type_name_of_val, Any},
+    fmt::Debug,
+};
+
+/// The [`AnyBuilder`] helps you to create an [`AnyMediator`].
+///
+/// The [`AnyBuilder`] is part of the builder pattern.
+/// Listeners are added for a concrete event type via [`AnyBuilder::add_listener()`].
+/// The mandatory [`BuilderFlow::build()`] returns an [`AnyMediator`].
+///
+pub struct AnyBuilder {
+    mediator: BasicMediator<AnyEvent>,
+}
+
+impl BuilderInternal<AnyMediator, AnyBuilder> for AnyMediator {
+    /// Creates an [`AnyBuilder`] with the goal of producing an [`AnyMediator`].
+    ///
+    fn builder() -> AnyBuilder {
+        AnyBuilder {
+            mediator: BasicMediator::new(),
+        }
+    }
+}
+
+impl<M> AnyMediatorBuilderInterface<M> for AnyBuilder {
+    /// Adds a user-defined listener for events of type `Ev` to the [`AnyBuilder`].
+    ///
+    /// The listener is only invoked for events of type `Ev`.
+    ///
+    fn add_listener<Ev>(self, f: impl Listener<Ev>) -> Self
+    where
+        Ev: Any + Debug,
+    {
+        self.mediator
+            .listener
+            .add(type_name_of_val(&f), ExclusiveListener::new(typed(f)));
+        self
+    }
+}
+
+impl<M> BehaviorMediatorBuilderInterface<M> for AnyBuilder {
+    /// Adds a user-defined [`Behavior`] to the [`AnyBuilder`].
+    ///
+    /// Behaviors wrap every request sent to the [`AnyMediator`]
+    /// in the order they were added.
+    ///
+    fn add_behavior(mut self, b: impl Behavior) -> Self {
+        self.mediator.behavior.push(Box::new(b));
+        self
+    }
+}
+
+impl<M> ChannelMediatorBuilderInterface<M> for AnyBuilder {
+    /// Bounds the channel of the [`AnyBuilder`] to `capacity` events.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`.
+    ///
+    fn with_capacity(mut self, capacity: usize) -> Self {
+        assert!(capacity > 0, "channel capacity must be greater than 0");
+        self.mediator.channel.set_capacity(Some(capacity));
+        self
+    }
+
+    /// Sets the [`OverflowPolicy`] of the [`AnyBuilder`].
+    ///
+    fn with_overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.mediator.channel.policy = policy;
+        self
+    }
+}
+
+impl DynamicHandlerBuilderInterface<AnyMediator, AnyEvent> for AnyBuilder {
+    /// Registers a handler for requests of type `Req` with the [`AnyBuilder`].
+    ///
+    fn register_handler<Req>(
+        mut self,
+        handler: impl Fn(&AnyMediator, Req) + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+    {
+        self.mediator
+            .handlers
+            .insert::<Req>(erase(move |m: &AnyMediator, req: Req| {
+                m.basic.run_request("send_dyn", req, |req| handler(m, req))
+            }));
+        self
+    }
+}
+
+impl AnyBuilder {
+    /// Adds a user-defined listener for events of type `Ev` to the [`AnyBuilder`].
+    ///
+    /// The listener is only invoked for events of type `Ev`,
+    /// which is usually inferred from the argument of the closure.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::any::*;
+    ///
+    /// #[derive(Debug)]
+    /// struct OrderCreated(u32);
+    ///
+    /// let mediator = AnyMediator::builder()
+    ///     .add_listener(|_: &OrderCreated| {
+    ///         /* Your listening logic */
+    ///     })
+    ///     .add_listener::<u32>(|_| {
+    ///         /* Your listening logic */
+    ///     })
+    ///     .build();
+    ///
+    pub fn add_listener<Ev>(self, f: impl Listener<Ev>) -> Self
+    where
+        Ev: Any + Debug,
+    {
+        <Self as AnyMediatorBuilderInterface<AnyMediator>>::add_listener(self, f)
+    }
+
+    /// Adds a user-defined [`Behavior`] to the [`AnyBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_behavior()`] for more info.
+    ///
+    pub fn add_behavior(self, b: impl Behavior) -> Self {
+        <Self as BehaviorMediatorBuilderInterface<AnyMediator>>::add_behavior(self, b)
+    }
+
+    /// Bounds the channel of the [`AnyBuilder`] to `capacity` events.
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_capacity()`] for more info.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`.
+    ///
+    pub fn with_capacity(self, capacity: usize) -> Self {
+        <Self as ChannelMediatorBuilderInterface<AnyMediator>>::with_capacity(self, capacity)
+    }
+
+    /// Sets the [`OverflowPolicy`] of the [`AnyBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_overflow_policy()`] for more info.
+    ///
+    pub fn with_overflow_policy(self, policy: OverflowPolicy) -> Self {
+        <Self as ChannelMediatorBuilderInterface<AnyMediator>>::with_overflow_policy(self, policy)
+    }
+
+    /// Registers a handler for requests of type `Req` with the [`AnyBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::register_handler()`] for more info.
+    ///
+    pub fn register_handler<Req>(
+        self,
+        handler: impl Fn(&AnyMediator, Req) + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+    {
+        <Self as DynamicHandlerBuilderInterface<AnyMediator, AnyEvent>>::register_handler(
+            self, handler,
+        )
+    }
+}
+
+impl BuilderFlow<AnyMediator> for AnyBuilder {
+    /// Builds the [`AnyMediator`] and returns it.
+    ///
+    fn build(self) -> AnyMediator {
+        AnyMediator {
+            basic: self.mediator,
+        }
+    }
+}
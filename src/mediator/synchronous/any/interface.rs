@@ -0,0 +1,38 @@
+use core::{any::Any, fmt::Debug};
+
+use crate::mediator::listener::{Listener, ListenerHandle};
+
+/// Publish an event of any type `Ev` from within a handler,
+/// or publish it to a topic.
+pub trait AnyMediatorInternal {
+    #[allow(missing_docs)]
+    fn publish<Ev>(&self, event: Ev)
+    where
+        Ev: Any + Debug + Send;
+
+    #[allow(missing_docs)]
+    fn publish_to<Ev>(&self, topic: &str, event: Ev)
+    where
+        Ev: Any + Debug + Send;
+}
+
+/// Subscribe and unsubscribe listeners for events of a concrete type `Ev`
+/// on an already built mediator.
+pub trait AnyMediatorInternalSubscribe {
+    #[allow(missing_docs)]
+    fn subscribe<Ev>(&self, f: impl Listener<Ev>) -> ListenerHandle
+    where
+        Ev: Any + Debug;
+
+    #[allow(missing_docs)]
+    fn unsubscribe(&self, handle: ListenerHandle) -> bool;
+}
+
+/// Basic builder fuctionality:
+/// Adding a [`Listener`] for events of a concrete type `Ev`.
+pub trait AnyMediatorBuilderInterface<M> {
+    #[allow(missing_docs)]
+    fn add_listener<Ev>(self, f: impl Listener<Ev>) -> Self
+    where
+        Ev: Any + Debug;
+}
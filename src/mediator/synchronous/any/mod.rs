@@ -0,0 +1,21 @@
+#[allow(clippy::module_inception)]
+pub(crate) mod any;
+pub(crate) mod builder;
+pub(crate) mod interface;
+
+pub use any::*;
+pub use builder::*;
+pub use interface::*;
+
+pub use crate::behavior::*;
+pub use crate::builder::{BuilderFlow, BuilderInternal};
+pub use crate::channel::OverflowPolicy;
+pub use crate::correlation::CorrelationId;
+pub use crate::listener::*;
+pub use crate::mediator::synchronous::basic::interface::{
+    BatchRequestHandler, BehaviorMediatorBuilderInterface, ChannelMediatorBuilderInterface,
+    DynamicHandlerBuilderInterface, RequestHandler, SyncMediatorInternalHandle,
+    SyncMediatorInternalNext, TryRequestHandler,
+};
+pub use crate::mediator::synchronous::basic::worker::EventWorker;
+pub use crate::observer::MediatorObserver;
@@ -1,8 +1,47 @@
-use std::sync::mpsc::{Receiver, Sender, TryRecvError};
+use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
+use core::any::{type_name, type_name_of_val, Any};
+use core::time::Duration;
 
 use core::fmt::Debug;
 
+#[cfg(feature = "persistence")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "persistence")]
+use std::{io, path::Path};
+
 use super::*;
+use crate::mediator::{
+    behavior::{run_pipeline, AnyRequest},
+    channel::{EventChannel, TryRecvError, TrySendError},
+    correlation::{self, CorrelationId, Trace},
+    deadletter::{DeadLetter, DeadLetterReason, RedeliveryPolicy},
+    instrument,
+    listener::{ExclusiveListener, ListenerPanic, ListenerRegistry, PublishInterceptor},
+    observer::Observers,
+    pause::Pause,
+    registry::{AnyHandler, DynHandler, FanOutHandler, HandlerRegistry},
+    replay::ReplayBuffer,
+    stats::MediatorStats,
+    system::{self, RequestEvent},
+    time::Stopwatch,
+    topics::Envelope,
+};
+
+#[cfg(feature = "std")]
+use crate::mediator::ratelimit::{RateLimiter, ThrottlePolicy, Throttled};
+#[cfg(feature = "schema")]
+use crate::mediator::schema::MediatorDescription;
+#[cfg(feature = "causality")]
+use crate::mediator::{causality::CausalityGraph, listener::ListenerHandle};
+#[cfg(feature = "persistence")]
+use crate::mediator::{
+    codec::{Codec, JsonCodec},
+    persistence::{read_journal_with, Upcasters},
+};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+#[cfg(feature = "causality")]
+use std::collections::BTreeMap;
 
 /// Basic mediator for synchronous environments with events of type `Ev`.
 ///
@@ -54,15 +93,352 @@ use super::*;
 #[derive(Debug)]
 pub struct BasicMediator<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
+{
+    pub(crate) channel: EventChannel<Envelope<Ev>>,
+    pub(crate) listener: ListenerRegistry<ExclusiveListener<Ev>>,
+    pub(crate) catch_all: bool,
+    pub(crate) interceptors: Vec<Box<dyn PublishInterceptor<Ev>>>,
+    pub(crate) request_events: Option<RequestEvent<Ev>>,
+    pub(crate) shared_listener: ListenerRegistry<ExclusiveListener<Arc<Ev>>>,
+    pub(crate) recover: Option<fn(Arc<Ev>) -> Ev>,
+    pub(crate) behavior: Vec<Box<dyn Behavior>>,
+    #[cfg(feature = "std")]
+    pub(crate) isolate_listeners: bool,
+    #[cfg(feature = "std")]
+    pub(crate) rate_limit: Option<RateLimiter>,
+    pub(crate) observer: Observers<Ev>,
+    pub(crate) replay: Option<ReplayBuffer<Ev>>,
+    pub(crate) dead_letters: Option<EventChannel<DeadLetter<Ev>>>,
+    pub(crate) handlers: HandlerRegistry<DynHandler>,
+    pub(crate) closures: HandlerRegistry<AnyHandler>,
+    pub(crate) fan_out: HandlerRegistry<AnyHandler>,
+    #[cfg(feature = "persistence")]
+    pub(crate) upcasters: Upcasters<Ev>,
+    pub(crate) paused: Pause,
+}
+
+impl<Ev> BasicMediator<Ev>
+where
+    Ev: MaybeDebug,
 {
-    pub(crate) channel: (Sender<Ev>, Receiver<Ev>),
-    pub(crate) listener: Vec<Box<dyn Listener<Ev>>>,
+    /// Creates an empty mediator with an unbounded channel.
+    pub(crate) fn new() -> Self {
+        Self {
+            channel: EventChannel::default(),
+            listener: ListenerRegistry::default(),
+            catch_all: false,
+            interceptors: vec![],
+            request_events: None,
+            shared_listener: ListenerRegistry::default(),
+            recover: None,
+            behavior: vec![],
+            #[cfg(feature = "std")]
+            isolate_listeners: false,
+            #[cfg(feature = "std")]
+            rate_limit: None,
+            observer: Observers::default(),
+            replay: None,
+            dead_letters: None,
+            handlers: HandlerRegistry::default(),
+            closures: HandlerRegistry::default(),
+            fan_out: HandlerRegistry::default(),
+            #[cfg(feature = "persistence")]
+            upcasters: Upcasters::default(),
+            paused: Pause::default(),
+        }
+    }
+
+    /// Passes the request `Req` through all behaviors to `handle`.
+    pub(crate) fn run_request<Req>(&self, op: &'static str, req: Req, handle: impl FnOnce(Req))
+    where
+        Req: Send + 'static,
+    {
+        self.report::<Req, _>(system::completed, || {
+            self.observer.request::<Req, _>(op, || {
+                if self.behavior.is_empty() {
+                    return handle(req);
+                }
+                run_pipeline(&self.behavior, AnyRequest::new(req), |req| {
+                    handle(req.into_inner())
+                })
+            })
+        })
+    }
+
+    /// Runs the request `Req` and publishes its request event afterwards,
+    /// if enabled with `with_request_events()`. `outcome` tells whether it failed.
+    pub(crate) fn report<Req, R>(
+        &self,
+        outcome: impl FnOnce(&R) -> Option<&'static str>,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        let Some(event) = self.request_events else {
+            return f();
+        };
+        let start = Stopwatch::start();
+        let res = f();
+        self.publish(event(type_name::<Req>(), start.elapsed(), outcome(&res)));
+        res
+    }
+
+    /// Invokes all listeners with a `&Ev`.
+    /// If listeners are isolated, their panics are caught and returned.
+    pub(crate) fn notify(&self, envelope: &Envelope<Ev>) -> Vec<ListenerPanic> {
+        let topic = envelope.topic.as_deref();
+        let notify = || {
+            #[cfg(feature = "std")]
+            if self.isolate_listeners {
+                return self
+                    .listener
+                    .notify_isolated(&envelope.ev, topic, &self.observer);
+            }
+            self.listener.notify(&envelope.ev, topic, &self.observer);
+            vec![]
+        };
+        let notify = || match &self.replay {
+            Some(replay) => replay.record(&envelope.ev, notify),
+            None => notify(),
+        };
+        correlation::scope(envelope.trace, || {
+            instrument::next(&envelope.ev, topic, || {
+                // Only catch-all listeners read the topic, so don't copy it otherwise.
+                if self.catch_all {
+                    correlation::scope_topic(topic, notify)
+                } else {
+                    notify()
+                }
+            })
+        })
+    }
+
+    /// Invokes all shared listeners with an `Arc<Ev>` and takes the event back afterwards.
+    /// The event is only cloned if a listener kept the [`Arc`].
+    pub(crate) fn notify_shared(
+        &self,
+        envelope: Envelope<Ev>,
+    ) -> (Envelope<Ev>, Vec<ListenerPanic>) {
+        let Some(recover) = self.recover else {
+            return (envelope, vec![]);
+        };
+        let Envelope {
+            topic,
+            trace,
+            ev,
+            attempts,
+            expiry,
+        } = envelope;
+        let ev = Arc::new(ev);
+        let panics = correlation::scope(trace, || {
+            #[cfg(feature = "std")]
+            if self.isolate_listeners {
+                return self
+                    .shared_listener
+                    .notify_isolated(&ev, topic.as_deref(), &self.observer);
+            }
+            self.shared_listener
+                .notify(&ev, topic.as_deref(), &self.observer);
+            vec![]
+        });
+        let ev = recover(ev);
+        (
+            Envelope {
+                topic,
+                trace,
+                ev,
+                attempts,
+                expiry,
+            },
+            panics,
+        )
+    }
+
+    /// Invokes all listeners and shared listeners,
+    /// handing the event back together with all caught listener panics.
+    pub(crate) fn notify_all(&self, envelope: Envelope<Ev>) -> (Envelope<Ev>, Vec<ListenerPanic>) {
+        let mut panics = self.notify(&envelope);
+        let (envelope, shared_panics) = self.notify_shared(envelope);
+        panics.extend(shared_panics);
+        (envelope, panics)
+    }
+
+    /// Receives the next published event, invokes all listeners
+    /// with a `&Ev` and hands the event back to the caller,
+    /// together with all caught listener panics.
+    pub(crate) fn process_next(&self) -> Result<(Envelope<Ev>, Vec<ListenerPanic>), TryRecvError> {
+        if self.paused.is_paused() {
+            return Err(TryRecvError::Empty);
+        }
+        let envelope = loop {
+            let envelope = self.channel.try_recv()?;
+            #[cfg(feature = "std")]
+            let envelope = self.limit_rate(envelope)?;
+            if let Some(envelope) = self.unexpired(envelope) {
+                break envelope;
+            }
+        };
+        let processed = self.notify_all(envelope);
+        self.observer.counters.processed();
+        Ok(processed)
+    }
+
+    /// Applies the rate limit, if any, to the received `envelope`,
+    /// blocking the current thread while waiting.
+    #[cfg(feature = "std")]
+    fn limit_rate(&self, mut envelope: Envelope<Ev>) -> Result<Envelope<Ev>, TryRecvError> {
+        let Some(limiter) = &self.rate_limit else {
+            return Ok(envelope);
+        };
+        loop {
+            match self.throttle(limiter, envelope)? {
+                Throttled::Deliver(envelope) => return Ok(envelope),
+                Throttled::Wait(pending, wait) => {
+                    std::thread::sleep(wait);
+                    envelope = self.coalesce(limiter, pending);
+                }
+            }
+        }
+    }
+
+    /// Takes a slot of the rate limit for the `envelope` or tells how long to wait for one.
+    /// With [`ThrottlePolicy::Drop`], events are dropped until there is a free slot,
+    /// failing once the channel is empty.
+    #[cfg(feature = "std")]
+    pub(crate) fn throttle(
+        &self,
+        limiter: &RateLimiter,
+        mut envelope: Envelope<Ev>,
+    ) -> Result<Throttled<Envelope<Ev>>, TryRecvError> {
+        loop {
+            match limiter.acquire() {
+                Ok(()) => return Ok(Throttled::Deliver(envelope)),
+                Err(_) if limiter.limit.policy() == ThrottlePolicy::Drop => {
+                    self.dead_letter(envelope, DeadLetterReason::RateLimited);
+                    envelope = self.channel.try_recv()?;
+                }
+                Err(wait) => return Ok(Throttled::Wait(envelope, wait)),
+            }
+        }
+    }
+
+    /// After waiting for a slot, replaces the `pending` event with the newest one
+    /// if configured with [`ThrottlePolicy::Coalesce`], dropping all events in between.
+    #[cfg(feature = "std")]
+    pub(crate) fn coalesce(&self, limiter: &RateLimiter, pending: Envelope<Ev>) -> Envelope<Ev> {
+        if limiter.limit.policy() != ThrottlePolicy::Coalesce {
+            return pending;
+        }
+        let mut newest = pending;
+        while let Ok(newer) = self.channel.try_recv() {
+            let older = core::mem::replace(&mut newest, newer);
+            self.dead_letter(older, DeadLetterReason::RateLimited);
+        }
+        newest
+    }
+
+    /// Passes the event `Ev` through all publish interceptors in the order they were added,
+    /// returns `None` if one of them dropped it.
+    pub(crate) fn intercept(&self, event: Ev) -> Option<Ev> {
+        self.interceptors
+            .iter()
+            .try_fold(event, |event, interceptor| interceptor(event))
+    }
+
+    /// Sends the [`Envelope`] through the channel,
+    /// events dropped by the [`OverflowPolicy`](crate::channel::OverflowPolicy) become dead letters.
+    pub(crate) fn dispatch(
+        &self,
+        envelope: Envelope<Ev>,
+    ) -> Result<(), TrySendError<Envelope<Ev>>> {
+        let dropped = self.channel.send(envelope)?;
+        self.dead_letter_overflow(dropped);
+        Ok(())
+    }
+
+    /// Same as [`BasicMediator::dispatch()`], but never blocks.
+    #[cfg(feature = "async")]
+    pub(crate) fn try_dispatch(
+        &self,
+        envelope: Envelope<Ev>,
+    ) -> Result<(), TrySendError<Envelope<Ev>>> {
+        let dropped = self.channel.try_send(envelope)?;
+        self.dead_letter_overflow(dropped);
+        Ok(())
+    }
+
+    /// Same as [`BasicMediator::dispatch()`], but awaits space in the channel
+    /// instead of blocking, if its backend can be awaited.
+    #[cfg(feature = "async")]
+    pub(crate) async fn dispatch_async(
+        &self,
+        envelope: Envelope<Ev>,
+    ) -> Result<(), TrySendError<Envelope<Ev>>> {
+        let dropped = self.channel.send_async(envelope).await?;
+        self.dead_letter_overflow(dropped);
+        Ok(())
+    }
+
+    /// Same as [`BasicMediator::dispatch()`], but a rejected event becomes a dead letter
+    /// instead of being handed back.
+    fn publish_envelope(&self, envelope: Envelope<Ev>) {
+        if let Err(TrySendError::Full(envelope) | TrySendError::Disconnected(envelope)) =
+            self.dispatch(envelope)
+        {
+            self.dead_letter_overflow(vec![envelope]);
+        }
+    }
+
+    /// Returns the recorded [`CausalityGraph`], naming listeners from `names`
+    /// and the listeners of this mediator.
+    #[cfg(feature = "causality")]
+    pub(crate) fn causality_graph_with(
+        &self,
+        mut names: BTreeMap<ListenerHandle, &'static str>,
+    ) -> CausalityGraph {
+        let Some(recorder) = &self.observer.causality else {
+            return CausalityGraph::default();
+        };
+        self.listener.names(&mut names);
+        self.shared_listener.names(&mut names);
+        recorder.graph(&names)
+    }
+
+    /// Pushes the event into the dead-letter queue, if enabled.
+    pub(crate) fn dead_letter(&self, envelope: Envelope<Ev>, reason: DeadLetterReason) {
+        if let Some(queue) = &self.dead_letters {
+            queue.send(DeadLetter::new(envelope, reason)).ok();
+        }
+    }
+
+    /// Pushes the event into the dead-letter queue, if enabled and any listener panicked.
+    pub(crate) fn dead_letter_panicked(&self, envelope: Envelope<Ev>, panics: &[ListenerPanic]) {
+        if let Some(queue) = &self.dead_letters {
+            if let Some(letter) = DeadLetter::panicked(envelope, panics) {
+                queue.send(letter).ok();
+            }
+        }
+    }
+
+    /// Hands the received `envelope` back unless it expired,
+    /// in which case it becomes a dead letter.
+    pub(crate) fn unexpired(&self, envelope: Envelope<Ev>) -> Option<Envelope<Ev>> {
+        if !envelope.is_expired() {
+            return Some(envelope);
+        }
+        self.dead_letter(envelope, DeadLetterReason::Expired);
+        None
+    }
+
+    pub(crate) fn dead_letter_overflow(&self, dropped: Vec<Envelope<Ev>>) {
+        for envelope in dropped {
+            self.dead_letter(envelope, DeadLetterReason::Overflow);
+        }
+    }
 }
 
 impl<Ev> SyncMediatorInternal<Ev> for BasicMediator<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Publishes an event `Ev`.
     ///
@@ -95,30 +471,426 @@ where
     /// }
     ///
     fn publish(&self, event: Ev) {
-        self.channel.0.send(event).ok();
+        let Some(event) = self.intercept(event) else {
+            return;
+        };
+        let envelope = Envelope::new(None, event);
+        self.observer.published(&envelope);
+        self.publish_envelope(envelope);
+    }
+
+    /// Tries to publish an event `Ev`.
+    ///
+    /// Behaves like [`BasicMediator::publish()`], but if the channel is full
+    /// and [`crate::channel::OverflowPolicy::Error`] was configured,
+    /// the event is handed back as a [`TrySendError::Full`].
+    /// For all other policies, this method returns `Ok(())`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    /// use mediatrix::channel::TrySendError;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .with_capacity(1)
+    ///     .with_overflow_policy(OverflowPolicy::Error)
+    ///     .build();
+    ///
+    /// assert!(mediator.try_publish(MyEvent::One).is_ok());
+    /// assert!(matches!(
+    ///     mediator.try_publish(MyEvent::One),
+    ///     Err(TrySendError::Full(MyEvent::One))
+    /// ));
+    ///
+    fn try_publish(&self, event: Ev) -> Result<(), TrySendError<Ev>> {
+        let Some(event) = self.intercept(event) else {
+            return Ok(());
+        };
+        let envelope = Envelope::new(None, event);
+        self.observer.published(&envelope);
+        self.dispatch(envelope).map_err(Envelope::open_rejected)
+    }
+
+    /// Publishes an event `Ev` to the given `topic`.
+    ///
+    /// Listeners added with [`super::BasicBuilder::add_listener_for_topic()`]
+    /// only receive the event if their [`crate::topics::TopicPattern`] matches the `topic`.
+    /// All other listeners receive the event as usual.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Created(u32)
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_listener_for_topic("orders.*", |_: &MyEvent| {
+    ///         /* Only invoked for order events */
+    ///     })
+    ///     .build();
+    ///
+    /// mediator.publish_to("orders.created", MyEvent::Created(1));
+    /// mediator.publish_to("users.created", MyEvent::Created(2));
+    ///
+    fn publish_to(&self, topic: &str, event: Ev) {
+        let Some(event) = self.intercept(event) else {
+            return;
+        };
+        let envelope = Envelope::new(Some(topic), event);
+        self.observer.published(&envelope);
+        self.publish_envelope(envelope);
+    }
+
+    /// Publishes an event `Ev` that expires once the `ttl` elapsed.
+    ///
+    /// If the event is still in the channel when its time-to-live elapsed,
+    /// processing it with [`BasicMediator::next()`] skips all listeners
+    /// and moves it to the dead-letter queue with [`DeadLetterReason::Expired`] instead,
+    /// if enabled, and processes the next event.
+    /// This suits events that are worthless after a delay, like UI refreshes or market data.
+    /// The time-to-live isn't persisted, so a serialized event never expires.
+    ///
+    /// Without the `std` feature, there is no clock, so the event never expires.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use mediatrix::synchronous::basic::*;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Price(u32)
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_listener(|ev: &MyEvent| {
+    ///         /* Only invoked for fresh prices */
+    ///     })
+    ///     .collect_dead_letters(true)
+    ///     .build();
+    ///
+    /// mediator.publish_with_ttl(MyEvent::Price(1), Duration::ZERO);
+    /// std::thread::sleep(Duration::from_millis(1));
+    ///
+    /// assert!(mediator.next().is_err());
+    /// let letter = mediator.dead_letters().next().unwrap();
+    /// assert_eq!(letter.reason(), &DeadLetterReason::Expired);
+    /// # }
+    ///
+    fn publish_with_ttl(&self, event: Ev, ttl: Duration) {
+        let Some(event) = self.intercept(event) else {
+            return;
+        };
+        let envelope = Envelope::new(None, event).with_ttl(ttl);
+        self.observer.published(&envelope);
+        self.publish_envelope(envelope);
+    }
+
+    /// Publishes an urgent event `Ev`, which is delivered before all regular events.
+    ///
+    /// Urgent events skip the backlog of regular events: [`BasicMediator::next()`]
+    /// processes all pending urgent events, oldest first, before any regular one.
+    /// This keeps critical notifications, e.g. about a shutdown or an error state,
+    /// from getting stuck behind a backlog of routine events.
+    /// Urgent events are never dropped, since their lane is unbounded,
+    /// regardless of the capacity and [`OverflowPolicy`](crate::channel::OverflowPolicy).
+    /// A thread blocked in [`BasicMediator::next_blocking()`] only picks the urgent event
+    /// up after the next regular event.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// enum MyEvent {
+    ///     Tick(u32),
+    ///     Shutdown
+    /// }
+    ///
+    /// let received = Arc::new(Mutex::new(Vec::new()));
+    /// let listener = received.clone();
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_listener(move |ev: &MyEvent| listener.lock().unwrap().push(ev.clone()))
+    ///     .build();
+    ///
+    /// mediator.publish(MyEvent::Tick(1));
+    /// mediator.publish(MyEvent::Tick(2));
+    /// mediator.publish_urgent(MyEvent::Shutdown);
+    ///
+    /// mediator.next().unwrap();
+    /// assert_eq!(received.lock().unwrap()[0], MyEvent::Shutdown);
+    ///
+    fn publish_urgent(&self, event: Ev) {
+        let Some(event) = self.intercept(event) else {
+            return;
+        };
+        let envelope = Envelope::new(None, event);
+        self.observer.published(&envelope);
+        self.channel.send_urgent(envelope);
     }
 }
 
 impl<Ev> SyncMediatorInternalHandle<Ev> for BasicMediator<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Send a request of type `Req` to the mediator.
     ///
     /// The request will be processed internally by [`RequestHandler::handle()`].
     /// This is why it is required to implement [`RequestHandler`] for [`BasicMediator`].
+    /// If any [`Behavior`] was added, the request passes through them first.
     ///
     fn send<Req>(&self, req: Req)
     where
         Self: RequestHandler<Req, Ev>,
+        Req: Send + 'static,
+    {
+        self.run_request("send", req, |req| {
+            <Self as RequestHandler<Req, Ev>>::handle(self, req)
+        })
+    }
+
+    /// Send a batch of requests of type `Req` to the mediator.
+    ///
+    /// The batch will be processed internally by [`BatchRequestHandler::handle_batch()`],
+    /// which is implemented for every [`RequestHandler`] and then handles the requests one by one.
+    /// Implement [`BatchRequestHandler`] instead to handle the whole batch at once.
+    /// If any [`Behavior`] was added, the batch passes through them first, as a single request.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Inserted(usize)
+    /// }
+    ///
+    /// struct Insert(u32);
+    ///
+    /// impl BatchRequestHandler<Insert, MyEvent> for BasicMediator<MyEvent> {
+    ///     fn handle_batch(&self, reqs: Vec<Insert>) {
+    ///         // Insert all rows in a single round-trip
+    ///         self.publish(MyEvent::Inserted(reqs.len()));
+    ///     }
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder().build();
+    ///
+    /// mediator.send_batch(vec![Insert(1), Insert(2), Insert(3)]);
+    ///
+    /// assert_eq!(mediator.next_all(), 1);
+    ///
+    fn send_batch<Req>(&self, reqs: Vec<Req>)
+    where
+        Self: BatchRequestHandler<Req, Ev>,
+        Req: Send + 'static,
+    {
+        self.run_request("send_batch", reqs, |reqs| {
+            <Self as BatchRequestHandler<Req, Ev>>::handle_batch(self, reqs)
+        })
+    }
+
+    /// Send a request of unknown type to the mediator.
+    ///
+    /// The request is processed by the handler registered for its type
+    /// via [`BasicBuilder::register_handler()`](super::BasicBuilder::register_handler()),
+    /// passing through all behaviors first, just like in [`BasicMediator::send()`].
+    /// This is useful for plugin systems, where request types aren't known at compile time.
+    /// If no handler is registered for the type, the request is handed back.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    /// use std::any::Any;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Greeted(String)
+    /// }
+    ///
+    /// struct Greet(String);
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .register_handler(|m: &BasicMediator<MyEvent>, req: Greet| {
+    ///         m.publish(MyEvent::Greeted(req.0));
+    ///     })
+    ///     .build();
+    ///
+    /// let req: Box<dyn Any + Send> = Box::new(Greet("World".to_string()));
+    /// assert!(mediator.send_dyn(req).is_ok());
+    /// assert!(mediator.send_dyn(Box::new(42u32)).is_err());
+    /// assert_eq!(mediator.next_all(), 1);
+    ///
+    fn send_dyn(&self, req: Box<dyn Any + Send>) -> Result<(), Box<dyn Any + Send>>
+    where
+        Self: 'static,
+    {
+        match self.handlers.get(&*req) {
+            Some(handler) => {
+                handler(self, req);
+                Ok(())
+            }
+            None => Err(req),
+        }
+    }
+
+    /// Send a request of type `Req` to the mediator and correlate it with the resulting events.
+    ///
+    /// The request is processed like in [`BasicMediator::send()`],
+    /// but a new [`CorrelationId`] is assigned to it and returned to the caller.
+    /// Every event published while handling the request carries that ID,
+    /// which listeners access through [`crate::correlation::Enveloped`]
+    /// or [`CorrelationId::current()`].
+    ///
+    /// See [`CorrelationId`] for an example.
+    ///
+    fn send_traced<Req>(&self, req: Req) -> CorrelationId
+    where
+        Self: RequestHandler<Req, Ev>,
+        Req: Send + 'static,
+    {
+        let id = CorrelationId::next();
+        correlation::scope(Trace::current().traced(id), || self.send(req));
+        id
+    }
+
+    /// Send a request of type `Req` to the mediator and receive a response of type `Resp`.
+    ///
+    /// The request will be processed internally by [`RequestHandler::handle()`],
+    /// whose return value is handed back to the caller.
+    /// This is useful for query-style requests, where the caller is
+    /// interested in a result rather than in published events.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Queried
+    /// }
+    ///
+    /// struct Square(u32);
+    ///
+    /// impl RequestHandler<Square, MyEvent, u32> for BasicMediator<MyEvent> {
+    ///     fn handle(&self, req: Square) -> u32 {
+    ///         self.publish(MyEvent::Queried);
+    ///         req.0 * req.0
+    ///     }
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder().build();
+    ///
+    /// let res: u32 = mediator.send_and_receive(Square(3));
+    /// assert_eq!(res, 9);
+    ///
+    fn send_and_receive<Req, Resp>(&self, req: Req) -> Resp
+    where
+        Self: RequestHandler<Req, Ev, Resp>,
+    {
+        self.report::<Req, _>(system::completed, || {
+            self.observer.request::<Req, _>("send_and_receive", || {
+                <Self as RequestHandler<Req, Ev, Resp>>::handle(self, req)
+            })
+        })
+    }
+
+    /// Send a request of type `Req` to the mediator, which may fail with an error of type `Err`.
+    ///
+    /// The request will be processed internally by [`TryRequestHandler::handle()`],
+    /// whose error is handed back to the caller.
+    /// If any [`Behavior`] was added, the request passes through them first.
+    /// A request that is short-circuited by a behavior counts as `Ok(())`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Withdrawn(u32)
+    /// }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum WithdrawError {
+    ///     TooMuch
+    /// }
+    ///
+    /// struct Withdraw(u32);
+    ///
+    /// impl TryRequestHandler<Withdraw, MyEvent, WithdrawError> for BasicMediator<MyEvent> {
+    ///     fn handle(&self, req: Withdraw) -> Result<(), WithdrawError> {
+    ///         if req.0 > 100 {
+    ///             return Err(WithdrawError::TooMuch);
+    ///         }
+    ///         self.publish(MyEvent::Withdrawn(req.0));
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder().build();
+    ///
+    /// assert_eq!(mediator.try_send(Withdraw(50)), Ok(()));
+    /// assert_eq!(mediator.try_send(Withdraw(500)), Err(WithdrawError::TooMuch));
+    ///
+    fn try_send<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Self: TryRequestHandler<Req, Ev, Err>,
+        Req: Send + 'static,
     {
-        <Self as RequestHandler<Req, Ev>>::handle(self, req);
+        self.report::<Req, _>(system::failed, || {
+            self.observer.request::<Req, _>("try_send", || {
+                if self.behavior.is_empty() {
+                    return <Self as TryRequestHandler<Req, Ev, Err>>::handle(self, req);
+                }
+                let mut res = Ok(());
+                run_pipeline(&self.behavior, AnyRequest::new(req), |req| {
+                    res = <Self as TryRequestHandler<Req, Ev, Err>>::handle(self, req.into_inner())
+                });
+                res
+            })
+        })
     }
 }
 
 impl<Ev> SyncMediatorInternalNext for BasicMediator<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Process the next published event `Ev`.
     ///
@@ -126,15 +898,707 @@ where
     /// registered listeners with a `&Ev`
     /// of the published event.
     ///
-    fn next(&self) -> Result<(), TryRecvError> {
-        match self.channel.1.try_recv() {
-            Ok(ev) => {
-                for listener in self.listener.iter() {
-                    listener(&ev)
-                }
-                Ok(())
+    /// If the mediator was built with [`super::BasicBuilder::isolate_listeners()`],
+    /// a panicking listener does not prevent the remaining listeners from being invoked.
+    /// Instead, all caught panics are returned in [`NextError::ListenerPanicked`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_listener(|_: &MyEvent| panic!("bad listener"))
+    ///     .add_listener(|_: &MyEvent| {
+    ///         /* Still invoked */
+    ///     })
+    ///     .isolate_listeners(true)
+    ///     .build();
+    ///
+    /// mediator.publish(MyEvent::One);
+    ///
+    /// match mediator.next() {
+    ///     Err(NextError::ListenerPanicked(panics)) => {
+    ///         assert_eq!(panics[0].message(), Some("bad listener"));
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// # }
+    ///
+    fn next(&self) -> Result<(), NextError> {
+        let (envelope, panics) = self.process_next()?;
+        self.dead_letter_panicked(envelope, &panics);
+        NextError::from_panics(panics)
+    }
+
+    /// Process all pending published events `Ev`.
+    ///
+    /// Invokes [`BasicMediator::next()`] until the channel is empty
+    /// and returns the number of processed events.
+    /// Events whose listeners panicked count as processed,
+    /// but the caught panics are discarded.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One
+    /// }
+    ///
+    /// struct Request;
+    ///
+    /// impl RequestHandler<Request, MyEvent> for BasicMediator<MyEvent> {
+    ///     fn handle(&self, _req: Request) {
+    ///         self.publish(MyEvent::One);
+    ///     }
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder().build();
+    ///
+    /// mediator.send(Request);
+    /// mediator.send(Request);
+    ///
+    /// assert_eq!(mediator.next_all(), 2);
+    ///
+    fn next_all(&self) -> usize {
+        let mut processed = 0;
+        while let Ok(()) | Err(NextError::ListenerPanicked(_)) = self.next() {
+            processed += 1;
+        }
+        processed
+    }
+
+    #[cfg(feature = "std")]
+    /// Process the next published event `Ev`, blocking until one is available.
+    ///
+    /// Unlike [`BasicMediator::next()`], this method does not return
+    /// immediately if the channel is empty, but blocks the current thread
+    /// until an event is published.
+    /// This allows for a simple event loop without busy-polling.
+    ///
+    fn next_blocking(&self) -> Result<(), NextError> {
+        let envelope = loop {
+            let envelope = self.channel.recv()?;
+            self.paused.wait();
+            match self.limit_rate(envelope) {
+                Err(TryRecvError::Empty) => continue,
+                res => {
+                    if let Some(envelope) = self.unexpired(res?) {
+                        break envelope;
+                    }
+                }
+            }
+        };
+        let (envelope, panics) = self.notify_all(envelope);
+        self.observer.counters.processed();
+        self.dead_letter_panicked(envelope, &panics);
+        NextError::from_panics(panics)
+    }
+
+    /// Returns an [`EventWorker`] to process events from another thread.
+    ///
+    /// Any number of workers can process the events of the [`BasicMediator`] concurrently,
+    /// e.g. on the threads of a pool.
+    /// The mediator can be shared between threads if the event type `Ev` is `Send`.
+    ///
+    /// See [`EventWorker`] for an example.
+    ///
+    #[cfg(not(feature = "async-only"))]
+    fn worker(&self) -> EventWorker<'_, Self>
+    where
+        Self: Sync,
+    {
+        EventWorker::new(self)
+    }
+}
+
+impl<Ev> SyncMediatorInternalSubscribe<Ev> for BasicMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Subscribes a user-defined listener to the already built [`BasicMediator`].
+    ///
+    /// The listener is invoked for every event processed by
+    /// [`BasicMediator::next()`] from now on.
+    /// The returned [`ListenerHandle`] can be passed to
+    /// [`BasicMediator::unsubscribe()`] to remove the listener again.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder().build();
+    ///
+    /// let handle = mediator.subscribe(|_: &MyEvent| {
+    ///     /* Your listening logic */
+    /// });
+    ///
+    /// assert!(mediator.unsubscribe(handle));
+    ///
+    fn subscribe(&self, f: impl Listener<Ev>) -> ListenerHandle {
+        self.listener
+            .add(type_name_of_val(&f), ExclusiveListener::new(f))
+    }
+
+    /// Unsubscribes the listener identified by `handle`.
+    ///
+    /// Returns `true` if the listener was found and removed,
+    /// and `false` if it was already unsubscribed.
+    ///
+    fn unsubscribe(&self, handle: ListenerHandle) -> bool {
+        self.listener.remove(handle) || self.shared_listener.remove(handle)
+    }
+
+    /// Subscribes a user-defined listener to the already built [`BasicMediator`]
+    /// and replays the last processed events to it.
+    ///
+    /// Before being subscribed like in [`BasicMediator::subscribe()`],
+    /// the listener is invoked with every event in the replay buffer, oldest first.
+    /// The replay buffer holds the last `n` events processed by [`BasicMediator::next()`],
+    /// where `n` is configured via [`BasicBuilder::with_replay_capacity()`].
+    /// Without a replay buffer, this is the same as [`BasicMediator::subscribe()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .with_replay_capacity(1)
+    ///     .build();
+    ///
+    /// mediator.publish(MyEvent::One);
+    /// mediator.publish(MyEvent::Two);
+    /// mediator.next_all();
+    ///
+    /// mediator.add_listener_with_replay(|_: &MyEvent| {
+    ///     /* Receives MyEvent::Two right away */
+    /// });
+    ///
+    fn add_listener_with_replay(&self, f: impl Listener<Ev>) -> ListenerHandle {
+        match &self.replay {
+            Some(replay) => replay.attach(f, |f| self.subscribe(f)),
+            None => self.subscribe(f),
+        }
+    }
+}
+
+impl<Ev> SyncMediatorInternalDeadLetters<Ev> for BasicMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Takes all events out of the dead-letter queue, oldest first.
+    ///
+    /// An event becomes a [`DeadLetter`] if it was dropped because the channel was full,
+    /// see [`OverflowPolicy`](crate::channel::OverflowPolicy),
+    /// or if listeners panicked while processing it, see [`BasicBuilder::isolate_listeners()`].
+    /// Events handed back to the publisher, e.g. by [`BasicMediator::try_publish()`],
+    /// do not become dead letters.
+    /// The dead-letter queue has to be enabled via [`BasicBuilder::collect_dead_letters()`],
+    /// otherwise the returned [`DeadLetters`] are always empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .with_capacity(1)
+    ///     .with_overflow_policy(OverflowPolicy::DropNewest)
+    ///     .collect_dead_letters(true)
+    ///     .build();
+    ///
+    /// mediator.publish(MyEvent::One);
+    /// mediator.publish(MyEvent::Two);
+    ///
+    /// for letter in mediator.dead_letters() {
+    ///     assert_eq!(letter.reason(), &DeadLetterReason::Overflow);
+    ///     /* Inspect or publish letter.into_event() again */
+    /// }
+    ///
+    fn dead_letters(&self) -> DeadLetters<Ev> {
+        DeadLetters::drain(self.dead_letters.as_ref())
+    }
+
+    /// Publishes all events in the dead-letter queue again, oldest first,
+    /// and returns how many were redelivered.
+    ///
+    /// The events keep their topic and metadata and are processed like any other event,
+    /// but observers aren't notified again.
+    /// If they fail again, they return to the dead-letter queue
+    /// with one more [`DeadLetter::attempts()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .with_capacity(1)
+    ///     .with_overflow_policy(OverflowPolicy::DropNewest)
+    ///     .collect_dead_letters(true)
+    ///     .build();
+    ///
+    /// mediator.publish(MyEvent::One);
+    /// mediator.publish(MyEvent::Two);
+    /// assert_eq!(mediator.next_all(), 1);
+    ///
+    /// assert_eq!(mediator.redeliver_all(), 1);
+    /// assert_eq!(mediator.next_all(), 1);
+    ///
+    fn redeliver_all(&self) -> usize {
+        self.redeliver_with(RedeliveryPolicy::default())
+    }
+
+    /// Publishes the events in the dead-letter queue whose cool-down elapsed
+    /// and which weren't redelivered too often yet, according to the [`RedeliveryPolicy`].
+    /// Returns how many were redelivered.
+    ///
+    /// All other events stay in the dead-letter queue.
+    /// See [`BasicMediator::redeliver_all()`] for more info.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use mediatrix::synchronous::basic::*;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .with_capacity(1)
+    ///     .with_overflow_policy(OverflowPolicy::DropNewest)
+    ///     .collect_dead_letters(true)
+    ///     .build();
+    ///
+    /// let policy = RedeliveryPolicy {
+    ///     max_attempts: Some(1),
+    ///     cool_down: Duration::from_millis(10),
+    /// };
+    ///
+    /// mediator.publish(MyEvent::One);
+    /// mediator.publish(MyEvent::Two);
+    /// assert_eq!(mediator.redeliver_with(policy), 0);
+    ///
+    /// std::thread::sleep(Duration::from_millis(10));
+    /// assert_eq!(mediator.redeliver_with(policy), 1);
+    ///
+    /// // The channel is still full, so MyEvent::Two ends up in the queue again.
+    /// std::thread::sleep(Duration::from_millis(20));
+    /// assert_eq!(mediator.redeliver_with(policy), 0);
+    /// assert_eq!(mediator.dead_letters().next().unwrap().attempts(), 1);
+    /// # }
+    ///
+    fn redeliver_with(&self, policy: RedeliveryPolicy) -> usize {
+        let due = policy.take_due(self.dead_letters.as_ref());
+        let redelivered = due.len();
+        for envelope in due {
+            self.publish_envelope(envelope);
+        }
+        redelivered
+    }
+}
+
+impl<Ev> SyncMediatorInternalRegistered for BasicMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Send a request of type `Req` to the closure added for it.
+    ///
+    /// The request is processed by the closure added for its type
+    /// via [`BasicBuilder::add_request_handler()`](super::BasicBuilder::add_request_handler()),
+    /// passing through all behaviors first, just like in [`BasicMediator::send()`].
+    /// If no closure without a response is added for the type, the request is handed back.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Greeted(String)
+    /// }
+    ///
+    /// struct Greet(String);
+    /// struct Add(u32, u32);
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_request_handler(|m: &BasicMediator<MyEvent>, req: Greet| {
+    ///         m.publish(MyEvent::Greeted(req.0));
+    ///     })
+    ///     .add_request_handler(|_: &BasicMediator<MyEvent>, req: Add| req.0 + req.1)
+    ///     .build();
+    ///
+    /// assert!(mediator.send_registered(Greet("World".to_string())).is_ok());
+    /// assert!(mediator.send_registered(Add(1, 2)).is_err());
+    /// assert_eq!(mediator.send_registered_and_receive::<_, u32>(Add(1, 2)).ok(), Some(3));
+    /// assert_eq!(mediator.next_all(), 1);
+    ///
+    fn send_registered<Req>(&self, req: Req) -> Result<(), Req>
+    where
+        Self: 'static,
+        Req: Send + 'static,
+    {
+        let Some(handler) = self.closures.closure::<Self, Req, ()>() else {
+            return Err(req);
+        };
+        self.run_request("send_registered", req, |req| handler(self, req));
+        Ok(())
+    }
+
+    /// Send a request of type `Req` to the closure added for it and receive a response of type `Resp`.
+    ///
+    /// The request is processed by the closure added for its type and response
+    /// via [`BasicBuilder::add_request_handler()`](super::BasicBuilder::add_request_handler()),
+    /// whose return value is handed back to the caller.
+    /// Behaviors are not applied, just like in [`BasicMediator::send_and_receive()`].
+    /// If no such closure is added, the request is handed back.
+    ///
+    /// See [`BasicMediator::send_registered()`] for an example.
+    ///
+    fn send_registered_and_receive<Req, Resp>(&self, req: Req) -> Result<Resp, Req>
+    where
+        Self: 'static,
+        Req: 'static,
+        Resp: 'static,
+    {
+        let Some(handler) = self.closures.closure::<Self, Req, Resp>() else {
+            return Err(req);
+        };
+        Ok(self.report::<Req, _>(system::completed, || {
+            self.observer
+                .request::<Req, _>("send_registered_and_receive", || handler(self, req))
+        }))
+    }
+
+    /// Send a request of type `Req` to all closures added for it.
+    ///
+    /// Unlike [`BasicMediator::send_registered()`], any number of closures may handle the request,
+    /// e.g. a validation, the main and an audit handler, added via
+    /// [`BasicBuilder::add_fan_out_handler()`](super::BasicBuilder::add_fan_out_handler()).
+    /// They are invoked with a `&Req` one after another, in the order they were added.
+    /// The request passes through all behaviors first, just like in [`BasicMediator::send()`].
+    /// Returns the number of closures added for the request type.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Validated(u32),
+    ///     Ordered(u32),
+    /// }
+    ///
+    /// struct Order(u32);
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_fan_out_handler(|m: &BasicMediator<MyEvent>, req: &Order| {
+    ///         m.publish(MyEvent::Validated(req.0));
+    ///     })
+    ///     .add_fan_out_handler(|m: &BasicMediator<MyEvent>, req: &Order| {
+    ///         m.publish(MyEvent::Ordered(req.0));
+    ///     })
+    ///     .build();
+    ///
+    /// assert_eq!(mediator.send_fan_out(Order(1)), 2);
+    /// assert_eq!(mediator.send_fan_out(42u32), 0);
+    /// assert_eq!(mediator.next_all(), 2);
+    ///
+    fn send_fan_out<Req>(&self, req: Req) -> usize
+    where
+        Self: 'static,
+        Req: Send + 'static,
+    {
+        let handlers = self.fan_out.fan_out::<Req, FanOutHandler<Self, Req>>();
+        self.run_request("send_fan_out", req, |req| {
+            handlers.iter().for_each(|handler| handler(self, &req))
+        });
+        handlers.len()
+    }
+}
+
+impl<Ev> SyncMediatorInternalStats for BasicMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Returns a snapshot of the counters of the [`BasicMediator`]:
+    /// the number of queued, published and processed events,
+    /// and how often and how long each listener was invoked.
+    ///
+    /// The counters are always kept, no observer is needed.
+    ///
+    /// See [`MediatorStats`] for an example.
+    ///
+    fn stats(&self) -> MediatorStats {
+        let mut listeners = self.listener.stats();
+        listeners.extend(self.shared_listener.stats());
+        self.observer
+            .counters
+            .snapshot(self.channel.len(), listeners)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<Ev> SyncMediatorInternalDescribe for BasicMediator<Ev>
+where
+    Ev: MaybeDebug + JsonSchema,
+{
+    /// Returns a catalog of the [`BasicMediator`]: the JSON Schema of its events `Ev`
+    /// and the name, topic and priority of every listener currently subscribed to it.
+    ///
+    /// See [`MediatorDescription`] for an example.
+    ///
+    fn describe(&self) -> MediatorDescription {
+        let mut listeners = self.listener.describe(false);
+        listeners.extend(self.shared_listener.describe(false));
+        MediatorDescription::new::<Ev>(listeners)
+    }
+}
+
+#[cfg(feature = "causality")]
+impl<Ev> SyncMediatorInternalCausality for BasicMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Returns the flows of requests, events and listeners recorded by the [`BasicMediator`].
+    ///
+    /// The graph is empty unless the mediator was built with
+    /// [`super::BasicBuilder::record_causality()`].
+    ///
+    /// See [`CausalityGraph`] for an example.
+    ///
+    fn causality_graph(&self) -> CausalityGraph {
+        self.causality_graph_with(BTreeMap::new())
+    }
+}
+
+impl<Ev> SyncMediatorInternalPause for BasicMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Pauses the delivery of events to listeners.
+    ///
+    /// While paused, [`BasicMediator::publish()`] still enqueues events,
+    /// but [`BasicMediator::next()`] delivers none of them and returns [`NextError::Empty`],
+    /// as if no event was pending. [`BasicMediator::next_blocking()`] waits until resumed.
+    /// Requests are still handled.
+    /// This is useful for maintenance windows, bulk imports or deterministic test setups.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Imported(u32)
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_listener(|ev: &MyEvent| {
+    ///         /* Your listening logic */
+    ///     })
+    ///     .build();
+    ///
+    /// mediator.pause();
+    /// for i in 0..3 {
+    ///     mediator.publish(MyEvent::Imported(i));
+    /// }
+    /// assert_eq!(mediator.next_all(), 0);
+    ///
+    /// mediator.resume();
+    /// assert_eq!(mediator.next_all(), 3);
+    ///
+    fn pause(&self) {
+        self.paused.pause()
+    }
+
+    /// Resumes the delivery of events paused with [`BasicMediator::pause()`],
+    /// starting with the oldest pending event.
+    ///
+    fn resume(&self) {
+        self.paused.resume()
+    }
+
+    /// Returns whether the delivery of events is paused, see [`BasicMediator::pause()`].
+    ///
+    fn is_paused(&self) -> bool {
+        self.paused.is_paused()
+    }
+}
+
+impl<Ev> SyncMediatorInternalPoison for BasicMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Returns whether a panic occurred while an internal lock of the [`BasicMediator`] was held,
+    /// e.g. if a listener panicked while the event was recorded in the replay buffer.
+    ///
+    /// A poisoned mediator stays usable: its locks are recovered on the next access.
+    /// Still, the state guarded by the lock might miss the change the panic interrupted,
+    /// e.g. the replay buffer lacks the event whose listener panicked.
+    /// Without the `std` feature, a mediator is never poisoned.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use mediatrix::synchronous::basic::*;
+    /// use std::panic::{self, AssertUnwindSafe};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyEvent {
+    ///     Valid,
+    ///     Invalid
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_listener(|ev: &MyEvent| {
+    ///         if let MyEvent::Invalid = ev {
+    ///             panic!("invalid event");
+    ///         }
+    ///     })
+    ///     .with_replay_capacity(8)
+    ///     .build();
+    ///
+    /// mediator.publish(MyEvent::Invalid);
+    /// panic::catch_unwind(AssertUnwindSafe(|| mediator.next())).ok();
+    /// assert!(mediator.is_poisoned());
+    ///
+    /// mediator.heal();
+    /// assert!(!mediator.is_poisoned());
+    ///
+    /// mediator.publish(MyEvent::Valid);
+    /// assert!(mediator.next().is_ok());
+    /// # }
+    ///
+    fn is_poisoned(&self) -> bool {
+        self.listener.is_poisoned()
+            || self.shared_listener.is_poisoned()
+            || self.replay.as_ref().is_some_and(ReplayBuffer::is_poisoned)
+    }
+
+    /// Clears the poisoning of all internal locks of the [`BasicMediator`],
+    /// accepting their current state.
+    ///
+    /// This allows a long-running process to recover from a panic,
+    /// instead of restarting to get rid of the poisoned mediator.
+    ///
+    /// See [`BasicMediator::is_poisoned()`] for more info.
+    ///
+    fn heal(&self) {
+        self.listener.clear_poison();
+        self.shared_listener.clear_poison();
+        if let Some(replay) = &self.replay {
+            replay.clear_poison();
+        }
+    }
+}
+
+#[cfg(feature = "persistence")]
+impl<Ev> SyncMediatorInternalJournal<Ev> for BasicMediator<Ev>
+where
+    Ev: MaybeDebug + DeserializeOwned,
+{
+    /// Publishes all events recorded in the journal at `path`, oldest first.
+    ///
+    /// Every event is published to the topic it was originally published to, if any,
+    /// just like [`BasicMediator::publish()`] or [`BasicMediator::publish_to()`] would.
+    /// Returns the number of replayed events.
+    /// If the journal can't be read or contains a malformed line, an error is returned
+    /// and only the events before it have been published.
+    /// Events of older versions are turned into `Ev` by the upcasters
+    /// added with [`BasicBuilder::register_upcaster()`].
+    ///
+    /// Replaying into a mediator that journals to the same file
+    /// appends all replayed events to the journal again.
+    ///
+    /// See [`Journal`](crate::persistence::Journal) for an example.
+    ///
+    fn replay_from(&self, path: impl AsRef<Path>) -> io::Result<usize> {
+        self.replay_from_with(path, JsonCodec)
+    }
+
+    /// Publishes all events recorded in the journal at `path` in the format of the `codec`, oldest first.
+    ///
+    /// See [`BasicMediator::replay_from()`] for more info.
+    ///
+    /// See [`Codec`] for an example.
+    ///
+    fn replay_from_with(&self, path: impl AsRef<Path>, codec: impl Codec) -> io::Result<usize> {
+        let mut count = 0;
+        for entry in read_journal_with(path, codec, self.upcasters.clone())? {
+            let entry = entry?;
+            match entry.topic {
+                Some(topic) => self.publish_to(&topic, entry.event),
+                None => self.publish(entry.event),
             }
-            Err(err) => Err(err),
+            count += 1;
         }
+        Ok(count)
     }
 }
@@ -1,9 +1,59 @@
-use super::{basic::BasicMediator, interface::BasicMediatorBuilderInterface};
+#[cfg(feature = "std")]
+use super::interface::{ListenerIsolationBuilderInterface, RateLimitBuilderInterface};
+use super::{
+    basic::BasicMediator,
+    interface::{
+        BasicMediatorBuilderInterface, BehaviorMediatorBuilderInterface,
+        CatchAllMediatorBuilderInterface, ChannelBackendBuilderInterface,
+        ChannelMediatorBuilderInterface, ClosureHandlerBuilderInterface,
+        ConvertingMediatorBuilderInterface, DeadLetterMediatorBuilderInterface,
+        DynamicHandlerBuilderInterface, EnvelopedMediatorBuilderInterface,
+        FilterMediatorBuilderInterface, Handler, HandlerMediatorBuilderInterface,
+        InterceptorBuilderInterface, NamedListenerBuilderInterface,
+        ObserverMediatorBuilderInterface, PriorityMediatorBuilderInterface,
+        ProfileBuilderInterface, ReplayMediatorBuilderInterface, RequestEventsBuilderInterface,
+        RequestHookBuilderInterface, SharedListenerBuilderInterface,
+        StandaloneHandlerBuilderInterface, TopicMediatorBuilderInterface,
+        VariantMediatorBuilderInterface,
+    },
+};
+#[cfg(feature = "std")]
+use crate::mediator::ratelimit::{RateLimit, RateLimiter};
 use crate::mediator::{
+    behavior::Behavior,
     builder::{BuilderFlow, BuilderInternal},
-    listener::Listener,
+    channel::{ChannelFlavor, EventChannel, OverflowPolicy},
+    correlation::{Enveloped, EventMeta},
+    listener::{
+        recover, variant_filter, ExclusiveListener, Filter, Listener, MaybeDebug,
+        NotificationHandler, PublishInterceptor, SharedListener,
+    },
+    observer::{MediatorObserver, RequestMeta},
+    profile::Profile,
+    registry::{erase, FanOutHandler},
+    replay::ReplayBuffer,
+    system::{RequestEvent, SystemEvent},
+    topics::TopicPattern,
+};
+use alloc::{boxed::Box, sync::Arc};
+use core::{any::type_name_of_val, time::Duration};
+
+#[cfg(feature = "tracing")]
+use crate::mediator::{
+    logging::LoggingListener, synchronous::basic::interface::LoggingMediatorBuilderInterface,
+};
+
+#[cfg(feature = "persistence")]
+use crate::mediator::synchronous::basic::interface::UpcasterBuilderInterface;
+#[cfg(feature = "persistence")]
+use serde_json::Value;
+
+#[cfg(feature = "causality")]
+use crate::mediator::{
+    causality::CausalityRecorder, synchronous::basic::interface::CausalityBuilderInterface,
 };
-use std::{fmt::Debug, sync::mpsc::channel};
+#[cfg(feature = "causality")]
+use core::fmt::Debug;
 
 /// The [`BasicBuilder`] helps you to create a [`BasicMediator`].
 ///
@@ -15,30 +65,27 @@ use std::{fmt::Debug, sync::mpsc::channel};
 ///
 pub struct BasicBuilder<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     mediator: BasicMediator<Ev>,
 }
 
 impl<Ev> BuilderInternal<BasicMediator<Ev>, BasicBuilder<Ev>> for BasicMediator<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Creates a [`BasicBuilder`] with the goal of producing a [`BasicMediator`].
     ///
     fn builder() -> BasicBuilder<Ev> {
         BasicBuilder::<Ev> {
-            mediator: BasicMediator::<Ev> {
-                channel: channel(),
-                listener: vec![],
-            },
+            mediator: BasicMediator::new(),
         }
     }
 }
 
 impl<M, Ev> BasicMediatorBuilderInterface<M, Ev> for BasicBuilder<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Adds a user-defined listener to the [`BasicBuilder`].
     ///
@@ -47,17 +94,519 @@ where
     ///
     /// Also it must be a `Fn(Ev)` with a return type of `()`
     /// where `Ev` is the user-defined event type
-    /// that must be [`Debug`].
+    /// that must be [`MaybeDebug`].
+    ///
+    fn add_listener(self, f: impl Listener<Ev>) -> Self {
+        self.mediator
+            .listener
+            .add(type_name_of_val(&f), ExclusiveListener::new(f));
+        self
+    }
+}
+
+impl<M, Ev> NamedListenerBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener with a `name` to the [`BasicBuilder`].
+    ///
+    fn add_listener_named(self, name: &'static str, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add(name, ExclusiveListener::new(f));
+        self
+    }
+}
+
+impl<M, Ev> BehaviorMediatorBuilderInterface<M> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined [`Behavior`] to the [`BasicBuilder`].
+    ///
+    /// Behaviors wrap every request sent to the [`BasicMediator`]
+    /// in the order they were added.
+    ///
+    fn add_behavior(mut self, b: impl Behavior) -> Self {
+        self.mediator.behavior.push(Box::new(b));
+        self
+    }
+}
+
+impl<M, Ev> ChannelMediatorBuilderInterface<M> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Bounds the channel of the [`BasicBuilder`] to `capacity` events.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`.
+    ///
+    fn with_capacity(mut self, capacity: usize) -> Self {
+        assert!(capacity > 0, "channel capacity must be greater than 0");
+        self.mediator.channel.set_capacity(Some(capacity));
+        self
+    }
+
+    /// Sets the [`OverflowPolicy`] of the [`BasicBuilder`].
+    ///
+    fn with_overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.mediator.channel.policy = policy;
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M, Ev> ListenerIsolationBuilderInterface<M> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Enables or disables listener isolation of the [`BasicBuilder`].
+    ///
+    fn isolate_listeners(mut self, isolate: bool) -> Self {
+        self.mediator.isolate_listeners = isolate;
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M, Ev> RateLimitBuilderInterface<M> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the [`RateLimit`] of the [`BasicBuilder`].
+    ///
+    fn with_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.mediator.rate_limit = Some(RateLimiter::new(limit));
+        self
+    }
+}
+
+impl<M, Ev> TopicMediatorBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener for a topic to the [`BasicBuilder`].
+    ///
+    /// The listener only receives events published to a topic
+    /// matching the [`TopicPattern`] `topic`.
+    ///
+    fn add_listener_for_topic(self, topic: &str, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add_for_topic(
+            type_name_of_val(&f),
+            Some(TopicPattern::new(topic)),
+            ExclusiveListener::new(f),
+        );
+        self
+    }
+}
+
+impl<M, Ev> FilterMediatorBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener guarded by a [`Filter`] to the [`BasicBuilder`].
+    ///
+    /// The listener is only invoked if `filter` returns `true` for an event.
+    ///
+    fn add_filtered_listener(self, filter: impl Filter<Ev>, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| {
+                if filter(ev) {
+                    f(ev)
+                }
+            }),
+        );
+        self
+    }
+}
+
+impl<M, Ev> VariantMediatorBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener for events with certain variant names to the [`BasicBuilder`].
+    ///
+    fn add_listener_for_variants(
+        self,
+        variants: impl IntoIterator<Item = &'static str>,
+        f: impl Listener<Ev>,
+    ) -> Self
+    where
+        for<'a> &'a Ev: Into<&'static str>,
+    {
+        <Self as FilterMediatorBuilderInterface<M, Ev>>::add_filtered_listener(
+            self,
+            variant_filter(variants),
+            f,
+        )
+    }
+}
+
+impl<M, Ev> ConvertingMediatorBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener for the events convertible into `SubEv` to the [`BasicBuilder`].
+    ///
+    fn add_converting_listener<SubEv>(self, f: impl Fn(SubEv) + Send + Sync + 'static) -> Self
+    where
+        Ev: Clone + TryInto<SubEv>,
+    {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| {
+                if let Ok(ev) = ev.clone().try_into() {
+                    f(ev)
+                }
+            }),
+        );
+        self
+    }
+}
+
+impl<M, Ev> PriorityMediatorBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener with a `priority` to the [`BasicBuilder`].
+    ///
+    /// Listeners are invoked in descending priority.
+    ///
+    fn add_listener_with_priority(self, priority: i32, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add_with_priority(
+            type_name_of_val(&f),
+            priority,
+            ExclusiveListener::new(f),
+        );
+        self
+    }
+}
+
+impl<M, Ev> HandlerMediatorBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined notification handler to the [`BasicBuilder`].
+    ///
+    /// The handler is invoked like any other listener.
+    ///
+    fn add_handler(self, handler: Arc<dyn NotificationHandler<Ev>>) -> Self
+    where
+        Ev: 'static,
+    {
+        self.mediator.listener.add(
+            handler.name(),
+            ExclusiveListener::new(move |ev: &Ev| handler.on_event(ev)),
+        );
+        self
+    }
+}
+
+impl<M, Ev> ObserverMediatorBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a [`MediatorObserver`] to the [`BasicBuilder`].
+    ///
+    /// Observers are notified in the order they were added.
+    ///
+    fn with_observer(mut self, observer: impl MediatorObserver<Ev> + 'static) -> Self {
+        self.mediator.observer.add(observer);
+        self
+    }
+}
+
+impl<M, Ev> RequestHookBuilderInterface<M> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a hook invoked before every request sent to the [`BasicBuilder`].
+    ///
+    fn on_before_send(mut self, f: impl Fn(&RequestMeta) + Send + Sync + 'static) -> Self {
+        self.mediator.observer.before_send(f);
+        self
+    }
+
+    /// Adds a hook invoked after every request sent to the [`BasicBuilder`].
+    ///
+    fn on_after_send(mut self, f: impl Fn(&RequestMeta, Duration) + Send + Sync + 'static) -> Self {
+        self.mediator.observer.after_send(f);
+        self
+    }
+}
+
+#[cfg(feature = "causality")]
+impl<M, Ev> CausalityBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Records the flows of requests, events and listeners of the [`BasicBuilder`].
+    ///
+    fn record_causality(mut self) -> Self
+    where
+        Ev: Debug,
+    {
+        self.mediator.observer.causality = Some(Arc::new(CausalityRecorder::new()));
+        self
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<M, Ev> LoggingMediatorBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    /// Adds a [`LoggingListener`] to the [`BasicBuilder`].
+    ///
+    fn add_logging_listener(self, listener: impl Into<LoggingListener<Ev>>) -> Self {
+        let listener = listener.into();
+        self.mediator.listener.add(
+            type_name_of_val(&listener),
+            ExclusiveListener::new(move |ev: &Ev| listener.log(&Enveloped::current(ev))),
+        );
+        self
+    }
+}
+
+#[cfg(feature = "persistence")]
+impl<M, Ev> UpcasterBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds an upcaster for events of the version `from_version` to the [`BasicBuilder`].
+    ///
+    fn register_upcaster(
+        mut self,
+        from_version: u32,
+        f: impl Fn(Value) -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        self.mediator.upcasters.register(from_version, f);
+        self
+    }
+}
+
+impl<M, Ev> ReplayMediatorBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the capacity of the replay buffer of the [`BasicBuilder`].
+    ///
+    /// A capacity of `0` disables the replay buffer.
+    ///
+    fn with_replay_capacity(mut self, capacity: usize) -> Self
+    where
+        Ev: Clone,
+    {
+        self.mediator.replay = (capacity > 0).then(|| ReplayBuffer::new(capacity));
+        self
+    }
+}
+
+impl<M, Ev> DeadLetterMediatorBuilderInterface<M> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Enables or disables the dead-letter queue of the [`BasicBuilder`].
+    ///
+    fn collect_dead_letters(mut self, collect: bool) -> Self {
+        self.mediator.dead_letters = collect.then(EventChannel::default);
+        self
+    }
+}
+
+impl<M, Ev> SharedListenerBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener receiving the event in an [`Arc`] to the [`BasicBuilder`].
+    ///
+    fn add_shared_listener(mut self, f: impl SharedListener<Ev>) -> Self
+    where
+        Ev: Clone,
+    {
+        self.mediator.shared_listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Arc<Ev>| f(ev.clone())),
+        );
+        self.mediator.recover = Some(recover::<Ev>);
+        self
+    }
+}
+
+impl<M, Ev> EnvelopedMediatorBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener receiving the event as [`Enveloped`] to the [`BasicBuilder`].
+    ///
+    fn add_enveloped_listener(
+        self,
+        f: impl Fn(&Enveloped<'_, Ev>) + Send + Sync + 'static,
+    ) -> Self {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| f(&Enveloped::current(ev))),
+        );
+        self
+    }
+}
+
+impl<M, Ev> CatchAllMediatorBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener receiving every event along with its [`EventMeta`] to the [`BasicBuilder`].
+    ///
+    fn add_catch_all_listener(
+        mut self,
+        f: impl Fn(&Ev, &EventMeta) + Send + Sync + 'static,
+    ) -> Self {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| f(ev, &EventMeta::current())),
+        );
+        self.mediator.catch_all = true;
+        self
+    }
+}
+
+impl<M, Ev> InterceptorBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined [`PublishInterceptor`] to the [`BasicBuilder`].
+    ///
+    fn add_publish_interceptor(mut self, f: impl PublishInterceptor<Ev>) -> Self {
+        self.mediator.interceptors.push(Box::new(f));
+        self
+    }
+}
+
+impl<M, Ev> RequestEventsBuilderInterface<M, SystemEvent<Ev>> for BasicBuilder<SystemEvent<Ev>>
+where
+    Ev: MaybeDebug,
+{
+    /// Enables or disables the request events of the [`BasicBuilder`].
+    ///
+    fn with_request_events(mut self, enabled: bool) -> Self {
+        self.mediator.request_events = enabled.then_some(SystemEvent::request as RequestEvent<_>);
+        self
+    }
+}
+
+impl<Ev> DynamicHandlerBuilderInterface<BasicMediator<Ev>, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    /// Registers a handler for requests of type `Req` with the [`BasicBuilder`].
+    ///
+    fn register_handler<Req>(
+        mut self,
+        handler: impl Fn(&BasicMediator<Ev>, Req) + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+    {
+        self.mediator
+            .handlers
+            .insert::<Req>(erase(move |m: &BasicMediator<Ev>, req: Req| {
+                m.run_request("send_dyn", req, |req| handler(m, req))
+            }));
+        self
+    }
+}
+
+impl<Ev> ClosureHandlerBuilderInterface<BasicMediator<Ev>, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    /// Adds a closure handling requests of type `Req` to the [`BasicBuilder`].
+    ///
+    fn add_request_handler<Req, Resp>(
+        mut self,
+        handler: impl Fn(&BasicMediator<Ev>, Req) -> Resp + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Resp: 'static,
+    {
+        self.mediator.closures.insert_closure(Box::new(handler));
+        self
+    }
+
+    /// Adds a closure handling requests of type `Req` together with others to the [`BasicBuilder`].
+    ///
+    fn add_fan_out_handler<Req>(
+        mut self,
+        handler: impl Fn(&BasicMediator<Ev>, &Req) + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+    {
+        self.mediator
+            .fan_out
+            .push_fan_out::<Req, FanOutHandler<BasicMediator<Ev>, Req>>(Box::new(handler));
+        self
+    }
+}
+
+impl<Ev> StandaloneHandlerBuilderInterface<BasicMediator<Ev>, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    /// Registers a [`Handler`] handling requests of type `Req` with the [`BasicBuilder`].
+    ///
+    fn register<Req, Resp>(
+        mut self,
+        handler: impl Handler<Req, Ev, Resp> + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Resp: 'static,
+    {
+        self.mediator
+            .closures
+            .insert_closure(Box::new(move |m: &BasicMediator<Ev>, req: Req| {
+                handler.handle(m, req)
+            }));
+        self
+    }
+}
+
+impl<M, Ev> ChannelBackendBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Creates the channel of the [`BasicBuilder`] through the [`ChannelFlavor`] `F`.
+    ///
+    fn with_channel_backend<F: ChannelFlavor>(mut self) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        self.mediator.channel.set_flavor::<F>();
+        self
+    }
+}
+
+impl<M, Ev> ProfileBuilderInterface<M, Ev> for BasicBuilder<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Configures the channel of the [`BasicBuilder`] from the [`Profile`].
     ///
-    fn add_listener(mut self, f: impl Listener<Ev>) -> Self {
-        self.mediator.listener.push(Box::new(f));
+    fn with_profile(mut self, profile: Profile) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        profile.configure(&mut self.mediator.channel);
         self
     }
 }
 
 impl<Ev> BasicBuilder<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Adds a user-defined listener to the [`BasicBuilder`].
     ///
@@ -66,7 +615,7 @@ where
     /// besides being `'static`.
     ///
     /// As a side note, here, `Ev` is the user-defined event type
-    /// that must be [`Debug`].
+    /// that must be [`MaybeDebug`].
     ///
     /// # Examples
     ///
@@ -90,11 +639,975 @@ where
     pub fn add_listener(self, f: impl Listener<Ev>) -> Self {
         <Self as BasicMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::add_listener(self, f)
     }
+
+    /// Adds a user-defined listener with a `name` to the [`BasicBuilder`].
+    ///
+    /// Same as [`BasicBuilder::add_listener()`], but the listener is identified by the `name`
+    /// instead of its type name, which is the same for every closure of a module.
+    /// The name shows up in the [`ListenerStats`](crate::stats::ListenerStats),
+    /// in the tracing span of every invocation with the `tracing` feature,
+    /// in every [`ListenerPanic`](crate::listener::ListenerPanic)
+    /// and in the [`DeadLetter`](crate::deadletter::DeadLetter) of an event the listener panicked on.
+    /// This tells which of many listeners is slow or failing.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_listener_named("audit-log", |ev: &MyEvent| {
+    ///         /* Your listening logic */
+    ///     })
+    ///     .build();
+    ///
+    /// mediator.publish(MyEvent::One);
+    /// mediator.next().ok();
+    ///
+    /// let stats = mediator.stats();
+    /// assert_eq!(stats.listeners()[0].name(), "audit-log");
+    /// assert_eq!(stats.listeners()[0].invocations(), 1);
+    ///
+    pub fn add_listener_named(self, name: &'static str, f: impl Listener<Ev>) -> Self {
+        <Self as NamedListenerBuilderInterface<BasicMediator<Ev>, Ev>>::add_listener_named(
+            self, name, f,
+        )
+    }
+
+    /// Adds a user-defined [`Behavior`] to the [`BasicBuilder`].
+    ///
+    /// Behaviors wrap every request sent to the mediator
+    /// in the order they were added.
+    /// A behavior may short-circuit a request by not calling [`crate::behavior::Next::run()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// struct Logging;
+    ///
+    /// impl Behavior for Logging {
+    ///     fn handle(&self, req: AnyRequest, next: Next<'_>) {
+    ///         println!("Handling {}", req.type_name());
+    ///         next.run(req);
+    ///     }
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_behavior(Logging)
+    ///     .build();
+    ///
+    pub fn add_behavior(self, b: impl Behavior) -> Self {
+        <Self as BehaviorMediatorBuilderInterface<BasicMediator<Ev>>>::add_behavior(self, b)
+    }
+
+    /// Bounds the channel of the [`BasicBuilder`] to `capacity` events.
+    ///
+    /// By default, the channel is unbounded.
+    /// Once bounded, publishing to a full channel is handled
+    /// according to the [`OverflowPolicy`], which defaults to
+    /// [`OverflowPolicy::Block`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .with_capacity(16)
+    ///     .with_overflow_policy(OverflowPolicy::DropOldest)
+    ///     .build();
+    ///
+    pub fn with_capacity(self, capacity: usize) -> Self {
+        <Self as ChannelMediatorBuilderInterface<BasicMediator<Ev>>>::with_capacity(self, capacity)
+    }
+
+    /// Sets the [`OverflowPolicy`] of the [`BasicBuilder`].
+    ///
+    /// The policy only takes effect if the channel was bounded
+    /// with [`BasicBuilder::with_capacity()`].
+    ///
+    pub fn with_overflow_policy(self, policy: OverflowPolicy) -> Self {
+        <Self as ChannelMediatorBuilderInterface<BasicMediator<Ev>>>::with_overflow_policy(
+            self, policy,
+        )
+    }
+    #[cfg(feature = "std")]
+    /// Enables or disables listener isolation of the [`BasicBuilder`].
+    ///
+    /// By default, a panicking listener unwinds through [`BasicMediator::next()`]
+    /// and the remaining listeners are not invoked for that event.
+    /// With isolation, each listener invocation is wrapped in [`std::panic::catch_unwind()`],
+    /// so all listeners are invoked and the caught panics are returned
+    /// in [`crate::listener::NextError::ListenerPanicked`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .isolate_listeners(true)
+    ///     .build();
+    ///
+    pub fn isolate_listeners(self, isolate: bool) -> Self {
+        <Self as ListenerIsolationBuilderInterface<BasicMediator<Ev>>>::isolate_listeners(
+            self, isolate,
+        )
+    }
+    #[cfg(feature = "std")]
+    /// Sets the [`RateLimit`] of the [`BasicBuilder`].
+    ///
+    /// At most as many events as the limit allows are delivered to the listeners
+    /// within its time window. Events exceeding the limit are queued, dropped or coalesced,
+    /// depending on the [`ThrottlePolicy`](crate::ratelimit::ThrottlePolicy).
+    /// Dropped events become dead letters.
+    ///
+    /// See [`RateLimit`] for an example.
+    ///
+    pub fn with_rate_limit(self, limit: RateLimit) -> Self {
+        <Self as RateLimitBuilderInterface<BasicMediator<Ev>>>::with_rate_limit(self, limit)
+    }
+    /// Adds a user-defined listener for a topic to the [`BasicBuilder`].
+    ///
+    /// The listener only receives events published with [`BasicMediator::publish_to()`]
+    /// to a topic matching the [`TopicPattern`] `topic`.
+    /// Events published with [`BasicMediator::publish()`] are not received.
+    /// Listeners added with [`BasicBuilder::add_listener()`] still receive every event.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_listener_for_topic("orders.#", |_: &MyEvent| {
+    ///         /* Your listening logic */
+    ///     })
+    ///     .build();
+    ///
+    pub fn add_listener_for_topic(self, topic: &str, f: impl Listener<Ev>) -> Self {
+        <Self as TopicMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::add_listener_for_topic(
+            self, topic, f,
+        )
+    }
+
+    /// Adds a user-defined listener guarded by a [`Filter`] to the [`BasicBuilder`].
+    ///
+    /// The listener is only invoked if `filter` returns `true` for an event.
+    /// This keeps the matching logic out of the listener itself.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_filtered_listener(
+    ///         |ev: &MyEvent| matches!(ev, MyEvent::One),
+    ///         |_: &MyEvent| {
+    ///             /* Only invoked for MyEvent::One */
+    ///         },
+    ///     )
+    ///     .build();
+    ///
+    pub fn add_filtered_listener(self, filter: impl Filter<Ev>, f: impl Listener<Ev>) -> Self {
+        <Self as FilterMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::add_filtered_listener(
+            self, filter, f,
+        )
+    }
+
+    /// Adds a user-defined listener for events with certain variant names to the [`BasicBuilder`].
+    ///
+    /// The listener is only invoked for events whose variant name is one of `variants`.
+    /// The variant name is obtained by converting a reference to the event into a `&'static str`,
+    /// e.g. through `#[derive(strum::IntoStaticStr)]` on the event enum.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// // Usually derived with strum::IntoStaticStr.
+    /// impl From<&MyEvent> for &'static str {
+    ///     fn from(ev: &MyEvent) -> Self {
+    ///         match ev {
+    ///             MyEvent::One => "One",
+    ///             MyEvent::Two => "Two",
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_listener_for_variants(["Two"], |_: &MyEvent| {
+    ///         /* Only invoked for MyEvent::Two */
+    ///     })
+    ///     .build();
+    ///
+    pub fn add_listener_for_variants(
+        self,
+        variants: impl IntoIterator<Item = &'static str>,
+        f: impl Listener<Ev>,
+    ) -> Self
+    where
+        for<'a> &'a Ev: Into<&'static str>,
+    {
+        <Self as VariantMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::add_listener_for_variants(
+            self, variants, f,
+        )
+    }
+
+    /// Adds a user-defined listener for the events convertible into `SubEv` to the [`BasicBuilder`].
+    ///
+    /// Every event is cloned and converted via [`TryInto`],
+    /// and the listener is only invoked with the events that convert successfully.
+    /// This way, a listener only deals with the sub-enum or struct it is interested in,
+    /// instead of matching on the whole event enum.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyEvent {
+    ///     Order(OrderEvent),
+    ///     UserSignedUp(u32)
+    /// }
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// enum OrderEvent {
+    ///     Placed(u32),
+    ///     Shipped(u32)
+    /// }
+    ///
+    /// impl TryFrom<MyEvent> for OrderEvent {
+    ///     type Error = MyEvent;
+    ///
+    ///     fn try_from(ev: MyEvent) -> Result<Self, Self::Error> {
+    ///         match ev {
+    ///             MyEvent::Order(ev) => Ok(ev),
+    ///             ev => Err(ev),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let orders = Arc::new(Mutex::new(Vec::new()));
+    /// let orders_clone = orders.clone();
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_converting_listener(move |ev: OrderEvent| {
+    ///         orders_clone.lock().unwrap().push(ev);
+    ///     })
+    ///     .build();
+    ///
+    /// mediator.publish(MyEvent::Order(OrderEvent::Placed(1)));
+    /// mediator.publish(MyEvent::UserSignedUp(7));
+    /// mediator.publish(MyEvent::Order(OrderEvent::Shipped(1)));
+    /// mediator.next_all();
+    ///
+    /// assert_eq!(*orders.lock().unwrap(), [OrderEvent::Placed(1), OrderEvent::Shipped(1)]);
+    ///
+    pub fn add_converting_listener<SubEv>(self, f: impl Fn(SubEv) + Send + Sync + 'static) -> Self
+    where
+        Ev: Clone + TryInto<SubEv>,
+    {
+        <Self as ConvertingMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::add_converting_listener(
+            self, f,
+        )
+    }
+
+    /// Adds a user-defined listener with a `priority` to the [`BasicBuilder`].
+    ///
+    /// During [`BasicMediator::next()`], listeners are invoked in descending priority.
+    /// Listeners with equal priority are invoked in the order they were added.
+    /// Listeners added without a priority, e.g. via [`BasicBuilder::add_listener()`],
+    /// have a priority of `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_listener(|_: &MyEvent| {
+    ///         /* Refresh the UI */
+    ///     })
+    ///     .add_listener_with_priority(10, |_: &MyEvent| {
+    ///         /* Invalidate the cache first */
+    ///     })
+    ///     .build();
+    ///
+    pub fn add_listener_with_priority(self, priority: i32, f: impl Listener<Ev>) -> Self {
+        <Self as PriorityMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::add_listener_with_priority(
+            self, priority, f,
+        )
+    }
+
+    /// Adds a user-defined notification handler to the [`BasicBuilder`].
+    ///
+    /// Other than [`BasicBuilder::add_listener()`], this accepts any type implementing
+    /// [`NotificationHandler`], including stateful handlers shared behind an [`Arc`].
+    /// Closures are notification handlers as well.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    /// use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Counter(AtomicUsize);
+    ///
+    /// impl NotificationHandler<MyEvent> for Counter {
+    ///     fn on_event(&self, _: &MyEvent) {
+    ///         self.0.fetch_add(1, Ordering::Relaxed);
+    ///     }
+    /// }
+    ///
+    /// let counter = Arc::new(Counter::default());
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_handler(counter.clone())
+    ///     .add_handler(Arc::new(|_: &MyEvent| {
+    ///         /* Your listening logic */
+    ///     }))
+    ///     .build();
+    ///
+    /// mediator.publish(MyEvent::One);
+    /// mediator.next().ok();
+    ///
+    /// assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+    /// ```
+    ///
+    pub fn add_handler(self, handler: Arc<dyn NotificationHandler<Ev>>) -> Self
+    where
+        Ev: 'static,
+    {
+        <Self as HandlerMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::add_handler(self, handler)
+    }
+
+    /// Adds a [`MediatorObserver`] to the [`BasicBuilder`].
+    ///
+    /// The observer is notified when a request starts and finishes,
+    /// when an event is published and after each listener invocation,
+    /// which allows to export metrics such as counters and histograms.
+    /// Multiple observers can be added, they are notified in the order they were added.
+    /// An [`Arc`](std::sync::Arc) of an observer is an observer as well,
+    /// so the observer can be kept to read its metrics.
+    ///
+    /// See [`MediatorObserver`] for an example.
+    ///
+    pub fn with_observer(self, observer: impl MediatorObserver<Ev> + 'static) -> Self {
+        <Self as ObserverMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::with_observer(
+            self, observer,
+        )
+    }
+
+    /// Adds a hook invoked before every request sent to the [`BasicBuilder`].
+    ///
+    /// The hook receives the [`RequestMeta`] of the request, i.e. its type name,
+    /// the method it was sent with and its [`CorrelationId`](crate::correlation::CorrelationId), if any.
+    /// Unlike a [`Behavior`], a hook can't alter or short-circuit the request,
+    /// which makes it a lightweight alternative for logging and profiling.
+    /// Multiple hooks can be added, they are invoked in the order they were added.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One
+    /// }
+    ///
+    /// struct Request;
+    ///
+    /// impl RequestHandler<Request, MyEvent> for BasicMediator<MyEvent> {
+    ///     fn handle(&self, _req: Request) {
+    ///         self.publish(MyEvent::One);
+    ///     }
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .on_before_send(|meta: &RequestMeta| {
+    ///         println!("{} via {}", meta.request(), meta.op());
+    ///     })
+    ///     .on_after_send(|meta: &RequestMeta, elapsed: Duration| {
+    ///         println!("{} took {:?}", meta.request(), elapsed);
+    ///     })
+    ///     .build();
+    ///
+    /// mediator.send(Request);
+    ///
+    pub fn on_before_send(self, f: impl Fn(&RequestMeta) + Send + Sync + 'static) -> Self {
+        <Self as RequestHookBuilderInterface<BasicMediator<Ev>>>::on_before_send(self, f)
+    }
+
+    /// Adds a hook invoked after every request sent to the [`BasicBuilder`].
+    ///
+    /// Besides the [`RequestMeta`], the hook receives how long the request took.
+    /// Without the `std` feature, the duration is always zero.
+    ///
+    /// See [`BasicBuilder::on_before_send()`] for an example.
+    ///
+    pub fn on_after_send(self, f: impl Fn(&RequestMeta, Duration) + Send + Sync + 'static) -> Self {
+        <Self as RequestHookBuilderInterface<BasicMediator<Ev>>>::on_after_send(self, f)
+    }
+
+    /// Records the flows of requests, events and listeners of the [`BasicBuilder`].
+    ///
+    /// Every published event is linked to the request handler or listener it was published from,
+    /// and every listener to the events it was invoked for.
+    /// The recorded graph is returned by `causality_graph()` of the mediator
+    /// and can be exported to DOT or JSON.
+    /// Events are labeled with their [`Debug`] representation.
+    ///
+    /// See [`CausalityGraph`](crate::causality::CausalityGraph) for an example.
+    ///
+    #[cfg(feature = "causality")]
+    pub fn record_causality(self) -> Self
+    where
+        Ev: Debug,
+    {
+        <Self as CausalityBuilderInterface<BasicMediator<Ev>, Ev>>::record_causality(self)
+    }
+
+    /// Sets the capacity of the replay buffer of the [`BasicBuilder`].
+    ///
+    /// The replay buffer holds the last `capacity` processed events,
+    /// which are replayed to listeners subscribed via
+    /// [`BasicMediator::add_listener_with_replay()`].
+    /// Once full, the oldest event is dropped for each new one.
+    /// Recording events requires them to be [`Clone`].
+    /// By default, there is no replay buffer, a capacity of `0` disables it as well.
+    ///
+    /// See [`BasicMediator::add_listener_with_replay()`] for an example.
+    ///
+    pub fn with_replay_capacity(self, capacity: usize) -> Self
+    where
+        Ev: Clone,
+    {
+        <Self as ReplayMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::with_replay_capacity(
+            self, capacity,
+        )
+    }
+
+    /// Enables or disables the dead-letter queue of the [`BasicBuilder`].
+    ///
+    /// If enabled, events which could not be processed are pushed into
+    /// a dead-letter queue instead of vanishing, see [`BasicMediator::dead_letters()`].
+    /// The queue is unbounded, so make sure to take the dead letters out regularly.
+    /// By default, the dead-letter queue is disabled.
+    ///
+    pub fn collect_dead_letters(self, collect: bool) -> Self {
+        <Self as DeadLetterMediatorBuilderInterface<BasicMediator<Ev>>>::collect_dead_letters(
+            self, collect,
+        )
+    }
+
+    /// Adds a user-defined listener receiving the event as [`Enveloped`] to the [`BasicBuilder`].
+    ///
+    /// The listener is invoked like any other listener, but besides the event,
+    /// it has access to the [`crate::correlation::CorrelationId`] of the request
+    /// the event originates from, see [`BasicMediator::send_traced()`].
+    /// This makes it possible to tie events back to their request, e.g. in logs.
+    ///
+    /// The event's metadata, namely its sequence number, the time it was published
+    /// and the type name of the request whose handler published it,
+    /// help to audit events and to debug their ordering.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_enveloped_listener(|ev: &Enveloped<'_, MyEvent>| {
+    ///         if let Some(id) = ev.correlation_id() {
+    ///             println!("{id}: {:?}", ev.event());
+    ///         }
+    ///     })
+    ///     .build();
+    ///
+    pub fn add_enveloped_listener(
+        self,
+        f: impl Fn(&Enveloped<'_, Ev>) + Send + Sync + 'static,
+    ) -> Self {
+        <Self as EnvelopedMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::add_enveloped_listener(
+            self, f,
+        )
+    }
+
+    /// Adds a user-defined listener receiving every event along with its [`EventMeta`] to the [`BasicBuilder`].
+    ///
+    /// The listener fires for every event the mediator processes, with or without a topic,
+    /// as it isn't subject to any filter or topic pattern.
+    /// The [`EventMeta`] holds the event's sequence number, the time it was published
+    /// and the topic it was published to, which makes the listener
+    /// a natural fit for audit logs and debugging.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_catch_all_listener(|ev: &MyEvent, meta: &EventMeta| {
+    ///         println!("{:?} {:?}: {ev:?}", meta.sequence(), meta.topic());
+    ///     })
+    ///     .build();
+    ///
+    pub fn add_catch_all_listener(
+        self,
+        f: impl Fn(&Ev, &EventMeta) + Send + Sync + 'static,
+    ) -> Self {
+        <Self as CatchAllMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::add_catch_all_listener(
+            self, f,
+        )
+    }
+
+    /// Adds a user-defined [`PublishInterceptor`] to the [`BasicBuilder`].
+    ///
+    /// Every published event is passed to the interceptor before it is enqueued,
+    /// which returns the event to enqueue instead, e.g. with personal data redacted,
+    /// or `None` to drop it. Dropped events never reach the channel, the listeners
+    /// or the observers. Interceptors run in the order they were added,
+    /// each receiving the event returned by the one before.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum MyEvent {
+    ///     SignedUp { email: String },
+    ///     Heartbeat
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_publish_interceptor(|ev: MyEvent| match ev {
+    ///         MyEvent::SignedUp { .. } => Some(MyEvent::SignedUp { email: "***".into() }),
+    ///         MyEvent::Heartbeat => None
+    ///     })
+    ///     .add_listener(|ev: &MyEvent| {
+    ///         assert_eq!(ev, &MyEvent::SignedUp { email: "***".into() });
+    ///     })
+    ///     .build();
+    ///
+    /// mediator.publish(MyEvent::SignedUp { email: "jane@example.com".into() });
+    /// mediator.publish(MyEvent::Heartbeat);
+    /// assert_eq!(mediator.next_all(), 1);
+    ///
+    pub fn add_publish_interceptor(self, f: impl PublishInterceptor<Ev>) -> Self {
+        <Self as InterceptorBuilderInterface<BasicMediator<Ev>, Ev>>::add_publish_interceptor(
+            self, f,
+        )
+    }
+
+    /// Adds a [`LoggingListener`] to the [`BasicBuilder`].
+    ///
+    /// Passing a [`tracing::Level`] logs the [`Debug`](core::fmt::Debug) representation
+    /// of every event at that level, pass a [`LoggingListener`] to customize the message.
+    ///
+    /// See [`LoggingListener`] for an example.
+    ///
+    #[cfg(feature = "tracing")]
+    pub fn add_logging_listener(self, listener: impl Into<LoggingListener<Ev>>) -> Self
+    where
+        Ev: 'static,
+    {
+        <Self as LoggingMediatorBuilderInterface<BasicMediator<Ev>, Ev>>::add_logging_listener(
+            self, listener,
+        )
+    }
+
+    /// Adds an upcaster for events of the version `from_version` to the [`BasicBuilder`].
+    ///
+    /// When replaying a journal with [`BasicMediator::replay_from()`],
+    /// events written with `from_version` are handed to the upcaster as JSON,
+    /// which turns them into the current event `Ev`.
+    /// This keeps journals loadable after the event type changed shape.
+    ///
+    /// See [`Upcasters`](crate::persistence::Upcasters) for an example.
+    ///
+    #[cfg(feature = "persistence")]
+    pub fn register_upcaster(
+        self,
+        from_version: u32,
+        f: impl Fn(Value) -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        <Self as UpcasterBuilderInterface<BasicMediator<Ev>, Ev>>::register_upcaster(
+            self,
+            from_version,
+            f,
+        )
+    }
+
+    /// Adds a user-defined listener receiving the event in an [`Arc`] to the [`BasicBuilder`].
+    ///
+    /// Unlike a [`Listener`], which only borrows the event, a [`SharedListener`]
+    /// can keep it, e.g. to hand it to another thread, without cloning the event.
+    /// Shared listeners are invoked after all other listeners.
+    /// Afterwards, the event is taken back out of the [`Arc`],
+    /// so it is only cloned if a listener kept a reference.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    /// use std::sync::{mpsc, Arc};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let (tx, rx) = mpsc::channel::<Arc<MyEvent>>();
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .add_shared_listener(move |ev: Arc<MyEvent>| {
+    ///         tx.send(ev).ok();
+    ///     })
+    ///     .build();
+    ///
+    /// mediator.publish(MyEvent::One);
+    /// mediator.next().ok();
+    ///
+    /// assert!(matches!(*rx.recv().unwrap(), MyEvent::One));
+    ///
+    pub fn add_shared_listener(self, f: impl SharedListener<Ev>) -> Self
+    where
+        Ev: Clone,
+    {
+        <Self as SharedListenerBuilderInterface<BasicMediator<Ev>, Ev>>::add_shared_listener(
+            self, f,
+        )
+    }
+
+    /// Registers a handler for requests of type `Req` with the [`BasicBuilder`].
+    ///
+    /// Unlike a [`RequestHandler`](super::RequestHandler) implementation,
+    /// the handler is looked up at runtime by the type of the request,
+    /// which is sent via [`BasicMediator::send_dyn()`].
+    /// Registering another handler for the same type replaces the previous one.
+    ///
+    /// See [`BasicMediator::send_dyn()`] for an example.
+    ///
+    pub fn register_handler<Req>(
+        self,
+        handler: impl Fn(&BasicMediator<Ev>, Req) + Send + Sync + 'static,
+    ) -> Self
+    where
+        Ev: 'static,
+        Req: Send + 'static,
+    {
+        <Self as DynamicHandlerBuilderInterface<BasicMediator<Ev>, Ev>>::register_handler(
+            self, handler,
+        )
+    }
+
+    /// Adds a closure handling requests of type `Req` to the [`BasicBuilder`].
+    ///
+    /// Unlike a [`RequestHandler`](super::RequestHandler) implementation,
+    /// no trait has to be implemented on the mediator, which is handy for small apps.
+    /// The closure may return a response of type `Resp`.
+    /// Requests are sent to it via [`BasicMediator::send_registered()`],
+    /// or [`BasicMediator::send_registered_and_receive()`] to receive the response.
+    /// Adding another closure for the same request and response type replaces the previous one.
+    ///
+    /// See [`BasicMediator::send_registered()`] for an example.
+    ///
+    pub fn add_request_handler<Req, Resp>(
+        self,
+        handler: impl Fn(&BasicMediator<Ev>, Req) -> Resp + Send + Sync + 'static,
+    ) -> Self
+    where
+        Ev: 'static,
+        Req: Send + 'static,
+        Resp: 'static,
+    {
+        <Self as ClosureHandlerBuilderInterface<BasicMediator<Ev>, Ev>>::add_request_handler(
+            self, handler,
+        )
+    }
+
+    /// Adds a closure handling requests of type `Req` together with others to the [`BasicBuilder`].
+    ///
+    /// All closures added for a request type are invoked by [`BasicMediator::send_fan_out()`],
+    /// in the order they were added, mirroring how listeners handle events.
+    ///
+    /// See [`BasicMediator::send_fan_out()`] for an example.
+    ///
+    pub fn add_fan_out_handler<Req>(
+        self,
+        handler: impl Fn(&BasicMediator<Ev>, &Req) + Send + Sync + 'static,
+    ) -> Self
+    where
+        Ev: 'static,
+        Req: Send + 'static,
+    {
+        <Self as ClosureHandlerBuilderInterface<BasicMediator<Ev>, Ev>>::add_fan_out_handler(
+            self, handler,
+        )
+    }
+
+    /// Registers a [`Handler`] handling requests of type `Req` with the [`BasicBuilder`].
+    ///
+    /// Unlike a [`RequestHandler`](super::RequestHandler) implementation,
+    /// the handler is a standalone struct, created by the user together with its dependencies,
+    /// e.g. a repository or a client, instead of reaching them through the mediator.
+    /// The handler may return a response of type `Resp`.
+    /// Requests are routed to it via [`BasicMediator::send_registered()`],
+    /// or [`BasicMediator::send_registered_and_receive()`] to receive the response.
+    /// Registering another handler for the same request and response type replaces the previous one.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::basic::*;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     UserCreated(u32)
+    /// }
+    ///
+    /// struct CreateUser(String);
+    ///
+    /// #[derive(Default)]
+    /// struct UserRepository {
+    ///     users: Mutex<Vec<String>>,
+    /// }
+    ///
+    /// struct CreateUserHandler {
+    ///     repository: Arc<UserRepository>,
+    /// }
+    ///
+    /// impl CreateUserHandler {
+    ///     fn new(repository: Arc<UserRepository>) -> Self {
+    ///         Self { repository }
+    ///     }
+    /// }
+    ///
+    /// impl Handler<CreateUser, MyEvent, u32> for CreateUserHandler {
+    ///     fn handle(&self, mediator: &BasicMediator<MyEvent>, req: CreateUser) -> u32 {
+    ///         let mut users = self.repository.users.lock().unwrap();
+    ///         users.push(req.0);
+    ///         let id = users.len() as u32;
+    ///         mediator.publish(MyEvent::UserCreated(id));
+    ///         id
+    ///     }
+    /// }
+    ///
+    /// let repository = Arc::new(UserRepository::default());
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .register(CreateUserHandler::new(repository.clone()))
+    ///     .build();
+    ///
+    /// let id = mediator.send_registered_and_receive::<_, u32>(CreateUser("ferris".into()));
+    /// assert_eq!(id.ok(), Some(1));
+    /// assert_eq!(mediator.next_all(), 1);
+    /// assert_eq!(repository.users.lock().unwrap().len(), 1);
+    ///
+    pub fn register<Req, Resp>(
+        self,
+        handler: impl Handler<Req, Ev, Resp> + Send + Sync + 'static,
+    ) -> Self
+    where
+        Ev: 'static,
+        Req: Send + 'static,
+        Resp: 'static,
+    {
+        <Self as StandaloneHandlerBuilderInterface<BasicMediator<Ev>, Ev>>::register(self, handler)
+    }
+
+    /// Creates the channel of the [`BasicBuilder`] through the [`ChannelFlavor`] `F`.
+    ///
+    /// By default, the channel is one of `std::sync::mpsc`.
+    /// Other backends trade differently between throughput, latency and fairness,
+    /// see the flavors in [`crate::channel`].
+    /// The capacity and [`OverflowPolicy`] apply to every backend,
+    /// no matter in which order they are configured.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use mediatrix::synchronous::basic::*;
+    /// use mediatrix::channel::StdChannel;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// let mediator = BasicMediator::<MyEvent>::builder()
+    ///     .with_channel_backend::<StdChannel>()
+    ///     .with_capacity(16)
+    ///     .build();
+    /// # }
+    ///
+    pub fn with_channel_backend<F: ChannelFlavor>(self) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        <Self as ChannelBackendBuilderInterface<BasicMediator<Ev>, Ev>>::with_channel_backend::<F>(
+            self,
+        )
+    }
+
+    /// Configures the channel of the [`BasicBuilder`] from the [`Profile`]:
+    /// its backend, capacity and [`OverflowPolicy`].
+    ///
+    /// This gives sane defaults for common use cases in one call.
+    /// Options configured afterwards override the ones of the profile.
+    ///
+    /// See [`Profile`] for an example.
+    ///
+    pub fn with_profile(self, profile: Profile) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        <Self as ProfileBuilderInterface<BasicMediator<Ev>, Ev>>::with_profile(self, profile)
+    }
+}
+
+impl<Ev> BasicBuilder<SystemEvent<Ev>>
+where
+    Ev: MaybeDebug,
+{
+    /// Publishes a [`SystemEvent::RequestCompleted`] after every handled request
+    /// and a [`SystemEvent::RequestFailed`] after every request that failed with `try_send()`,
+    /// if `enabled`. Disabled by default.
+    ///
+    /// The request event is published after the handler returned, so it follows the events
+    /// published by the handler. Requests are identified by their type name.
+    ///
+    /// See [`SystemEvent`] for an example.
+    ///
+    pub fn with_request_events(self, enabled: bool) -> Self {
+        <Self as RequestEventsBuilderInterface<BasicMediator<SystemEvent<Ev>>, SystemEvent<Ev>>>::with_request_events(
+            self, enabled,
+        )
+    }
 }
 
 impl<Ev> BuilderFlow<BasicMediator<Ev>> for BasicBuilder<Ev>
 where
-    Ev: Debug,
+    Ev: MaybeDebug,
 {
     /// Builds the [`BasicMediator`] and returns it.
     ///
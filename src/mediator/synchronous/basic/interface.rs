@@ -1,34 +1,464 @@
-use std::{fmt::Debug, sync::mpsc::TryRecvError};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{any::Any, time::Duration};
 
-use crate::mediator::listener::Listener;
+#[cfg(feature = "persistence")]
+use std::{io, path::Path};
+
+#[cfg(feature = "causality")]
+use crate::mediator::causality::CausalityGraph;
+#[cfg(feature = "persistence")]
+use crate::mediator::codec::Codec;
+#[cfg(feature = "tracing")]
+use crate::mediator::logging::LoggingListener;
+#[cfg(feature = "std")]
+use crate::mediator::ratelimit::RateLimit;
+#[cfg(feature = "schema")]
+use crate::mediator::schema::MediatorDescription;
+#[cfg(feature = "causality")]
+use core::fmt::Debug;
+#[cfg(feature = "persistence")]
+use serde_json::Value;
+
+use super::basic::BasicMediator;
+#[cfg(not(feature = "async-only"))]
+use super::worker::EventWorker;
+use crate::mediator::{
+    behavior::Behavior,
+    channel::{ChannelFlavor, OverflowPolicy, TrySendError},
+    correlation::{CorrelationId, Enveloped, EventMeta},
+    deadletter::{DeadLetters, RedeliveryPolicy},
+    listener::{
+        Filter, Listener, ListenerHandle, MaybeDebug, NextError, NotificationHandler,
+        PublishInterceptor, SharedListener,
+    },
+    observer::{MediatorObserver, RequestMeta},
+    profile::Profile,
+    stats::MediatorStats,
+};
 
 /// Publish an event `Ev` from within a handler.
-pub trait SyncMediatorInternal<Ev: Debug> {
+/// Alternatively, try to publish it and get it back if the channel is full,
+/// or publish it to a topic.
+pub trait SyncMediatorInternal<Ev: MaybeDebug> {
     #[allow(missing_docs)]
     fn publish(&self, event: Ev);
+
+    #[allow(missing_docs)]
+    fn try_publish(&self, event: Ev) -> Result<(), TrySendError<Ev>>;
+
+    #[allow(missing_docs)]
+    fn publish_to(&self, topic: &str, event: Ev);
+
+    #[allow(missing_docs)]
+    fn publish_with_ttl(&self, event: Ev, ttl: Duration);
+
+    #[allow(missing_docs)]
+    fn publish_urgent(&self, event: Ev);
+}
+
+/// Publish the events `Ev` recorded in a [`Journal`](crate::persistence::Journal) again,
+/// e.g. to rebuild state in a fresh mediator.
+#[cfg(feature = "persistence")]
+pub trait SyncMediatorInternalJournal<Ev: MaybeDebug> {
+    #[allow(missing_docs)]
+    fn replay_from(&self, path: impl AsRef<Path>) -> io::Result<usize>;
+
+    #[allow(missing_docs)]
+    fn replay_from_with(&self, path: impl AsRef<Path>, codec: impl Codec) -> io::Result<usize>;
 }
 
 /// Send a request `Req` for processing to the mediator.
 /// This will call the handler.
-pub trait SyncMediatorInternalHandle<Ev: Debug> {
+pub trait SyncMediatorInternalHandle<Ev: MaybeDebug> {
     #[allow(missing_docs)]
     fn send<Req>(&self, req: Req)
     where
-        Self: RequestHandler<Req, Ev>;
+        Self: RequestHandler<Req, Ev>,
+        Req: Send + 'static;
+
+    #[allow(missing_docs)]
+    fn send_traced<Req>(&self, req: Req) -> CorrelationId
+    where
+        Self: RequestHandler<Req, Ev>,
+        Req: Send + 'static;
+
+    #[allow(missing_docs)]
+    fn send_and_receive<Req, Resp>(&self, req: Req) -> Resp
+    where
+        Self: RequestHandler<Req, Ev, Resp>;
+
+    #[allow(missing_docs)]
+    fn send_batch<Req>(&self, reqs: Vec<Req>)
+    where
+        Self: BatchRequestHandler<Req, Ev>,
+        Req: Send + 'static;
+
+    #[allow(missing_docs)]
+    fn send_dyn(&self, req: Box<dyn Any + Send>) -> Result<(), Box<dyn Any + Send>>
+    where
+        Self: 'static;
+
+    #[allow(missing_docs)]
+    fn try_send<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Self: TryRequestHandler<Req, Ev, Err>,
+        Req: Send + 'static;
 }
 
 /// Process the next event `Ev` from the channel.
 /// This will call all listeners with a clone of that event.
+/// Alternatively, process all pending events at once
+/// or block until the next event is available.
 pub trait SyncMediatorInternalNext {
     #[allow(missing_docs)]
-    fn next(&self) -> Result<(), TryRecvError>;
+    fn next(&self) -> Result<(), NextError>;
+
+    #[allow(missing_docs)]
+    fn next_all(&self) -> usize;
+
+    #[cfg(feature = "std")]
+    #[allow(missing_docs)]
+    fn next_blocking(&self) -> Result<(), NextError>;
+
+    #[cfg(not(feature = "async-only"))]
+    #[allow(missing_docs)]
+    fn worker(&self) -> EventWorker<'_, Self>
+    where
+        Self: Sized + Sync;
+}
+
+/// Subscribe and unsubscribe listeners on an already built mediator.
+pub trait SyncMediatorInternalSubscribe<Ev: MaybeDebug> {
+    #[allow(missing_docs)]
+    fn subscribe(&self, f: impl Listener<Ev>) -> ListenerHandle;
+
+    #[allow(missing_docs)]
+    fn unsubscribe(&self, handle: ListenerHandle) -> bool;
+
+    #[allow(missing_docs)]
+    fn add_listener_with_replay(&self, f: impl Listener<Ev>) -> ListenerHandle;
+}
+
+/// Take the events that could not be processed out of the dead-letter queue.
+/// Alternatively, redeliver them to the listeners.
+pub trait SyncMediatorInternalDeadLetters<Ev: MaybeDebug> {
+    #[allow(missing_docs)]
+    fn dead_letters(&self) -> DeadLetters<Ev>;
+
+    #[allow(missing_docs)]
+    fn redeliver_all(&self) -> usize;
+
+    #[allow(missing_docs)]
+    fn redeliver_with(&self, policy: RedeliveryPolicy) -> usize;
+}
+
+/// Send a request `Req` to the closure added for its type with `add_request_handler()`.
+/// Alternatively, receive the response of the closure
+/// or send it to all closures added with `add_fan_out_handler()`.
+pub trait SyncMediatorInternalRegistered {
+    #[allow(missing_docs)]
+    fn send_registered<Req>(&self, req: Req) -> Result<(), Req>
+    where
+        Self: 'static,
+        Req: Send + 'static;
+
+    #[allow(missing_docs)]
+    fn send_registered_and_receive<Req, Resp>(&self, req: Req) -> Result<Resp, Req>
+    where
+        Self: 'static,
+        Req: 'static,
+        Resp: 'static;
+
+    #[allow(missing_docs)]
+    fn send_fan_out<Req>(&self, req: Req) -> usize
+    where
+        Self: 'static,
+        Req: Send + 'static;
+}
+
+/// Take a snapshot of the counters of the mediator.
+pub trait SyncMediatorInternalStats {
+    #[allow(missing_docs)]
+    fn stats(&self) -> MediatorStats;
+}
+
+/// Describe the events and listeners of the mediator for documentation tooling.
+#[cfg(feature = "schema")]
+pub trait SyncMediatorInternalDescribe {
+    #[allow(missing_docs)]
+    fn describe(&self) -> MediatorDescription;
+}
+
+/// Export the recorded flows of requests, events and listeners.
+#[cfg(feature = "causality")]
+pub trait SyncMediatorInternalCausality {
+    #[allow(missing_docs)]
+    fn causality_graph(&self) -> CausalityGraph;
+}
+
+/// Pause and resume the delivery of events to listeners.
+pub trait SyncMediatorInternalPause {
+    #[allow(missing_docs)]
+    fn pause(&self);
+
+    #[allow(missing_docs)]
+    fn resume(&self);
+
+    #[allow(missing_docs)]
+    fn is_paused(&self) -> bool;
+}
+
+/// Detect and recover from panics that occurred while internal locks were held.
+pub trait SyncMediatorInternalPoison {
+    #[allow(missing_docs)]
+    fn is_poisoned(&self) -> bool;
+
+    #[allow(missing_docs)]
+    fn heal(&self);
 }
 
 /// Handles the request `Req`.
 /// Implemented by the user.
-pub trait RequestHandler<Req, Res> {
+/// Returns a response of type `Resp`, which defaults to `()`.
+pub trait RequestHandler<Req, Ev, Resp = ()> {
+    #[allow(missing_docs)]
+    fn handle(&self, req: Req) -> Resp;
+}
+
+/// Handles a batch of requests `Req` at once.
+/// Implemented for every [`RequestHandler`], handling the requests one by one.
+/// Implement it instead of [`RequestHandler`] for a more efficient code path,
+/// e.g. a single database round-trip for the whole batch.
+pub trait BatchRequestHandler<Req, Ev> {
+    #[allow(missing_docs)]
+    fn handle_batch(&self, reqs: Vec<Req>);
+}
+
+impl<M, Req, Ev> BatchRequestHandler<Req, Ev> for M
+where
+    M: RequestHandler<Req, Ev>,
+{
+    fn handle_batch(&self, reqs: Vec<Req>) {
+        reqs.into_iter().for_each(|req| self.handle(req))
+    }
+}
+
+/// Handles the request `Req`, which may fail with an error of type `Err`.
+/// Implemented by the user.
+pub trait TryRequestHandler<Req, Ev, Err> {
+    #[allow(missing_docs)]
+    fn handle(&self, req: Req) -> Result<(), Err>;
+}
+
+/// Handles the request `Req` in a standalone struct instead of on the mediator itself,
+/// receiving the [`BasicMediator`] the request was sent to.
+/// Implemented by the user.
+/// Returns a response of type `Resp`, which defaults to `()`.
+///
+/// Wire handlers to the mediator with [`mediator_requests!`](crate::mediator_requests),
+/// or register instances holding their dependencies on the builder with `register()`.
+pub trait Handler<Req, Ev: MaybeDebug, Resp = ()> {
     #[allow(missing_docs)]
-    fn handle(&self, req: Req);
+    fn handle(&self, mediator: &BasicMediator<Ev>, req: Req) -> Resp;
+}
+
+/// Implements [`RequestHandler`] for the [`BasicMediator`] of every event type,
+/// delegating each request type to its standalone [`Handler`].
+///
+/// `mediator_requests! { Ping => PingHandler, CreateUser => CreateUserHandler }`
+/// routes `Ping` to `PingHandler` and `CreateUser` to `CreateUserHandler`.
+/// Every handler is created with [`Default`] for the request it handles,
+/// so handler logic lives in its own type and module instead of on the mediator,
+/// while requests are still sent with `send()` and `send_and_receive()`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::mediator_requests;
+/// use mediatrix::synchronous::basic::*;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     Pinged,
+///     UserCreated(String)
+/// }
+///
+/// struct Ping;
+/// struct CreateUser(String);
+///
+/// #[derive(Default)]
+/// struct PingHandler;
+///
+/// impl Handler<Ping, MyEvent> for PingHandler {
+///     fn handle(&self, mediator: &BasicMediator<MyEvent>, _req: Ping) {
+///         mediator.publish(MyEvent::Pinged);
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct CreateUserHandler;
+///
+/// impl Handler<CreateUser, MyEvent, usize> for CreateUserHandler {
+///     fn handle(&self, mediator: &BasicMediator<MyEvent>, req: CreateUser) -> usize {
+///         let len = req.0.len();
+///         mediator.publish(MyEvent::UserCreated(req.0));
+///         len
+///     }
+/// }
+///
+/// mediator_requests! {
+///     Ping => PingHandler,
+///     CreateUser => CreateUserHandler,
+/// }
+///
+/// let mediator = BasicMediator::<MyEvent>::builder().build();
+///
+/// mediator.send(Ping);
+/// assert_eq!(mediator.send_and_receive::<_, usize>(CreateUser("ferris".into())), 6);
+/// assert_eq!(mediator.next_all(), 2);
+///
+#[macro_export]
+macro_rules! mediator_requests {
+    ($($req:ty => $handler:ty),* $(,)?) => {
+        $(
+            impl<Ev, Resp> $crate::synchronous::basic::RequestHandler<$req, Ev, Resp>
+                for $crate::synchronous::basic::BasicMediator<Ev>
+            where
+                Ev: $crate::listener::MaybeDebug,
+                $handler: $crate::synchronous::basic::Handler<$req, Ev, Resp> + ::core::default::Default,
+            {
+                fn handle(&self, req: $req) -> Resp {
+                    $crate::synchronous::basic::Handler::handle(
+                        &<$handler as ::core::default::Default>::default(),
+                        self,
+                        req,
+                    )
+                }
+            }
+        )*
+    };
+}
+
+/// Declares a module of requests sent to a [`BasicMediator`] with events of type `Ev`,
+/// checking at compile time that every request has a handler.
+///
+/// The `requests` list every request of the module, followed by the type of its response, if any.
+/// The optional `handlers` map requests to their [`Handler`], like [`mediator_requests!`].
+/// Requests may also be handled by a [`RequestHandler`] implementation of their own.
+///
+/// The declared type has a `register()` function, which registers every request
+/// with a builder, so it can be sent via `send_dyn()` as well.
+/// Unlike handlers registered one by one with `register_handler()`,
+/// a request lacking a handler doesn't surface at runtime, but fails compilation.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::mediator_module;
+/// use mediatrix::synchronous::basic::*;
+/// use std::any::Any;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     Pinged,
+///     UserCreated(String)
+/// }
+///
+/// struct Ping;
+/// struct CreateUser(String);
+///
+/// #[derive(Default)]
+/// struct CreateUserHandler;
+///
+/// impl Handler<CreateUser, MyEvent, usize> for CreateUserHandler {
+///     fn handle(&self, mediator: &BasicMediator<MyEvent>, req: CreateUser) -> usize {
+///         let len = req.0.len();
+///         mediator.publish(MyEvent::UserCreated(req.0));
+///         len
+///     }
+/// }
+///
+/// impl RequestHandler<Ping, MyEvent> for BasicMediator<MyEvent> {
+///     fn handle(&self, _req: Ping) {
+///         self.publish(MyEvent::Pinged);
+///     }
+/// }
+///
+/// mediator_module! {
+///     /// Everything about users.
+///     struct Users for MyEvent {
+///         requests: [Ping, CreateUser: usize],
+///         handlers: [CreateUser => CreateUserHandler],
+///     }
+/// }
+///
+/// let mediator = Users::register(BasicMediator::<MyEvent>::builder()).build();
+///
+/// let req: Box<dyn Any + Send> = Box::new(CreateUser("ferris".into()));
+/// assert!(mediator.send_dyn(req).is_ok());
+/// assert!(mediator.send_dyn(Box::new(Ping)).is_ok());
+/// assert_eq!(mediator.next_all(), 2);
+/// ```
+///
+/// A request without a handler fails compilation:
+///
+/// ```compile_fail
+/// use mediatrix::mediator_module;
+/// use mediatrix::synchronous::basic::*;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {}
+///
+/// struct Ping;
+///
+/// mediator_module! {
+///     struct Users for MyEvent {
+///         requests: [Ping],
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! mediator_module {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident for $ev:ty {
+            requests: [$($req:ty $(: $resp:ty)?),* $(,)?]
+            $(, handlers: [$($handled:ty => $handler:ty),* $(,)?])?
+            $(,)?
+        }
+    ) => {
+        $($crate::mediator_requests! { $($handled => $handler),* })?
+
+        $(#[$attr])*
+        $vis struct $name;
+
+        impl $name {
+            /// Registers every request of the module with the `builder`,
+            /// so it can be sent via `send_dyn()`.
+            #[allow(dead_code)]
+            pub fn register(
+                builder: $crate::synchronous::basic::BasicBuilder<$ev>,
+            ) -> $crate::synchronous::basic::BasicBuilder<$ev> {
+                $(
+                    let builder = builder.register_handler(
+                        |mediator: &$crate::synchronous::basic::BasicMediator<$ev>, req: $req| {
+                            let _: $crate::mediator_module!(@response $($resp)?) =
+                                $crate::synchronous::basic::SyncMediatorInternalHandle::send_and_receive(
+                                    mediator, req,
+                                );
+                        },
+                    );
+                )*
+                builder
+            }
+        }
+    };
+    (@response) => { () };
+    (@response $resp:ty) => { $resp };
 }
 
 /// Basic builder fuctionality:
@@ -36,6 +466,273 @@ pub trait RequestHandler<Req, Res> {
 pub trait BasicMediatorBuilderInterface<M, Ev> {
     #[allow(missing_docs)]
     fn add_listener(self, f: impl Listener<Ev>) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Named listener builder fuctionality:
+/// Adding a [`Listener`] with a name to the builder.
+pub trait NamedListenerBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_listener_named(self, name: &'static str, f: impl Listener<Ev>) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Pipeline builder fuctionality:
+/// Adding a [`Behavior`] to the builder.
+pub trait BehaviorMediatorBuilderInterface<M> {
+    #[allow(missing_docs)]
+    fn add_behavior(self, b: impl Behavior) -> Self;
+}
+
+/// Channel builder fuctionality:
+/// Bounding the channel and choosing an [`OverflowPolicy`].
+pub trait ChannelMediatorBuilderInterface<M> {
+    #[allow(missing_docs)]
+    fn with_capacity(self, capacity: usize) -> Self;
+
+    #[allow(missing_docs)]
+    fn with_overflow_policy(self, policy: OverflowPolicy) -> Self;
+}
+
+/// Channel backend builder fuctionality:
+/// Choosing the [`ChannelFlavor`] the channel is created with.
+pub trait ChannelBackendBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn with_channel_backend<F: ChannelFlavor>(self) -> Self
+    where
+        Ev: Send + 'static;
+}
+
+/// Profile builder fuctionality:
+/// Configuring the channel and processing options from a [`Profile`] in one call.
+pub trait ProfileBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn with_profile(self, profile: Profile) -> Self
+    where
+        Ev: Send + 'static;
+}
+
+#[cfg(feature = "std")]
+/// Listener isolation builder fuctionality:
+/// Catching panics of listeners instead of unwinding.
+pub trait ListenerIsolationBuilderInterface<M> {
+    #[allow(missing_docs)]
+    fn isolate_listeners(self, isolate: bool) -> Self;
+}
+
+#[cfg(feature = "std")]
+/// Rate limit builder fuctionality:
+/// Limiting how many events are delivered to the listeners within a time window.
+pub trait RateLimitBuilderInterface<M> {
+    #[allow(missing_docs)]
+    fn with_rate_limit(self, limit: RateLimit) -> Self;
+}
+
+/// Topic builder fuctionality:
+/// Adding a [`Listener`] for a topic to the builder.
+pub trait TopicMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_listener_for_topic(self, topic: &str, f: impl Listener<Ev>) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Filter builder fuctionality:
+/// Adding a [`Listener`] guarded by a [`Filter`] to the builder.
+pub trait FilterMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_filtered_listener(self, filter: impl Filter<Ev>, f: impl Listener<Ev>) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Variant builder fuctionality:
+/// Adding a [`Listener`] for events with certain variant names to the builder.
+pub trait VariantMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_listener_for_variants(
+        self,
+        variants: impl IntoIterator<Item = &'static str>,
+        f: impl Listener<Ev>,
+    ) -> Self
+    where
+        Ev: MaybeDebug,
+        for<'a> &'a Ev: Into<&'static str>;
+}
+
+/// Converting builder fuctionality:
+/// Adding a listener for the events convertible into a sub-event `SubEv` to the builder.
+pub trait ConvertingMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_converting_listener<SubEv>(self, f: impl Fn(SubEv) + Send + Sync + 'static) -> Self
+    where
+        Ev: MaybeDebug + Clone + TryInto<SubEv>;
+}
+
+/// Priority builder fuctionality:
+/// Adding a [`Listener`] with a priority to the builder.
+pub trait PriorityMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_listener_with_priority(self, priority: i32, f: impl Listener<Ev>) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Handler builder fuctionality:
+/// Adding a [`NotificationHandler`] to the builder.
+pub trait HandlerMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_handler(self, handler: Arc<dyn NotificationHandler<Ev>>) -> Self
+    where
+        Ev: MaybeDebug + 'static;
+}
+
+/// Observer builder fuctionality:
+/// Adding a [`MediatorObserver`] to the builder.
+pub trait ObserverMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn with_observer(self, observer: impl MediatorObserver<Ev> + 'static) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Causality builder fuctionality:
+/// Recording which request or listener caused which event.
+#[cfg(feature = "causality")]
+pub trait CausalityBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn record_causality(self) -> Self
     where
         Ev: Debug;
 }
+
+/// Request hook builder fuctionality:
+/// Adding hooks invoked before and after every request to the builder.
+pub trait RequestHookBuilderInterface<M> {
+    #[allow(missing_docs)]
+    fn on_before_send(self, f: impl Fn(&RequestMeta) + Send + Sync + 'static) -> Self;
+
+    #[allow(missing_docs)]
+    fn on_after_send(self, f: impl Fn(&RequestMeta, Duration) + Send + Sync + 'static) -> Self;
+}
+
+/// Logging builder fuctionality:
+/// Adding a [`LoggingListener`] to the builder.
+#[cfg(feature = "tracing")]
+pub trait LoggingMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_logging_listener(self, listener: impl Into<LoggingListener<Ev>>) -> Self;
+}
+
+/// Upcaster builder fuctionality:
+/// Turning events of older journal versions into the current event `Ev` when replaying.
+#[cfg(feature = "persistence")]
+pub trait UpcasterBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn register_upcaster(
+        self,
+        from_version: u32,
+        f: impl Fn(Value) -> Ev + Send + Sync + 'static,
+    ) -> Self;
+}
+
+/// Replay builder fuctionality:
+/// Configuring the replay buffer of the mediator.
+pub trait ReplayMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn with_replay_capacity(self, capacity: usize) -> Self
+    where
+        Ev: MaybeDebug + Clone;
+}
+
+/// Dead-letter builder fuctionality:
+/// Enabling the dead-letter queue of the mediator.
+pub trait DeadLetterMediatorBuilderInterface<M> {
+    #[allow(missing_docs)]
+    fn collect_dead_letters(self, collect: bool) -> Self;
+}
+
+/// Enveloped listener builder fuctionality:
+/// Adding a listener that receives the event as [`Enveloped`] to the builder.
+pub trait EnvelopedMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_enveloped_listener(self, f: impl Fn(&Enveloped<'_, Ev>) + Send + Sync + 'static) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Catch-all listener builder fuctionality:
+/// Adding a listener that receives every event along with its [`EventMeta`] to the builder.
+pub trait CatchAllMediatorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_catch_all_listener(self, f: impl Fn(&Ev, &EventMeta) + Send + Sync + 'static) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Publish interceptor builder fuctionality:
+/// Adding a [`PublishInterceptor`] rewriting or dropping published events to the builder.
+pub trait InterceptorBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_publish_interceptor(self, f: impl PublishInterceptor<Ev>) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Request events builder fuctionality:
+/// Enabling the [`SystemEvent`](crate::system::SystemEvent)s published after every request.
+pub trait RequestEventsBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn with_request_events(self, enabled: bool) -> Self;
+}
+
+/// Shared listener builder fuctionality:
+/// Adding a [`SharedListener`] that receives the event in an [`Arc`] to the builder.
+pub trait SharedListenerBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_shared_listener(self, f: impl SharedListener<Ev>) -> Self
+    where
+        Ev: MaybeDebug + Clone;
+}
+
+/// Register a handler for requests `Req` sent to the mediator `M` via `send_dyn()`.
+pub trait DynamicHandlerBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn register_handler<Req>(self, handler: impl Fn(&M, Req) + Send + Sync + 'static) -> Self
+    where
+        Req: Send + 'static;
+}
+
+/// Closure handler builder fuctionality:
+/// Adding a closure handling requests `Req` sent to the mediator `M` via `send_registered()`,
+/// or one of many closures handling requests sent via `send_fan_out()`.
+pub trait ClosureHandlerBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_request_handler<Req, Resp>(
+        self,
+        handler: impl Fn(&M, Req) -> Resp + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Resp: 'static;
+
+    #[allow(missing_docs)]
+    fn add_fan_out_handler<Req>(self, handler: impl Fn(&M, &Req) + Send + Sync + 'static) -> Self
+    where
+        Req: Send + 'static;
+}
+
+/// Standalone handler builder fuctionality:
+/// Registering a [`Handler`] struct handling requests `Req` sent to the mediator `M` via `send_registered()`.
+pub trait StandaloneHandlerBuilderInterface<M, Ev: MaybeDebug> {
+    #[allow(missing_docs)]
+    fn register<Req, Resp>(
+        self,
+        handler: impl Handler<Req, Ev, Resp> + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Resp: 'static;
+}
@@ -1,10 +1,34 @@
+#[allow(clippy::module_inception)]
 pub(crate) mod basic;
 pub(crate) mod builder;
 pub(crate) mod interface;
+#[cfg(not(feature = "async-only"))]
+pub(crate) mod shared;
+#[cfg(not(feature = "async-only"))]
+pub(crate) mod worker;
 
 pub use basic::*;
 pub use builder::*;
 pub use interface::*;
+#[cfg(not(feature = "async-only"))]
+pub use shared::*;
+#[cfg(not(feature = "async-only"))]
+pub use worker::*;
 
+pub use crate::behavior::*;
 pub use crate::builder::{BuilderFlow, BuilderInternal};
+#[cfg(feature = "causality")]
+pub use crate::causality::{CausalityGraph, CausalityNode};
+pub use crate::channel::OverflowPolicy;
+pub use crate::correlation::{CorrelationId, Enveloped, EventMeta};
+pub use crate::deadletter::{DeadLetter, DeadLetterReason, DeadLetters, RedeliveryPolicy};
 pub use crate::listener::*;
+pub use crate::observer::{MediatorObserver, RequestMeta};
+#[cfg(feature = "persistence")]
+pub use crate::persistence::{Journal, JournalEntry};
+pub use crate::profile::Profile;
+#[cfg(feature = "std")]
+pub use crate::ratelimit::{RateLimit, ThrottlePolicy};
+#[cfg(feature = "schema")]
+pub use crate::schema::{ListenerDescription, MediatorDescription};
+pub use crate::stats::{ListenerStats, MediatorStats};
@@ -0,0 +1,272 @@
+use alloc::{sync::Arc, vec::Vec};
+use core::time::Duration;
+
+use crate::mediator::{channel::TrySendError, listener::NextError, stats::MediatorStats};
+
+use super::*;
+
+/// A thread-safe, cheaply cloneable [`BasicMediator`] with events of type `Ev`.
+///
+/// Created from a built mediator with [`SharedMediator::new()`] or [`From`].
+/// All clones share the mediator, i.e. its listeners, channel and the configuration of the builder,
+/// so clones can be moved to as many threads as needed to send requests and publish events,
+/// while a dedicated thread processes the events, e.g. with [`SharedMediator::next_blocking()`].
+///
+/// The default channel lets only one thread at a time wait for events.
+/// Build the mediator with a multi-consumer backend like `CrossbeamChannel` or `FlumeChannel`
+/// to let several threads process events at the same time, see `with_channel_backend()`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use mediatrix::synchronous::basic::*;
+/// use std::thread;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     Ping(u32)
+/// }
+///
+/// struct Ping(u32);
+///
+/// impl RequestHandler<Ping, MyEvent> for BasicMediator<MyEvent> {
+///     fn handle(&self, req: Ping) {
+///         self.publish(MyEvent::Ping(req.0));
+///     }
+/// }
+///
+/// let mediator = SharedMediator::new(
+///     BasicMediator::<MyEvent>::builder()
+///         .add_listener(|ev: &MyEvent| println!("{ev:?}"))
+///         .build(),
+/// );
+///
+/// let processor = mediator.clone();
+/// let processing = thread::spawn(move || {
+///     for _ in 0..4 {
+///         processor.next_blocking().unwrap();
+///     }
+/// });
+///
+/// let senders: Vec<_> = (0..4)
+///     .map(|i| {
+///         let mediator = mediator.clone();
+///         thread::spawn(move || mediator.send(Ping(i)))
+///     })
+///     .collect();
+/// for sender in senders {
+///     sender.join().unwrap();
+/// }
+///
+/// processing.join().unwrap();
+/// assert_eq!(mediator.stats().processed(), 4);
+/// # }
+///
+#[derive(Debug)]
+pub struct SharedMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    mediator: Arc<BasicMediator<Ev>>,
+}
+
+impl<Ev> Clone for SharedMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn clone(&self) -> Self {
+        Self {
+            mediator: self.mediator.clone(),
+        }
+    }
+}
+
+impl<Ev> From<BasicMediator<Ev>> for SharedMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    fn from(mediator: BasicMediator<Ev>) -> Self {
+        Self::new(mediator)
+    }
+}
+
+impl<Ev> SharedMediator<Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Creates a [`SharedMediator`] sharing the built `mediator`.
+    pub fn new(mediator: BasicMediator<Ev>) -> Self {
+        Self {
+            mediator: Arc::new(mediator),
+        }
+    }
+
+    /// Returns the shared [`BasicMediator`], for everything not forwarded by the [`SharedMediator`].
+    pub fn mediator(&self) -> &BasicMediator<Ev> {
+        &self.mediator
+    }
+
+    /// Send a request of type `Req` to the mediator.
+    ///
+    /// See [`BasicMediator::send()`] for more info.
+    ///
+    pub fn send<Req>(&self, req: Req)
+    where
+        BasicMediator<Ev>: RequestHandler<Req, Ev>,
+        Req: Send + 'static,
+    {
+        self.mediator.send(req)
+    }
+
+    /// Send a request of type `Req` to the mediator
+    /// and receive a response of type `Resp`.
+    ///
+    /// See [`BasicMediator::send_and_receive()`] for more info.
+    ///
+    pub fn send_and_receive<Req, Resp>(&self, req: Req) -> Resp
+    where
+        BasicMediator<Ev>: RequestHandler<Req, Ev, Resp>,
+    {
+        self.mediator.send_and_receive(req)
+    }
+
+    /// Send a batch of requests of type `Req` to the mediator.
+    ///
+    /// See [`BasicMediator::send_batch()`] for more info.
+    ///
+    pub fn send_batch<Req>(&self, reqs: Vec<Req>)
+    where
+        BasicMediator<Ev>: BatchRequestHandler<Req, Ev>,
+        Req: Send + 'static,
+    {
+        self.mediator.send_batch(reqs)
+    }
+
+    /// Try to send a request of type `Req` to the mediator.
+    ///
+    /// See [`BasicMediator::try_send()`] for more info.
+    ///
+    pub fn try_send<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        BasicMediator<Ev>: TryRequestHandler<Req, Ev, Err>,
+        Req: Send + 'static,
+    {
+        self.mediator.try_send(req)
+    }
+
+    /// Publishes an event `Ev`.
+    ///
+    /// See [`BasicMediator::publish()`] for more info.
+    ///
+    pub fn publish(&self, event: Ev) {
+        self.mediator.publish(event)
+    }
+
+    /// Tries to publish an event `Ev`.
+    ///
+    /// See [`BasicMediator::try_publish()`] for more info.
+    ///
+    pub fn try_publish(&self, event: Ev) -> Result<(), TrySendError<Ev>> {
+        self.mediator.try_publish(event)
+    }
+
+    /// Publishes an event `Ev` to the given `topic`.
+    ///
+    /// See [`BasicMediator::publish_to()`] for more info.
+    ///
+    pub fn publish_to(&self, topic: &str, event: Ev) {
+        self.mediator.publish_to(topic, event)
+    }
+
+    /// Publishes an event `Ev` that expires once the `ttl` elapsed.
+    ///
+    /// See [`BasicMediator::publish_with_ttl()`] for more info.
+    ///
+    pub fn publish_with_ttl(&self, event: Ev, ttl: Duration) {
+        self.mediator.publish_with_ttl(event, ttl)
+    }
+
+    /// Publishes an urgent event `Ev`, which is delivered before all regular events.
+    ///
+    /// See [`BasicMediator::publish_urgent()`] for more info.
+    ///
+    pub fn publish_urgent(&self, event: Ev) {
+        self.mediator.publish_urgent(event)
+    }
+
+    /// Processes the next published event.
+    ///
+    /// See [`BasicMediator::next()`] for more info.
+    ///
+    pub fn next(&self) -> Result<(), NextError> {
+        self.mediator.next()
+    }
+
+    /// Processes published events until the channel is empty.
+    ///
+    /// See [`BasicMediator::next_all()`] for more info.
+    ///
+    pub fn next_all(&self) -> usize {
+        self.mediator.next_all()
+    }
+
+    /// Waits for the next published event and processes it.
+    ///
+    /// See [`BasicMediator::next_blocking()`] for more info.
+    ///
+    #[cfg(feature = "std")]
+    pub fn next_blocking(&self) -> Result<(), NextError> {
+        self.mediator.next_blocking()
+    }
+
+    /// Pauses the delivery of events to listeners.
+    ///
+    /// See [`BasicMediator::pause()`] for more info.
+    ///
+    pub fn pause(&self) {
+        self.mediator.pause()
+    }
+
+    /// Resumes the delivery of events paused with [`SharedMediator::pause()`].
+    ///
+    /// See [`BasicMediator::resume()`] for more info.
+    ///
+    pub fn resume(&self) {
+        self.mediator.resume()
+    }
+
+    /// Returns whether the delivery of events is paused.
+    ///
+    /// See [`BasicMediator::is_paused()`] for more info.
+    ///
+    pub fn is_paused(&self) -> bool {
+        self.mediator.is_paused()
+    }
+
+    /// Subscribes a listener to the mediator.
+    ///
+    /// See [`BasicMediator::subscribe()`] for more info.
+    ///
+    pub fn subscribe(&self, f: impl Listener<Ev>) -> ListenerHandle {
+        self.mediator.subscribe(f)
+    }
+
+    /// Unsubscribes the listener of the `handle`.
+    ///
+    /// See [`BasicMediator::unsubscribe()`] for more info.
+    ///
+    pub fn unsubscribe(&self, handle: ListenerHandle) -> bool {
+        self.mediator.unsubscribe(handle)
+    }
+
+    /// Returns a snapshot of the counters of the mediator.
+    ///
+    /// See [`BasicMediator::stats()`] for more info.
+    ///
+    pub fn stats(&self) -> MediatorStats {
+        self.mediator.stats()
+    }
+}
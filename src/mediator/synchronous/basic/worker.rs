@@ -0,0 +1,92 @@
+use crate::mediator::listener::NextError;
+
+use super::interface::SyncMediatorInternalNext;
+
+/// A handle processing the events of a mediator `M`, returned by `worker()`.
+///
+/// Workers are cheap to copy and can be moved to other threads,
+/// so several threads can process the events of the same mediator concurrently,
+/// each invoking the listeners for a different event.
+/// Events are still received in publishing order, but may finish processing out of order.
+/// A single listener is invoked by one worker at a time, so listeners don't need to be `Sync`.
+///
+/// The default channel lets only one thread at a time receive,
+/// which matters for threads waiting in [`EventWorker::next_blocking()`].
+/// Use a multi-consumer backend like `CrossbeamChannel` or `FlumeChannel`
+/// to let all workers wait for events at the same time, see `with_channel_backend()`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use std::thread;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     One,
+///     Two
+/// }
+///
+/// let mediator = BasicMediator::<MyEvent>::builder()
+///     .add_listener(|ev: &MyEvent| println!("{ev:?}"))
+///     .build();
+///
+/// (0..8).for_each(|_| mediator.publish(MyEvent::One));
+///
+/// let processed: usize = thread::scope(|s| {
+///     let workers: Vec<_> = (0..4)
+///         .map(|_| {
+///             let worker = mediator.worker();
+///             s.spawn(move || worker.next_all())
+///         })
+///         .collect();
+///     workers.into_iter().map(|w| w.join().unwrap()).sum()
+/// });
+///
+/// assert_eq!(processed, 8);
+///
+#[derive(Debug)]
+pub struct EventWorker<'a, M> {
+    mediator: &'a M,
+}
+
+impl<M> Clone for EventWorker<'_, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M> Copy for EventWorker<'_, M> {}
+
+impl<'a, M> EventWorker<'a, M>
+where
+    M: SyncMediatorInternalNext + Sync,
+{
+    pub(crate) fn new(mediator: &'a M) -> Self {
+        Self { mediator }
+    }
+
+    /// Processes the next published event.
+    ///
+    /// See `SyncMediatorInternalNext::next()` of the mediator for more info.
+    ///
+    pub fn next(&self) -> Result<(), NextError> {
+        self.mediator.next()
+    }
+
+    /// Processes published events until the channel is empty,
+    /// returning how many events this worker processed.
+    ///
+    pub fn next_all(&self) -> usize {
+        self.mediator.next_all()
+    }
+
+    /// Processes the next published event, blocking until one is available.
+    ///
+    #[cfg(feature = "std")]
+    pub fn next_blocking(&self) -> Result<(), NextError> {
+        self.mediator.next_blocking()
+    }
+}
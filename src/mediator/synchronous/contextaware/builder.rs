@@ -0,0 +1,1310 @@
+#[cfg(feature = "std")]
+use crate::mediator::ratelimit::{RateLimit, RateLimiter};
+#[cfg(feature = "std")]
+use crate::mediator::synchronous::basic::interface::{
+    ListenerIsolationBuilderInterface, RateLimitBuilderInterface,
+};
+use crate::mediator::{
+    behavior::Behavior,
+    builder::{validate_strict, BuildError, TryBuilderFlow, TryBuilderInternal},
+    channel::{ChannelFlavor, EventChannel, OverflowPolicy},
+    correlation::{Enveloped, EventMeta},
+    dependencies::Dependencies,
+    listener::{
+        recover, variant_filter, ExclusiveListener, Filter, Listener, MaybeDebug,
+        NotificationHandler, PublishInterceptor, SharedListener,
+    },
+    observer::{MediatorObserver, RequestMeta},
+    profile::Profile,
+    registry::{erase, FanOutHandler},
+    replay::ReplayBuffer,
+    synchronous::{
+        basic::{
+            basic::BasicMediator,
+            interface::{
+                BasicMediatorBuilderInterface, BehaviorMediatorBuilderInterface,
+                CatchAllMediatorBuilderInterface, ChannelBackendBuilderInterface,
+                ChannelMediatorBuilderInterface, ConvertingMediatorBuilderInterface,
+                DeadLetterMediatorBuilderInterface, EnvelopedMediatorBuilderInterface,
+                FilterMediatorBuilderInterface, Handler, HandlerMediatorBuilderInterface,
+                InterceptorBuilderInterface, NamedListenerBuilderInterface,
+                ObserverMediatorBuilderInterface, PriorityMediatorBuilderInterface,
+                ProfileBuilderInterface, ReplayMediatorBuilderInterface,
+                RequestEventsBuilderInterface, RequestHookBuilderInterface,
+                SharedListenerBuilderInterface, StandaloneHandlerBuilderInterface,
+                TopicMediatorBuilderInterface, VariantMediatorBuilderInterface,
+            },
+        },
+        contextaware::{
+            contextaware::CxAwareMediator,
+            interface::{
+                CxAwareClosureHandlerBuilderInterface, CxAwareDependencyBuilderInterface,
+                CxAwareDynamicHandlerBuilderInterface, CxAwareMediatorBuilderInterface,
+                StrictBuilderInterface,
+            },
+        },
+    },
+    system::{RequestEvent, SystemEvent},
+    topics::TopicPattern,
+};
+use alloc::{boxed::Box, sync::Arc};
+use core::{any::type_name_of_val, time::Duration};
+
+#[cfg(feature = "tracing")]
+use crate::mediator::{
+    logging::LoggingListener, synchronous::basic::interface::LoggingMediatorBuilderInterface,
+};
+
+#[cfg(feature = "persistence")]
+use crate::mediator::synchronous::basic::interface::UpcasterBuilderInterface;
+#[cfg(feature = "persistence")]
+use serde_json::Value;
+
+#[cfg(feature = "causality")]
+use crate::mediator::{
+    causality::CausalityRecorder, synchronous::basic::interface::CausalityBuilderInterface,
+};
+#[cfg(feature = "causality")]
+use core::fmt::Debug;
+
+#[cfg(feature = "async")]
+use crate::mediator::{
+    lifecycle::{CxLifecycle, Lifecycle},
+    sync::Mutex,
+    synchronous::contextaware::interface::CxAwareLifecycleBuilderInterface,
+};
+
+/// The [`CxAwareBuilder`] helps you to create a [`CxAwareMediator`].
+///
+/// The [`CxAwareBuilder`] is part of the builder pattern.
+/// It has three functionalities. The first one is adding a [`Listener`] via
+/// [`CxAwareBuilder::add_listener()`].
+/// Secondly, a context `Cx` can be added via [`CxAwareBuilder::add_context()`].
+/// This must be done in order to receive a [`CxAwareMediator`] from [`TryBuilderFlow::build()`].
+/// The third functionality is the mandatory [`TryBuilderFlow::build()`], which returns
+/// a [`Result`] of type [`Result<CxAwareMediator<Cx, Ev>, Self::Error>`].
+///
+pub struct CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    mediator: BasicMediator<Ev>,
+    cx: Option<Cx>,
+    #[cfg(feature = "async")]
+    lifecycle: Option<Lifecycle<Cx>>,
+    strict: bool,
+    error: Option<BuildError>,
+}
+
+impl<Cx, Ev> TryBuilderInternal<CxAwareMediator<Cx, Ev>, CxAwareBuilder<Cx, Ev>>
+    for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Creates a [`CxAwareBuilder`] with the goal of producing a [`CxAwareMediator`].
+    ///
+    fn builder() -> CxAwareBuilder<Cx, Ev> {
+        CxAwareBuilder::<Cx, Ev> {
+            mediator: BasicMediator::new(),
+            cx: None,
+            #[cfg(feature = "async")]
+            lifecycle: None,
+            strict: false,
+            error: None,
+        }
+    }
+}
+
+impl<M, Cx, Ev> BasicMediatorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener to the [`CxAwareBuilder`].
+    ///
+    /// To be able to supply a closure that implements [`Listener`],
+    /// it must satisfy [`Send`] and `'static` bounds.
+    ///
+    /// Also it must be a `Fn(Ev)` with a return type of `()`
+    /// where `Ev` is the user-defined event type
+    /// that must be [`MaybeDebug`].
+    ///
+    fn add_listener(self, f: impl Listener<Ev>) -> Self {
+        self.mediator
+            .listener
+            .add(type_name_of_val(&f), ExclusiveListener::new(f));
+        self
+    }
+}
+
+impl<M, Cx, Ev> NamedListenerBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener with a `name` to the [`CxAwareBuilder`].
+    ///
+    fn add_listener_named(self, name: &'static str, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add(name, ExclusiveListener::new(f));
+        self
+    }
+}
+
+impl<M, Cx, Ev> CxAwareMediatorBuilderInterface<M, Cx, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined context of type `Cx` to the [`CxAwareBuilder`].
+    ///
+    /// The context is available in [`super::CxAwareRequestHandler::handle()`].
+    ///
+    fn add_context(mut self, cx: Cx) -> Self
+    where
+        Ev: MaybeDebug,
+    {
+        self.cx = Some(cx);
+        self
+    }
+}
+
+impl<M, Cx, Ev> BehaviorMediatorBuilderInterface<M> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined [`Behavior`] to the [`CxAwareBuilder`].
+    ///
+    /// Behaviors wrap every request sent to the [`CxAwareMediator`]
+    /// in the order they were added.
+    ///
+    fn add_behavior(mut self, b: impl Behavior) -> Self {
+        self.mediator.behavior.push(Box::new(b));
+        self
+    }
+}
+
+impl<M, Cx, Ev> ChannelMediatorBuilderInterface<M> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Bounds the channel of the [`CxAwareBuilder`] to `capacity` events.
+    ///
+    /// Building fails with [`BuildError::InvalidCapacity`] if `capacity` is `0`.
+    ///
+    fn with_capacity(mut self, capacity: usize) -> Self {
+        if capacity == 0 {
+            self.error.get_or_insert(BuildError::InvalidCapacity);
+        } else {
+            self.mediator.channel.set_capacity(Some(capacity));
+        }
+        self
+    }
+
+    /// Sets the [`OverflowPolicy`] of the [`CxAwareBuilder`].
+    ///
+    fn with_overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.mediator.channel.policy = policy;
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M, Cx, Ev> ListenerIsolationBuilderInterface<M> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Enables or disables listener isolation of the [`CxAwareBuilder`].
+    ///
+    fn isolate_listeners(mut self, isolate: bool) -> Self {
+        self.mediator.isolate_listeners = isolate;
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M, Cx, Ev> RateLimitBuilderInterface<M> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the [`RateLimit`] of the [`CxAwareBuilder`].
+    ///
+    fn with_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.mediator.rate_limit = Some(RateLimiter::new(limit));
+        self
+    }
+}
+
+impl<M, Cx, Ev> TopicMediatorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener for a topic to the [`CxAwareBuilder`].
+    ///
+    /// The listener only receives events published to a topic
+    /// matching the [`TopicPattern`] `topic`.
+    ///
+    fn add_listener_for_topic(self, topic: &str, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add_for_topic(
+            type_name_of_val(&f),
+            Some(TopicPattern::new(topic)),
+            ExclusiveListener::new(f),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> FilterMediatorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener guarded by a [`Filter`] to the [`CxAwareBuilder`].
+    ///
+    /// The listener is only invoked if `filter` returns `true` for an event.
+    ///
+    fn add_filtered_listener(self, filter: impl Filter<Ev>, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| {
+                if filter(ev) {
+                    f(ev)
+                }
+            }),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> VariantMediatorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener for events with certain variant names to the [`CxAwareBuilder`].
+    ///
+    fn add_listener_for_variants(
+        self,
+        variants: impl IntoIterator<Item = &'static str>,
+        f: impl Listener<Ev>,
+    ) -> Self
+    where
+        for<'a> &'a Ev: Into<&'static str>,
+    {
+        <Self as FilterMediatorBuilderInterface<M, Ev>>::add_filtered_listener(
+            self,
+            variant_filter(variants),
+            f,
+        )
+    }
+}
+
+impl<M, Cx, Ev> ConvertingMediatorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener for the events convertible into `SubEv` to the [`CxAwareBuilder`].
+    ///
+    fn add_converting_listener<SubEv>(self, f: impl Fn(SubEv) + Send + Sync + 'static) -> Self
+    where
+        Ev: Clone + TryInto<SubEv>,
+    {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| {
+                if let Ok(ev) = ev.clone().try_into() {
+                    f(ev)
+                }
+            }),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> PriorityMediatorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener with a `priority` to the [`CxAwareBuilder`].
+    ///
+    /// Listeners are invoked in descending priority.
+    ///
+    fn add_listener_with_priority(self, priority: i32, f: impl Listener<Ev>) -> Self {
+        self.mediator.listener.add_with_priority(
+            type_name_of_val(&f),
+            priority,
+            ExclusiveListener::new(f),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> HandlerMediatorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined notification handler to the [`CxAwareBuilder`].
+    ///
+    /// The handler is invoked like any other listener.
+    ///
+    fn add_handler(self, handler: Arc<dyn NotificationHandler<Ev>>) -> Self
+    where
+        Ev: 'static,
+    {
+        self.mediator.listener.add(
+            handler.name(),
+            ExclusiveListener::new(move |ev: &Ev| handler.on_event(ev)),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> ObserverMediatorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a [`MediatorObserver`] to the [`CxAwareBuilder`].
+    ///
+    /// Observers are notified in the order they were added.
+    ///
+    fn with_observer(mut self, observer: impl MediatorObserver<Ev> + 'static) -> Self {
+        self.mediator.observer.add(observer);
+        self
+    }
+}
+
+impl<M, Cx, Ev> RequestHookBuilderInterface<M> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a hook invoked before every request sent to the [`CxAwareBuilder`].
+    ///
+    fn on_before_send(mut self, f: impl Fn(&RequestMeta) + Send + Sync + 'static) -> Self {
+        self.mediator.observer.before_send(f);
+        self
+    }
+
+    /// Adds a hook invoked after every request sent to the [`CxAwareBuilder`].
+    ///
+    fn on_after_send(mut self, f: impl Fn(&RequestMeta, Duration) + Send + Sync + 'static) -> Self {
+        self.mediator.observer.after_send(f);
+        self
+    }
+}
+
+#[cfg(feature = "causality")]
+impl<M, Cx, Ev> CausalityBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Records the flows of requests, events and listeners of the [`CxAwareBuilder`].
+    ///
+    fn record_causality(mut self) -> Self
+    where
+        Ev: Debug,
+    {
+        self.mediator.observer.causality = Some(Arc::new(CausalityRecorder::new()));
+        self
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<M, Cx, Ev> LoggingMediatorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    /// Adds a [`LoggingListener`] to the [`CxAwareBuilder`].
+    ///
+    fn add_logging_listener(self, listener: impl Into<LoggingListener<Ev>>) -> Self {
+        let listener = listener.into();
+        self.mediator.listener.add(
+            type_name_of_val(&listener),
+            ExclusiveListener::new(move |ev: &Ev| listener.log(&Enveloped::current(ev))),
+        );
+        self
+    }
+}
+
+#[cfg(feature = "persistence")]
+impl<M, Cx, Ev> UpcasterBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds an upcaster for events of the version `from_version` to the [`CxAwareBuilder`].
+    ///
+    fn register_upcaster(
+        mut self,
+        from_version: u32,
+        f: impl Fn(Value) -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        self.mediator.upcasters.register(from_version, f);
+        self
+    }
+}
+
+impl<M, Cx, Ev> ReplayMediatorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Sets the capacity of the replay buffer of the [`CxAwareBuilder`].
+    ///
+    /// A capacity of `0` disables the replay buffer.
+    ///
+    fn with_replay_capacity(mut self, capacity: usize) -> Self
+    where
+        Ev: Clone,
+    {
+        self.mediator.replay = (capacity > 0).then(|| ReplayBuffer::new(capacity));
+        self
+    }
+}
+
+impl<M, Cx, Ev> DeadLetterMediatorBuilderInterface<M> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Enables or disables the dead-letter queue of the [`CxAwareBuilder`].
+    ///
+    fn collect_dead_letters(mut self, collect: bool) -> Self {
+        self.mediator.dead_letters = collect.then(EventChannel::default);
+        self
+    }
+}
+
+impl<M, Cx, Ev> SharedListenerBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener receiving the event in an [`Arc`] to the [`CxAwareBuilder`].
+    ///
+    fn add_shared_listener(mut self, f: impl SharedListener<Ev>) -> Self
+    where
+        Ev: Clone,
+    {
+        self.mediator.shared_listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Arc<Ev>| f(ev.clone())),
+        );
+        self.mediator.recover = Some(recover::<Ev>);
+        self
+    }
+}
+
+impl<M, Cx, Ev> EnvelopedMediatorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener receiving the event as [`Enveloped`] to the [`CxAwareBuilder`].
+    ///
+    fn add_enveloped_listener(
+        self,
+        f: impl Fn(&Enveloped<'_, Ev>) + Send + Sync + 'static,
+    ) -> Self {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| f(&Enveloped::current(ev))),
+        );
+        self
+    }
+}
+
+impl<M, Cx, Ev> CatchAllMediatorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener receiving every event along with its [`EventMeta`] to the [`CxAwareBuilder`].
+    ///
+    fn add_catch_all_listener(
+        mut self,
+        f: impl Fn(&Ev, &EventMeta) + Send + Sync + 'static,
+    ) -> Self {
+        self.mediator.listener.add(
+            type_name_of_val(&f),
+            ExclusiveListener::new(move |ev: &Ev| f(ev, &EventMeta::current())),
+        );
+        self.mediator.catch_all = true;
+        self
+    }
+}
+
+impl<M, Cx, Ev> InterceptorBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined [`PublishInterceptor`] to the [`CxAwareBuilder`].
+    ///
+    fn add_publish_interceptor(mut self, f: impl PublishInterceptor<Ev>) -> Self {
+        self.mediator.interceptors.push(Box::new(f));
+        self
+    }
+}
+
+impl<M, Cx, Ev> RequestEventsBuilderInterface<M, SystemEvent<Ev>>
+    for CxAwareBuilder<Cx, SystemEvent<Ev>>
+where
+    Ev: MaybeDebug,
+{
+    /// Enables or disables the request events of the [`CxAwareBuilder`].
+    ///
+    fn with_request_events(mut self, enabled: bool) -> Self {
+        self.mediator.request_events = enabled.then_some(SystemEvent::request as RequestEvent<_>);
+        self
+    }
+}
+
+impl<Cx, Ev> CxAwareDynamicHandlerBuilderInterface<CxAwareMediator<Cx, Ev>, Cx, Ev>
+    for CxAwareBuilder<Cx, Ev>
+where
+    Cx: 'static,
+    Ev: MaybeDebug + 'static,
+{
+    /// Registers a handler for requests of type `Req` with the [`CxAwareBuilder`].
+    ///
+    fn register_handler<Req>(
+        mut self,
+        handler: impl Fn(&CxAwareMediator<Cx, Ev>, Req, &Cx) + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+    {
+        self.mediator.handlers.insert::<Req>(erase(
+            move |m: &CxAwareMediator<Cx, Ev>, req: Req| {
+                m.basic
+                    .run_request("send_dyn", req, |req| handler(m, req, &m.cx))
+            },
+        ));
+        self
+    }
+}
+
+impl<Cx, Ev> CxAwareClosureHandlerBuilderInterface<CxAwareMediator<Cx, Ev>, Cx, Ev>
+    for CxAwareBuilder<Cx, Ev>
+where
+    Cx: 'static,
+    Ev: MaybeDebug + 'static,
+{
+    /// Adds a closure handling requests of type `Req` to the [`CxAwareBuilder`].
+    ///
+    fn add_request_handler<Req, Resp>(
+        mut self,
+        handler: impl Fn(&CxAwareMediator<Cx, Ev>, Req, &Cx) -> Resp + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Resp: 'static,
+    {
+        self.mediator.closures.insert_closure(Box::new(
+            move |m: &CxAwareMediator<Cx, Ev>, req: Req| handler(m, req, &m.cx),
+        ));
+        self
+    }
+
+    /// Adds a closure handling requests of type `Req` together with others to the [`CxAwareBuilder`].
+    ///
+    fn add_fan_out_handler<Req>(
+        mut self,
+        handler: impl Fn(&CxAwareMediator<Cx, Ev>, &Req, &Cx) + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+    {
+        self.mediator
+            .fan_out
+            .push_fan_out::<Req, FanOutHandler<CxAwareMediator<Cx, Ev>, Req>>(Box::new(
+                move |m: &CxAwareMediator<Cx, Ev>, req: &Req| handler(m, req, &m.cx),
+            ));
+        self
+    }
+}
+
+impl<Cx, Ev> StandaloneHandlerBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>
+    for CxAwareBuilder<Cx, Ev>
+where
+    Cx: 'static,
+    Ev: MaybeDebug + 'static,
+{
+    /// Registers a [`Handler`] handling requests of type `Req` with the [`CxAwareBuilder`].
+    ///
+    fn register<Req, Resp>(
+        mut self,
+        handler: impl Handler<Req, Ev, Resp> + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Resp: 'static,
+    {
+        self.mediator.closures.insert_closure(Box::new(
+            move |m: &CxAwareMediator<Cx, Ev>, req: Req| handler.handle(&m.basic, req),
+        ));
+        self
+    }
+}
+
+impl<M, Cx, Ev> ChannelBackendBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Creates the channel of the [`CxAwareBuilder`] through the [`ChannelFlavor`] `F`.
+    ///
+    fn with_channel_backend<F: ChannelFlavor>(mut self) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        self.mediator.channel.set_flavor::<F>();
+        self
+    }
+}
+
+impl<M, Cx, Ev> ProfileBuilderInterface<M, Ev> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Configures the channel of the [`CxAwareBuilder`] from the [`Profile`].
+    ///
+    fn with_profile(mut self, profile: Profile) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        profile.configure(&mut self.mediator.channel);
+        self
+    }
+}
+
+impl<Cx, Ev> CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined listener to the [`CxAwareBuilder`].
+    ///
+    /// The supplied type must be a [`Listener`].
+    /// As such, it must implement [`Send`] and `Fn(Ev)`,
+    /// besides being `'static`.
+    ///
+    /// As a side note, here, `Ev` is the user-defined event type
+    /// that must be [`MaybeDebug`].
+    ///
+    /// Note: The following example will add a [`Listener`] to the builder,
+    /// but the result of `.build()` here will be an `Err` value.
+    /// This is because in order to receive a valid [`CxAwareMediator`]
+    /// you need to add a context. See [`CxAwareBuilder::add_context()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::contextaware::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct MyContext(u32);
+    ///
+    /// let mediator = CxAwareMediator::<MyContext, MyEvent>::builder()
+    ///     .add_listener(|_: &MyEvent| {
+    ///         /* Your listening logic */
+    ///     })
+    ///     .build();
+    ///
+    pub fn add_listener(self, f: impl Listener<Ev>) -> Self {
+        <Self as BasicMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_listener(self, f)
+    }
+
+    /// Adds a user-defined listener with a `name` to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_named()`] for more info.
+    ///
+    pub fn add_listener_named(self, name: &'static str, f: impl Listener<Ev>) -> Self {
+        <Self as NamedListenerBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_listener_named(
+            self, name, f,
+        )
+    }
+
+    /// Adds a user-defined context of type `Cx` to the [`CxAwareBuilder`].
+    ///
+    /// The context is available in [`super::CxAwareRequestHandler::handle()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::contextaware::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct MyContext(u32);
+    ///
+    /// let mediator = CxAwareMediator::<MyContext, MyEvent>::builder()
+    ///     .add_context(MyContext::default())
+    ///     .build();
+    ///
+    pub fn add_context(self, cx: Cx) -> Self {
+        <Self as CxAwareMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Cx, Ev>>::add_context(
+            self, cx,
+        )
+    }
+
+    /// Makes the [`CxAwareBuilder`] strict.
+    ///
+    /// Building a strict builder fails with [`BuildError::NoListeners`]
+    /// if no listener was added, and with [`BuildError::DuplicateTopicSubscription`]
+    /// if more than one listener was added for the same topic pattern.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::contextaware::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct MyContext(u32);
+    ///
+    /// let res = CxAwareMediator::<MyContext, MyEvent>::builder()
+    ///     .add_context(MyContext::default())
+    ///     .strict()
+    ///     .build();
+    ///
+    /// assert_eq!(res.unwrap_err(), BuildError::NoListeners);
+    ///
+    pub fn strict(self) -> Self {
+        <Self as StrictBuilderInterface<CxAwareMediator<Cx, Ev>>>::strict(self)
+    }
+
+    /// Calls the [`CxLifecycle`] hooks of the context.
+    ///
+    /// [`CxLifecycle::on_start()`] is called when the [`CxAwareMediator`] is built
+    /// and [`CxLifecycle::on_shutdown()`] by [`CxAwareMediator::shutdown()`].
+    /// See [`CxLifecycle`] for an example.
+    ///
+    #[cfg(feature = "async")]
+    pub fn with_lifecycle(self) -> Self
+    where
+        Cx: CxLifecycle,
+    {
+        <Self as CxAwareLifecycleBuilderInterface<CxAwareMediator<Cx, Ev>, Cx>>::with_lifecycle(
+            self,
+        )
+    }
+
+    /// Adds a user-defined [`Behavior`] to the [`CxAwareBuilder`].
+    ///
+    /// Behaviors wrap every request sent to the mediator
+    /// in the order they were added.
+    /// See [`crate::synchronous::basic::BasicBuilder::add_behavior()`] for more info.
+    ///
+    pub fn add_behavior(self, b: impl Behavior) -> Self {
+        <Self as BehaviorMediatorBuilderInterface<CxAwareMediator<Cx, Ev>>>::add_behavior(self, b)
+    }
+
+    /// Bounds the channel of the [`CxAwareBuilder`] to `capacity` events.
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_capacity()`] for more info.
+    ///
+    /// # Errors
+    ///
+    /// Building fails with [`BuildError::InvalidCapacity`] if `capacity` is `0`.
+    ///
+    pub fn with_capacity(self, capacity: usize) -> Self {
+        <Self as ChannelMediatorBuilderInterface<CxAwareMediator<Cx, Ev>>>::with_capacity(
+            self, capacity,
+        )
+    }
+
+    /// Sets the [`OverflowPolicy`] of the [`CxAwareBuilder`].
+    ///
+    /// The policy only takes effect if the channel was bounded
+    /// with [`CxAwareBuilder::with_capacity()`].
+    ///
+    pub fn with_overflow_policy(self, policy: OverflowPolicy) -> Self {
+        <Self as ChannelMediatorBuilderInterface<CxAwareMediator<Cx, Ev>>>::with_overflow_policy(
+            self, policy,
+        )
+    }
+    #[cfg(feature = "std")]
+    /// Enables or disables listener isolation of the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::isolate_listeners()`] for more info.
+    ///
+    pub fn isolate_listeners(self, isolate: bool) -> Self {
+        <Self as ListenerIsolationBuilderInterface<CxAwareMediator<Cx, Ev>>>::isolate_listeners(
+            self, isolate,
+        )
+    }
+    #[cfg(feature = "std")]
+    /// Sets the [`RateLimit`] of the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_rate_limit()`] for more info.
+    ///
+    pub fn with_rate_limit(self, limit: RateLimit) -> Self {
+        <Self as RateLimitBuilderInterface<CxAwareMediator<Cx, Ev>>>::with_rate_limit(self, limit)
+    }
+    /// Adds a user-defined listener for a topic to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_for_topic()`] for more info.
+    ///
+    pub fn add_listener_for_topic(self, topic: &str, f: impl Listener<Ev>) -> Self {
+        <Self as TopicMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_listener_for_topic(
+            self, topic, f,
+        )
+    }
+
+    /// Adds a user-defined listener guarded by a [`Filter`] to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_filtered_listener()`] for more info.
+    ///
+    pub fn add_filtered_listener(self, filter: impl Filter<Ev>, f: impl Listener<Ev>) -> Self {
+        <Self as FilterMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_filtered_listener(
+            self, filter, f,
+        )
+    }
+
+    /// Adds a user-defined listener for events with certain variant names to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_for_variants()`] for more info.
+    ///
+    pub fn add_listener_for_variants(
+        self,
+        variants: impl IntoIterator<Item = &'static str>,
+        f: impl Listener<Ev>,
+    ) -> Self
+    where
+        for<'a> &'a Ev: Into<&'static str>,
+    {
+        <Self as VariantMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_listener_for_variants(
+            self, variants, f,
+        )
+    }
+
+    /// Adds a user-defined listener for the events convertible into `SubEv` to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_converting_listener()`] for more info.
+    ///
+    pub fn add_converting_listener<SubEv>(self, f: impl Fn(SubEv) + Send + Sync + 'static) -> Self
+    where
+        Ev: Clone + TryInto<SubEv>,
+    {
+        <Self as ConvertingMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_converting_listener(self, f)
+    }
+
+    /// Adds a user-defined listener with a `priority` to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_listener_with_priority()`] for more info.
+    ///
+    pub fn add_listener_with_priority(self, priority: i32, f: impl Listener<Ev>) -> Self {
+        <Self as PriorityMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_listener_with_priority(
+            self, priority, f,
+        )
+    }
+
+    /// Adds a user-defined notification handler to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_handler()`] for more info.
+    ///
+    pub fn add_handler(self, handler: Arc<dyn NotificationHandler<Ev>>) -> Self
+    where
+        Ev: 'static,
+    {
+        <Self as HandlerMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_handler(
+            self, handler,
+        )
+    }
+
+    /// Adds a [`MediatorObserver`] to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_observer()`] for more info.
+    ///
+    pub fn with_observer(self, observer: impl MediatorObserver<Ev> + 'static) -> Self {
+        <Self as ObserverMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::with_observer(
+            self, observer,
+        )
+    }
+
+    /// Adds a hook invoked before every request sent to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::on_before_send()`] for more info.
+    ///
+    pub fn on_before_send(self, f: impl Fn(&RequestMeta) + Send + Sync + 'static) -> Self {
+        <Self as RequestHookBuilderInterface<CxAwareMediator<Cx, Ev>>>::on_before_send(self, f)
+    }
+
+    /// Adds a hook invoked after every request sent to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::on_after_send()`] for more info.
+    ///
+    pub fn on_after_send(self, f: impl Fn(&RequestMeta, Duration) + Send + Sync + 'static) -> Self {
+        <Self as RequestHookBuilderInterface<CxAwareMediator<Cx, Ev>>>::on_after_send(self, f)
+    }
+
+    /// Records the flows of requests, events and listeners of the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::record_causality()`] for more info.
+    ///
+    #[cfg(feature = "causality")]
+    pub fn record_causality(self) -> Self
+    where
+        Ev: Debug,
+    {
+        <Self as CausalityBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::record_causality(self)
+    }
+
+    /// Sets the capacity of the replay buffer of the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_replay_capacity()`] for more info.
+    ///
+    pub fn with_replay_capacity(self, capacity: usize) -> Self
+    where
+        Ev: Clone,
+    {
+        <Self as ReplayMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::with_replay_capacity(
+            self, capacity,
+        )
+    }
+
+    /// Enables or disables the dead-letter queue of the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::collect_dead_letters()`] for more info.
+    ///
+    pub fn collect_dead_letters(self, collect: bool) -> Self {
+        <Self as DeadLetterMediatorBuilderInterface<CxAwareMediator<Cx, Ev>>>::collect_dead_letters(
+            self, collect,
+        )
+    }
+
+    /// Adds a user-defined listener receiving the event as [`Enveloped`] to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_enveloped_listener()`] for more info.
+    ///
+    pub fn add_enveloped_listener(
+        self,
+        f: impl Fn(&Enveloped<'_, Ev>) + Send + Sync + 'static,
+    ) -> Self {
+        <Self as EnvelopedMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_enveloped_listener(
+            self, f,
+        )
+    }
+
+    /// Adds a user-defined listener receiving every event along with its [`EventMeta`] to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_catch_all_listener()`] for more info.
+    ///
+    pub fn add_catch_all_listener(
+        self,
+        f: impl Fn(&Ev, &EventMeta) + Send + Sync + 'static,
+    ) -> Self {
+        <Self as CatchAllMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_catch_all_listener(self, f)
+    }
+
+    /// Adds a user-defined [`PublishInterceptor`] to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_publish_interceptor()`] for more info.
+    ///
+    pub fn add_publish_interceptor(self, f: impl PublishInterceptor<Ev>) -> Self {
+        <Self as InterceptorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_publish_interceptor(
+            self, f,
+        )
+    }
+
+    /// Adds a [`LoggingListener`] to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_logging_listener()`] for more info.
+    ///
+    #[cfg(feature = "tracing")]
+    pub fn add_logging_listener(self, listener: impl Into<LoggingListener<Ev>>) -> Self
+    where
+        Ev: 'static,
+    {
+        <Self as LoggingMediatorBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_logging_listener(
+            self, listener,
+        )
+    }
+
+    /// Adds an upcaster for events of the version `from_version` to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::register_upcaster()`] for more info.
+    ///
+    #[cfg(feature = "persistence")]
+    pub fn register_upcaster(
+        self,
+        from_version: u32,
+        f: impl Fn(Value) -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        <Self as UpcasterBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::register_upcaster(
+            self,
+            from_version,
+            f,
+        )
+    }
+
+    /// Adds a user-defined listener receiving the event in an [`Arc`] to the [`CxAwareBuilder`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_shared_listener()`] for more info.
+    ///
+    pub fn add_shared_listener(self, f: impl SharedListener<Ev>) -> Self
+    where
+        Ev: Clone,
+    {
+        <Self as SharedListenerBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::add_shared_listener(
+            self, f,
+        )
+    }
+
+    /// Registers a handler for requests of type `Req` with the [`CxAwareBuilder`].
+    ///
+    /// The handler has access to the context `Cx`.
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::register_handler()`] for more info.
+    ///
+    pub fn register_handler<Req>(
+        self,
+        handler: impl Fn(&CxAwareMediator<Cx, Ev>, Req, &Cx) + Send + Sync + 'static,
+    ) -> Self
+    where
+        Cx: 'static,
+        Ev: 'static,
+        Req: Send + 'static,
+    {
+        <Self as CxAwareDynamicHandlerBuilderInterface<CxAwareMediator<Cx, Ev>, Cx, Ev>>::register_handler(
+            self, handler,
+        )
+    }
+
+    /// Adds a closure handling requests of type `Req` to the [`CxAwareBuilder`].
+    ///
+    /// The closure has access to the context `Cx`.
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_request_handler()`] for more info.
+    ///
+    pub fn add_request_handler<Req, Resp>(
+        self,
+        handler: impl Fn(&CxAwareMediator<Cx, Ev>, Req, &Cx) -> Resp + Send + Sync + 'static,
+    ) -> Self
+    where
+        Cx: 'static,
+        Ev: 'static,
+        Req: Send + 'static,
+        Resp: 'static,
+    {
+        <Self as CxAwareClosureHandlerBuilderInterface<CxAwareMediator<Cx, Ev>, Cx, Ev>>::add_request_handler(
+            self, handler,
+        )
+    }
+
+    /// Adds a closure handling requests of type `Req` together with others to the [`CxAwareBuilder`].
+    ///
+    /// The closure has access to the context `Cx`.
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::add_fan_out_handler()`] for more info.
+    ///
+    pub fn add_fan_out_handler<Req>(
+        self,
+        handler: impl Fn(&CxAwareMediator<Cx, Ev>, &Req, &Cx) + Send + Sync + 'static,
+    ) -> Self
+    where
+        Cx: 'static,
+        Ev: 'static,
+        Req: Send + 'static,
+    {
+        <Self as CxAwareClosureHandlerBuilderInterface<CxAwareMediator<Cx, Ev>, Cx, Ev>>::add_fan_out_handler(
+            self, handler,
+        )
+    }
+
+    /// Registers a [`Handler`] handling requests of type `Req` with the [`CxAwareBuilder`].
+    ///
+    /// The handler receives the [`BasicMediator`] the [`CxAwareMediator`] is built upon,
+    /// and holds its dependencies itself instead of taking them from the context.
+    /// Requests are routed to it via `send_registered()`.
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::register()`] for more info.
+    ///
+    pub fn register<Req, Resp>(
+        self,
+        handler: impl Handler<Req, Ev, Resp> + Send + Sync + 'static,
+    ) -> Self
+    where
+        Cx: 'static,
+        Ev: 'static,
+        Req: Send + 'static,
+        Resp: 'static,
+    {
+        <Self as StandaloneHandlerBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::register(
+            self, handler,
+        )
+    }
+
+    /// Creates the channel of the [`CxAwareBuilder`] through the [`ChannelFlavor`] `F`.
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_channel_backend()`] for more info.
+    ///
+    pub fn with_channel_backend<F: ChannelFlavor>(self) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        <Self as ChannelBackendBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::with_channel_backend::<
+            F,
+        >(self)
+    }
+
+    /// Configures the channel of the [`CxAwareBuilder`] from the [`Profile`].
+    ///
+    /// See [`crate::synchronous::basic::BasicBuilder::with_profile()`] for more info.
+    ///
+    pub fn with_profile(self, profile: Profile) -> Self
+    where
+        Ev: Send + 'static,
+    {
+        <Self as ProfileBuilderInterface<CxAwareMediator<Cx, Ev>, Ev>>::with_profile(self, profile)
+    }
+}
+
+impl<M, Ev> CxAwareDependencyBuilderInterface<M, Ev> for CxAwareBuilder<Dependencies, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined dependency of type `T` to the [`CxAwareBuilder`].
+    ///
+    /// The dependency is available in the [`Dependencies`] context
+    /// of [`super::CxAwareRequestHandler::handle()`].
+    ///
+    fn add_dependency<T>(mut self, value: T) -> Self
+    where
+        T: Send + Sync + 'static,
+    {
+        self.cx
+            .get_or_insert_with(Dependencies::default)
+            .insert(value);
+        self
+    }
+}
+
+impl<Ev> CxAwareBuilder<Dependencies, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Adds a user-defined dependency of type `T` to the [`CxAwareBuilder`].
+    ///
+    /// This is only available if the context is of type [`Dependencies`].
+    /// Each dependency is stored by its type, which means that adding
+    /// a dependency of the same type twice replaces the first one.
+    /// Adding a dependency counts as adding a context.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::contextaware::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// struct DbPool;
+    /// struct Config { retries: u32 }
+    ///
+    /// let mediator = CxAwareMediator::<Dependencies, MyEvent>::builder()
+    ///     .add_dependency(DbPool)
+    ///     .add_dependency(Config { retries: 3 })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    pub fn add_dependency<T>(self, value: T) -> Self
+    where
+        T: Send + Sync + 'static,
+    {
+        <Self as CxAwareDependencyBuilderInterface<CxAwareMediator<Dependencies, Ev>, Ev>>::add_dependency(
+            self, value,
+        )
+    }
+}
+
+#[cfg(feature = "async")]
+impl<M, Cx, Ev> CxAwareLifecycleBuilderInterface<M, Cx> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Calls the [`CxLifecycle`] hooks of the context.
+    ///
+    fn with_lifecycle(mut self) -> Self
+    where
+        Cx: CxLifecycle,
+    {
+        self.lifecycle = Some(Lifecycle::new());
+        self
+    }
+}
+
+impl<M, Cx, Ev> StrictBuilderInterface<M> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Makes the [`CxAwareBuilder`] strict.
+    ///
+    fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+}
+
+impl<Cx, Ev> CxAwareBuilder<Cx, SystemEvent<Ev>>
+where
+    Ev: MaybeDebug,
+{
+    /// Publishes the request events of every request, if `enabled`.
+    ///
+    /// See [`BasicBuilder::with_request_events()`](crate::synchronous::basic::BasicBuilder::with_request_events) for more info.
+    ///
+    pub fn with_request_events(self, enabled: bool) -> Self {
+        <Self as RequestEventsBuilderInterface<
+            CxAwareMediator<Cx, SystemEvent<Ev>>,
+            SystemEvent<Ev>,
+        >>::with_request_events(self, enabled)
+    }
+}
+
+impl<Cx, Ev> TryBuilderFlow<CxAwareMediator<Cx, Ev>> for CxAwareBuilder<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    type Error = BuildError;
+    /// Builds the [`CxAwareMediator`] and returns it.
+    ///
+    /// Because [`CxAwareMediator`] implements [`TryBuilderInternal`],
+    /// which in turn means, that the [`CxAwareBuilder`] implements [`TryBuilderFlow`]
+    /// this method will return a [`Result<CxAwareMediator<Cx, Ev>, Self::Error>`] as stated by the return type.
+    /// Note that here `Self::Error` is a [`BuildError`], e.g. [`BuildError::NoCxAvailable`]
+    /// if no context was added in the process of building.
+    ///
+    fn build(self) -> Result<CxAwareMediator<Cx, Ev>, Self::Error> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        let cx = self.cx.ok_or(BuildError::NoCxAvailable)?;
+        if self.strict {
+            validate_strict(self.mediator.listener.topics())?;
+        }
+        #[cfg(feature = "async")]
+        if let Some(lifecycle) = &self.lifecycle {
+            lifecycle.start(&cx);
+        }
+        Ok(CxAwareMediator {
+            basic: self.mediator,
+            cx,
+            #[cfg(feature = "async")]
+            lifecycle: Mutex::new(self.lifecycle),
+        })
+    }
+}
@@ -0,0 +1,658 @@
+use alloc::boxed::Box;
+use core::{any::Any, fmt::Debug, time::Duration};
+
+#[cfg(feature = "persistence")]
+use crate::mediator::codec::Codec;
+#[cfg(feature = "persistence")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "persistence")]
+use std::{io, path::Path};
+
+use crate::mediator::behavior::{run_pipeline, AnyRequest};
+#[cfg(feature = "causality")]
+use crate::mediator::causality::CausalityGraph;
+use crate::mediator::channel::TrySendError;
+use crate::mediator::correlation::{self, CorrelationId, Trace};
+use crate::mediator::registry::FanOutHandler;
+#[cfg(feature = "schema")]
+use crate::mediator::schema::MediatorDescription;
+use crate::mediator::stats::MediatorStats;
+use crate::mediator::system;
+use crate::synchronous::basic::BasicMediator;
+
+#[cfg(feature = "async")]
+use crate::mediator::{lifecycle::Lifecycle, sync::Mutex};
+#[cfg(feature = "async")]
+use async_trait::async_trait;
+
+use super::*;
+
+/// Context aware mediator for synchronous environments with events of type `Ev`.
+///
+/// Uses an underlying [`BasicMediator`] for base functionality
+/// and stores the user-defined context `Cx`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::contextaware::*;
+/// use std::sync::Arc;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     One,
+///     Two
+/// }
+///
+/// #[derive(Debug, Default)]
+/// struct MyContext(Arc<u32>);
+///
+/// struct Request(u32);
+///
+/// impl CxAwareRequestHandler<MyContext, Request, MyEvent> for CxAwareMediator<MyContext, MyEvent> {
+///     fn handle(&self, req: Request, cx: &MyContext) {
+///         let my_context: u32 = *cx.0;
+///         match req.0 {
+///             1 => self.publish(MyEvent::One),
+///             2 => self.publish(MyEvent::Two),
+///             _ => ()
+///         };
+///     }
+/// }
+///
+/// let mediator = CxAwareMediator::<MyContext, MyEvent>::builder()
+///     .add_listener(move |_: &MyEvent| {
+///         /* Your listening logic */
+///     })
+///     .add_listener(move |_: &MyEvent| {
+///         /* Your listening logic */
+///     })
+///     .add_context(MyContext::default())
+///     .build()
+///     .unwrap();
+///
+/// mediator.send(Request(1));
+/// mediator.next().ok();
+///
+#[derive(Debug)]
+pub struct CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    pub(crate) basic: BasicMediator<Ev>,
+    pub(crate) cx: Cx,
+    #[cfg(feature = "async")]
+    pub(crate) lifecycle: Mutex<Option<Lifecycle<Cx>>>,
+}
+
+impl<Cx, Ev> SyncMediatorInternal<Ev> for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Publishes an event `Ev`.
+    ///
+    /// This method instructs the underlying [`BasicMediator`]
+    /// to publish a user-defined event.
+    ///
+    /// It should be used within [`CxAwareRequestHandler::handle()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::contextaware::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One,
+    ///     Two
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct MyContext(u32);
+    ///
+    /// struct Request(u32);
+    ///
+    /// impl CxAwareRequestHandler<MyContext, Request, MyEvent> for CxAwareMediator<MyContext, MyEvent> {
+    ///     fn handle(&self, req: Request, cx: &MyContext) {
+    ///         match req.0 + cx.0 {
+    ///             1 => self.publish(MyEvent::One),
+    ///             2 => self.publish(MyEvent::Two),
+    ///             _ => ()
+    ///         };
+    ///     }
+    /// }
+    ///
+    fn publish(&self, event: Ev) {
+        self.basic.publish(event)
+    }
+
+    /// Tries to publish an event `Ev`.
+    ///
+    /// See [`BasicMediator::try_publish()`] for more info.
+    ///
+    fn try_publish(&self, event: Ev) -> Result<(), TrySendError<Ev>> {
+        self.basic.try_publish(event)
+    }
+
+    /// Publishes an event `Ev` to the given `topic`.
+    ///
+    /// See [`BasicMediator::publish_to()`] for more info.
+    ///
+    fn publish_to(&self, topic: &str, event: Ev) {
+        self.basic.publish_to(topic, event)
+    }
+
+    /// Publishes an event `Ev` that expires once the `ttl` elapsed.
+    ///
+    /// See [`BasicMediator::publish_with_ttl()`] for more info.
+    ///
+    fn publish_with_ttl(&self, event: Ev, ttl: Duration) {
+        self.basic.publish_with_ttl(event, ttl)
+    }
+
+    /// Publishes an urgent event `Ev`, which is delivered before all regular events.
+    ///
+    /// See [`BasicMediator::publish_urgent()`] for more info.
+    ///
+    fn publish_urgent(&self, event: Ev) {
+        self.basic.publish_urgent(event)
+    }
+}
+
+impl<Cx, Ev> CxAwareMediatorInternalHandle<Cx, Ev> for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Send a request of type `Req` to the mediator.
+    ///
+    /// The request will be processed internally by [`CxAwareRequestHandler::handle()`].
+    /// This is why it is required to implement [`CxAwareRequestHandler`] for [`CxAwareMediator`].
+    /// If any [`Behavior`] was added, the request passes through them first.
+    ///
+    fn send<Req>(&self, req: Req)
+    where
+        Self: CxAwareRequestHandler<Cx, Req, Ev>,
+        Req: Send + 'static,
+    {
+        self.basic.run_request("send", req, |req| {
+            <Self as CxAwareRequestHandler<Cx, Req, Ev>>::handle(self, req, &self.cx)
+        })
+    }
+
+    /// Send a request of unknown type to the mediator.
+    ///
+    /// The request is processed by the handler registered for its type
+    /// via [`CxAwareBuilder::register_handler()`](super::CxAwareBuilder::register_handler()).
+    ///
+    /// See [`BasicMediator::send_dyn()`] for more info.
+    ///
+    fn send_dyn(&self, req: Box<dyn Any + Send>) -> Result<(), Box<dyn Any + Send>>
+    where
+        Self: 'static,
+    {
+        match self.basic.handlers.get(&*req) {
+            Some(handler) => {
+                handler(self, req);
+                Ok(())
+            }
+            None => Err(req),
+        }
+    }
+
+    /// Send a request of type `Req` to the mediator and correlate it with the resulting events.
+    ///
+    /// See [`BasicMediator::send_traced()`] for more info.
+    ///
+    fn send_traced<Req>(&self, req: Req) -> CorrelationId
+    where
+        Self: CxAwareRequestHandler<Cx, Req, Ev>,
+        Req: Send + 'static,
+    {
+        let id = CorrelationId::next();
+        correlation::scope(Trace::current().traced(id), || self.send(req));
+        id
+    }
+
+    /// Send a request of type `Req` to the mediator and receive a response of type `Resp`.
+    ///
+    /// The request will be processed internally by [`CxAwareRequestHandler::handle()`],
+    /// whose return value is handed back to the caller.
+    ///
+    fn send_and_receive<Req, Resp>(&self, req: Req) -> Resp
+    where
+        Self: CxAwareRequestHandler<Cx, Req, Ev, Resp>,
+    {
+        self.basic.report::<Req, _>(system::completed, || {
+            self.basic
+                .observer
+                .request::<Req, _>("send_and_receive", || {
+                    <Self as CxAwareRequestHandler<Cx, Req, Ev, Resp>>::handle(self, req, &self.cx)
+                })
+        })
+    }
+
+    /// Send a request of type `Req` to the mediator together with a per-request `scope`.
+    ///
+    /// The request will be processed internally by [`ScopedCxAwareRequestHandler::handle()`],
+    /// which receives the scope next to the long-lived context `Cx`.
+    /// This keeps per-request data, e.g. the authenticated user or a request ID,
+    /// out of the request itself.
+    /// If any [`Behavior`] was added, the request passes through them first.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::contextaware::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     Deleted { id: u32, by: String }
+    /// }
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct MyContext;
+    ///
+    /// struct User(String);
+    ///
+    /// struct Delete(u32);
+    ///
+    /// impl ScopedCxAwareRequestHandler<MyContext, User, Delete, MyEvent> for CxAwareMediator<MyContext, MyEvent> {
+    ///     fn handle(&self, req: Delete, _cx: &MyContext, user: &User) {
+    ///         self.publish(MyEvent::Deleted { id: req.0, by: user.0.clone() });
+    ///     }
+    /// }
+    ///
+    /// let mediator = CxAwareMediator::<MyContext, MyEvent>::builder()
+    ///     .add_context(MyContext)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// mediator.send_with_scope(Delete(7), User("admin".into()));
+    /// assert_eq!(mediator.next_all(), 1);
+    ///
+    fn send_with_scope<Req, Scope>(&self, req: Req, scope: Scope)
+    where
+        Self: ScopedCxAwareRequestHandler<Cx, Scope, Req, Ev>,
+        Req: Send + 'static,
+    {
+        self.basic.run_request("send_with_scope", req, |req| {
+            <Self as ScopedCxAwareRequestHandler<Cx, Scope, Req, Ev>>::handle(
+                self, req, &self.cx, &scope,
+            )
+        })
+    }
+
+    /// Send a request of type `Req` to the mediator, which may fail with an error of type `Err`.
+    ///
+    /// The request will be processed internally by [`TryCxAwareRequestHandler::handle()`],
+    /// whose error is handed back to the caller.
+    ///
+    /// See [`BasicMediator::try_send()`] for more info.
+    ///
+    fn try_send<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Self: TryCxAwareRequestHandler<Cx, Req, Ev, Err>,
+        Req: Send + 'static,
+    {
+        self.basic.report::<Req, _>(system::failed, || {
+            self.basic.observer.request::<Req, _>("try_send", || {
+                if self.basic.behavior.is_empty() {
+                    return <Self as TryCxAwareRequestHandler<Cx, Req, Ev, Err>>::handle(
+                        self, req, &self.cx,
+                    );
+                }
+                let mut res = Ok(());
+                run_pipeline(&self.basic.behavior, AnyRequest::new(req), |req| {
+                    res = <Self as TryCxAwareRequestHandler<Cx, Req, Ev, Err>>::handle(
+                        self,
+                        req.into_inner(),
+                        &self.cx,
+                    )
+                });
+                res
+            })
+        })
+    }
+}
+
+impl<Cx, Ev> SyncMediatorInternalNext for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Process the next published event `Ev`.
+    ///
+    /// This method instructs the underlying [`BasicMediator`]
+    /// to process the next event.
+    ///
+    /// See [`BasicMediator::next()`] for more info.
+    ///
+    fn next(&self) -> Result<(), NextError> {
+        self.basic.next()
+    }
+
+    /// Process all pending published events `Ev`.
+    ///
+    /// See [`BasicMediator::next_all()`] for more info.
+    ///
+    fn next_all(&self) -> usize {
+        self.basic.next_all()
+    }
+
+    #[cfg(feature = "std")]
+    /// Process the next published event `Ev`, blocking until one is available.
+    ///
+    /// See [`BasicMediator::next_blocking()`] for more info.
+    ///
+    fn next_blocking(&self) -> Result<(), NextError> {
+        self.basic.next_blocking()
+    }
+
+    /// Returns an [`EventWorker`] to process events from another thread.
+    ///
+    /// The mediator can be shared between threads
+    /// if the context `Cx` is `Sync` and the event type `Ev` is `Send`.
+    /// See [`BasicMediator::worker()`] for more info.
+    ///
+    fn worker(&self) -> EventWorker<'_, Self>
+    where
+        Self: Sync,
+    {
+        EventWorker::new(self)
+    }
+}
+
+impl<Cx, Ev> SyncMediatorInternalSubscribe<Ev> for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Subscribes a user-defined listener to the already built [`CxAwareMediator`].
+    ///
+    /// This method instructs the underlying [`BasicMediator`]
+    /// to subscribe the listener.
+    ///
+    /// See [`BasicMediator::subscribe()`] for more info.
+    ///
+    fn subscribe(&self, f: impl Listener<Ev>) -> ListenerHandle {
+        self.basic.subscribe(f)
+    }
+
+    /// Unsubscribes the listener identified by `handle`.
+    ///
+    /// See [`BasicMediator::unsubscribe()`] for more info.
+    ///
+    fn unsubscribe(&self, handle: ListenerHandle) -> bool {
+        self.basic.unsubscribe(handle)
+    }
+
+    /// Subscribes a user-defined listener to the already built [`CxAwareMediator`]
+    /// and replays the last processed events to it.
+    ///
+    /// See [`BasicMediator::add_listener_with_replay()`] for more info.
+    ///
+    fn add_listener_with_replay(&self, f: impl Listener<Ev>) -> ListenerHandle {
+        self.basic.add_listener_with_replay(f)
+    }
+}
+
+impl<Cx, Ev> SyncMediatorInternalDeadLetters<Ev> for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Takes all events out of the dead-letter queue, oldest first.
+    ///
+    /// See [`BasicMediator::dead_letters()`] for more info.
+    ///
+    fn dead_letters(&self) -> DeadLetters<Ev> {
+        self.basic.dead_letters()
+    }
+
+    /// Publishes all events in the dead-letter queue again, oldest first.
+    ///
+    /// See [`BasicMediator::redeliver_all()`] for more info.
+    ///
+    fn redeliver_all(&self) -> usize {
+        self.basic.redeliver_all()
+    }
+
+    /// Publishes the events in the dead-letter queue that are due according to the `policy`.
+    ///
+    /// See [`BasicMediator::redeliver_with()`] for more info.
+    ///
+    fn redeliver_with(&self, policy: RedeliveryPolicy) -> usize {
+        self.basic.redeliver_with(policy)
+    }
+}
+
+impl<Cx, Ev> SyncMediatorInternalRegistered for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Send a request of type `Req` to the closure added for it.
+    ///
+    /// The closure is added via [`CxAwareBuilder::add_request_handler()`](super::CxAwareBuilder::add_request_handler())
+    /// and has access to the context `Cx`.
+    ///
+    /// See [`BasicMediator::send_registered()`] for more info.
+    ///
+    fn send_registered<Req>(&self, req: Req) -> Result<(), Req>
+    where
+        Self: 'static,
+        Req: Send + 'static,
+    {
+        let Some(handler) = self.basic.closures.closure::<Self, Req, ()>() else {
+            return Err(req);
+        };
+        self.basic
+            .run_request("send_registered", req, |req| handler(self, req));
+        Ok(())
+    }
+
+    /// Send a request of type `Req` to the closure added for it and receive a response of type `Resp`.
+    ///
+    /// See [`BasicMediator::send_registered_and_receive()`] for more info.
+    ///
+    fn send_registered_and_receive<Req, Resp>(&self, req: Req) -> Result<Resp, Req>
+    where
+        Self: 'static,
+        Req: 'static,
+        Resp: 'static,
+    {
+        let Some(handler) = self.basic.closures.closure::<Self, Req, Resp>() else {
+            return Err(req);
+        };
+        Ok(self.basic.report::<Req, _>(system::completed, || {
+            self.basic
+                .observer
+                .request::<Req, _>("send_registered_and_receive", || handler(self, req))
+        }))
+    }
+
+    /// Send a request of type `Req` to all closures added for it.
+    ///
+    /// The closures are added via [`CxAwareBuilder::add_fan_out_handler()`](super::CxAwareBuilder::add_fan_out_handler())
+    /// and have access to the context `Cx`.
+    ///
+    /// See [`BasicMediator::send_fan_out()`] for more info.
+    ///
+    fn send_fan_out<Req>(&self, req: Req) -> usize
+    where
+        Self: 'static,
+        Req: Send + 'static,
+    {
+        let handlers = self
+            .basic
+            .fan_out
+            .fan_out::<Req, FanOutHandler<Self, Req>>();
+        self.basic.run_request("send_fan_out", req, |req| {
+            handlers.iter().for_each(|handler| handler(self, &req))
+        });
+        handlers.len()
+    }
+}
+
+impl<Cx, Ev> SyncMediatorInternalStats for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Returns a snapshot of the counters of the [`CxAwareMediator`].
+    ///
+    /// See [`BasicMediator::stats()`] for more info.
+    ///
+    fn stats(&self) -> MediatorStats {
+        self.basic.stats()
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<Cx, Ev> SyncMediatorInternalDescribe for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug + schemars::JsonSchema,
+{
+    /// Returns a catalog of the events and listeners of the [`CxAwareMediator`].
+    ///
+    /// See [`BasicMediator::describe()`] for more info.
+    ///
+    fn describe(&self) -> MediatorDescription {
+        self.basic.describe()
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait]
+impl<Cx, Ev> CxAwareMediatorInternalShutdown for CxAwareMediator<Cx, Ev>
+where
+    Cx: Sync,
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Shuts down the [`CxAwareMediator`].
+    ///
+    /// If the mediator was built with
+    /// [`CxAwareBuilder::with_lifecycle()`](super::CxAwareBuilder::with_lifecycle()),
+    /// this calls [`CxLifecycle::on_shutdown()`](crate::lifecycle::CxLifecycle::on_shutdown())
+    /// on the context. The hook is called only once, shutting down again does nothing.
+    ///
+    /// The mediator can still be used afterwards,
+    /// but the context may no longer hold its resources.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use mediatrix::synchronous::contextaware::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum MyEvent {
+    ///     One
+    /// }
+    ///
+    /// struct MyContext;
+    ///
+    /// let mediator = CxAwareMediator::<MyContext, MyEvent>::builder()
+    ///     .add_context(MyContext)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// async_std::task::block_on(mediator.shutdown());
+    ///
+    async fn shutdown(&self) {
+        let lifecycle = self.lifecycle.lock().take();
+        if let Some(lifecycle) = lifecycle {
+            lifecycle.shutdown(&self.cx).await;
+        }
+    }
+}
+
+#[cfg(feature = "causality")]
+impl<Cx, Ev> SyncMediatorInternalCausality for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Returns the flows of requests, events and listeners recorded by the [`CxAwareMediator`].
+    ///
+    /// See [`BasicMediator::causality_graph()`] for more info.
+    ///
+    fn causality_graph(&self) -> CausalityGraph {
+        self.basic.causality_graph()
+    }
+}
+
+impl<Cx, Ev> SyncMediatorInternalPause for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Pauses the delivery of events to listeners.
+    ///
+    /// See [`BasicMediator::pause()`] for more info.
+    ///
+    fn pause(&self) {
+        self.basic.pause()
+    }
+
+    /// Resumes the delivery of events paused with [`CxAwareMediator::pause()`].
+    ///
+    /// See [`BasicMediator::resume()`] for more info.
+    ///
+    fn resume(&self) {
+        self.basic.resume()
+    }
+
+    /// Returns whether the delivery of events is paused.
+    ///
+    /// See [`BasicMediator::is_paused()`] for more info.
+    ///
+    fn is_paused(&self) -> bool {
+        self.basic.is_paused()
+    }
+}
+
+impl<Cx, Ev> SyncMediatorInternalPoison for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug,
+{
+    /// Returns whether a panic occurred while an internal lock of the [`CxAwareMediator`] was held.
+    ///
+    /// See [`BasicMediator::is_poisoned()`] for more info.
+    ///
+    fn is_poisoned(&self) -> bool {
+        self.basic.is_poisoned()
+    }
+
+    /// Clears the poisoning of all internal locks of the [`CxAwareMediator`].
+    ///
+    /// See [`BasicMediator::heal()`] for more info.
+    ///
+    fn heal(&self) {
+        self.basic.heal()
+    }
+}
+
+#[cfg(feature = "persistence")]
+impl<Cx, Ev> SyncMediatorInternalJournal<Ev> for CxAwareMediator<Cx, Ev>
+where
+    Ev: MaybeDebug + DeserializeOwned,
+{
+    /// Publishes all events recorded in the journal at `path`, oldest first.
+    ///
+    /// See [`BasicMediator::replay_from()`] for more info.
+    ///
+    fn replay_from(&self, path: impl AsRef<Path>) -> io::Result<usize> {
+        self.basic.replay_from(path)
+    }
+
+    /// Publishes all events recorded in the journal at `path` in the format of the `codec`, oldest first.
+    ///
+    /// See [`BasicMediator::replay_from_with()`] for more info.
+    ///
+    fn replay_from_with(&self, path: impl AsRef<Path>, codec: impl Codec) -> io::Result<usize> {
+        self.basic.replay_from_with(path, codec)
+    }
+}
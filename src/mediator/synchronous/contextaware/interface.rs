@@ -0,0 +1,150 @@
+use alloc::boxed::Box;
+use core::any::Any;
+
+use crate::mediator::correlation::CorrelationId;
+use crate::mediator::listener::MaybeDebug;
+
+#[cfg(feature = "async")]
+use crate::mediator::lifecycle::CxLifecycle;
+#[cfg(feature = "async")]
+use async_trait::async_trait;
+
+/// Send a request `Req` for processing to the mediator.
+/// This will call the handler.
+/// The handler here is context-dependent.
+pub trait CxAwareMediatorInternalHandle<Cx, Ev: MaybeDebug> {
+    #[allow(missing_docs)]
+    fn send<Req>(&self, req: Req)
+    where
+        Self: CxAwareRequestHandler<Cx, Req, Ev>,
+        Req: Send + 'static;
+
+    #[allow(missing_docs)]
+    fn send_traced<Req>(&self, req: Req) -> CorrelationId
+    where
+        Self: CxAwareRequestHandler<Cx, Req, Ev>,
+        Req: Send + 'static;
+
+    #[allow(missing_docs)]
+    fn send_and_receive<Req, Resp>(&self, req: Req) -> Resp
+    where
+        Self: CxAwareRequestHandler<Cx, Req, Ev, Resp>;
+
+    #[allow(missing_docs)]
+    fn send_with_scope<Req, Scope>(&self, req: Req, scope: Scope)
+    where
+        Self: ScopedCxAwareRequestHandler<Cx, Scope, Req, Ev>,
+        Req: Send + 'static;
+
+    #[allow(missing_docs)]
+    fn send_dyn(&self, req: Box<dyn Any + Send>) -> Result<(), Box<dyn Any + Send>>
+    where
+        Self: 'static;
+
+    #[allow(missing_docs)]
+    fn try_send<Req, Err>(&self, req: Req) -> Result<(), Err>
+    where
+        Self: TryCxAwareRequestHandler<Cx, Req, Ev, Err>,
+        Req: Send + 'static;
+}
+
+/// Handles the request `Req`.
+/// Implemented by the user.
+/// Gives access to the context `Cx`.
+/// Returns a response of type `Resp`, which defaults to `()`.
+pub trait CxAwareRequestHandler<Cx, Req, Ev, Resp = ()> {
+    #[allow(missing_docs)]
+    fn handle(&self, req: Req, cx: &Cx) -> Resp;
+}
+
+/// Handles the request `Req` sent with a per-request scope of type `Scope`.
+/// Implemented by the user.
+/// Gives access to the context `Cx` and the scope, e.g. the authenticated user.
+pub trait ScopedCxAwareRequestHandler<Cx, Scope, Req, Ev> {
+    #[allow(missing_docs)]
+    fn handle(&self, req: Req, cx: &Cx, scope: &Scope);
+}
+
+/// Handles the request `Req`, which may fail with an error of type `Err`.
+/// Implemented by the user.
+/// Gives access to the context `Cx`.
+pub trait TryCxAwareRequestHandler<Cx, Req, Ev, Err> {
+    #[allow(missing_docs)]
+    fn handle(&self, req: Req, cx: &Cx) -> Result<(), Err>;
+}
+
+/// Advanced builder fuctionality:
+/// Adding a context `cx` to the builder.
+pub trait CxAwareMediatorBuilderInterface<M, Cx, Ev> {
+    #[allow(missing_docs)]
+    fn add_context(self, cx: Cx) -> Self
+    where
+        Ev: MaybeDebug;
+}
+
+/// Validation builder fuctionality:
+/// Failing the build if no listener was added or a topic was subscribed to more than once.
+pub trait StrictBuilderInterface<M> {
+    #[allow(missing_docs)]
+    fn strict(self) -> Self;
+}
+
+/// Advanced builder fuctionality:
+/// Adding a single dependency of type `T` to a [`crate::dependencies::Dependencies`] context.
+pub trait CxAwareDependencyBuilderInterface<M, Ev> {
+    #[allow(missing_docs)]
+    fn add_dependency<T>(self, value: T) -> Self
+    where
+        T: Send + Sync + 'static;
+}
+
+/// Lifecycle builder fuctionality:
+/// Calling the [`crate::lifecycle::CxLifecycle`] hooks of the context `Cx`.
+#[cfg(feature = "async")]
+pub trait CxAwareLifecycleBuilderInterface<M, Cx> {
+    #[allow(missing_docs)]
+    fn with_lifecycle(self) -> Self
+    where
+        Cx: CxLifecycle;
+}
+
+/// Shut down the mediator, calling the [`crate::lifecycle::CxLifecycle::on_shutdown()`]
+/// hook of its context if it was built with `with_lifecycle()`.
+#[cfg(feature = "async")]
+#[async_trait]
+pub trait CxAwareMediatorInternalShutdown {
+    #[allow(missing_docs)]
+    async fn shutdown(&self);
+}
+
+/// Register a handler for requests `Req` sent to the mediator `M` via `send_dyn()`.
+/// Gives access to the context `Cx`.
+pub trait CxAwareDynamicHandlerBuilderInterface<M, Cx, Ev> {
+    #[allow(missing_docs)]
+    fn register_handler<Req>(self, handler: impl Fn(&M, Req, &Cx) + Send + Sync + 'static) -> Self
+    where
+        Req: Send + 'static;
+}
+
+/// Closure handler builder fuctionality:
+/// Adding a closure handling requests `Req` sent to the mediator `M` via `send_registered()`,
+/// or one of many closures handling requests sent via `send_fan_out()`.
+/// Gives access to the context `Cx`.
+pub trait CxAwareClosureHandlerBuilderInterface<M, Cx, Ev> {
+    #[allow(missing_docs)]
+    fn add_request_handler<Req, Resp>(
+        self,
+        handler: impl Fn(&M, Req, &Cx) -> Resp + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static,
+        Resp: 'static;
+
+    #[allow(missing_docs)]
+    fn add_fan_out_handler<Req>(
+        self,
+        handler: impl Fn(&M, &Req, &Cx) + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: Send + 'static;
+}
@@ -0,0 +1,62 @@
+#[cfg(not(feature = "async-only"))]
+pub(crate) mod builder;
+#[cfg(not(feature = "async-only"))]
+#[allow(clippy::module_inception)]
+pub(crate) mod contextaware;
+pub(crate) mod interface;
+
+#[cfg(not(feature = "async-only"))]
+pub use builder::*;
+#[cfg(not(feature = "async-only"))]
+pub use contextaware::*;
+pub use interface::*;
+
+pub use crate::behavior::*;
+pub use crate::builder::{BuildError, TryBuilderFlow, TryBuilderInternal};
+#[cfg(feature = "causality")]
+pub use crate::causality::{CausalityGraph, CausalityNode};
+pub use crate::channel::OverflowPolicy;
+pub use crate::correlation::{CorrelationId, Enveloped, EventMeta};
+pub use crate::deadletter::{DeadLetter, DeadLetterReason, DeadLetters, RedeliveryPolicy};
+pub use crate::dependencies::*;
+#[cfg(feature = "async")]
+pub use crate::lifecycle::CxLifecycle;
+pub use crate::listener::*;
+pub use crate::mediator::synchronous::basic::basic::BasicMediator;
+#[cfg(feature = "schema")]
+pub use crate::mediator::synchronous::basic::interface::SyncMediatorInternalDescribe;
+pub use crate::mediator::synchronous::basic::interface::{
+    BehaviorMediatorBuilderInterface, CatchAllMediatorBuilderInterface,
+    ChannelBackendBuilderInterface, ChannelMediatorBuilderInterface,
+    DeadLetterMediatorBuilderInterface, EnvelopedMediatorBuilderInterface,
+    FilterMediatorBuilderInterface, Handler, HandlerMediatorBuilderInterface,
+    InterceptorBuilderInterface, NamedListenerBuilderInterface, ObserverMediatorBuilderInterface,
+    PriorityMediatorBuilderInterface, ProfileBuilderInterface, ReplayMediatorBuilderInterface,
+    RequestEventsBuilderInterface, StandaloneHandlerBuilderInterface, SyncMediatorInternal,
+    SyncMediatorInternalDeadLetters, SyncMediatorInternalNext, SyncMediatorInternalPause,
+    SyncMediatorInternalPoison, SyncMediatorInternalRegistered, SyncMediatorInternalStats,
+    SyncMediatorInternalSubscribe, TopicMediatorBuilderInterface,
+};
+#[cfg(feature = "causality")]
+pub use crate::mediator::synchronous::basic::interface::{
+    CausalityBuilderInterface, SyncMediatorInternalCausality,
+};
+#[cfg(feature = "std")]
+pub use crate::mediator::synchronous::basic::interface::{
+    ListenerIsolationBuilderInterface, RateLimitBuilderInterface,
+};
+#[cfg(feature = "persistence")]
+pub use crate::mediator::synchronous::basic::interface::{
+    SyncMediatorInternalJournal, UpcasterBuilderInterface,
+};
+#[cfg(not(feature = "async-only"))]
+pub use crate::mediator::synchronous::basic::worker::EventWorker;
+pub use crate::observer::{MediatorObserver, RequestMeta};
+#[cfg(feature = "persistence")]
+pub use crate::persistence::{Journal, JournalEntry};
+pub use crate::profile::Profile;
+#[cfg(feature = "std")]
+pub use crate::ratelimit::{RateLimit, ThrottlePolicy};
+#[cfg(feature = "schema")]
+pub use crate::schema::{ListenerDescription, MediatorDescription};
+pub use crate::stats::{ListenerStats, MediatorStats};
@@ -1,2 +1,7 @@
+#[cfg(not(feature = "async-only"))]
+/// Synchronous mediator for events of any type.
+pub mod any;
 /// Synchronous mediator with base functionality.
 pub mod basic;
+/// Synchronous mediator with base functionality + context awareness.
+pub mod contextaware;
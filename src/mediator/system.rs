@@ -0,0 +1,128 @@
+use core::{any::type_name, time::Duration};
+
+/// An event of a mediator with request events, wrapping the user-defined events `Ev`.
+///
+/// A mediator with events of type [`SystemEvent<Ev>`] built with `with_request_events(true)`
+/// publishes a [`SystemEvent::RequestCompleted`] or a [`SystemEvent::RequestFailed`]
+/// after every request sent to it, so monitoring listeners receive uniform lifecycle events
+/// without every handler publishing them. Handlers publish their own events as
+/// [`SystemEvent::Event`], e.g. with `publish(ev.into())`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::synchronous::basic::*;
+/// use mediatrix::system::SystemEvent;
+///
+/// #[derive(Debug)]
+/// enum MyEvent {
+///     Saved
+/// }
+///
+/// struct Save;
+///
+/// impl RequestHandler<Save, SystemEvent<MyEvent>> for BasicMediator<SystemEvent<MyEvent>> {
+///     fn handle(&self, _: Save) {
+///         self.publish(MyEvent::Saved.into());
+///     }
+/// }
+///
+/// let mediator = BasicMediator::<SystemEvent<MyEvent>>::builder()
+///     .with_request_events(true)
+///     .add_listener(|ev: &SystemEvent<MyEvent>| match ev {
+///         SystemEvent::Event(ev) => println!("{ev:?}"),
+///         SystemEvent::RequestCompleted { request_type, duration } => {
+///             println!("{request_type} took {duration:?}")
+///         }
+///         SystemEvent::RequestFailed { request_type, error_type } => {
+///             println!("{request_type} failed with an error of type {error_type}")
+///         }
+///     })
+///     .build();
+///
+/// mediator.send(Save);
+/// assert_eq!(mediator.next_all(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SystemEvent<Ev> {
+    /// A user-defined event.
+    Event(Ev),
+    /// A request was handled.
+    RequestCompleted {
+        /// The type name of the request.
+        request_type: &'static str,
+        /// How long it took to handle the request.
+        ///
+        /// Without the `std` feature, this is always zero.
+        duration: Duration,
+    },
+    /// A request sent with `try_send()` failed.
+    RequestFailed {
+        /// The type name of the request.
+        request_type: &'static str,
+        /// The type name of the error, e.g. to count failures by their kind.
+        ///
+        /// The error itself is only returned to the sender of the request,
+        /// so error types don't need to be [`Clone`] or [`Display`](core::fmt::Display).
+        error_type: &'static str,
+    },
+}
+
+impl<Ev> SystemEvent<Ev> {
+    /// Returns the user-defined event, if this is a [`SystemEvent::Event`].
+    pub fn event(&self) -> Option<&Ev> {
+        match self {
+            SystemEvent::Event(ev) => Some(ev),
+            _ => None,
+        }
+    }
+
+    /// Consumes the [`SystemEvent`] and returns the user-defined event, if any.
+    pub fn into_event(self) -> Option<Ev> {
+        match self {
+            SystemEvent::Event(ev) => Some(ev),
+            _ => None,
+        }
+    }
+
+    /// Creates the request event of the request `request_type`,
+    /// failed if there is an `error_type`.
+    pub(crate) fn request(
+        request_type: &'static str,
+        duration: Duration,
+        error_type: Option<&'static str>,
+    ) -> Self {
+        match error_type {
+            Some(error_type) => SystemEvent::RequestFailed {
+                request_type,
+                error_type,
+            },
+            None => SystemEvent::RequestCompleted {
+                request_type,
+                duration,
+            },
+        }
+    }
+}
+
+impl<Ev> From<Ev> for SystemEvent<Ev> {
+    fn from(ev: Ev) -> Self {
+        SystemEvent::Event(ev)
+    }
+}
+
+/// Creates the request event `Ev` of a finished request, enabled with `with_request_events()`.
+pub(crate) type RequestEvent<Ev> = fn(&'static str, Duration, Option<&'static str>) -> Ev;
+
+/// The outcome of a request that can't fail.
+pub(crate) fn completed<R>(_: &R) -> Option<&'static str> {
+    None
+}
+
+/// The outcome of a request sent with `try_send()`, the type name of the error if it failed.
+pub(crate) fn failed<T, Err>(res: &Result<T, Err>) -> Option<&'static str> {
+    res.as_ref().err().map(|_| type_name::<Err>())
+}
@@ -0,0 +1,127 @@
+use core::fmt::Debug;
+
+use crate::mediator::{
+    asynchronous::contextaware::{
+        ContextReadGuard, CxAwareAsyncMediator, CxAwareAsyncRequestHandler,
+    },
+    builder::{TryBuilderFlow, TryBuilderInternal},
+    listener::MaybeDebug,
+};
+
+/// A test bed for the [`CxAwareAsyncRequestHandler`]s of a [`CxAwareAsyncMediator`].
+///
+/// It builds a [`CxAwareAsyncMediator`] around a mock or stub context `Cx`
+/// and calls the handlers directly, bypassing behaviors and observers.
+/// Events published by the handlers are captured instead of being processed,
+/// so no listener is invoked and they can be asserted with
+/// [`CxAwareTestBed::published_events()`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::asynchronous::contextaware::*;
+/// use mediatrix::testing::CxAwareTestBed;
+/// use async_trait::async_trait;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyEvent {
+///     Greeted(String)
+/// }
+///
+/// struct Directory {
+///     name: fn(u32) -> String
+/// }
+///
+/// struct Greet(u32);
+///
+/// #[async_trait]
+/// impl CxAwareAsyncRequestHandler<Directory, Greet, MyEvent> for CxAwareAsyncMediator<Directory, MyEvent> {
+///     async fn handle(&self, req: Greet, cx: &Directory) {
+///         self.publish(MyEvent::Greeted((cx.name)(req.0))).await;
+///     }
+/// }
+///
+/// let stub = Directory {
+///     name: |_| "stub".to_string()
+/// };
+///
+/// async_std::task::block_on(async {
+///     let bed = CxAwareTestBed::<Directory, MyEvent>::new(stub);
+///
+///     assert_eq!(
+///         bed.send(Greet(1)).await.published_events(),
+///         vec![MyEvent::Greeted("stub".to_string())]
+///     );
+/// });
+///
+pub struct CxAwareTestBed<Cx, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    mediator: CxAwareAsyncMediator<Cx, Ev>,
+}
+
+impl<Cx, Ev> CxAwareTestBed<Cx, Ev>
+where
+    Cx: Send + Sync,
+    Ev: MaybeDebug + Send + Sync,
+{
+    /// Creates a [`CxAwareTestBed`] around the context `cx`.
+    pub fn new(cx: Cx) -> Self {
+        let mediator = CxAwareAsyncMediator::<Cx, Ev>::builder()
+            .add_context(cx)
+            .build()
+            .expect("a mediator with a context always builds");
+        Self { mediator }
+    }
+
+    /// Calls [`CxAwareAsyncRequestHandler::handle()`] for the request `Req` with the context.
+    ///
+    /// You need to await the `Future` using `.await`.
+    pub async fn send<Req>(&self, req: Req) -> &Self
+    where
+        CxAwareAsyncMediator<Cx, Ev>: CxAwareAsyncRequestHandler<Cx, Req, Ev>,
+    {
+        let cx = self.mediator.cx.for_request().await;
+        <CxAwareAsyncMediator<Cx, Ev> as CxAwareAsyncRequestHandler<Cx, Req, Ev>>::handle(
+            &self.mediator,
+            req,
+            &cx,
+        )
+        .await;
+        self
+    }
+
+    /// Takes all events published since the last call, in publishing order.
+    pub fn published_events(&self) -> Vec<Ev> {
+        let basic = &self.mediator.basic.inner.basic;
+        let events = core::iter::from_fn(|| basic.channel.try_recv().ok())
+            .map(|envelope| envelope.ev)
+            .collect();
+        self.mediator.basic.inner.consumed.notify(usize::MAX);
+        events
+    }
+
+    /// Locks the context for reading, e.g. to inspect a mock after a request.
+    ///
+    /// You need to await the `Future` using `.await`.
+    pub async fn context(&self) -> ContextReadGuard<'_, Cx> {
+        self.mediator.cx.read().await
+    }
+
+    /// Returns the underlying [`CxAwareAsyncMediator`].
+    pub fn mediator(&self) -> &CxAwareAsyncMediator<Cx, Ev> {
+        &self.mediator
+    }
+}
+
+impl<Cx, Ev> Debug for CxAwareTestBed<Cx, Ev>
+where
+    Ev: MaybeDebug + 'static,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CxAwareTestBed").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,84 @@
+use std::{
+    fmt::Debug,
+    future::{poll_fn, Future},
+    pin::pin,
+    time::Duration,
+};
+
+use crate::mediator::{
+    asynchronous::basic::{AsyncMediatorInternal, BasicAsyncMediator},
+    listener::MaybeDebug,
+    runtime,
+    time::Instant,
+};
+
+/// Publishes a generated event `Ev` on a fixed interval, added with `with_ticker()`.
+///
+/// Tickers only tick while the mediator runs, see `run()`.
+/// The first event is published one interval after `run()` was called,
+/// later deadlines don't drift with the time it takes to publish.
+pub(crate) struct Ticker<Ev> {
+    interval: Duration,
+    generate: Box<dyn Fn() -> Ev + Send + Sync>,
+}
+
+impl<Ev> Ticker<Ev> {
+    pub(crate) fn new(
+        interval: Duration,
+        generate: impl Fn() -> Ev + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            interval,
+            generate: Box::new(generate),
+        }
+    }
+
+    /// Publishes a generated event to the `mediator` on every tick, forever.
+    async fn tick(&self, mediator: &BasicAsyncMediator<Ev>)
+    where
+        Ev: MaybeDebug + Send + Sync,
+    {
+        let mut deadline = Instant::now();
+        loop {
+            deadline += self.interval;
+            runtime::sleep(deadline.saturating_duration_since(Instant::now())).await;
+            mediator.publish((self.generate)()).await;
+        }
+    }
+}
+
+impl<Ev> Debug for Ticker<Ev> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Ticker")
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Runs `fut` to completion while all `tickers` publish to the `mediator`.
+/// The tickers stop once `fut` completes.
+pub(crate) async fn while_ticking<T, Ev>(
+    tickers: &[Ticker<Ev>],
+    mediator: &BasicAsyncMediator<Ev>,
+    fut: impl Future<Output = T>,
+) -> T
+where
+    Ev: MaybeDebug + Send + Sync,
+{
+    if tickers.is_empty() {
+        return fut.await;
+    }
+    let mut fut = pin!(fut);
+    let mut ticking = Vec::with_capacity(tickers.len());
+    for ticker in tickers {
+        ticking.push(Box::pin(ticker.tick(mediator)));
+    }
+    poll_fn(|cx| {
+        // Tickers never complete, so polling them again is fine.
+        for ticker in &mut ticking {
+            let _ = ticker.as_mut().poll(cx);
+        }
+        fut.as_mut().poll(cx)
+    })
+    .await
+}
@@ -0,0 +1,58 @@
+//! Clock types, backed by the browser on `wasm32` targets with the `wasm` feature,
+//! where the ones of `std` panic.
+//! Without the `std` feature, there is no clock at all.
+
+use core::time::Duration;
+
+#[cfg(all(feature = "std", not(all(feature = "wasm", target_arch = "wasm32"))))]
+pub(crate) use std::time::{Instant, SystemTime};
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub(crate) use web_time::{Instant, SystemTime};
+
+/// Measures the time elapsed since it was started.
+///
+/// Without the `std` feature, the elapsed time is always zero.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Stopwatch(#[cfg(feature = "std")] Instant);
+
+impl Stopwatch {
+    pub(crate) fn start() -> Self {
+        Self(
+            #[cfg(feature = "std")]
+            Instant::now(),
+        )
+    }
+
+    pub(crate) fn elapsed(&self) -> Duration {
+        #[cfg(feature = "std")]
+        return self.0.elapsed();
+        #[cfg(not(feature = "std"))]
+        return Duration::ZERO;
+    }
+}
+
+/// The deadline of an event published with `publish_with_ttl()`.
+///
+/// Without the `std` feature, the event never expires.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Expiry(#[cfg(feature = "std")] Instant);
+
+impl Expiry {
+    /// Returns the deadline of an event published now with the given `ttl`,
+    /// `None` if it is too far in the future to ever expire.
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    pub(crate) fn after(ttl: Duration) -> Option<Self> {
+        #[cfg(feature = "std")]
+        return Instant::now().checked_add(ttl).map(Self);
+        #[cfg(not(feature = "std"))]
+        return Some(Self());
+    }
+
+    /// Returns `true` once the deadline passed.
+    pub(crate) fn is_expired(&self) -> bool {
+        #[cfg(feature = "std")]
+        return Instant::now() > self.0;
+        #[cfg(not(feature = "std"))]
+        return false;
+    }
+}
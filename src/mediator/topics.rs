@@ -0,0 +1,105 @@
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+use core::time::Duration;
+
+use crate::mediator::{channel::TrySendError, correlation::Trace, time::Expiry};
+
+/// A pattern that is matched against the topic of a published event.
+///
+/// Topics consist of segments separated by dots, e.g. `orders.created`.
+/// Within a pattern, `*` matches exactly one segment
+/// and a trailing `#` matches any number of remaining segments, including none.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use mediatrix::topics::TopicPattern;
+///
+/// assert!(TopicPattern::new("orders").matches("orders"));
+/// assert!(TopicPattern::new("orders.*").matches("orders.created"));
+/// assert!(!TopicPattern::new("orders.*").matches("orders"));
+/// assert!(TopicPattern::new("orders.#").matches("orders"));
+/// assert!(TopicPattern::new("orders.#").matches("orders.created.eu"));
+/// assert!(TopicPattern::new("*.created").matches("users.created"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TopicPattern(String);
+
+impl TopicPattern {
+    /// Creates a [`TopicPattern`] from its textual representation.
+    pub fn new(pattern: &str) -> Self {
+        TopicPattern(pattern.to_owned())
+    }
+
+    /// Returns the textual representation of the pattern.
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the given `topic` matches this pattern.
+    pub fn matches(&self, topic: &str) -> bool {
+        let pattern: Vec<_> = self.0.split('.').collect();
+        let topic: Vec<_> = topic.split('.').collect();
+        matches_segments(&pattern, &topic)
+    }
+}
+
+fn matches_segments(pattern: &[&str], topic: &[&str]) -> bool {
+    match (pattern.split_first(), topic.split_first()) {
+        (Some((&"#", [])), _) => true,
+        (Some((p, pattern)), Some((t, topic))) => {
+            (*p == "*" || p == t) && matches_segments(pattern, topic)
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// An event `Ev` on its way through the channel,
+/// together with the topic it was published to and its [`Trace`].
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Envelope<Ev> {
+    pub(crate) topic: Option<String>,
+    pub(crate) trace: Trace,
+    pub(crate) ev: Ev,
+    /// How often the event was redelivered from the dead-letter queue.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) attempts: u32,
+    /// When the event expires, if it was published with a time-to-live.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) expiry: Option<Expiry>,
+}
+
+impl<Ev> Envelope<Ev> {
+    pub(crate) fn new(topic: Option<&str>, ev: Ev) -> Self {
+        Envelope {
+            topic: topic.map(str::to_owned),
+            trace: Trace::publish(),
+            ev,
+            attempts: 0,
+            expiry: None,
+        }
+    }
+
+    /// Lets the event expire once the `ttl` elapsed.
+    pub(crate) fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.expiry = Expiry::after(ttl);
+        self
+    }
+
+    /// Returns `true` if the event was published with a time-to-live that elapsed.
+    pub(crate) fn is_expired(&self) -> bool {
+        self.expiry.is_some_and(|expiry| expiry.is_expired())
+    }
+
+    /// Hands the event of a rejected [`Envelope`] back to the publisher.
+    pub(crate) fn open_rejected(err: TrySendError<Envelope<Ev>>) -> TrySendError<Ev> {
+        match err {
+            TrySendError::Full(envelope) => TrySendError::Full(envelope.ev),
+            TrySendError::Disconnected(envelope) => TrySendError::Disconnected(envelope.ev),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::mediator::{
+    cancellation::CancellationToken,
+    runtime::{self, Task},
+};
+
+/// A background task processing the events of a mediator,
+/// returned by `spawn_worker()`.
+///
+/// Use [`Worker::shutdown()`] to stop the worker gracefully:
+/// the event currently being processed and all pending events
+/// are still processed before the worker stops.
+/// Dropping the [`Worker`] signals the shutdown as well, but doesn't wait for it.
+///
+#[derive(Debug)]
+pub struct Worker {
+    shutdown: CancellationToken,
+    task: Option<Task<usize>>,
+}
+
+impl Worker {
+    /// Spawns the task created by `f`, which stops once the given token is cancelled.
+    pub(crate) fn spawn<F>(f: impl FnOnce(CancellationToken) -> F) -> Self
+    where
+        F: Future<Output = usize> + Send + 'static,
+    {
+        let shutdown = CancellationToken::new();
+        let task = runtime::spawn(f(shutdown.clone()));
+        Self {
+            shutdown,
+            task: Some(task),
+        }
+    }
+
+    /// Returns `true` if the shutdown of the worker was signalled.
+    pub fn is_shutdown(&self) -> bool {
+        self.shutdown.is_cancelled()
+    }
+
+    /// Signals the shutdown and waits until the worker stopped.
+    ///
+    /// Returns the number of events the worker processed.
+    ///
+    /// You need to await the `Future` using `.await`.
+    ///
+    pub async fn shutdown(mut self) -> usize {
+        self.shutdown.cancel();
+        match self.task.take() {
+            Some(task) => task.await,
+            None => 0,
+        }
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        self.shutdown.cancel();
+    }
+}
+
+/// A request handled in the background, returned by `send_detached()`.
+///
+/// Awaiting the [`JoinHandle`] waits until the task completed and returns its output.
+/// Dropping it detaches the task, which keeps running to completion regardless.
+///
+#[derive(Debug)]
+pub struct JoinHandle<T>(Task<T>);
+
+impl<T> JoinHandle<T> {
+    /// Spawns `fut` onto the runtime.
+    pub(crate) fn spawn<F>(fut: F) -> Self
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        Self(runtime::spawn(fut))
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
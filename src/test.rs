@@ -191,3 +191,7951 @@ fn cxaware_mediator_atomic_arc_test_async() {
         assert_eq!(*(u.lock().unwrap()), 12usize);
     })
 }
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn request_response_test_sync() {
+    use crate::synchronous::basic::*;
+
+    struct DoubleRequest(usize);
+    #[derive(Debug)]
+    struct DoubledEvent;
+
+    impl RequestHandler<DoubleRequest, DoubledEvent, usize> for BasicMediator<DoubledEvent> {
+        fn handle(&self, req: DoubleRequest) -> usize {
+            self.publish(DoubledEvent);
+            req.0 * 2
+        }
+    }
+
+    let mediator = BasicMediator::<DoubledEvent>::builder().build();
+
+    let res: usize = mediator.send_and_receive(DoubleRequest(21));
+    assert_eq!(res, 42);
+    assert!(mediator.next().is_ok());
+    assert!(mediator.next().is_err());
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn request_response_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::contextaware::*;
+
+    struct MultiplyRequest(usize);
+    #[derive(Debug)]
+    struct MultipliedEvent;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<usize, MultiplyRequest, MultipliedEvent, usize>
+        for CxAwareAsyncMediator<usize, MultipliedEvent>
+    {
+        async fn handle(&self, req: MultiplyRequest, cx: &usize) -> usize {
+            self.publish(MultipliedEvent).await;
+            req.0 * *cx
+        }
+    }
+
+    async_std::task::block_on(async {
+        let async_mediator = CxAwareAsyncMediator::<usize, MultipliedEvent>::builder()
+            .add_context(3)
+            .build()
+            .unwrap();
+
+        let res: usize = async_mediator
+            .send_and_receive(MultiplyRequest(5))
+            .await
+            .unwrap();
+        assert_eq!(res, 15);
+        assert!(async_mediator.next().await.is_ok());
+        assert!(async_mediator.next().await.is_err());
+    })
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn cxaware_mediator_atomic_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+
+    struct IncrementRequest;
+    #[derive(Debug)]
+    struct IncrementEvent(usize);
+
+    impl CxAwareRequestHandler<usize, IncrementRequest, IncrementEvent>
+        for CxAwareMediator<usize, IncrementEvent>
+    {
+        fn handle(&self, _req: IncrementRequest, cx: &usize) {
+            self.publish(IncrementEvent(*cx))
+        }
+    }
+
+    let u = Arc::new(Mutex::new(0usize));
+    let cloned = u.clone();
+    let mediator = CxAwareMediator::<usize, IncrementEvent>::builder()
+        .add_listener(move |x: &IncrementEvent| {
+            let mut m = cloned.lock().unwrap();
+            let c = *m;
+            *m = c + x.0;
+        })
+        .add_context(3)
+        .build()
+        .unwrap();
+
+    mediator.send(IncrementRequest);
+
+    mediator.next().ok();
+    assert_eq!(*(u.lock().unwrap()), 3usize);
+
+    mediator.send(IncrementRequest);
+    mediator.send(IncrementRequest);
+
+    mediator.next().ok();
+    mediator.next().ok();
+
+    assert_eq!(*(u.lock().unwrap()), 9usize);
+
+    assert!(CxAwareMediator::<usize, IncrementEvent>::builder()
+        .build()
+        .is_err());
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn subscribe_unsubscribe_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    struct IncrementRequest;
+    #[derive(Debug)]
+    struct IncrementEvent;
+
+    impl RequestHandler<IncrementRequest, IncrementEvent> for BasicMediator<IncrementEvent> {
+        fn handle(&self, _req: IncrementRequest) {
+            self.publish(IncrementEvent)
+        }
+    }
+
+    let u = Arc::new(Mutex::new(0usize));
+    let cloned = u.clone();
+    let mediator = BasicMediator::<IncrementEvent>::builder().build();
+
+    let handle = mediator.subscribe(move |_: &IncrementEvent| {
+        *cloned.lock().unwrap() += 1;
+    });
+
+    mediator.send(IncrementRequest);
+    mediator.next().ok();
+    assert_eq!(*(u.lock().unwrap()), 1usize);
+
+    assert!(mediator.unsubscribe(handle));
+    assert!(!mediator.unsubscribe(handle));
+
+    mediator.send(IncrementRequest);
+    mediator.next().ok();
+    assert_eq!(*(u.lock().unwrap()), 1usize);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn subscribe_unsubscribe_test_async() {
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+
+    use crate::asynchronous::basic::*;
+
+    struct IncrementRequest;
+    #[derive(Debug)]
+    struct IncrementEvent;
+
+    #[async_trait]
+    impl AsyncRequestHandler<IncrementRequest, IncrementEvent> for BasicAsyncMediator<IncrementEvent> {
+        async fn handle(&self, _req: IncrementRequest) {
+            self.publish(IncrementEvent).await
+        }
+    }
+
+    async_std::task::block_on(async {
+        let u = Arc::new(Mutex::new(0usize));
+        let cloned = u.clone();
+        let async_mediator = BasicAsyncMediator::<IncrementEvent>::builder().build();
+
+        let handle = async_mediator
+            .subscribe(move |_: &IncrementEvent| {
+                *cloned.lock().unwrap() += 1;
+            })
+            .await;
+
+        async_mediator.send(IncrementRequest).await;
+        async_mediator.next().await.ok();
+        assert_eq!(*(u.lock().unwrap()), 1usize);
+
+        assert!(async_mediator.unsubscribe(handle).await);
+
+        async_mediator.send(IncrementRequest).await;
+        async_mediator.next().await.ok();
+        assert_eq!(*(u.lock().unwrap()), 1usize);
+    })
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn next_all_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct NumberEvent(usize);
+
+    let received = Arc::new(Mutex::new(vec![]));
+    let cloned = received.clone();
+    let mediator = BasicMediator::<NumberEvent>::builder()
+        .add_listener(move |ev: &NumberEvent| cloned.lock().unwrap().push(ev.0))
+        .build();
+
+    assert_eq!(mediator.next_all(), 0);
+
+    (1..=3).for_each(|n| mediator.publish(NumberEvent(n)));
+    assert_eq!(mediator.next_all(), 3);
+    assert_eq!(mediator.next_all(), 0);
+    assert_eq!(*received.lock().unwrap(), vec![1, 2, 3]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn next_all_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct NumberEvent(usize);
+
+    async_std::task::block_on(async {
+        let received = Arc::new(Mutex::new(vec![]));
+        let cloned = received.clone();
+        let async_mediator = BasicAsyncMediator::<NumberEvent>::builder()
+            .add_listener(move |ev: &NumberEvent| cloned.lock().unwrap().push(ev.0))
+            .build();
+
+        assert_eq!(async_mediator.next_all().await, 0);
+
+        for n in 1..=3 {
+            async_mediator.publish(NumberEvent(n)).await;
+        }
+        assert_eq!(async_mediator.next_all().await, 3);
+        assert_eq!(async_mediator.next_all().await, 0);
+        assert_eq!(*received.lock().unwrap(), vec![1, 2, 3]);
+    })
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn async_listener_test_async() {
+    use async_trait::async_trait;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use crate::asynchronous::basic::*;
+
+    struct IncrementRequest;
+    #[derive(Debug)]
+    struct IncrementEvent(usize);
+
+    #[async_trait]
+    impl AsyncRequestHandler<IncrementRequest, IncrementEvent> for BasicAsyncMediator<IncrementEvent> {
+        async fn handle(&self, _req: IncrementRequest) {
+            self.publish(IncrementEvent(2)).await
+        }
+    }
+
+    struct Counter(Arc<AtomicUsize>);
+
+    #[async_trait]
+    impl AsyncListener<IncrementEvent> for Counter {
+        async fn on_event(&self, ev: &IncrementEvent) {
+            async_std::task::yield_now().await;
+            self.0.fetch_add(ev.0, Ordering::SeqCst);
+        }
+    }
+
+    async_std::task::block_on(async {
+        let u = Arc::new(AtomicUsize::new(0));
+        let cloned = u.clone();
+        let async_mediator = BasicAsyncMediator::<IncrementEvent>::builder()
+            .add_async_listener(Counter(u.clone()))
+            .add_async_listener(move |ev: &IncrementEvent| {
+                let (u, n) = (cloned.clone(), ev.0);
+                async move {
+                    u.fetch_add(n * 10, Ordering::SeqCst);
+                }
+            })
+            .build();
+
+        async_mediator.send(IncrementRequest).await;
+        async_mediator.next().await.ok();
+        assert_eq!(u.load(Ordering::SeqCst), 22);
+    })
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn next_blocking_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    struct IncrementRequest;
+    #[derive(Debug)]
+    struct IncrementEvent;
+
+    impl RequestHandler<IncrementRequest, IncrementEvent> for BasicMediator<IncrementEvent> {
+        fn handle(&self, _req: IncrementRequest) {
+            self.publish(IncrementEvent)
+        }
+    }
+
+    let u = Arc::new(Mutex::new(0usize));
+    let cloned = u.clone();
+    let mediator = BasicMediator::<IncrementEvent>::builder()
+        .add_listener(move |_: &IncrementEvent| {
+            *cloned.lock().unwrap() += 1;
+        })
+        .build();
+
+    mediator.send(IncrementRequest);
+    mediator.send(IncrementRequest);
+
+    assert!(mediator.next_blocking().is_ok());
+    assert!(mediator.next_blocking().is_ok());
+    assert_eq!(*(u.lock().unwrap()), 2usize);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn next_async_test_async() {
+    use async_trait::async_trait;
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use crate::asynchronous::basic::*;
+
+    struct IncrementRequest;
+    #[derive(Debug)]
+    struct IncrementEvent;
+
+    #[async_trait]
+    impl AsyncRequestHandler<IncrementRequest, IncrementEvent> for BasicAsyncMediator<IncrementEvent> {
+        async fn handle(&self, _req: IncrementRequest) {
+            self.publish(IncrementEvent).await
+        }
+    }
+
+    async_std::task::block_on(async {
+        let u = Arc::new(Mutex::new(0usize));
+        let cloned = u.clone();
+        let async_mediator = Arc::new(
+            BasicAsyncMediator::<IncrementEvent>::builder()
+                .add_listener(move |_: &IncrementEvent| {
+                    *cloned.lock().unwrap() += 1;
+                })
+                .build(),
+        );
+
+        let sender = async_mediator.clone();
+        let task = async_std::task::spawn(async move {
+            async_std::task::sleep(Duration::from_millis(20)).await;
+            sender.send(IncrementRequest).await;
+            async_std::task::sleep(Duration::from_millis(20)).await;
+            sender.send(IncrementRequest).await;
+        });
+
+        assert!(async_mediator.next_async().await.is_ok());
+        assert!(async_mediator.next_async().await.is_ok());
+        task.await;
+
+        assert_eq!(*(u.lock().unwrap()), 2usize);
+    })
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn event_stream_test_async() {
+    use async_trait::async_trait;
+    use futures::StreamExt;
+    use std::sync::{Arc, Mutex};
+
+    use crate::asynchronous::contextaware::*;
+
+    struct IncrementRequest;
+    #[derive(Debug, PartialEq)]
+    struct IncrementEvent(usize);
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<usize, IncrementRequest, IncrementEvent>
+        for CxAwareAsyncMediator<usize, IncrementEvent>
+    {
+        async fn handle(&self, _req: IncrementRequest, cx: &usize) {
+            self.publish(IncrementEvent(*cx)).await
+        }
+    }
+
+    async_std::task::block_on(async {
+        let u = Arc::new(Mutex::new(0usize));
+        let cloned = u.clone();
+        let async_mediator = CxAwareAsyncMediator::<usize, IncrementEvent>::builder()
+            .add_listener(move |x: &IncrementEvent| {
+                *cloned.lock().unwrap() += x.0;
+            })
+            .add_context(4)
+            .build()
+            .unwrap();
+
+        async_mediator.send(IncrementRequest).await;
+        async_mediator.send(IncrementRequest).await;
+        async_mediator.send(IncrementRequest).await;
+
+        let events: Vec<IncrementEvent> = async_mediator.event_stream().take(3).collect().await;
+        assert!(events.iter().all(|ev| *ev == IncrementEvent(4)));
+        assert_eq!(events.len(), 3);
+        assert_eq!(*(u.lock().unwrap()), 12usize);
+    })
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn behavior_pipeline_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    struct IncrementRequest(usize);
+    #[derive(Debug)]
+    struct IncrementEvent(usize);
+
+    impl RequestHandler<IncrementRequest, IncrementEvent> for BasicMediator<IncrementEvent> {
+        fn handle(&self, req: IncrementRequest) {
+            self.publish(IncrementEvent(req.0))
+        }
+    }
+
+    struct Trace(Arc<Mutex<Vec<&'static str>>>, &'static str);
+
+    impl Behavior for Trace {
+        fn handle(&self, req: AnyRequest, next: Next<'_>) {
+            self.0.lock().unwrap().push(self.1);
+            next.run(req);
+        }
+    }
+
+    struct Validate;
+
+    impl Behavior for Validate {
+        fn handle(&self, mut req: AnyRequest, next: Next<'_>) {
+            match req.downcast_mut::<IncrementRequest>() {
+                Some(IncrementRequest(0)) => (),
+                Some(IncrementRequest(n)) => {
+                    *n *= 10;
+                    next.run(req)
+                }
+                None => next.run(req),
+            }
+        }
+    }
+
+    let trace = Arc::new(Mutex::new(vec![]));
+    let u = Arc::new(Mutex::new(0usize));
+    let cloned = u.clone();
+    let mediator = BasicMediator::<IncrementEvent>::builder()
+        .add_listener(move |x: &IncrementEvent| {
+            *cloned.lock().unwrap() += x.0;
+        })
+        .add_behavior(Trace(trace.clone(), "first"))
+        .add_behavior(Validate)
+        .add_behavior(Trace(trace.clone(), "last"))
+        .build();
+
+    mediator.send(IncrementRequest(0));
+    assert_eq!(mediator.next_all(), 0);
+
+    mediator.send(IncrementRequest(2));
+    assert_eq!(mediator.next_all(), 1);
+
+    assert_eq!(*(u.lock().unwrap()), 20usize);
+    assert_eq!(*(trace.lock().unwrap()), vec!["first", "first", "last"]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn behavior_pipeline_test_async() {
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+
+    use crate::asynchronous::contextaware::*;
+
+    struct IncrementRequest;
+    #[derive(Debug)]
+    struct IncrementEvent;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<usize, IncrementRequest, IncrementEvent>
+        for CxAwareAsyncMediator<usize, IncrementEvent>
+    {
+        async fn handle(&self, _req: IncrementRequest, _cx: &usize) {
+            self.publish(IncrementEvent).await
+        }
+    }
+
+    struct Trace(Arc<Mutex<Vec<String>>>);
+
+    #[async_trait]
+    impl AsyncBehavior for Trace {
+        async fn handle(&self, req: AnyRequest, next: AsyncNext<'_>) {
+            let name = req.type_name();
+            self.0.lock().unwrap().push(format!("before {}", name));
+            next.run(req).await;
+            self.0.lock().unwrap().push(format!("after {}", name));
+        }
+    }
+
+    struct Deny;
+
+    #[async_trait]
+    impl AsyncBehavior for Deny {
+        async fn handle(&self, _req: AnyRequest, _next: AsyncNext<'_>) {}
+    }
+
+    async_std::task::block_on(async {
+        let trace = Arc::new(Mutex::new(vec![]));
+        let async_mediator = CxAwareAsyncMediator::<usize, IncrementEvent>::builder()
+            .add_behavior(Trace(trace.clone()))
+            .add_context(3)
+            .build()
+            .unwrap();
+
+        async_mediator.send(IncrementRequest).await;
+        assert_eq!(async_mediator.next_all().await, 1);
+        assert_eq!(trace.lock().unwrap().len(), 2);
+        assert!(trace.lock().unwrap()[0].ends_with("IncrementRequest"));
+
+        let denying_mediator = CxAwareAsyncMediator::<usize, IncrementEvent>::builder()
+            .add_behavior(Deny)
+            .add_context(3)
+            .build()
+            .unwrap();
+
+        denying_mediator.send(IncrementRequest).await;
+        assert_eq!(denying_mediator.next_all().await, 0);
+    })
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn dependencies_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    struct Base(usize);
+    struct Factor(usize);
+
+    struct ComputeRequest;
+    #[derive(Debug)]
+    struct ComputedEvent;
+
+    impl CxAwareRequestHandler<Dependencies, ComputeRequest, ComputedEvent, Option<usize>>
+        for CxAwareMediator<Dependencies, ComputedEvent>
+    {
+        fn handle(&self, _req: ComputeRequest, cx: &Dependencies) -> Option<usize> {
+            self.publish(ComputedEvent);
+            Some(cx.get::<Base>()?.0 * cx.get::<Factor>()?.0)
+        }
+    }
+
+    let mediator = CxAwareMediator::<Dependencies, ComputedEvent>::builder()
+        .add_dependency(Base(2))
+        .add_dependency(Factor(3))
+        .add_dependency(Factor(4))
+        .build()
+        .unwrap();
+
+    assert_eq!(mediator.send_and_receive(ComputeRequest), Some(8));
+    assert_eq!(mediator.next_all(), 1);
+
+    let mediator = CxAwareMediator::<Dependencies, ComputedEvent>::builder()
+        .add_dependency(Base(2))
+        .build()
+        .unwrap();
+
+    assert_eq!(mediator.send_and_receive(ComputeRequest), None);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn dependencies_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::contextaware::*;
+
+    struct Base(usize);
+    struct Factor(usize);
+
+    struct ComputeRequest;
+    #[derive(Debug)]
+    struct ComputedEvent;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Dependencies, ComputeRequest, ComputedEvent, usize>
+        for CxAwareAsyncMediator<Dependencies, ComputedEvent>
+    {
+        async fn handle(&self, _req: ComputeRequest, cx: &Dependencies) -> usize {
+            self.publish(ComputedEvent).await;
+            cx.get::<Base>().unwrap().0 * cx.get::<Factor>().unwrap().0
+        }
+    }
+
+    async_std::task::block_on(async {
+        let async_mediator = CxAwareAsyncMediator::<Dependencies, ComputedEvent>::builder()
+            .add_dependency(Base(2))
+            .add_dependency(Factor(5))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            async_mediator.send_and_receive(ComputeRequest).await,
+            Ok(10)
+        );
+        assert_eq!(async_mediator.next_all().await, 1);
+    })
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn bounded_channel_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use crate::channel::TrySendError;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, PartialEq)]
+    struct NumberEvent(usize);
+
+    let received = Arc::new(Mutex::new(vec![]));
+    let cloned = received.clone();
+    let mediator = BasicMediator::<NumberEvent>::builder()
+        .add_listener(move |ev: &NumberEvent| cloned.lock().unwrap().push(ev.0))
+        .with_capacity(2)
+        .with_overflow_policy(OverflowPolicy::DropOldest)
+        .build();
+
+    (1..=3).for_each(|n| mediator.publish(NumberEvent(n)));
+    assert_eq!(mediator.next_all(), 2);
+    assert_eq!(*received.lock().unwrap(), vec![2, 3]);
+
+    let mediator = BasicMediator::<NumberEvent>::builder()
+        .with_overflow_policy(OverflowPolicy::DropNewest)
+        .with_capacity(2)
+        .build();
+
+    (1..=3).for_each(|n| assert!(mediator.try_publish(NumberEvent(n)).is_ok()));
+    assert_eq!(mediator.next_all(), 2);
+
+    let mediator = BasicMediator::<NumberEvent>::builder()
+        .with_capacity(1)
+        .with_overflow_policy(OverflowPolicy::Error)
+        .build();
+
+    assert!(mediator.try_publish(NumberEvent(1)).is_ok());
+    assert_eq!(
+        mediator.try_publish(NumberEvent(2)),
+        Err(TrySendError::Full(NumberEvent(2)))
+    );
+    assert_eq!(mediator.next_all(), 1);
+}
+
+#[cfg(not(feature = "std"))]
+#[test]
+fn queue_backend_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use crate::channel::TrySendError;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, PartialEq)]
+    struct NumberEvent(usize);
+
+    let received = Arc::new(Mutex::new(vec![]));
+    let cloned = received.clone();
+    let mediator = BasicMediator::<NumberEvent>::builder()
+        .add_listener(move |ev: &NumberEvent| cloned.lock().unwrap().push(ev.0))
+        .build();
+    assert!(format!("{mediator:?}").contains("backend: Queue"));
+
+    (1..=3).for_each(|n| mediator.publish(NumberEvent(n)));
+    mediator.publish_urgent(NumberEvent(0));
+    assert_eq!(mediator.next_all(), 4);
+    assert_eq!(*received.lock().unwrap(), vec![0, 1, 2, 3]);
+
+    let mediator = BasicMediator::<NumberEvent>::builder()
+        .with_capacity(2)
+        .with_overflow_policy(OverflowPolicy::DropOldest)
+        .build();
+
+    (1..=3).for_each(|n| mediator.publish(NumberEvent(n)));
+    assert_eq!(mediator.next_all(), 2);
+
+    // Nothing can wait for space in the queue, so blocking fails like `OverflowPolicy::Error`.
+    for policy in [OverflowPolicy::Block, OverflowPolicy::Error] {
+        let mediator = BasicMediator::<NumberEvent>::builder()
+            .with_capacity(1)
+            .with_overflow_policy(policy)
+            .build();
+
+        assert!(mediator.try_publish(NumberEvent(1)).is_ok());
+        assert_eq!(
+            mediator.try_publish(NumberEvent(2)),
+            Err(TrySendError::Full(NumberEvent(2)))
+        );
+        assert_eq!(mediator.next_all(), 1);
+        assert!(mediator.try_publish(NumberEvent(3)).is_ok());
+    }
+}
+
+#[cfg(not(feature = "std"))]
+#[test]
+fn queue_backend_request_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone)]
+    enum CounterEvent {
+        Incremented(u32),
+    }
+
+    struct Increment;
+
+    #[derive(Debug, Default)]
+    struct Counter(Mutex<u32>);
+
+    impl CxAwareRequestHandler<Counter, Increment, CounterEvent>
+        for CxAwareMediator<Counter, CounterEvent>
+    {
+        fn handle(&self, _: Increment, cx: &Counter) {
+            let mut count = cx.0.lock().unwrap();
+            *count += 1;
+            self.publish(CounterEvent::Incremented(*count));
+        }
+    }
+
+    let received = Arc::new(Mutex::new(vec![]));
+    let cloned = received.clone();
+    let mediator = CxAwareMediator::<Counter, CounterEvent>::builder()
+        .add_listener(move |CounterEvent::Incremented(n): &CounterEvent| {
+            cloned.lock().unwrap().push(*n)
+        })
+        .add_context(Counter::default())
+        .build()
+        .unwrap();
+
+    (0..3).for_each(|_| mediator.send(Increment));
+    assert_eq!(mediator.next_all(), 3);
+    assert_eq!(*received.lock().unwrap(), vec![1, 2, 3]);
+    assert_eq!(mediator.stats().processed(), 3);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn bounded_channel_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct NumberEvent(usize);
+
+    let received = Arc::new(Mutex::new(vec![]));
+    let cloned = received.clone();
+
+    async_std::task::block_on(async {
+        let async_mediator = Arc::new(
+            BasicAsyncMediator::<NumberEvent>::builder()
+                .add_listener(move |ev: &NumberEvent| cloned.lock().unwrap().push(ev.0))
+                .with_capacity(1)
+                .build(),
+        );
+
+        async_mediator.publish(NumberEvent(1)).await;
+
+        let publisher = async_mediator.clone();
+        let blocked = async_std::task::spawn(async move {
+            publisher.publish(NumberEvent(2)).await;
+        });
+
+        async_mediator.next_async().await.unwrap();
+        blocked.await;
+        assert_eq!(async_mediator.next_all().await, 1);
+        assert_eq!(*received.lock().unwrap(), vec![1, 2]);
+    })
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn try_send_test_sync() {
+    use crate::synchronous::basic::*;
+
+    struct DivideRequest(usize, usize);
+    #[derive(Debug)]
+    struct DividedEvent;
+    #[derive(Debug, PartialEq)]
+    struct DivisionByZero;
+
+    impl TryRequestHandler<DivideRequest, DividedEvent, DivisionByZero>
+        for BasicMediator<DividedEvent>
+    {
+        fn handle(&self, req: DivideRequest) -> Result<(), DivisionByZero> {
+            req.0.checked_div(req.1).ok_or(DivisionByZero)?;
+            self.publish(DividedEvent);
+            Ok(())
+        }
+    }
+
+    struct SkipAll;
+
+    impl Behavior for SkipAll {
+        fn handle(&self, _req: AnyRequest, _next: Next<'_>) {}
+    }
+
+    let mediator = BasicMediator::<DividedEvent>::builder().build();
+
+    assert_eq!(mediator.try_send(DivideRequest(4, 2)), Ok(()));
+    assert_eq!(mediator.try_send(DivideRequest(4, 0)), Err(DivisionByZero));
+    assert_eq!(mediator.next_all(), 1);
+
+    let mediator = BasicMediator::<DividedEvent>::builder()
+        .add_behavior(SkipAll)
+        .build();
+
+    assert_eq!(mediator.try_send(DivideRequest(4, 0)), Ok(()));
+    assert_eq!(mediator.next_all(), 0);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn try_send_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::contextaware::*;
+
+    #[derive(Debug)]
+    struct Divisor(usize);
+
+    struct DivideRequest(usize);
+    #[derive(Debug)]
+    struct DividedEvent;
+    #[derive(Debug, PartialEq)]
+    struct DivisionByZero;
+
+    impl From<ReentrantSendError> for DivisionByZero {
+        fn from(_: ReentrantSendError) -> Self {
+            unreachable!("requests aren't sent from within handlers")
+        }
+    }
+
+    #[async_trait]
+    impl TryCxAwareAsyncRequestHandler<Divisor, DivideRequest, DividedEvent, DivisionByZero>
+        for CxAwareAsyncMediator<Divisor, DividedEvent>
+    {
+        async fn handle(&self, req: DivideRequest, cx: &Divisor) -> Result<(), DivisionByZero> {
+            req.0.checked_div(cx.0).ok_or(DivisionByZero)?;
+            self.publish(DividedEvent).await;
+            Ok(())
+        }
+    }
+
+    struct PassThrough;
+
+    #[async_trait]
+    impl AsyncBehavior for PassThrough {
+        async fn handle(&self, req: AnyRequest, next: AsyncNext<'_>) {
+            next.run(req).await;
+        }
+    }
+
+    async_std::task::block_on(async {
+        let async_mediator = CxAwareAsyncMediator::<Divisor, DividedEvent>::builder()
+            .add_context(Divisor(2))
+            .add_behavior(PassThrough)
+            .build()
+            .unwrap();
+
+        assert_eq!(async_mediator.try_send(DivideRequest(4)).await, Ok(()));
+        assert_eq!(async_mediator.next_all().await, 1);
+
+        let async_mediator = CxAwareAsyncMediator::<Divisor, DividedEvent>::builder()
+            .add_context(Divisor(0))
+            .add_behavior(PassThrough)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            async_mediator.try_send(DivideRequest(4)).await,
+            Err(DivisionByZero)
+        );
+        assert_eq!(async_mediator.next_all().await, 0);
+    })
+}
+
+#[cfg(all(feature = "derive", feature = "async"))]
+#[test]
+fn mediator_handler_test_async() {
+    use crate::asynchronous::contextaware::*;
+    use crate::mediator_handler;
+
+    #[derive(Debug)]
+    struct Multiplier(usize);
+
+    struct MultiplyRequest(usize);
+    struct PingRequest;
+    #[derive(Debug)]
+    struct PingEvent;
+
+    #[mediator_handler]
+    impl CxAwareAsyncMediator<Multiplier, PingEvent> {
+        async fn multiply(&self, req: MultiplyRequest, cx: &Multiplier) -> usize {
+            req.0 * cx.0
+        }
+
+        async fn ping(&self, _req: PingRequest, _cx: &Multiplier) {
+            self.publish(PingEvent).await;
+        }
+    }
+
+    async_std::task::block_on(async {
+        let async_mediator = CxAwareAsyncMediator::<Multiplier, PingEvent>::builder()
+            .add_context(Multiplier(3))
+            .build()
+            .unwrap();
+
+        async_mediator.send(PingRequest).await;
+        let res: usize = async_mediator
+            .send_and_receive(MultiplyRequest(2))
+            .await
+            .unwrap();
+        assert_eq!(res, 6);
+        assert_eq!(async_mediator.next_all().await, 1);
+    })
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn isolate_listeners_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct PanicEvent;
+
+    let invoked = Arc::new(Mutex::new(0usize));
+    let cloned = invoked.clone();
+    let mediator = BasicMediator::<PanicEvent>::builder()
+        .add_listener(|_: &PanicEvent| panic!("first"))
+        .add_listener(move |_: &PanicEvent| *cloned.lock().unwrap() += 1)
+        .isolate_listeners(true)
+        .build();
+    let handle = mediator.subscribe(|_: &PanicEvent| panic!("{}", "third"));
+
+    mediator.publish(PanicEvent);
+    mediator.publish(PanicEvent);
+
+    match mediator.next() {
+        Err(NextError::ListenerPanicked(panics)) => {
+            let messages: Vec<_> = panics.iter().map(|p| p.message()).collect();
+            assert_eq!(messages, vec![Some("first"), Some("third")]);
+            assert_eq!(panics[1].handle(), handle);
+        }
+        res => panic!("unexpected result: {:?}", res),
+    }
+    assert_eq!(mediator.next_all(), 1);
+    assert_eq!(*invoked.lock().unwrap(), 2);
+    assert!(matches!(mediator.next(), Err(NextError::Empty)));
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn isolate_listeners_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct PanicEvent;
+
+    let invoked = Arc::new(Mutex::new(0usize));
+    let cloned = invoked.clone();
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<PanicEvent>::builder()
+            .add_listener(|_: &PanicEvent| panic!("sync"))
+            .add_async_listener(|_: &PanicEvent| async { panic!("async") })
+            .add_async_listener(move |_: &PanicEvent| {
+                *cloned.lock().unwrap() += 1;
+                async {}
+            })
+            .isolate_listeners(true)
+            .build();
+
+        async_mediator.publish(PanicEvent).await;
+
+        match async_mediator.next().await {
+            Err(NextError::ListenerPanicked(panics)) => {
+                let messages: Vec<_> = panics.iter().map(|p| p.message()).collect();
+                assert_eq!(messages, vec![Some("sync"), Some("async")]);
+            }
+            res => panic!("unexpected result: {:?}", res),
+        }
+        assert_eq!(*invoked.lock().unwrap(), 1);
+        assert!(matches!(async_mediator.next().await, Err(NextError::Empty)));
+    })
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn topic_routing_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct NamedEvent(&'static str);
+
+    let orders = Arc::new(Mutex::new(vec![]));
+    let all = Arc::new(Mutex::new(vec![]));
+    let (orders_cloned, all_cloned) = (orders.clone(), all.clone());
+    let mediator = BasicMediator::<NamedEvent>::builder()
+        .add_listener_for_topic("orders.*", move |ev: &NamedEvent| {
+            orders_cloned.lock().unwrap().push(ev.0)
+        })
+        .add_listener(move |ev: &NamedEvent| all_cloned.lock().unwrap().push(ev.0))
+        .build();
+
+    mediator.publish_to("orders.created", NamedEvent("created"));
+    mediator.publish_to("orders", NamedEvent("bare"));
+    mediator.publish_to("users.created", NamedEvent("user"));
+    mediator.publish(NamedEvent("untopiced"));
+
+    assert_eq!(mediator.next_all(), 4);
+    assert_eq!(*orders.lock().unwrap(), vec!["created"]);
+    assert_eq!(
+        *all.lock().unwrap(),
+        vec!["created", "bare", "user", "untopiced"]
+    );
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn topic_routing_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct NamedEvent(&'static str);
+
+    let received = Arc::new(Mutex::new(vec![]));
+    let (sync_cloned, async_cloned) = (received.clone(), received.clone());
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<NamedEvent>::builder()
+            .add_listener_for_topic("orders.#", move |ev: &NamedEvent| {
+                sync_cloned.lock().unwrap().push(("sync", ev.0))
+            })
+            .add_async_listener_for_topic("*.created", move |ev: &NamedEvent| {
+                async_cloned.lock().unwrap().push(("async", ev.0));
+                async {}
+            })
+            .build();
+
+        async_mediator
+            .publish_to("orders.created", NamedEvent("order"))
+            .await;
+        async_mediator
+            .publish_to("users.created", NamedEvent("user"))
+            .await;
+        async_mediator.publish(NamedEvent("untopiced")).await;
+
+        assert_eq!(async_mediator.next_all().await, 3);
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![("sync", "order"), ("async", "order"), ("async", "user")]
+        );
+    })
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn filtered_listener_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct NumberEvent(usize);
+
+    let even = Arc::new(Mutex::new(vec![]));
+    let cloned = even.clone();
+    let mediator = CxAwareMediator::<(), NumberEvent>::builder()
+        .add_context(())
+        .add_filtered_listener(
+            |ev: &NumberEvent| ev.0.is_multiple_of(2),
+            move |ev: &NumberEvent| cloned.lock().unwrap().push(ev.0),
+        )
+        .build()
+        .unwrap();
+
+    (1..=6).for_each(|n| mediator.publish(NumberEvent(n)));
+
+    assert_eq!(mediator.next_all(), 6);
+    assert_eq!(*even.lock().unwrap(), vec![2, 4, 6]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn filtered_listener_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct NumberEvent(usize);
+
+    let received = Arc::new(Mutex::new(vec![]));
+    let (sync_cloned, async_cloned) = (received.clone(), received.clone());
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<NumberEvent>::builder()
+            .add_filtered_listener(
+                |ev: &NumberEvent| ev.0 < 2,
+                move |ev: &NumberEvent| sync_cloned.lock().unwrap().push(("sync", ev.0)),
+            )
+            .add_filtered_async_listener(
+                |ev: &NumberEvent| ev.0 > 2,
+                move |ev: &NumberEvent| {
+                    async_cloned.lock().unwrap().push(("async", ev.0));
+                    async {}
+                },
+            )
+            .build();
+
+        for n in 1..=3 {
+            async_mediator.publish(NumberEvent(n)).await;
+        }
+
+        assert_eq!(async_mediator.next_all().await, 3);
+        assert_eq!(*received.lock().unwrap(), vec![("sync", 1), ("async", 3)]);
+    })
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn listener_priority_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct OrderEvent;
+
+    let order = Arc::new(Mutex::new(vec![]));
+    let (first, second, third, fourth) =
+        (order.clone(), order.clone(), order.clone(), order.clone());
+    let mediator = BasicMediator::<OrderEvent>::builder()
+        .add_listener(move |_: &OrderEvent| first.lock().unwrap().push("ui"))
+        .add_listener_with_priority(10, move |_: &OrderEvent| {
+            second.lock().unwrap().push("cache")
+        })
+        .add_listener_with_priority(-5, move |_: &OrderEvent| third.lock().unwrap().push("log"))
+        .add_listener_with_priority(10, move |_: &OrderEvent| {
+            fourth.lock().unwrap().push("index")
+        })
+        .build();
+    let last = order.clone();
+    mediator.subscribe(move |_: &OrderEvent| last.lock().unwrap().push("metrics"));
+
+    mediator.publish(OrderEvent);
+    mediator.next().unwrap();
+
+    assert_eq!(
+        *order.lock().unwrap(),
+        vec!["cache", "index", "ui", "metrics", "log"]
+    );
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn listener_priority_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct OrderEvent;
+
+    let order = Arc::new(Mutex::new(vec![]));
+    let (first, second, third, fourth) =
+        (order.clone(), order.clone(), order.clone(), order.clone());
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<OrderEvent>::builder()
+            .add_async_listener(move |_: &OrderEvent| {
+                first.lock().unwrap().push("ui");
+                async {}
+            })
+            .add_async_listener_with_priority(10, move |_: &OrderEvent| {
+                second.lock().unwrap().push("cache");
+                async {}
+            })
+            .add_listener_with_priority(-5, move |_: &OrderEvent| {
+                third.lock().unwrap().push("sync log")
+            })
+            .add_listener(move |_: &OrderEvent| fourth.lock().unwrap().push("sync ui"))
+            .build();
+
+        async_mediator.publish(OrderEvent).await;
+        async_mediator.next().await.unwrap();
+
+        assert_eq!(
+            *order.lock().unwrap(),
+            vec!["sync ui", "sync log", "cache", "ui"]
+        );
+    })
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn notification_handler_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct Deposit(u32);
+
+    #[derive(Default)]
+    struct Balance(Mutex<u32>);
+
+    impl NotificationHandler<Deposit> for Balance {
+        fn on_event(&self, ev: &Deposit) {
+            *self.0.lock().unwrap() += ev.0;
+        }
+    }
+
+    let balance = Arc::new(Balance::default());
+    let deposits = Arc::new(Mutex::new(0usize));
+    let cloned = deposits.clone();
+
+    let mediator = CxAwareMediator::<(), Deposit>::builder()
+        .add_handler(balance.clone())
+        .add_handler(Arc::new(move |_: &Deposit| *cloned.lock().unwrap() += 1))
+        .add_context(())
+        .build()
+        .unwrap();
+
+    mediator.publish(Deposit(20));
+    mediator.publish(Deposit(22));
+
+    assert_eq!(mediator.next_all(), 2);
+    assert_eq!(*balance.0.lock().unwrap(), 42);
+    assert_eq!(*deposits.lock().unwrap(), 2);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn notification_handler_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct Deposit(u32);
+
+    #[derive(Default)]
+    struct Balance(Mutex<u32>);
+
+    impl NotificationHandler<Deposit> for Balance {
+        fn on_event(&self, ev: &Deposit) {
+            *self.0.lock().unwrap() += ev.0;
+        }
+    }
+
+    let balance = Arc::new(Balance::default());
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<Deposit>::builder()
+            .add_handler(balance.clone())
+            .build();
+
+        async_mediator.publish(Deposit(20)).await;
+        async_mediator.publish(Deposit(22)).await;
+
+        assert_eq!(async_mediator.next_all().await, 2);
+        assert_eq!(*balance.0.lock().unwrap(), 42);
+    })
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn send_with_cancellation_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::contextaware::*;
+
+    use std::time::Duration;
+
+    #[derive(Debug)]
+    struct Steps(usize);
+
+    struct ImportRequest(CancellationToken);
+    #[derive(Debug)]
+    struct ImportedEvent;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Steps, ImportRequest, ImportedEvent>
+        for CxAwareAsyncMediator<Steps, ImportedEvent>
+    {
+        async fn handle(&self, req: ImportRequest, cx: &Steps) {
+            for _ in 0..cx.0 {
+                if req.0.is_cancelled() {
+                    return;
+                }
+                async_std::task::sleep(Duration::from_millis(10)).await;
+            }
+            self.publish(ImportedEvent).await;
+        }
+    }
+
+    async_std::task::block_on(async {
+        let async_mediator = CxAwareAsyncMediator::<Steps, ImportedEvent>::builder()
+            .add_context(Steps(1))
+            .build()
+            .unwrap();
+
+        let token = CancellationToken::new();
+        let res = async_mediator
+            .send_with_cancellation(ImportRequest(token.clone()), &token)
+            .await;
+        assert_eq!(res, Ok(()));
+        assert_eq!(async_mediator.next_all().await, 1);
+
+        let async_mediator = CxAwareAsyncMediator::<Steps, ImportedEvent>::builder()
+            .add_context(Steps(1000))
+            .build()
+            .unwrap();
+
+        let spent = token.clone();
+        let token = CancellationToken::new();
+        let cloned = token.clone();
+        async_std::task::spawn(async move {
+            async_std::task::sleep(Duration::from_millis(30)).await;
+            cloned.cancel();
+        });
+        let res = async_mediator
+            .send_with_cancellation(ImportRequest(token.clone()), &token)
+            .await;
+        assert_eq!(res, Err(CancelledError));
+        assert!(token.is_cancelled());
+        assert_eq!(async_mediator.next_all().await, 0);
+
+        spent.cancel();
+        let res = async_mediator
+            .send_with_cancellation(ImportRequest(spent.clone()), &spent)
+            .await;
+        assert_eq!(res, Err(CancelledError));
+    })
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn send_with_timeout_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::basic::*;
+
+    use std::time::Duration;
+
+    struct FetchRequest(Duration);
+    #[derive(Debug)]
+    struct FetchedEvent;
+
+    #[async_trait]
+    impl AsyncRequestHandler<FetchRequest, FetchedEvent> for BasicAsyncMediator<FetchedEvent> {
+        async fn handle(&self, req: FetchRequest) {
+            async_std::task::sleep(req.0).await;
+            self.publish(FetchedEvent).await;
+        }
+    }
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<FetchedEvent>::builder().build();
+        let timeout = Duration::from_millis(50);
+
+        let res = async_mediator
+            .send_with_timeout(FetchRequest(Duration::ZERO), timeout)
+            .await;
+        assert_eq!(res, Ok(()));
+
+        let res = async_mediator
+            .send_with_timeout(FetchRequest(Duration::from_secs(3600)), timeout)
+            .await;
+        assert_eq!(res, Err(TimeoutError));
+
+        assert_eq!(async_mediator.next_all().await, 1);
+    })
+}
+
+#[cfg(all(feature = "tracing", feature = "async"))]
+#[test]
+fn tracing_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::basic::*;
+
+    use std::{
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Event, Metadata, Subscriber,
+    };
+
+    /// Records every span and event as `name{field=value ...}`.
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    struct Fields(String);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            if field.name() != "elapsed" {
+                self.0 += &format!(" {}={:?}", field.name(), value);
+            }
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = Fields(span.metadata().name().to_owned());
+            span.record(&mut fields);
+            let mut records = self.0.lock().unwrap();
+            records.push(fields.0);
+            Id::from_u64(records.len() as u64)
+        }
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields(String::from("event"));
+            event.record(&mut fields);
+            self.0.lock().unwrap().push(fields.0);
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    struct PingRequest;
+    #[derive(Debug)]
+    struct PingEvent;
+
+    #[async_trait]
+    impl AsyncRequestHandler<PingRequest, PingEvent> for BasicAsyncMediator<PingEvent> {
+        async fn handle(&self, _: PingRequest) {
+            self.publish(PingEvent).await;
+        }
+    }
+
+    let records = Arc::new(Mutex::new(vec![]));
+    let subscriber = Recorder(records.clone());
+
+    tracing::subscriber::with_default(subscriber, || {
+        async_std::task::block_on(async {
+            let async_mediator = BasicAsyncMediator::<PingEvent>::builder()
+                .add_listener(|_: &PingEvent| {})
+                .build();
+
+            async_mediator.send(PingRequest).await;
+            async_mediator.next().await.unwrap();
+        })
+    });
+
+    let records = records.lock().unwrap();
+    let request = format!(
+        "request op=\"send\" request={:?}",
+        std::any::type_name::<PingRequest>()
+    );
+    assert_eq!(records[0], request);
+    assert!(records.contains(&String::from("event message=published event=PingEvent")));
+    assert!(records.contains(&String::from("next event=PingEvent")));
+    assert!(records
+        .iter()
+        .any(|record| record.starts_with("event message=listener invoked listener=")));
+    assert!(records
+        .iter()
+        .any(|record| record.starts_with("listener listener=") && record.contains(" name=")));
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn mediator_observer_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    struct PingRequest;
+    #[derive(Debug)]
+    struct PingEvent;
+
+    impl RequestHandler<PingRequest, PingEvent> for BasicMediator<PingEvent> {
+        fn handle(&self, _: PingRequest) {
+            self.publish_to("pings", PingEvent);
+        }
+    }
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<String>>);
+
+    impl MediatorObserver<PingEvent> for Recorder {
+        fn on_request_start(&self, request: &'static str) {
+            assert_eq!(request, std::any::type_name::<PingRequest>());
+            self.0.lock().unwrap().push(String::from("start"));
+        }
+
+        fn on_request_finish(&self, _: &'static str, _: Duration) {
+            self.0.lock().unwrap().push(String::from("finish"));
+        }
+
+        fn on_event_published(&self, ev: &PingEvent, topic: Option<&str>) {
+            let record = format!("published {:?} to {:?}", ev, topic);
+            self.0.lock().unwrap().push(record);
+        }
+
+        fn on_listener_invoked(&self, _: ListenerHandle, _: Duration) {
+            self.0.lock().unwrap().push(String::from("invoked"));
+        }
+    }
+
+    let recorder = Arc::new(Recorder::default());
+    let mediator = BasicMediator::<PingEvent>::builder()
+        .add_listener(|_: &PingEvent| {})
+        .add_listener_for_topic("other", |_: &PingEvent| {})
+        .with_observer(recorder.clone())
+        .build();
+
+    mediator.send(PingRequest);
+    mediator.next().unwrap();
+
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        vec![
+            "start",
+            "published PingEvent to Some(\"pings\")",
+            "finish",
+            "invoked"
+        ]
+    );
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn mediator_observer_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::contextaware::*;
+
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    struct PingRequest;
+    #[derive(Debug)]
+    struct PingEvent;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<(), PingRequest, PingEvent>
+        for CxAwareAsyncMediator<(), PingEvent>
+    {
+        async fn handle(&self, _: PingRequest, _: &()) {
+            self.publish(PingEvent).await;
+        }
+    }
+
+    #[derive(Default)]
+    struct Counter {
+        requests: AtomicUsize,
+        published: AtomicUsize,
+        invoked: AtomicUsize,
+    }
+
+    impl MediatorObserver<PingEvent> for Counter {
+        fn on_request_finish(&self, _: &'static str, _: Duration) {
+            self.requests.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn on_event_published(&self, _: &PingEvent, _: Option<&str>) {
+            self.published.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn on_listener_invoked(&self, _: ListenerHandle, _: Duration) {
+            self.invoked.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let counter = Arc::new(Counter::default());
+
+    async_std::task::block_on(async {
+        let async_mediator = CxAwareAsyncMediator::<(), PingEvent>::builder()
+            .add_listener(|_: &PingEvent| {})
+            .add_async_listener(|_: &PingEvent| async {})
+            .with_observer(counter.clone())
+            .add_context(())
+            .build()
+            .unwrap();
+
+        async_mediator.send(PingRequest).await;
+        async_mediator.send(PingRequest).await;
+        assert_eq!(async_mediator.next_all().await, 2);
+    });
+
+    assert_eq!(counter.requests.load(Ordering::Relaxed), 2);
+    assert_eq!(counter.published.load(Ordering::Relaxed), 2);
+    assert_eq!(counter.invoked.load(Ordering::Relaxed), 4);
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn replay_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct ConfigEvent(u32);
+
+    let mediator = BasicMediator::<ConfigEvent>::builder()
+        .with_replay_capacity(2)
+        .build();
+
+    for i in 0..3 {
+        mediator.publish(ConfigEvent(i));
+    }
+    assert_eq!(mediator.next_all(), 3);
+    mediator.publish(ConfigEvent(3));
+
+    let received = Arc::new(Mutex::new(vec![]));
+    let cloned = received.clone();
+    mediator.add_listener_with_replay(move |ev: &ConfigEvent| cloned.lock().unwrap().push(ev.0));
+    assert_eq!(*received.lock().unwrap(), vec![1, 2]);
+
+    assert_eq!(mediator.next_all(), 1);
+    assert_eq!(*received.lock().unwrap(), vec![1, 2, 3]);
+
+    let late = Arc::new(Mutex::new(vec![]));
+    let cloned = late.clone();
+    mediator.add_listener_with_replay(move |ev: &ConfigEvent| cloned.lock().unwrap().push(ev.0));
+    assert_eq!(*late.lock().unwrap(), vec![2, 3]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn replay_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct ConfigEvent(u32);
+
+    let received = Arc::new(Mutex::new(vec![]));
+    let cloned = received.clone();
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<ConfigEvent>::builder().build();
+        async_mediator.publish(ConfigEvent(0)).await;
+        assert_eq!(async_mediator.next_all().await, 1);
+        async_mediator
+            .add_listener_with_replay(|_: &ConfigEvent| panic!("nothing to replay"))
+            .await;
+
+        let async_mediator = BasicAsyncMediator::<ConfigEvent>::builder()
+            .with_replay_capacity(8)
+            .build();
+        async_mediator.publish(ConfigEvent(0)).await;
+        async_mediator.publish(ConfigEvent(1)).await;
+        assert_eq!(async_mediator.next_all().await, 2);
+
+        async_mediator
+            .add_listener_with_replay(move |ev: &ConfigEvent| cloned.lock().unwrap().push(ev.0))
+            .await;
+        assert_eq!(*received.lock().unwrap(), vec![0, 1]);
+    })
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn dead_letters_test_sync() {
+    use crate::synchronous::basic::*;
+
+    #[derive(Debug, PartialEq)]
+    struct JobEvent(u32);
+
+    let mediator = BasicMediator::<JobEvent>::builder()
+        .with_capacity(2)
+        .with_overflow_policy(OverflowPolicy::DropOldest)
+        .collect_dead_letters(true)
+        .build();
+
+    for i in 0..3 {
+        mediator.publish(JobEvent(i));
+    }
+    let letters: Vec<_> = mediator.dead_letters().collect();
+    assert_eq!(letters.len(), 1);
+    assert_eq!(letters[0].event(), &JobEvent(0));
+    assert_eq!(letters[0].reason(), &DeadLetterReason::Overflow);
+    assert_eq!(mediator.dead_letters().len(), 0);
+
+    let mediator = BasicMediator::<JobEvent>::builder()
+        .add_listener(|ev: &JobEvent| assert_ne!(ev.0, 1))
+        .isolate_listeners(true)
+        .collect_dead_letters(true)
+        .build();
+    let handle = mediator.subscribe(|_: &JobEvent| {});
+
+    mediator.publish(JobEvent(0));
+    mediator.publish_to("jobs", JobEvent(1));
+    assert_eq!(mediator.next_all(), 2);
+
+    let letter = mediator.dead_letters().next().unwrap();
+    assert_eq!(letter.topic(), Some("jobs"));
+    match letter.reason() {
+        DeadLetterReason::ListenerPanicked(handles) => {
+            assert_eq!(handles.len(), 1);
+            assert_ne!(handles[0], handle);
+        }
+        reason => panic!("unexpected reason: {:?}", reason),
+    }
+
+    let mediator = BasicMediator::<JobEvent>::builder()
+        .with_capacity(1)
+        .with_overflow_policy(OverflowPolicy::Error)
+        .build();
+    mediator.publish(JobEvent(0));
+    mediator.publish(JobEvent(1));
+    assert_eq!(mediator.dead_letters().len(), 0);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn dead_letters_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use crate::channel::TrySendError;
+
+    #[derive(Debug, PartialEq)]
+    struct JobEvent(u32);
+
+    async_std::task::block_on(async {
+        let async_mediator = CxAwareAsyncMediator::<(), JobEvent>::builder()
+            .add_async_listener(|ev: &JobEvent| {
+                let failed = ev.0 == 1;
+                async move { assert!(!failed) }
+            })
+            .with_capacity(2)
+            .with_overflow_policy(OverflowPolicy::Error)
+            .isolate_listeners(true)
+            .collect_dead_letters(true)
+            .add_context(())
+            .build()
+            .unwrap();
+
+        for i in 0..3 {
+            async_mediator.publish(JobEvent(i)).await;
+        }
+        assert!(matches!(
+            async_mediator.try_publish(JobEvent(3)).await,
+            Err(TrySendError::Full(JobEvent(3)))
+        ));
+        assert_eq!(async_mediator.next_all().await, 2);
+
+        let letters: Vec<_> = async_mediator.dead_letters().await.collect();
+        assert_eq!(letters.len(), 2);
+        assert_eq!(letters[0].reason(), &DeadLetterReason::Overflow);
+        assert!(matches!(
+            letters[1].reason(),
+            DeadLetterReason::ListenerPanicked(_)
+        ));
+
+        for letter in letters {
+            async_mediator.publish(letter.into_event()).await;
+        }
+        assert_eq!(async_mediator.next_all().await, 2);
+        assert_eq!(async_mediator.dead_letters().await.len(), 1);
+    })
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn retry_policy_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::contextaware::*;
+
+    use std::{
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc, Mutex,
+        },
+        time::{Duration, Instant},
+    };
+
+    #[derive(Clone)]
+    struct ConnectRequest {
+        succeed_on: u32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct ConnectError(u32);
+
+    impl From<ReentrantSendError> for ConnectError {
+        fn from(_: ReentrantSendError) -> Self {
+            unreachable!("requests aren't sent from within handlers")
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum ConnectEvent {
+        Connected(u32),
+        GaveUp(u32),
+    }
+
+    #[async_trait]
+    impl TryCxAwareAsyncRequestHandler<AtomicU32, ConnectRequest, ConnectEvent, ConnectError>
+        for CxAwareAsyncMediator<AtomicU32, ConnectEvent>
+    {
+        async fn handle(&self, req: ConnectRequest, cx: &AtomicU32) -> Result<(), ConnectError> {
+            let call = cx.fetch_add(1, Ordering::SeqCst) + 1;
+            if call < req.succeed_on {
+                return Err(ConnectError(call));
+            }
+            self.publish(ConnectEvent::Connected(call)).await;
+            Ok(())
+        }
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+
+    async_std::task::block_on(async {
+        let async_mediator = CxAwareAsyncMediator::<AtomicU32, ConnectEvent>::builder()
+            .add_context(AtomicU32::new(0))
+            .with_retry_policy(RetryPolicy {
+                max_attempts: 3,
+                backoff: Duration::from_millis(5),
+            })
+            .on_retry_exhausted(|exhausted| ConnectEvent::GaveUp(exhausted.attempts))
+            .add_listener(move |ev: &ConnectEvent| {
+                events_clone.lock().unwrap().push(ev.clone());
+            })
+            .build()
+            .unwrap();
+
+        let res = async_mediator
+            .try_send_with_retry(ConnectRequest { succeed_on: 3 })
+            .await;
+        assert_eq!(res, Ok(()));
+
+        let start = Instant::now();
+        let res = async_mediator
+            .try_send_with_retry(ConnectRequest { succeed_on: 100 })
+            .await;
+        assert_eq!(res, Err(ConnectError(6)));
+        assert!(start.elapsed() >= Duration::from_millis(15));
+
+        let res = async_mediator
+            .try_send(ConnectRequest { succeed_on: 100 })
+            .await;
+        assert_eq!(res, Err(ConnectError(7)));
+
+        async_mediator.next_all().await;
+    });
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![ConnectEvent::Connected(3), ConnectEvent::GaveUp(3)]
+    );
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn scheduled_publish_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::basic::*;
+
+    use std::{
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    };
+
+    struct ScheduleRequest;
+    #[derive(Debug, Clone, PartialEq)]
+    enum ScheduledEvent {
+        Now,
+        Soon,
+        Later,
+    }
+
+    #[async_trait]
+    impl AsyncRequestHandler<ScheduleRequest, ScheduledEvent> for BasicAsyncMediator<ScheduledEvent> {
+        async fn handle(&self, _: ScheduleRequest) {
+            self.publish_after(ScheduledEvent::Later, Duration::from_millis(40))
+                .await;
+            self.publish_after(ScheduledEvent::Soon, Duration::from_millis(20))
+                .await;
+            self.publish_at(ScheduledEvent::Now, Instant::now()).await;
+        }
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<ScheduledEvent>::builder()
+            .add_listener(move |ev: &ScheduledEvent| {
+                events_clone.lock().unwrap().push(ev.clone());
+            })
+            .build();
+
+        let start = Instant::now();
+        async_mediator.send(ScheduleRequest).await;
+
+        for _ in 0..3 {
+            async_mediator.next_async().await.unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(40));
+        assert!(matches!(async_mediator.next().await, Err(NextError::Empty)));
+    });
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            ScheduledEvent::Now,
+            ScheduledEvent::Soon,
+            ScheduledEvent::Later
+        ]
+    );
+}
+
+// The `wasm` feature only swaps the clock and timers on `wasm32` targets,
+// everywhere else they keep working as before.
+#[cfg(all(feature = "async", feature = "wasm"))]
+#[test]
+fn wasm_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::basic::*;
+
+    use std::time::{Duration, Instant};
+
+    struct SlowRequest;
+    #[derive(Debug)]
+    struct SlowEvent;
+
+    #[async_trait]
+    impl AsyncRequestHandler<SlowRequest, SlowEvent> for BasicAsyncMediator<SlowEvent> {
+        async fn handle(&self, _: SlowRequest) {
+            async_std::task::sleep(Duration::from_secs(3600)).await;
+        }
+    }
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<SlowEvent>::builder().build();
+
+        let start = Instant::now();
+        async_mediator
+            .publish_after(SlowEvent, Duration::from_millis(20))
+            .await;
+        assert!(matches!(async_mediator.next().await, Err(NextError::Empty)));
+        async_mediator.next_async().await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        let res = async_mediator
+            .send_with_timeout(SlowRequest, Duration::from_millis(20))
+            .await;
+        assert_eq!(res, Err(TimeoutError));
+    })
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn worker_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    struct CountRequest(usize);
+    #[derive(Debug)]
+    struct CountEvent;
+
+    #[async_trait]
+    impl AsyncRequestHandler<CountRequest, CountEvent> for BasicAsyncMediator<CountEvent> {
+        async fn handle(&self, req: CountRequest) {
+            for _ in 0..req.0 {
+                self.publish(CountEvent).await;
+            }
+        }
+    }
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let counter_clone = counter.clone();
+
+    async_std::task::block_on(async {
+        let async_mediator = Arc::new(
+            BasicAsyncMediator::<CountEvent>::builder()
+                .add_listener(move |_: &CountEvent| {
+                    counter_clone.fetch_add(1, Ordering::SeqCst);
+                })
+                .build(),
+        );
+
+        let worker = async_mediator.clone().spawn_worker();
+        assert!(!worker.is_shutdown());
+
+        async_mediator.send(CountRequest(3)).await;
+        async_mediator.send(CountRequest(7)).await;
+
+        // Pending events are still processed during shutdown.
+        assert_eq!(worker.shutdown().await, 10);
+        assert_eq!(counter.load(Ordering::SeqCst), 10);
+
+        let shutdown = CancellationToken::new();
+        shutdown.cancel();
+        async_mediator.send(CountRequest(2)).await;
+        assert_eq!(async_mediator.run(&shutdown).await, 2);
+    });
+
+    assert_eq!(counter.load(Ordering::SeqCst), 12);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn mediator_handle_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::contextaware::*;
+
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[derive(Debug, Default)]
+    struct Visits(AtomicUsize);
+
+    struct VisitRequest;
+    #[derive(Debug)]
+    enum VisitEvent {
+        Visited,
+        Announced,
+    }
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Visits, VisitRequest, VisitEvent>
+        for CxAwareAsyncMediator<Visits, VisitEvent>
+    {
+        async fn handle(&self, _: VisitRequest, cx: &Visits) {
+            cx.0.fetch_add(1, Ordering::SeqCst);
+            self.publish(VisitEvent::Visited).await;
+        }
+    }
+
+    fn assert_shareable<T: Clone + Send + Sync + 'static>(_: &T) {}
+
+    let announced = Arc::new(AtomicUsize::new(0));
+    let announced_clone = announced.clone();
+
+    async_std::task::block_on(async {
+        let async_mediator = CxAwareAsyncMediator::<Visits, VisitEvent>::builder()
+            .add_context(Visits::default())
+            .add_listener(move |ev: &VisitEvent| {
+                if let VisitEvent::Announced = ev {
+                    announced_clone.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+            .build()
+            .unwrap();
+
+        let handle = async_mediator.new_handle();
+        assert_shareable(&handle);
+
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let handle = handle.clone();
+                async_std::task::spawn(async move {
+                    handle.send(VisitRequest).await;
+                    handle.publish(VisitEvent::Announced).await;
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await;
+        }
+        drop(handle);
+
+        assert_eq!(async_mediator.next_all().await, 16);
+        let cx = async_mediator.cx.lock().await;
+        assert_eq!(cx.0.load(Ordering::SeqCst), 8);
+    });
+
+    assert_eq!(announced.load(Ordering::SeqCst), 8);
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn correlation_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    struct OrderRequest;
+    #[derive(Debug)]
+    struct OrderEvent(Option<CorrelationId>);
+
+    impl RequestHandler<OrderRequest, OrderEvent> for BasicMediator<OrderEvent> {
+        fn handle(&self, _req: OrderRequest) {
+            self.publish(OrderEvent(CorrelationId::current()));
+        }
+    }
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+    let mediator = BasicMediator::<OrderEvent>::builder()
+        .add_enveloped_listener(move |ev: &Enveloped<'_, OrderEvent>| {
+            assert_eq!(ev.event().0, ev.correlation_id());
+            assert_eq!(CorrelationId::current(), ev.correlation_id());
+            cloned.lock().unwrap().push(ev.correlation_id());
+        })
+        .build();
+
+    mediator.send(OrderRequest);
+    let first = mediator.send_traced(OrderRequest);
+    let second = mediator.send_traced(OrderRequest);
+    assert_ne!(first, second);
+    assert_eq!(CorrelationId::current(), None);
+
+    assert_eq!(mediator.next_all(), 3);
+    assert_eq!(*seen.lock().unwrap(), vec![None, Some(first), Some(second)]);
+    assert_eq!(CorrelationId::current(), None);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn correlation_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    struct OrderRequest;
+    #[derive(Debug)]
+    struct OrderEvent(Option<CorrelationId>);
+
+    #[async_trait]
+    impl AsyncRequestHandler<OrderRequest, OrderEvent> for BasicAsyncMediator<OrderEvent> {
+        async fn handle(&self, _req: OrderRequest) {
+            async_std::task::yield_now().await;
+            self.publish(OrderEvent(CorrelationId::current())).await;
+        }
+    }
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<OrderEvent>::builder()
+            .add_enveloped_listener(move |ev: &Enveloped<'_, OrderEvent>| {
+                assert_eq!(ev.event().0, ev.correlation_id());
+                cloned.lock().unwrap().push(ev.correlation_id());
+            })
+            .build();
+
+        async_mediator.send(OrderRequest).await;
+        let id = async_mediator.send_traced(OrderRequest).await;
+        assert_eq!(CorrelationId::current(), None);
+
+        assert_eq!(async_mediator.next_all().await, 2);
+        assert_eq!(*seen.lock().unwrap(), vec![None, Some(id)]);
+    });
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn envelope_metadata_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::{
+        sync::{Arc, Mutex},
+        time::SystemTime,
+    };
+
+    struct AuditRequest;
+    #[derive(Debug)]
+    struct AuditEvent;
+
+    impl RequestHandler<AuditRequest, AuditEvent> for BasicMediator<AuditEvent> {
+        fn handle(&self, _req: AuditRequest) {
+            self.publish(AuditEvent);
+        }
+    }
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+    let mediator = BasicMediator::<AuditEvent>::builder()
+        .add_enveloped_listener(move |ev: &Enveloped<'_, AuditEvent>| {
+            cloned.lock().unwrap().push((
+                ev.sequence().unwrap(),
+                ev.timestamp().unwrap(),
+                ev.source(),
+            ));
+        })
+        .build();
+
+    let before = SystemTime::now();
+    mediator.send(AuditRequest);
+    mediator.publish(AuditEvent);
+    mediator.send(AuditRequest);
+    assert_eq!(mediator.next_all(), 3);
+
+    let seen = seen.lock().unwrap();
+    assert!(seen.windows(2).all(|w| w[0].0 < w[1].0));
+    assert!(seen.windows(2).all(|w| w[0].1 <= w[1].1));
+    assert!(seen[0].1 >= before);
+    let sources: Vec<_> = seen.iter().map(|(_, _, source)| *source).collect();
+    assert_eq!(
+        sources,
+        vec![
+            Some(std::any::type_name::<AuditRequest>()),
+            None,
+            Some(std::any::type_name::<AuditRequest>()),
+        ]
+    );
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn envelope_metadata_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+
+    struct AuditRequest;
+    #[derive(Debug)]
+    struct AuditEvent;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<(), AuditRequest, AuditEvent>
+        for CxAwareAsyncMediator<(), AuditEvent>
+    {
+        async fn handle(&self, _req: AuditRequest, _cx: &()) {
+            async_std::task::yield_now().await;
+            self.publish(AuditEvent).await;
+        }
+    }
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+
+    async_std::task::block_on(async {
+        let async_mediator = CxAwareAsyncMediator::<(), AuditEvent>::builder()
+            .add_context(())
+            .add_enveloped_listener(move |ev: &Enveloped<'_, AuditEvent>| {
+                cloned
+                    .lock()
+                    .unwrap()
+                    .push((ev.sequence().unwrap(), ev.source()));
+            })
+            .build()
+            .unwrap();
+
+        async_mediator.publish(AuditEvent).await;
+        async_mediator.send(AuditRequest).await;
+        assert_eq!(async_mediator.next_all().await, 2);
+    });
+
+    let seen = seen.lock().unwrap();
+    assert!(seen[0].0 < seen[1].0);
+    assert_eq!(seen[0].1, None);
+    assert_eq!(seen[1].1, Some(std::any::type_name::<AuditRequest>()));
+}
+
+#[cfg(all(not(feature = "async"), feature = "persistence"))]
+#[test]
+fn journal_test_sync() {
+    use crate::persistence::read_journal;
+    use crate::synchronous::basic::*;
+
+    use serde::{Deserialize, Serialize};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum AccountEvent {
+        Deposited(u32),
+        Withdrawn(u32),
+    }
+
+    let path = std::env::temp_dir().join(format!("mediatrix-journal-{}.jsonl", std::process::id()));
+    std::fs::remove_file(&path).ok();
+
+    let journal = Journal::open(&path).unwrap();
+    let mediator = BasicMediator::<AccountEvent>::builder()
+        .with_observer(journal.clone())
+        .build();
+    mediator.publish(AccountEvent::Deposited(50));
+    mediator.publish_to("audit", AccountEvent::Withdrawn(20));
+    mediator.publish(AccountEvent::Deposited(5));
+    assert!(journal.take_error().is_none());
+
+    let entries: Vec<_> = read_journal::<AccountEvent>(&path)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[1].topic.as_deref(), Some("audit"));
+    assert_eq!(entries[1].event, AccountEvent::Withdrawn(20));
+
+    let balance = Arc::new(Mutex::new(0i64));
+    let cloned = balance.clone();
+    let fresh = BasicMediator::<AccountEvent>::builder()
+        .add_listener(move |ev: &AccountEvent| match ev {
+            AccountEvent::Deposited(n) => *cloned.lock().unwrap() += *n as i64,
+            AccountEvent::Withdrawn(n) => *cloned.lock().unwrap() -= *n as i64,
+        })
+        .build();
+    assert_eq!(fresh.replay_from(&path).unwrap(), 3);
+    assert_eq!(fresh.next_all(), 3);
+    assert_eq!(*balance.lock().unwrap(), 35);
+
+    std::fs::write(&path, "{\"topic\":null,\"event\":\"Unknown\"}\n").unwrap();
+    assert!(fresh.replay_from(&path).is_err());
+    std::fs::remove_file(&path).ok();
+}
+
+#[cfg(all(feature = "async", feature = "persistence"))]
+#[test]
+fn journal_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use serde::{Deserialize, Serialize};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct CountEvent(u32);
+
+    let path = std::env::temp_dir().join(format!("mediatrix-journal-{}.jsonl", std::process::id()));
+    std::fs::remove_file(&path).ok();
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+
+    async_std::task::block_on(async {
+        let journal = Journal::open(&path).unwrap();
+        let async_mediator = CxAwareAsyncMediator::<(), CountEvent>::builder()
+            .add_context(())
+            .with_observer(journal.clone())
+            .build()
+            .unwrap();
+        async_mediator.publish(CountEvent(1)).await;
+        async_mediator.publish_to("counts", CountEvent(2)).await;
+        assert!(journal.take_error().is_none());
+
+        let fresh = CxAwareAsyncMediator::<(), CountEvent>::builder()
+            .add_context(())
+            .add_listener_for_topic("counts", move |ev: &CountEvent| {
+                cloned.lock().unwrap().push(ev.0)
+            })
+            .build()
+            .unwrap();
+        assert_eq!(fresh.replay_from(&path).await.unwrap(), 2);
+        assert_eq!(fresh.next_all().await, 2);
+    });
+
+    assert_eq!(*seen.lock().unwrap(), vec![2]);
+    std::fs::remove_file(&path).ok();
+}
+
+#[cfg(all(feature = "remote", unix))]
+#[test]
+fn remote_bridge_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::remote::{serve, Endpoint, RemoteForwarder};
+
+    use serde::{Deserialize, Serialize};
+    use std::{
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct PriceEvent(u32);
+
+    let path = std::env::temp_dir().join(format!("mediatrix-remote-{}.sock", std::process::id()));
+    std::fs::remove_file(&path).ok();
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+
+    async_std::task::block_on(async {
+        let (forwarder, worker) =
+            RemoteForwarder::spawn(Endpoint::Unix(path.clone()), Duration::from_millis(5));
+        let local = BasicAsyncMediator::<PriceEvent>::builder()
+            .with_observer(forwarder)
+            .build();
+
+        // Nobody listens yet, the forwarder keeps reconnecting.
+        local.publish(PriceEvent(1)).await;
+        local.publish_to("prices", PriceEvent(2)).await;
+        async_std::task::sleep(Duration::from_millis(20)).await;
+
+        let remote = Arc::new(
+            BasicAsyncMediator::<PriceEvent>::builder()
+                .add_listener_for_topic("prices", move |ev: &PriceEvent| {
+                    cloned.lock().unwrap().push(ev.0)
+                })
+                .build(),
+        );
+        let server = serve(Endpoint::Unix(path.clone()), remote.clone())
+            .await
+            .unwrap();
+
+        let start = Instant::now();
+        let mut received = 0;
+        while received < 2 && start.elapsed() < Duration::from_secs(5) {
+            received += remote.next_all().await;
+            async_std::task::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(received, 2);
+
+        assert_eq!(worker.shutdown().await, 2);
+        assert_eq!(server.shutdown().await, 2);
+    });
+
+    assert_eq!(*seen.lock().unwrap(), vec![2]);
+    std::fs::remove_file(&path).ok();
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn dynamic_dispatch_test_sync() {
+    use crate::synchronous::basic::*;
+    use crate::synchronous::contextaware::*;
+
+    use std::{
+        any::Any,
+        sync::{Arc, Mutex},
+    };
+
+    struct AddRequest(u32);
+    struct UnknownRequest;
+    #[derive(Debug)]
+    struct SumEvent(u32);
+
+    struct Trace(Arc<Mutex<Vec<&'static str>>>);
+
+    impl Behavior for Trace {
+        fn handle(&self, req: AnyRequest, next: Next<'_>) {
+            self.0.lock().unwrap().push(req.type_name());
+            next.run(req);
+        }
+    }
+
+    let trace = Arc::new(Mutex::new(vec![]));
+    let sums = Arc::new(Mutex::new(vec![]));
+    let cloned = sums.clone();
+    let mediator = BasicMediator::<SumEvent>::builder()
+        .add_listener(move |ev: &SumEvent| cloned.lock().unwrap().push(ev.0))
+        .add_behavior(Trace(trace.clone()))
+        .register_handler(|m: &BasicMediator<SumEvent>, req: AddRequest| {
+            m.publish(SumEvent(req.0 + 1));
+        })
+        .build();
+
+    let requests: Vec<Box<dyn Any + Send>> = vec![Box::new(AddRequest(1)), Box::new(AddRequest(2))];
+    for req in requests {
+        assert!(mediator.send_dyn(req).is_ok());
+    }
+    let rejected = mediator.send_dyn(Box::new(UnknownRequest)).unwrap_err();
+    assert!(rejected.downcast_ref::<UnknownRequest>().is_some());
+
+    assert_eq!(mediator.next_all(), 2);
+    assert_eq!(*sums.lock().unwrap(), vec![2, 3]);
+    assert_eq!(trace.lock().unwrap().len(), 2);
+    assert!(trace.lock().unwrap()[0].ends_with("AddRequest"));
+
+    let cx_mediator = CxAwareMediator::<u32, SumEvent>::builder()
+        .register_handler(
+            |m: &CxAwareMediator<u32, SumEvent>, req: AddRequest, cx: &u32| {
+                m.publish(SumEvent(req.0 + cx));
+            },
+        )
+        .add_context(10)
+        .build()
+        .unwrap();
+
+    assert!(cx_mediator.send_dyn(Box::new(AddRequest(5))).is_ok());
+    assert!(cx_mediator.send_dyn(Box::new(UnknownRequest)).is_err());
+    assert_eq!(cx_mediator.next_all(), 1);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn dynamic_dispatch_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::asynchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+
+    struct AddRequest(u32);
+    struct UnknownRequest;
+    #[derive(Debug)]
+    struct SumEvent(u32);
+
+    let sums = Arc::new(Mutex::new(vec![]));
+    let cloned = sums.clone();
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<SumEvent>::builder()
+            .add_listener(move |ev: &SumEvent| cloned.lock().unwrap().push(ev.0))
+            .register_handler(|m: MediatorHandle<SumEvent>, req: AddRequest| async move {
+                m.publish(SumEvent(req.0 + 1)).await;
+            })
+            .build();
+
+        assert!(async_mediator
+            .send_dyn(Box::new(AddRequest(1)))
+            .await
+            .is_ok());
+        let rejected = async_mediator
+            .send_dyn(Box::new(UnknownRequest))
+            .await
+            .unwrap_err();
+        assert!(rejected.downcast_ref::<UnknownRequest>().is_some());
+        assert_eq!(async_mediator.next_all().await, 1);
+        assert_eq!(*sums.lock().unwrap(), vec![2]);
+
+        let cx_mediator = CxAwareAsyncMediator::<u32, SumEvent>::builder()
+            .register_handler(
+                |m: CxAwareMediatorHandle<u32, SumEvent>, req: AddRequest, cx| async move {
+                    let cx = *cx.lock().await;
+                    m.publish(SumEvent(req.0 + cx)).await;
+                },
+            )
+            .add_context(10)
+            .build()
+            .unwrap();
+
+        assert!(cx_mediator.send_dyn(Box::new(AddRequest(5))).await.is_ok());
+        assert!(cx_mediator
+            .send_dyn(Box::new(UnknownRequest))
+            .await
+            .is_err());
+        assert_eq!(cx_mediator.next_all().await, 1);
+    });
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn stream_request_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use futures::stream::{self, Stream, StreamExt};
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    struct PageRequest(u32);
+    #[derive(Debug)]
+    struct PageEvent(u32);
+
+    impl StreamRequestHandler<PageRequest, PageEvent, u32> for BasicAsyncMediator<PageEvent> {
+        fn handle(&self, req: PageRequest) -> impl Stream<Item = u32> + Send + '_ {
+            stream::iter(1..=req.0).then(move |page| async move {
+                self.publish(PageEvent(page)).await;
+                page * 10
+            })
+        }
+    }
+
+    #[derive(Default)]
+    struct Requests(Mutex<Vec<&'static str>>);
+
+    impl MediatorObserver<PageEvent> for Requests {
+        fn on_request_start(&self, _request: &'static str) {
+            self.0.lock().unwrap().push("start");
+        }
+
+        fn on_request_finish(&self, _request: &'static str, _elapsed: Duration) {
+            self.0.lock().unwrap().push("finish");
+        }
+    }
+
+    let sources = Arc::new(Mutex::new(vec![]));
+    let cloned = sources.clone();
+    let requests = Arc::new(Requests::default());
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<PageEvent>::builder()
+            .add_enveloped_listener(move |ev: &Enveloped<'_, PageEvent>| {
+                cloned.lock().unwrap().push((ev.event().0, ev.source()));
+            })
+            .with_observer(requests.clone())
+            .build();
+
+        let mut pages = async_mediator.send_stream(PageRequest(3));
+        assert_eq!(pages.next().await, Some(10));
+        assert_eq!(*requests.0.lock().unwrap(), vec!["start"]);
+        assert_eq!(pages.next().await, Some(20));
+        assert_eq!(pages.next().await, Some(30));
+        assert_eq!(pages.next().await, None);
+        assert_eq!(*requests.0.lock().unwrap(), vec!["start", "finish"]);
+        drop(pages);
+
+        let handle = async_mediator.new_handle();
+        let pages: Vec<u32> = handle.send_stream(PageRequest(2)).collect().await;
+        assert_eq!(pages, vec![10, 20]);
+
+        assert_eq!(async_mediator.next_all().await, 5);
+        let sources = sources.lock().unwrap();
+        assert_eq!(sources.len(), 5);
+        assert!(sources
+            .iter()
+            .all(|(_, source)| source.unwrap().ends_with("PageRequest")));
+    });
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn concurrency_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::asynchronous::contextaware::{
+        CxAwareAsyncMediator, TryBuilderFlow, TryBuilderInternal,
+    };
+
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    #[derive(Debug)]
+    struct FetchEvent;
+
+    #[derive(Default)]
+    struct InFlight {
+        current: AtomicUsize,
+        max: AtomicUsize,
+    }
+
+    impl InFlight {
+        async fn track(&self) {
+            let current = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max.fetch_max(current, Ordering::SeqCst);
+            async_std::task::sleep(Duration::from_millis(20)).await;
+            self.current.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    async_std::task::block_on(async {
+        let sequential = Arc::new(InFlight::default());
+        let cloned = sequential.clone();
+        let async_mediator = BasicAsyncMediator::<FetchEvent>::builder()
+            .add_async_listener(move |_: &FetchEvent| {
+                let in_flight = cloned.clone();
+                async move { in_flight.track().await }
+            })
+            .build();
+
+        for _ in 0..4 {
+            async_mediator.publish(FetchEvent).await;
+        }
+        assert_eq!(async_mediator.next_all().await, 4);
+        assert_eq!(sequential.max.load(Ordering::SeqCst), 1);
+
+        let concurrent = Arc::new(InFlight::default());
+        let cloned = concurrent.clone();
+        let async_mediator = BasicAsyncMediator::<FetchEvent>::builder()
+            .add_async_listener(move |_: &FetchEvent| {
+                let in_flight = cloned.clone();
+                async move { in_flight.track().await }
+            })
+            .with_concurrency(3)
+            .build();
+
+        for _ in 0..6 {
+            async_mediator.publish(FetchEvent).await;
+        }
+        assert_eq!(async_mediator.next_all().await, 6);
+        assert_eq!(concurrent.max.load(Ordering::SeqCst), 3);
+
+        let listeners = Arc::new(InFlight::default());
+        let (first, second) = (listeners.clone(), listeners.clone());
+        let cx_mediator = CxAwareAsyncMediator::<(), FetchEvent>::builder()
+            .add_async_listener(move |_: &FetchEvent| {
+                let in_flight = first.clone();
+                async move { in_flight.track().await }
+            })
+            .add_async_listener(move |_: &FetchEvent| {
+                let in_flight = second.clone();
+                async move { in_flight.track().await }
+            })
+            .with_concurrency(2)
+            .add_context(())
+            .build()
+            .unwrap();
+
+        cx_mediator.publish(FetchEvent).await;
+        cx_mediator.next().await.unwrap();
+        assert_eq!(listeners.max.load(Ordering::SeqCst), 2);
+    });
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn channel_backend_test_sync() {
+    use crate::channel::{ChannelFlavor, StdChannel};
+    use crate::synchronous::basic::*;
+
+    use crate::channel::TrySendError;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, PartialEq)]
+    struct NumberEvent(usize);
+
+    fn check<F: ChannelFlavor>() {
+        let received = Arc::new(Mutex::new(vec![]));
+        let cloned = received.clone();
+        let mediator = BasicMediator::<NumberEvent>::builder()
+            .add_listener(move |ev: &NumberEvent| cloned.lock().unwrap().push(ev.0))
+            .with_capacity(2)
+            .with_channel_backend::<F>()
+            .with_overflow_policy(OverflowPolicy::DropOldest)
+            .build();
+
+        (1..=3).for_each(|n| mediator.publish(NumberEvent(n)));
+        assert_eq!(mediator.next_all(), 2);
+        assert_eq!(*received.lock().unwrap(), vec![2, 3]);
+
+        let mediator = BasicMediator::<NumberEvent>::builder()
+            .with_channel_backend::<F>()
+            .with_capacity(1)
+            .with_overflow_policy(OverflowPolicy::Error)
+            .build();
+
+        assert!(mediator.try_publish(NumberEvent(1)).is_ok());
+        assert_eq!(
+            mediator.try_publish(NumberEvent(2)),
+            Err(TrySendError::Full(NumberEvent(2)))
+        );
+        assert_eq!(mediator.next_all(), 1);
+    }
+
+    check::<StdChannel>();
+    #[cfg(feature = "crossbeam")]
+    check::<crate::channel::CrossbeamChannel>();
+    #[cfg(feature = "flume")]
+    check::<crate::channel::FlumeChannel>();
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn channel_backend_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::channel::{AsyncChannel, ChannelFlavor};
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct NumberEvent(usize);
+
+    async fn check<F: ChannelFlavor>() {
+        let received = Arc::new(Mutex::new(vec![]));
+        let cloned = received.clone();
+        let async_mediator = Arc::new(
+            BasicAsyncMediator::<NumberEvent>::builder()
+                .add_listener(move |ev: &NumberEvent| cloned.lock().unwrap().push(ev.0))
+                .with_channel_backend::<F>()
+                .with_capacity(1)
+                .build(),
+        );
+
+        async_mediator.publish(NumberEvent(1)).await;
+
+        let publisher = async_mediator.clone();
+        let blocked = async_std::task::spawn(async move {
+            publisher.publish(NumberEvent(2)).await;
+        });
+
+        async_mediator.next_async().await.unwrap();
+        blocked.await;
+        assert_eq!(async_mediator.next_all().await, 1);
+        assert_eq!(*received.lock().unwrap(), vec![1, 2]);
+    }
+
+    async_std::task::block_on(async {
+        check::<AsyncChannel>().await;
+        #[cfg(feature = "crossbeam")]
+        check::<crate::channel::CrossbeamChannel>().await;
+        #[cfg(feature = "flume")]
+        check::<crate::channel::FlumeChannel>().await;
+    })
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn event_worker_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    #[derive(Debug)]
+    struct NumberEvent(usize);
+
+    let sum = Arc::new(AtomicUsize::new(0));
+    let cloned = sum.clone();
+    let mediator = BasicMediator::<NumberEvent>::builder()
+        .add_listener(move |ev: &NumberEvent| {
+            cloned.fetch_add(ev.0, Ordering::SeqCst);
+        })
+        .build();
+
+    (1..=100).for_each(|n| mediator.publish(NumberEvent(n)));
+
+    let processed: usize = thread::scope(|s| {
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let worker = mediator.worker();
+                s.spawn(move || worker.next_all())
+            })
+            .collect();
+        workers.into_iter().map(|w| w.join().unwrap()).sum()
+    });
+
+    assert_eq!(processed, 100);
+    assert_eq!(sum.load(Ordering::SeqCst), 5050);
+
+    thread::scope(|s| {
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let worker = mediator.worker();
+                s.spawn(move || worker.next_blocking())
+            })
+            .collect();
+        (1..=4).for_each(|n| mediator.publish(NumberEvent(n)));
+        workers
+            .into_iter()
+            .for_each(|w| assert!(w.join().unwrap().is_ok()));
+    });
+
+    assert_eq!(sum.load(Ordering::SeqCst), 5060);
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn event_worker_non_sync_listener_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::{
+        cell::Cell,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    #[derive(Debug)]
+    struct NumberEvent(usize);
+
+    let sum = Arc::new(AtomicUsize::new(0));
+    let cloned = sum.clone();
+    let running = Cell::new(0);
+    let mediator = BasicMediator::<NumberEvent>::builder()
+        .add_listener(move |ev: &NumberEvent| {
+            running.set(running.get() + ev.0);
+            cloned.store(running.get(), Ordering::SeqCst);
+        })
+        .build();
+
+    (1..=100).for_each(|n| mediator.publish(NumberEvent(n)));
+
+    thread::scope(|s| {
+        (0..4).for_each(|_| {
+            let worker = mediator.worker();
+            s.spawn(move || worker.next_all());
+        })
+    });
+
+    assert_eq!(sum.load(Ordering::SeqCst), 5050);
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn batch_request_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    enum BatchEvent {
+        Pinged,
+        Inserted(Vec<u32>),
+    }
+
+    struct Ping;
+    struct Insert(u32);
+
+    impl RequestHandler<Ping, BatchEvent> for BasicMediator<BatchEvent> {
+        fn handle(&self, _req: Ping) {
+            self.publish(BatchEvent::Pinged);
+        }
+    }
+
+    impl BatchRequestHandler<Insert, BatchEvent> for BasicMediator<BatchEvent> {
+        fn handle_batch(&self, reqs: Vec<Insert>) {
+            self.publish(BatchEvent::Inserted(
+                reqs.into_iter().map(|req| req.0).collect(),
+            ));
+        }
+    }
+
+    let inserted = Arc::new(Mutex::new(vec![]));
+    let cloned = inserted.clone();
+    let mediator = BasicMediator::<BatchEvent>::builder()
+        .add_listener(move |ev: &BatchEvent| {
+            if let BatchEvent::Inserted(ids) = ev {
+                cloned.lock().unwrap().push(ids.clone());
+            }
+        })
+        .build();
+
+    mediator.send_batch(vec![Ping, Ping, Ping]);
+    assert_eq!(mediator.next_all(), 3);
+
+    mediator.send_batch(vec![Insert(1), Insert(2)]);
+    assert_eq!(mediator.next_all(), 1);
+    assert_eq!(*inserted.lock().unwrap(), vec![vec![1, 2]]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn batch_request_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    enum BatchEvent {
+        Pinged,
+        Inserted(Vec<u32>),
+    }
+
+    struct Ping;
+    struct Insert(u32);
+
+    #[async_trait]
+    impl AsyncRequestHandler<Ping, BatchEvent> for BasicAsyncMediator<BatchEvent> {
+        async fn handle(&self, _req: Ping) {
+            self.publish(BatchEvent::Pinged).await;
+        }
+    }
+
+    #[async_trait]
+    impl AsyncBatchRequestHandler<Insert, BatchEvent> for BasicAsyncMediator<BatchEvent> {
+        async fn handle_batch(&self, reqs: Vec<Insert>) {
+            self.publish(BatchEvent::Inserted(
+                reqs.into_iter().map(|req| req.0).collect(),
+            ))
+            .await;
+        }
+    }
+
+    let inserted = Arc::new(Mutex::new(vec![]));
+    let cloned = inserted.clone();
+
+    async_std::task::block_on(async {
+        let async_mediator = BasicAsyncMediator::<BatchEvent>::builder()
+            .add_listener(move |ev: &BatchEvent| {
+                if let BatchEvent::Inserted(ids) = ev {
+                    cloned.lock().unwrap().push(ids.clone());
+                }
+            })
+            .build();
+
+        async_mediator.send_batch(vec![Ping, Ping, Ping]).await;
+        assert_eq!(async_mediator.next_all().await, 3);
+
+        async_mediator
+            .new_handle()
+            .send_batch(vec![Insert(1), Insert(2)])
+            .await;
+        assert_eq!(async_mediator.next_all().await, 1);
+        assert_eq!(*inserted.lock().unwrap(), vec![vec![1, 2]]);
+    })
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn saga_test_sync() {
+    use crate::saga::{add_saga, Saga};
+    use crate::synchronous::basic::*;
+
+    use std::sync::Arc;
+
+    #[derive(Debug)]
+    enum OrderEvent {
+        Placed(u32),
+        Paid(u32),
+        Shipped(u32),
+    }
+
+    struct PlaceOrder(u32);
+
+    enum OrderCommand {
+        Pay(u32),
+        Ship(u32),
+    }
+
+    impl RequestHandler<PlaceOrder, OrderEvent> for BasicMediator<OrderEvent> {
+        fn handle(&self, req: PlaceOrder) {
+            self.publish(OrderEvent::Placed(req.0));
+        }
+    }
+
+    impl RequestHandler<OrderCommand, OrderEvent> for BasicMediator<OrderEvent> {
+        fn handle(&self, cmd: OrderCommand) {
+            match cmd {
+                OrderCommand::Pay(order) => self.publish(OrderEvent::Paid(order)),
+                OrderCommand::Ship(order) => self.publish(OrderEvent::Shipped(order)),
+            }
+        }
+    }
+
+    #[derive(Debug, Default, Clone, PartialEq)]
+    struct Fulfillment {
+        order: Option<u32>,
+        paid: bool,
+        shipped: bool,
+    }
+
+    struct FulfillmentSaga;
+
+    impl Saga<Fulfillment, OrderEvent> for FulfillmentSaga {
+        type Command = OrderCommand;
+
+        fn apply(&self, state: &mut Fulfillment, ev: &OrderEvent) -> Vec<OrderCommand> {
+            match ev {
+                OrderEvent::Placed(order) => {
+                    state.order = Some(*order);
+                    vec![OrderCommand::Pay(*order)]
+                }
+                OrderEvent::Paid(order) => {
+                    state.paid = true;
+                    vec![OrderCommand::Ship(*order)]
+                }
+                OrderEvent::Shipped(order) => {
+                    state.shipped = state.order == Some(*order);
+                    vec![]
+                }
+            }
+        }
+
+        fn is_complete(&self, state: &Fulfillment) -> bool {
+            state.shipped
+        }
+    }
+
+    let mediator = Arc::new(BasicMediator::<OrderEvent>::builder().build());
+    let sagas = add_saga(&mediator, FulfillmentSaga);
+
+    // Untraced events don't start a saga.
+    mediator.publish(OrderEvent::Placed(1));
+    assert!(mediator.next().is_ok());
+    assert!(sagas.is_empty());
+
+    let first = mediator.send_traced(PlaceOrder(7));
+    let second = mediator.send_traced(PlaceOrder(8));
+    for _ in 0..4 {
+        assert!(mediator.next().is_ok());
+    }
+    assert_eq!(sagas.len(), 2);
+    assert_eq!(
+        sagas.get(first),
+        Some(Fulfillment {
+            order: Some(7),
+            paid: true,
+            shipped: false
+        })
+    );
+    assert_eq!(sagas.get(second).and_then(|state| state.order), Some(8));
+
+    assert_eq!(mediator.next_all(), 2);
+    assert!(sagas.is_empty());
+
+    mediator.unsubscribe(sagas.handle());
+    mediator.send_traced(PlaceOrder(9));
+    assert_eq!(mediator.next_all(), 1);
+    assert!(sagas.is_empty());
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn bridge_test_sync() {
+    use crate::bridge::bridge;
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    enum DomainEvent {
+        Created(u32),
+        Deleted(u32),
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum AppEvent {
+        Changed(i64),
+    }
+
+    let seen = Arc::new(Mutex::new(vec![]));
+    let cloned = seen.clone();
+
+    let domain = BasicMediator::<DomainEvent>::builder().build();
+    let app = Arc::new(
+        BasicMediator::<AppEvent>::builder()
+            .add_listener(move |ev: &AppEvent| cloned.lock().unwrap().push(ev.clone()))
+            .build(),
+    );
+
+    let handle = bridge(&domain, &app, |ev: &DomainEvent| match ev {
+        DomainEvent::Created(id) => AppEvent::Changed(*id as i64),
+        DomainEvent::Deleted(id) => AppEvent::Changed(-(*id as i64)),
+    });
+
+    domain.publish(DomainEvent::Created(1));
+    domain.publish(DomainEvent::Deleted(2));
+    assert_eq!(app.next_all(), 0);
+    assert_eq!(domain.next_all(), 2);
+    assert_eq!(app.next_all(), 2);
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![AppEvent::Changed(1), AppEvent::Changed(-2)]
+    );
+
+    assert!(domain.unsubscribe(handle));
+    domain.publish(DomainEvent::Created(3));
+    assert_eq!(domain.next_all(), 1);
+    assert_eq!(app.next_all(), 0);
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn any_mediator_test_sync() {
+    use crate::synchronous::any::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct OrderCreated(u32);
+
+    #[derive(Debug)]
+    struct UserRegistered(&'static str);
+
+    struct CreateOrder(u32);
+
+    impl RequestHandler<CreateOrder, AnyEvent> for AnyMediator {
+        fn handle(&self, req: CreateOrder) {
+            self.publish(OrderCreated(req.0));
+        }
+    }
+
+    let orders = Arc::new(Mutex::new(vec![]));
+    let users = Arc::new(Mutex::new(vec![]));
+    let cloned_orders = orders.clone();
+    let cloned_users = users.clone();
+
+    let mediator = AnyMediator::builder()
+        .add_listener(move |ev: &OrderCreated| cloned_orders.lock().unwrap().push(ev.0))
+        .add_listener(move |ev: &UserRegistered| cloned_users.lock().unwrap().push(ev.0))
+        .register_handler(|m: &AnyMediator, name: &'static str| m.publish(UserRegistered(name)))
+        .build();
+
+    mediator.send(CreateOrder(1));
+    mediator.publish(UserRegistered("anna"));
+    assert!(mediator.send_dyn(Box::new("bert")).is_ok());
+    assert!(mediator.send_dyn(Box::new(2u8)).is_err());
+    // Events without a listener for their type are processed all the same.
+    mediator.publish(7u64);
+    assert_eq!(mediator.next_all(), 4);
+
+    let handle = mediator.subscribe(|ev: &u64| assert_eq!(*ev, 8));
+    mediator.publish(8u64);
+    mediator.send(CreateOrder(3));
+    assert_eq!(mediator.next_all(), 2);
+    assert!(mediator.unsubscribe(handle));
+
+    assert_eq!(*orders.lock().unwrap(), vec![1, 3]);
+    assert_eq!(*users.lock().unwrap(), vec!["anna", "bert"]);
+
+    let ev = AnyEvent::new(OrderCreated(4));
+    assert!(ev.is::<OrderCreated>());
+    assert_eq!(format!("{:?}", ev), "OrderCreated(4)");
+    let ev = ev.downcast::<UserRegistered>().unwrap_err();
+    assert_eq!(ev.downcast::<OrderCreated>().unwrap().0, 4);
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn build_error_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    #[derive(Debug)]
+    enum MyEvent {
+        One,
+    }
+
+    #[derive(Debug, Default)]
+    struct MyContext;
+
+    let err = CxAwareMediator::<MyContext, MyEvent>::builder()
+        .build()
+        .unwrap_err();
+    assert_eq!(err, BuildError::NoCxAvailable);
+    assert_eq!(err.to_string(), "no context was added");
+
+    let err = CxAwareMediator::<MyContext, MyEvent>::builder()
+        .add_context(MyContext)
+        .with_capacity(0)
+        .build()
+        .unwrap_err();
+    assert_eq!(err, BuildError::InvalidCapacity);
+
+    let err = CxAwareMediator::<MyContext, MyEvent>::builder()
+        .add_context(MyContext)
+        .strict()
+        .build()
+        .unwrap_err();
+    assert_eq!(err, BuildError::NoListeners);
+
+    let err = CxAwareMediator::<MyContext, MyEvent>::builder()
+        .add_context(MyContext)
+        .add_listener_for_topic("orders.*", |_: &MyEvent| {})
+        .add_listener_for_topic("orders.#", |_: &MyEvent| {})
+        .add_listener_for_topic("orders.*", |_: &MyEvent| {})
+        .strict()
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        BuildError::DuplicateTopicSubscription("orders.*".to_string())
+    );
+
+    // Duplicate topics are fine unless the builder is strict.
+    let mediator = CxAwareMediator::<MyContext, MyEvent>::builder()
+        .add_context(MyContext)
+        .add_listener_for_topic("orders", |_: &MyEvent| {})
+        .add_listener_for_topic("orders", |_: &MyEvent| {})
+        .build();
+    assert!(mediator.is_ok());
+
+    let mediator = CxAwareMediator::<MyContext, MyEvent>::builder()
+        .add_context(MyContext)
+        .add_listener(|_: &MyEvent| {})
+        .add_listener(|_: &MyEvent| {})
+        .strict()
+        .build()
+        .unwrap();
+    mediator.publish(MyEvent::One);
+    assert_eq!(mediator.next_all(), 1);
+
+    fn build() -> Result<(), Box<dyn std::error::Error>> {
+        CxAwareMediator::<MyContext, MyEvent>::builder().build()?;
+        Ok(())
+    }
+    assert!(build().is_err());
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn build_error_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    #[derive(Debug)]
+    enum MyEvent {
+        One,
+    }
+
+    #[derive(Debug, Default)]
+    struct MyContext;
+
+    let err = CxAwareAsyncMediator::<MyContext, MyEvent>::builder()
+        .add_context(MyContext)
+        .with_capacity(0)
+        .build()
+        .unwrap_err();
+    assert_eq!(err, BuildError::InvalidCapacity);
+
+    let err = CxAwareAsyncMediator::<MyContext, MyEvent>::builder()
+        .add_context(MyContext)
+        .add_listener_for_topic("orders", |_: &MyEvent| {})
+        .add_async_listener_for_topic("orders", |_: &MyEvent| async {})
+        .strict()
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        BuildError::DuplicateTopicSubscription("orders".to_string())
+    );
+
+    async_std::task::block_on(async {
+        let mediator = CxAwareAsyncMediator::<MyContext, MyEvent>::builder()
+            .add_context(MyContext)
+            .add_async_listener(|_: &MyEvent| async {})
+            .strict()
+            .build()
+            .unwrap();
+        mediator.publish(MyEvent::One).await;
+        assert!(mediator.next().await.is_ok());
+    });
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn mediator_error_test_sync() {
+    use crate::channel::{TryRecvError, TrySendError};
+    use crate::error::MediatorError;
+    use crate::synchronous::contextaware::*;
+
+    use std::error::Error;
+
+    #[derive(Debug)]
+    enum MyEvent {
+        One,
+    }
+
+    #[derive(Debug, Default)]
+    struct MyContext;
+
+    fn run(cx: Option<MyContext>) -> Result<usize, MediatorError> {
+        let mut builder = CxAwareMediator::<MyContext, MyEvent>::builder()
+            .with_capacity(1)
+            .with_overflow_policy(OverflowPolicy::Error);
+        if let Some(cx) = cx {
+            builder = builder.add_context(cx);
+        }
+        let mediator = builder.build()?;
+        mediator.try_publish(MyEvent::One)?;
+        mediator.next()?;
+        mediator.next()?;
+        Ok(0)
+    }
+
+    let err = run(None).unwrap_err();
+    assert!(matches!(
+        err,
+        MediatorError::Build(BuildError::NoCxAvailable)
+    ));
+    assert_eq!(err.to_string(), "building the mediator failed");
+    assert_eq!(err.source().unwrap().to_string(), "no context was added");
+
+    let err = run(Some(MyContext)).unwrap_err();
+    assert!(matches!(err, MediatorError::Next(NextError::Empty)));
+
+    let err = MediatorError::from(TrySendError::Full(MyEvent::One));
+    assert!(matches!(
+        err,
+        MediatorError::Publish(TrySendError::Full(()))
+    ));
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        "sending on a full channel"
+    );
+
+    assert_eq!(
+        NextError::from(TryRecvError::Disconnected).to_string(),
+        "channel is disconnected"
+    );
+    assert_eq!(TrySendError::Full(7).into_inner(), 7);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn lazy_context_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use async_trait::async_trait;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[derive(Debug, PartialEq)]
+    enum PoolEvent {
+        Queried(u32),
+    }
+
+    #[derive(Debug)]
+    struct Pool(u32);
+
+    struct Query;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Pool, Query, PoolEvent> for CxAwareAsyncMediator<Pool, PoolEvent> {
+        async fn handle(&self, _req: Query, cx: &Pool) {
+            self.publish(PoolEvent::Queried(cx.0)).await;
+        }
+    }
+
+    async_std::task::block_on(async {
+        let connects = Arc::new(AtomicUsize::new(0));
+        let cloned = connects.clone();
+
+        let mediator = Arc::new(
+            CxAwareAsyncMediator::<Pool, PoolEvent>::builder()
+                .add_context_with(move || async move {
+                    cloned.fetch_add(1, Ordering::SeqCst);
+                    Pool(5)
+                })
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(connects.load(Ordering::SeqCst), 0);
+
+        let sends: Vec<_> = (0..4)
+            .map(|_| {
+                let mediator = mediator.clone();
+                async_std::task::spawn(async move { mediator.send(Query).await })
+            })
+            .collect();
+        for send in sends {
+            send.await;
+        }
+        assert_eq!(connects.load(Ordering::SeqCst), 1);
+        assert_eq!(mediator.next_all().await, 4);
+
+        // A context added afterwards replaces the factory.
+        let mediator = CxAwareAsyncMediator::<Pool, PoolEvent>::builder()
+            .add_context_with(|| async { unreachable!() })
+            .add_context(Pool(1))
+            .build()
+            .unwrap();
+        mediator.send(Query).await;
+        assert_eq!(mediator.next_all().await, 1);
+    });
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn scoped_send_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum AuditEvent {
+        Deleted { id: u32, by: String, tenant: u32 },
+    }
+
+    #[derive(Debug)]
+    struct Tenant(u32);
+
+    struct User(String);
+
+    struct Delete(u32);
+
+    impl ScopedCxAwareRequestHandler<Tenant, User, Delete, AuditEvent>
+        for CxAwareMediator<Tenant, AuditEvent>
+    {
+        fn handle(&self, req: Delete, cx: &Tenant, scope: &User) {
+            self.publish(AuditEvent::Deleted {
+                id: req.0,
+                by: scope.0.clone(),
+                tenant: cx.0,
+            });
+        }
+    }
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+
+    let mediator = CxAwareMediator::<Tenant, AuditEvent>::builder()
+        .add_listener(move |ev: &AuditEvent| cloned.lock().unwrap().push(ev.clone()))
+        .add_context(Tenant(3))
+        .build()
+        .unwrap();
+
+    mediator.send_with_scope(Delete(1), User("alice".into()));
+    mediator.send_with_scope(Delete(2), User("bob".into()));
+    assert_eq!(mediator.next_all(), 2);
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![
+            AuditEvent::Deleted {
+                id: 1,
+                by: "alice".into(),
+                tenant: 3
+            },
+            AuditEvent::Deleted {
+                id: 2,
+                by: "bob".into(),
+                tenant: 3
+            },
+        ]
+    );
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn scoped_send_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum AuditEvent {
+        Deleted { id: u32, by: String, tenant: u32 },
+    }
+
+    #[derive(Debug)]
+    struct Tenant(u32);
+
+    struct User(String);
+
+    struct Delete(u32);
+
+    #[async_trait]
+    impl ScopedCxAwareAsyncRequestHandler<Tenant, User, Delete, AuditEvent>
+        for CxAwareAsyncMediator<Tenant, AuditEvent>
+    {
+        async fn handle(&self, req: Delete, cx: &Tenant, scope: &User) {
+            self.publish(AuditEvent::Deleted {
+                id: req.0,
+                by: scope.0.clone(),
+                tenant: cx.0,
+            })
+            .await;
+        }
+    }
+
+    async_std::task::block_on(async {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let cloned = seen.clone();
+
+        let mediator = CxAwareAsyncMediator::<Tenant, AuditEvent>::builder()
+            .add_listener(move |ev: &AuditEvent| cloned.lock().unwrap().push(ev.clone()))
+            .add_context(Tenant(3))
+            .build()
+            .unwrap();
+
+        mediator
+            .send_with_scope(Delete(1), User("alice".into()))
+            .await;
+        mediator
+            .new_handle()
+            .send_with_scope(Delete(2), User("bob".into()))
+            .await;
+        assert_eq!(mediator.next_all().await, 2);
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                AuditEvent::Deleted {
+                    id: 1,
+                    by: "alice".into(),
+                    tenant: 3
+                },
+                AuditEvent::Deleted {
+                    id: 2,
+                    by: "bob".into(),
+                    tenant: 3
+                },
+            ]
+        );
+    });
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn read_write_context_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use async_std::channel::{self, Receiver, Sender};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    #[derive(Debug, PartialEq)]
+    enum GateEvent {
+        Passed(u32),
+    }
+
+    #[derive(Debug)]
+    struct Gate {
+        open: Sender<()>,
+        opened: Receiver<()>,
+        passes: u32,
+    }
+
+    struct Wait;
+    struct Open;
+    struct Pass;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Gate, Wait, GateEvent> for CxAwareAsyncMediator<Gate, GateEvent> {
+        async fn handle(&self, _req: Wait, cx: &Gate) {
+            cx.opened.recv().await.unwrap();
+            self.publish(GateEvent::Passed(cx.passes)).await;
+        }
+    }
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Gate, Open, GateEvent> for CxAwareAsyncMediator<Gate, GateEvent> {
+        async fn handle(&self, _req: Open, cx: &Gate) {
+            cx.open.send(()).await.unwrap();
+        }
+    }
+
+    #[async_trait]
+    impl CxAwareAsyncMutRequestHandler<Gate, Pass, GateEvent>
+        for CxAwareAsyncMediator<Gate, GateEvent>
+    {
+        async fn handle(&self, _req: Pass, cx: &mut Gate) {
+            cx.passes += 1;
+        }
+    }
+
+    async_std::task::block_on(async {
+        let (open, opened) = channel::bounded(1);
+        let mediator = Arc::new(
+            CxAwareAsyncMediator::<Gate, GateEvent>::builder()
+                .add_context(Gate {
+                    open,
+                    opened,
+                    passes: 0,
+                })
+                .read_write_context()
+                .build()
+                .unwrap(),
+        );
+
+        mediator.send_mut(Pass).await;
+        mediator.new_handle().send_mut(Pass).await;
+
+        // The waiting request holds a read lock while the opening one is handled.
+        let cloned = mediator.clone();
+        let waiting = async_std::task::spawn(async move { cloned.send(Wait).await });
+        async_std::task::sleep(std::time::Duration::from_millis(20)).await;
+        mediator.send(Open).await;
+        waiting.await;
+
+        assert_eq!(mediator.next_all().await, 1);
+        assert_eq!(mediator.cx.read().await.passes, 2);
+    });
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn shared_context_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use async_std::channel::{self, Receiver, Sender};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    #[derive(Debug, PartialEq)]
+    enum GateEvent {
+        Passed(&'static str),
+    }
+
+    #[derive(Debug)]
+    struct Gate {
+        name: &'static str,
+        open: Sender<()>,
+        opened: Receiver<()>,
+    }
+
+    struct Wait;
+    struct Open;
+    struct Peek;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Gate, Wait, GateEvent> for CxAwareAsyncMediator<Gate, GateEvent> {
+        async fn handle(&self, _req: Wait, cx: &Gate) {
+            cx.opened.recv().await.unwrap();
+            self.publish(GateEvent::Passed(cx.name)).await;
+        }
+    }
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Gate, Open, GateEvent> for CxAwareAsyncMediator<Gate, GateEvent> {
+        async fn handle(&self, _req: Open, cx: &Gate) {
+            cx.open.send(()).await.unwrap();
+        }
+    }
+
+    async_std::task::block_on(async {
+        let (open, opened) = channel::bounded(1);
+        let gate = Arc::new(Gate {
+            name: "north",
+            open,
+            opened,
+        });
+
+        let mediator = Arc::new(
+            CxAwareAsyncMediator::<Gate, GateEvent>::builder()
+                .add_shared_context(gate.clone())
+                .register_handler(|m, _req: Peek, cx| async move {
+                    m.publish(GateEvent::Passed(cx.read().await.name)).await;
+                })
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(Arc::strong_count(&gate), 2);
+
+        // The context isn't locked while the waiting request is handled.
+        let cloned = mediator.clone();
+        let waiting = async_std::task::spawn(async move { cloned.send(Wait).await });
+        async_std::task::sleep(std::time::Duration::from_millis(20)).await;
+        mediator.send(Open).await;
+        waiting.await;
+
+        mediator.send_dyn(Box::new(Peek)).await.ok();
+        assert_eq!(mediator.next_all().await, 2);
+        assert_eq!(mediator.cx.read().await.name, "north");
+    });
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn listener_matching_test_sync() {
+    use crate::add_listener_matching;
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum DoorEvent {
+        Opened,
+        Closed,
+        Knocked(u32),
+    }
+
+    impl From<&DoorEvent> for &'static str {
+        fn from(ev: &DoorEvent) -> Self {
+            match ev {
+                DoorEvent::Opened => "Opened",
+                DoorEvent::Closed => "Closed",
+                DoorEvent::Knocked(_) => "Knocked",
+            }
+        }
+    }
+
+    let matched = Arc::new(Mutex::new(Vec::new()));
+    let by_variant = Arc::new(Mutex::new(Vec::new()));
+    let cloned_matched = matched.clone();
+    let cloned_by_variant = by_variant.clone();
+
+    let builder = BasicMediator::<DoorEvent>::builder();
+    let mediator = add_listener_matching!(
+        builder,
+        DoorEvent::Opened | DoorEvent::Knocked(2..),
+        move |ev: &DoorEvent| cloned_matched.lock().unwrap().push(ev.clone()),
+    )
+    .add_listener_for_variants(["Closed", "Knocked"], move |ev: &DoorEvent| {
+        cloned_by_variant.lock().unwrap().push(ev.clone())
+    })
+    .build();
+
+    mediator.publish(DoorEvent::Opened);
+    mediator.publish(DoorEvent::Knocked(1));
+    mediator.publish(DoorEvent::Knocked(3));
+    mediator.publish(DoorEvent::Closed);
+    assert_eq!(mediator.next_all(), 4);
+
+    assert_eq!(
+        *matched.lock().unwrap(),
+        vec![DoorEvent::Opened, DoorEvent::Knocked(3)]
+    );
+    assert_eq!(
+        *by_variant.lock().unwrap(),
+        vec![
+            DoorEvent::Knocked(1),
+            DoorEvent::Knocked(3),
+            DoorEvent::Closed
+        ]
+    );
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn listener_matching_test_async() {
+    use crate::add_listener_matching;
+    use crate::asynchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum DoorEvent {
+        Opened,
+        Closed,
+    }
+
+    impl From<&DoorEvent> for &'static str {
+        fn from(ev: &DoorEvent) -> Self {
+            match ev {
+                DoorEvent::Opened => "Opened",
+                DoorEvent::Closed => "Closed",
+            }
+        }
+    }
+
+    async_std::task::block_on(async {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let cloned_matched = seen.clone();
+        let cloned_by_variant = seen.clone();
+
+        let builder = CxAwareAsyncMediator::<(), DoorEvent>::builder().add_context(());
+        let mediator = add_listener_matching!(builder, DoorEvent::Opened, move |ev: &DoorEvent| {
+            cloned_matched.lock().unwrap().push(ev.clone())
+        })
+        .add_listener_for_variants(["Closed"], move |ev: &DoorEvent| {
+            cloned_by_variant.lock().unwrap().push(ev.clone())
+        })
+        .build()
+        .unwrap();
+
+        mediator.publish(DoorEvent::Closed).await;
+        mediator.publish(DoorEvent::Opened).await;
+        assert_eq!(mediator.next_all().await, 2);
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![DoorEvent::Closed, DoorEvent::Opened]
+        );
+    });
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn concurrent_publish_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    };
+    use std::time::Duration;
+
+    #[derive(Debug, PartialEq)]
+    enum SlowEvent {
+        Slow,
+        Fast,
+    }
+
+    let (tx, rx) = mpsc::channel::<()>();
+    let rx = Mutex::new(rx);
+    let unblocked = Arc::new(AtomicBool::new(false));
+    let cloned = unblocked.clone();
+
+    let mediator = Arc::new(
+        BasicAsyncMediator::<SlowEvent>::builder()
+            .add_listener(move |ev: &SlowEvent| {
+                if *ev == SlowEvent::Slow {
+                    // Only returns early if publishing isn't blocked by this listener.
+                    let res = rx.lock().unwrap().recv_timeout(Duration::from_secs(5));
+                    cloned.store(res.is_ok(), Ordering::SeqCst);
+                }
+            })
+            .build(),
+    );
+
+    async_std::task::block_on(async {
+        mediator.publish(SlowEvent::Slow).await;
+
+        let cloned = mediator.clone();
+        let processing = std::thread::spawn(move || {
+            async_std::task::block_on(async { cloned.next().await.ok() })
+        });
+        std::thread::sleep(Duration::from_millis(50));
+
+        mediator.publish(SlowEvent::Fast).await;
+        let handle = mediator
+            .subscribe(|_: &SlowEvent| { /* subscribed while a listener runs */ })
+            .await;
+        tx.send(()).unwrap();
+        processing.join().unwrap();
+
+        assert!(unblocked.load(Ordering::SeqCst));
+        assert!(mediator.unsubscribe(handle).await);
+        assert_eq!(mediator.next_all().await, 1);
+    });
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn send_sync_test_sync() {
+    use crate::synchronous::basic::*;
+    use crate::synchronous::contextaware::*;
+
+    use std::fmt::Debug;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    // Holds for every event and context satisfying the bounds, not just the ones below.
+    #[allow(dead_code)]
+    fn assert_mediators<Ev, Cx>()
+    where
+        Ev: Debug + Send + 'static,
+        Cx: Debug + Send + Sync + 'static,
+    {
+        assert_send_sync::<BasicMediator<Ev>>();
+        assert_send_sync::<CxAwareMediator<Cx, Ev>>();
+    }
+
+    #[derive(Debug)]
+    struct Ping;
+
+    #[derive(Debug, Default)]
+    struct Pings(AtomicUsize);
+
+    impl CxAwareRequestHandler<Pings, Ping, Ping> for CxAwareMediator<Pings, Ping> {
+        fn handle(&self, req: Ping, cx: &Pings) {
+            cx.0.fetch_add(1, Ordering::SeqCst);
+            self.publish(req);
+        }
+    }
+
+    let mediator = CxAwareMediator::<Pings, Ping>::builder()
+        .add_context(Pings::default())
+        .build()
+        .unwrap();
+
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..8 {
+                    mediator.send(Ping);
+                }
+            });
+        }
+    });
+
+    assert_eq!(mediator.cx.0.load(Ordering::SeqCst), 32);
+    assert_eq!(mediator.next_all(), 32);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn send_sync_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::asynchronous::contextaware::*;
+
+    use async_trait::async_trait;
+    use std::fmt::Debug;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    // Holds for every event and context satisfying the bounds, not just the ones below.
+    #[allow(dead_code)]
+    fn assert_mediators<Ev, Cx>()
+    where
+        Ev: Debug + Send + 'static,
+        Cx: Debug + Send + Sync + 'static,
+    {
+        assert_send_sync::<BasicAsyncMediator<Ev>>();
+        assert_send_sync::<MediatorHandle<Ev>>();
+        assert_send_sync::<CxAwareAsyncMediator<Cx, Ev>>();
+        assert_send_sync::<CxAwareMediatorHandle<Cx, Ev>>();
+        assert_send_sync::<LazyContext<Cx>>();
+    }
+
+    #[derive(Debug)]
+    struct Ping;
+
+    #[derive(Debug, Default)]
+    struct Pings(AtomicUsize);
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Pings, Ping, Ping> for CxAwareAsyncMediator<Pings, Ping> {
+        async fn handle(&self, req: Ping, cx: &Pings) {
+            cx.0.fetch_add(1, Ordering::SeqCst);
+            self.publish(req).await;
+        }
+    }
+
+    async_std::task::block_on(async {
+        let mediator = Arc::new(
+            CxAwareAsyncMediator::<Pings, Ping>::builder()
+                .add_context(Pings::default())
+                .read_write_context()
+                .build()
+                .unwrap(),
+        );
+
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let mediator = mediator.clone();
+                async_std::task::spawn(async move {
+                    for _ in 0..8 {
+                        mediator.send(Ping).await;
+                    }
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await;
+        }
+
+        assert_eq!(mediator.cx.read().await.0.load(Ordering::SeqCst), 32);
+        assert_eq!(mediator.next_all().await, 32);
+    });
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn shared_listener_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug, PartialEq)]
+    struct Payload(u32);
+
+    impl Clone for Payload {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::SeqCst);
+            Payload(self.0)
+        }
+    }
+
+    let borrowed = Arc::new(Mutex::new(Vec::new()));
+    let kept = Arc::new(Mutex::new(Vec::new()));
+    let cloned_borrowed = borrowed.clone();
+    let cloned_kept = kept.clone();
+
+    let mediator = BasicMediator::<Payload>::builder()
+        .add_listener(move |ev: &Payload| cloned_borrowed.lock().unwrap().push(ev.0))
+        .add_shared_listener(move |ev: Arc<Payload>| {
+            if ev.0 > 1 {
+                cloned_kept.lock().unwrap().push(ev);
+            }
+        })
+        .build();
+
+    mediator.publish(Payload(1));
+    mediator.next().unwrap();
+    assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+
+    mediator.publish(Payload(2));
+    mediator.next().unwrap();
+    assert_eq!(CLONES.load(Ordering::SeqCst), 1);
+
+    assert_eq!(*borrowed.lock().unwrap(), vec![1, 2]);
+    assert_eq!(*kept.lock().unwrap(), vec![Arc::new(Payload(2))]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn shared_listener_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug, PartialEq)]
+    struct Payload(u32);
+
+    impl Clone for Payload {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::SeqCst);
+            Payload(self.0)
+        }
+    }
+
+    async_std::task::block_on(async {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let cloned_sync = seen.clone();
+        let cloned_async = seen.clone();
+
+        let mediator = BasicAsyncMediator::<Payload>::builder()
+            .add_shared_listener(move |ev: Arc<Payload>| {
+                cloned_sync.lock().unwrap().push(("sync", ev.0));
+            })
+            .add_async_shared_listener(move |ev: Arc<Payload>| {
+                let seen = cloned_async.clone();
+                async move {
+                    async_std::task::yield_now().await;
+                    seen.lock().unwrap().push(("async", ev.0));
+                }
+            })
+            .build();
+
+        mediator.publish(Payload(1)).await;
+        mediator.publish(Payload(2)).await;
+        assert_eq!(mediator.next_all().await, 2);
+
+        assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![("sync", 1), ("async", 1), ("sync", 2), ("async", 2)]
+        );
+    });
+}
+
+#[cfg(not(any(feature = "async", feature = "tracing")))]
+#[test]
+fn non_debug_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+
+    struct Pool {
+        connections: Mutex<Vec<u32>>,
+    }
+
+    struct Checkout;
+
+    struct Leased(u32);
+
+    impl CxAwareRequestHandler<Pool, Checkout, Leased> for CxAwareMediator<Pool, Leased> {
+        fn handle(&self, _req: Checkout, cx: &Pool) {
+            if let Some(conn) = cx.connections.lock().unwrap().pop() {
+                self.publish(Leased(conn));
+            }
+        }
+    }
+
+    let leased = Arc::new(Mutex::new(Vec::new()));
+    let cloned = leased.clone();
+
+    let mediator = CxAwareMediator::<Pool, Leased>::builder()
+        .add_context(Pool {
+            connections: Mutex::new(vec![1, 2]),
+        })
+        .add_listener(move |ev: &Leased| cloned.lock().unwrap().push(ev.0))
+        .build()
+        .unwrap();
+
+    mediator.send(Checkout);
+    mediator.send(Checkout);
+    mediator.send(Checkout);
+    assert_eq!(mediator.next_all(), 2);
+
+    assert_eq!(*leased.lock().unwrap(), vec![2, 1]);
+}
+
+#[cfg(all(feature = "async", not(feature = "tracing")))]
+#[test]
+fn non_debug_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+
+    struct Pool {
+        connections: Mutex<Vec<u32>>,
+    }
+
+    struct Checkout;
+
+    struct Leased(u32);
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Pool, Checkout, Leased> for CxAwareAsyncMediator<Pool, Leased> {
+        async fn handle(&self, _req: Checkout, cx: &Pool) {
+            let conn = cx.connections.lock().unwrap().pop();
+            if let Some(conn) = conn {
+                self.publish(Leased(conn)).await;
+            }
+        }
+    }
+
+    async_std::task::block_on(async {
+        let leased = Arc::new(Mutex::new(Vec::new()));
+        let cloned = leased.clone();
+
+        let mediator = CxAwareAsyncMediator::<Pool, Leased>::builder()
+            .add_context(Pool {
+                connections: Mutex::new(vec![1, 2]),
+            })
+            .add_listener(move |ev: &Leased| cloned.lock().unwrap().push(ev.0))
+            .build()
+            .unwrap();
+
+        mediator.send(Checkout).await;
+        mediator.send(Checkout).await;
+        mediator.send(Checkout).await;
+        assert_eq!(mediator.next_all().await, 2);
+
+        assert_eq!(*leased.lock().unwrap(), vec![2, 1]);
+    });
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn request_hooks_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    #[derive(Debug)]
+    enum MyEvent {
+        Pinged,
+    }
+
+    struct Ping;
+
+    impl RequestHandler<Ping, MyEvent> for BasicMediator<MyEvent> {
+        fn handle(&self, _req: Ping) {
+            std::thread::sleep(Duration::from_millis(5));
+            self.publish(MyEvent::Pinged);
+        }
+    }
+
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let before = records.clone();
+    let after = records.clone();
+
+    let mediator = BasicMediator::<MyEvent>::builder()
+        .on_before_send(move |meta: &RequestMeta| {
+            before.lock().unwrap().push((
+                "before",
+                meta.op(),
+                meta.correlation_id(),
+                Duration::ZERO,
+            ));
+        })
+        .on_after_send(move |meta: &RequestMeta, elapsed: Duration| {
+            assert_eq!(meta.request(), std::any::type_name::<Ping>());
+            after
+                .lock()
+                .unwrap()
+                .push(("after", meta.op(), meta.correlation_id(), elapsed));
+        })
+        .build();
+
+    mediator.send(Ping);
+    let id = mediator.send_traced(Ping);
+    assert_eq!(mediator.next_all(), 2);
+
+    let records = records.lock().unwrap();
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0], ("before", "send", None, Duration::ZERO));
+    assert_eq!(records[1].0, "after");
+    assert!(records[1].3 >= Duration::from_millis(5));
+    assert_eq!(records[2].2, Some(id));
+    assert_eq!(records[3].2, Some(id));
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn request_hooks_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::contextaware::*;
+
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    #[derive(Debug)]
+    enum MyEvent {
+        Pinged,
+    }
+
+    struct Ping;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<(), Ping, MyEvent> for CxAwareAsyncMediator<(), MyEvent> {
+        async fn handle(&self, _req: Ping, _cx: &()) {
+            async_std::task::sleep(Duration::from_millis(5)).await;
+            self.publish(MyEvent::Pinged).await;
+        }
+    }
+
+    async_std::task::block_on(async {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let before = records.clone();
+        let after = records.clone();
+
+        let mediator = CxAwareAsyncMediator::<(), MyEvent>::builder()
+            .add_context(())
+            .on_before_send(move |meta: &RequestMeta| {
+                before.lock().unwrap().push((meta.op(), None));
+            })
+            .on_after_send(move |meta: &RequestMeta, elapsed: Duration| {
+                after.lock().unwrap().push((meta.op(), Some(elapsed)));
+            })
+            .build()
+            .unwrap();
+
+        mediator.send(Ping).await;
+        assert_eq!(mediator.next_all().await, 1);
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0], ("send", None));
+        assert_eq!(records[1].0, "send");
+        assert!(records[1].1.unwrap() >= Duration::from_millis(5));
+    });
+}
+
+#[cfg(all(feature = "tracing", feature = "async"))]
+#[test]
+fn logging_listener_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::logging::LoggingListener;
+
+    use std::{
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Event, Level, Metadata, Subscriber,
+    };
+
+    /// Records every event logged by a [`LoggingListener`] as `level field=value ...`.
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    struct Fields(String);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0 += &format!(" {}={:?}", field.name(), value);
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target() == "mediatrix::events"
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields(event.metadata().level().to_string());
+            event.record(&mut fields);
+            self.0.lock().unwrap().push(fields.0);
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[derive(Debug)]
+    enum MyEvent {
+        Created(u32),
+    }
+
+    let records = Arc::new(Mutex::new(vec![]));
+    let subscriber = Recorder(records.clone());
+
+    tracing::subscriber::with_default(subscriber, || {
+        async_std::task::block_on(async {
+            let mediator = BasicAsyncMediator::<MyEvent>::builder()
+                .add_logging_listener(Level::DEBUG)
+                .add_logging_listener(LoggingListener::new(Level::WARN).with_formatter(
+                    |ev: &MyEvent| match ev {
+                        MyEvent::Created(id) => format!("created #{id}"),
+                    },
+                ))
+                .build();
+
+            mediator.publish(MyEvent::Created(7)).await;
+            mediator.next().await.unwrap();
+        })
+    });
+
+    let records = records.lock().unwrap();
+    assert_eq!(records.len(), 2);
+    assert!(records[0].starts_with("DEBUG message=event event=\"Created(7)\" sequence="));
+    assert!(records[1].starts_with("WARN message=event event=\"created #7\" sequence="));
+}
+
+#[cfg(all(feature = "persistence", feature = "async"))]
+#[test]
+fn serde_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::builder::BuildError;
+
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct JobEvent(u32);
+
+    struct Job;
+
+    #[async_trait::async_trait]
+    impl AsyncRequestHandler<Job, JobEvent> for BasicAsyncMediator<JobEvent> {
+        async fn handle(&self, _req: Job) {
+            self.publish_to("jobs", JobEvent(1)).await;
+        }
+    }
+
+    async_std::task::block_on(async {
+        let mediator = BasicAsyncMediator::<JobEvent>::builder()
+            .add_listener(|ev: &JobEvent| assert_ne!(ev.0, 1))
+            .isolate_listeners(true)
+            .collect_dead_letters(true)
+            .build();
+
+        let id = mediator.send_traced(Job).await;
+        assert!(mediator.next().await.is_err());
+
+        let letter = mediator.dead_letters().await.next().unwrap();
+        let json = serde_json::to_string(&letter).unwrap();
+        assert!(json.contains(&format!("\"correlation\":{}", id.get())));
+
+        let restored: DeadLetter<JobEvent> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.event(), &JobEvent(1));
+        assert_eq!(restored.topic(), Some("jobs"));
+        assert_eq!(restored.reason(), letter.reason());
+    });
+
+    let id: CorrelationId = serde_json::from_str("42").unwrap();
+    assert_eq!(id.get(), 42);
+
+    let err = BuildError::DuplicateTopicSubscription("jobs".into());
+    let json = serde_json::to_string(&err).unwrap();
+    assert_eq!(serde_json::from_str::<BuildError>(&json).unwrap(), err);
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn hierarchy_test_sync() {
+    use crate::bridge::ParentMediator;
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    enum StockEvent {
+        Reserved(u32),
+        Checked,
+    }
+
+    #[derive(Debug)]
+    enum OrderEvent {
+        Stock(u32),
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum AppEvent {
+        Order(u32),
+    }
+
+    let seen = Arc::new(Mutex::new(vec![]));
+    let cloned = seen.clone();
+
+    let app = Arc::new(
+        BasicMediator::<AppEvent>::builder()
+            .add_listener(move |ev: &AppEvent| cloned.lock().unwrap().push(ev.clone()))
+            .build(),
+    );
+    let orders = Arc::new(BasicMediator::<OrderEvent>::builder().build());
+    let stock = BasicMediator::<StockEvent>::builder().build();
+
+    let handle = app.attach_child(&*orders, |ev: &OrderEvent| match ev {
+        OrderEvent::Stock(id) => Some(AppEvent::Order(*id)),
+    });
+    orders.attach_child(&stock, |ev: &StockEvent| match ev {
+        StockEvent::Reserved(id) => Some(OrderEvent::Stock(*id)),
+        StockEvent::Checked => None,
+    });
+
+    stock.publish(StockEvent::Reserved(1));
+    stock.publish(StockEvent::Checked);
+    assert_eq!(stock.next_all(), 2);
+    assert_eq!(orders.next_all(), 1);
+    assert_eq!(app.next_all(), 1);
+    assert_eq!(*seen.lock().unwrap(), vec![AppEvent::Order(1)]);
+
+    assert!(orders.unsubscribe(handle));
+    stock.publish(StockEvent::Reserved(3));
+    assert_eq!(stock.next_all(), 1);
+    assert_eq!(orders.next_all(), 1);
+    assert_eq!(app.next_all(), 0);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn testbed_test_async() {
+    use async_trait::async_trait;
+
+    use crate::asynchronous::contextaware::*;
+    use crate::testing::CxAwareTestBed;
+
+    use std::sync::Mutex;
+
+    #[derive(Debug, PartialEq)]
+    enum MyEvent {
+        Charged(u32),
+        Declined,
+    }
+
+    trait Payments {
+        fn charge(&self, amount: u32) -> bool;
+    }
+
+    #[derive(Default)]
+    struct MockPayments {
+        charged: Mutex<Vec<u32>>,
+    }
+
+    impl Payments for MockPayments {
+        fn charge(&self, amount: u32) -> bool {
+            self.charged.lock().unwrap().push(amount);
+            amount <= 100
+        }
+    }
+
+    struct Charge(u32);
+
+    #[async_trait]
+    impl<Cx: Payments + Send + Sync> CxAwareAsyncRequestHandler<Cx, Charge, MyEvent>
+        for CxAwareAsyncMediator<Cx, MyEvent>
+    {
+        async fn handle(&self, req: Charge, cx: &Cx) {
+            match cx.charge(req.0) {
+                true => self.publish(MyEvent::Charged(req.0)).await,
+                false => self.publish(MyEvent::Declined).await,
+            }
+        }
+    }
+
+    async_std::task::block_on(async {
+        let bed = CxAwareTestBed::<MockPayments, MyEvent>::new(MockPayments::default());
+
+        assert_eq!(
+            bed.send(Charge(50)).await.published_events(),
+            vec![MyEvent::Charged(50)]
+        );
+        bed.send(Charge(10)).await.send(Charge(500)).await;
+        assert_eq!(
+            bed.published_events(),
+            vec![MyEvent::Charged(10), MyEvent::Declined]
+        );
+        assert!(bed.published_events().is_empty());
+        assert_eq!(
+            *bed.context().await.charged.lock().unwrap(),
+            vec![50, 10, 500]
+        );
+    });
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn ordering_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum MyEvent {
+        Slow(u32),
+        Fast(u32),
+    }
+
+    fn mediator(ordering: Ordering, seen: Arc<Mutex<Vec<MyEvent>>>) -> BasicAsyncMediator<MyEvent> {
+        BasicAsyncMediator::<MyEvent>::builder()
+            .add_async_listener(move |ev: &MyEvent| {
+                let (seen, ev) = (seen.clone(), *ev);
+                async move {
+                    if let MyEvent::Slow(_) = ev {
+                        async_std::task::sleep(Duration::from_millis(20)).await;
+                    }
+                    seen.lock().unwrap().push(ev);
+                }
+            })
+            .with_concurrency(4)
+            .with_ordering(ordering)
+            .build()
+    }
+
+    async_std::task::block_on(async {
+        let seen = Arc::new(Mutex::new(vec![]));
+        let strict = mediator(Ordering::StrictFifo, seen.clone());
+        strict.publish_to("a", MyEvent::Slow(1)).await;
+        strict.publish_to("b", MyEvent::Fast(2)).await;
+        strict.publish(MyEvent::Fast(3)).await;
+        assert_eq!(strict.next_all().await, 3);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![MyEvent::Slow(1), MyEvent::Fast(2), MyEvent::Fast(3)]
+        );
+
+        let seen = Arc::new(Mutex::new(vec![]));
+        let per_topic = mediator(Ordering::PerTopicFifo, seen.clone());
+        per_topic.publish_to("a", MyEvent::Slow(1)).await;
+        per_topic.publish_to("a", MyEvent::Fast(2)).await;
+        per_topic.publish_to("b", MyEvent::Fast(3)).await;
+        assert_eq!(per_topic.next_all().await, 3);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![MyEvent::Fast(3), MyEvent::Slow(1), MyEvent::Fast(2)]
+        );
+
+        let seen = Arc::new(Mutex::new(vec![]));
+        let unordered = mediator(Ordering::Unordered, seen.clone());
+        unordered.publish_to("a", MyEvent::Slow(1)).await;
+        unordered.publish_to("a", MyEvent::Fast(2)).await;
+        assert_eq!(unordered.next_all().await, 2);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![MyEvent::Fast(2), MyEvent::Slow(1)]
+        );
+    });
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn poison_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyEvent {
+        Valid(u32),
+        Invalid,
+    }
+
+    let mediator = CxAwareMediator::<(), MyEvent>::builder()
+        .add_listener(|ev: &MyEvent| {
+            if let MyEvent::Invalid = ev {
+                panic!("invalid event");
+            }
+        })
+        .with_replay_capacity(4)
+        .add_context(())
+        .build()
+        .unwrap();
+    assert!(!mediator.is_poisoned());
+
+    mediator.publish(MyEvent::Valid(1));
+    mediator.publish(MyEvent::Invalid);
+    mediator.publish(MyEvent::Valid(2));
+    assert!(mediator.next().is_ok());
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| mediator.next())).is_err());
+    assert!(mediator.is_poisoned());
+
+    // The poisoned mediator keeps working.
+    assert!(mediator.next().is_ok());
+    let replayed = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+    let cloned = replayed.clone();
+    mediator.add_listener_with_replay(move |ev: &MyEvent| cloned.lock().unwrap().push(ev.clone()));
+    assert_eq!(
+        *replayed.lock().unwrap(),
+        vec![MyEvent::Valid(1), MyEvent::Valid(2)]
+    );
+
+    mediator.heal();
+    assert!(!mediator.is_poisoned());
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn closure_handler_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    #[derive(Debug, PartialEq)]
+    enum MyEvent {
+        Deposited(u32),
+    }
+
+    struct Deposit(u32);
+    struct Balance;
+
+    let mediator = CxAwareMediator::<u32, MyEvent>::builder()
+        .add_request_handler(|m: &CxAwareMediator<u32, MyEvent>, req: Deposit, _: &u32| {
+            m.publish(MyEvent::Deposited(req.0));
+        })
+        .add_request_handler(|_: &CxAwareMediator<u32, MyEvent>, _: Balance, cx: &u32| *cx)
+        .add_context(100)
+        .build()
+        .unwrap();
+
+    assert!(mediator.send_registered(Deposit(5)).is_ok());
+    assert_eq!(
+        mediator.send_registered_and_receive::<_, u32>(Balance).ok(),
+        Some(100)
+    );
+    assert!(mediator.send_registered(Balance).is_err());
+    assert!(mediator
+        .send_registered_and_receive::<_, String>(Balance)
+        .is_err());
+    assert_eq!(mediator.next_all(), 1);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn closure_handler_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+
+    #[derive(Debug, PartialEq)]
+    enum MyEvent {
+        Deposited(u32),
+    }
+
+    struct Deposit(u32);
+    struct Balance;
+
+    async_std::task::block_on(async {
+        let mediator = CxAwareAsyncMediator::<AtomicU32, MyEvent>::builder()
+            .add_request_handler(
+                |m: CxAwareMediatorHandle<AtomicU32, MyEvent>,
+                 req: Deposit,
+                 cx: Arc<LazyContext<AtomicU32>>| async move {
+                    cx.read().await.fetch_add(req.0, Ordering::SeqCst);
+                    m.publish(MyEvent::Deposited(req.0)).await;
+                },
+            )
+            .add_request_handler(
+                |_, _: Balance, cx: Arc<LazyContext<AtomicU32>>| async move {
+                    cx.read().await.load(Ordering::SeqCst)
+                },
+            )
+            .add_context(AtomicU32::new(100))
+            .build()
+            .unwrap();
+
+        assert!(mediator.send_registered(Deposit(5)).await.is_ok());
+        assert_eq!(
+            mediator
+                .send_registered_and_receive::<_, u32>(Balance)
+                .await
+                .ok(),
+            Some(105)
+        );
+        assert!(mediator.send_registered(Balance).await.is_err());
+        assert_eq!(mediator.next_all().await, 1);
+    });
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn fan_out_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, PartialEq)]
+    enum MyEvent {
+        Validated(u32),
+        Ordered(u32),
+    }
+
+    struct Order(u32);
+
+    let audit = Arc::new(Mutex::new(vec![]));
+    let cloned = audit.clone();
+
+    let mediator = CxAwareMediator::<u32, MyEvent>::builder()
+        .add_fan_out_handler(
+            |m: &CxAwareMediator<u32, MyEvent>, req: &Order, limit: &u32| {
+                if req.0 <= *limit {
+                    m.publish(MyEvent::Validated(req.0));
+                }
+            },
+        )
+        .add_fan_out_handler(|m: &CxAwareMediator<u32, MyEvent>, req: &Order, _: &u32| {
+            m.publish(MyEvent::Ordered(req.0));
+        })
+        .add_fan_out_handler(
+            move |_: &CxAwareMediator<u32, MyEvent>, req: &Order, _: &u32| {
+                cloned.lock().unwrap().push(req.0);
+            },
+        )
+        .add_context(10)
+        .build()
+        .unwrap();
+
+    assert_eq!(mediator.send_fan_out(Order(5)), 3);
+    assert_eq!(mediator.send_fan_out(Order(50)), 3);
+    assert_eq!(mediator.send_fan_out(5u32), 0);
+    assert!(mediator.send_registered(Order(5)).is_err());
+    assert_eq!(*audit.lock().unwrap(), vec![5, 50]);
+    assert_eq!(mediator.next_all(), 3);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn fan_out_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Debug, PartialEq)]
+    enum MyEvent {
+        Handled(&'static str),
+    }
+
+    struct Order;
+
+    async_std::task::block_on(async {
+        let seen = Arc::new(Mutex::new(vec![]));
+        let (slow, fast) = (seen.clone(), seen.clone());
+
+        let mediator = CxAwareAsyncMediator::<u32, MyEvent>::builder()
+            .add_fan_out_handler(move |m, _: Arc<Order>, _| {
+                let seen = slow.clone();
+                async move {
+                    async_std::task::sleep(Duration::from_millis(20)).await;
+                    seen.lock().unwrap().push("slow");
+                    m.publish(MyEvent::Handled("slow")).await;
+                }
+            })
+            .add_fan_out_handler(move |m, _: Arc<Order>, cx: Arc<LazyContext<u32>>| {
+                let seen = fast.clone();
+                async move {
+                    assert_eq!(*cx.read().await, 7);
+                    seen.lock().unwrap().push("fast");
+                    m.publish(MyEvent::Handled("fast")).await;
+                }
+            })
+            .add_context(7)
+            .with_concurrency(2)
+            .build()
+            .unwrap();
+
+        assert_eq!(mediator.send_fan_out(Order).await, 2);
+        assert_eq!(mediator.send_fan_out(1u8).await, 0);
+        assert_eq!(*seen.lock().unwrap(), vec!["fast", "slow"]);
+        assert_eq!(mediator.next_all().await, 2);
+    });
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn send_detached_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use async_trait::async_trait;
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+    use std::time::Duration;
+
+    #[derive(Debug, PartialEq)]
+    enum MyEvent {
+        Mailed(u32),
+    }
+
+    struct SendMail(u32);
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Arc<AtomicU32>, SendMail, MyEvent>
+        for CxAwareAsyncMediator<Arc<AtomicU32>, MyEvent>
+    {
+        async fn handle(&self, req: SendMail, cx: &Arc<AtomicU32>) {
+            async_std::task::sleep(Duration::from_millis(20)).await;
+            cx.fetch_add(1, Ordering::SeqCst);
+            self.publish(MyEvent::Mailed(req.0)).await;
+        }
+    }
+
+    async_std::task::block_on(async {
+        let sent = Arc::new(AtomicU32::new(0));
+        let mediator = CxAwareAsyncMediator::<Arc<AtomicU32>, MyEvent>::builder()
+            .add_context(sent.clone())
+            .build()
+            .unwrap();
+
+        let first = mediator.send_detached(SendMail(1));
+        drop(mediator.send_detached(SendMail(2)));
+        assert_eq!(sent.load(Ordering::SeqCst), 0);
+
+        first.await;
+        while sent.load(Ordering::SeqCst) < 2 {
+            async_std::task::yield_now().await;
+        }
+        assert_eq!(mediator.next_all().await, 2);
+    });
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn rate_limit_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    #[derive(Debug, PartialEq)]
+    struct Tick(u32);
+
+    let dropped = BasicMediator::<Tick>::builder()
+        .with_rate_limit(RateLimit::per_second(2).with_policy(ThrottlePolicy::Drop))
+        .collect_dead_letters(true)
+        .build();
+    for i in 0..5 {
+        dropped.publish(Tick(i));
+    }
+    assert_eq!(dropped.next_all(), 2);
+    let letters: Vec<_> = dropped.dead_letters().collect();
+    assert_eq!(letters.len(), 3);
+    assert_eq!(letters[0].event(), &Tick(2));
+    assert_eq!(letters[0].reason(), &DeadLetterReason::RateLimited);
+
+    let queued = BasicMediator::<Tick>::builder()
+        .with_rate_limit(RateLimit::new(2, Duration::from_millis(50)))
+        .build();
+    for i in 0..3 {
+        queued.publish(Tick(i));
+    }
+    let start = Instant::now();
+    assert_eq!(queued.next_all(), 3);
+    assert!(start.elapsed() >= Duration::from_millis(50));
+
+    let seen = Arc::new(Mutex::new(vec![]));
+    let cloned = seen.clone();
+    let coalesced = BasicMediator::<Tick>::builder()
+        .add_listener(move |ev: &Tick| cloned.lock().unwrap().push(ev.0))
+        .with_rate_limit(
+            RateLimit::new(1, Duration::from_millis(30)).with_policy(ThrottlePolicy::Coalesce),
+        )
+        .collect_dead_letters(true)
+        .build();
+    for i in 0..4 {
+        coalesced.publish(Tick(i));
+    }
+    assert_eq!(coalesced.next_all(), 2);
+    assert_eq!(*seen.lock().unwrap(), vec![0, 3]);
+    assert_eq!(coalesced.dead_letters().len(), 2);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn rate_limit_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    #[derive(Debug, PartialEq)]
+    struct Tick(u32);
+
+    async_std::task::block_on(async {
+        let seen = Arc::new(Mutex::new(vec![]));
+        let cloned = seen.clone();
+        let mediator = BasicAsyncMediator::<Tick>::builder()
+            .add_async_listener(move |ev: &Tick| {
+                let (seen, tick) = (cloned.clone(), ev.0);
+                async move { seen.lock().unwrap().push(tick) }
+            })
+            .with_rate_limit(RateLimit::new(2, Duration::from_millis(40)))
+            .build();
+
+        for i in 0..4 {
+            mediator.publish(Tick(i)).await;
+        }
+        let start = Instant::now();
+        assert_eq!(mediator.next_all().await, 4);
+        assert!(start.elapsed() >= Duration::from_millis(40));
+        assert_eq!(*seen.lock().unwrap(), vec![0, 1, 2, 3]);
+
+        let mediator = BasicAsyncMediator::<Tick>::builder()
+            .with_rate_limit(
+                RateLimit::new(1, Duration::from_millis(30)).with_policy(ThrottlePolicy::Coalesce),
+            )
+            .collect_dead_letters(true)
+            .build();
+
+        for i in 0..3 {
+            mediator.publish(Tick(i)).await;
+        }
+        assert_eq!(mediator.next_all().await, 2);
+        let letters: Vec<_> = mediator.dead_letters().await.collect();
+        assert_eq!(letters.len(), 1);
+        assert_eq!(letters[0].event(), &Tick(1));
+        assert_eq!(letters[0].reason(), &DeadLetterReason::RateLimited);
+    });
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn debounce_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum MyEvent {
+        Invalidated(&'static str, u32),
+    }
+
+    async_std::task::block_on(async {
+        let seen = Arc::new(Mutex::new(vec![]));
+        let cloned = seen.clone();
+
+        let mediator = CxAwareAsyncMediator::<(), MyEvent>::builder()
+            .add_debounced_listener(
+                Duration::from_millis(30),
+                |MyEvent::Invalidated(key, _): &MyEvent| *key,
+                move |ev: &MyEvent| {
+                    let (seen, ev) = (cloned.clone(), ev.clone());
+                    async move { seen.lock().unwrap().push(ev) }
+                },
+            )
+            .add_context(())
+            .build()
+            .unwrap();
+
+        for version in 0..3 {
+            mediator.publish(MyEvent::Invalidated("a", version)).await;
+            mediator.publish(MyEvent::Invalidated("b", version)).await;
+        }
+        assert_eq!(mediator.next_all().await, 6);
+        assert!(seen.lock().unwrap().is_empty());
+
+        async_std::task::sleep(Duration::from_millis(150)).await;
+        let mut delivered = seen.lock().unwrap().clone();
+        delivered.sort_by_key(|MyEvent::Invalidated(key, _)| *key);
+        assert_eq!(
+            delivered,
+            vec![MyEvent::Invalidated("a", 2), MyEvent::Invalidated("b", 2)]
+        );
+
+        mediator.publish(MyEvent::Invalidated("a", 3)).await;
+        mediator.next_all().await;
+        async_std::task::sleep(Duration::from_millis(150)).await;
+        assert_eq!(seen.lock().unwrap().len(), 3);
+    });
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn stats_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    #[derive(Debug, Clone)]
+    enum MyEvent {
+        One,
+        Two,
+    }
+
+    let mediator = CxAwareMediator::<(), MyEvent>::builder()
+        .add_listener(|_: &MyEvent| {})
+        .add_context(())
+        .with_capacity(2)
+        .with_overflow_policy(OverflowPolicy::DropOldest)
+        .build()
+        .unwrap();
+
+    let handle = mediator.subscribe(|ev: &MyEvent| {
+        if let MyEvent::Two = ev {
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+    });
+
+    mediator.publish(MyEvent::One);
+    mediator.publish(MyEvent::Two);
+    mediator.publish(MyEvent::Two);
+
+    let stats = mediator.stats();
+    assert_eq!(stats.published(), 3);
+    assert_eq!(stats.queued(), 2);
+    assert_eq!(stats.processed(), 0);
+    assert_eq!(stats.listener_count(), 2);
+
+    assert_eq!(mediator.next_all(), 2);
+    let stats = mediator.stats();
+    assert_eq!(stats.queued(), 0);
+    assert_eq!(stats.processed(), 2);
+    assert!(stats.listeners().iter().all(|l| l.invocations() == 2));
+    assert!(
+        stats.listener(handle).unwrap().total_duration() >= std::time::Duration::from_millis(10)
+    );
+
+    mediator.unsubscribe(handle);
+    assert_eq!(mediator.stats().listener_count(), 1);
+    assert!(mediator.stats().listener(handle).is_none());
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn stats_test_async() {
+    use crate::asynchronous::basic::*;
+
+    #[derive(Debug, Clone)]
+    enum MyEvent {
+        One,
+    }
+
+    async_std::task::block_on(async {
+        let mediator = BasicAsyncMediator::<MyEvent>::builder()
+            .add_listener(|_: &MyEvent| {})
+            .add_async_listener(|_: &MyEvent| async {})
+            .build();
+
+        mediator.publish(MyEvent::One).await;
+        mediator.publish(MyEvent::One).await;
+        mediator
+            .publish_after(MyEvent::One, std::time::Duration::from_secs(60))
+            .await;
+
+        let stats = mediator.stats();
+        assert_eq!(stats.queued(), 2);
+        assert_eq!(stats.listener_count(), 2);
+
+        assert_eq!(mediator.next_all().await, 2);
+        let stats = mediator.stats();
+        assert_eq!(stats.published(), 2);
+        assert_eq!(stats.processed(), 2);
+        assert_eq!(stats.queued(), 0);
+        assert!(stats.listeners().iter().all(|l| l.invocations() == 2));
+    });
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn profile_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    #[derive(Debug)]
+    struct Tick(u32);
+
+    let mediator = CxAwareMediator::<(), Tick>::builder()
+        .add_context(())
+        .with_capacity(2)
+        .with_profile(Profile::Deterministic)
+        .build()
+        .unwrap();
+
+    for i in 0..100 {
+        assert!(mediator.try_publish(Tick(i)).is_ok());
+    }
+    assert_eq!(mediator.next_all(), 100);
+
+    let mediator = CxAwareMediator::<(), Tick>::builder()
+        .add_context(())
+        .with_profile(Profile::LowLatency)
+        .with_capacity(4)
+        .collect_dead_letters(true)
+        .build()
+        .unwrap();
+
+    for i in 0..10 {
+        mediator.publish(Tick(i));
+    }
+    assert_eq!(mediator.next_all(), 4);
+    let dropped: Vec<_> = mediator.dead_letters().map(|l| l.event().0).collect();
+    assert_eq!(dropped, vec![0, 1, 2, 3, 4, 5]);
+
+    let mediator = CxAwareMediator::<(), Tick>::builder()
+        .add_context(())
+        .with_profile(Profile::HighThroughput)
+        .with_overflow_policy(OverflowPolicy::Error)
+        .build()
+        .unwrap();
+
+    for i in 0..1024 {
+        mediator.publish(Tick(i));
+    }
+    assert!(mediator.try_publish(Tick(1024)).is_err());
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn profile_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    assert_eq!(Profile::Deterministic.ordering(), Ordering::StrictFifo);
+    assert_eq!(Profile::HighThroughput.concurrency(), 32);
+
+    #[derive(Debug, Clone, Copy)]
+    struct Tick(u64);
+
+    async_std::task::block_on(async {
+        let seen = Arc::new(Mutex::new(vec![]));
+        let cloned = seen.clone();
+        let mediator = BasicAsyncMediator::<Tick>::builder()
+            .add_async_listener(move |ev: &Tick| {
+                let (seen, tick) = (cloned.clone(), ev.0);
+                async move {
+                    async_std::task::sleep(Duration::from_millis(10 - tick * 3)).await;
+                    seen.lock().unwrap().push(tick);
+                }
+            })
+            .with_concurrency(4)
+            .with_profile(Profile::Deterministic)
+            .build();
+
+        for i in 0..3 {
+            mediator.publish(Tick(i)).await;
+        }
+        assert_eq!(mediator.next_all().await, 3);
+        assert_eq!(*seen.lock().unwrap(), vec![0, 1, 2]);
+
+        let mediator = BasicAsyncMediator::<Tick>::builder()
+            .with_profile(Profile::LowLatency)
+            .build();
+
+        for i in 0..100 {
+            mediator.publish(Tick(i)).await;
+        }
+        assert_eq!(mediator.next_all().await, 64);
+    });
+}
+
+#[cfg(all(not(feature = "async"), feature = "persistence"))]
+#[test]
+fn upcaster_test_sync() {
+    use crate::persistence::{read_journal, read_journal_upcasted, Upcasters};
+    use crate::synchronous::contextaware::*;
+
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum AccountEvent {
+        Deposited { cents: u64 },
+    }
+
+    let path =
+        std::env::temp_dir().join(format!("mediatrix-upcaster-{}.jsonl", std::process::id()));
+    std::fs::write(
+        &path,
+        concat!(
+            "{\"topic\":null,\"event\":{\"Deposited\":5}}\n",
+            "{\"topic\":\"audit\",\"version\":1,\"event\":{\"Deposited\":{\"euros\":2}}}\n",
+        ),
+    )
+    .unwrap();
+
+    let journal = Journal::open(&path).unwrap().with_version(2);
+    let mediator = CxAwareMediator::<(), AccountEvent>::builder()
+        .add_context(())
+        .with_observer(journal.clone())
+        .build()
+        .unwrap();
+    mediator.publish(AccountEvent::Deposited { cents: 300 });
+    assert!(journal.take_error().is_none());
+
+    assert!(read_journal::<AccountEvent>(&path)
+        .unwrap()
+        .any(|entry| entry.is_err()));
+
+    let mut upcasters = Upcasters::default();
+    upcasters.register(0, |old| AccountEvent::Deposited {
+        cents: old["Deposited"].as_u64().unwrap() * 100,
+    });
+    upcasters.register(1, |old| AccountEvent::Deposited {
+        cents: old["Deposited"]["euros"].as_u64().unwrap() * 100,
+    });
+    let entries: Vec<_> = read_journal_upcasted(&path, upcasters)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        entries.iter().map(|entry| &entry.event).collect::<Vec<_>>(),
+        vec![
+            &AccountEvent::Deposited { cents: 500 },
+            &AccountEvent::Deposited { cents: 200 },
+            &AccountEvent::Deposited { cents: 300 },
+        ]
+    );
+    assert_eq!(entries[1].topic.as_deref(), Some("audit"));
+
+    let fresh = CxAwareMediator::<(), AccountEvent>::builder()
+        .add_context(())
+        .register_upcaster(0, |old| AccountEvent::Deposited {
+            cents: old["Deposited"].as_u64().unwrap() * 100,
+        })
+        .build()
+        .unwrap();
+    assert!(fresh.replay_from(&path).is_err());
+    std::fs::remove_file(&path).ok();
+}
+
+#[cfg(all(feature = "async", feature = "persistence"))]
+#[test]
+fn upcaster_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use serde::{Deserialize, Serialize};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Renamed {
+        name: String,
+    }
+
+    let path =
+        std::env::temp_dir().join(format!("mediatrix-upcaster-{}.jsonl", std::process::id()));
+    std::fs::write(&path, "{\"topic\":null,\"event\":{\"title\":\"old\"}}\n").unwrap();
+
+    let seen = Arc::new(Mutex::new(vec![]));
+    let cloned = seen.clone();
+
+    async_std::task::block_on(async {
+        let journal = Journal::open(&path).unwrap().with_version(1);
+        let mediator = BasicAsyncMediator::<Renamed>::builder()
+            .with_observer(journal)
+            .build();
+        mediator
+            .publish(Renamed {
+                name: "new".to_string(),
+            })
+            .await;
+
+        let fresh = BasicAsyncMediator::<Renamed>::builder()
+            .add_listener(move |ev: &Renamed| cloned.lock().unwrap().push(ev.name.clone()))
+            .register_upcaster(0, |old| Renamed {
+                name: old["title"].as_str().unwrap_or_default().to_string(),
+            })
+            .build();
+        assert_eq!(fresh.replay_from(&path).await.unwrap(), 2);
+        assert_eq!(fresh.next_all().await, 2);
+    });
+
+    assert_eq!(*seen.lock().unwrap(), vec!["old", "new"]);
+    std::fs::remove_file(&path).ok();
+}
+
+#[cfg(feature = "actix")]
+#[test]
+fn actix_test_async() {
+    use crate::actix::{MediatorActor, MediatorRequest, RecipientForwarder};
+    use crate::asynchronous::contextaware::*;
+
+    use ::actix::{Actor, Context, Handler, Message};
+    use async_trait::async_trait;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum CounterEvent {
+        Added(u32),
+    }
+
+    impl Message for CounterEvent {
+        type Result = ();
+    }
+
+    struct Collected;
+
+    impl Message for Collected {
+        type Result = Vec<CounterEvent>;
+    }
+
+    #[derive(Default)]
+    struct Collector(Vec<CounterEvent>);
+
+    impl Actor for Collector {
+        type Context = Context<Self>;
+    }
+
+    impl Handler<CounterEvent> for Collector {
+        type Result = ();
+
+        fn handle(&mut self, ev: CounterEvent, _ctx: &mut Context<Self>) {
+            self.0.push(ev);
+        }
+    }
+
+    impl Handler<Collected> for Collector {
+        type Result = Vec<CounterEvent>;
+
+        fn handle(&mut self, _msg: Collected, _ctx: &mut Context<Self>) -> Vec<CounterEvent> {
+            self.0.clone()
+        }
+    }
+
+    struct Add(u32);
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<u32, Add, CounterEvent, u32>
+        for CxAwareAsyncMediator<u32, CounterEvent>
+    {
+        async fn handle(&self, req: Add, cx: &u32) -> u32 {
+            self.publish(CounterEvent::Added(req.0)).await;
+            cx + req.0
+        }
+    }
+
+    ::actix::System::new().block_on(async {
+        let collector = Collector::default().start();
+        let mediator = CxAwareAsyncMediator::<u32, CounterEvent>::builder()
+            .add_context(10)
+            .with_observer(RecipientForwarder::new(collector.clone().recipient()))
+            .build()
+            .unwrap();
+        let actor = MediatorActor::new(mediator.new_handle()).start();
+
+        let first = actor.send(MediatorRequest::<_, u32>::new(Add(1))).await;
+        let second = actor.send(MediatorRequest::<_, u32>::new(Add(2))).await;
+        assert_eq!(first.unwrap(), 11);
+        assert_eq!(second.unwrap(), 12);
+        assert_eq!(mediator.next_all().await, 2);
+
+        assert_eq!(
+            collector.send(Collected).await.unwrap(),
+            vec![CounterEvent::Added(1), CounterEvent::Added(2)]
+        );
+    });
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn axum_test_async() {
+    use crate::asynchronous::contextaware::*;
+    use crate::axum::{Mediator, MediatorLayer};
+
+    use ::axum::{
+        body::Body,
+        http::{Request, StatusCode},
+        routing::post,
+        Router,
+    };
+    use async_trait::async_trait;
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+    use tower::ServiceExt;
+
+    #[derive(Debug)]
+    enum OrderEvent {
+        Placed(u32),
+    }
+
+    struct Orders(AtomicU32);
+
+    struct Place;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Orders, Place, OrderEvent>
+        for CxAwareAsyncMediator<Orders, OrderEvent>
+    {
+        async fn handle(&self, _req: Place, orders: &Orders) {
+            let id = orders.0.fetch_add(1, Ordering::Relaxed) + 1;
+            self.publish(OrderEvent::Placed(id)).await;
+        }
+    }
+
+    async fn place(Mediator(m): Mediator<Orders, OrderEvent>) {
+        m.send(Place).await;
+    }
+
+    let placed = Arc::new(AtomicU32::new(0));
+    let cloned = placed.clone();
+    let mediator = Arc::new(
+        CxAwareAsyncMediator::<Orders, OrderEvent>::builder()
+            .add_context(Orders(AtomicU32::new(0)))
+            .add_listener(move |ev: &OrderEvent| {
+                let OrderEvent::Placed(id) = ev;
+                cloned.store(*id, Ordering::Relaxed);
+            })
+            .build()
+            .unwrap(),
+    );
+
+    let app = Router::new().route("/orders", post(place));
+    let request = || Request::post("/orders").body(Body::empty()).unwrap();
+
+    async_std::task::block_on(async {
+        let missing = app.clone().oneshot(request()).await.unwrap();
+        assert_eq!(missing.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let app = app.layer(MediatorLayer::new(mediator.clone()));
+        for _ in 0..2 {
+            let response = app.clone().oneshot(request()).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        assert_eq!(mediator.next_all().await, 2);
+    });
+
+    assert_eq!(placed.load(Ordering::Relaxed), 2);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn ticker_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+    use std::time::Duration;
+
+    #[derive(Debug)]
+    enum CacheEvent {
+        Expire,
+        Poll,
+    }
+
+    let expired = Arc::new(AtomicU32::new(0));
+    let polled = Arc::new(AtomicU32::new(0));
+    let (expired_clone, polled_clone) = (expired.clone(), polled.clone());
+
+    async_std::task::block_on(async {
+        let mediator = Arc::new(
+            CxAwareAsyncMediator::<(), CacheEvent>::builder()
+                .add_context(())
+                .add_listener(move |ev: &CacheEvent| {
+                    let counter = match ev {
+                        CacheEvent::Expire => &expired_clone,
+                        CacheEvent::Poll => &polled_clone,
+                    };
+                    counter.fetch_add(1, Ordering::Relaxed);
+                })
+                .with_ticker(Duration::from_millis(10), || CacheEvent::Expire)
+                .with_ticker(Duration::from_millis(25), || CacheEvent::Poll)
+                .build()
+                .unwrap(),
+        );
+
+        // Tickers only tick while the mediator runs.
+        async_std::task::sleep(Duration::from_millis(30)).await;
+        assert_eq!(mediator.next_all().await, 0);
+
+        let worker = mediator.clone().spawn_worker();
+        async_std::task::sleep(Duration::from_millis(60)).await;
+        let processed = worker.shutdown().await;
+        assert!(processed >= 5, "processed {processed}");
+
+        async_std::task::sleep(Duration::from_millis(30)).await;
+        assert_eq!(mediator.next_all().await, 0);
+    });
+
+    assert!(expired.load(Ordering::Relaxed) >= 4);
+    assert!(polled.load(Ordering::Relaxed) >= 1);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn priority_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Debug)]
+    enum JobEvent {
+        Ran(&'static str),
+    }
+
+    struct Job(&'static str);
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<(), Job, JobEvent> for CxAwareAsyncMediator<(), JobEvent> {
+        async fn handle(&self, req: Job, _cx: &()) {
+            self.publish(JobEvent::Ran(req.0)).await;
+        }
+    }
+
+    let log = Arc::new(Mutex::new(vec![]));
+    let cloned = log.clone();
+
+    async_std::task::block_on(async {
+        let mediator = Arc::new(
+            CxAwareAsyncMediator::<(), JobEvent>::builder()
+                .add_context(())
+                .add_listener(move |JobEvent::Ran(name): &JobEvent| {
+                    cloned.lock().unwrap().push(*name)
+                })
+                .build()
+                .unwrap(),
+        );
+
+        let queued = [
+            ("bulk-1", Priority::Low),
+            ("default", Priority::Normal),
+            ("bulk-2", Priority::Low),
+            ("control-1", Priority::High),
+            ("control-2", Priority::High),
+        ];
+        let mut tasks = vec![];
+        for (name, priority) in queued {
+            let mediator = mediator.clone();
+            tasks.push(async_std::task::spawn(async move {
+                mediator.send_with_priority(Job(name), priority).await
+            }));
+            async_std::task::sleep(Duration::from_millis(5)).await;
+        }
+
+        // Queued requests are only handled while the mediator runs.
+        async_std::task::sleep(Duration::from_millis(20)).await;
+        assert_eq!(mediator.next_all().await, 0);
+
+        let worker = mediator.clone().spawn_worker();
+        for task in tasks {
+            task.await;
+        }
+        assert_eq!(worker.shutdown().await, 5);
+    });
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec!["control-1", "control-2", "default", "bulk-1", "bulk-2"]
+    );
+}
+
+#[cfg(all(not(feature = "async"), feature = "persistence"))]
+#[test]
+fn codec_test_sync() {
+    use crate::codec::{Codec, JsonCodec};
+    use crate::persistence::{read_journal_with, Upcasters};
+    use crate::synchronous::basic::*;
+
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum AccountEvent {
+        Deposited { cents: u64 },
+        Closed,
+    }
+
+    fn round_trip(codec: impl Codec, name: &str) {
+        let path = std::env::temp_dir().join(format!(
+            "mediatrix-codec-{}-{}.journal",
+            name,
+            std::process::id()
+        ));
+        std::fs::remove_file(&path).ok();
+
+        let journal = Journal::open_with_codec(&path, codec.clone()).unwrap();
+        let mediator = BasicMediator::<AccountEvent>::builder()
+            .with_observer(journal.clone())
+            .build();
+        mediator.publish(AccountEvent::Deposited { cents: 250 });
+        mediator.publish_to("audit", AccountEvent::Closed);
+        assert!(journal.take_error().is_none());
+
+        let entries: Vec<_> =
+            read_journal_with::<AccountEvent, _>(&path, codec.clone(), Upcasters::default())
+                .unwrap()
+                .collect::<Result<_, _>>()
+                .unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].event, AccountEvent::Deposited { cents: 250 });
+        assert_eq!(entries[1].topic.as_deref(), Some("audit"));
+
+        let fresh = BasicMediator::<AccountEvent>::builder().build();
+        assert_eq!(fresh.replay_from_with(&path, codec).unwrap(), 2);
+        assert_eq!(fresh.next_all(), 2);
+        std::fs::remove_file(&path).ok();
+    }
+
+    round_trip(JsonCodec, "json");
+    #[cfg(feature = "bincode")]
+    round_trip(crate::codec::BincodeCodec, "bincode");
+    #[cfg(feature = "msgpack")]
+    round_trip(crate::codec::MessagePackCodec, "msgpack");
+    #[cfg(feature = "cbor")]
+    round_trip(crate::codec::CborCodec, "cbor");
+}
+
+#[cfg(all(feature = "async", feature = "persistence"))]
+#[test]
+fn codec_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::codec::{Codec, JsonCodec};
+
+    use serde::{Deserialize, Serialize};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Renamed {
+        name: String,
+    }
+
+    async fn round_trip(codec: impl Codec, name: &str, upcasted: bool) -> Vec<String> {
+        let path = std::env::temp_dir().join(format!(
+            "mediatrix-codec-{}-{}.journal",
+            name,
+            std::process::id()
+        ));
+        std::fs::remove_file(&path).ok();
+
+        let journal = Journal::open_with_codec(&path, codec.clone())
+            .unwrap()
+            .with_version(1);
+        let mediator = BasicAsyncMediator::<Renamed>::builder()
+            .with_observer(journal.clone())
+            .build();
+        mediator
+            .publish(Renamed {
+                name: name.to_string(),
+            })
+            .await;
+        assert!(journal.take_error().is_none());
+
+        let seen = Arc::new(Mutex::new(vec![]));
+        let cloned = seen.clone();
+        let mut builder = BasicAsyncMediator::<Renamed>::builder()
+            .add_listener(move |ev: &Renamed| cloned.lock().unwrap().push(ev.name.clone()));
+        // Upcasting decodes every entry into a self-describing value first.
+        if upcasted {
+            builder = builder.register_upcaster(0, |old| Renamed {
+                name: old["title"].as_str().unwrap_or_default().to_string(),
+            });
+        }
+        let fresh = builder.build();
+        assert_eq!(fresh.replay_from_with(&path, codec).await.unwrap(), 1);
+        assert_eq!(fresh.next_all().await, 1);
+        std::fs::remove_file(&path).ok();
+
+        let seen = seen.lock().unwrap().clone();
+        seen
+    }
+
+    async_std::task::block_on(async {
+        assert_eq!(round_trip(JsonCodec, "json", true).await, vec!["json"]);
+        #[cfg(feature = "bincode")]
+        assert_eq!(
+            round_trip(crate::codec::BincodeCodec, "bincode", false).await,
+            vec!["bincode"]
+        );
+        #[cfg(feature = "msgpack")]
+        assert_eq!(
+            round_trip(crate::codec::MessagePackCodec, "msgpack", true).await,
+            vec!["msgpack"]
+        );
+        #[cfg(feature = "cbor")]
+        assert_eq!(
+            round_trip(crate::codec::CborCodec, "cbor", true).await,
+            vec!["cbor"]
+        );
+    });
+}
+
+#[cfg(all(feature = "remote", feature = "msgpack", unix))]
+#[test]
+fn remote_codec_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::codec::MessagePackCodec;
+    use crate::remote::{serve_with_codec, Endpoint, RemoteForwarder};
+
+    use serde::{Deserialize, Serialize};
+    use std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct PriceEvent(u32);
+
+    let path = std::env::temp_dir().join(format!(
+        "mediatrix-remote-codec-{}.sock",
+        std::process::id()
+    ));
+    std::fs::remove_file(&path).ok();
+
+    async_std::task::block_on(async {
+        let remote = Arc::new(BasicAsyncMediator::<PriceEvent>::builder().build());
+        let server = serve_with_codec(
+            Endpoint::Unix(path.clone()),
+            remote.clone(),
+            MessagePackCodec,
+        )
+        .await
+        .unwrap();
+
+        let (forwarder, worker) = RemoteForwarder::spawn_with_codec(
+            Endpoint::Unix(path.clone()),
+            Duration::from_millis(5),
+            MessagePackCodec,
+        );
+        let local = BasicAsyncMediator::<PriceEvent>::builder()
+            .with_observer(forwarder)
+            .build();
+        local.publish(PriceEvent(1)).await;
+        local.publish_to("prices", PriceEvent(2)).await;
+
+        let start = Instant::now();
+        let mut received = 0;
+        while received < 2 && start.elapsed() < Duration::from_secs(5) {
+            received += remote.next_all().await;
+            async_std::task::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(received, 2);
+
+        assert_eq!(worker.shutdown().await, 2);
+        assert_eq!(server.shutdown().await, 2);
+    });
+
+    std::fs::remove_file(&path).ok();
+}
+
+#[cfg(all(not(feature = "async"), feature = "schema"))]
+#[test]
+fn describe_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    use schemars::JsonSchema;
+    use std::sync::Arc;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, JsonSchema)]
+    enum OrderEvent {
+        Placed { id: u32, total: u64 },
+        Cancelled { id: u32 },
+    }
+
+    struct Shipping;
+
+    impl NotificationHandler<OrderEvent> for Shipping {
+        fn on_event(&self, _ev: &OrderEvent) {}
+
+        fn name(&self) -> &'static str {
+            "shipping"
+        }
+    }
+
+    fn bill(_ev: &OrderEvent) {}
+
+    let mediator = CxAwareMediator::<(), OrderEvent>::builder()
+        .add_context(())
+        .add_listener(bill)
+        .add_listener_with_priority(10, |_: &OrderEvent| {})
+        .add_listener_for_topic("orders.*", |_: &OrderEvent| {})
+        .add_handler(Arc::new(Shipping))
+        .build()
+        .unwrap();
+
+    let description = mediator.describe();
+    assert_eq!(description.event(), "OrderEvent");
+    let listeners = description.listeners();
+    assert_eq!(listeners.len(), 4);
+    assert_eq!(listeners[0].priority(), 10);
+    assert!(listeners[0].name().ends_with("{{closure}}"));
+    assert!(listeners[1].name().ends_with("::bill"));
+    assert_eq!(listeners[2].topic(), Some("orders.*"));
+    assert_eq!(listeners[3].name(), "shipping");
+    assert!(listeners.iter().all(|listener| !listener.is_async()));
+
+    let handle = mediator.subscribe(bill);
+    assert_eq!(mediator.describe().listeners().len(), 5);
+    assert!(mediator.unsubscribe(handle));
+
+    let json: serde_json::Value = serde_json::from_str(&description.to_json()).unwrap();
+    assert_eq!(json["event"], "OrderEvent");
+    assert!(json["schema"]["oneOf"].is_array());
+    assert_eq!(json["listeners"][3]["name"], "shipping");
+}
+
+#[cfg(all(feature = "async", feature = "schema"))]
+#[test]
+fn describe_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use schemars::JsonSchema;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, JsonSchema)]
+    struct PriceEvent {
+        cents: u64,
+    }
+
+    let mediator = BasicAsyncMediator::<PriceEvent>::builder()
+        .add_listener(|_: &PriceEvent| {})
+        .add_async_listener_for_topic("prices", |_: &PriceEvent| async {})
+        .build();
+
+    let description = mediator.describe();
+    assert_eq!(description.event(), "PriceEvent");
+    assert_eq!(
+        description
+            .listeners()
+            .iter()
+            .map(|listener| (listener.topic(), listener.is_async()))
+            .collect::<Vec<_>>(),
+        vec![(None, false), (Some("prices"), true)]
+    );
+    assert!(description.schema().get("properties").is_some());
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn subscribe_async_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    #[derive(Debug, Clone)]
+    struct PluginEvent(u32);
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+
+    async_std::task::block_on(async {
+        let mediator = Arc::new(
+            CxAwareAsyncMediator::<(), PluginEvent>::builder()
+                .add_context(())
+                .build()
+                .unwrap(),
+        );
+        let worker = mediator.clone().spawn_worker();
+
+        // A plugin loaded after the mediator started running.
+        let handle = mediator
+            .subscribe_async(move |ev: &PluginEvent| {
+                let seen = cloned.clone();
+                let n = ev.0;
+                async move { seen.lock().unwrap().push(n) }
+            })
+            .await;
+        mediator.publish(PluginEvent(1)).await;
+
+        let start = Instant::now();
+        while seen.lock().unwrap().is_empty() && start.elapsed() < Duration::from_secs(5) {
+            async_std::task::sleep(Duration::from_millis(5)).await;
+        }
+
+        assert!(mediator.unsubscribe(handle).await);
+        assert!(!mediator.unsubscribe(handle).await);
+        mediator.publish(PluginEvent(2)).await;
+        assert_eq!(worker.shutdown().await, 2);
+    });
+
+    assert_eq!(*seen.lock().unwrap(), vec![1]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn queue_request_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    #[derive(Debug)]
+    enum JobEvent {
+        Done(u32),
+    }
+
+    struct Job(u32);
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Mutex<Vec<u32>>, Job, JobEvent>
+        for CxAwareAsyncMediator<Mutex<Vec<u32>>, JobEvent>
+    {
+        async fn handle(&self, req: Job, cx: &Mutex<Vec<u32>>) {
+            cx.lock().unwrap().push(req.0);
+            self.publish(JobEvent::Done(req.0)).await;
+        }
+    }
+
+    let done = Arc::new(Mutex::new(Vec::new()));
+    let cloned = done.clone();
+
+    async_std::task::block_on(async {
+        let mediator = Arc::new(
+            CxAwareAsyncMediator::<Mutex<Vec<u32>>, JobEvent>::builder()
+                .add_context(Mutex::new(Vec::new()))
+                .add_listener(move |JobEvent::Done(n): &JobEvent| cloned.lock().unwrap().push(*n))
+                .build()
+                .unwrap(),
+        );
+
+        // Queuing returns before the request is handled.
+        mediator.queue_request(Job(1));
+        assert!(done.lock().unwrap().is_empty());
+
+        let worker = mediator.clone().spawn_worker();
+        let producers: Vec<_> = (2..5)
+            .map(|n| {
+                let handle = mediator.new_handle();
+                async_std::task::spawn(async move { handle.queue_request(Job(n)) })
+            })
+            .collect();
+        for producer in producers {
+            producer.await;
+        }
+
+        let start = Instant::now();
+        while done.lock().unwrap().len() < 4 && start.elapsed() < Duration::from_secs(5) {
+            async_std::task::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(worker.shutdown().await, 4);
+    });
+
+    let mut done = done.lock().unwrap().clone();
+    assert_eq!(done[0], 1);
+    done.sort();
+    assert_eq!(done, vec![1, 2, 3, 4]);
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn redeliver_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+    use std::time::Duration;
+
+    #[derive(Debug, PartialEq)]
+    enum PaymentEvent {
+        Charged(u32),
+        Refunded(u32),
+    }
+
+    let failures = Arc::new(AtomicU32::new(0));
+    let cloned = failures.clone();
+    let mediator = BasicMediator::<PaymentEvent>::builder()
+        .isolate_listeners(true)
+        .collect_dead_letters(true)
+        .add_listener(move |ev: &PaymentEvent| {
+            // The payment provider is down for the first two deliveries of a charge.
+            if matches!(ev, PaymentEvent::Charged(_)) && cloned.fetch_add(1, Ordering::SeqCst) < 2 {
+                panic!("provider unavailable");
+            }
+        })
+        .build();
+
+    mediator.publish_to("payments", PaymentEvent::Charged(10));
+    assert!(mediator.next().is_err());
+
+    let policy = RedeliveryPolicy {
+        max_attempts: Some(1),
+        cool_down: Duration::from_millis(20),
+    };
+    assert_eq!(mediator.redeliver_with(policy), 0);
+    std::thread::sleep(Duration::from_millis(20));
+    assert_eq!(mediator.redeliver_with(policy), 1);
+    assert!(mediator.next().is_err());
+
+    // The single attempt is used up, so the letter stays in the queue.
+    std::thread::sleep(Duration::from_millis(50));
+    assert_eq!(mediator.redeliver_with(policy), 0);
+    assert_eq!(mediator.redeliver_all(), 1);
+    assert!(mediator.next().is_ok());
+    assert_eq!(mediator.dead_letters().len(), 0);
+    assert_eq!(failures.load(Ordering::SeqCst), 3);
+
+    mediator.publish(PaymentEvent::Refunded(10));
+    mediator.publish(PaymentEvent::Charged(20));
+    assert_eq!(mediator.redeliver_all(), 0);
+    assert_eq!(mediator.next_all(), 2);
+    assert_eq!(mediator.dead_letters().len(), 0);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn redeliver_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct SyncEvent(u32);
+
+    let online = Arc::new(AtomicBool::new(false));
+    let synced = Arc::new(Mutex::new(Vec::new()));
+    let (online_clone, synced_clone) = (online.clone(), synced.clone());
+
+    async_std::task::block_on(async {
+        let mediator = CxAwareAsyncMediator::<(), SyncEvent>::builder()
+            .add_context(())
+            .isolate_listeners(true)
+            .collect_dead_letters(true)
+            .add_listener(move |ev: &SyncEvent| {
+                assert!(online_clone.load(Ordering::SeqCst), "offline");
+                synced_clone.lock().unwrap().push(ev.0);
+            })
+            .build()
+            .unwrap();
+
+        mediator.publish_to("sync", SyncEvent(1)).await;
+        mediator.publish(SyncEvent(2)).await;
+        assert_eq!(mediator.next_all().await, 2);
+
+        assert_eq!(mediator.redeliver_all().await, 2);
+        assert_eq!(mediator.next_all().await, 2);
+
+        let letters: Vec<_> = mediator.dead_letters().await.collect();
+        assert_eq!(letters.len(), 2);
+        assert_eq!(letters[0].topic(), Some("sync"));
+        assert!(letters.iter().all(|letter| letter.attempts() == 1));
+        for letter in letters {
+            mediator.publish(letter.into_event()).await;
+        }
+        mediator.next_all().await;
+
+        online.store(true, Ordering::SeqCst);
+        let policy = RedeliveryPolicy {
+            max_attempts: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(mediator.redeliver_with(policy).await, 2);
+        assert_eq!(mediator.next_all().await, 2);
+        assert_eq!(mediator.dead_letters().await.len(), 0);
+    });
+
+    assert_eq!(*synced.lock().unwrap(), vec![1, 2]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn lifecycle_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone)]
+    enum Event {}
+
+    #[derive(Debug, Default)]
+    struct Pool(Arc<Mutex<Vec<&'static str>>>);
+
+    #[async_trait]
+    impl CxLifecycle for Pool {
+        fn on_start(&self) {
+            self.0.lock().unwrap().push("start");
+        }
+
+        async fn on_shutdown(&self) {
+            self.0.lock().unwrap().push("shutdown");
+        }
+    }
+
+    struct Query;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Pool, Query, Event> for CxAwareAsyncMediator<Pool, Event> {
+        async fn handle(&self, _req: Query, pool: &Pool) {
+            pool.0.lock().unwrap().push("query");
+        }
+    }
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let log_clone = log.clone();
+
+    async_std::task::block_on(async {
+        #[cfg(not(feature = "async-only"))]
+        {
+            let sync = crate::synchronous::contextaware::CxAwareMediator::<Pool, Event>::builder()
+                .add_context(Pool(log.clone()))
+                .with_lifecycle()
+                .build()
+                .unwrap();
+            assert_eq!(*log.lock().unwrap(), vec!["start"]);
+            sync.shutdown().await;
+            sync.shutdown().await;
+            assert_eq!(*log.lock().unwrap(), vec!["start", "shutdown"]);
+            log.lock().unwrap().clear();
+        }
+
+        let mediator = CxAwareAsyncMediator::<Pool, Event>::builder()
+            .add_context_with(move || async move { Pool(log_clone) })
+            .with_lifecycle()
+            .build()
+            .unwrap();
+        assert!(log.lock().unwrap().is_empty());
+
+        mediator.send(Query).await;
+        mediator.send(Query).await;
+        mediator.shutdown().await;
+        mediator.shutdown().await;
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["start", "query", "query", "shutdown"]
+        );
+
+        let untouched = CxAwareAsyncMediator::<Pool, Event>::builder()
+            .add_context_with(|| async { panic!("the context is never created") })
+            .with_lifecycle()
+            .build()
+            .unwrap();
+        untouched.shutdown().await;
+    });
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn shared_mediator_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    #[derive(Debug, Clone)]
+    enum Event {
+        Pinged(u32),
+    }
+
+    struct Ping(u32);
+
+    impl RequestHandler<Ping, Event> for BasicMediator<Event> {
+        fn handle(&self, req: Ping) {
+            self.publish(Event::Pinged(req.0));
+        }
+    }
+
+    fn assert_shareable<T: Send + Sync + Clone>(_: &T) {}
+
+    let pinged = Arc::new(Mutex::new(Vec::new()));
+    let pinged_clone = pinged.clone();
+
+    let mediator: SharedMediator<Event> = BasicMediator::<Event>::builder()
+        .add_listener(move |ev: &Event| {
+            let Event::Pinged(n) = ev;
+            pinged_clone.lock().unwrap().push(*n);
+        })
+        .build()
+        .into();
+    assert_shareable(&mediator);
+
+    let processor = mediator.clone();
+    let processing = thread::spawn(move || {
+        for _ in 0..8 {
+            processor.next_blocking().unwrap();
+        }
+    });
+
+    let senders: Vec<_> = (0..4)
+        .map(|i| {
+            let mediator = mediator.clone();
+            thread::spawn(move || {
+                mediator.send(Ping(i));
+                mediator.publish(Event::Pinged(i + 4));
+            })
+        })
+        .collect();
+    for sender in senders {
+        sender.join().unwrap();
+    }
+    processing.join().unwrap();
+
+    let mut pinged = pinged.lock().unwrap().clone();
+    pinged.sort();
+    assert_eq!(pinged, (0..8).collect::<Vec<_>>());
+    assert_eq!(mediator.stats().processed(), 8);
+    assert!(matches!(mediator.next(), Err(NextError::Empty)));
+}
+
+#[cfg(all(not(feature = "async"), feature = "causality"))]
+#[test]
+fn causality_test_sync() {
+    use crate::synchronous::basic::*;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Clone)]
+    enum Event {
+        Ordered(u32),
+        Shipped(u32),
+    }
+
+    struct Order(u32);
+
+    impl RequestHandler<Order, Event> for BasicMediator<Event> {
+        fn handle(&self, req: Order) {
+            self.publish(Event::Ordered(req.0));
+        }
+    }
+
+    let untraced = BasicMediator::<Event>::builder().build();
+    untraced.send(Order(0));
+    untraced.next_all();
+    assert_eq!(untraced.causality_graph(), CausalityGraph::default());
+
+    let mediator: SharedMediator<Event> = BasicMediator::<Event>::builder()
+        .record_causality()
+        .build()
+        .into();
+    let shipping = mediator.clone();
+    let handle = mediator.subscribe(move |ev: &Event| {
+        if let Event::Ordered(n) = ev {
+            shipping.publish(Event::Shipped(*n));
+        }
+    });
+
+    mediator.send(Order(1));
+    assert_eq!(mediator.next_all(), 2);
+
+    let graph = mediator.mediator().causality_graph();
+    let nodes = graph.nodes();
+    assert_eq!(nodes.len(), 4);
+    let index = |f: &dyn Fn(&CausalityNode) -> bool| nodes.iter().position(f).unwrap();
+    let order =
+        index(&|node| matches!(node, CausalityNode::Request { name } if name.ends_with("Order")));
+    let ordered =
+        index(&|node| matches!(node, CausalityNode::Event { label, .. } if label == "Ordered(1)"));
+    let shipped =
+        index(&|node| matches!(node, CausalityNode::Event { label, .. } if label == "Shipped(1)"));
+    let listener = index(
+        &|node| matches!(node, CausalityNode::Listener { handle: h, name: Some(_) } if *h == handle),
+    );
+
+    let mut edges = graph.edges().to_vec();
+    edges.sort();
+    let mut expected = vec![
+        (order, ordered),
+        (ordered, listener),
+        (listener, shipped),
+        (shipped, listener),
+    ];
+    expected.sort();
+    assert_eq!(edges, expected);
+
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph causality {"));
+    assert_eq!(dot.matches("->").count(), 4);
+    assert!(graph.to_json().contains("\"kind\": \"listener\""));
+}
+
+#[cfg(all(feature = "async", feature = "causality"))]
+#[test]
+fn causality_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use async_trait::async_trait;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Clone)]
+    enum Event {
+        Ordered(u32),
+        Shipped(u32),
+    }
+
+    struct Order(u32);
+    struct Ship(u32);
+
+    #[async_trait]
+    impl AsyncRequestHandler<Order, Event> for BasicAsyncMediator<Event> {
+        async fn handle(&self, req: Order) {
+            self.publish(Event::Ordered(req.0)).await;
+            self.send(Ship(req.0)).await;
+        }
+    }
+
+    #[async_trait]
+    impl AsyncRequestHandler<Ship, Event> for BasicAsyncMediator<Event> {
+        async fn handle(&self, req: Ship) {
+            self.publish(Event::Shipped(req.0)).await;
+        }
+    }
+
+    let mediator = BasicAsyncMediator::<Event>::builder()
+        .record_causality()
+        .add_async_listener(|_: &Event| async {})
+        .build();
+
+    async_std::task::block_on(async {
+        mediator.send(Order(1)).await;
+        mediator.next().await.unwrap();
+        mediator.next().await.unwrap();
+    });
+
+    let graph = mediator.causality_graph();
+    let nodes = graph.nodes();
+    assert_eq!(nodes.len(), 5);
+    let index = |f: &dyn Fn(&CausalityNode) -> bool| nodes.iter().position(f).unwrap();
+    let order =
+        index(&|node| matches!(node, CausalityNode::Request { name } if name.ends_with("Order")));
+    let ship =
+        index(&|node| matches!(node, CausalityNode::Request { name } if name.ends_with("Ship")));
+    let ordered =
+        index(&|node| matches!(node, CausalityNode::Event { label, .. } if label == "Ordered(1)"));
+    let shipped =
+        index(&|node| matches!(node, CausalityNode::Event { label, .. } if label == "Shipped(1)"));
+    let listener = index(&|node| matches!(node, CausalityNode::Listener { name: Some(_), .. }));
+
+    let mut edges = graph.edges().to_vec();
+    edges.sort();
+    let mut expected = vec![
+        (order, ordered),
+        (order, ship),
+        (ship, shipped),
+        (ordered, listener),
+        (shipped, listener),
+    ];
+    expected.sort();
+    assert_eq!(edges, expected);
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn mediator_requests_test_sync() {
+    use crate::mediator_requests;
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Event {
+        Pinged,
+        UserCreated(u32),
+    }
+
+    struct Ping;
+    struct CreateUser(u32);
+
+    #[derive(Default)]
+    struct PingHandler;
+
+    impl<Ev: MaybeDebug + From<Ping>> Handler<Ping, Ev> for PingHandler {
+        fn handle(&self, mediator: &BasicMediator<Ev>, req: Ping) {
+            mediator.publish(req.into());
+        }
+    }
+
+    impl From<Ping> for Event {
+        fn from(_: Ping) -> Self {
+            Event::Pinged
+        }
+    }
+
+    #[derive(Default)]
+    struct CreateUserHandler;
+
+    impl Handler<CreateUser, Event, u32> for CreateUserHandler {
+        fn handle(&self, mediator: &BasicMediator<Event>, req: CreateUser) -> u32 {
+            mediator.publish(Event::UserCreated(req.0));
+            req.0 + 1
+        }
+    }
+
+    impl Handler<CreateUser, Event> for CreateUserHandler {
+        fn handle(&self, mediator: &BasicMediator<Event>, req: CreateUser) {
+            mediator.publish(Event::UserCreated(req.0));
+        }
+    }
+
+    mediator_requests! {
+        Ping => PingHandler,
+        CreateUser => CreateUserHandler
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+
+    let mediator = BasicMediator::<Event>::builder()
+        .add_listener(move |ev: &Event| events_clone.lock().unwrap().push(ev.clone()))
+        .build();
+
+    mediator.send(Ping);
+    mediator.send(CreateUser(1));
+    assert_eq!(mediator.send_and_receive::<_, u32>(CreateUser(2)), 3);
+    assert_eq!(mediator.next_all(), 3);
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![Event::Pinged, Event::UserCreated(1), Event::UserCreated(2)]
+    );
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn standalone_handler_test_sync() {
+    use crate::synchronous::contextaware::*;
+
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Event {
+        Deposited(u32),
+    }
+
+    struct Deposit(u32);
+    struct Balance;
+
+    struct DepositHandler {
+        account: Arc<AtomicU32>,
+    }
+
+    impl Handler<Deposit, Event> for DepositHandler {
+        fn handle(&self, mediator: &BasicMediator<Event>, req: Deposit) {
+            self.account.fetch_add(req.0, Ordering::SeqCst);
+            mediator.publish(Event::Deposited(req.0));
+        }
+    }
+
+    struct BalanceHandler {
+        account: Arc<AtomicU32>,
+    }
+
+    impl Handler<Balance, Event, u32> for BalanceHandler {
+        fn handle(&self, _: &BasicMediator<Event>, _: Balance) -> u32 {
+            self.account.load(Ordering::SeqCst)
+        }
+    }
+
+    let account = Arc::new(AtomicU32::new(0));
+
+    let mediator = CxAwareMediator::<(), Event>::builder()
+        .add_context(())
+        .register(DepositHandler {
+            account: account.clone(),
+        })
+        .register(BalanceHandler {
+            account: account.clone(),
+        })
+        .build()
+        .unwrap();
+
+    assert!(mediator.send_registered(Deposit(5)).is_ok());
+    assert!(mediator.send_registered(Deposit(7)).is_ok());
+    assert!(mediator.send_registered(Balance).is_err());
+    assert_eq!(
+        mediator.send_registered_and_receive::<_, u32>(Balance).ok(),
+        Some(12)
+    );
+    assert_eq!(mediator.next_all(), 2);
+    assert_eq!(account.load(Ordering::SeqCst), 12);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn converting_listener_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone)]
+    enum Event {
+        Payment(PaymentEvent),
+        Login(u32),
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct PaymentEvent(u32);
+
+    impl TryFrom<Event> for PaymentEvent {
+        type Error = ();
+
+        fn try_from(ev: Event) -> Result<Self, Self::Error> {
+            match ev {
+                Event::Payment(payment) => Ok(payment),
+                Event::Login(_) => Err(()),
+            }
+        }
+    }
+
+    impl TryFrom<Event> for u32 {
+        type Error = ();
+
+        fn try_from(ev: Event) -> Result<Self, Self::Error> {
+            match ev {
+                Event::Login(user) => Ok(user),
+                Event::Payment(_) => Err(()),
+            }
+        }
+    }
+
+    let payments = Arc::new(Mutex::new(Vec::new()));
+    let payments_clone = payments.clone();
+    let logins = Arc::new(Mutex::new(Vec::new()));
+    let logins_clone = logins.clone();
+
+    let mediator = BasicAsyncMediator::<Event>::builder()
+        .add_converting_listener(move |ev: PaymentEvent| payments_clone.lock().unwrap().push(ev))
+        .add_converting_listener(move |user: u32| logins_clone.lock().unwrap().push(user))
+        .build();
+
+    async_std::task::block_on(async {
+        mediator.publish(Event::Payment(PaymentEvent(10))).await;
+        mediator.publish(Event::Login(1)).await;
+        mediator.publish(Event::Payment(PaymentEvent(20))).await;
+        for _ in 0..3 {
+            mediator.next().await.unwrap();
+        }
+    });
+
+    assert_eq!(
+        *payments.lock().unwrap(),
+        vec![PaymentEvent(10), PaymentEvent(20)]
+    );
+    assert_eq!(*logins.lock().unwrap(), vec![1]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn executor_test_async() {
+    use crate::cancellation::TimeoutError;
+    use crate::executor::{AsyncStdExecutor, Executor};
+
+    use std::time::Duration;
+
+    async fn check(executor: &impl Executor) {
+        let (done, received) = async_std::channel::bounded(1);
+        executor.spawn(Box::pin(async move {
+            done.send(7).await.ok();
+        }));
+        assert_eq!(received.recv().await, Ok(7));
+
+        executor.sleep(Duration::from_millis(1)).await;
+
+        assert_eq!(
+            executor.timeout(Duration::from_secs(5), async { 1 }).await,
+            Ok(1)
+        );
+        assert_eq!(
+            executor
+                .timeout(Duration::from_millis(1), std::future::pending::<()>())
+                .await,
+            Err(TimeoutError)
+        );
+    }
+
+    async_std::task::block_on(check(&AsyncStdExecutor));
+
+    #[cfg(feature = "tokio")]
+    {
+        use crate::executor::TokioExecutor;
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let executor = TokioExecutor::new(runtime.handle().clone());
+        runtime.block_on(check(&executor));
+    }
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn reentrant_send_test_async() {
+    use crate::asynchronous::contextaware::*;
+    use async_trait::async_trait;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Event {
+        Ordered(u32),
+        Shipped(u32),
+        Counted(u32),
+    }
+
+    #[derive(Debug, Default)]
+    struct Shop {
+        count: u32,
+    }
+
+    struct Order(u32);
+    struct Ship(u32);
+    struct Count;
+    struct Query;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Shop, Order, Event> for CxAwareAsyncMediator<Shop, Event> {
+        async fn handle(&self, req: Order, _cx: &Shop) {
+            self.send(Ship(req.0)).await;
+            self.send_mut(Count).await;
+            self.send_with_scope(Ship(req.0), 100).await;
+            self.publish(Event::Ordered(req.0)).await;
+        }
+    }
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Shop, Ship, Event> for CxAwareAsyncMediator<Shop, Event> {
+        async fn handle(&self, req: Ship, cx: &Shop) {
+            self.publish(Event::Shipped(req.0 + cx.count)).await;
+        }
+    }
+
+    #[async_trait]
+    impl ScopedCxAwareAsyncRequestHandler<Shop, u32, Ship, Event>
+        for CxAwareAsyncMediator<Shop, Event>
+    {
+        async fn handle(&self, req: Ship, _cx: &Shop, extra: &u32) {
+            self.publish(Event::Shipped(req.0 + extra)).await;
+        }
+    }
+
+    #[async_trait]
+    impl CxAwareAsyncMutRequestHandler<Shop, Count, Event> for CxAwareAsyncMediator<Shop, Event> {
+        async fn handle(&self, _req: Count, cx: &mut Shop) {
+            cx.count += 1;
+            self.publish(Event::Counted(cx.count)).await;
+        }
+    }
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Shop, Query, Event, bool> for CxAwareAsyncMediator<Shop, Event> {
+        async fn handle(&self, _req: Query, _cx: &Shop) -> bool {
+            self.send_and_receive::<Query, bool>(Query).await == Err(ReentrantSendError)
+        }
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = events.clone();
+    let mediator = CxAwareAsyncMediator::<Shop, Event>::builder()
+        .add_listener(move |ev: &Event| recorded.lock().unwrap().push(ev.clone()))
+        .add_context(Shop::default())
+        .build()
+        .unwrap();
+
+    async_std::task::block_on(async {
+        mediator.send(Order(1)).await;
+        mediator.defer_send(Ship(10)).await;
+        mediator.next_all().await;
+    });
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            Event::Ordered(1),
+            Event::Shipped(1),
+            Event::Counted(1),
+            Event::Shipped(101),
+            Event::Shipped(11)
+        ]
+    );
+
+    let reentrant = async_std::task::block_on(mediator.send_and_receive::<Query, bool>(Query));
+    assert_eq!(reentrant, Ok(true));
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn deferred_send_scope_test_async() {
+    use crate::asynchronous::contextaware::*;
+    use async_trait::async_trait;
+
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Event {
+        Ordered(u32),
+        Shipped(u32),
+        Viewed,
+    }
+
+    struct Order(u32);
+    struct Ship(u32);
+    struct View;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<(), Order, Event> for CxAwareAsyncMediator<(), Event> {
+        async fn handle(&self, req: Order, _cx: &()) {
+            self.defer_send(Ship(req.0)).await;
+            async_std::task::sleep(Duration::from_millis(50)).await;
+            self.publish(Event::Ordered(req.0)).await;
+        }
+    }
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<(), Ship, Event> for CxAwareAsyncMediator<(), Event> {
+        async fn handle(&self, req: Ship, _cx: &()) {
+            self.publish(Event::Shipped(req.0)).await;
+        }
+    }
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<(), View, Event> for CxAwareAsyncMediator<(), Event> {
+        async fn handle(&self, _req: View, _cx: &()) {
+            self.publish(Event::Viewed).await;
+        }
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = events.clone();
+    let mediator = CxAwareAsyncMediator::<(), Event>::builder()
+        .add_listener(move |ev: &Event| recorded.lock().unwrap().push(ev.clone()))
+        .add_context(())
+        .read_write_context()
+        .build()
+        .unwrap();
+
+    async_std::task::block_on(async {
+        // Another request completing while the order is handled leaves its deferred request alone.
+        let ordering = mediator.new_handle();
+        let order = async_std::task::spawn(async move { ordering.send(Order(1)).await });
+        async_std::task::sleep(Duration::from_millis(10)).await;
+        mediator.send(View).await;
+        order.await;
+        mediator.next_all().await;
+    });
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![Event::Viewed, Event::Ordered(1), Event::Shipped(1)]
+    );
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn ttl_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Debug, PartialEq)]
+    struct PriceEvent(u32);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let listener = received.clone();
+    let mediator = BasicMediator::<PriceEvent>::builder()
+        .add_listener(move |ev: &PriceEvent| listener.lock().unwrap().push(ev.0))
+        .collect_dead_letters(true)
+        .build();
+
+    mediator.publish_with_ttl(PriceEvent(1), Duration::ZERO);
+    mediator.publish_with_ttl(PriceEvent(2), Duration::from_secs(60));
+    mediator.publish(PriceEvent(3));
+    std::thread::sleep(Duration::from_millis(5));
+
+    assert_eq!(mediator.next_all(), 2);
+    assert_eq!(*received.lock().unwrap(), vec![2, 3]);
+
+    let letters: Vec<_> = mediator.dead_letters().collect();
+    assert_eq!(letters.len(), 1);
+    assert_eq!(letters[0].event(), &PriceEvent(1));
+    assert_eq!(letters[0].reason(), &DeadLetterReason::Expired);
+
+    mediator.publish_with_ttl(PriceEvent(4), Duration::ZERO);
+    std::thread::sleep(Duration::from_millis(5));
+    assert!(mediator.next().is_err());
+    assert_eq!(mediator.stats().processed(), 2);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn sharded_test_async() {
+    use crate::asynchronous::basic::*;
+    use async_trait::async_trait;
+
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone)]
+    struct Deposited {
+        account: u32,
+        amount: u32,
+    }
+
+    struct Deposit(u32, u32);
+
+    #[async_trait]
+    impl AsyncRequestHandler<Deposit, Deposited> for BasicAsyncMediator<Deposited> {
+        async fn handle(&self, req: Deposit) {
+            self.publish(Deposited {
+                account: req.0,
+                amount: req.1,
+            })
+            .await;
+        }
+    }
+
+    let ledger = Arc::new(Mutex::new(BTreeMap::<u32, Vec<u32>>::new()));
+    let shards = Arc::new(Mutex::new(Vec::new()));
+    let mediator = ShardedAsyncMediator::<u32, Deposited>::new(4, |shard| {
+        let ledger = ledger.clone();
+        let shards = shards.clone();
+        BasicAsyncMediator::<Deposited>::builder()
+            .add_listener(move |ev: &Deposited| {
+                shards.lock().unwrap().push((ev.account, shard));
+                ledger
+                    .lock()
+                    .unwrap()
+                    .entry(ev.account)
+                    .or_default()
+                    .push(ev.amount);
+            })
+            .build()
+    });
+    assert_eq!(mediator.shards().len(), 4);
+    assert_eq!(mediator.shard_of(&7), mediator.shard_of(&7));
+
+    async_std::task::block_on(async {
+        let workers = mediator.spawn_workers();
+        for amount in 0..20 {
+            for account in 0..8 {
+                mediator
+                    .publish_keyed(&account, Deposited { account, amount })
+                    .await;
+            }
+        }
+        mediator.send_keyed(&3, Deposit(3, 20)).await;
+
+        let mut processed = 0;
+        for worker in workers {
+            processed += worker.shutdown().await;
+        }
+        assert_eq!(processed, 161);
+    });
+
+    let ledger = ledger.lock().unwrap();
+    for account in 0..8 {
+        let expected: Vec<u32> = (0..if account == 3 { 21 } else { 20 }).collect();
+        assert_eq!(ledger[&account], expected);
+    }
+    for (account, shard) in shards.lock().unwrap().iter() {
+        assert_eq!(*shard, mediator.shard_of(account));
+    }
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn pause_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    #[derive(Debug)]
+    struct ImportEvent(u32);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let listener = received.clone();
+    let mediator = SharedMediator::new(
+        BasicMediator::<ImportEvent>::builder()
+            .add_listener(move |ev: &ImportEvent| listener.lock().unwrap().push(ev.0))
+            .build(),
+    );
+
+    mediator.pause();
+    assert!(mediator.is_paused());
+    for i in 0..3 {
+        mediator.publish(ImportEvent(i));
+    }
+    assert!(matches!(mediator.next(), Err(NextError::Empty)));
+    assert_eq!(mediator.next_all(), 0);
+    assert!(received.lock().unwrap().is_empty());
+
+    mediator.resume();
+    assert!(!mediator.is_paused());
+    assert_eq!(mediator.next_all(), 3);
+    assert_eq!(*received.lock().unwrap(), vec![0, 1, 2]);
+
+    mediator.pause();
+    mediator.publish(ImportEvent(3));
+    let processor = mediator.clone();
+    let processing = thread::spawn(move || processor.next_blocking());
+    thread::sleep(Duration::from_millis(20));
+    assert_eq!(received.lock().unwrap().len(), 3);
+
+    mediator.resume();
+    processing.join().unwrap().unwrap();
+    assert_eq!(*received.lock().unwrap(), vec![0, 1, 2, 3]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn pause_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[derive(Debug)]
+    struct ImportEvent;
+
+    let received = Arc::new(AtomicUsize::new(0));
+    let listener = received.clone();
+    let mediator = Arc::new(
+        CxAwareAsyncMediator::<(), ImportEvent>::builder()
+            .add_listener(move |_: &ImportEvent| {
+                listener.fetch_add(1, Ordering::SeqCst);
+            })
+            .add_context(())
+            .build()
+            .unwrap(),
+    );
+
+    async_std::task::block_on(async {
+        mediator.pause();
+        let worker = mediator.clone().spawn_worker();
+        mediator.publish(ImportEvent).await;
+        mediator.publish(ImportEvent).await;
+        async_std::task::sleep(Duration::from_millis(20)).await;
+        assert_eq!(received.load(Ordering::SeqCst), 0);
+        assert!(mediator.is_paused());
+
+        mediator.resume();
+        while received.load(Ordering::SeqCst) < 2 {
+            async_std::task::sleep(Duration::from_millis(1)).await;
+        }
+        assert_eq!(worker.shutdown().await, 2);
+    });
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn urgent_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Event {
+        Tick(u32),
+        Alarm(u32),
+    }
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let listener = received.clone();
+    let mediator = BasicMediator::<Event>::builder()
+        .add_listener(move |ev: &Event| listener.lock().unwrap().push(ev.clone()))
+        .with_capacity(2)
+        .with_overflow_policy(OverflowPolicy::DropNewest)
+        .build();
+
+    for i in 0..3 {
+        mediator.publish(Event::Tick(i));
+    }
+    mediator.publish_urgent(Event::Alarm(0));
+    mediator.publish_urgent(Event::Alarm(1));
+    assert_eq!(mediator.stats().queued(), 4);
+
+    mediator.next().unwrap();
+    mediator.publish_urgent(Event::Alarm(2));
+    assert_eq!(mediator.next_all(), 4);
+
+    assert_eq!(
+        *received.lock().unwrap(),
+        vec![
+            Event::Alarm(0),
+            Event::Alarm(1),
+            Event::Alarm(2),
+            Event::Tick(0),
+            Event::Tick(1)
+        ]
+    );
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn urgent_test_async() {
+    use crate::asynchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Event {
+        Tick(u32),
+        Alarm,
+    }
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let listener = received.clone();
+    let mediator = BasicAsyncMediator::<Event>::builder()
+        .add_listener(move |ev: &Event| listener.lock().unwrap().push(ev.clone()))
+        .with_capacity(1)
+        .build();
+
+    async_std::task::block_on(async {
+        mediator.publish(Event::Tick(0)).await;
+        mediator.publish_urgent(Event::Alarm).await;
+        assert_eq!(mediator.next_all().await, 2);
+    });
+
+    assert_eq!(
+        *received.lock().unwrap(),
+        vec![Event::Alarm, Event::Tick(0)]
+    );
+}
+
+#[cfg(all(not(feature = "async"), feature = "std"))]
+#[test]
+fn catch_all_test_sync() {
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct NamedEvent(&'static str);
+
+    let seen = Arc::new(Mutex::new(vec![]));
+    let cloned = seen.clone();
+    let mediator = BasicMediator::<NamedEvent>::builder()
+        .add_listener_for_topic("orders.*", |_: &NamedEvent| {})
+        .add_catch_all_listener(move |ev: &NamedEvent, meta: &EventMeta| {
+            assert!(meta.timestamp().is_some());
+            cloned.lock().unwrap().push((
+                ev.0,
+                meta.sequence().unwrap(),
+                meta.topic().map(str::to_owned),
+            ));
+        })
+        .build();
+
+    mediator.publish_to("orders.created", NamedEvent("created"));
+    mediator.publish_to("users.created", NamedEvent("user"));
+    mediator.publish(NamedEvent("untopiced"));
+
+    assert_eq!(mediator.next_all(), 3);
+    let seen = seen.lock().unwrap();
+    let names: Vec<_> = seen.iter().map(|(name, ..)| *name).collect();
+    assert_eq!(names, vec!["created", "user", "untopiced"]);
+    assert!(seen.windows(2).all(|pair| pair[0].1 < pair[1].1));
+    let topics: Vec<_> = seen.iter().map(|(.., topic)| topic.as_deref()).collect();
+    assert_eq!(
+        topics,
+        vec![Some("orders.created"), Some("users.created"), None]
+    );
+}
+
+#[cfg(feature = "async-only")]
+#[test]
+fn async_only_test_async() {
+    use crate::asynchronous::contextaware::*;
+
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone)]
+    struct CountedEvent(usize);
+
+    #[derive(Debug, Default)]
+    struct Counter(Mutex<usize>);
+
+    struct Increment;
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Counter, Increment, CountedEvent>
+        for CxAwareAsyncMediator<Counter, CountedEvent>
+    {
+        async fn handle(&self, _req: Increment, cx: &Counter) {
+            let count = {
+                let mut count = cx.0.lock().unwrap();
+                *count += 1;
+                *count
+            };
+            self.publish(CountedEvent(count)).await;
+        }
+    }
+
+    // Only the sync-only mediators are compiled out, the async ones keep working.
+    let received = Arc::new(Mutex::new(vec![]));
+    let cloned = received.clone();
+    async_std::task::block_on(async {
+        let async_mediator = CxAwareAsyncMediator::<Counter, CountedEvent>::builder()
+            .add_listener(move |ev: &CountedEvent| cloned.lock().unwrap().push(ev.0))
+            .add_context(Counter::default())
+            .build()
+            .unwrap();
+
+        async_mediator.send(Increment).await;
+        async_mediator.send(Increment).await;
+        assert_eq!(async_mediator.next_all().await, 2);
+    });
+
+    assert_eq!(*received.lock().unwrap(), vec![1, 2]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn default_channel_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::channel::StdChannel;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone)]
+    struct NumberEvent(usize);
+
+    let received = Arc::new(Mutex::new(vec![]));
+    let cloned = received.clone();
+
+    async_std::task::block_on(async {
+        let mediator = Arc::new(
+            BasicAsyncMediator::<NumberEvent>::builder()
+                .add_listener(move |ev: &NumberEvent| cloned.lock().unwrap().push(ev.0))
+                .with_capacity(2)
+                .build(),
+        );
+        assert!(format!("{mediator:?}").contains("backend: Async"));
+
+        let publisher = mediator.clone();
+        let publishing = async_std::task::spawn(async move {
+            for n in 1..=5 {
+                publisher.publish(NumberEvent(n)).await;
+            }
+        });
+        for _ in 1..=5 {
+            mediator.next_async().await.unwrap();
+        }
+        publishing.await;
+        assert_eq!(*received.lock().unwrap(), vec![1, 2, 3, 4, 5]);
+
+        let mediator = BasicAsyncMediator::<NumberEvent>::builder()
+            .with_profile(Profile::Deterministic)
+            .build();
+        assert!(format!("{mediator:?}").contains("backend: Async"));
+
+        let mediator = BasicAsyncMediator::<NumberEvent>::builder()
+            .with_channel_backend::<StdChannel>()
+            .build();
+        assert!(!format!("{mediator:?}").contains("backend: Async"));
+    });
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn default_channel_await_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::topics::Envelope;
+
+    use async_std::future::timeout;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone)]
+    struct NumberEvent(usize);
+
+    async_std::task::block_on(async {
+        // Receiving awaits the channel itself, not only the wake-up of a publish.
+        let mediator = Arc::new(BasicAsyncMediator::<NumberEvent>::builder().build());
+        let receiver = mediator.clone();
+        let receiving = async_std::task::spawn(async move { receiver.next_async().await });
+        async_std::task::sleep(Duration::from_millis(50)).await;
+        mediator
+            .inner
+            .basic
+            .try_dispatch(Envelope::new(None, NumberEvent(1)))
+            .unwrap();
+        let received = timeout(Duration::from_secs(5), receiving).await;
+        assert!(matches!(received, Ok(Ok(()))));
+
+        // Publishing into a full channel awaits space, not only the wake-up of a processed event.
+        let mediator = Arc::new(
+            BasicAsyncMediator::<NumberEvent>::builder()
+                .with_capacity(1)
+                .with_overflow_policy(OverflowPolicy::Block)
+                .build(),
+        );
+        mediator.publish(NumberEvent(1)).await;
+        let publisher = mediator.clone();
+        let publishing =
+            async_std::task::spawn(async move { publisher.publish(NumberEvent(2)).await });
+        async_std::task::sleep(Duration::from_millis(50)).await;
+        let taken = mediator.inner.basic.channel.try_recv().unwrap();
+        assert_eq!(taken.ev.0, 1);
+        assert!(timeout(Duration::from_secs(5), publishing).await.is_ok());
+        assert_eq!(mediator.inner.basic.channel.try_recv().unwrap().ev.0, 2);
+
+        // Cancelling a publish waiting for space doesn't count its event.
+        mediator.publish(NumberEvent(3)).await;
+        let cancelled = timeout(Duration::from_millis(50), mediator.publish(NumberEvent(4))).await;
+        assert!(cancelled.is_err());
+        assert_eq!(mediator.inner.basic.channel.len(), 1);
+    });
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn default_channel_await_urgent_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::topics::Envelope;
+
+    use async_std::future::timeout;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Debug, Clone)]
+    struct NumberEvent(usize);
+
+    async_std::task::block_on(async {
+        let received = Arc::new(Mutex::new(vec![]));
+        let cloned = received.clone();
+        let mediator = Arc::new(
+            BasicAsyncMediator::<NumberEvent>::builder()
+                .add_listener(move |ev: &NumberEvent| cloned.lock().unwrap().push(ev.0))
+                .build(),
+        );
+
+        // An urgent event arriving while awaiting the channel is still processed first.
+        let receiver = mediator.clone();
+        let receiving = async_std::task::spawn(async move { receiver.next_async().await });
+        async_std::task::sleep(Duration::from_millis(50)).await;
+        let m = &mediator.inner.basic;
+        m.channel.send_urgent(Envelope::new(None, NumberEvent(1)));
+        m.try_dispatch(Envelope::new(None, NumberEvent(2))).unwrap();
+        let processed = timeout(Duration::from_secs(5), receiving).await;
+        assert!(matches!(processed, Ok(Ok(()))));
+        assert_eq!(*received.lock().unwrap(), vec![1]);
+
+        assert_eq!(mediator.next_all().await, 1);
+        assert_eq!(*received.lock().unwrap(), vec![1, 2]);
+        assert_eq!(m.channel.len(), 0);
+    });
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn mediator_module_test_sync() {
+    use crate::mediator_module;
+    use crate::synchronous::basic::*;
+
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Event {
+        Pinged,
+        UserCreated(u32),
+    }
+
+    struct Ping;
+    struct CreateUser(u32);
+    struct Unlisted;
+
+    #[derive(Default)]
+    struct CreateUserHandler;
+
+    impl Handler<CreateUser, Event, u32> for CreateUserHandler {
+        fn handle(&self, mediator: &BasicMediator<Event>, req: CreateUser) -> u32 {
+            mediator.publish(Event::UserCreated(req.0));
+            req.0 + 1
+        }
+    }
+
+    impl RequestHandler<Ping, Event> for BasicMediator<Event> {
+        fn handle(&self, _req: Ping) {
+            self.publish(Event::Pinged);
+        }
+    }
+
+    impl RequestHandler<Unlisted, Event> for BasicMediator<Event> {
+        fn handle(&self, _req: Unlisted) {}
+    }
+
+    mediator_module! {
+        struct Users for Event {
+            requests: [Ping, CreateUser: u32],
+            handlers: [CreateUser => CreateUserHandler],
+        }
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+
+    let mediator = Users::register(
+        BasicMediator::<Event>::builder()
+            .add_listener(move |ev: &Event| events_clone.lock().unwrap().push(ev.clone())),
+    )
+    .build();
+
+    assert!(mediator.send_dyn(Box::new(Ping)).is_ok());
+    assert!(mediator.send_dyn(Box::new(CreateUser(1))).is_ok());
+    assert!(mediator.send_dyn(Box::new(Unlisted)).is_err());
+    assert_eq!(mediator.send_and_receive::<_, u32>(CreateUser(2)), 3);
+    assert_eq!(mediator.next_all(), 3);
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![Event::Pinged, Event::UserCreated(1), Event::UserCreated(2)]
+    );
+}
+
+#[cfg(feature = "brokers")]
+#[test]
+fn brokers_test_async() {
+    use crate::asynchronous::basic::*;
+    use crate::brokers::{
+        inject, BrokerForwarder, BrokerMessage, InboundBridge, MemoryBroker, OutboundBridge,
+    };
+
+    use serde::{Deserialize, Serialize};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct PriceEvent(u32);
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+
+    async_std::task::block_on(async {
+        let broker = MemoryBroker::new();
+        let mut audit = broker.subscribe();
+
+        let (forwarder, outbound) = BrokerForwarder::spawn(broker.clone());
+        let local = BasicAsyncMediator::<PriceEvent>::builder()
+            .with_observer(forwarder)
+            .build();
+
+        let remote = Arc::new(
+            BasicAsyncMediator::<PriceEvent>::builder()
+                .add_listener_for_topic("prices", move |ev: &PriceEvent| {
+                    cloned.lock().unwrap().push(ev.0)
+                })
+                .build(),
+        );
+        let inbound = inject(broker.subscribe(), remote.clone());
+
+        local.publish(PriceEvent(1)).await;
+        broker
+            .send(BrokerMessage {
+                topic: None,
+                payload: b"malformed".to_vec(),
+            })
+            .await
+            .unwrap();
+        local.publish_to("prices", PriceEvent(2)).await;
+
+        remote.next_async().await.unwrap();
+        remote.next_async().await.unwrap();
+
+        assert_eq!(outbound.shutdown().await, 2);
+        assert_eq!(inbound.shutdown().await, 2);
+
+        let mut messages = Vec::new();
+        for _ in 0..3 {
+            let message = audit.recv().await.unwrap();
+            messages.push((message.topic, message.payload));
+        }
+        messages.sort();
+        assert_eq!(
+            messages,
+            vec![
+                (None, b"1".to_vec()),
+                (None, b"malformed".to_vec()),
+                (Some("prices".to_owned()), b"2".to_vec()),
+            ]
+        );
+    });
+
+    assert_eq!(*seen.lock().unwrap(), vec![2]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn scope_test_async() {
+    use async_trait::async_trait;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::sync::{Arc, Mutex};
+
+    use crate::asynchronous::contextaware::*;
+
+    struct AddRequest(usize);
+    #[derive(Debug)]
+    struct AddedEvent(usize);
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Arc<Mutex<usize>>, AddRequest, AddedEvent>
+        for CxAwareAsyncMediator<Arc<Mutex<usize>>, AddedEvent>
+    {
+        async fn handle(&self, req: AddRequest, cx: &Arc<Mutex<usize>>) {
+            *cx.lock().unwrap() += req.0;
+            self.publish(AddedEvent(req.0)).await
+        }
+    }
+
+    let total = Arc::new(Mutex::new(0usize));
+    let seen = Arc::new(Mutex::new(0usize));
+    let cloned = seen.clone();
+
+    let escaped = async_std::task::block_on(async {
+        let mediator = CxAwareAsyncMediator::<Arc<Mutex<usize>>, AddedEvent>::builder()
+            .add_listener(move |ev: &AddedEvent| *cloned.lock().unwrap() += ev.0)
+            .add_context(total.clone())
+            .build()
+            .unwrap();
+
+        let (sent, escaped) = mediator
+            .scoped(|scope| async move {
+                scope.spawn_worker();
+                for n in 1..=3 {
+                    scope.send_detached(AddRequest(n));
+                }
+                let inner = scope.clone();
+                async_std::task::spawn(async move { inner.send_detached(AddRequest(4)) }).await;
+                (4, scope)
+            })
+            .await;
+        assert_eq!(sent, 4);
+
+        // Every request was handled and every event processed before the scope returned.
+        assert_eq!(*total.lock().unwrap(), 10);
+        assert_eq!(*seen.lock().unwrap(), 10);
+        escaped
+    });
+
+    let spawned = catch_unwind(AssertUnwindSafe(|| escaped.spawn_worker()));
+    assert!(spawned.is_err());
+
+    let counter = Arc::new(Mutex::new(1usize));
+    async_std::task::block_on(
+        CxAwareAsyncMediator::<Arc<Mutex<usize>>, AddedEvent>::scope(
+            counter.clone(),
+            |scope| async move {
+                scope.send(AddRequest(2)).await;
+                scope.send_detached(AddRequest(3));
+            },
+        ),
+    );
+    assert_eq!(*counter.lock().unwrap(), 6);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn spawned_listener_test_async() {
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use crate::asynchronous::basic::*;
+
+    #[derive(Debug, Clone)]
+    struct UploadEvent(u32);
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+
+    async_std::task::block_on(async {
+        let (uploaded, received) = async_std::channel::unbounded();
+
+        let mediator = BasicAsyncMediator::<UploadEvent>::builder()
+            .add_listener(move |ev: &UploadEvent| cloned.lock().unwrap().push(ev.0))
+            .add_spawned_listener(move |ev: &UploadEvent| {
+                let (uploaded, id) = (uploaded.clone(), ev.0);
+                async move {
+                    async_std::task::sleep(Duration::from_millis(50)).await;
+                    assert_ne!(id, 2, "upload failed");
+                    uploaded.send(id).await.unwrap();
+                }
+            })
+            .build();
+
+        for id in 1..=3 {
+            mediator.publish(UploadEvent(id)).await;
+        }
+        let start = Instant::now();
+        assert_eq!(mediator.next_all().await, 3);
+        assert!(start.elapsed() < Duration::from_millis(50));
+        assert_eq!(*seen.lock().unwrap(), vec![1, 2, 3]);
+
+        // The panicking upload is skipped, the others arrive in publishing order.
+        assert_eq!(received.recv().await, Ok(1));
+        assert_eq!(received.recv().await, Ok(3));
+
+        // Dropping the mediator ends the task of the listener.
+        drop(mediator);
+        assert!(received.recv().await.is_err());
+    });
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn named_listener_test_sync() {
+    use crate::synchronous::basic::*;
+
+    #[derive(Debug)]
+    struct OrderEvent(u32);
+
+    let mediator = BasicMediator::<OrderEvent>::builder()
+        .add_listener_named("audit-log", |_: &OrderEvent| {})
+        .add_listener_named("billing", |ev: &OrderEvent| assert_ne!(ev.0, 1))
+        .add_listener(|_: &OrderEvent| {})
+        .isolate_listeners(true)
+        .collect_dead_letters(true)
+        .build();
+
+    mediator.publish(OrderEvent(0));
+    mediator.next().unwrap();
+
+    let stats = mediator.stats();
+    let names: Vec<_> = stats.listeners().iter().map(ListenerStats::name).collect();
+    assert_eq!(names[..2], ["audit-log", "billing"]);
+    assert!(names[2].contains("named_listener_test_sync"));
+    assert!(stats
+        .listeners()
+        .iter()
+        .all(|stats| stats.invocations() == 1));
+
+    mediator.publish(OrderEvent(1));
+    match mediator.next() {
+        Err(err @ NextError::ListenerPanicked(_)) => {
+            assert_eq!(err.to_string(), "1 listener(s) panicked: billing");
+            let NextError::ListenerPanicked(panics) = err else {
+                unreachable!()
+            };
+            assert_eq!(panics[0].name(), "billing");
+            assert_eq!(panics[0].handle(), stats.listeners()[1].handle());
+        }
+        res => panic!("unexpected result: {:?}", res),
+    }
+
+    let letter = mediator.dead_letters().next().unwrap();
+    assert_eq!(letter.listener_names(), ["billing"]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn named_listener_test_async() {
+    use crate::asynchronous::basic::*;
+
+    #[derive(Debug)]
+    struct OrderEvent;
+
+    async_std::task::block_on(async {
+        let mediator = BasicAsyncMediator::<OrderEvent>::builder()
+            .add_listener_named("audit-log", |_: &OrderEvent| {})
+            .add_async_listener_named("remote-sync", |_: &OrderEvent| async {})
+            .build();
+
+        mediator.publish(OrderEvent).await;
+        mediator.next().await.unwrap();
+
+        let stats = mediator.stats();
+        let mut names: Vec<_> = stats.listeners().iter().map(ListenerStats::name).collect();
+        names.sort();
+        assert_eq!(names, ["audit-log", "remote-sync"]);
+    });
+}
+
+#[cfg(not(feature = "async-only"))]
+#[test]
+fn publish_interceptor_test_sync() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::synchronous::basic::*;
+
+    #[derive(Debug)]
+    struct NumberEvent(u32);
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+
+    let mediator = BasicMediator::<NumberEvent>::builder()
+        .add_publish_interceptor(|ev: NumberEvent| (ev.0 != 0).then(|| NumberEvent(ev.0 + 1)))
+        .add_publish_interceptor(|ev: NumberEvent| Some(NumberEvent(ev.0 * 10)))
+        .add_listener(move |ev: &NumberEvent| cloned.lock().unwrap().push(ev.0))
+        .build();
+
+    mediator.publish(NumberEvent(1));
+    mediator.publish(NumberEvent(0));
+    assert!(mediator.try_publish(NumberEvent(0)).is_ok());
+    mediator.publish_to("numbers", NumberEvent(2));
+    mediator.publish_urgent(NumberEvent(3));
+
+    assert_eq!(mediator.stats().published(), 3);
+    assert_eq!(mediator.next_all(), 3);
+    assert_eq!(*seen.lock().unwrap(), vec![40, 20, 30]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn publish_interceptor_test_async() {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use crate::asynchronous::contextaware::*;
+
+    #[derive(Debug)]
+    struct NumberEvent(u32);
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+
+    async_std::task::block_on(async {
+        let mediator = CxAwareAsyncMediator::<(), NumberEvent>::builder()
+            .add_publish_interceptor(|ev: NumberEvent| (ev.0 % 2 == 1).then_some(ev))
+            .add_listener(move |ev: &NumberEvent| cloned.lock().unwrap().push(ev.0))
+            .add_context(())
+            .build()
+            .unwrap();
+
+        for n in 0..4 {
+            mediator.publish(NumberEvent(n)).await;
+        }
+        mediator.publish_after(NumberEvent(4), Duration::ZERO).await;
+        mediator.publish_after(NumberEvent(5), Duration::ZERO).await;
+        async_std::task::sleep(Duration::from_millis(20)).await;
+
+        assert_eq!(mediator.next_all().await, 3);
+    });
+
+    assert_eq!(*seen.lock().unwrap(), vec![1, 3, 5]);
+}
+
+#[cfg(not(feature = "async-only"))]
+#[test]
+fn request_events_test_sync() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::synchronous::basic::*;
+    use crate::system::SystemEvent;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Saved;
+
+    struct Save;
+    struct TrySave(bool);
+
+    impl RequestHandler<Save, SystemEvent<Saved>> for BasicMediator<SystemEvent<Saved>> {
+        fn handle(&self, _: Save) {
+            self.publish(Saved.into());
+        }
+    }
+
+    impl TryRequestHandler<TrySave, SystemEvent<Saved>, &'static str>
+        for BasicMediator<SystemEvent<Saved>>
+    {
+        fn handle(&self, req: TrySave) -> Result<(), &'static str> {
+            if req.0 {
+                Ok(())
+            } else {
+                Err("not saved")
+            }
+        }
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let cloned = events.clone();
+
+    let mediator = BasicMediator::<SystemEvent<Saved>>::builder()
+        .with_request_events(true)
+        .add_listener(move |ev: &SystemEvent<Saved>| cloned.lock().unwrap().push(ev.clone()))
+        .build();
+
+    mediator.send(Save);
+    assert_eq!(mediator.try_send(TrySave(false)), Err("not saved"));
+    assert_eq!(mediator.try_send(TrySave(true)), Ok(()));
+    assert_eq!(mediator.next_all(), 4);
+
+    let events = events.lock().unwrap();
+    assert_eq!(events[0], SystemEvent::Event(Saved));
+    assert!(matches!(
+        events[1],
+        SystemEvent::RequestCompleted { request_type, .. } if request_type.ends_with("Save")
+    ));
+    assert!(matches!(
+        &events[2],
+        SystemEvent::RequestFailed { request_type, error_type }
+            if request_type.ends_with("TrySave") && *error_type == "&str"
+    ));
+    assert!(matches!(
+        events[3],
+        SystemEvent::RequestCompleted { request_type, .. } if request_type.ends_with("TrySave")
+    ));
+
+    let mediator = BasicMediator::<SystemEvent<Saved>>::builder()
+        .with_request_events(false)
+        .build();
+
+    mediator.send(Save);
+    assert_eq!(mediator.next_all(), 1);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn request_events_test_async() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::asynchronous::contextaware::*;
+    use crate::system::SystemEvent;
+    use async_trait::async_trait;
+
+    #[derive(Debug, Clone)]
+    struct Saved;
+
+    struct Save;
+    struct TrySave;
+    #[derive(Debug, PartialEq)]
+    struct SaveError(u32);
+
+    impl From<ReentrantSendError> for SaveError {
+        fn from(_: ReentrantSendError) -> Self {
+            unreachable!("requests aren't sent from within handlers")
+        }
+    }
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<(), Save, SystemEvent<Saved>>
+        for CxAwareAsyncMediator<(), SystemEvent<Saved>>
+    {
+        async fn handle(&self, _: Save, _: &()) {
+            self.publish(Saved.into()).await;
+        }
+    }
+
+    #[async_trait]
+    impl TryCxAwareAsyncRequestHandler<(), TrySave, SystemEvent<Saved>, SaveError>
+        for CxAwareAsyncMediator<(), SystemEvent<Saved>>
+    {
+        async fn handle(&self, _: TrySave, _: &()) -> Result<(), SaveError> {
+            Err(SaveError(7))
+        }
+    }
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let cloned = seen.clone();
+
+    async_std::task::block_on(async {
+        let mediator = CxAwareAsyncMediator::<(), SystemEvent<Saved>>::builder()
+            .with_request_events(true)
+            .add_listener(move |ev: &SystemEvent<Saved>| {
+                cloned.lock().unwrap().push(match ev {
+                    SystemEvent::Event(_) => "event".to_string(),
+                    SystemEvent::RequestCompleted { .. } => "completed".to_string(),
+                    SystemEvent::RequestFailed { error_type, .. } => error_type.to_string(),
+                })
+            })
+            .add_context(())
+            .build()
+            .unwrap();
+
+        mediator.send(Save).await;
+        assert_eq!(mediator.try_send(TrySave).await, Err(SaveError(7)));
+        assert_eq!(mediator.next_all().await, 3);
+    });
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec!["event", "completed", std::any::type_name::<SaveError>()]
+    );
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn contexts_test_async() {
+    use std::time::Duration;
+
+    use crate::asynchronous::contextaware::*;
+    use async_std::channel::{self, Receiver, Sender};
+    use async_trait::async_trait;
+
+    #[derive(Debug, Clone)]
+    enum MyEvent {
+        Saved(u32),
+    }
+
+    type Cx = Contexts<(Vec<u32>, Vec<u32>, (Sender<()>, Receiver<()>))>;
+
+    struct SaveAfterCached(u32);
+    struct Cache(u32);
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Cx, SaveAfterCached, MyEvent>
+        for CxAwareAsyncMediator<Cx, MyEvent>
+    {
+        async fn handle(&self, req: SaveAfterCached, cx: &Cx) {
+            let (db, _, cached) = cx.split();
+            let mut db = db.lock().await;
+            let cached = cached.read().await.1.clone();
+            cached.recv().await.unwrap();
+            db.push(req.0);
+            self.publish(MyEvent::Saved(req.0)).await;
+        }
+    }
+
+    #[async_trait]
+    impl CxAwareAsyncRequestHandler<Cx, Cache, MyEvent> for CxAwareAsyncMediator<Cx, MyEvent> {
+        async fn handle(&self, req: Cache, cx: &Cx) {
+            cx.get::<1>().lock().await.push(req.0);
+            cx.get::<2>().read().await.0.send(()).await.unwrap();
+        }
+    }
+
+    let mediator = CxAwareAsyncMediator::<Cx, MyEvent>::builder()
+        .add_contexts((vec![], vec![], channel::unbounded()))
+        .add_listener(|MyEvent::Saved(n): &MyEvent| assert_eq!(*n, 1))
+        .build()
+        .unwrap();
+
+    async_std::task::block_on(async {
+        // The first request holds the first element until the second one used the second element.
+        let both =
+            futures::future::join(mediator.send(SaveAfterCached(1)), mediator.send(Cache(1)));
+        async_std::future::timeout(Duration::from_secs(5), both)
+            .await
+            .expect("the elements are locked independently");
+
+        assert_eq!(mediator.next_all().await, 1);
+    });
+}